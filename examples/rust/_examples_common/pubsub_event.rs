@@ -26,7 +26,8 @@ pub enum PubSubEvent {
 
 impl From<PubSubEvent> for EventId {
     fn from(value: PubSubEvent) -> Self {
-        EventId::new(value as usize)
+        // all PubSubEvent values are far below EventId::max_supported()
+        EventId::new_unchecked(value as usize)
     }
 }
 
@@ -30,7 +30,7 @@ fn main() -> Result<(), Box<dyn core::error::Error>> {
     let mut counter: usize = 0;
     while node.wait(CYCLE_TIME).is_ok() {
         counter += 1;
-        notifier.notify_with_custom_event_id(EventId::new(counter % max_event_id))?;
+        notifier.notify_with_custom_event_id(EventId::new(counter % max_event_id)?)?;
 
         println!("Trigger event with id {counter} ...");
     }
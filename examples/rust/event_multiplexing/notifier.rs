@@ -29,7 +29,7 @@ fn main() -> Result<(), Box<dyn core::error::Error>> {
     let notifier = event.notifier_builder().create()?;
 
     while node.wait(CYCLE_TIME).is_ok() {
-        notifier.notify_with_custom_event_id(EventId::new(args.event_id))?;
+        notifier.notify_with_custom_event_id(EventId::new(args.event_id)?)?;
 
         println!("[service: \"{}\"] Trigger event ...", args.service);
     }
@@ -608,7 +608,7 @@ impl<K: Eq, V: Clone> RelocatableFlatMap<K, V> {
 pub struct FixedSizeFlatMap<K: Eq, V: Clone, const CAPACITY: usize> {
     map: RelocatableFlatMap<K, V>,
     _idx_to_data: MaybeUninit<[usize; CAPACITY]>,
-    _idx_to_data_free_list: MaybeUninit<[FreeListEntry; CAPACITY]>,
+    _idx_to_data_free_list: MaybeUninit<[FreeListEntry<usize>; CAPACITY]>,
     _data: MaybeUninit<[Option<Entry<K, V>>; CAPACITY]>,
     _data_next_free_index: MaybeUninit<[usize; CAPACITY]>,
 }
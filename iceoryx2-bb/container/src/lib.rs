@@ -109,3 +109,21 @@ pub mod string;
 pub(crate) mod vec;
 /// A trait that defines the interface of a vector and several vector variants.
 pub mod vector;
+
+/// Marks the [`GenericRelocatable`](iceoryx2_bb_elementary::relocatable_ptr::GenericRelocatable)
+/// pointers the shared-memory compatible containers can be built upon. The pointer defines how
+/// wide the stored distances to the underlying memory are.
+#[doc(hidden)]
+pub trait RelocatablePointerWidth:
+    iceoryx2_bb_elementary::relocatable_ptr::GenericRelocatable
+{
+}
+
+impl RelocatablePointerWidth
+    for iceoryx2_bb_elementary::relocatable_ptr::GenericRelocatablePointer
+{
+}
+impl RelocatablePointerWidth
+    for iceoryx2_bb_elementary::relocatable_ptr::GenericRelocatablePointer32
+{
+}
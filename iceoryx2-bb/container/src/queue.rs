@@ -105,7 +105,10 @@ use core::marker::PhantomData;
 use core::{alloc::Layout, fmt::Debug, mem::MaybeUninit};
 use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
 use iceoryx2_bb_elementary::math::unaligned_mem_size;
-use iceoryx2_bb_elementary::relocatable_ptr::{GenericRelocatablePointer, RelocatablePointer};
+use iceoryx2_bb_elementary::relocatable_ptr::{
+    GenericRelocatablePointer, GenericRelocatablePointer32, RelocatablePointer,
+};
+use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_elementary_traits::allocator::{AllocationError, BaseAllocator};
 use iceoryx2_bb_elementary_traits::generic_pointer::GenericPointer;
 use iceoryx2_bb_elementary_traits::owning_pointer::{GenericOwningPointer, OwningPointer};
@@ -114,6 +117,8 @@ use iceoryx2_bb_elementary_traits::pointer_trait::PointerTrait;
 pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::{fail, fatal_panic};
+
+use crate::RelocatablePointerWidth;
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
 
 /// Queue with run-time fixed size capacity. In contrast to its counterpart the
@@ -121,6 +126,9 @@ use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
 pub type Queue<T> = MetaQueue<T, GenericOwningPointer>;
 /// **Non-movable** relocatable queue with runtime fixed size capacity.
 pub type RelocatableQueue<T> = MetaQueue<T, GenericRelocatablePointer>;
+/// **Non-movable** relocatable queue with runtime fixed size capacity that stores its data
+/// pointer as a 32-bit offset. The data must reside within ±2 GiB of the queue itself.
+pub type RelocatableQueue32<T> = MetaQueue<T, GenericRelocatablePointer32>;
 
 #[doc(hidden)]
 /// **Non-movable** relocatable queue with runtime fixed size capacity.
@@ -167,6 +175,12 @@ impl<T> Queue<T> {
         unsafe { self.peek_mut_impl() }
     }
 
+    /// Calls the provided callback with the index and a reference of every element, starting
+    /// with the oldest element, until it returns [`CallbackProgression::Stop`].
+    pub fn for_each<F: FnMut(usize, &T) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.for_each_impl(callback) }
+    }
+
     /// Removes the element from the beginning of the queue. If the queue is empty it returns [`None`].
     pub fn pop(&mut self) -> Option<T> {
         unsafe { self.pop_impl() }
@@ -182,6 +196,40 @@ impl<T> Queue<T> {
     pub fn push_with_overflow(&mut self, value: T) -> Option<T> {
         unsafe { self.push_with_overflow_impl(value) }
     }
+
+    /// Increases the capacity so that at least `additional` more elements fit into the queue.
+    /// If the capacity is already sufficient nothing happens. The order of the contained
+    /// elements is preserved.
+    pub fn reserve(&mut self, additional: usize) {
+        let new_capacity = self.len + additional;
+        if new_capacity <= self.capacity {
+            return;
+        }
+
+        let old_capacity = self.capacity;
+        let head = if old_capacity == 0 {
+            0
+        } else {
+            (self.start - self.len) % old_capacity
+        };
+
+        unsafe { self.data_ptr.realloc(new_capacity, old_capacity) };
+
+        // the elements wrap around the end of the old memory, move the leading part to the
+        // end of the new memory so that the elements are contiguous modulo the new capacity
+        let mut new_head = head;
+        if head + self.len > old_capacity {
+            let head_len = old_capacity - head;
+            new_head = new_capacity - head_len;
+            unsafe {
+                let data = self.data_ptr.as_mut_ptr();
+                core::ptr::copy(data.add(head), data.add(new_head), head_len);
+            }
+        }
+
+        self.capacity = new_capacity;
+        self.start = new_head + self.len;
+    }
 }
 
 impl<T: Copy + Debug, Ptr: GenericPointer + Debug> MetaQueue<T, Ptr> {
@@ -212,10 +260,10 @@ impl<T: Copy + Debug, Ptr: GenericPointer + Debug> MetaQueue<T, Ptr> {
     }
 }
 
-impl<T> RelocatableContainer for RelocatableQueue<T> {
+impl<T, Ptr: RelocatablePointerWidth> RelocatableContainer for MetaQueue<T, Ptr> {
     unsafe fn new_uninit(capacity: usize) -> Self {
         Self {
-            data_ptr: RelocatablePointer::new_uninit(),
+            data_ptr: Ptr::new_uninit(),
             start: 0,
             len: 0,
             capacity,
@@ -238,12 +286,20 @@ impl<T> RelocatableContainer for RelocatableQueue<T> {
             );
         }
 
-        self.data_ptr.init(fail!(from "Queue::init", when allocator
-             .allocate(Layout::from_size_align_unchecked(
-                 core::mem::size_of::<T>() * self.capacity,
-                 core::mem::align_of::<T>(),
-             )), "Failed to initialize queue since the allocation of the data memory failed."
-        ));
+        let layout = Layout::from_size_align_unchecked(
+            core::mem::size_of::<T>() * self.capacity,
+            core::mem::align_of::<T>(),
+        );
+        let memory = match allocator.allocate(layout) {
+            Ok(memory) => memory,
+            Err(e) => {
+                fail!(from "Queue::init", with e,
+                    "Failed to initialize queue since the allocation of {} bytes for the data memory failed ({:?}, {:?}).",
+                    layout.size(), e, allocator.statistics());
+            }
+        };
+        fail!(from "Queue::init", when Ptr::init(&self.data_ptr, memory),
+            "Failed to initialize queue since the data memory cannot be addressed by the underlying pointer.");
         self.is_initialized
             .store(true, core::sync::atomic::Ordering::Relaxed);
 
@@ -255,14 +311,43 @@ impl<T> RelocatableContainer for RelocatableQueue<T> {
     }
 }
 
-unsafe impl<T: ZeroCopySend> ZeroCopySend for RelocatableQueue<T> {}
+unsafe impl<T: ZeroCopySend, Ptr: RelocatablePointerWidth> ZeroCopySend for MetaQueue<T, Ptr> {}
 
-impl<T> RelocatableQueue<T> {
+impl<T, Ptr: RelocatablePointerWidth> MetaQueue<T, Ptr> {
     /// Returns the required memory size for a queue with a specified capacity
     pub const fn const_memory_size(capacity: usize) -> usize {
         unaligned_mem_size::<T>(capacity)
     }
+}
 
+impl<T> RelocatableQueue<T> {
+    /// Creates an already initialized [`RelocatableQueue`] whose data resides `distance` bytes
+    /// after the memory location of the queue itself. Used to construct self-contained
+    /// containers in a const context.
+    ///
+    /// # Safety
+    ///
+    ///  * the memory at `distance` must be part of the same object as the queue
+    ///  * the memory must contain `len` initialized elements starting at index `start - len`
+    ///
+    pub(crate) const unsafe fn new_preinitialized(
+        distance: isize,
+        capacity: usize,
+        start: usize,
+        len: usize,
+    ) -> Self {
+        Self {
+            data_ptr: RelocatablePointer::new(distance),
+            start,
+            len,
+            capacity,
+            is_initialized: IoxAtomicBool::new(true),
+            _phantom_data: PhantomData,
+        }
+    }
+}
+
+impl<T, Ptr: RelocatablePointerWidth> MetaQueue<T, Ptr> {
     /// Removes all elements from the queue
     ///
     /// # Safety
@@ -295,6 +380,17 @@ impl<T> RelocatableQueue<T> {
         unsafe { self.peek_mut_impl() }
     }
 
+    /// Calls the provided callback with the index and a reference of every element, starting
+    /// with the oldest element, until it returns [`CallbackProgression::Stop`].
+    ///
+    /// # Safety
+    ///
+    ///  * [`Queue::init()`] must have been called once before
+    ///
+    pub unsafe fn for_each<F: FnMut(usize, &T) -> CallbackProgression>(&self, callback: F) {
+        self.for_each_impl(callback)
+    }
+
     /// Removes the element from the beginning of the queue. If the queue is empty it returns [`None`].
     ///
     /// # Safety
@@ -338,6 +434,13 @@ impl<T, Ptr: GenericPointer> MetaQueue<T, Ptr> {
             );
     }
 
+    /// Returns true if the queue was initialized, otherwise false. A [`Queue`] is always
+    /// initialized, a [`RelocatableQueue`] only after [`RelocatableContainer::init()`] was called.
+    pub fn is_initialized(&self) -> bool {
+        self.is_initialized
+            .load(core::sync::atomic::Ordering::Relaxed)
+    }
+
     /// Returns true if the queue is empty, otherwise false
     pub fn is_empty(&self) -> bool {
         self.len == 0
@@ -386,6 +489,21 @@ impl<T, Ptr: GenericPointer> MetaQueue<T, Ptr> {
         Some((*self.data_ptr.as_ptr().add(index)).assume_init_ref())
     }
 
+    pub(crate) unsafe fn for_each_impl<F: FnMut(usize, &T) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        self.verify_init("for_each()");
+
+        for n in 0..self.len {
+            let index = (self.start - self.len + n) % self.capacity;
+            let value = (*self.data_ptr.as_ptr().add(index)).assume_init_ref();
+            if callback(n, value) == CallbackProgression::Stop {
+                return;
+            }
+        }
+    }
+
     pub(crate) unsafe fn pop_impl(&mut self) -> Option<T> {
         self.verify_init("pop()");
 
@@ -464,11 +582,15 @@ impl<T, const CAPACITY: usize> PlacementDefault for FixedSizeQueue<T, CAPACITY>
         let state_ptr = core::ptr::addr_of_mut!((*ptr).state);
         state_ptr.write(RelocatableQueue::new_uninit(CAPACITY));
 
-        let allocator = BumpAllocator::new((*ptr)._data.as_mut_ptr().cast());
-        (*ptr)
-            .state
-            .init(&allocator)
-            .expect("All required memory is preallocated.");
+        let allocator = BumpAllocator::new_with_size_limit(
+            (*ptr)._data.as_mut_ptr().cast(),
+            core::mem::size_of::<[MaybeUninit<T>; CAPACITY]>(),
+        );
+        if let Err(e) = (*ptr).state.init(&allocator) {
+            fatal_panic!(from "FixedSizeQueue::placement_default()",
+                "This should never happen! All required memory is preallocated but the queue could not be initialized ({:?}). Required {} bytes, used {} bytes, remaining {} bytes.",
+                e, RelocatableQueue::<T>::const_memory_size(CAPACITY), allocator.used_bytes(), allocator.remaining_bytes());
+        }
     }
 }
 
@@ -479,13 +601,15 @@ impl<T, const CAPACITY: usize> Default for FixedSizeQueue<T, CAPACITY> {
             _data: unsafe { MaybeUninit::uninit().assume_init() },
         };
 
-        let allocator = BumpAllocator::new(new_self._data.as_mut_ptr().cast());
-        unsafe {
-            new_self
-                .state
-                .init(&allocator)
-                .expect("All required memory is preallocated.")
-        };
+        let allocator = BumpAllocator::new_with_size_limit(
+            new_self._data.as_mut_ptr().cast(),
+            core::mem::size_of::<[MaybeUninit<T>; CAPACITY]>(),
+        );
+        if let Err(e) = unsafe { new_self.state.init(&allocator) } {
+            fatal_panic!(from "FixedSizeQueue::default()",
+                "This should never happen! All required memory is preallocated but the queue could not be initialized ({:?}). Required {} bytes, used {} bytes, remaining {} bytes.",
+                e, RelocatableQueue::<T>::const_memory_size(CAPACITY), allocator.used_bytes(), allocator.remaining_bytes());
+        }
 
         new_self
     }
@@ -554,6 +678,12 @@ impl<T, const CAPACITY: usize> FixedSizeQueue<T, CAPACITY> {
         unsafe { self.state.peek_mut_impl() }
     }
 
+    /// Calls the provided callback with the index and a reference of every element, starting
+    /// with the oldest element, until it returns [`CallbackProgression::Stop`].
+    pub fn for_each<F: FnMut(usize, &T) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.state.for_each_impl(callback) }
+    }
+
     /// Removes the element from the beginning of the queue. If the queue is empty it returns [`None`].
     pub fn pop(&mut self) -> Option<T> {
         unsafe { self.state.pop_impl() }
@@ -15,6 +15,13 @@
 //! strong string types with semantic content contracts. They can be created
 //! with the help of the [`semantic_string`](crate::semantic_string!) macro.
 //!
+//! Every generated type implements [`serde::Serialize`] and [`serde::Deserialize`] and is
+//! represented as string. Deserialization validates the content like
+//! [`SemanticString::new()`] does, therefore only unicode points less than 128 are accepted.
+//! Should the content nevertheless not be valid UTF-8, e.g. when it was created with
+//! [`SemanticString::new_unchecked()`], it is serialized lossy and every invalid sequence is
+//! replaced with `U+FFFD`.
+//!
 //! # Example, create a string that can contain a posix group name
 //!
 //! ```
@@ -71,7 +78,7 @@ use core::ops::Deref;
 use iceoryx2_bb_log::fail;
 
 /// Failures that can occur when a [`SemanticString`] is created or modified
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum SemanticStringError {
     /// The modification would lead to a [`SemanticString`] with invalid content.
     InvalidContent,
@@ -193,6 +200,11 @@ pub trait SemanticString<const CAPACITY: usize>:
         self.as_string().find(bytes)
     }
 
+    /// Returns the number of bytes that can still be added before the capacity is exceeded
+    fn remaining_capacity(&self) -> usize {
+        CAPACITY - self.len()
+    }
+
     /// Returns true when the string is full, otherwise false
     fn is_full(&self) -> bool {
         self.as_string().is_full()
@@ -218,10 +230,19 @@ pub trait SemanticString<const CAPACITY: usize>:
     /// illegal characters or the content would result in an illegal content it fails.
     fn insert_bytes(&mut self, idx: usize, bytes: &[u8]) -> Result<(), SemanticStringError> {
         let msg = "Unable to insert byte string";
-        fail!(from self, when unsafe { self.get_mut_string().insert_bytes(idx, bytes) },
-                with SemanticStringError::ExceedsMaximumLength,
+        match unsafe { self.get_mut_string().insert_bytes(idx, bytes) } {
+            Ok(()) => (),
+            Err(StringModificationError::InsertWouldExceedCapacity) => {
+                fail!(from self, with SemanticStringError::ExceedsMaximumLength,
                     "{} \"{}\" since it would exceed the maximum allowed length of {}.",
                         msg, as_escaped_string(bytes), CAPACITY);
+            }
+            Err(StringModificationError::InvalidCharacter) => {
+                fail!(from self, with SemanticStringError::InvalidContent,
+                    "{} \"{}\" since it contains invalid characters.",
+                        msg, as_escaped_string(bytes));
+            }
+        }
 
         if Self::is_invalid_content(self.as_bytes()) {
             unsafe { self.get_mut_string().remove_range(idx, bytes.len()) };
@@ -443,7 +464,8 @@ macro_rules! semantic_string {
             where
                 S: serde::Serializer,
             {
-                serializer.serialize_str(core::str::from_utf8(self.as_bytes()).unwrap())
+                // contents that are not valid UTF-8 are serialized lossy
+                serializer.serialize_str(&alloc::string::String::from_utf8_lossy(self.as_bytes()))
             }
         }
         // END: serde
@@ -31,6 +31,14 @@
 //! The SlotMap is the perfect container when elements shall be added, removed and accesses quickly
 //! but iteration is allowed to be slow.
 //!
+//! # Key Stability
+//!
+//! A [`SlotMapKey`] stays valid until its value is removed. Inserting, removing and, for the
+//! [`SlotMap`], reserving never changes the key of any other value. The key of a removed value
+//! is recycled by a later insert. The only operation that changes keys is `compact()` which
+//! moves all values to the lowest keys and returns a [`SlotMapKeyRemap`] so that callers can
+//! update their stored keys.
+//!
 //! # User Examples
 //!
 //! ```
@@ -43,14 +51,36 @@
 //!
 //! println!("value: {:?}", slotmap.get(key));
 //! ```
+//!
+//! # Index Width
+//!
+//! All variations store their internal indices as `usize` by default. The optional
+//! [`SlotMapIndex`] parameter `K` stores them as a smaller unsigned integer instead to reduce the
+//! memory footprint, e.g. when many small slotmaps are embedded in shared memory. The capacity
+//! must then be less than `K::MAX`. The [`SlotMapKey`] is independent of `K`.
+//!
+//! ```
+//! use iceoryx2_bb_container::slotmap::FixedSizeSlotMap;
+//!
+//! let mut slotmap = FixedSizeSlotMap::<u64, 16, u8>::new();
+//! assert!(FixedSizeSlotMap::<u64, 16, u8>::memory_size() < FixedSizeSlotMap::<u64, 16>::memory_size());
+//!
+//! let key = slotmap.insert(78181).unwrap();
+//! assert_eq!(slotmap.get(key), Some(&78181));
+//! ```
 
 use crate::queue::MetaQueue;
 use crate::vec::MetaVec;
-use crate::{queue::RelocatableQueue, vec::RelocatableVec};
+use crate::{queue::RelocatableQueue, vec::RelocatableVec, RelocatablePointerWidth};
+use core::fmt::Debug;
 use core::mem::MaybeUninit;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
-use iceoryx2_bb_elementary::relocatable_ptr::GenericRelocatablePointer;
+use iceoryx2_bb_elementary::relocatable_ptr::{
+    GenericRelocatablePointer, GenericRelocatablePointer32,
+};
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_elementary_traits::allocator::AllocationError;
 use iceoryx2_bb_elementary_traits::generic_pointer::GenericPointer;
 use iceoryx2_bb_elementary_traits::owning_pointer::GenericOwningPointer;
 use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
@@ -76,35 +106,187 @@ impl SlotMapKey {
     }
 }
 
+#[doc(hidden)]
+pub(crate) mod internal {
+    #[doc(hidden)]
+    pub trait SlotMapIndexConversion {
+        const INVALID: Self;
+        const MAX_CAPACITY: usize;
+
+        fn from_usize(value: usize) -> Self;
+        fn to_usize(self) -> usize;
+    }
+}
+
+/// The unsigned integer type a [`SlotMap`], [`RelocatableSlotMap`] or [`FixedSizeSlotMap`] uses
+/// to store its internal indices. The maximum value is reserved to mark unused entries,
+/// therefore the capacity must be less than the maximum value of the type.
+pub trait SlotMapIndex:
+    internal::SlotMapIndexConversion + Copy + Eq + Debug + ZeroCopySend
+{
+}
+
+macro_rules! impl_slotmap_index {
+    ($($t:ty),*) => {
+        $(
+            impl internal::SlotMapIndexConversion for $t {
+                const INVALID: Self = <$t>::MAX;
+                const MAX_CAPACITY: usize = if (<$t>::MAX as u128) < (usize::MAX as u128) {
+                    <$t>::MAX as usize
+                } else {
+                    usize::MAX
+                };
+
+                fn from_usize(value: usize) -> Self {
+                    value as $t
+                }
+
+                fn to_usize(self) -> usize {
+                    self as usize
+                }
+            }
+
+            impl SlotMapIndex for $t {}
+        )*
+    };
+}
+
+impl_slotmap_index!(u8, u16, u32, u64, usize);
+
+/// Returned by [`SlotMap::try_insert()`], [`RelocatableSlotMap::try_insert()`] or
+/// [`FixedSizeSlotMap::try_insert()`] when the container is full. It returns the ownership
+/// of the value that could not be inserted back to the caller.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct SlotMapFullError<T> {
+    /// The value that could not be inserted.
+    pub value: T,
+}
+
+impl<T> core::fmt::Display for SlotMapFullError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SlotMapFullError<{}>", core::any::type_name::<T>())
+    }
+}
+
+impl<T: core::fmt::Debug> core::error::Error for SlotMapFullError<T> {}
+
+/// Returned by [`SlotMap::try_insert_at()`], [`SlotMap::try_remove()`] and their
+/// counterparts in [`RelocatableSlotMap`] and [`FixedSizeSlotMap`] when the provided
+/// [`SlotMapKey`] exceeds the capacity of the container.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct SlotMapKeyOutOfBounds {
+    /// The key that is out of bounds.
+    pub key: SlotMapKey,
+}
+
+impl core::fmt::Display for SlotMapKeyOutOfBounds {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SlotMapKeyOutOfBounds {{ key: {} }}", self.key.value())
+    }
+}
+
+impl core::error::Error for SlotMapKeyOutOfBounds {}
+
+/// Returned by [`FixedSizeSlotMap::try_new()`] and [`RelocatableSlotMap::init_checked()`] when
+/// the underlying data structures could not be initialized.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum SlotMapInitError {
+    /// The preallocated memory was insufficient to initialize the data structures.
+    AllocationFailed(AllocationError),
+    /// The capacity the [`RelocatableSlotMap`] was created with differs from the expected
+    /// capacity.
+    CapacityMismatch {
+        /// The capacity provided in [`RelocatableContainer::new_uninit()`].
+        capacity: usize,
+        /// The capacity the caller expected.
+        expected_capacity: usize,
+    },
+    /// The allocator cannot provide the memory the [`RelocatableSlotMap`] requires.
+    InsufficientMemory {
+        /// The number of bytes required for the expected capacity.
+        required: usize,
+        /// The number of bytes the allocator can still provide.
+        available: usize,
+    },
+}
+
+impl core::fmt::Display for SlotMapInitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SlotMapInitError::{self:?}")
+    }
+}
+
+impl core::error::Error for SlotMapInitError {}
+
+/// Returned by [`SlotMap::compact()`], [`RelocatableSlotMap::compact()`] and
+/// [`FixedSizeSlotMap::compact()`]. Contains the old and new [`SlotMapKey`] of every value
+/// whose key was changed. Keys that are not contained were not changed.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct SlotMapKeyRemap {
+    changes: alloc::vec::Vec<(SlotMapKey, SlotMapKey)>,
+}
+
+impl SlotMapKeyRemap {
+    /// Returns the new [`SlotMapKey`] of the value that was stored under `old_key`. If the
+    /// key was not changed it returns [`None`].
+    pub fn get(&self, old_key: SlotMapKey) -> Option<SlotMapKey> {
+        self.changes
+            .binary_search_by_key(&old_key, |(old, _)| *old)
+            .ok()
+            .map(|n| self.changes[n].1)
+    }
+
+    /// Returns an iterator over all changed keys as `(old_key, new_key)` in ascending order
+    /// of the old key.
+    pub fn iter(&self) -> impl Iterator<Item = (SlotMapKey, SlotMapKey)> + '_ {
+        self.changes.iter().copied()
+    }
+
+    /// Returns the number of changed keys.
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Returns true if no key was changed, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy, ZeroCopySend)]
-pub(crate) struct FreeListEntry {
-    previous: usize,
-    next: usize,
+pub(crate) struct FreeListEntry<K: SlotMapIndex> {
+    previous: K,
+    next: K,
 }
 
 /// A runtime fixed-size, non-shared memory compatible [`SlotMap`]. The [`SlotMap`]s memory resides
 /// in the heap.
-pub type SlotMap<T> = MetaSlotMap<T, GenericOwningPointer>;
+pub type SlotMap<T, K = usize> = MetaSlotMap<T, GenericOwningPointer, K>;
 
 /// A runtime fixed-size, shared-memory compatible [`RelocatableSlotMap`].
-pub type RelocatableSlotMap<T> = MetaSlotMap<T, GenericRelocatablePointer>;
+pub type RelocatableSlotMap<T, K = usize> = MetaSlotMap<T, GenericRelocatablePointer, K>;
+
+/// A runtime fixed-size, shared-memory compatible [`RelocatableSlotMap`] that stores the
+/// pointers to its underlying memory as 32-bit offsets. The memory must reside within ±2 GiB
+/// of the [`RelocatableSlotMap32`] itself.
+pub type RelocatableSlotMap32<T, K = usize> = MetaSlotMap<T, GenericRelocatablePointer32, K>;
 
 const INVALID: usize = usize::MAX;
 
 #[doc(hidden)]
 /// The iterator of a [`SlotMap`], [`RelocatableSlotMap`] or [`FixedSizeSlotMap`].
-pub struct Iter<'slotmap, T, Ptr: GenericPointer> {
-    slotmap: &'slotmap MetaSlotMap<T, Ptr>,
+pub struct Iter<'slotmap, T, Ptr: GenericPointer, K: SlotMapIndex = usize> {
+    slotmap: &'slotmap MetaSlotMap<T, Ptr, K>,
     key: SlotMapKey,
 }
 
 #[doc(hidden)]
-pub type OwningIter<'slotmap, T> = Iter<'slotmap, T, GenericOwningPointer>;
+pub type OwningIter<'slotmap, T, K = usize> = Iter<'slotmap, T, GenericOwningPointer, K>;
 #[doc(hidden)]
-pub type RelocatableIter<'slotmap, T> = Iter<'slotmap, T, GenericRelocatablePointer>;
+pub type RelocatableIter<'slotmap, T, K = usize> = Iter<'slotmap, T, GenericRelocatablePointer, K>;
 
-impl<'slotmap, T, Ptr: GenericPointer> Iterator for Iter<'slotmap, T, Ptr> {
+impl<'slotmap, T, Ptr: GenericPointer, K: SlotMapIndex> Iterator for Iter<'slotmap, T, Ptr, K> {
     type Item = (SlotMapKey, &'slotmap T);
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -117,20 +299,39 @@ impl<'slotmap, T, Ptr: GenericPointer> Iterator for Iter<'slotmap, T, Ptr> {
     }
 }
 
+/// Consuming iterator of a [`SlotMap`] that moves the values out in key order. Values that
+/// were not yet returned are dropped together with the iterator.
+#[doc(hidden)]
+pub struct IntoIter<T, K: SlotMapIndex = usize> {
+    slotmap: SlotMap<T, K>,
+    key: SlotMapKey,
+}
+
+impl<T, K: SlotMapIndex> Iterator for IntoIter<T, K> {
+    type Item = (SlotMapKey, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (next_key, _) = self.slotmap.next_available_key_after(self.key)?;
+        self.key.0 = next_key.0 + 1;
+        self.slotmap.remove(next_key).map(|value| (next_key, value))
+    }
+}
+
 #[doc(hidden)]
 #[repr(C)]
 #[derive(Debug)]
-pub struct MetaSlotMap<T, Ptr: GenericPointer> {
-    idx_to_data: MetaVec<usize, Ptr>,
-    idx_to_data_free_list: MetaVec<FreeListEntry, Ptr>,
+pub struct MetaSlotMap<T, Ptr: GenericPointer, K: SlotMapIndex = usize> {
+    idx_to_data: MetaVec<K, Ptr>,
+    idx_to_data_free_list: MetaVec<FreeListEntry<K>, Ptr>,
     data: MetaVec<Option<T>, Ptr>,
-    data_next_free_index: MetaQueue<usize, Ptr>,
-    idx_to_data_free_list_head: usize,
+    data_next_free_index: MetaQueue<K, Ptr>,
+    idx_to_data_free_list_head: K,
     is_initialized: IoxAtomicBool,
     len: usize,
+    high_water_mark: usize,
 }
 
-impl<T, Ptr: GenericPointer> MetaSlotMap<T, Ptr> {
+impl<T, Ptr: GenericPointer, K: SlotMapIndex> MetaSlotMap<T, Ptr, K> {
     #[inline(always)]
     fn verify_init(&self, source: &str) {
         debug_assert!(
@@ -141,15 +342,31 @@ impl<T, Ptr: GenericPointer> MetaSlotMap<T, Ptr> {
             );
     }
 
+    /// Returns true if the slotmap was initialized, otherwise false. A [`SlotMap`] is always
+    /// initialized, a [`RelocatableSlotMap`] only after [`RelocatableContainer::init()`] was
+    /// called.
+    pub fn is_initialized(&self) -> bool {
+        self.is_initialized
+            .load(core::sync::atomic::Ordering::Relaxed)
+    }
+
+    fn verify_capacity(origin: &str, capacity: usize) {
+        if capacity > K::MAX_CAPACITY {
+            fatal_panic!(from origin,
+                "The capacity {} exceeds the maximum capacity {} of the index type {}.",
+                capacity, K::MAX_CAPACITY, core::any::type_name::<K>());
+        }
+    }
+
     fn next_available_key_after(&self, start: SlotMapKey) -> Option<(SlotMapKey, &T)> {
         let idx_to_data = &self.idx_to_data;
 
         for n in start.0..idx_to_data.len() {
             let data_idx = self.idx_to_data[n];
-            if data_idx != INVALID {
+            if data_idx != K::INVALID {
                 return Some((
                     SlotMapKey(n),
-                    self.data[data_idx].as_ref().expect(
+                    self.data[data_idx.to_usize()].as_ref().expect(
                         "By contract, data contains a value when idx_to_data contains a value",
                     ),
                 ));
@@ -162,18 +379,26 @@ impl<T, Ptr: GenericPointer> MetaSlotMap<T, Ptr> {
     pub(crate) unsafe fn initialize_data_structures(&mut self) {
         let capacity = self.capacity_impl();
         for n in 0..capacity {
-            self.idx_to_data.push_impl(INVALID);
+            self.idx_to_data.push_impl(K::INVALID);
             self.data.push_impl(None);
-            self.data_next_free_index.push_impl(n);
-
-            let previous = if n == 0 { INVALID } else { n - 1 };
-            let next = if n < capacity - 1 { n + 1 } else { INVALID };
+            self.data_next_free_index.push_impl(K::from_usize(n));
+
+            let previous = if n == 0 {
+                K::INVALID
+            } else {
+                K::from_usize(n - 1)
+            };
+            let next = if n < capacity - 1 {
+                K::from_usize(n + 1)
+            } else {
+                K::INVALID
+            };
             self.idx_to_data_free_list
                 .push_impl(FreeListEntry { previous, next });
         }
     }
 
-    pub(crate) unsafe fn iter_impl(&self) -> Iter<'_, T, Ptr> {
+    pub(crate) unsafe fn iter_impl(&self) -> Iter<'_, T, Ptr, K> {
         self.verify_init("iter()");
         Iter {
             slotmap: self,
@@ -181,16 +406,62 @@ impl<T, Ptr: GenericPointer> MetaSlotMap<T, Ptr> {
         }
     }
 
+    pub(crate) unsafe fn iter_from_impl(&self, start: SlotMapKey) -> Iter<'_, T, Ptr, K> {
+        self.verify_init("iter_from()");
+        Iter {
+            slotmap: self,
+            key: start,
+        }
+    }
+
+    pub(crate) unsafe fn for_each_impl<F: FnMut(SlotMapKey, &T) -> CallbackProgression>(
+        &self,
+        mut callback: F,
+    ) {
+        self.verify_init("for_each()");
+        for n in 0..self.idx_to_data.len() {
+            let data_idx = self.idx_to_data[n];
+            if data_idx == K::INVALID {
+                continue;
+            }
+
+            let value = self.data[data_idx.to_usize()]
+                .as_ref()
+                .expect("By contract, data contains a value when idx_to_data contains a value");
+            if callback(SlotMapKey(n), value) == CallbackProgression::Stop {
+                return;
+            }
+        }
+    }
+
+    pub(crate) unsafe fn snapshot_keys_impl<const BUFFER_CAPACITY: usize>(
+        &self,
+    ) -> impl Iterator<Item = SlotMapKey> {
+        self.verify_init("snapshot_keys()");
+        let live_keys = (0..self.idx_to_data.len())
+            .filter(|n| self.idx_to_data[*n] != K::INVALID)
+            .map(SlotMapKey);
+
+        let mut keys = [SlotMapKey(0); BUFFER_CAPACITY];
+        let mut number_of_keys = 0;
+        for (target, key) in keys.iter_mut().zip(live_keys) {
+            *target = key;
+            number_of_keys += 1;
+        }
+
+        keys.into_iter().take(number_of_keys)
+    }
+
     pub(crate) unsafe fn contains_impl(&self, key: SlotMapKey) -> bool {
         self.verify_init("contains()");
-        self.idx_to_data[key.0] != INVALID
+        self.idx_to_data[key.0] != K::INVALID
     }
 
     pub(crate) unsafe fn get_impl(&self, key: SlotMapKey) -> Option<&T> {
         self.verify_init("get()");
         match self.idx_to_data[key.0] {
-            INVALID => None,
-            n => Some(self.data[n].as_ref().expect(
+            n if n == K::INVALID => None,
+            n => Some(self.data[n.to_usize()].as_ref().expect(
                 "data and idx_to_data correspond and this value must be always available.",
             )),
         }
@@ -199,23 +470,23 @@ impl<T, Ptr: GenericPointer> MetaSlotMap<T, Ptr> {
     pub(crate) unsafe fn get_mut_impl(&mut self, key: SlotMapKey) -> Option<&mut T> {
         self.verify_init("get_mut()");
         match self.idx_to_data[key.0] {
-            INVALID => None,
-            n => Some(self.data[n].as_mut().expect(
+            n if n == K::INVALID => None,
+            n => Some(self.data[n.to_usize()].as_mut().expect(
                 "data and idx_to_data correspond and this value must be always available.",
             )),
         }
     }
 
     unsafe fn acquire_next_free_index(&mut self) -> Option<usize> {
-        if self.idx_to_data_free_list_head == INVALID {
+        if self.idx_to_data_free_list_head == K::INVALID {
             return None;
         }
 
-        let free_idx = self.idx_to_data_free_list_head;
+        let free_idx = self.idx_to_data_free_list_head.to_usize();
         let next = self.idx_to_data_free_list[free_idx].next;
 
-        if next != INVALID {
-            self.idx_to_data_free_list[next].previous = INVALID;
+        if next != K::INVALID {
+            self.idx_to_data_free_list[next.to_usize()].previous = K::INVALID;
         }
         self.idx_to_data_free_list_head = next;
         Some(free_idx)
@@ -227,27 +498,30 @@ impl<T, Ptr: GenericPointer> MetaSlotMap<T, Ptr> {
         }
 
         let entry = self.idx_to_data_free_list[idx];
-        if entry.previous != INVALID {
-            self.idx_to_data_free_list[entry.previous].next = entry.next;
+        if entry.previous != K::INVALID {
+            self.idx_to_data_free_list[entry.previous.to_usize()].next = entry.next;
+        } else if self.idx_to_data_free_list_head.to_usize() == idx {
+            self.idx_to_data_free_list_head = entry.next;
         }
-        if entry.next != INVALID {
-            self.idx_to_data_free_list[entry.next].previous = entry.previous;
+        if entry.next != K::INVALID {
+            self.idx_to_data_free_list[entry.next.to_usize()].previous = entry.previous;
         }
-        self.idx_to_data_free_list[idx].next = INVALID;
-        self.idx_to_data_free_list[idx].previous = INVALID;
+        self.idx_to_data_free_list[idx].next = K::INVALID;
+        self.idx_to_data_free_list[idx].previous = K::INVALID;
     }
 
     unsafe fn release_free_index(&mut self, idx: usize) {
-        if self.idx_to_data_free_list_head != INVALID {
-            self.idx_to_data_free_list[self.idx_to_data_free_list_head].previous = idx;
+        if self.idx_to_data_free_list_head != K::INVALID {
+            self.idx_to_data_free_list[self.idx_to_data_free_list_head.to_usize()].previous =
+                K::from_usize(idx);
         }
 
         self.idx_to_data_free_list[idx] = FreeListEntry {
-            previous: INVALID,
+            previous: K::INVALID,
             next: self.idx_to_data_free_list_head,
         };
 
-        self.idx_to_data_free_list_head = idx;
+        self.idx_to_data_free_list_head = K::from_usize(idx);
     }
 
     pub(crate) unsafe fn insert_impl(&mut self, value: T) -> Option<SlotMapKey> {
@@ -259,6 +533,21 @@ impl<T, Ptr: GenericPointer> MetaSlotMap<T, Ptr> {
         })
     }
 
+    pub(crate) unsafe fn try_insert_impl(
+        &mut self,
+        value: T,
+    ) -> Result<SlotMapKey, SlotMapFullError<T>> {
+        self.verify_init("try_insert()");
+        match self.acquire_next_free_index() {
+            Some(key) => {
+                let key = SlotMapKey(key);
+                self.store_value(key, value);
+                Ok(key)
+            }
+            None => Err(SlotMapFullError { value }),
+        }
+    }
+
     pub(crate) unsafe fn insert_at_impl(&mut self, key: SlotMapKey, value: T) -> bool {
         self.verify_init("insert_at()");
         self.claim_index(key.value());
@@ -267,20 +556,21 @@ impl<T, Ptr: GenericPointer> MetaSlotMap<T, Ptr> {
 
     pub(crate) unsafe fn store_value(&mut self, key: SlotMapKey, value: T) -> bool {
         self.verify_init("store()");
-        if key.0 > self.capacity_impl() {
+        if key.0 >= self.capacity_impl() {
             return false;
         }
 
         let data_idx = self.idx_to_data[key.0];
-        if data_idx != INVALID {
-            self.data[data_idx] = Some(value);
+        if data_idx != K::INVALID {
+            self.data[data_idx.to_usize()] = Some(value);
         } else {
             let n = self.data_next_free_index.pop_impl().expect(
                 "data and idx_to_data correspond and there must be always a free index available.",
             );
             self.idx_to_data[key.0] = n;
-            self.data[n] = Some(value);
+            self.data[n.to_usize()] = Some(value);
             self.len += 1;
+            self.high_water_mark = self.high_water_mark.max(self.len);
         }
 
         true
@@ -288,17 +578,17 @@ impl<T, Ptr: GenericPointer> MetaSlotMap<T, Ptr> {
 
     pub(crate) unsafe fn remove_impl(&mut self, key: SlotMapKey) -> Option<T> {
         self.verify_init("remove()");
-        if key.0 > self.idx_to_data.len() {
+        if key.0 >= self.idx_to_data.len() {
             return None;
         }
 
         let data_idx = self.idx_to_data[key.0];
-        if data_idx != INVALID {
-            let ret = self.data[data_idx].take();
+        if data_idx != K::INVALID {
+            let ret = self.data[data_idx.to_usize()].take();
             let push_result = self.data_next_free_index.push_impl(data_idx);
             debug_assert!(push_result);
             self.release_free_index(key.0);
-            self.idx_to_data[key.0] = INVALID;
+            self.idx_to_data[key.0] = K::INVALID;
             self.len -= 1;
             ret
         } else {
@@ -306,19 +596,153 @@ impl<T, Ptr: GenericPointer> MetaSlotMap<T, Ptr> {
         }
     }
 
+    pub(crate) unsafe fn try_insert_at_impl(
+        &mut self,
+        key: SlotMapKey,
+        value: T,
+    ) -> Result<Option<T>, SlotMapKeyOutOfBounds> {
+        self.verify_init("try_insert_at()");
+        if key.0 >= self.capacity_impl() {
+            return Err(SlotMapKeyOutOfBounds { key });
+        }
+
+        if let Some(stored_value) = self.get_mut_impl(key) {
+            return Ok(Some(core::mem::replace(stored_value, value)));
+        }
+
+        self.insert_at_impl(key, value);
+        Ok(None)
+    }
+
+    pub(crate) unsafe fn get_or_insert_with_impl<F: FnOnce() -> T>(
+        &mut self,
+        key: SlotMapKey,
+        f: F,
+    ) -> Option<&mut T> {
+        self.verify_init("get_or_insert_with()");
+        if key.0 >= self.capacity_impl() {
+            return None;
+        }
+
+        if !self.contains_impl(key) {
+            self.insert_at_impl(key, f());
+        }
+
+        self.get_mut_impl(key)
+    }
+
+    pub(crate) unsafe fn try_remove_impl(
+        &mut self,
+        key: SlotMapKey,
+    ) -> Result<Option<T>, SlotMapKeyOutOfBounds> {
+        self.verify_init("try_remove()");
+        if key.0 >= self.capacity_impl() {
+            return Err(SlotMapKeyOutOfBounds { key });
+        }
+
+        Ok(self.remove_impl(key))
+    }
+
+    pub(crate) unsafe fn compact_impl(&mut self) -> SlotMapKeyRemap {
+        self.verify_init("compact()");
+        let capacity = self.capacity_impl();
+
+        // the free list is rebuilt at the end, until then the entry at data index n contains
+        // the data index the value stored at n is moved to
+        for entry in self.idx_to_data_free_list.iter_mut() {
+            entry.previous = K::INVALID;
+        }
+
+        let mut len = 0;
+        for key in 0..capacity {
+            let data_idx = self.idx_to_data[key];
+            if data_idx != K::INVALID {
+                self.idx_to_data_free_list[data_idx.to_usize()].previous = K::from_usize(len);
+                len += 1;
+            }
+        }
+
+        // every swap moves one value to its final data index
+        for n in 0..capacity {
+            loop {
+                let target = self.idx_to_data_free_list[n].previous;
+                if target == K::INVALID || target.to_usize() == n {
+                    break;
+                }
+
+                self.data.swap(n, target.to_usize());
+                self.idx_to_data_free_list.swap(n, target.to_usize());
+            }
+        }
+
+        // the value of key n is stored at data index n
+        let mut changes = alloc::vec::Vec::new();
+        let mut new_key = 0;
+        for old_key in 0..capacity {
+            if self.idx_to_data[old_key] == K::INVALID {
+                continue;
+            }
+
+            self.idx_to_data[old_key] = K::INVALID;
+            self.idx_to_data[new_key] = K::from_usize(new_key);
+            if new_key != old_key {
+                changes.push((SlotMapKey(old_key), SlotMapKey(new_key)));
+            }
+            new_key += 1;
+        }
+
+        // all remaining keys and data indices are free in ascending order
+        while self.data_next_free_index.pop_impl().is_some() {}
+
+        for n in 0..capacity {
+            if n < len {
+                self.idx_to_data_free_list[n] = FreeListEntry {
+                    previous: K::INVALID,
+                    next: K::INVALID,
+                };
+            } else {
+                self.data_next_free_index.push_impl(K::from_usize(n));
+                self.idx_to_data_free_list[n] = FreeListEntry {
+                    previous: if n == len {
+                        K::INVALID
+                    } else {
+                        K::from_usize(n - 1)
+                    },
+                    next: if n < capacity - 1 {
+                        K::from_usize(n + 1)
+                    } else {
+                        K::INVALID
+                    },
+                };
+            }
+        }
+
+        self.idx_to_data_free_list_head = if len < capacity {
+            K::from_usize(len)
+        } else {
+            K::INVALID
+        };
+
+        SlotMapKeyRemap { changes }
+    }
+
     pub(crate) unsafe fn next_free_key_impl(&self) -> Option<SlotMapKey> {
         self.verify_init("next_free_key()");
-        if self.idx_to_data_free_list_head == INVALID {
+        if self.idx_to_data_free_list_head == K::INVALID {
             return None;
         }
 
-        Some(SlotMapKey::new(self.idx_to_data_free_list_head))
+        Some(SlotMapKey::new(self.idx_to_data_free_list_head.to_usize()))
     }
 
     pub(crate) fn len_impl(&self) -> usize {
         self.len
     }
 
+    pub(crate) fn high_water_mark_impl(&self) -> usize {
+        self.high_water_mark
+    }
+
     pub(crate) fn capacity_impl(&self) -> usize {
         self.idx_to_data.capacity()
     }
@@ -332,15 +756,19 @@ impl<T, Ptr: GenericPointer> MetaSlotMap<T, Ptr> {
     }
 }
 
-impl<T> RelocatableContainer for RelocatableSlotMap<T> {
+impl<T, Ptr: RelocatablePointerWidth, K: SlotMapIndex> RelocatableContainer
+    for MetaSlotMap<T, Ptr, K>
+{
     unsafe fn new_uninit(capacity: usize) -> Self {
+        Self::verify_capacity("RelocatableSlotMap::new_uninit()", capacity);
         Self {
             len: 0,
-            idx_to_data_free_list_head: 0,
-            idx_to_data: RelocatableVec::new_uninit(capacity),
-            idx_to_data_free_list: RelocatableVec::new_uninit(capacity),
-            data: RelocatableVec::new_uninit(capacity),
-            data_next_free_index: RelocatableQueue::new_uninit(capacity),
+            high_water_mark: 0,
+            idx_to_data_free_list_head: K::from_usize(0),
+            idx_to_data: MetaVec::new_uninit(capacity),
+            idx_to_data_free_list: MetaVec::new_uninit(capacity),
+            data: MetaVec::new_uninit(capacity),
+            data_next_free_index: MetaQueue::new_uninit(capacity),
             is_initialized: IoxAtomicBool::new(false),
         }
     }
@@ -380,12 +808,15 @@ impl<T> RelocatableContainer for RelocatableSlotMap<T> {
     }
 }
 
-impl<T> SlotMap<T> {
-    /// Creates a new runtime-fixed size [`SlotMap`] on the heap with the given capacity.
+impl<T, K: SlotMapIndex> SlotMap<T, K> {
+    /// Creates a new runtime-fixed size [`SlotMap`] on the heap with the given capacity. The
+    /// capacity must be less than the maximum value of `K`.
     pub fn new(capacity: usize) -> Self {
+        Self::verify_capacity("SlotMap::new()", capacity);
         let mut new_self = Self {
             len: 0,
-            idx_to_data_free_list_head: 0,
+            high_water_mark: 0,
+            idx_to_data_free_list_head: K::from_usize(0),
             idx_to_data: MetaVec::new(capacity),
             idx_to_data_free_list: MetaVec::new(capacity),
             data: MetaVec::new(capacity),
@@ -397,10 +828,22 @@ impl<T> SlotMap<T> {
     }
 
     /// Returns the [`Iter`]ator to iterate over all entries.
-    pub fn iter(&self) -> OwningIter<'_, T> {
+    pub fn iter(&self) -> OwningIter<'_, T, K> {
         unsafe { self.iter_impl() }
     }
 
+    /// Returns the [`Iter`]ator to iterate over all entries whose key is greater than or equal
+    /// to `start`.
+    pub fn iter_from(&self, start: SlotMapKey) -> OwningIter<'_, T, K> {
+        unsafe { self.iter_from_impl(start) }
+    }
+
+    /// Calls the provided callback for every entry in key order until it returns
+    /// [`CallbackProgression::Stop`].
+    pub fn for_each<F: FnMut(SlotMapKey, &T) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.for_each_impl(callback) }
+    }
+
     /// Returns `true` if the provided `key` is contained, otherwise `false`.
     pub fn contains(&self, key: SlotMapKey) -> bool {
         unsafe { self.contains_impl(key) }
@@ -424,6 +867,12 @@ impl<T> SlotMap<T> {
         unsafe { self.insert_impl(value) }
     }
 
+    /// Insert a value and returns the corresponding [`SlotMapKey`]. If the container is full
+    /// a [`SlotMapFullError`] is returned that contains the provided value.
+    pub fn try_insert(&mut self, value: T) -> Result<SlotMapKey, SlotMapFullError<T>> {
+        unsafe { self.try_insert_impl(value) }
+    }
+
     /// Insert a value at the specified [`SlotMapKey`] and returns true.  If the provided key
     /// is out-of-bounds it returns `false` and adds nothing. If there is already a value
     /// stored at the `key`s index, the value is overridden with the provided value.
@@ -437,17 +886,60 @@ impl<T> SlotMap<T> {
         unsafe { self.remove_impl(key) }
     }
 
+    /// Insert a value at the specified [`SlotMapKey`]. If there is already a value stored at
+    /// the `key`s index, it is replaced and returned, otherwise [`None`] is returned. If the
+    /// provided key is out-of-bounds a [`SlotMapKeyOutOfBounds`] error is returned.
+    pub fn try_insert_at(
+        &mut self,
+        key: SlotMapKey,
+        value: T,
+    ) -> Result<Option<T>, SlotMapKeyOutOfBounds> {
+        unsafe { self.try_insert_at_impl(key, value) }
+    }
+
+    /// Removes a value at the specified [`SlotMapKey`]. If there was no value corresponding
+    /// to the [`SlotMapKey`] it returns None, otherwise Some(value). If the provided key is
+    /// out-of-bounds a [`SlotMapKeyOutOfBounds`] error is returned.
+    pub fn try_remove(&mut self, key: SlotMapKey) -> Result<Option<T>, SlotMapKeyOutOfBounds> {
+        unsafe { self.try_remove_impl(key) }
+    }
+
+    /// Returns a mutable reference to the value stored under the given key. If there is no
+    /// such key, the value created by `f` is inserted at the `key`s index and a reference to it
+    /// is returned. If the provided key is out-of-bounds [`None`] is returned and `f` is not
+    /// called.
+    pub fn get_or_insert_with<F: FnOnce() -> T>(
+        &mut self,
+        key: SlotMapKey,
+        f: F,
+    ) -> Option<&mut T> {
+        unsafe { self.get_or_insert_with_impl(key, f) }
+    }
+
     /// Returns the [`SlotMapKey`] that will be used when the user calls
     /// [`SlotMap::insert()`]. If the [`SlotMap`] is full it returns [`None`].
     pub fn next_free_key(&self) -> Option<SlotMapKey> {
         unsafe { self.next_free_key_impl() }
     }
 
+    /// Moves all values to the lowest keys in ascending key order and removes the internal
+    /// fragmentation caused by many removals. All keys whose value was moved are invalidated,
+    /// the returned [`SlotMapKeyRemap`] contains their new keys.
+    pub fn compact(&mut self) -> SlotMapKeyRemap {
+        unsafe { self.compact_impl() }
+    }
+
     /// Returns the number of stored values.
     pub fn len(&self) -> usize {
         self.len_impl()
     }
 
+    /// Returns the maximum number of values that were stored at the same time over the
+    /// lifetime of the container.
+    pub fn high_water_mark(&self) -> usize {
+        self.high_water_mark_impl()
+    }
+
     /// Returns the capacity.
     pub fn capacity(&self) -> usize {
         self.capacity_impl()
@@ -462,18 +954,133 @@ impl<T> SlotMap<T> {
     pub fn is_full(&self) -> bool {
         self.is_full_impl()
     }
+
+    /// Increases the capacity so that at least `additional` more values can be inserted. All
+    /// existing [`SlotMapKey`]s stay valid. The new keys are used after the already available
+    /// free keys. The new capacity must be less than the maximum value of `K`.
+    pub fn reserve(&mut self, additional: usize) {
+        let old_capacity = self.capacity_impl();
+        let new_capacity = self.len + additional;
+        if new_capacity <= old_capacity {
+            return;
+        }
+        Self::verify_capacity("SlotMap::reserve()", new_capacity);
+
+        let mut free_list_tail = K::INVALID;
+        let mut current = self.idx_to_data_free_list_head;
+        while current != K::INVALID {
+            free_list_tail = current;
+            current = self.idx_to_data_free_list[current.to_usize()].next;
+        }
+
+        self.idx_to_data.reserve(new_capacity - old_capacity);
+        self.idx_to_data_free_list
+            .reserve(new_capacity - old_capacity);
+        self.data.reserve(new_capacity - old_capacity);
+        self.data_next_free_index
+            .reserve(new_capacity - self.data_next_free_index.len());
+
+        for n in old_capacity..new_capacity {
+            unsafe {
+                self.idx_to_data.push_impl(K::INVALID);
+                self.data.push_impl(None);
+                self.data_next_free_index.push_impl(K::from_usize(n));
+            }
+
+            let previous = if n == old_capacity {
+                free_list_tail
+            } else {
+                K::from_usize(n - 1)
+            };
+            let next = if n < new_capacity - 1 {
+                K::from_usize(n + 1)
+            } else {
+                K::INVALID
+            };
+            unsafe {
+                self.idx_to_data_free_list
+                    .push_impl(FreeListEntry { previous, next })
+            };
+        }
+
+        if free_list_tail == K::INVALID {
+            self.idx_to_data_free_list_head = K::from_usize(old_capacity);
+        } else {
+            self.idx_to_data_free_list[free_list_tail.to_usize()].next =
+                K::from_usize(old_capacity);
+        }
+    }
+
+    /// Consumes the [`SlotMap`] and returns all stored values in key order.
+    pub fn into_values(self) -> impl Iterator<Item = T> {
+        self.into_iter().map(|(_, value)| value)
+    }
 }
 
-unsafe impl<T: ZeroCopySend> ZeroCopySend for RelocatableSlotMap<T> {}
+impl<T, K: SlotMapIndex> IntoIterator for SlotMap<T, K> {
+    type Item = (SlotMapKey, T);
+    type IntoIter = IntoIter<T, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            slotmap: self,
+            key: SlotMapKey(0),
+        }
+    }
+}
 
-impl<T> RelocatableSlotMap<T> {
+unsafe impl<T: ZeroCopySend, Ptr: RelocatablePointerWidth, K: SlotMapIndex> ZeroCopySend
+    for MetaSlotMap<T, Ptr, K>
+{
+}
+
+impl<T, Ptr: RelocatablePointerWidth, K: SlotMapIndex> MetaSlotMap<T, Ptr, K> {
     /// Returns how many memory the [`RelocatableSlotMap`] will allocate from the allocator
     /// in [`RelocatableSlotMap::init()`].
     pub const fn const_memory_size(capacity: usize) -> usize {
-        RelocatableVec::<usize>::const_memory_size(capacity)
-            + RelocatableVec::<FreeListEntry>::const_memory_size(capacity)
-            + RelocatableVec::<Option<T>>::const_memory_size(capacity)
-            + RelocatableQueue::<usize>::const_memory_size(capacity)
+        MetaVec::<K, Ptr>::const_memory_size(capacity)
+            + MetaVec::<FreeListEntry<K>, Ptr>::const_memory_size(capacity)
+            + MetaVec::<Option<T>, Ptr>::const_memory_size(capacity)
+            + MetaQueue::<K, Ptr>::const_memory_size(capacity)
+    }
+
+    /// Verifies that the [`RelocatableSlotMap`] was created with `expected_capacity` and that
+    /// the `allocator` can provide at least
+    /// [`RelocatableSlotMap::const_memory_size()`] bytes for it before calling
+    /// [`RelocatableContainer::init()`]. A mismatch is reported as [`SlotMapInitError`]
+    /// instead of corrupting the memory behind the allocator.
+    ///
+    /// # Safety
+    ///
+    ///  * must be called exactly once before any other method is called
+    ///  * shall not be called when [`RelocatableSlotMap::init()`] was already called
+    ///
+    pub unsafe fn init_checked(
+        &mut self,
+        allocator: &BumpAllocator,
+        expected_capacity: usize,
+    ) -> Result<(), SlotMapInitError> {
+        let origin = "RelocatableSlotMap::init_checked()";
+        let msg = "Unable to initialize RelocatableSlotMap";
+        let capacity = self.capacity_impl();
+        if capacity != expected_capacity {
+            fail!(from origin, with SlotMapInitError::CapacityMismatch { capacity, expected_capacity },
+                "{msg} since it was created with a capacity of {capacity} but a capacity of {expected_capacity} was expected.");
+        }
+
+        let required = Self::const_memory_size(expected_capacity);
+        let available = allocator.remaining_bytes();
+        if available < required {
+            fail!(from origin, with SlotMapInitError::InsufficientMemory { required, available },
+                "{msg} since the allocator provides only {available} bytes but {required} bytes are required.");
+        }
+
+        if let Err(e) = self.init(allocator) {
+            fail!(from origin, with SlotMapInitError::AllocationFailed(e),
+                "{msg} since the underlying data structures could not be allocated ({e:?}).");
+        }
+
+        Ok(())
     }
 
     /// Returns the [`Iter`]ator to iterate over all entries.
@@ -482,10 +1089,32 @@ impl<T> RelocatableSlotMap<T> {
     ///
     ///  * [`RelocatableSlotMap::init()`] must be called once before
     ///
-    pub unsafe fn iter(&self) -> RelocatableIter<'_, T> {
+    pub unsafe fn iter(&self) -> Iter<'_, T, Ptr, K> {
         self.iter_impl()
     }
 
+    /// Returns the [`Iter`]ator to iterate over all entries whose key is greater than or
+    /// equal to `start`.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableSlotMap::init()`] must be called once before
+    ///
+    pub unsafe fn iter_from(&self, start: SlotMapKey) -> Iter<'_, T, Ptr, K> {
+        self.iter_from_impl(start)
+    }
+
+    /// Calls the provided callback for every entry in key order until it returns
+    /// [`CallbackProgression::Stop`].
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableSlotMap::init()`] must be called once before
+    ///
+    pub unsafe fn for_each<F: FnMut(SlotMapKey, &T) -> CallbackProgression>(&self, callback: F) {
+        self.for_each_impl(callback)
+    }
+
     /// Returns `true` if the provided `key` is contained, otherwise `false`.
     ///
     /// # Safety
@@ -496,6 +1125,30 @@ impl<T> RelocatableSlotMap<T> {
         self.contains_impl(key)
     }
 
+    /// Captures the keys of all values that are stored at the time of the call in ascending
+    /// order into a stack buffer of `BUFFER_CAPACITY` keys and returns an iterator over them.
+    /// A `BUFFER_CAPACITY` of at least [`RelocatableSlotMap::capacity()`] always captures all
+    /// keys, a smaller buffer captures only the first `BUFFER_CAPACITY` keys. In contrast to
+    /// [`RelocatableSlotMap::iter()`] the returned iterator does not borrow the
+    /// [`RelocatableSlotMap`], therefore values can be removed while iterating over it
+    /// without affecting the captured set of keys.
+    ///
+    /// The [`RelocatableSlotMap`] itself performs no synchronization. When it is shared
+    /// between processes the writer must not modify it while the snapshot is taken, e.g. by
+    /// protecting it with a lock, and all writes must happen-before the snapshot. Values may
+    /// be removed after the snapshot was taken, so [`RelocatableSlotMap::get()`] can return
+    /// [`None`] for a captured key.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableSlotMap::init()`] must be called once before
+    ///
+    pub unsafe fn snapshot_keys<const BUFFER_CAPACITY: usize>(
+        &self,
+    ) -> impl Iterator<Item = SlotMapKey> {
+        self.snapshot_keys_impl::<BUFFER_CAPACITY>()
+    }
+
     /// Returns a reference to the value stored under the given key. If there is no such key,
     /// [`None`] is returned.
     ///
@@ -529,6 +1182,17 @@ impl<T> RelocatableSlotMap<T> {
         self.insert_impl(value)
     }
 
+    /// Insert a value and returns the corresponding [`SlotMapKey`]. If the container is full
+    /// a [`SlotMapFullError`] is returned that contains the provided value.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableSlotMap::init()`] must be called once before
+    ///
+    pub unsafe fn try_insert(&mut self, value: T) -> Result<SlotMapKey, SlotMapFullError<T>> {
+        self.try_insert_impl(value)
+    }
+
     /// Insert a value at the specified [`SlotMapKey`] and returns true.  If the provided key
     /// is out-of-bounds it returns `false` and adds nothing. If there is already a value
     /// stored at the `key`s index, the value is overridden with the provided value.
@@ -552,6 +1216,54 @@ impl<T> RelocatableSlotMap<T> {
         self.remove_impl(key)
     }
 
+    /// Insert a value at the specified [`SlotMapKey`]. If there is already a value stored at
+    /// the `key`s index, it is replaced and returned, otherwise [`None`] is returned. If the
+    /// provided key is out-of-bounds a [`SlotMapKeyOutOfBounds`] error is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableSlotMap::init()`] must be called once before
+    ///
+    pub unsafe fn try_insert_at(
+        &mut self,
+        key: SlotMapKey,
+        value: T,
+    ) -> Result<Option<T>, SlotMapKeyOutOfBounds> {
+        self.try_insert_at_impl(key, value)
+    }
+
+    /// Removes a value at the specified [`SlotMapKey`]. If there was no value corresponding
+    /// to the [`SlotMapKey`] it returns None, otherwise Some(value). If the provided key is
+    /// out-of-bounds a [`SlotMapKeyOutOfBounds`] error is returned.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableSlotMap::init()`] must be called once before
+    ///
+    pub unsafe fn try_remove(
+        &mut self,
+        key: SlotMapKey,
+    ) -> Result<Option<T>, SlotMapKeyOutOfBounds> {
+        self.try_remove_impl(key)
+    }
+
+    /// Returns a mutable reference to the value stored under the given key. If there is no
+    /// such key, the value created by `f` is inserted at the `key`s index and a reference to it
+    /// is returned. If the provided key is out-of-bounds [`None`] is returned and `f` is not
+    /// called.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableSlotMap::init()`] must be called once before
+    ///
+    pub unsafe fn get_or_insert_with<F: FnOnce() -> T>(
+        &mut self,
+        key: SlotMapKey,
+        f: F,
+    ) -> Option<&mut T> {
+        self.get_or_insert_with_impl(key, f)
+    }
+
     /// Returns the [`SlotMapKey`] that will be used when the user calls
     /// [`SlotMap::insert()`]. If the [`SlotMap`] is full it returns [`None`].
     ///
@@ -563,11 +1275,29 @@ impl<T> RelocatableSlotMap<T> {
         self.next_free_key_impl()
     }
 
+    /// Moves all values to the lowest keys in ascending key order and removes the internal
+    /// fragmentation caused by many removals. All keys whose value was moved are invalidated,
+    /// the returned [`SlotMapKeyRemap`] contains their new keys.
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatableSlotMap::init()`] must be called once before
+    ///
+    pub unsafe fn compact(&mut self) -> SlotMapKeyRemap {
+        self.compact_impl()
+    }
+
     /// Returns the number of stored values.
     pub fn len(&self) -> usize {
         self.len_impl()
     }
 
+    /// Returns the maximum number of values that were stored at the same time over the
+    /// lifetime of the container.
+    pub fn high_water_mark(&self) -> usize {
+        self.high_water_mark_impl()
+    }
+
     /// Returns the capacity.
     pub fn capacity(&self) -> usize {
         self.capacity_impl()
@@ -584,20 +1314,26 @@ impl<T> RelocatableSlotMap<T> {
     }
 }
 
-/// A compile-time fixed-size, shared memory compatible [`FixedSizeSlotMap`].
+/// A compile-time fixed-size, shared memory compatible [`FixedSizeSlotMap`]. The `CAPACITY`
+/// must be less than the maximum value of `K`.
 #[repr(C)]
 #[derive(Debug)]
-pub struct FixedSizeSlotMap<T, const CAPACITY: usize> {
-    state: RelocatableSlotMap<T>,
-    _idx_to_data: MaybeUninit<[usize; CAPACITY]>,
-    _idx_to_data_free_list: MaybeUninit<[FreeListEntry; CAPACITY]>,
+pub struct FixedSizeSlotMap<T, const CAPACITY: usize, K: SlotMapIndex = usize> {
+    state: RelocatableSlotMap<T, K>,
+    _idx_to_data: MaybeUninit<[K; CAPACITY]>,
+    _idx_to_data_free_list: MaybeUninit<[FreeListEntry<K>; CAPACITY]>,
     _data: MaybeUninit<[Option<T>; CAPACITY]>,
-    _data_next_free_index: MaybeUninit<[usize; CAPACITY]>,
+    _data_next_free_index: MaybeUninit<[K; CAPACITY]>,
 }
 
-unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for FixedSizeSlotMap<T, CAPACITY> {}
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize, K: SlotMapIndex> ZeroCopySend
+    for FixedSizeSlotMap<T, CAPACITY, K>
+{
+}
 
-impl<T, const CAPACITY: usize> PlacementDefault for FixedSizeSlotMap<T, CAPACITY> {
+impl<T, const CAPACITY: usize, K: SlotMapIndex> PlacementDefault
+    for FixedSizeSlotMap<T, CAPACITY, K>
+{
     unsafe fn placement_default(ptr: *mut Self) {
         let state_ptr = core::ptr::addr_of_mut!((*ptr).state);
         state_ptr.write(unsafe { RelocatableSlotMap::new_uninit(CAPACITY) });
@@ -609,8 +1345,30 @@ impl<T, const CAPACITY: usize> PlacementDefault for FixedSizeSlotMap<T, CAPACITY
     }
 }
 
-impl<T, const CAPACITY: usize> Default for FixedSizeSlotMap<T, CAPACITY> {
+impl<T, const CAPACITY: usize, K: SlotMapIndex> Default for FixedSizeSlotMap<T, CAPACITY, K> {
     fn default() -> Self {
+        Self::try_new().expect("All required memory is preallocated.")
+    }
+}
+
+impl<T, const CAPACITY: usize, K: SlotMapIndex> FixedSizeSlotMap<T, CAPACITY, K> {
+    /// Creates a new empty [`FixedSizeSlotMap`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the total number of bytes the [`FixedSizeSlotMap`] occupies. In contrast to
+    /// [`RelocatableSlotMap::const_memory_size()`], which covers only the four embedded arrays,
+    /// it includes the management state and padding. It can be used in const contexts, e.g.
+    /// to verify at compile time that a set of containers fits into a fixed memory segment.
+    pub const fn memory_size() -> usize {
+        core::mem::size_of::<Self>()
+    }
+
+    /// Creates a new empty [`FixedSizeSlotMap`]. In contrast to [`FixedSizeSlotMap::new()`] it
+    /// does not panic when the underlying data structures cannot be initialized but returns
+    /// a [`SlotMapInitError`].
+    pub fn try_new() -> Result<Self, SlotMapInitError> {
         let mut new_self = Self {
             _idx_to_data: MaybeUninit::uninit(),
             _idx_to_data_free_list: MaybeUninit::uninit(),
@@ -619,34 +1377,48 @@ impl<T, const CAPACITY: usize> Default for FixedSizeSlotMap<T, CAPACITY> {
             state: unsafe { RelocatableSlotMap::new_uninit(CAPACITY) },
         };
 
-        let allocator = BumpAllocator::new(new_self._idx_to_data.as_mut_ptr().cast());
-        unsafe {
-            new_self
-                .state
-                .init(&allocator)
-                .expect("All required memory is preallocated.")
-        };
-
-        new_self
-    }
-}
+        let allocator = BumpAllocator::new_with_size_limit(
+            new_self._idx_to_data.as_mut_ptr().cast(),
+            core::mem::size_of::<Self>() - core::mem::offset_of!(Self, _idx_to_data),
+        );
+        if let Err(e) = unsafe { new_self.state.init(&allocator) } {
+            fail!(from "FixedSizeSlotMap::try_new()", with SlotMapInitError::AllocationFailed(e),
+                "Unable to create FixedSizeSlotMap since the underlying data structures could not be initialized ({:?}). Required {} bytes, used {} bytes, remaining {} bytes.",
+                e, RelocatableSlotMap::<T, K>::const_memory_size(CAPACITY), allocator.used_bytes(), allocator.remaining_bytes());
+        }
 
-impl<T, const CAPACITY: usize> FixedSizeSlotMap<T, CAPACITY> {
-    /// Creates a new empty [`FixedSizeSlotMap`].
-    pub fn new() -> Self {
-        Self::default()
+        Ok(new_self)
     }
 
     /// Returns the [`RelocatableIter`]ator to iterate over all entries.
-    pub fn iter(&self) -> RelocatableIter<'_, T> {
+    pub fn iter(&self) -> RelocatableIter<'_, T, K> {
         unsafe { self.state.iter_impl() }
     }
 
+    /// Returns the [`RelocatableIter`]ator to iterate over all entries whose key is greater than
+    /// or equal to `start`.
+    pub fn iter_from(&self, start: SlotMapKey) -> RelocatableIter<'_, T, K> {
+        unsafe { self.state.iter_from_impl(start) }
+    }
+
+    /// Calls the provided callback for every entry in key order until it returns
+    /// [`CallbackProgression::Stop`].
+    pub fn for_each<F: FnMut(SlotMapKey, &T) -> CallbackProgression>(&self, callback: F) {
+        unsafe { self.state.for_each_impl(callback) }
+    }
+
     /// Returns `true` if the provided `key` is contained, otherwise `false`.
     pub fn contains(&self, key: SlotMapKey) -> bool {
         unsafe { self.state.contains_impl(key) }
     }
 
+    /// Captures the keys of all values that are stored at the time of the call into a stack
+    /// buffer of `CAPACITY` keys and returns an iterator over them.
+    /// See [`RelocatableSlotMap::snapshot_keys()`].
+    pub fn snapshot_keys(&self) -> impl Iterator<Item = SlotMapKey> {
+        unsafe { self.state.snapshot_keys_impl::<CAPACITY>() }
+    }
+
     /// Returns a reference to the value stored under the given key. If there is no such key,
     /// [`None`] is returned.
     pub fn get(&self, key: SlotMapKey) -> Option<&T> {
@@ -665,6 +1437,12 @@ impl<T, const CAPACITY: usize> FixedSizeSlotMap<T, CAPACITY> {
         unsafe { self.state.insert_impl(value) }
     }
 
+    /// Insert a value and returns the corresponding [`SlotMapKey`]. If the container is full
+    /// a [`SlotMapFullError`] is returned that contains the provided value.
+    pub fn try_insert(&mut self, value: T) -> Result<SlotMapKey, SlotMapFullError<T>> {
+        unsafe { self.state.try_insert_impl(value) }
+    }
+
     /// Insert a value at the specified [`SlotMapKey`] and returns true.  If the provided key
     /// is out-of-bounds it returns `false` and adds nothing. If there is already a value
     /// stored at the `key`s index, the value is overridden with the provided value.
@@ -678,17 +1456,60 @@ impl<T, const CAPACITY: usize> FixedSizeSlotMap<T, CAPACITY> {
         unsafe { self.state.remove_impl(key) }
     }
 
+    /// Insert a value at the specified [`SlotMapKey`]. If there is already a value stored at
+    /// the `key`s index, it is replaced and returned, otherwise [`None`] is returned. If the
+    /// provided key is out-of-bounds a [`SlotMapKeyOutOfBounds`] error is returned.
+    pub fn try_insert_at(
+        &mut self,
+        key: SlotMapKey,
+        value: T,
+    ) -> Result<Option<T>, SlotMapKeyOutOfBounds> {
+        unsafe { self.state.try_insert_at_impl(key, value) }
+    }
+
+    /// Removes a value at the specified [`SlotMapKey`]. If there was no value corresponding
+    /// to the [`SlotMapKey`] it returns None, otherwise Some(value). If the provided key is
+    /// out-of-bounds a [`SlotMapKeyOutOfBounds`] error is returned.
+    pub fn try_remove(&mut self, key: SlotMapKey) -> Result<Option<T>, SlotMapKeyOutOfBounds> {
+        unsafe { self.state.try_remove_impl(key) }
+    }
+
+    /// Returns a mutable reference to the value stored under the given key. If there is no
+    /// such key, the value created by `f` is inserted at the `key`s index and a reference to it
+    /// is returned. If the provided key is out-of-bounds [`None`] is returned and `f` is not
+    /// called.
+    pub fn get_or_insert_with<F: FnOnce() -> T>(
+        &mut self,
+        key: SlotMapKey,
+        f: F,
+    ) -> Option<&mut T> {
+        unsafe { self.state.get_or_insert_with_impl(key, f) }
+    }
+
     /// Returns the [`SlotMapKey`] that will be used when the user calls
     /// [`SlotMap::insert()`]. If the [`SlotMap`] is full it returns [`None`].
     pub fn next_free_key(&self) -> Option<SlotMapKey> {
         unsafe { self.state.next_free_key_impl() }
     }
 
+    /// Moves all values to the lowest keys in ascending key order and removes the internal
+    /// fragmentation caused by many removals. All keys whose value was moved are invalidated,
+    /// the returned [`SlotMapKeyRemap`] contains their new keys.
+    pub fn compact(&mut self) -> SlotMapKeyRemap {
+        unsafe { self.state.compact_impl() }
+    }
+
     /// Returns the number of stored values.
     pub fn len(&self) -> usize {
         self.state.len_impl()
     }
 
+    /// Returns the maximum number of values that were stored at the same time over the
+    /// lifetime of the container.
+    pub fn high_water_mark(&self) -> usize {
+        self.state.high_water_mark_impl()
+    }
+
     /// Returns the capacity.
     pub fn capacity(&self) -> usize {
         self.state.capacity_impl()
@@ -704,3 +1525,110 @@ impl<T, const CAPACITY: usize> FixedSizeSlotMap<T, CAPACITY> {
         self.state.is_full_impl()
     }
 }
+
+impl<T, const CAPACITY: usize> FixedSizeSlotMap<T, CAPACITY> {
+    /// Creates a new empty [`FixedSizeSlotMap`] in a const context so that it can be placed
+    /// into a `static`. The internal arrays and free index queues are initialized at compile
+    /// time.
+    ///
+    /// It is only available with the default `usize` index type.
+    ///
+    /// No trait bounds on `T` are required since the data array is initialized with
+    /// `[const { None }; CAPACITY]`. To modify a `static` [`FixedSizeSlotMap`] it must be
+    /// wrapped into a synchronization primitive like a mutex which then requires `T: Send`.
+    ///
+    /// ```
+    /// use iceoryx2_bb_container::slotmap::FixedSizeSlotMap;
+    /// use std::sync::Mutex;
+    ///
+    /// static REGISTRY: Mutex<FixedSizeSlotMap<u64, 16>> = Mutex::new(FixedSizeSlotMap::new_const());
+    ///
+    /// let key = REGISTRY.lock().unwrap().insert(123).unwrap();
+    /// assert_eq!(REGISTRY.lock().unwrap().get(key), Some(&123));
+    /// ```
+    pub const fn new_const() -> Self {
+        let mut idx_to_data = [INVALID; CAPACITY];
+        let mut idx_to_data_free_list = [FreeListEntry {
+            previous: INVALID,
+            next: INVALID,
+        }; CAPACITY];
+        let mut data_next_free_index = [0; CAPACITY];
+
+        let mut n = 0;
+        while n < CAPACITY {
+            idx_to_data[n] = INVALID;
+            idx_to_data_free_list[n] = FreeListEntry {
+                previous: if n == 0 { INVALID } else { n - 1 },
+                next: if n < CAPACITY - 1 { n + 1 } else { INVALID },
+            };
+            data_next_free_index[n] = n;
+            n += 1;
+        }
+
+        // the relocatable pointers store the distance from their own position to the data,
+        // since every container starts with its pointer this is the distance from the
+        // container to the corresponding array inside of Self
+        const fn distance(state: usize, container: usize, array: usize) -> isize {
+            array as isize - state as isize - container as isize
+        }
+        let state = core::mem::offset_of!(Self, state);
+
+        Self {
+            state: RelocatableSlotMap {
+                idx_to_data: unsafe {
+                    RelocatableVec::new_preinitialized(
+                        distance(
+                            state,
+                            core::mem::offset_of!(RelocatableSlotMap<T>, idx_to_data),
+                            core::mem::offset_of!(Self, _idx_to_data),
+                        ),
+                        CAPACITY,
+                        CAPACITY,
+                    )
+                },
+                idx_to_data_free_list: unsafe {
+                    RelocatableVec::new_preinitialized(
+                        distance(
+                            state,
+                            core::mem::offset_of!(RelocatableSlotMap<T>, idx_to_data_free_list),
+                            core::mem::offset_of!(Self, _idx_to_data_free_list),
+                        ),
+                        CAPACITY,
+                        CAPACITY,
+                    )
+                },
+                data: unsafe {
+                    RelocatableVec::new_preinitialized(
+                        distance(
+                            state,
+                            core::mem::offset_of!(RelocatableSlotMap<T>, data),
+                            core::mem::offset_of!(Self, _data),
+                        ),
+                        CAPACITY,
+                        CAPACITY,
+                    )
+                },
+                data_next_free_index: unsafe {
+                    RelocatableQueue::new_preinitialized(
+                        distance(
+                            state,
+                            core::mem::offset_of!(RelocatableSlotMap<T>, data_next_free_index),
+                            core::mem::offset_of!(Self, _data_next_free_index),
+                        ),
+                        CAPACITY,
+                        CAPACITY,
+                        CAPACITY,
+                    )
+                },
+                idx_to_data_free_list_head: 0,
+                is_initialized: IoxAtomicBool::new(true),
+                len: 0,
+                high_water_mark: 0,
+            },
+            _idx_to_data: MaybeUninit::new(idx_to_data),
+            _idx_to_data_free_list: MaybeUninit::new(idx_to_data_free_list),
+            _data: MaybeUninit::new([const { None }; CAPACITY]),
+            _data_next_free_index: MaybeUninit::new(data_next_free_index),
+        }
+    }
+}
@@ -388,6 +388,43 @@ pub trait String:
         }
     }
 
+    /// Removes all leading and trailing ASCII whitespace characters from the string.
+    /// ```
+    /// use iceoryx2_bb_container::string::*;
+    ///
+    /// let mut some_string = StaticString::<123>::from_bytes(b" \t hello world\n").unwrap();
+    /// some_string.trim_ascii_whitespace();
+    ///
+    /// assert!(some_string == b"hello world");
+    /// ```
+    fn trim_ascii_whitespace(&mut self) {
+        let trimmed_len = self.as_bytes().trim_ascii_end().len();
+        self.truncate(trimmed_len);
+        let leading = self.len() - self.as_bytes().trim_ascii_start().len();
+        self.remove_range(0, leading);
+    }
+
+    /// Removes all leading and trailing occurrences of the provided byte from the string.
+    /// ```
+    /// use iceoryx2_bb_container::string::*;
+    ///
+    /// let mut some_string = StaticString::<123>::from_bytes(b"//some/path//").unwrap();
+    /// some_string.trim_matches(b'/');
+    ///
+    /// assert!(some_string == b"some/path");
+    /// ```
+    fn trim_matches(&mut self, byte: u8) {
+        let trailing = self
+            .as_bytes()
+            .iter()
+            .rev()
+            .take_while(|b| **b == byte)
+            .count();
+        self.truncate(self.len() - trailing);
+        let leading = self.as_bytes().iter().take_while(|b| **b == byte).count();
+        self.remove_range(0, leading);
+    }
+
     /// Truncates the string to new_len.
     fn truncate(&mut self, new_len: usize) {
         if self.len() < new_len {
@@ -72,6 +72,7 @@ use iceoryx2_bb_elementary::math::unaligned_mem_size;
 use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
 use iceoryx2_bb_elementary_traits::pointer_trait::PointerTrait;
 pub use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::{fail, fatal_panic};
 
 use crate::string::{as_escaped_string, internal, String};
@@ -84,6 +85,8 @@ pub struct RelocatableString {
     len: u64,
 }
 
+unsafe impl ZeroCopySend for RelocatableString {}
+
 impl internal::StringView for RelocatableString {
     fn data(&self) -> &[MaybeUninit<u8>] {
         self.verify_init("data()");
@@ -29,6 +29,8 @@ use iceoryx2_bb_elementary::{math::unaligned_mem_size, relocatable_ptr::Relocata
 
 use iceoryx2_bb_log::{fail, fatal_panic};
 
+use crate::RelocatablePointerWidth;
+
 pub(crate) type Vec<T> = MetaVec<T, GenericOwningPointer>;
 
 pub(crate) type RelocatableVec<T> = MetaVec<T, GenericRelocatablePointer>;
@@ -76,10 +78,10 @@ impl<T, Ptr: GenericPointer> Drop for MetaVec<T, Ptr> {
     }
 }
 
-impl<T> RelocatableContainer for RelocatableVec<T> {
+impl<T, Ptr: RelocatablePointerWidth> RelocatableContainer for MetaVec<T, Ptr> {
     unsafe fn new_uninit(capacity: usize) -> Self {
         Self {
-            data_ptr: RelocatablePointer::new_uninit(),
+            data_ptr: Ptr::new_uninit(),
             capacity,
             len: 0,
             _phantom_data: PhantomData,
@@ -94,12 +96,20 @@ impl<T> RelocatableContainer for RelocatableVec<T> {
             fatal_panic!(from "Vec::init()", "Memory already initialized, Initializing it twice may lead to undefined behavior.");
         }
 
-        self.data_ptr.init(fail!(from "Vec::init", when allocator
-             .allocate(Layout::from_size_align_unchecked(
-                 core::mem::size_of::<T>() * self.capacity,
-                 core::mem::align_of::<T>(),
-             )), "Failed to initialize vec since the allocation of the data memory failed."
-        ));
+        let layout = Layout::from_size_align_unchecked(
+            core::mem::size_of::<T>() * self.capacity,
+            core::mem::align_of::<T>(),
+        );
+        let memory = match allocator.allocate(layout) {
+            Ok(memory) => memory,
+            Err(e) => {
+                fail!(from "Vec::init", with e,
+                    "Failed to initialize vec since the allocation of {} bytes for the data memory failed ({:?}, {:?}).",
+                    layout.size(), e, allocator.statistics());
+            }
+        };
+        fail!(from "Vec::init", when Ptr::init(&self.data_ptr, memory),
+            "Failed to initialize vec since the data memory cannot be addressed by the underlying pointer.");
 
         Ok(())
     }
@@ -224,12 +234,48 @@ impl<T> Vec<T> {
             _phantom_data: PhantomData,
         }
     }
+
+    /// Increases the capacity so that at least `additional` more elements fit into the vector.
+    /// If the capacity is already sufficient nothing happens.
+    pub fn reserve(&mut self, additional: usize) {
+        let new_capacity = self.len + additional;
+        if new_capacity <= self.capacity {
+            return;
+        }
+
+        unsafe { self.data_ptr.realloc(new_capacity, self.len) };
+        self.capacity = new_capacity;
+    }
 }
 
-unsafe impl<T: ZeroCopySend> ZeroCopySend for RelocatableVec<T> {}
+unsafe impl<T: ZeroCopySend, Ptr: RelocatablePointerWidth> ZeroCopySend for MetaVec<T, Ptr> {}
 
-impl<T> RelocatableVec<T> {
+impl<T, Ptr: RelocatablePointerWidth> MetaVec<T, Ptr> {
     pub const fn const_memory_size(capacity: usize) -> usize {
         unaligned_mem_size::<T>(capacity)
     }
 }
+
+impl<T> RelocatableVec<T> {
+    /// Creates an already initialized [`RelocatableVec`] whose data resides `distance` bytes
+    /// after the memory location of the vector itself. Used to construct self-contained
+    /// containers in a const context.
+    ///
+    /// # Safety
+    ///
+    ///  * the memory at `distance` must be part of the same object as the vector
+    ///  * the first `len` elements of the memory must be initialized
+    ///
+    pub(crate) const unsafe fn new_preinitialized(
+        distance: isize,
+        capacity: usize,
+        len: usize,
+    ) -> Self {
+        Self {
+            data_ptr: RelocatablePointer::new(distance),
+            capacity,
+            len,
+            _phantom_data: PhantomData,
+        }
+    }
+}
@@ -20,6 +20,7 @@ pub mod relocatable_vec;
 /// Compile-time fixed-capacity shared-memory compatible vector
 pub mod static_vec;
 
+use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_log::fail;
 pub use polymorphic_vec::*;
 pub use relocatable_vec::*;
@@ -139,6 +140,16 @@ pub trait Vector<T>: Deref<Target = [T]> + DerefMut + internal::VectorView<T> {
         unsafe { self.set_len(self.len() as u64 + other.len() as u64) };
     }
 
+    /// Calls the provided callback with the index and a reference of every element until it
+    /// returns [`CallbackProgression::Stop`].
+    fn for_each<F: FnMut(usize, &T) -> CallbackProgression>(&self, mut callback: F) {
+        for (n, element) in self.as_slice().iter().enumerate() {
+            if callback(n, element) == CallbackProgression::Stop {
+                return;
+            }
+        }
+    }
+
     /// Inserts an element at the provided index and shifting all elements
     /// after the index to the right.
     fn insert(&mut self, index: usize, element: T) -> Result<(), VectorModificationError> {
@@ -162,6 +162,11 @@ impl<T> RelocatableVec<T> {
             );
     }
 
+    /// Returns true if [`RelocatableContainer::init()`] was called, otherwise false.
+    pub fn is_initialized(&self) -> bool {
+        self.data_ptr.is_initialized()
+    }
+
     /// Returns the required memory size for a vec with a specified capacity
     pub const fn const_memory_size(capacity: usize) -> usize {
         unaligned_mem_size::<T>(capacity)
@@ -187,15 +192,17 @@ impl<T> RelocatableContainer for RelocatableVec<T> {
                 "Memory already initialized. Initializing it twice may lead to undefined behavior.");
         }
 
-        let ptr = match allocator.allocate(Layout::from_size_align_unchecked(
+        let layout = Layout::from_size_align_unchecked(
             core::mem::size_of::<T>() * self.capacity as usize,
             core::mem::align_of::<T>(),
-        )) {
+        );
+        let ptr = match allocator.allocate(layout) {
             Ok(ptr) => ptr,
             Err(e) => {
                 let origin = format!("RelocatableVec<{}>::init()", core::any::type_name::<T>());
                 fail!(from origin, with e,
-                    "Failed to initialize since the allocation of the data memory failed.");
+                    "Failed to initialize since the allocation of {} bytes for the data memory failed ({:?}, {:?}).",
+                    layout.size(), e, allocator.statistics());
             }
         };
 
@@ -208,6 +208,35 @@ mod flat_map {
         assert_that!(map.is_full(), eq true);
     }
 
+    #[test]
+    fn relocatable_flatmap_with_over_aligned_value_fits_into_memory_size() {
+        #[derive(Debug, Clone, PartialEq)]
+        #[repr(align(64))]
+        struct CacheLinePadded(u32);
+
+        const MEM_SIZE: usize =
+            RelocatableFlatMap::<u32, CacheLinePadded>::const_memory_size(CAPACITY);
+        let mut memory = vec![0u8; MEM_SIZE + 64];
+
+        for offset in 0..64 {
+            let bump_allocator = BumpAllocator::new_with_size_limit(
+                unsafe { memory.as_mut_ptr().add(offset) },
+                MEM_SIZE,
+            );
+
+            let mut sut =
+                unsafe { RelocatableFlatMap::<u32, CacheLinePadded>::new_uninit(CAPACITY) };
+            assert_that!(unsafe { sut.init(&bump_allocator) }, is_ok);
+
+            for i in 0..CAPACITY as u32 {
+                assert_that!(unsafe { sut.insert(i, CacheLinePadded(i)) }, is_ok);
+            }
+            for i in 0..CAPACITY as u32 {
+                assert_that!(unsafe { sut.get(&i) }, eq Some(CacheLinePadded(i)));
+            }
+        }
+    }
+
     #[test]
     #[should_panic]
     fn double_init_call_causes_panic() {
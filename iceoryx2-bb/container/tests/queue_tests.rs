@@ -13,6 +13,7 @@
 mod queue {
     use iceoryx2_bb_container::queue::*;
     use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+    use iceoryx2_bb_elementary::CallbackProgression;
     use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
     use iceoryx2_bb_testing::{assert_that, lifetime_tracker::LifetimeTracker, memory::RawMemory};
 
@@ -48,6 +49,56 @@ mod queue {
         assert_that!(sut, len 0);
     }
 
+    #[test]
+    fn relocatable_queue_with_32_bit_pointer_push_pop_works() {
+        const MEM_SIZE: usize = RelocatableQueue32::<usize>::const_memory_size(SUT_CAPACITY);
+        let mut memory = [0u8; MEM_SIZE];
+        let allocator = BumpAllocator::new(memory.as_mut_ptr());
+
+        let mut sut = unsafe { RelocatableQueue32::<usize>::new_uninit(SUT_CAPACITY) };
+        unsafe { assert_that!(sut.init(&allocator), is_ok) };
+
+        for i in 0..sut.capacity() {
+            assert_that!(unsafe { sut.push(i * 5) }, eq true);
+        }
+        assert_that!(sut.is_full(), eq true);
+
+        for i in 0..sut.capacity() {
+            assert_that!(unsafe { sut.pop() }, eq Some(i * 5));
+        }
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    fn relocatable_queue_with_over_aligned_type_fits_into_memory_size() {
+        #[derive(Debug, PartialEq)]
+        #[repr(align(64))]
+        struct CacheLinePadded(usize);
+
+        const CAPACITY: usize = 12;
+        const MEM_SIZE: usize = RelocatableQueue::<CacheLinePadded>::const_memory_size(CAPACITY);
+        let mut memory = vec![0u8; MEM_SIZE + 64];
+
+        for offset in 0..64 {
+            let allocator = BumpAllocator::new_with_size_limit(
+                unsafe { memory.as_mut_ptr().add(offset) },
+                MEM_SIZE,
+            );
+
+            let mut sut = unsafe { RelocatableQueue::<CacheLinePadded>::new_uninit(CAPACITY) };
+            assert_that!(unsafe { sut.init(&allocator) }, is_ok);
+
+            for i in 0..CAPACITY {
+                assert_that!(unsafe { sut.push(CacheLinePadded(i)) }, eq true);
+            }
+            assert_that!(sut.peek().unwrap() as *const CacheLinePadded as usize % 64, eq 0);
+
+            for i in 0..CAPACITY {
+                assert_that!(unsafe { sut.pop() }, eq Some(CacheLinePadded(i)));
+            }
+        }
+    }
+
     #[test]
     fn relocatable_clear_empties_queue() {
         let mut memory = [0u8; 1024];
@@ -200,6 +251,51 @@ mod queue {
         }
     }
 
+    #[test]
+    fn for_each_visits_all_elements_starting_with_the_oldest() {
+        let mut sut = Sut::new();
+
+        for i in 0..sut.capacity() / 2 {
+            sut.push_with_overflow(i);
+        }
+
+        for i in 0..sut.capacity() {
+            sut.push_with_overflow(2 * i + 25);
+        }
+
+        let mut number_of_calls = 0;
+        sut.for_each(|index, value| {
+            assert_that!(index, eq number_of_calls);
+            assert_that!(*value, eq 2 * index + 25);
+            number_of_calls += 1;
+            CallbackProgression::Continue
+        });
+
+        assert_that!(number_of_calls, eq sut.len());
+    }
+
+    #[test]
+    fn for_each_stops_when_callback_returns_stop() {
+        const STOP_AFTER: usize = 7;
+        let mut sut = Queue::<usize>::new(SUT_CAPACITY);
+
+        for i in 0..SUT_CAPACITY {
+            sut.push(i);
+        }
+
+        let mut visited_values = vec![];
+        sut.for_each(|index, value| {
+            visited_values.push(*value);
+            if index + 1 == STOP_AFTER {
+                CallbackProgression::Stop
+            } else {
+                CallbackProgression::Continue
+            }
+        });
+
+        assert_that!(visited_values, eq(0..STOP_AFTER).collect::<Vec<_>>());
+    }
+
     #[test]
     fn drops_all_objects_when_out_of_scope() {
         let state = LifetimeTracker::start_tracking();
@@ -293,6 +389,61 @@ mod queue {
         }
     }
 
+    #[test]
+    fn reserve_preserves_order_of_wrapped_elements() {
+        const CAPACITY: usize = 5;
+        let mut sut = Queue::<usize>::new(CAPACITY);
+        for i in 0..CAPACITY {
+            assert_that!(sut.push(i), eq true);
+        }
+        for i in 0..3 {
+            assert_that!(sut.pop(), eq Some(i));
+        }
+        for i in CAPACITY..CAPACITY + 3 {
+            assert_that!(sut.push(i), eq true);
+        }
+        assert_that!(sut.is_full(), eq true);
+
+        sut.reserve(4);
+        assert_that!(sut.capacity(), eq CAPACITY + 4);
+        assert_that!(sut, len CAPACITY);
+
+        for i in CAPACITY + 3..CAPACITY + 7 {
+            assert_that!(sut.push(i), eq true);
+        }
+        assert_that!(sut.is_full(), eq true);
+
+        for i in 3..CAPACITY + 7 {
+            assert_that!(sut.pop(), eq Some(i));
+        }
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    fn reserve_with_sufficient_capacity_does_nothing() {
+        let mut sut = Queue::<usize>::new(SUT_CAPACITY);
+        assert_that!(sut.push(123), eq true);
+
+        sut.reserve(SUT_CAPACITY - 1);
+
+        assert_that!(sut.capacity(), eq SUT_CAPACITY);
+        assert_that!(sut.pop(), eq Some(123));
+    }
+
+    #[test]
+    fn reserve_does_not_drop_or_copy_objects() {
+        let state = LifetimeTracker::start_tracking();
+        let mut sut = Queue::<LifetimeTracker>::new(2);
+        assert_that!(sut.push(LifetimeTracker::new_with_value(1)), eq true);
+        assert_that!(sut.push(LifetimeTracker::new_with_value(2)), eq true);
+
+        sut.reserve(8);
+        assert_that!(state.number_of_living_instances(), eq 2);
+        assert_that!(sut.pop().map(|v| v.value), eq Some(1));
+        assert_that!(sut.pop().map(|v| v.value), eq Some(2));
+        assert_that!(state.number_of_living_instances(), eq 0);
+    }
+
     #[test]
     fn placement_default_works() {
         type Sut = FixedSizeQueue<usize, SUT_CAPACITY>;
@@ -325,6 +476,19 @@ mod queue {
         assert_that!(*sut.peek_mut().unwrap(), eq 99182);
     }
 
+    #[test]
+    fn is_initialized_is_set_by_init() {
+        const MEM_SIZE: usize = RelocatableQueue::<usize>::const_memory_size(SUT_CAPACITY);
+        let mut memory = [0u8; MEM_SIZE];
+        let bump_allocator = BumpAllocator::new(memory.as_mut_ptr());
+
+        let mut sut = unsafe { RelocatableQueue::<usize>::new_uninit(SUT_CAPACITY) };
+        assert_that!(sut.is_initialized(), eq false);
+
+        unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+        assert_that!(sut.is_initialized(), eq true);
+    }
+
     #[test]
     #[should_panic]
     fn double_init_call_causes_panic() {
@@ -36,6 +36,20 @@ fn panic_is_called_in_debug_mode_if_vec_is_not_initialized() {
     assert_that!(sut.push(0), is_ok);
 }
 
+#[test]
+fn is_initialized_is_set_by_init() {
+    const CAPACITY: usize = 12;
+    const MEM_SIZE: usize = RelocatableVec::<u128>::const_memory_size(CAPACITY);
+    let mut memory = [0u8; MEM_SIZE];
+    let bump_allocator = BumpAllocator::new(memory.as_mut_ptr());
+
+    let mut sut = unsafe { RelocatableVec::<u128>::new_uninit(CAPACITY) };
+    assert_that!(sut.is_initialized(), eq false);
+
+    unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+    assert_that!(sut.is_initialized(), eq true);
+}
+
 #[test]
 fn two_vectors_with_same_content_are_equal() {
     const SUT_CAPACITY: usize = 12;
@@ -102,3 +116,32 @@ fn two_vectors_with_different_len_are_not_equal() {
 
     assert_that!(sut_1, ne sut_2);
 }
+
+#[test]
+fn relocatable_vec_with_over_aligned_type_fits_into_memory_size() {
+    #[derive(Debug, PartialEq)]
+    #[repr(align(64))]
+    struct CacheLinePadded(usize);
+
+    const CAPACITY: usize = 12;
+    const MEM_SIZE: usize = RelocatableVec::<CacheLinePadded>::const_memory_size(CAPACITY);
+    let mut memory = vec![0u8; MEM_SIZE + 64];
+
+    for offset in 0..64 {
+        let bump_allocator = BumpAllocator::new_with_size_limit(
+            unsafe { memory.as_mut_ptr().add(offset) },
+            MEM_SIZE,
+        );
+
+        let mut sut = unsafe { RelocatableVec::<CacheLinePadded>::new_uninit(CAPACITY) };
+        assert_that!(unsafe { sut.init(&bump_allocator) }, is_ok);
+
+        for i in 0..CAPACITY {
+            assert_that!(sut.push(CacheLinePadded(i)), is_ok);
+        }
+        assert_that!(sut.as_ptr() as usize % 64, eq 0);
+        for i in 0..CAPACITY {
+            assert_that!(sut[i], eq CacheLinePadded(i));
+        }
+    }
+}
@@ -12,9 +12,12 @@
 
 use iceoryx2_bb_container::slotmap::*;
 use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
-use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::lifetime_tracker::LifetimeTracker;
 use iceoryx2_bb_testing::memory::RawMemory;
+use iceoryx2_bb_testing::{assert_that, test_fail};
+use std::sync::Mutex;
 
 mod slot_map {
     use super::*;
@@ -23,6 +26,15 @@ mod slot_map {
     type Sut = SlotMap<usize>;
     type FixedSizeSut = FixedSizeSlotMap<usize, SUT_CAPACITY>;
 
+    const _: usize = FixedSizeSlotMap::<u64, 8>::memory_size();
+    const _: () = assert!(FixedSizeSut::memory_size() >= SUT_CAPACITY * 4);
+
+    #[test]
+    fn fixed_size_slotmap_memory_size_covers_embedded_arrays() {
+        assert_that!(FixedSizeSut::memory_size(), eq core::mem::size_of::<FixedSizeSut>());
+        assert_that!(FixedSizeSut::memory_size(), ge RelocatableSlotMap::<usize>::const_memory_size(SUT_CAPACITY));
+    }
+
     #[test]
     fn new_slotmap_is_empty() {
         let sut = Sut::new(SUT_CAPACITY);
@@ -43,6 +55,18 @@ mod slot_map {
         assert_that!(sut.capacity(), eq SUT_CAPACITY);
     }
 
+    #[test]
+    fn try_new_fixed_size_slotmap_works() {
+        let sut = FixedSizeSut::try_new();
+        assert_that!(sut, is_ok);
+        let mut sut = sut.unwrap();
+
+        assert_that!(sut, is_empty);
+        assert_that!(sut.capacity(), eq SUT_CAPACITY);
+        let key = sut.insert(123).unwrap();
+        assert_that!(sut.get(key), eq Some(&123));
+    }
+
     #[test]
     fn inserting_elements_works() {
         let mut sut = FixedSizeSut::new();
@@ -71,6 +95,28 @@ mod slot_map {
         assert_that!(sut.insert(34), is_none);
     }
 
+    #[test]
+    fn try_insert_when_full_returns_value() {
+        let mut sut = SlotMap::<Vec<usize>>::new(SUT_CAPACITY);
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.try_insert(vec![i]), is_ok);
+        }
+
+        let result = sut.try_insert(vec![1, 2, 3]);
+        assert_that!(result, is_err);
+        assert_that!(result.unwrap_err().value, eq vec![1, 2, 3]);
+        assert_that!(sut, len SUT_CAPACITY);
+    }
+
+    #[test]
+    fn try_insert_returns_key_of_inserted_value() {
+        let mut sut = FixedSizeSut::new();
+
+        let key = sut.try_insert(123).unwrap();
+        assert_that!(*sut.get(key).unwrap(), eq 123);
+    }
+
     #[test]
     fn removing_elements_works() {
         let mut sut = FixedSizeSut::new();
@@ -160,6 +206,76 @@ mod slot_map {
         assert_that!(sut.insert_at(key, 781), eq false);
     }
 
+    #[test]
+    fn insert_at_and_remove_with_key_at_capacity_do_not_panic() {
+        let mut sut = Sut::new(SUT_CAPACITY);
+        let mut fixed_size_sut = FixedSizeSut::new();
+        let key = SlotMapKey::new(SUT_CAPACITY);
+
+        assert_that!(sut.insert_at(key, 781), eq false);
+        assert_that!(sut.remove(key), eq None);
+        assert_that!(sut.len(), eq 0);
+
+        assert_that!(fixed_size_sut.insert_at(key, 781), eq false);
+        assert_that!(fixed_size_sut.remove(key), eq None);
+        assert_that!(fixed_size_sut.len(), eq 0);
+    }
+
+    #[test]
+    fn try_insert_at_and_try_remove_with_key_at_capacity_fail() {
+        let mut sut = Sut::new(SUT_CAPACITY);
+        let mut fixed_size_sut = FixedSizeSut::new();
+        let key = SlotMapKey::new(SUT_CAPACITY);
+
+        assert_that!(sut.try_insert_at(key, 781), eq Err(SlotMapKeyOutOfBounds { key }));
+        assert_that!(sut.try_remove(key), eq Err(SlotMapKeyOutOfBounds { key }));
+
+        assert_that!(fixed_size_sut.try_insert_at(key, 781), eq Err(SlotMapKeyOutOfBounds { key }));
+        assert_that!(fixed_size_sut.try_remove(key), eq Err(SlotMapKeyOutOfBounds { key }));
+    }
+
+    #[test]
+    fn try_insert_at_returns_replaced_value() {
+        let mut sut = FixedSizeSut::new();
+        let key = SlotMapKey::new(SUT_CAPACITY - 1);
+
+        assert_that!(sut.try_insert_at(key, 781), eq Ok(None));
+        assert_that!(sut.try_insert_at(key, 9182), eq Ok(Some(781)));
+        assert_that!(*sut.get(key).unwrap(), eq 9182);
+        assert_that!(sut.len(), eq 1);
+    }
+
+    #[test]
+    fn try_insert_at_of_next_free_key_does_not_reuse_key_for_insert() {
+        let mut sut = Sut::new(SUT_CAPACITY);
+        let key = SlotMapKey::new(0);
+
+        assert_that!(sut.try_insert_at(key, 781), eq Ok(None));
+
+        for n in 1..SUT_CAPACITY {
+            let new_key = sut.insert(n);
+            assert_that!(new_key, is_some);
+            assert_that!(new_key, ne Some(key));
+            assert_that!(sut, len n + 1);
+        }
+
+        assert_that!(sut.is_full(), eq true);
+        assert_that!(sut.insert(0), is_none);
+        assert_that!(sut.get(key), eq Some(&781));
+    }
+
+    #[test]
+    fn try_remove_returns_removed_value() {
+        let mut sut = Sut::new(SUT_CAPACITY);
+        let key = SlotMapKey::new(SUT_CAPACITY - 1);
+
+        assert_that!(sut.try_remove(key), eq Ok(None));
+        assert_that!(sut.try_insert_at(key, 781), eq Ok(None));
+        assert_that!(sut.try_remove(key), eq Ok(Some(781)));
+        assert_that!(sut.try_remove(key), eq Ok(None));
+        assert_that!(sut.is_empty(), eq true);
+    }
+
     #[test]
     fn iterating_works() {
         let mut sut = FixedSizeSut::new();
@@ -174,6 +290,183 @@ mod slot_map {
         }
     }
 
+    #[test]
+    fn iter_from_resumes_iteration_at_given_key() {
+        const PAGE_SIZE: usize = 10;
+        let mut sut = Sut::new(SUT_CAPACITY);
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(5 * i + 3), is_some);
+        }
+        for n in 0..SUT_CAPACITY / 4 {
+            assert_that!(sut.remove(SlotMapKey::new(4 * n + 1)), is_some);
+        }
+
+        let mut visited_keys = vec![];
+        let mut start = SlotMapKey::new(0);
+        loop {
+            let page: Vec<_> = sut.iter_from(start).take(PAGE_SIZE).collect();
+            if page.is_empty() {
+                break;
+            }
+            assert_that!(page.len(), le PAGE_SIZE);
+
+            for (key, value) in &page {
+                assert_that!(**value, eq 5 * key.value() + 3);
+                visited_keys.push(*key);
+            }
+            start = SlotMapKey::new(page.last().unwrap().0.value() + 1);
+        }
+
+        let expected_keys: Vec<_> = sut.iter().map(|(key, _)| key).collect();
+        assert_that!(visited_keys, eq expected_keys);
+        assert_that!(sut.iter_from(SlotMapKey::new(SUT_CAPACITY)).next(), is_none);
+    }
+
+    #[test]
+    fn for_each_visits_all_entries_in_key_order() {
+        let mut sut = Sut::new(SUT_CAPACITY);
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(5 * i + 3), is_some);
+        }
+        for n in 0..SUT_CAPACITY / 2 {
+            assert_that!(sut.remove(SlotMapKey::new(2 * n)), eq Some(10 * n + 3));
+        }
+
+        let mut visited_keys = vec![];
+        sut.for_each(|key, value| {
+            assert_that!(*value, eq 5 * key.value() + 3);
+            visited_keys.push(key.value());
+            CallbackProgression::Continue
+        });
+
+        let expected_keys: Vec<usize> = (0..SUT_CAPACITY / 2).map(|n| 2 * n + 1).collect();
+        assert_that!(visited_keys, eq expected_keys);
+    }
+
+    #[test]
+    fn for_each_stops_when_callback_returns_stop() {
+        const STOP_AFTER: usize = 12;
+        let mut sut = FixedSizeSut::new();
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(i), is_some);
+        }
+
+        let mut number_of_calls = 0;
+        sut.for_each(|_, value| {
+            number_of_calls += 1;
+            if *value + 1 == STOP_AFTER {
+                CallbackProgression::Stop
+            } else {
+                CallbackProgression::Continue
+            }
+        });
+
+        assert_that!(number_of_calls, eq STOP_AFTER);
+    }
+
+    #[test]
+    fn high_water_mark_reflects_peak_occupancy() {
+        const PEAK: usize = SUT_CAPACITY / 2;
+        let mut sut = Sut::new(SUT_CAPACITY);
+        assert_that!(sut.high_water_mark(), eq 0);
+
+        let mut keys = vec![];
+        for i in 0..PEAK {
+            keys.push(sut.insert(i).unwrap());
+            assert_that!(sut.high_water_mark(), eq i + 1);
+        }
+
+        for key in keys.drain(..) {
+            assert_that!(sut.remove(key), is_some);
+        }
+        assert_that!(sut, len 0);
+        assert_that!(sut.high_water_mark(), eq PEAK);
+
+        for i in 0..PEAK / 2 {
+            assert_that!(sut.insert(i), is_some);
+        }
+        assert_that!(sut, len PEAK / 2);
+        assert_that!(sut.high_water_mark(), eq PEAK);
+    }
+
+    #[test]
+    fn high_water_mark_of_fixed_size_slotmap_counts_insert_at_and_ignores_overrides() {
+        let mut sut = FixedSizeSut::new();
+
+        assert_that!(sut.insert_at(SlotMapKey::new(5), 1), eq true);
+        assert_that!(sut.insert_at(SlotMapKey::new(5), 2), eq true);
+        assert_that!(sut.high_water_mark(), eq 1);
+
+        assert_that!(sut.insert(3), is_some);
+        assert_that!(sut.high_water_mark(), eq 2);
+
+        assert_that!(sut.remove(SlotMapKey::new(5)), eq Some(2));
+        assert_that!(sut.high_water_mark(), eq 2);
+    }
+
+    #[test]
+    fn get_or_insert_with_returns_stored_value_when_occupied() {
+        let mut sut = FixedSizeSut::new();
+        let key = sut.insert(42).unwrap();
+
+        let value = sut.get_or_insert_with(key, || test_fail!("must not be called"));
+        assert_that!(value, eq Some(&mut 42));
+
+        *sut.get_or_insert_with(key, || 0).unwrap() += 1;
+        assert_that!(sut.get(key), eq Some(&43));
+        assert_that!(sut, len 1);
+    }
+
+    #[test]
+    fn get_or_insert_with_inserts_value_when_vacant() {
+        let mut sut = FixedSizeSut::new();
+        let key = SlotMapKey::new(7);
+
+        let value = sut.get_or_insert_with(key, || 73);
+        assert_that!(value, eq Some(&mut 73));
+        assert_that!(sut.get(key), eq Some(&73));
+        assert_that!(sut, len 1);
+
+        // the key was claimed and is not used by insert
+        for _ in 1..SUT_CAPACITY {
+            assert_that!(sut.insert(0), ne Some(key));
+        }
+        assert_that!(sut.is_full(), eq true);
+    }
+
+    #[test]
+    fn get_or_insert_with_of_next_free_key_does_not_reuse_key_for_insert() {
+        let mut sut = FixedSizeSut::new();
+        let key = SlotMapKey::new(0);
+
+        assert_that!(sut.get_or_insert_with(key, || 10), eq Some(&mut 10));
+
+        for n in 1..SUT_CAPACITY {
+            let new_key = sut.insert(n);
+            assert_that!(new_key, is_some);
+            assert_that!(new_key, ne Some(key));
+            assert_that!(sut, len n + 1);
+        }
+
+        assert_that!(sut.is_full(), eq true);
+        assert_that!(sut.insert(0), is_none);
+        assert_that!(sut.get(key), eq Some(&10));
+    }
+
+    #[test]
+    fn get_or_insert_with_out_of_bounds_key_returns_none() {
+        let mut sut = FixedSizeSut::new();
+
+        let value = sut.get_or_insert_with(SlotMapKey::new(SUT_CAPACITY), || {
+            test_fail!("must not be called")
+        });
+        assert_that!(value, is_none);
+        assert_that!(sut, is_empty);
+    }
+
     #[test]
     fn insert_remove_and_insert_works() {
         let mut sut = FixedSizeSut::new();
@@ -227,6 +520,220 @@ mod slot_map {
         assert_that!(next_key, is_none);
     }
 
+    #[test]
+    fn into_iter_returns_all_values_in_key_order() {
+        let mut sut = Sut::new(SUT_CAPACITY);
+        let mut keys = vec![];
+        for n in 0..SUT_CAPACITY {
+            keys.push(sut.insert(3 * n).unwrap());
+        }
+
+        for key in keys.iter().step_by(2) {
+            sut.remove(*key);
+        }
+
+        let mut number_of_values = 0;
+        for (n, (key, value)) in sut.into_iter().enumerate() {
+            let idx = 2 * n + 1;
+            assert_that!(key, eq keys[idx]);
+            assert_that!(value, eq 3 * idx);
+            number_of_values += 1;
+        }
+        assert_that!(number_of_values, eq SUT_CAPACITY / 2);
+    }
+
+    #[test]
+    fn into_values_drops_every_value_exactly_once() {
+        let state = LifetimeTracker::start_tracking();
+        let mut sut = SlotMap::<LifetimeTracker>::new(SUT_CAPACITY);
+        let mut keys = vec![];
+        for n in 0..SUT_CAPACITY {
+            keys.push(sut.insert(LifetimeTracker::new_with_value(n)).unwrap());
+        }
+
+        for key in keys.iter().take(SUT_CAPACITY / 4) {
+            sut.remove(*key);
+        }
+        assert_that!(state.number_of_living_instances(), eq SUT_CAPACITY - SUT_CAPACITY / 4);
+
+        let mut values = sut.into_values();
+        for n in SUT_CAPACITY / 4..SUT_CAPACITY / 2 {
+            let value = values.next().unwrap();
+            assert_that!(value.value, eq n);
+        }
+        assert_that!(state.number_of_living_instances(), eq SUT_CAPACITY / 2);
+
+        drop(values);
+        assert_that!(state.number_of_living_instances(), eq 0);
+
+        let mut drop_order = state.drop_order();
+        drop_order.sort();
+        assert_that!(drop_order, eq(0..SUT_CAPACITY).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn reserve_keeps_keys_valid() {
+        const CAPACITY: usize = 16;
+        let mut sut = Sut::new(CAPACITY);
+        let mut keys = vec![];
+        for n in 0..CAPACITY {
+            keys.push(sut.insert(5 * n).unwrap());
+        }
+        assert_that!(sut.insert(0), is_none);
+
+        sut.reserve(CAPACITY);
+        assert_that!(sut.capacity(), eq 2 * CAPACITY);
+        assert_that!(sut, len CAPACITY);
+
+        for (n, key) in keys.iter().enumerate() {
+            assert_that!(sut.get(*key), eq Some(&(5 * n)));
+        }
+
+        for n in CAPACITY..2 * CAPACITY {
+            keys.push(sut.insert(5 * n).unwrap());
+        }
+        assert_that!(sut.is_full(), eq true);
+
+        for (n, key) in keys.iter().enumerate() {
+            assert_that!(sut.remove(*key), eq Some(5 * n));
+        }
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    fn reserve_uses_new_keys_after_available_free_keys() {
+        const CAPACITY: usize = 4;
+        let mut sut = Sut::new(CAPACITY);
+        let key = sut.insert(1).unwrap();
+        let free_key = sut.next_free_key().unwrap();
+
+        sut.reserve(CAPACITY);
+        assert_that!(sut.capacity(), eq CAPACITY + 1);
+        assert_that!(sut.next_free_key(), eq Some(free_key));
+
+        for n in 0..CAPACITY {
+            assert_that!(sut.insert(n), is_some);
+        }
+        assert_that!(sut.is_full(), eq true);
+        assert_that!(sut.get(key), eq Some(&1));
+        assert_that!(sut.iter().count(), eq CAPACITY + 1);
+    }
+
+    #[test]
+    fn compact_keeps_values_reachable_via_remapped_keys() {
+        let mut sut = Sut::new(SUT_CAPACITY);
+        let mut keys = vec![];
+        for n in 0..SUT_CAPACITY {
+            keys.push(sut.insert(3 * n).unwrap());
+        }
+
+        // fragment the map by removing every value whose index is not divisible by 3
+        let mut remaining = vec![];
+        for (n, key) in keys.iter().enumerate() {
+            if n % 3 == 0 {
+                remaining.push((*key, 3 * n));
+            } else {
+                assert_that!(sut.remove(*key), eq Some(3 * n));
+            }
+        }
+
+        let remap = sut.compact();
+        assert_that!(sut, len remaining.len());
+        assert_that!(remap.is_empty(), eq false);
+        let number_of_moved_values = remaining
+            .iter()
+            .enumerate()
+            .filter(|(n, (key, _))| key.value() != *n)
+            .count();
+        assert_that!(remap.len(), eq number_of_moved_values);
+
+        for (old_key, value) in &remaining {
+            let new_key = remap.get(*old_key).unwrap_or(*old_key);
+            assert_that!(new_key.value(), lt remaining.len());
+            assert_that!(sut.get(new_key), eq Some(value));
+        }
+
+        let changes: Vec<(SlotMapKey, SlotMapKey)> = remap.iter().collect();
+        for keys in changes.windows(2) {
+            assert_that!(keys[0].0, lt keys[1].0);
+            assert_that!(keys[0].1, lt keys[1].1);
+        }
+
+        // the compacted map is dense
+        for (n, (key, _)) in sut.iter().enumerate() {
+            assert_that!(key.value(), eq n);
+        }
+    }
+
+    #[test]
+    fn compact_moves_values_whose_data_index_differs_from_the_key() {
+        const CAPACITY: usize = 16;
+        let mut sut = Sut::new(CAPACITY);
+        for n in 0..CAPACITY {
+            sut.insert(n).unwrap();
+        }
+
+        // recycled data indices are assigned in removal order, the keys are chosen by the user
+        for n in [3, 7, 1, 12, 5] {
+            sut.remove(SlotMapKey::new(n));
+        }
+        assert_that!(sut.insert_at(SlotMapKey::new(12), 112), eq true);
+        assert_that!(sut.insert_at(SlotMapKey::new(1), 101), eq true);
+        for n in [0, 9] {
+            sut.remove(SlotMapKey::new(n));
+        }
+
+        let expected_values: Vec<usize> = sut.iter().map(|(_, value)| *value).collect();
+        sut.compact();
+
+        let values: Vec<usize> = sut.iter().map(|(_, value)| *value).collect();
+        assert_that!(values, eq expected_values);
+        for (n, (key, _)) in sut.iter().enumerate() {
+            assert_that!(key.value(), eq n);
+        }
+    }
+
+    #[test]
+    fn compacted_slotmap_can_be_filled_and_emptied_again() {
+        const CAPACITY: usize = 16;
+        let mut sut = Sut::new(CAPACITY);
+        let mut keys = vec![];
+        for n in 0..CAPACITY {
+            keys.push(sut.insert(n).unwrap());
+        }
+        for key in keys.iter().take(CAPACITY / 2) {
+            sut.remove(*key);
+        }
+
+        assert_that!(sut.compact().len(), eq CAPACITY / 2);
+        assert_that!(sut.next_free_key(), eq Some(SlotMapKey::new(CAPACITY / 2)));
+
+        for n in 0..CAPACITY / 2 {
+            assert_that!(sut.insert(CAPACITY + n), eq Some(SlotMapKey::new(CAPACITY / 2 + n)));
+        }
+        assert_that!(sut.is_full(), eq true);
+        assert_that!(sut.insert(0), is_none);
+
+        for n in 0..CAPACITY {
+            assert_that!(sut.remove(SlotMapKey::new(n)), eq Some(CAPACITY / 2 + n));
+        }
+        assert_that!(sut, is_empty);
+        assert_that!(sut.compact().is_empty(), eq true);
+    }
+
+    #[test]
+    fn compact_of_dense_slotmap_changes_no_key() {
+        let mut sut = FixedSizeSut::new();
+        for n in 0..SUT_CAPACITY / 2 {
+            sut.insert(n).unwrap();
+        }
+
+        assert_that!(sut.compact().is_empty(), eq true);
+        for n in 0..SUT_CAPACITY / 2 {
+            assert_that!(sut.get(SlotMapKey::new(n)), eq Some(&n));
+        }
+    }
+
     #[test]
     fn placement_default_works() {
         let mut sut = RawMemory::<FixedSizeSut>::new_zeroed();
@@ -236,6 +743,165 @@ mod slot_map {
         assert_that!(res, is_some);
     }
 
+    #[test]
+    fn const_constructed_static_slotmap_works() {
+        static SUT: Mutex<FixedSizeSut> = Mutex::new(FixedSizeSut::new_const());
+
+        let mut sut = SUT.lock().unwrap();
+        assert_that!(*sut, len 0);
+        assert_that!(sut.capacity(), eq SUT_CAPACITY);
+        assert_that!(sut.next_free_key(), eq Some(SlotMapKey::new(0)));
+
+        let mut keys = vec![];
+        for i in 0..SUT_CAPACITY {
+            keys.push(sut.insert(i).unwrap());
+        }
+        assert_that!(sut.is_full(), eq true);
+        assert_that!(sut.insert(0), is_none);
+
+        for (i, key) in keys.iter().enumerate() {
+            assert_that!(*sut.get(*key).unwrap(), eq i);
+        }
+
+        for key in keys {
+            assert_that!(sut.remove(key), is_some);
+        }
+        assert_that!(*sut, is_empty);
+    }
+
+    #[test]
+    fn snapshot_keys_is_stable_when_values_are_removed() {
+        const MEM_SIZE: usize = RelocatableSlotMap::<usize>::const_memory_size(SUT_CAPACITY);
+        let mut memory = [0u8; MEM_SIZE];
+        let bump_allocator = BumpAllocator::new(memory.as_mut_ptr());
+
+        let mut sut = unsafe { RelocatableSlotMap::<usize>::new_uninit(SUT_CAPACITY) };
+        unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+
+        let mut keys = vec![];
+        for n in 0..SUT_CAPACITY / 2 {
+            keys.push(unsafe { sut.insert(n).unwrap() });
+        }
+
+        let mut snapshot = unsafe { sut.snapshot_keys::<SUT_CAPACITY>() };
+        assert_that!(unsafe { sut.remove(snapshot.next().unwrap()) }, is_some);
+        for key in &keys[1..] {
+            assert_that!(unsafe { sut.remove(*key) }, is_some);
+        }
+        assert_that!(sut, is_empty);
+
+        let remaining_keys: Vec<SlotMapKey> = snapshot.collect();
+        assert_that!(remaining_keys, eq keys[1..]);
+        for key in remaining_keys {
+            assert_that!(unsafe { sut.get(key) }, is_none);
+        }
+    }
+
+    #[test]
+    fn fixed_size_slotmap_snapshot_keys_contains_all_keys() {
+        let mut sut = FixedSizeSut::new();
+        assert_that!(sut.snapshot_keys().next(), is_none);
+
+        let key_1 = sut.insert(1).unwrap();
+        let key_2 = sut.insert(2).unwrap();
+        let key_3 = sut.insert(3).unwrap();
+        sut.remove(key_2);
+
+        let snapshot: Vec<SlotMapKey> = sut.snapshot_keys().collect();
+        assert_that!(snapshot, eq [key_1, key_3]);
+    }
+
+    #[test]
+    fn fixed_size_slotmap_snapshot_keys_of_full_slotmap_contains_all_keys() {
+        let mut sut = FixedSizeSut::new();
+        let mut keys = vec![];
+        for n in 0..SUT_CAPACITY {
+            keys.push(sut.insert(n).unwrap());
+        }
+
+        let snapshot = sut.snapshot_keys();
+        for key in &keys {
+            assert_that!(sut.remove(*key), is_some);
+        }
+
+        let snapshot: Vec<SlotMapKey> = snapshot.collect();
+        assert_that!(snapshot, eq keys);
+    }
+
+    #[test]
+    fn snapshot_keys_captures_only_as_many_keys_as_the_buffer_can_hold() {
+        const MEM_SIZE: usize = RelocatableSlotMap::<usize>::const_memory_size(SUT_CAPACITY);
+        let mut memory = [0u8; MEM_SIZE];
+        let bump_allocator = BumpAllocator::new(memory.as_mut_ptr());
+
+        let mut sut = unsafe { RelocatableSlotMap::<usize>::new_uninit(SUT_CAPACITY) };
+        unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+        let key_1 = unsafe { sut.insert(1).unwrap() };
+        let key_2 = unsafe { sut.insert(2).unwrap() };
+        unsafe { sut.insert(3).unwrap() };
+
+        let snapshot: Vec<SlotMapKey> = unsafe { sut.snapshot_keys::<2>() }.collect();
+        assert_that!(snapshot, eq [key_1, key_2]);
+        assert_that!(unsafe { sut.snapshot_keys::<0>() }.next(), is_none);
+    }
+
+    #[test]
+    fn is_initialized_is_set_by_init() {
+        const MEM_SIZE: usize = RelocatableSlotMap::<usize>::const_memory_size(SUT_CAPACITY);
+        let mut memory = [0u8; MEM_SIZE];
+        let bump_allocator = BumpAllocator::new(memory.as_mut_ptr());
+
+        let mut sut = unsafe { RelocatableSlotMap::<usize>::new_uninit(SUT_CAPACITY) };
+        assert_that!(sut.is_initialized(), eq false);
+
+        unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+        assert_that!(sut.is_initialized(), eq true);
+    }
+
+    #[test]
+    fn init_checked_with_sufficient_memory_works() {
+        const MEM_SIZE: usize = RelocatableSlotMap::<usize>::const_memory_size(SUT_CAPACITY);
+        let mut memory = [0u8; MEM_SIZE];
+        let bump_allocator = BumpAllocator::new_with_size_limit(memory.as_mut_ptr(), MEM_SIZE);
+
+        let mut sut = unsafe { RelocatableSlotMap::<usize>::new_uninit(SUT_CAPACITY) };
+        assert_that!(
+            unsafe { sut.init_checked(&bump_allocator, SUT_CAPACITY) },
+            is_ok
+        );
+        assert_that!(sut.is_initialized(), eq true);
+        assert_that!(unsafe { sut.insert(123) }, is_some);
+    }
+
+    #[test]
+    fn init_checked_with_undersized_allocator_fails() {
+        const MEM_SIZE: usize = RelocatableSlotMap::<usize>::const_memory_size(SUT_CAPACITY);
+        let mut memory = [0u8; MEM_SIZE];
+        let bump_allocator = BumpAllocator::new_with_size_limit(memory.as_mut_ptr(), MEM_SIZE - 1);
+
+        let mut sut = unsafe { RelocatableSlotMap::<usize>::new_uninit(SUT_CAPACITY) };
+        assert_that!(unsafe { sut.init_checked(&bump_allocator, SUT_CAPACITY) }, eq Err(SlotMapInitError::InsufficientMemory {
+            required: MEM_SIZE,
+            available: MEM_SIZE - 1
+        }));
+        assert_that!(sut.is_initialized(), eq false);
+        assert_that!(bump_allocator.used_bytes(), eq 0);
+    }
+
+    #[test]
+    fn init_checked_with_capacity_mismatch_fails() {
+        const MEM_SIZE: usize = RelocatableSlotMap::<usize>::const_memory_size(SUT_CAPACITY);
+        let mut memory = [0u8; MEM_SIZE];
+        let bump_allocator = BumpAllocator::new_with_size_limit(memory.as_mut_ptr(), MEM_SIZE);
+
+        let mut sut = unsafe { RelocatableSlotMap::<usize>::new_uninit(SUT_CAPACITY - 1) };
+        assert_that!(unsafe { sut.init_checked(&bump_allocator, SUT_CAPACITY) }, eq Err(SlotMapInitError::CapacityMismatch {
+            capacity: SUT_CAPACITY - 1,
+            expected_capacity: SUT_CAPACITY
+        }));
+        assert_that!(sut.is_initialized(), eq false);
+    }
+
     #[test]
     #[should_panic]
     fn double_init_call_causes_panic() {
@@ -249,6 +915,159 @@ mod slot_map {
         unsafe { sut.init(&bump_allocator).expect("sut init failed") };
     }
 
+    #[test]
+    fn relocatable_slotmap_with_over_aligned_type_fits_into_memory_size() {
+        #[derive(Debug, PartialEq)]
+        #[repr(align(64))]
+        struct CacheLinePadded(usize);
+
+        const CAPACITY: usize = 12;
+        const MEM_SIZE: usize = RelocatableSlotMap::<CacheLinePadded>::const_memory_size(CAPACITY);
+        let mut memory = vec![0u8; MEM_SIZE + 64];
+
+        for offset in 0..64 {
+            let bump_allocator = BumpAllocator::new_with_size_limit(
+                unsafe { memory.as_mut_ptr().add(offset) },
+                MEM_SIZE,
+            );
+
+            let mut sut = unsafe { RelocatableSlotMap::<CacheLinePadded>::new_uninit(CAPACITY) };
+            assert_that!(unsafe { sut.init(&bump_allocator) }, is_ok);
+
+            for i in 0..CAPACITY {
+                let key = unsafe { sut.insert(CacheLinePadded(i)) }.unwrap();
+                let value = unsafe { sut.get(key) }.unwrap();
+                assert_that!(value as *const CacheLinePadded as usize % 64, eq 0);
+                assert_that!(*value, eq CacheLinePadded(i));
+            }
+        }
+    }
+
+    #[test]
+    fn relocatable_slotmap_with_32_bit_pointers_works() {
+        const MEM_SIZE: usize = RelocatableSlotMap32::<usize>::const_memory_size(SUT_CAPACITY);
+        let mut memory = [0u8; MEM_SIZE];
+        let bump_allocator = BumpAllocator::new(memory.as_mut_ptr());
+
+        let mut sut = unsafe { RelocatableSlotMap32::<usize>::new_uninit(SUT_CAPACITY) };
+        unsafe { sut.init(&bump_allocator).expect("sut init failed") };
+
+        let mut keys = vec![];
+        for i in 0..SUT_CAPACITY {
+            let key = unsafe { sut.insert(i * 3) };
+            assert_that!(key, is_some);
+            keys.push(key.unwrap());
+        }
+        assert_that!(unsafe { sut.insert(0) }, is_none);
+
+        for (i, key) in keys.iter().enumerate() {
+            assert_that!(unsafe { sut.get(*key) }, eq Some(&(i * 3)));
+        }
+
+        assert_that!(unsafe { sut.remove(keys[7]) }, eq Some(21));
+        assert_that!(unsafe { sut.get(keys[7]) }, is_none);
+        assert_that!(sut.len(), eq SUT_CAPACITY - 1);
+    }
+
+    #[test]
+    fn slotmap_with_u16_index_works() {
+        const CAPACITY: usize = 12;
+        let mut sut = SlotMap::<usize, u16>::new(CAPACITY);
+
+        let mut keys = vec![];
+        for i in 0..CAPACITY {
+            keys.push(sut.insert(i * 5).unwrap());
+        }
+        assert_that!(sut.is_full(), eq true);
+        assert_that!(sut.insert(0), is_none);
+
+        assert_that!(sut.remove(keys[3]), eq Some(15));
+        assert_that!(sut.remove(keys[8]), eq Some(40));
+        assert_that!(sut.next_free_key(), eq Some(keys[8]));
+        assert_that!(sut.insert(123), eq Some(keys[8]));
+        assert_that!(sut.insert_at(keys[3], 456), eq true);
+
+        assert_that!(sut.compact().is_empty(), eq true);
+
+        sut.reserve(4);
+        assert_that!(sut.capacity(), eq CAPACITY + 4);
+        let key = sut.insert(789).unwrap();
+        assert_that!(key.value(), eq CAPACITY);
+
+        let values: Vec<usize> = sut.into_values().collect();
+        assert_that!(values, len CAPACITY + 1);
+        assert_that!(values[3], eq 456);
+        assert_that!(values[8], eq 123);
+        assert_that!(values[CAPACITY], eq 789);
+    }
+
+    #[test]
+    fn fixed_size_slotmap_with_u16_index_requires_less_memory() {
+        const CAPACITY: usize = 32;
+        type SmallSut = FixedSizeSlotMap<u8, CAPACITY, u16>;
+
+        assert_that!(SmallSut::memory_size(), lt FixedSizeSlotMap::<u8, CAPACITY>::memory_size());
+        assert_that!(
+            RelocatableSlotMap::<u8, u16>::const_memory_size(CAPACITY),
+            lt RelocatableSlotMap::<u8>::const_memory_size(CAPACITY)
+        );
+
+        let mut sut = SmallSut::new();
+        for i in 0..CAPACITY {
+            assert_that!(sut.insert(i as u8), eq Some(SlotMapKey::new(i)));
+        }
+        assert_that!(sut.insert(0), is_none);
+
+        for i in (0..CAPACITY).step_by(2) {
+            assert_that!(sut.remove(SlotMapKey::new(i)), eq Some(i as u8));
+        }
+
+        let keys: Vec<usize> = sut.iter().map(|(key, _)| key.value()).collect();
+        assert_that!(keys, eq(1..CAPACITY).step_by(2).collect::<Vec<usize>>());
+
+        assert_that!(sut.compact().len(), eq CAPACITY / 2);
+        for (n, (_, value)) in sut.iter().enumerate() {
+            assert_that!(*value, eq(2 * n + 1) as u8);
+        }
+    }
+
+    #[test]
+    fn relocatable_slotmap_with_u16_index_works() {
+        const CAPACITY: usize = 10;
+        const MEM_SIZE: usize = RelocatableSlotMap::<u32, u16>::const_memory_size(CAPACITY);
+        let mut memory = [0u8; MEM_SIZE];
+        let bump_allocator = BumpAllocator::new(memory.as_mut_ptr());
+
+        let mut sut = unsafe { RelocatableSlotMap::<u32, u16>::new_uninit(CAPACITY) };
+        assert_that!(
+            unsafe { sut.init_checked(&bump_allocator, CAPACITY) },
+            is_ok
+        );
+
+        for i in 0..CAPACITY {
+            assert_that!(unsafe { sut.insert(i as u32) }, is_some);
+        }
+        assert_that!(unsafe { sut.insert(0) }, is_none);
+        assert_that!(unsafe { sut.try_remove(SlotMapKey::new(CAPACITY)) }, eq Err(SlotMapKeyOutOfBounds { key: SlotMapKey::new(CAPACITY) }));
+        assert_that!(unsafe { sut.remove(SlotMapKey::new(4)) }, eq Some(4));
+        assert_that!(unsafe { sut.next_free_key() }, eq Some(SlotMapKey::new(4)));
+    }
+
+    #[test]
+    fn slotmap_with_u8_index_supports_capacity_below_index_max() {
+        let mut sut = SlotMap::<u8, u8>::new(u8::MAX as usize);
+        for i in 0..u8::MAX {
+            assert_that!(sut.insert(i), eq Some(SlotMapKey::new(i as usize)));
+        }
+        assert_that!(sut.is_full(), eq true);
+    }
+
+    #[test]
+    #[should_panic]
+    fn slotmap_with_capacity_exceeding_index_max_panics() {
+        let _sut = SlotMap::<u8, u8>::new(u8::MAX as usize + 1);
+    }
+
     #[cfg(debug_assertions)]
     #[test]
     #[should_panic]
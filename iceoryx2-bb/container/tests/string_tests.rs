@@ -18,6 +18,7 @@ use std::hash::{Hash, Hasher};
 use iceoryx2_bb_container::string::{RelocatableString, *};
 use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
 use iceoryx2_bb_elementary_traits::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_testing::assert_that;
 use std::collections::hash_map::DefaultHasher;
 
@@ -1018,6 +1019,72 @@ mod string {
         assert_that!(sut.as_bytes(), eq b"");
     }
 
+    #[test]
+    fn trim_ascii_whitespace_removes_leading_and_trailing_whitespace<Factory: StringTestFactory>() {
+        let factory = Factory::new();
+        let mut sut = factory.create_sut();
+        assert_that!(
+            sut.push_bytes(b" \t\nall glory \t to\r\n the hypnotoad \n\t "),
+            is_ok
+        );
+        sut.trim_ascii_whitespace();
+
+        assert_that!(sut.as_bytes(), eq b"all glory \t to\r\n the hypnotoad");
+    }
+
+    #[test]
+    fn trim_ascii_whitespace_of_whitespace_only_string_results_in_empty_string<
+        Factory: StringTestFactory,
+    >() {
+        let factory = Factory::new();
+        let mut sut = factory.create_sut();
+        assert_that!(sut.push_bytes(b" \t\n\r  "), is_ok);
+        sut.trim_ascii_whitespace();
+
+        assert_that!(sut, is_empty);
+        assert_that!(sut.as_bytes(), eq b"");
+    }
+
+    #[test]
+    fn trim_ascii_whitespace_of_empty_string_does_nothing<Factory: StringTestFactory>() {
+        let factory = Factory::new();
+        let mut sut = factory.create_sut();
+        sut.trim_ascii_whitespace();
+
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    fn trim_matches_removes_leading_and_trailing_bytes<Factory: StringTestFactory>() {
+        let factory = Factory::new();
+        let mut sut = factory.create_sut();
+        assert_that!(sut.push_bytes(b"///hypno//toad//"), is_ok);
+        sut.trim_matches(b'/');
+
+        assert_that!(sut.as_bytes(), eq b"hypno//toad");
+    }
+
+    #[test]
+    fn trim_matches_without_matches_does_nothing<Factory: StringTestFactory>() {
+        let factory = Factory::new();
+        let mut sut = factory.create_sut();
+        assert_that!(sut.push_bytes(b"hypno/toad"), is_ok);
+        sut.trim_matches(b'/');
+
+        assert_that!(sut.as_bytes(), eq b"hypno/toad");
+    }
+
+    #[test]
+    fn trim_matches_where_all_bytes_match_results_in_empty_string<Factory: StringTestFactory>() {
+        let factory = Factory::new();
+        let mut sut = factory.create_sut();
+        assert_that!(sut.push_bytes(b"/////"), is_ok);
+        sut.trim_matches(b'/');
+
+        assert_that!(sut, is_empty);
+        assert_that!(sut.as_bytes(), eq b"");
+    }
+
     #[test]
     fn ordering_works<Factory: StringTestFactory>() {
         let factory = Factory::new();
@@ -1115,3 +1182,11 @@ fn error_display_works() {
     assert_that!(format!("{}", StringModificationError::InsertWouldExceedCapacity), eq "StringModificationError::InsertWouldExceedCapacity");
     assert_that!(format!("{}", StringModificationError::InvalidCharacter), eq "StringModificationError::InvalidCharacter");
 }
+
+#[test]
+fn shared_memory_compatible_strings_are_zero_copy_send() {
+    fn requires_zero_copy_send<T: ZeroCopySend>() {}
+
+    requires_zero_copy_send::<RelocatableString>();
+    requires_zero_copy_send::<StaticString<64>>();
+}
@@ -16,6 +16,7 @@ mod vector {
 
     use iceoryx2_bb_container::vector::*;
     use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+    use iceoryx2_bb_elementary::CallbackProgression;
     use iceoryx2_bb_testing::{assert_that, lifetime_tracker::LifetimeTracker};
 
     const SUT_CAPACITY: usize = 10;
@@ -235,6 +236,52 @@ mod vector {
         }
     }
 
+    #[test]
+    fn for_each_visits_all_elements_in_order<Factory: VectorTestFactory>() {
+        let factory = Factory::new();
+        let mut sut = factory.create_sut();
+
+        for number in 0..SUT_CAPACITY {
+            assert_that!(
+                sut.push(LifetimeTracker::new_with_value(3 * number + 1)),
+                is_ok
+            );
+        }
+
+        let mut number_of_calls = 0;
+        sut.for_each(|index, element| {
+            assert_that!(index, eq number_of_calls);
+            assert_that!(element.value, eq 3 * index + 1);
+            number_of_calls += 1;
+            CallbackProgression::Continue
+        });
+
+        assert_that!(number_of_calls, eq SUT_CAPACITY);
+    }
+
+    #[test]
+    fn for_each_stops_when_callback_returns_stop<Factory: VectorTestFactory>() {
+        const STOP_AFTER: usize = 4;
+        let factory = Factory::new();
+        let mut sut = factory.create_sut();
+
+        for number in 0..SUT_CAPACITY {
+            assert_that!(sut.push(LifetimeTracker::new_with_value(number)), is_ok);
+        }
+
+        let mut number_of_calls = 0;
+        sut.for_each(|index, _| {
+            number_of_calls += 1;
+            if index + 1 == STOP_AFTER {
+                CallbackProgression::Stop
+            } else {
+                CallbackProgression::Continue
+            }
+        });
+
+        assert_that!(number_of_calls, eq STOP_AFTER);
+    }
+
     #[test]
     fn truncate_drops_all_elements_right_of_new_len<Factory: VectorTestFactory>() {
         let half_capacity = SUT_CAPACITY / 2;
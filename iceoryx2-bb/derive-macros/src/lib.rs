@@ -19,12 +19,20 @@
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
 use syn::{parse_macro_input, Data, DeriveInput, Fields, LitStr};
 
 /// Implements the [`iceoryx2_bb_elementary_traits::placement_default::PlacementDefault`] trait when all
 /// fields of the struct implement it.
 ///
+/// Fields that do not implement it can be annotated with
+/// `#[placement_default(with = path::to::fn)]`, the field is then initialized with the return
+/// value of the provided function.
+///
+/// For enums the variant annotated with `#[default]` is placed. Its fields are constructed on
+/// the stack before the variant is written to the target memory.
+///
 /// ```
 /// use iceoryx2_bb_derive_macros::PlacementDefault;
 /// use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
@@ -37,6 +45,19 @@ use syn::{parse_macro_input, Data, DeriveInput, Fields, LitStr};
 ///     value_1: u64,
 ///     value_2: Option<usize>,
 ///     value_3: [u8; 10485760],
+///     #[placement_default(with = initial_state)]
+///     state: State,
+/// }
+///
+/// #[derive(PlacementDefault)]
+/// enum State {
+///     #[default]
+///     Idle,
+///     Active(u64),
+/// }
+///
+/// fn initial_state() -> State {
+///     State::Active(42)
 /// }
 ///
 /// let layout = Layout::new::<MyLargeType>();
@@ -48,63 +69,155 @@ use syn::{parse_macro_input, Data, DeriveInput, Fields, LitStr};
 /// unsafe { core::ptr::drop_in_place(raw_memory) };
 /// unsafe { dealloc(raw_memory.cast(), layout) };
 /// ```
-#[proc_macro_derive(PlacementDefault)]
+#[proc_macro_derive(PlacementDefault, attributes(placement_default, default))]
 pub fn placement_default_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
-    let place_default_impl = match input.data {
-        Data::Struct(ref data_struct) => match data_struct.fields {
-            Fields::Named(ref fields_named) => {
-                let field_inits = fields_named.named.iter().map(|f| {
-                    let name = &f.ident;
-                    quote! {
-                        let field_address = core::ptr::addr_of_mut!((*ptr).#name);
-                        PlacementDefault::placement_default(field_address);
-                    }
-                });
-
-                quote! {
-                    unsafe fn placement_default(ptr: *mut Self) {
-                        #(#field_inits)*
-                    }
-                }
-            }
-            Fields::Unnamed(ref fields_unnamed) => {
-                let field_inits = fields_unnamed.unnamed.iter().enumerate().map(|(i, _)| {
-                    let index = syn::Index::from(i);
-                    quote! {
-                        let field_address = core::ptr::addr_of_mut!((*ptr).#index);
-                        PlacementDefault::placement_default(field_address);
-                    }
-                });
+    let place_default_body = match input.data {
+        Data::Struct(ref data_struct) => placement_default_struct_body(&data_struct.fields),
+        Data::Enum(ref data_enum) => placement_default_enum_body(name, data_enum),
+        Data::Union(ref data_union) => Err(syn::Error::new_spanned(
+            data_union.union_token,
+            "PlacementDefault cannot be derived for unions since the active field is unknown, implement it manually",
+        )),
+    };
 
-                quote! {
-                    unsafe fn placement_default(ptr: *mut Self) {
-                        #(#field_inits)*
-                    }
-                }
-            }
-            Fields::Unit => {
-                quote! {
-                    unsafe fn placement_default(ptr: *mut Self) {
-                    }
-                }
-            }
-        },
-        _ => unimplemented!(),
+    let place_default_body = match place_default_body {
+        Ok(body) => body,
+        Err(e) => return e.to_compile_error().into(),
     };
 
     let expanded = quote! {
         impl #impl_generics PlacementDefault for #name #ty_generics #where_clause {
-            #place_default_impl
+            unsafe fn placement_default(ptr: *mut Self) {
+                #place_default_body
+            }
         }
     };
 
     TokenStream::from(expanded)
 }
 
+enum FieldInit {
+    PlacementDefault,
+    With(syn::Path),
+}
+
+impl FieldInit {
+    fn from_attributes(field: &syn::Field) -> syn::Result<Self> {
+        let mut init = FieldInit::PlacementDefault;
+        for attribute in field
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("placement_default"))
+        {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("with") {
+                    init = FieldInit::With(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error(
+                        "unsupported placement_default attribute, expected `with = path::to::fn`",
+                    ))
+                }
+            })?;
+        }
+
+        Ok(init)
+    }
+}
+
+fn field_accessors(fields: &Fields) -> Vec<proc_macro2::TokenStream> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| match &f.ident {
+            Some(name) => quote! { #name },
+            None => {
+                let index = syn::Index::from(i);
+                quote! { #index }
+            }
+        })
+        .collect()
+}
+
+fn placement_default_struct_body(fields: &Fields) -> syn::Result<proc_macro2::TokenStream> {
+    let mut field_inits = Vec::new();
+    for (field, accessor) in fields.iter().zip(field_accessors(fields)) {
+        let ty = &field.ty;
+        field_inits.push(match FieldInit::from_attributes(field)? {
+            FieldInit::PlacementDefault => quote_spanned! { ty.span() =>
+                let field_address = core::ptr::addr_of_mut!((*ptr).#accessor);
+                PlacementDefault::placement_default(field_address);
+            },
+            FieldInit::With(init) => quote! {
+                let field_address = core::ptr::addr_of_mut!((*ptr).#accessor);
+                field_address.write(#init());
+            },
+        });
+    }
+
+    Ok(quote! { #(#field_inits)* })
+}
+
+fn placement_default_enum_body(
+    name: &syn::Ident,
+    data_enum: &syn::DataEnum,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let mut default_variants = data_enum
+        .variants
+        .iter()
+        .filter(|v| v.attrs.iter().any(|a| a.path().is_ident("default")));
+
+    let variant =
+        match (default_variants.next(), default_variants.next()) {
+            (Some(variant), None) => variant,
+            (None, _) => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "PlacementDefault for enums requires one variant annotated with #[default]",
+                ))
+            }
+            (Some(_), Some(second)) => return Err(syn::Error::new_spanned(
+                second,
+                "PlacementDefault for enums requires exactly one variant annotated with #[default]",
+            )),
+        };
+
+    let mut values = Vec::new();
+    let mut value_inits = Vec::new();
+    for (n, field) in variant.fields.iter().enumerate() {
+        let value = quote::format_ident!("value_{}", n);
+        let ty = &field.ty;
+        value_inits.push(match FieldInit::from_attributes(field)? {
+            FieldInit::PlacementDefault => quote_spanned! { ty.span() =>
+                let mut #value = core::mem::MaybeUninit::<#ty>::uninit();
+                PlacementDefault::placement_default(#value.as_mut_ptr());
+                let #value = #value.assume_init();
+            },
+            FieldInit::With(init) => quote! {
+                let #value: #ty = #init();
+            },
+        });
+        values.push(value);
+    }
+
+    let variant_name = &variant.ident;
+    let accessors = field_accessors(&variant.fields);
+    let construct = match variant.fields {
+        Fields::Named(_) => quote! { Self::#variant_name { #(#accessors: #values),* } },
+        Fields::Unnamed(_) => quote! { Self::#variant_name ( #(#values),* ) },
+        Fields::Unit => quote! { Self::#variant_name },
+    };
+
+    Ok(quote! {
+        #(#value_inits)*
+        ptr.write(#construct);
+    })
+}
+
 /// Implements the [`iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend`] trait when all fields of
 /// the struct implement it and the struct is annotated with `repr(C)`. A type name can be optionally
 /// set with the helper attribute `type_name`.
@@ -330,5 +443,8 @@ pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+#[cfg(doctest)]
+mod placement_default_compile_tests;
+
 #[cfg(doctest)]
 mod zero_copy_send_compile_tests;
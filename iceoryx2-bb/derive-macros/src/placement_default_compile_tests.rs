@@ -0,0 +1,109 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+/// === Structs ===
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::PlacementDefault;
+/// use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+///
+/// struct Foo(u16);
+///
+/// #[derive(PlacementDefault)]
+/// struct NamedTestStruct {
+///     val1: u64,
+///     val2: Foo,
+/// }
+/// ```
+#[cfg(doctest)]
+fn placement_default_derive_does_not_work_when_field_does_not_implement_it() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::PlacementDefault;
+/// use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+///
+/// #[derive(PlacementDefault)]
+/// struct GenericTestStruct<T>(u64, T);
+/// ```
+#[cfg(doctest)]
+fn placement_default_derive_does_not_work_for_generic_struct_without_bound() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::PlacementDefault;
+/// use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+///
+/// #[derive(PlacementDefault)]
+/// struct NamedTestStruct {
+///     #[placement_default(unknown)]
+///     val1: u64,
+/// }
+/// ```
+#[cfg(doctest)]
+fn placement_default_derive_does_not_work_with_unknown_field_attribute() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::PlacementDefault;
+/// use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+///
+/// #[derive(PlacementDefault)]
+/// struct NamedTestStruct {
+///     #[placement_default(skip)]
+///     val1: core::mem::MaybeUninit<u64>,
+/// }
+/// ```
+#[cfg(doctest)]
+fn placement_default_derive_does_not_support_skipping_fields() {}
+
+/// === Enums ===
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::PlacementDefault;
+/// use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+///
+/// #[derive(PlacementDefault)]
+/// enum TestEnum {
+///     Idle,
+///     Active(u64),
+/// }
+/// ```
+#[cfg(doctest)]
+fn placement_default_derive_does_not_work_for_enum_without_default_variant() {}
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::PlacementDefault;
+/// use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+///
+/// #[derive(PlacementDefault)]
+/// enum TestEnum {
+///     #[default]
+///     Idle,
+///     #[default]
+///     Active(u64),
+/// }
+/// ```
+#[cfg(doctest)]
+fn placement_default_derive_does_not_work_for_enum_with_multiple_default_variants() {}
+
+/// === Unions ===
+
+/// ``` compile_fail
+/// use iceoryx2_bb_derive_macros::PlacementDefault;
+/// use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+///
+/// #[derive(PlacementDefault)]
+/// union TestUnion {
+///     val1: u64,
+///     val2: f32,
+/// }
+/// ```
+#[cfg(doctest)]
+fn placement_default_derive_does_not_work_for_unions() {}
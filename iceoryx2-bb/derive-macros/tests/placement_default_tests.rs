@@ -72,6 +72,63 @@ mod placement_new {
     #[derive(PlacementDefault)]
     struct GenericUnnamedStruct<T1: PlacementDefault, T2: PlacementDefault>(T1, T2);
 
+    #[derive(PlacementDefault)]
+    struct GenericWhereClauseStruct<T1, T2>
+    where
+        T1: PlacementDefault,
+        T2: PlacementDefault,
+    {
+        value1: T1,
+        value2: T2,
+    }
+
+    fn fuu_with_custom_value() -> Fuu {
+        Fuu(FUU_VALUE.load(Ordering::Relaxed) + 1)
+    }
+
+    #[derive(PlacementDefault)]
+    struct StructWithFieldAttributes {
+        value1: Bar,
+        #[placement_default(with = fuu_with_custom_value)]
+        value2: Fuu,
+    }
+
+    #[allow(dead_code)]
+    #[derive(PlacementDefault)]
+    enum UnitDefaultEnum {
+        Active(Fuu),
+        #[default]
+        Idle,
+    }
+
+    #[allow(dead_code)]
+    #[derive(PlacementDefault)]
+    enum UnnamedDefaultEnum {
+        Idle,
+        #[default]
+        Active(Fuu, Bar),
+    }
+
+    #[allow(dead_code)]
+    #[derive(PlacementDefault)]
+    enum NamedDefaultEnum<T: PlacementDefault> {
+        #[default]
+        Active {
+            value: T,
+            #[placement_default(with = fuu_with_custom_value)]
+            fuu: Fuu,
+        },
+        Idle,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Debug, PartialEq, Default, PlacementDefault)]
+    enum EnumWithDefaultDerive {
+        Idle,
+        #[default]
+        Active,
+    }
+
     #[test]
     fn placement_default_derive_for_structs_works() {
         DEFAULT_CTOR_COUNT.store(0, Ordering::Relaxed);
@@ -130,4 +187,94 @@ mod placement_new {
         assert_that!(unsafe {memory.assume_init()}.0.0, eq FUU_VALUE.load(Ordering::Relaxed));
         assert_that!(unsafe {memory.assume_init()}.1.value, eq BAR_VALUE.load(Ordering::Relaxed));
     }
+
+    #[test]
+    fn placement_default_derive_for_generic_structs_with_where_clause_works() {
+        type SutType = GenericWhereClauseStruct<Fuu, Bar>;
+        DEFAULT_CTOR_COUNT.store(0, Ordering::Relaxed);
+        FUU_VALUE.store(7781, Ordering::Relaxed);
+        BAR_VALUE.store(1782, Ordering::Relaxed);
+
+        let memory = RawMemory::<SutType>::new_zeroed();
+        unsafe { SutType::placement_default(memory.as_mut_ptr()) };
+
+        assert_that!(DEFAULT_CTOR_COUNT.load(Ordering::Relaxed), eq 2);
+        assert_that!(unsafe {memory.assume_init()}.value1.0, eq FUU_VALUE.load(Ordering::Relaxed));
+        assert_that!(unsafe {memory.assume_init()}.value2.value, eq BAR_VALUE.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn placement_default_derive_with_field_attributes_works() {
+        DEFAULT_CTOR_COUNT.store(0, Ordering::Relaxed);
+        FUU_VALUE.store(912, Ordering::Relaxed);
+        BAR_VALUE.store(8123, Ordering::Relaxed);
+
+        let memory = RawMemory::<StructWithFieldAttributes>::new_filled(0xab);
+        unsafe { StructWithFieldAttributes::placement_default(memory.as_mut_ptr()) };
+
+        assert_that!(DEFAULT_CTOR_COUNT.load(Ordering::Relaxed), eq 1);
+        assert_that!(unsafe {memory.assume_init()}.value1.value, eq BAR_VALUE.load(Ordering::Relaxed));
+        assert_that!(unsafe {memory.assume_init()}.value2.0, eq FUU_VALUE.load(Ordering::Relaxed) + 1);
+    }
+
+    #[test]
+    fn placement_default_derive_for_enum_with_unit_default_variant_works() {
+        DEFAULT_CTOR_COUNT.store(0, Ordering::Relaxed);
+
+        let memory = RawMemory::<UnitDefaultEnum>::new_zeroed();
+        unsafe { UnitDefaultEnum::placement_default(memory.as_mut_ptr()) };
+
+        assert_that!(DEFAULT_CTOR_COUNT.load(Ordering::Relaxed), eq 0);
+        assert_that!(matches!(unsafe {memory.assume_init()}, UnitDefaultEnum::Idle), eq true);
+    }
+
+    #[test]
+    fn placement_default_derive_for_enum_with_unnamed_default_variant_works() {
+        DEFAULT_CTOR_COUNT.store(0, Ordering::Relaxed);
+        FUU_VALUE.store(5512, Ordering::Relaxed);
+        BAR_VALUE.store(9913, Ordering::Relaxed);
+
+        let memory = RawMemory::<UnnamedDefaultEnum>::new_zeroed();
+        unsafe { UnnamedDefaultEnum::placement_default(memory.as_mut_ptr()) };
+
+        assert_that!(DEFAULT_CTOR_COUNT.load(Ordering::Relaxed), eq 2);
+        match unsafe { memory.assume_init() } {
+            UnnamedDefaultEnum::Active(fuu, bar) => {
+                assert_that!(fuu.0, eq FUU_VALUE.load(Ordering::Relaxed));
+                assert_that!(bar.value, eq BAR_VALUE.load(Ordering::Relaxed));
+            }
+            UnnamedDefaultEnum::Idle => panic!("the default variant must be placed"),
+        }
+    }
+
+    #[test]
+    fn placement_default_derive_for_generic_enum_with_named_default_variant_works() {
+        type SutType = NamedDefaultEnum<Bar>;
+        DEFAULT_CTOR_COUNT.store(0, Ordering::Relaxed);
+        FUU_VALUE.store(123, Ordering::Relaxed);
+        BAR_VALUE.store(321, Ordering::Relaxed);
+
+        let memory = RawMemory::<SutType>::new_zeroed();
+        unsafe { SutType::placement_default(memory.as_mut_ptr()) };
+
+        assert_that!(DEFAULT_CTOR_COUNT.load(Ordering::Relaxed), eq 1);
+        match unsafe { memory.assume_init() } {
+            SutType::Active { value, fuu } => {
+                assert_that!(value.value, eq BAR_VALUE.load(Ordering::Relaxed));
+                assert_that!(fuu.0, eq FUU_VALUE.load(Ordering::Relaxed) + 1);
+            }
+            SutType::Idle => panic!("the default variant must be placed"),
+        }
+    }
+
+    #[test]
+    fn placement_default_derive_places_same_variant_as_default_derive() {
+        let memory = RawMemory::<EnumWithDefaultDerive>::new_zeroed();
+        unsafe { EnumWithDefaultDerive::placement_default(memory.as_mut_ptr()) };
+
+        assert_that!(
+            unsafe { memory.assume_init() },
+            eq & EnumWithDefaultDerive::default()
+        );
+    }
 }
@@ -23,6 +23,9 @@ pub enum AllocationError {
     AlignmentFailure,
     OutOfMemory,
     InternalError,
+    /// The allocated memory is too far away to be addressed by the pointer that shall
+    /// refer to it.
+    OutOfPointerRange,
 }
 
 impl core::fmt::Display for AllocationError {
@@ -33,6 +36,12 @@ impl core::fmt::Display for AllocationError {
 
 impl core::error::Error for AllocationError {}
 
+impl From<core::alloc::LayoutError> for AllocationError {
+    fn from(_: core::alloc::LayoutError) -> Self {
+        AllocationError::SizeTooLarge
+    }
+}
+
 /// Failures caused by [`Allocator::grow()`] or [`Allocator::grow_zeroed()`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum AllocationGrowError {
@@ -68,6 +77,15 @@ impl core::fmt::Display for AllocationShrinkError {
 
 impl core::error::Error for AllocationShrinkError {}
 
+/// The memory usage of an allocator, see [`BaseAllocator::statistics()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct AllocatorStatistics {
+    /// The number of bytes that are already allocated, including padding.
+    pub used_bytes: usize,
+    /// The number of bytes that can still be allocated.
+    pub remaining_bytes: usize,
+}
+
 /// The most minimalistic requirement for an allocator
 pub trait BaseAllocator {
     /// Allocates a memory chunk with the properties provided in layout and either
@@ -98,6 +116,12 @@ pub trait BaseAllocator {
     ///    resized, the same value as it was resized to
     ///
     unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout);
+
+    /// Returns the current [`AllocatorStatistics`] or [`None`] when the allocator does not
+    /// track its memory usage.
+    fn statistics(&self) -> Option<AllocatorStatistics> {
+        None
+    }
 }
 
 /// Allocator with grow and shrink features.
@@ -51,6 +51,25 @@ impl<T> OwningPointer<T> {
             layout,
         }
     }
+
+    /// Allocates new memory for T and new_number_of_elements, copies the first
+    /// number_of_initialized_elements bytewise into it and releases the old memory.
+    ///
+    /// # Safety
+    ///
+    ///  * number_of_initialized_elements must not exceed the number of elements of the
+    ///    current and the new allocation
+    ///  * all pointers acquired via [`PointerTrait`] before are dangling afterwards
+    ///
+    pub unsafe fn realloc(
+        &mut self,
+        new_number_of_elements: usize,
+        number_of_initialized_elements: usize,
+    ) {
+        let new_self = Self::new_with_alloc(new_number_of_elements);
+        core::ptr::copy_nonoverlapping(self.ptr, new_self.ptr, number_of_initialized_elements);
+        *self = new_self;
+    }
 }
 
 impl<T> Drop for OwningPointer<T> {
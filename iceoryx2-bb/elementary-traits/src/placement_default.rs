@@ -52,6 +52,10 @@ use iceoryx2_pal_concurrency_sync::iox_atomic::*;
 /// unsafe { core::ptr::drop_in_place(raw_memory) };
 /// unsafe { dealloc(raw_memory.cast(), layout) };
 /// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not implement `PlacementDefault`",
+    note = "derive `PlacementDefault` for `{Self}` or, when it is a field of a derived type, annotate the field with `#[placement_default(with = ...)]`"
+)]
 pub trait PlacementDefault {
     /// Performs a initialization of Self at the provided memory position
     /// with [`Default::default()`].
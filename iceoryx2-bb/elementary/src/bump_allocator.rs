@@ -11,24 +11,93 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use crate::math::align;
+use core::ops::Deref;
 use core::sync::atomic::Ordering;
-use iceoryx2_bb_elementary_traits::allocator::{AllocationError, BaseAllocator};
+use iceoryx2_bb_elementary_traits::allocator::{
+    AllocationError, AllocatorStatistics, BaseAllocator,
+};
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
 
 /// A minimalistic [`BumpAllocator`].
 pub struct BumpAllocator {
     start: *mut u8,
+    size: usize,
     pos: IoxAtomicUsize,
 }
 
 impl BumpAllocator {
-    /// Creates a new [`BumpAllocator`] that manages the memory starting at `start`.
+    /// Creates a new [`BumpAllocator`] that manages the memory starting at `start`. The
+    /// caller must ensure that the memory is large enough for all allocations.
     pub fn new(start: *mut u8) -> Self {
+        Self::new_with_size_limit(start, usize::MAX - start as usize)
+    }
+
+    /// Creates a new [`BumpAllocator`] that manages `size` bytes of memory starting at
+    /// `start`. Allocations that would exceed `size` fail with [`AllocationError::OutOfMemory`].
+    pub fn new_with_size_limit(start: *mut u8, size: usize) -> Self {
         Self {
             start,
+            size,
             pos: IoxAtomicUsize::new(start as usize),
         }
     }
+
+    /// Returns the number of bytes that are already allocated, including the padding
+    /// required for alignment.
+    pub fn used_bytes(&self) -> usize {
+        self.pos.load(Ordering::Relaxed) - self.start as usize
+    }
+
+    /// Returns the number of bytes that can still be allocated. Padding required for the
+    /// alignment of further allocations is not considered.
+    pub fn remaining_bytes(&self) -> usize {
+        self.size - self.used_bytes()
+    }
+
+    /// Reserves `size` bytes and returns a [`BumpAllocatorScope`] that manages exactly this
+    /// memory. While the scope exists, the whole reserved memory counts as used in the parent
+    /// allocator, therefore a failing initialization inside the scope never affects the memory
+    /// that remains available in the parent, and the scope can be [`BumpAllocator::reset()`]
+    /// to retry with a smaller configuration. When the scope is dropped, the memory it did not
+    /// allocate is returned to the parent, unless the parent allocated further memory in the
+    /// meantime.
+    /// If less than `size` bytes are remaining it fails with [`AllocationError::OutOfMemory`].
+    ///
+    /// ```
+    /// use core::alloc::Layout;
+    /// use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+    /// use iceoryx2_bb_elementary_traits::allocator::BaseAllocator;
+    ///
+    /// let mut memory = [0u8; 256];
+    /// let allocator = BumpAllocator::new_with_size_limit(memory.as_mut_ptr(), memory.len());
+    ///
+    /// let scope = allocator.scope(64).expect("sufficient memory available");
+    /// assert_eq!(allocator.remaining_bytes(), 192);
+    ///
+    /// assert!(scope.allocate(Layout::from_size_align(128, 1).unwrap()).is_err());
+    /// assert!(scope.allocate(Layout::from_size_align(32, 1).unwrap()).is_ok());
+    /// assert_eq!(scope.remaining_bytes(), 32);
+    /// assert_eq!(allocator.remaining_bytes(), 192);
+    ///
+    /// drop(scope);
+    /// assert_eq!(allocator.remaining_bytes(), 224);
+    /// ```
+    pub fn scope(&self, size: usize) -> Result<BumpAllocatorScope<'_>, AllocationError> {
+        let memory = self.allocate(core::alloc::Layout::from_size_align(size, 1)?)?;
+        Ok(BumpAllocatorScope {
+            parent: self,
+            allocator: Self::new_with_size_limit(memory.as_ptr().cast(), size),
+        })
+    }
+
+    /// Releases all allocations so that the memory can be used for a new initialization.
+    ///
+    /// # Safety
+    ///
+    ///  * the previously allocated memory must not be used anymore
+    pub unsafe fn reset(&self) {
+        self.pos.store(self.start as usize, Ordering::Relaxed);
+    }
 }
 
 impl BaseAllocator for BumpAllocator {
@@ -37,7 +106,11 @@ impl BaseAllocator for BumpAllocator {
         layout: core::alloc::Layout,
     ) -> Result<core::ptr::NonNull<[u8]>, AllocationError> {
         let mem = align(self.pos.load(Ordering::Relaxed), layout.align());
-        self.pos.store(mem + layout.size(), Ordering::Relaxed);
+        let end = match mem.checked_add(layout.size()) {
+            Some(end) if end - (self.start as usize) <= self.size => end,
+            _ => return Err(AllocationError::OutOfMemory),
+        };
+        self.pos.store(end, Ordering::Relaxed);
 
         unsafe {
             Ok(core::ptr::NonNull::new_unchecked(
@@ -50,6 +123,61 @@ impl BaseAllocator for BumpAllocator {
     }
 
     unsafe fn deallocate(&self, _ptr: core::ptr::NonNull<u8>, _layout: core::alloc::Layout) {
-        self.pos.store(self.start as usize, Ordering::Relaxed);
+        self.reset();
+    }
+
+    fn statistics(&self) -> Option<AllocatorStatistics> {
+        Some(AllocatorStatistics {
+            used_bytes: self.used_bytes(),
+            remaining_bytes: self.remaining_bytes(),
+        })
+    }
+}
+
+/// A [`BumpAllocator`] that manages a part of the memory of its parent, created by
+/// [`BumpAllocator::scope()`]. On drop, the memory that was not allocated within the scope is
+/// returned to the parent.
+pub struct BumpAllocatorScope<'a> {
+    parent: &'a BumpAllocator,
+    allocator: BumpAllocator,
+}
+
+impl Deref for BumpAllocatorScope<'_> {
+    type Target = BumpAllocator;
+
+    fn deref(&self) -> &Self::Target {
+        &self.allocator
+    }
+}
+
+impl Drop for BumpAllocatorScope<'_> {
+    fn drop(&mut self) {
+        let reserved_end = self.allocator.start as usize + self.allocator.size;
+        let used_end = self.allocator.pos.load(Ordering::Relaxed);
+        // fails when the parent allocated memory after the scope, then the unused memory of the
+        // scope stays reserved
+        let _ = self.parent.pos.compare_exchange(
+            reserved_end,
+            used_end,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        );
+    }
+}
+
+impl BaseAllocator for BumpAllocatorScope<'_> {
+    fn allocate(
+        &self,
+        layout: core::alloc::Layout,
+    ) -> Result<core::ptr::NonNull<[u8]>, AllocationError> {
+        self.allocator.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: core::ptr::NonNull<u8>, layout: core::alloc::Layout) {
+        self.allocator.deallocate(ptr, layout)
+    }
+
+    fn statistics(&self) -> Option<AllocatorStatistics> {
+        self.allocator.statistics()
     }
 }
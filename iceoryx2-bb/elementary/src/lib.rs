@@ -26,6 +26,8 @@ pub mod cyclic_tagger;
 pub mod lazy_singleton;
 pub mod math;
 pub mod package_version;
+pub mod placement_new;
+pub mod relocatable_once_cell;
 pub mod relocatable_ptr;
 pub mod scope_guard;
 pub mod static_assert;
@@ -55,3 +57,6 @@ pub enum CallbackProgression {
     /// Continues the iteration
     Continue,
 }
+
+#[cfg(doctest)]
+mod placement_new_compile_tests;
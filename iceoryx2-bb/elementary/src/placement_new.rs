@@ -0,0 +1,99 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Provides the [`placement_new!`](crate::placement_new!) macro which constructs a struct
+//! directly at a given memory position. Every field is written separately into the target
+//! memory, therefore the struct is never materialized on the stack. This allows to construct
+//! structs that are larger than the stack, for instance in a freshly mapped shared memory
+//! segment or in the payload of a sample acquired with `loan_uninit()`.
+//!
+//! A field is either initialized with an arbitrary expression or, when `PlacementDefault` is
+//! stated instead of an expression, with
+//! [`PlacementDefault::placement_default()`](iceoryx2_bb_elementary_traits::placement_default::PlacementDefault::placement_default()).
+//! Every field must be stated exactly once, otherwise the code does not compile.
+//!
+//! # Example
+//!
+//! ```
+//! use core::alloc::Layout;
+//! extern crate alloc;
+//! use alloc::alloc::{alloc, dealloc};
+//! use iceoryx2_bb_elementary::placement_new;
+//!
+//! struct MyLargeType {
+//!     id: u64,
+//!     name: &'static str,
+//!     data: [u64; 10485760],
+//! }
+//!
+//! let layout = Layout::new::<MyLargeType>();
+//! let raw_memory = unsafe { alloc(layout) } as *mut MyLargeType;
+//!
+//! unsafe {
+//!     placement_new!(raw_memory, MyLargeType {
+//!         id: 123,
+//!         name: "fuu",
+//!         data: PlacementDefault,
+//!     })
+//! };
+//!
+//! assert_eq!(unsafe { &*raw_memory }.id, 123);
+//! assert_eq!(unsafe { &*raw_memory }.data[456], 0);
+//!
+//! unsafe { core::ptr::drop_in_place(raw_memory) };
+//! unsafe { dealloc(raw_memory.cast(), layout) };
+//! ```
+
+#[doc(hidden)]
+pub mod __internal {
+    pub use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
+}
+
+/// Constructs a struct in place at the provided pointer without materializing it on the
+/// stack. See the [module documentation](crate::placement_new) for details.
+///
+/// # Safety
+///
+///  * must be called in an `unsafe` context
+///  * the pointer must be aligned and point to valid memory with at least the size of the
+///    struct
+///  * the memory must not contain an initialized value, it is overridden without being
+///    dropped
+///  * when a field expression panics, all fields that were written before are leaked
+#[macro_export]
+macro_rules! placement_new {
+    ($ptr:expr, $type:path { $($fields:tt)* }) => {{
+        let ptr: *mut $type = $ptr;
+        $crate::placement_new!(@field ptr, $type, [], $($fields)*);
+    }};
+
+    (@field $ptr:ident, $type:path, [$($done:ident)*], ) => {
+        // verifies at compile time that every field is initialized exactly once
+        #[allow(unreachable_code, clippy::diverging_sub_expression)]
+        if false {
+            type __Target = $type;
+            let _ = __Target { $($done: ::core::unreachable!()),* };
+        }
+    };
+
+    (@field $ptr:ident, $type:path, [$($done:ident)*], $field:ident : PlacementDefault $(, $($rest:tt)*)?) => {
+        $crate::placement_new::__internal::PlacementDefault::placement_default(
+            ::core::ptr::addr_of_mut!((*$ptr).$field),
+        );
+        $crate::placement_new!(@field $ptr, $type, [$($done)* $field], $($($rest)*)?);
+    };
+
+    (@field $ptr:ident, $type:path, [$($done:ident)*], $field:ident : $value:expr $(, $($rest:tt)*)?) => {
+        ::core::ptr::addr_of_mut!((*$ptr).$field).write($value);
+        $crate::placement_new!(@field $ptr, $type, [$($done)* $field], $($($rest)*)?);
+    };
+}
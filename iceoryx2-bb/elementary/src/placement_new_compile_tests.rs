@@ -0,0 +1,58 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+/// ``` compile_fail
+/// use core::mem::MaybeUninit;
+/// use iceoryx2_bb_elementary::placement_new;
+///
+/// struct TestStruct {
+///     val1: u64,
+///     val2: u64,
+/// }
+///
+/// let mut sut = MaybeUninit::<TestStruct>::uninit();
+/// unsafe { placement_new!(sut.as_mut_ptr(), TestStruct { val1: 1 }) };
+/// ```
+#[cfg(doctest)]
+fn placement_new_does_not_work_when_a_field_is_missing() {}
+
+/// ``` compile_fail
+/// use core::mem::MaybeUninit;
+/// use iceoryx2_bb_elementary::placement_new;
+///
+/// struct TestStruct {
+///     val1: u64,
+///     val2: u64,
+/// }
+///
+/// let mut sut = MaybeUninit::<TestStruct>::uninit();
+/// unsafe { placement_new!(sut.as_mut_ptr(), TestStruct { val1: 1, val2: 2, val1: 3 }) };
+/// ```
+#[cfg(doctest)]
+fn placement_new_does_not_work_when_a_field_is_initialized_twice() {}
+
+/// ``` compile_fail
+/// use core::mem::MaybeUninit;
+/// use iceoryx2_bb_elementary::placement_new;
+///
+/// struct Foo(u16);
+///
+/// struct TestStruct {
+///     val1: u64,
+///     val2: Foo,
+/// }
+///
+/// let mut sut = MaybeUninit::<TestStruct>::uninit();
+/// unsafe { placement_new!(sut.as_mut_ptr(), TestStruct { val1: 1, val2: PlacementDefault }) };
+/// ```
+#[cfg(doctest)]
+fn placement_new_does_not_work_when_field_does_not_implement_placement_default() {}
@@ -0,0 +1,241 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A cell that can be initialized exactly once and that can be placed in shared memory.
+//!
+//! The [`RelocatableOnceCell`] stores its value inline and contains no pointers, therefore
+//! it can be used from multiple processes that map the same memory at different addresses.
+//! The first caller of [`RelocatableOnceCell::get_or_init()`] initializes the value while
+//! all other callers wait until the initialization is finished.
+//!
+//! When the initializer panics the cell becomes poisoned and all current and future callers
+//! receive [`RelocatableOnceCellError::Poisoned`]. The death of an initializing process
+//! cannot be detected by the cell itself, therefore
+//! [`RelocatableOnceCell::get_or_init_with_monitor()`] allows waiting callers to verify
+//! that the initializer is still alive.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_elementary::relocatable_once_cell::*;
+//!
+//! let cell = RelocatableOnceCell::<u64>::new();
+//! assert!(cell.get().is_none());
+//!
+//! let value = cell.get_or_init(|| 1234).expect("cell is not poisoned");
+//! assert_eq!(*value, 1234);
+//!
+//! // the value is initialized only once
+//! let value = cell.get_or_init(|| 5678).expect("cell is not poisoned");
+//! assert_eq!(*value, 1234);
+//! ```
+
+use core::{cell::UnsafeCell, fmt::Debug, mem::MaybeUninit, sync::atomic::Ordering};
+
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+
+// the lower bits contain the state, the upper bits the id of the initializer
+const STATE_BITS: u32 = 2;
+const STATE_MASK: u64 = (1 << STATE_BITS) - 1;
+const UNINITIALIZED: u64 = 0;
+const INITIALIZING: u64 = 1;
+const INITIALIZED: u64 = 2;
+const POISONED: u64 = 3;
+// waiters spin for a short time and afterwards yield the cpu to the initializer
+const SPIN_REPETITIONS: u64 = 10000;
+
+/// Failures that can occur when accessing the value of a [`RelocatableOnceCell`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum RelocatableOnceCellError {
+    /// The initialization of the value was aborted, either because the initializer panicked
+    /// or because the initializer was no longer alive.
+    Poisoned,
+}
+
+impl core::fmt::Display for RelocatableOnceCellError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "RelocatableOnceCellError::{self:?}")
+    }
+}
+
+impl core::error::Error for RelocatableOnceCellError {}
+
+/// A shared memory compatible cell that is initialized at most once.
+#[repr(C)]
+pub struct RelocatableOnceCell<T> {
+    state: IoxAtomicU64,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+unsafe impl<T: Send> Send for RelocatableOnceCell<T> {}
+unsafe impl<T: Send + Sync> Sync for RelocatableOnceCell<T> {}
+unsafe impl<T: ZeroCopySend> ZeroCopySend for RelocatableOnceCell<T> {}
+
+impl<T: Debug> Debug for RelocatableOnceCell<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "RelocatableOnceCell<{}> {{ state: {}, value: {:?} }}",
+            core::any::type_name::<T>(),
+            self.state.load(Ordering::Relaxed) & STATE_MASK,
+            self.get()
+        )
+    }
+}
+
+impl<T> Default for RelocatableOnceCell<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for RelocatableOnceCell<T> {
+    fn drop(&mut self) {
+        if self.state.load(Ordering::Acquire) == INITIALIZED {
+            unsafe { (*self.value.get()).assume_init_drop() };
+        }
+    }
+}
+
+/// Poisons the cell when the initializer unwinds.
+struct PoisonOnUnwind<'a> {
+    state: &'a IoxAtomicU64,
+}
+
+impl Drop for PoisonOnUnwind<'_> {
+    fn drop(&mut self) {
+        self.state.store(POISONED, Ordering::Release);
+    }
+}
+
+impl<T> RelocatableOnceCell<T> {
+    /// Creates a new uninitialized [`RelocatableOnceCell`].
+    pub const fn new() -> Self {
+        Self {
+            state: IoxAtomicU64::new(UNINITIALIZED),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Returns true when the value was initialized, otherwise false.
+    pub fn is_initialized(&self) -> bool {
+        self.state.load(Ordering::Acquire) == INITIALIZED
+    }
+
+    /// Returns true when the initialization was aborted.
+    pub fn is_poisoned(&self) -> bool {
+        self.state.load(Ordering::Acquire) == POISONED
+    }
+
+    /// Returns a reference to the value if it is initialized, otherwise [`None`].
+    pub fn get(&self) -> Option<&T> {
+        if self.is_initialized() {
+            Some(unsafe { (*self.value.get()).assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the value. If the value is not yet initialized, the provided
+    /// `initializer` is called to create it. If another caller is initializing the value
+    /// concurrently, it waits until the initialization is finished.
+    ///
+    /// The death of an initializing process cannot be detected, use
+    /// [`RelocatableOnceCell::get_or_init_with_monitor()`] when the cell is shared between
+    /// processes.
+    pub fn get_or_init<F: FnOnce() -> T>(
+        &self,
+        initializer: F,
+    ) -> Result<&T, RelocatableOnceCellError> {
+        self.get_or_init_with_monitor(0, initializer, |_| true)
+    }
+
+    /// Like [`RelocatableOnceCell::get_or_init()`] but stores `initializer_id`, e.g. the
+    /// process id, when the caller becomes the initializer. While waiting for another
+    /// initializer, `is_initializer_alive` is called with the id of the initializer. As soon
+    /// as it returns false the cell is poisoned and [`RelocatableOnceCellError::Poisoned`] is
+    /// returned.
+    ///
+    /// Only the lower 62 bits of `initializer_id` are stored.
+    pub fn get_or_init_with_monitor<F: FnOnce() -> T, M: FnMut(u64) -> bool>(
+        &self,
+        initializer_id: u64,
+        initializer: F,
+        mut is_initializer_alive: M,
+    ) -> Result<&T, RelocatableOnceCellError> {
+        let initializing = (initializer_id << STATE_BITS) | INITIALIZING;
+        match self.state.compare_exchange(
+            UNINITIALIZED,
+            initializing,
+            Ordering::Acquire,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => self.initialize(initializing, initializer),
+            Err(_) => {
+                let mut retry_counter = 0;
+                loop {
+                    let state = self.state.load(Ordering::Acquire);
+                    match state & STATE_MASK {
+                        INITIALIZED => return Ok(unsafe { (*self.value.get()).assume_init_ref() }),
+                        POISONED => return Err(RelocatableOnceCellError::Poisoned),
+                        _ => {
+                            if !is_initializer_alive(state >> STATE_BITS) {
+                                // the initializer may have finished in the meantime
+                                let _ = self.state.compare_exchange(
+                                    state,
+                                    POISONED,
+                                    Ordering::AcqRel,
+                                    Ordering::Acquire,
+                                );
+                                continue;
+                            }
+
+                            if retry_counter < SPIN_REPETITIONS {
+                                retry_counter += 1;
+                                core::hint::spin_loop();
+                            } else {
+                                std::thread::yield_now();
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn initialize<F: FnOnce() -> T>(
+        &self,
+        initializing: u64,
+        initializer: F,
+    ) -> Result<&T, RelocatableOnceCellError> {
+        let poison_guard = PoisonOnUnwind { state: &self.state };
+        let value = initializer();
+        core::mem::forget(poison_guard);
+
+        unsafe { (*self.value.get()).write(value) };
+
+        match self.state.compare_exchange(
+            initializing,
+            INITIALIZED,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        ) {
+            Ok(_) => Ok(unsafe { (*self.value.get()).assume_init_ref() }),
+            Err(_) => {
+                // the cell was poisoned by a waiter that considered the initializer dead
+                unsafe { (*self.value.get()).assume_init_drop() };
+                Err(RelocatableOnceCellError::Poisoned)
+            }
+        }
+    }
+}
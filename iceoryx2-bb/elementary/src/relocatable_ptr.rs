@@ -65,9 +65,34 @@
 //! ```
 
 use core::{fmt::Debug, marker::PhantomData, ptr::NonNull};
+use iceoryx2_bb_elementary_traits::allocator::AllocationError;
 use iceoryx2_bb_elementary_traits::generic_pointer::GenericPointer;
 pub use iceoryx2_bb_elementary_traits::pointer_trait::PointerTrait;
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicIsize;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicI32, IoxAtomicIsize};
+
+/// A [`GenericPointer`] whose pointers are relocatable and can therefore be stored in shared
+/// memory. It allows containers to be generic over the width of the stored distance.
+pub trait GenericRelocatable: GenericPointer {
+    /// Creates a new uninitialized pointer.
+    ///
+    /// # Safety
+    ///
+    ///  * [`GenericRelocatable::init()`] must be called once before use
+    ///
+    unsafe fn new_uninit<T: Debug>() -> Self::Type<T>;
+
+    /// Initializes the pointer so that it points to the provided memory. Fails with
+    /// [`AllocationError::OutOfPointerRange`] when the distance cannot be represented.
+    ///
+    /// # Safety
+    ///
+    ///  * see [`RelocatablePointer::init()`]
+    ///
+    unsafe fn init<T: Debug>(
+        pointer: &Self::Type<T>,
+        memory: NonNull<[u8]>,
+    ) -> Result<(), AllocationError>;
+}
 
 #[derive(Debug)]
 pub struct GenericRelocatablePointer;
@@ -95,7 +120,7 @@ pub struct RelocatablePointer<T> {
 impl<T> RelocatablePointer<T> {
     /// Creates a new [`RelocatablePointer`]. The distance is the relative distance to the memory
     /// destination starting from the memory location of this [`RelocatablePointer`].
-    pub fn new(distance: isize) -> Self {
+    pub const fn new(distance: isize) -> Self {
         Self {
             distance: IoxAtomicIsize::new(distance),
             _phantom: PhantomData,
@@ -150,3 +175,103 @@ impl<T> PointerTrait<T> for RelocatablePointer<T> {
 impl GenericPointer for GenericRelocatablePointer {
     type Type<T: Debug> = RelocatablePointer<T>;
 }
+
+impl GenericRelocatable for GenericRelocatablePointer {
+    unsafe fn new_uninit<T: Debug>() -> Self::Type<T> {
+        RelocatablePointer::new_uninit()
+    }
+
+    unsafe fn init<T: Debug>(
+        pointer: &Self::Type<T>,
+        memory: NonNull<[u8]>,
+    ) -> Result<(), AllocationError> {
+        pointer.init(memory);
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct GenericRelocatablePointer32;
+
+/// Compact variant of the [`RelocatablePointer`] that stores the distance as 32-bit value. It
+/// can only address memory that is at most [`i32::MAX`] bytes away from its own location.
+#[repr(C)]
+#[derive(Debug)]
+pub struct RelocatablePointer32<T> {
+    distance: IoxAtomicI32,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> RelocatablePointer32<T> {
+    /// Creates a new [`RelocatablePointer32`]. The distance is the relative distance to the
+    /// memory destination starting from the memory location of this [`RelocatablePointer32`].
+    pub const fn new(distance: i32) -> Self {
+        Self {
+            distance: IoxAtomicI32::new(distance),
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Creates a new uninitialized [`RelocatablePointer32`].
+    ///
+    /// # Safety
+    ///
+    ///  * [`RelocatablePointer32::init()`] must be called once before use
+    ///
+    pub unsafe fn new_uninit() -> Self {
+        Self::new(0)
+    }
+
+    /// Initializes the [`RelocatablePointer32`] by setting the distance to the memory
+    /// destination. If the distance does not fit into 32-bit it fails with
+    /// [`AllocationError::OutOfPointerRange`] and the pointer stays uninitialized.
+    ///
+    /// # Safety
+    ///
+    ///  * see [`RelocatablePointer::init()`]
+    ///
+    pub unsafe fn init(&self, ptr: NonNull<[u8]>) -> Result<(), AllocationError> {
+        let distance = (ptr.as_ptr() as *const u8) as isize - (self as *const Self) as isize;
+        match i32::try_from(distance) {
+            Ok(distance) => {
+                self.distance
+                    .store(distance, core::sync::atomic::Ordering::Relaxed);
+                Ok(())
+            }
+            Err(_) => Err(AllocationError::OutOfPointerRange),
+        }
+    }
+}
+
+impl<T> PointerTrait<T> for RelocatablePointer32<T> {
+    unsafe fn as_ptr(&self) -> *const T {
+        ((self as *const Self) as isize
+            + self.distance.load(core::sync::atomic::Ordering::Relaxed) as isize)
+            as *const T
+    }
+
+    unsafe fn as_mut_ptr(&mut self) -> *mut T {
+        self.as_ptr() as *mut T
+    }
+
+    fn is_initialized(&self) -> bool {
+        self.distance.load(core::sync::atomic::Ordering::Relaxed) != 0
+    }
+}
+
+impl GenericPointer for GenericRelocatablePointer32 {
+    type Type<T: Debug> = RelocatablePointer32<T>;
+}
+
+impl GenericRelocatable for GenericRelocatablePointer32 {
+    unsafe fn new_uninit<T: Debug>() -> Self::Type<T> {
+        RelocatablePointer32::new_uninit()
+    }
+
+    unsafe fn init<T: Debug>(
+        pointer: &Self::Type<T>,
+        memory: NonNull<[u8]>,
+    ) -> Result<(), AllocationError> {
+        pointer.init(memory)
+    }
+}
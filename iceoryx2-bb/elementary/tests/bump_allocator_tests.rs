@@ -13,7 +13,9 @@
 use core::{alloc::Layout, ptr::NonNull};
 
 use iceoryx2_bb_elementary::{bump_allocator::*, math::align};
-use iceoryx2_bb_elementary_traits::allocator::BaseAllocator;
+use iceoryx2_bb_elementary_traits::allocator::{
+    AllocationError, AllocatorStatistics, BaseAllocator,
+};
 use iceoryx2_bb_testing::assert_that;
 
 #[test]
@@ -99,3 +101,239 @@ fn deallocating_releases_everything() {
     assert_that!(unsafe { memory.as_ref() }.as_ptr() as usize, eq start_position as usize);
     assert_that!(unsafe { memory.as_ref() }.len(), eq MEM_SIZE);
 }
+
+#[test]
+fn used_and_remaining_bytes_are_tracked() {
+    const MEMORY_SIZE: usize = 128;
+    let mut memory = [0u8; MEMORY_SIZE];
+    let sut = BumpAllocator::new_with_size_limit(memory.as_mut_ptr(), MEMORY_SIZE);
+
+    assert_that!(sut.used_bytes(), eq 0);
+    assert_that!(sut.remaining_bytes(), eq MEMORY_SIZE);
+
+    sut.allocate(Layout::from_size_align(10, 1).unwrap())
+        .unwrap();
+    assert_that!(sut.used_bytes(), eq 10);
+    assert_that!(sut.remaining_bytes(), eq MEMORY_SIZE - 10);
+
+    let start_position = memory.as_ptr() as usize;
+    let aligned_memory = sut
+        .allocate(Layout::from_size_align(8, 8).unwrap())
+        .unwrap();
+    let end_position = unsafe { aligned_memory.as_ref() }.as_ptr() as usize + 8;
+    assert_that!(sut.used_bytes(), eq end_position - start_position);
+    assert_that!(sut.used_bytes(), ge 18);
+    assert_that!(sut.used_bytes() + sut.remaining_bytes(), eq MEMORY_SIZE);
+}
+
+#[test]
+fn allocation_exceeding_size_limit_fails() {
+    const MEMORY_SIZE: usize = 64;
+    let mut memory = [0u8; MEMORY_SIZE];
+    let sut = BumpAllocator::new_with_size_limit(memory.as_mut_ptr(), MEMORY_SIZE);
+
+    assert_that!(sut.allocate(Layout::from_size_align(MEMORY_SIZE + 1, 1).unwrap()), eq Err(AllocationError::OutOfMemory));
+    assert_that!(sut.used_bytes(), eq 0);
+
+    assert_that!(
+        sut.allocate(Layout::from_size_align(MEMORY_SIZE - 1, 1).unwrap()),
+        is_ok
+    );
+    assert_that!(sut.allocate(Layout::from_size_align(2, 1).unwrap()), eq Err(AllocationError::OutOfMemory));
+    assert_that!(sut.allocate(Layout::from_size_align(1, 1).unwrap()), is_ok);
+    assert_that!(sut.remaining_bytes(), eq 0);
+}
+
+#[test]
+fn statistics_report_used_and_remaining_bytes() {
+    const MEMORY_SIZE: usize = 64;
+    let mut memory = [0u8; MEMORY_SIZE];
+    let sut = BumpAllocator::new_with_size_limit(memory.as_mut_ptr(), MEMORY_SIZE);
+
+    assert_that!(sut.allocate(Layout::from_size_align(24, 1).unwrap()), is_ok);
+
+    assert_that!(sut.statistics(), eq Some(AllocatorStatistics {
+        used_bytes: 24,
+        remaining_bytes: MEMORY_SIZE - 24
+    }));
+}
+
+#[test]
+fn reset_allows_reinitialization() {
+    const MEMORY_SIZE: usize = 64;
+    let mut memory = [0u8; MEMORY_SIZE];
+    let start_position = memory.as_mut_ptr();
+    let sut = BumpAllocator::new_with_size_limit(start_position, MEMORY_SIZE);
+
+    assert_that!(sut.allocate(Layout::from_size_align(48, 1).unwrap()), is_ok);
+    assert_that!(
+        sut.allocate(Layout::from_size_align(32, 1).unwrap()),
+        is_err
+    );
+
+    unsafe { sut.reset() };
+    assert_that!(sut.used_bytes(), eq 0);
+
+    let memory = sut
+        .allocate(Layout::from_size_align(32, 1).unwrap())
+        .unwrap();
+    assert_that!(unsafe { memory.as_ref() }.as_ptr() as usize, eq start_position as usize);
+}
+
+#[test]
+fn scope_reserves_memory_in_parent() {
+    const MEMORY_SIZE: usize = 128;
+    const SCOPE_SIZE: usize = 48;
+    let mut memory = [0u8; MEMORY_SIZE];
+    let sut = BumpAllocator::new_with_size_limit(memory.as_mut_ptr(), MEMORY_SIZE);
+
+    assert_that!(sut.allocate(Layout::from_size_align(3, 1).unwrap()), is_ok);
+    let scope = sut.scope(SCOPE_SIZE).unwrap();
+
+    assert_that!(scope.used_bytes(), eq 0);
+    assert_that!(scope.remaining_bytes(), eq SCOPE_SIZE);
+    assert_that!(sut.remaining_bytes(), eq MEMORY_SIZE - SCOPE_SIZE - 3);
+
+    let scope_memory = scope
+        .allocate(Layout::from_size_align(SCOPE_SIZE, 1).unwrap())
+        .unwrap();
+    let parent_memory = sut
+        .allocate(Layout::from_size_align(1, 1).unwrap())
+        .unwrap();
+
+    assert_that!(unsafe { scope_memory.as_ref() }.as_ptr() as usize, eq memory.as_ptr() as usize + 3);
+    assert_that!(unsafe { parent_memory.as_ref() }.as_ptr() as usize, eq memory.as_ptr() as usize + 3 + SCOPE_SIZE);
+}
+
+#[test]
+fn scope_exceeding_remaining_bytes_fails() {
+    const MEMORY_SIZE: usize = 64;
+    let mut memory = [0u8; MEMORY_SIZE];
+    let sut = BumpAllocator::new_with_size_limit(memory.as_mut_ptr(), MEMORY_SIZE);
+
+    assert_that!(sut.allocate(Layout::from_size_align(16, 1).unwrap()), is_ok);
+
+    assert_that!(sut.scope(MEMORY_SIZE - 15).err(), eq Some(AllocationError::OutOfMemory));
+    assert_that!(sut.remaining_bytes(), eq MEMORY_SIZE - 16);
+    let scope = sut.scope(MEMORY_SIZE - 16);
+    assert_that!(scope, is_ok);
+    assert_that!(sut.remaining_bytes(), eq 0);
+}
+
+#[test]
+fn exhausted_scope_does_not_affect_parent() {
+    const MEMORY_SIZE: usize = 128;
+    const SCOPE_SIZE: usize = 32;
+    let mut memory = [0u8; MEMORY_SIZE];
+    let sut = BumpAllocator::new_with_size_limit(memory.as_mut_ptr(), MEMORY_SIZE);
+
+    let scope = sut.scope(SCOPE_SIZE).unwrap();
+    assert_that!(
+        scope.allocate(Layout::from_size_align(24, 1).unwrap()),
+        is_ok
+    );
+    assert_that!(scope.allocate(Layout::from_size_align(16, 1).unwrap()), eq Err(AllocationError::OutOfMemory));
+    assert_that!(scope.remaining_bytes(), eq SCOPE_SIZE - 24);
+    assert_that!(sut.remaining_bytes(), eq MEMORY_SIZE - SCOPE_SIZE);
+
+    // retry with a smaller configuration
+    unsafe { scope.reset() };
+    assert_that!(
+        scope.allocate(Layout::from_size_align(16, 1).unwrap()),
+        is_ok
+    );
+    assert_that!(
+        scope.allocate(Layout::from_size_align(16, 1).unwrap()),
+        is_ok
+    );
+    assert_that!(scope.remaining_bytes(), eq 0);
+    assert_that!(sut.remaining_bytes(), eq MEMORY_SIZE - SCOPE_SIZE);
+}
+
+#[test]
+fn nested_scopes_are_bounded_by_their_parent_scope() {
+    const MEMORY_SIZE: usize = 256;
+    let mut memory = [0u8; MEMORY_SIZE];
+    let sut = BumpAllocator::new_with_size_limit(memory.as_mut_ptr(), MEMORY_SIZE);
+
+    let outer_scope = sut.scope(128).unwrap();
+    let inner_scope = outer_scope.scope(64).unwrap();
+
+    assert_that!(outer_scope.scope(65).err(), eq Some(AllocationError::OutOfMemory));
+    assert_that!(inner_scope.scope(65).err(), eq Some(AllocationError::OutOfMemory));
+    assert_that!(
+        inner_scope.allocate(Layout::from_size_align(64, 1).unwrap()),
+        is_ok
+    );
+    assert_that!(
+        inner_scope.allocate(Layout::from_size_align(1, 1).unwrap()),
+        is_err
+    );
+
+    assert_that!(outer_scope.remaining_bytes(), eq 64);
+    assert_that!(sut.remaining_bytes(), eq 128);
+}
+
+#[test]
+fn dropped_scope_returns_unused_memory_to_parent() {
+    const MEMORY_SIZE: usize = 128;
+    const SCOPE_SIZE: usize = 64;
+    let mut memory = [0u8; MEMORY_SIZE];
+    let sut = BumpAllocator::new_with_size_limit(memory.as_mut_ptr(), MEMORY_SIZE);
+
+    let scope = sut.scope(SCOPE_SIZE).unwrap();
+    let scope_memory = scope
+        .allocate(Layout::from_size_align(24, 1).unwrap())
+        .unwrap();
+    drop(scope);
+
+    assert_that!(sut.used_bytes(), eq 24);
+    let parent_memory = sut
+        .allocate(Layout::from_size_align(1, 1).unwrap())
+        .unwrap();
+    assert_that!(unsafe { parent_memory.as_ref() }.as_ptr() as usize, eq unsafe { scope_memory.as_ref() }.as_ptr() as usize + 24);
+}
+
+#[test]
+fn dropped_scope_keeps_memory_reserved_when_parent_allocated_afterwards() {
+    const MEMORY_SIZE: usize = 128;
+    const SCOPE_SIZE: usize = 64;
+    let mut memory = [0u8; MEMORY_SIZE];
+    let sut = BumpAllocator::new_with_size_limit(memory.as_mut_ptr(), MEMORY_SIZE);
+
+    let scope = sut.scope(SCOPE_SIZE).unwrap();
+    assert_that!(sut.allocate(Layout::from_size_align(8, 1).unwrap()), is_ok);
+    drop(scope);
+
+    assert_that!(sut.used_bytes(), eq SCOPE_SIZE + 8);
+}
+
+#[test]
+fn dropped_nested_scope_returns_unused_memory_to_its_parent_scope() {
+    const MEMORY_SIZE: usize = 256;
+    let mut memory = [0u8; MEMORY_SIZE];
+    let sut = BumpAllocator::new_with_size_limit(memory.as_mut_ptr(), MEMORY_SIZE);
+
+    let outer_scope = sut.scope(128).unwrap();
+    let inner_scope = outer_scope.scope(64).unwrap();
+    assert_that!(
+        inner_scope.allocate(Layout::from_size_align(16, 1).unwrap()),
+        is_ok
+    );
+    drop(inner_scope);
+
+    assert_that!(outer_scope.used_bytes(), eq 16);
+    assert_that!(sut.used_bytes(), eq 128);
+
+    drop(outer_scope);
+    assert_that!(sut.used_bytes(), eq 16);
+}
+
+#[test]
+fn scope_with_size_exceeding_layout_limit_fails() {
+    let mut memory = [0u8; 64];
+    let sut = BumpAllocator::new_with_size_limit(memory.as_mut_ptr(), memory.len());
+
+    assert_that!(sut.scope(usize::MAX).err(), eq Some(AllocationError::SizeTooLarge));
+    assert_that!(sut.used_bytes(), eq 0);
+}
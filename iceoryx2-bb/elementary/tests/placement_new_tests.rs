@@ -0,0 +1,132 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::mem::MaybeUninit;
+
+use iceoryx2_bb_elementary::placement_new;
+use iceoryx2_bb_testing::{assert_that, lifetime_tracker::LifetimeTracker, memory::RawMemory};
+
+#[derive(Debug)]
+struct SmallType {
+    id: u64,
+    flag: bool,
+    tracker: LifetimeTracker,
+}
+
+const LARGE_ARRAY_SIZE: usize = 1024 * 1024;
+
+struct LargeType {
+    id: u64,
+    first: [u64; LARGE_ARRAY_SIZE],
+    name: &'static str,
+    second: [u64; LARGE_ARRAY_SIZE],
+}
+
+#[test]
+fn placement_new_writes_all_fields() {
+    let tracker = LifetimeTracker::start_tracking();
+    let sut = RawMemory::<SmallType>::new_filled(0xff);
+
+    unsafe {
+        placement_new!(
+            sut.as_mut_ptr(),
+            SmallType {
+                id: 8127,
+                flag: true,
+                tracker: LifetimeTracker::new_with_value(91),
+            }
+        )
+    };
+
+    let sut = unsafe { sut.assume_init() };
+    assert_that!(sut.id, eq 8127);
+    assert_that!(sut.flag, eq true);
+    assert_that!(sut.tracker.value, eq 91);
+    assert_that!(tracker.number_of_living_instances(), eq 1);
+
+    unsafe { core::ptr::drop_in_place(sut as *const SmallType as *mut SmallType) };
+    assert_that!(tracker.number_of_living_instances(), eq 0);
+}
+
+#[test]
+fn placement_new_mixes_values_and_placement_default_fields() {
+    let sut = RawMemory::<SmallType>::new_filled(0xff);
+
+    unsafe {
+        placement_new!(
+            sut.as_mut_ptr(),
+            SmallType {
+                id: PlacementDefault,
+                flag: PlacementDefault,
+                tracker: LifetimeTracker::new(),
+            }
+        )
+    };
+
+    let sut = unsafe { sut.assume_init() };
+    assert_that!(sut.id, eq 0);
+    assert_that!(sut.flag, eq false);
+}
+
+#[test]
+fn placement_new_works_with_maybe_uninit() {
+    let mut sut = MaybeUninit::<SmallType>::uninit();
+
+    unsafe {
+        placement_new!(
+            sut.as_mut_ptr(),
+            SmallType {
+                tracker: LifetimeTracker::new_with_value(3),
+                id: 12,
+                flag: true
+            }
+        )
+    };
+
+    let sut = unsafe { sut.assume_init() };
+    assert_that!(sut.id, eq 12);
+    assert_that!(sut.flag, eq true);
+    assert_that!(sut.tracker.value, eq 3);
+}
+
+#[test]
+fn placement_new_constructs_type_larger_than_the_stack() {
+    const STACK_SIZE: usize = 64 * 1024;
+    assert_that!(core::mem::size_of::<LargeType>(), gt 100 * STACK_SIZE);
+
+    let handle = std::thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(|| {
+            let mut memory: Box<MaybeUninit<LargeType>> = Box::new_uninit();
+
+            unsafe {
+                placement_new!(
+                    memory.as_mut_ptr(),
+                    LargeType {
+                        id: 42,
+                        first: PlacementDefault,
+                        name: "large",
+                        second: PlacementDefault,
+                    }
+                )
+            };
+
+            let sut = unsafe { memory.assume_init() };
+            assert_that!(sut.id, eq 42);
+            assert_that!(sut.name, eq "large");
+            assert_that!(sut.first.iter().all(|v| *v == 0), eq true);
+            assert_that!(sut.second.iter().all(|v| *v == 0), eq true);
+        })
+        .unwrap();
+
+    assert_that!(handle.join(), is_ok);
+}
@@ -0,0 +1,154 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::sync::atomic::Ordering;
+use std::sync::Barrier;
+
+use iceoryx2_bb_elementary::relocatable_once_cell::*;
+use iceoryx2_bb_testing::{assert_that, lifetime_tracker::LifetimeTracker};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
+
+#[test]
+fn relocatable_once_cell_is_uninitialized_after_creation() {
+    let sut = RelocatableOnceCell::<u64>::new();
+
+    assert_that!(sut.is_initialized(), eq false);
+    assert_that!(sut.is_poisoned(), eq false);
+    assert_that!(sut.get(), is_none);
+}
+
+#[test]
+fn relocatable_once_cell_get_or_init_initializes_value_once() {
+    let sut = RelocatableOnceCell::<u64>::new();
+    let mut number_of_calls = 0;
+
+    assert_that!(sut.get_or_init(|| { number_of_calls += 1; 781 }), eq Ok(&781));
+    assert_that!(sut.get_or_init(|| { number_of_calls += 1; 912 }), eq Ok(&781));
+
+    assert_that!(number_of_calls, eq 1);
+    assert_that!(sut.is_initialized(), eq true);
+    assert_that!(sut.get(), eq Some(&781));
+}
+
+#[test]
+fn relocatable_once_cell_is_poisoned_when_initializer_panics() {
+    let sut = RelocatableOnceCell::<u64>::new();
+
+    let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+        let _ = sut.get_or_init(|| panic!("initialization failed"));
+    }));
+    assert_that!(result, is_err);
+
+    assert_that!(sut.is_poisoned(), eq true);
+    assert_that!(sut.get(), is_none);
+    assert_that!(sut.get_or_init(|| 1), eq Err(RelocatableOnceCellError::Poisoned));
+}
+
+#[test]
+fn relocatable_once_cell_drops_initialized_value() {
+    let tracker = LifetimeTracker::start_tracking();
+    let sut = RelocatableOnceCell::<LifetimeTracker>::new();
+    assert_that!(sut.get_or_init(LifetimeTracker::new), is_ok);
+    assert_that!(tracker.number_of_living_instances(), eq 1);
+
+    drop(sut);
+    assert_that!(tracker.number_of_living_instances(), eq 0);
+}
+
+#[test]
+fn relocatable_once_cell_waiter_reports_poisoned_when_initializer_is_dead() {
+    const INITIALIZER_ID: u64 = 4711;
+    let sut = RelocatableOnceCell::<u64>::new();
+    let initializer_is_alive = IoxAtomicBool::new(true);
+    let barrier = Barrier::new(2);
+
+    std::thread::scope(|s| {
+        let initializer = s.spawn(|| {
+            sut.get_or_init_with_monitor(
+                INITIALIZER_ID,
+                || {
+                    barrier.wait();
+                    // simulates a process that dies during the initialization
+                    while initializer_is_alive.load(Ordering::Relaxed) {
+                        core::hint::spin_loop();
+                    }
+                    123
+                },
+                |_| true,
+            )
+            .copied()
+        });
+
+        barrier.wait();
+        let mut reported_id = 0;
+        let result = sut.get_or_init_with_monitor(
+            0,
+            || 456,
+            |id| {
+                reported_id = id;
+                initializer_is_alive.store(false, Ordering::Relaxed);
+                false
+            },
+        );
+
+        assert_that!(result, eq Err(RelocatableOnceCellError::Poisoned));
+        assert_that!(reported_id, eq INITIALIZER_ID);
+        assert_that!(
+            initializer.join().unwrap(),
+            eq Err(RelocatableOnceCellError::Poisoned)
+        );
+    });
+
+    assert_that!(sut.is_poisoned(), eq true);
+    assert_that!(sut.get(), is_none);
+}
+
+#[test]
+fn relocatable_once_cell_is_initialized_once_with_concurrent_callers() {
+    const NUMBER_OF_THREADS: usize = 8;
+    const NUMBER_OF_CELLS: usize = 1000;
+    let cells: Vec<_> = (0..NUMBER_OF_CELLS)
+        .map(|_| RelocatableOnceCell::<usize>::new())
+        .collect();
+    let number_of_initializations = IoxAtomicUsize::new(0);
+    let barrier = Barrier::new(NUMBER_OF_THREADS);
+
+    std::thread::scope(|s| {
+        let mut threads = vec![];
+        for thread_id in 0..NUMBER_OF_THREADS {
+            let cells = &cells;
+            let barrier = &barrier;
+            let number_of_initializations = &number_of_initializations;
+            threads.push(s.spawn(move || {
+                barrier.wait();
+                let mut values = vec![];
+                for cell in cells {
+                    let value = cell
+                        .get_or_init(|| {
+                            number_of_initializations.fetch_add(1, Ordering::Relaxed);
+                            thread_id
+                        })
+                        .unwrap();
+                    values.push(*value);
+                }
+                values
+            }));
+        }
+
+        let results: Vec<_> = threads.into_iter().map(|t| t.join().unwrap()).collect();
+        for values in &results {
+            assert_that!(*values, eq results[0]);
+        }
+    });
+
+    assert_that!(number_of_initializations.load(Ordering::Relaxed), eq NUMBER_OF_CELLS);
+}
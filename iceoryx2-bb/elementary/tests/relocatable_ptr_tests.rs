@@ -10,7 +10,10 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
+use core::ptr::NonNull;
+
+use iceoryx2_bb_elementary::relocatable_ptr::{RelocatablePointer, RelocatablePointer32};
+use iceoryx2_bb_elementary_traits::allocator::AllocationError;
 use iceoryx2_bb_elementary_traits::pointer_trait::PointerTrait;
 use iceoryx2_bb_testing::assert_that;
 
@@ -27,3 +30,37 @@ fn relocatable_pointer_works() {
     _o3 = value;
     assert_that!(unsafe { *sut.as_ptr() }, eq value);
 }
+
+#[test]
+fn relocatable_pointer_32_stores_32_bit_distance() {
+    assert_that!(core::mem::size_of::<RelocatablePointer32<u64>>(), eq 4);
+}
+
+#[test]
+fn relocatable_pointer_32_works() {
+    let sut = unsafe { RelocatablePointer32::<u64>::new_uninit() };
+    let mut memory = [0u64; 4];
+    memory[2] = 819203;
+    assert_that!(sut.is_initialized(), eq false);
+
+    let target = NonNull::slice_from_raw_parts(
+        NonNull::new(core::ptr::addr_of_mut!(memory[2]) as *mut u8).unwrap(),
+        core::mem::size_of::<u64>(),
+    );
+    assert_that!(unsafe { sut.init(target) }, is_ok);
+    assert_that!(sut.is_initialized(), eq true);
+    assert_that!(unsafe { *sut.as_ptr() }, eq 819203);
+}
+
+#[test]
+fn relocatable_pointer_32_init_fails_when_distance_exceeds_32_bit() {
+    let sut = unsafe { RelocatablePointer32::<u8>::new_uninit() };
+
+    let far_away = (core::ptr::addr_of!(sut) as usize).wrapping_add(i32::MAX as usize + 1);
+    // the memory is never accessed, only the distance to it is computed
+    let target = NonNull::slice_from_raw_parts(NonNull::new(far_away as *mut u8).unwrap(), 1);
+
+    let result = unsafe { sut.init(target) };
+    assert_that!(result, eq Err(AllocationError::OutOfPointerRange));
+    assert_that!(sut.is_initialized(), eq false);
+}
@@ -46,8 +46,8 @@ use core::{alloc::Layout, cell::UnsafeCell, fmt::Debug, sync::atomic::Ordering};
 use iceoryx2_bb_elementary::math::unaligned_mem_size;
 use iceoryx2_bb_elementary::{bump_allocator::BumpAllocator, relocatable_ptr::RelocatablePointer};
 use iceoryx2_bb_elementary_traits::{
-    owning_pointer::OwningPointer, pointer_trait::PointerTrait,
-    relocatable_container::RelocatableContainer,
+    owning_pointer::OwningPointer, placement_default::PlacementDefault,
+    pointer_trait::PointerTrait, relocatable_container::RelocatableContainer,
 };
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
@@ -377,6 +377,19 @@ impl<const CAPACITY: usize> Default for FixedSizeIndexQueue<CAPACITY> {
     }
 }
 
+impl<const CAPACITY: usize> PlacementDefault for FixedSizeIndexQueue<CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        let state_ptr = core::ptr::addr_of_mut!((*ptr).state);
+        state_ptr.write(RelocatableIndexQueue::new_uninit(CAPACITY));
+
+        let allocator = BumpAllocator::new((*ptr).data.as_mut_ptr().cast());
+        (*ptr)
+            .state
+            .init(&allocator)
+            .expect("All required memory is preallocated.");
+    }
+}
+
 impl<const CAPACITY: usize> FixedSizeIndexQueue<CAPACITY> {
     /// Creates a new empty [`FixedSizeIndexQueue`].
     pub fn new() -> Self {
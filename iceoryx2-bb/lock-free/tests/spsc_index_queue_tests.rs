@@ -10,9 +10,10 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use iceoryx2_bb_elementary_traits::placement_default::PlacementDefault;
 use iceoryx2_bb_lock_free::spsc::index_queue::*;
 use iceoryx2_bb_posix::barrier::*;
-use iceoryx2_bb_testing::assert_that;
+use iceoryx2_bb_testing::{assert_that, memory::RawMemory};
 use std::sync::{Arc, Mutex};
 use std::thread;
 
@@ -116,6 +117,30 @@ fn spsc_index_queue_get_producer_after_release_succeeds() {
     assert_that!(sut.acquire_producer(), is_some);
 }
 
+#[test]
+fn spsc_index_queue_placement_default_works() {
+    const CAPACITY: usize = 12;
+    type Sut = FixedSizeIndexQueue<CAPACITY>;
+    let sut = RawMemory::<Sut>::new_filled(0xff);
+    unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+    let sut = unsafe { sut.assume_init() };
+    assert_that!(sut.is_empty(), eq true);
+    assert_that!(sut.capacity(), eq CAPACITY);
+
+    let mut producer = sut.acquire_producer().unwrap();
+    let mut consumer = sut.acquire_consumer().unwrap();
+    for i in 0..CAPACITY {
+        assert_that!(producer.push(i as u64), eq true);
+    }
+    assert_that!(producer.push(0), eq false);
+
+    for i in 0..CAPACITY {
+        assert_that!(consumer.pop(), eq Some(i as u64));
+    }
+    assert_that!(consumer.pop(), is_none);
+}
+
 #[test]
 fn spsc_index_queue_push_pop_works_concurrently() {
     const LIMIT: usize = 1000000;
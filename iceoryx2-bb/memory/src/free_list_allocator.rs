@@ -0,0 +1,353 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A **non-threadsafe**, shared memory compatible [`BaseAllocator`] which manages memory
+//! chunks of variable size in a free list. Chunks are acquired first-fit and adjacent free
+//! blocks are coalesced when a chunk is deallocated.
+//!
+//! The whole bookkeeping is stored inside the managed memory and refers to blocks only by
+//! their offset, the managed memory itself is referred to with a
+//! [`RelocatablePointer`]. Therefore, the [`FreeListAllocator`] can be placed together with
+//! its memory in a shared memory segment but must not be moved after
+//! [`FreeListAllocator::init()`] was called.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+//! use iceoryx2_bb_memory::free_list_allocator::*;
+//!
+//! const MEMORY_SIZE: usize = 1024;
+//! const MIN_BLOCK_SIZE: usize = 32;
+//! const ALIGNMENT: usize = 8;
+//! let mut memory = [0u8; MEMORY_SIZE + ALIGNMENT];
+//! let bump_allocator =
+//!     BumpAllocator::new(NonNull::new(memory.as_mut_ptr()).unwrap(), memory.len());
+//!
+//! let mut allocator =
+//!     unsafe { FreeListAllocator::new_uninit(MEMORY_SIZE, MIN_BLOCK_SIZE, ALIGNMENT) };
+//! unsafe { allocator.init(&bump_allocator).expect("failed to initialize allocator") };
+//!
+//! let layout = Layout::from_size_align(100, 4).unwrap();
+//! let chunk = allocator.allocate(layout).expect("failed to allocate");
+//! assert!(allocator.total_free() < MEMORY_SIZE);
+//!
+//! unsafe { allocator.deallocate(NonNull::new(chunk.as_ptr() as *mut u8).unwrap(), layout) };
+//! assert_eq!(allocator.total_free(), MEMORY_SIZE);
+//! ```
+
+use core::cell::Cell;
+use core::sync::atomic::Ordering;
+
+pub use core::alloc::Layout;
+use iceoryx2_bb_elementary::math::align;
+use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
+pub use iceoryx2_bb_elementary_traits::allocator::*;
+use iceoryx2_bb_elementary_traits::pointer_trait::PointerTrait;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+
+const INVALID: usize = usize::MAX;
+
+/// Stored at the beginning of every free block.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct FreeBlock {
+    size: usize,
+    next: usize,
+}
+
+/// Stored directly in front of every chunk that was handed out.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+struct AllocationHeader {
+    block_start: usize,
+    block_size: usize,
+}
+
+#[derive(Debug)]
+#[repr(C)]
+pub struct FreeListAllocator {
+    memory: RelocatablePointer<u8>,
+    size: usize,
+    min_block_size: usize,
+    alignment: usize,
+    free_list_head: Cell<usize>,
+    is_memory_initialized: IoxAtomicBool,
+}
+
+impl FreeListAllocator {
+    fn verify_init(&self, source: &str) {
+        debug_assert!(
+            self.is_memory_initialized.load(Ordering::Relaxed),
+            "From: {self:?}, Undefined behavior when calling \"{source}\" and the object is not initialized."
+        );
+    }
+
+    /// Creates a new uninitialized [`FreeListAllocator`] that manages `size` bytes. Every
+    /// block starts at a multiple of `alignment` and no block is smaller than
+    /// `min_block_size`. Both values are increased when they are too small to hold the
+    /// internal bookkeeping.
+    ///
+    /// # Safety
+    ///
+    ///  * before any other method can be called [`FreeListAllocator::init()`] must be called once
+    ///
+    pub unsafe fn new_uninit(size: usize, min_block_size: usize, alignment: usize) -> Self {
+        if !alignment.is_power_of_two() {
+            fatal_panic!(from "FreeListAllocator::new_uninit()",
+                "The alignment {} must be a power of two.", alignment);
+        }
+
+        let alignment = alignment
+            .max(core::mem::align_of::<FreeBlock>())
+            .max(core::mem::align_of::<AllocationHeader>());
+        let min_block_size = align(
+            min_block_size.max(core::mem::size_of::<FreeBlock>()),
+            alignment,
+        );
+
+        Self {
+            memory: RelocatablePointer::new_uninit(),
+            size: size - size % alignment,
+            min_block_size,
+            alignment,
+            free_list_head: Cell::new(INVALID),
+            is_memory_initialized: IoxAtomicBool::new(false),
+        }
+    }
+
+    /// Acquires the managed memory from the provided allocator.
+    ///
+    /// # Safety
+    ///
+    ///  * must be called exactly once before any other method can be called
+    ///  * the [`FreeListAllocator`] must not be moved afterwards
+    ///
+    pub unsafe fn init<Allocator: BaseAllocator>(
+        &mut self,
+        allocator: &Allocator,
+    ) -> Result<(), AllocationError> {
+        if self.is_memory_initialized.load(Ordering::Relaxed) {
+            fatal_panic!(
+                from self,
+                "Memory already initialized. Initializing it twice may lead to undefined behavior."
+            );
+        }
+
+        let memory = fail!(from self, when allocator.allocate(Layout::from_size_align_unchecked(self.size, self.alignment)),
+                "Unable to initialize free list allocator since the managed memory could not be allocated.");
+        self.memory.init(memory);
+
+        if self.min_block_size <= self.size {
+            self.write_free_block(
+                0,
+                FreeBlock {
+                    size: self.size,
+                    next: INVALID,
+                },
+            );
+            self.free_list_head.set(0);
+        }
+
+        self.is_memory_initialized.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Returns the number of bytes that are managed by the allocator.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the size of the smallest block the allocator hands out.
+    pub fn min_block_size(&self) -> usize {
+        self.min_block_size
+    }
+
+    /// Returns the alignment of every block.
+    pub fn alignment(&self) -> usize {
+        self.alignment
+    }
+
+    /// Returns the size of the largest contiguous free block. Since every chunk requires some
+    /// bookkeeping the largest chunk that can be allocated is slightly smaller.
+    pub fn largest_free_block(&self) -> usize {
+        self.verify_init("largest_free_block");
+        self.free_blocks().map(|(_, b)| b.size).max().unwrap_or(0)
+    }
+
+    /// Returns the sum of the sizes of all free blocks.
+    pub fn total_free(&self) -> usize {
+        self.verify_init("total_free");
+        self.free_blocks().map(|(_, b)| b.size).sum()
+    }
+
+    fn base_address(&self) -> usize {
+        unsafe { self.memory.as_ptr() as usize }
+    }
+
+    fn free_blocks(&self) -> impl Iterator<Item = (usize, FreeBlock)> + '_ {
+        let mut current = self.free_list_head.get();
+        core::iter::from_fn(move || {
+            if current == INVALID {
+                return None;
+            }
+
+            let offset = current;
+            let block = unsafe { self.read_free_block(offset) };
+            current = block.next;
+            Some((offset, block))
+        })
+    }
+
+    unsafe fn read_free_block(&self, offset: usize) -> FreeBlock {
+        ((self.base_address() + offset) as *const FreeBlock).read()
+    }
+
+    unsafe fn write_free_block(&self, offset: usize, block: FreeBlock) {
+        ((self.base_address() + offset) as *mut FreeBlock).write(block)
+    }
+
+    unsafe fn set_next(&self, previous: usize, next: usize) {
+        if previous == INVALID {
+            self.free_list_head.set(next);
+        } else {
+            let mut block = self.read_free_block(previous);
+            block.next = next;
+            self.write_free_block(previous, block);
+        }
+    }
+
+    fn verify_ptr_is_managed_by_allocator(&self, ptr: NonNull<u8>) {
+        let position = ptr.as_ptr() as usize;
+        debug_assert!(
+            !(position < self.base_address() + core::mem::size_of::<AllocationHeader>()
+                || position >= self.base_address() + self.size),
+            "The pointer {ptr:?} is not managed by this allocator."
+        );
+    }
+}
+
+impl BaseAllocator for FreeListAllocator {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocationError> {
+        self.verify_init("allocate");
+
+        if layout.size() == 0 {
+            fail!(from self, with AllocationError::SizeIsZero,
+                "Unable to allocate a chunk with a size of zero.");
+        }
+
+        let base_address = self.base_address();
+        let chunk_alignment = layout.align().max(self.alignment);
+        let mut previous = INVALID;
+
+        for (offset, block) in self.free_blocks() {
+            let block_address = base_address + offset;
+            let chunk_address = align(
+                block_address + core::mem::size_of::<AllocationHeader>(),
+                chunk_alignment,
+            );
+            let required_size = align(
+                chunk_address + layout.size() - block_address,
+                self.alignment,
+            )
+            .max(self.min_block_size);
+
+            if block.size < required_size {
+                previous = offset;
+                continue;
+            }
+
+            let remaining_size = block.size - required_size;
+            let (block_size, next) = if self.min_block_size <= remaining_size {
+                let remainder = offset + required_size;
+                unsafe {
+                    self.write_free_block(
+                        remainder,
+                        FreeBlock {
+                            size: remaining_size,
+                            next: block.next,
+                        },
+                    )
+                };
+                (required_size, remainder)
+            } else {
+                (block.size, block.next)
+            };
+
+            unsafe {
+                self.set_next(previous, next);
+                ((chunk_address - core::mem::size_of::<AllocationHeader>())
+                    as *mut AllocationHeader)
+                    .write(AllocationHeader {
+                        block_start: offset,
+                        block_size,
+                    });
+            }
+
+            return Ok(NonNull::slice_from_raw_parts(
+                unsafe { NonNull::new_unchecked(chunk_address as *mut u8) },
+                layout.size(),
+            ));
+        }
+
+        fail!(from self, with AllocationError::OutOfMemory,
+            "Unable to allocate {} bytes with an alignment of {} since no free block is large enough.",
+                layout.size(), layout.align());
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, _layout: Layout) {
+        self.verify_init("deallocate");
+        self.verify_ptr_is_managed_by_allocator(ptr);
+
+        let header = ((ptr.as_ptr() as usize - core::mem::size_of::<AllocationHeader>())
+            as *const AllocationHeader)
+            .read();
+        let offset = header.block_start;
+
+        let mut previous = INVALID;
+        let mut next = self.free_list_head.get();
+        while next != INVALID && next < offset {
+            previous = next;
+            next = self.read_free_block(next).next;
+        }
+
+        debug_assert!(
+            next != offset,
+            "The pointer {ptr:?} was already deallocated."
+        );
+
+        let mut block = FreeBlock {
+            size: header.block_size,
+            next,
+        };
+
+        if next != INVALID && offset + block.size == next {
+            let next_block = self.read_free_block(next);
+            block.size += next_block.size;
+            block.next = next_block.next;
+        }
+
+        if previous != INVALID {
+            let mut previous_block = self.read_free_block(previous);
+            if previous + previous_block.size == offset {
+                previous_block.size += block.size;
+                previous_block.next = block.next;
+                self.write_free_block(previous, previous_block);
+                return;
+            }
+        }
+
+        self.write_free_block(offset, block);
+        self.set_next(previous, offset);
+    }
+}
@@ -15,6 +15,7 @@
 #![warn(clippy::std_instead_of_core)]
 
 pub mod bump_allocator;
+pub mod free_list_allocator;
 pub mod heap_allocator;
 pub mod one_chunk_allocator;
 pub mod pool_allocator;
@@ -0,0 +1,315 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_memory::{bump_allocator::BumpAllocator, free_list_allocator::*};
+use iceoryx2_bb_testing::assert_that;
+
+const MEMORY_SIZE: usize = 1024;
+const MIN_BLOCK_SIZE: usize = 32;
+const ALIGNMENT: usize = 8;
+const HEADER_SIZE: usize = 16;
+
+struct TestFixture {
+    raw_memory: [u8; MEMORY_SIZE + ALIGNMENT],
+}
+
+impl TestFixture {
+    fn new() -> Self {
+        Self {
+            raw_memory: [0xff; MEMORY_SIZE + ALIGNMENT],
+        }
+    }
+
+    fn bump_allocator(&mut self) -> BumpAllocator {
+        BumpAllocator::new(
+            NonNull::new(self.raw_memory.as_mut_ptr()).unwrap(),
+            self.raw_memory.len(),
+        )
+    }
+}
+
+fn layout(size: usize) -> Layout {
+    Layout::from_size_align(size, 1).unwrap()
+}
+
+fn allocate(sut: &FreeListAllocator, size: usize) -> NonNull<u8> {
+    let memory = sut.allocate(layout(size)).unwrap();
+    NonNull::new(memory.as_ptr() as *mut u8).unwrap()
+}
+
+#[test]
+fn free_list_allocator_is_set_up_correctly() {
+    let mut test = TestFixture::new();
+    let mut sut = unsafe { FreeListAllocator::new_uninit(MEMORY_SIZE, MIN_BLOCK_SIZE, ALIGNMENT) };
+    assert_that!(unsafe { sut.init(&test.bump_allocator()) }, is_ok);
+
+    assert_that!(sut.size(), eq MEMORY_SIZE);
+    assert_that!(sut.min_block_size(), eq MIN_BLOCK_SIZE);
+    assert_that!(sut.alignment(), eq ALIGNMENT);
+    assert_that!(sut.total_free(), eq MEMORY_SIZE);
+    assert_that!(sut.largest_free_block(), eq MEMORY_SIZE);
+}
+
+#[test]
+fn free_list_allocator_adjusts_too_small_configuration() {
+    let mut test = TestFixture::new();
+    let mut sut = unsafe { FreeListAllocator::new_uninit(MEMORY_SIZE - 3, 1, 1) };
+    assert_that!(unsafe { sut.init(&test.bump_allocator()) }, is_ok);
+
+    assert_that!(sut.alignment(), eq ALIGNMENT);
+    assert_that!(sut.min_block_size(), eq HEADER_SIZE);
+    assert_that!(sut.size(), eq MEMORY_SIZE - ALIGNMENT);
+}
+
+#[test]
+fn free_list_allocator_init_fails_when_memory_is_insufficient() {
+    let mut test = TestFixture::new();
+    let mut sut =
+        unsafe { FreeListAllocator::new_uninit(2 * MEMORY_SIZE, MIN_BLOCK_SIZE, ALIGNMENT) };
+
+    assert_that!(unsafe { sut.init(&test.bump_allocator()) }, is_err);
+}
+
+#[test]
+fn free_list_allocator_allocate_with_size_zero_fails() {
+    let mut test = TestFixture::new();
+    let mut sut = unsafe { FreeListAllocator::new_uninit(MEMORY_SIZE, MIN_BLOCK_SIZE, ALIGNMENT) };
+    assert_that!(unsafe { sut.init(&test.bump_allocator()) }, is_ok);
+
+    let result = sut.allocate(layout(0));
+    assert_that!(result, is_err);
+    assert_that!(result.err().unwrap(), eq AllocationError::SizeIsZero);
+}
+
+#[test]
+fn free_list_allocator_allocates_memory_with_requested_alignment() {
+    let mut test = TestFixture::new();
+    let mut sut = unsafe { FreeListAllocator::new_uninit(MEMORY_SIZE, MIN_BLOCK_SIZE, ALIGNMENT) };
+    assert_that!(unsafe { sut.init(&test.bump_allocator()) }, is_ok);
+
+    for alignment in [1, 2, 4, 8, 16, 32, 64, 128] {
+        let memory = sut
+            .allocate(Layout::from_size_align(5, alignment).unwrap())
+            .unwrap();
+        assert_that!(memory.len(), eq 5);
+        assert_that!(memory.as_ptr() as *const u8 as usize % alignment, eq 0);
+    }
+}
+
+#[test]
+fn free_list_allocator_allocated_chunks_do_not_overlap() {
+    const NUMBER_OF_CHUNKS: usize = 8;
+    const CHUNK_SIZE: usize = 50;
+    let mut test = TestFixture::new();
+    let mut sut = unsafe { FreeListAllocator::new_uninit(MEMORY_SIZE, MIN_BLOCK_SIZE, ALIGNMENT) };
+    assert_that!(unsafe { sut.init(&test.bump_allocator()) }, is_ok);
+
+    let mut chunks = vec![];
+    for i in 0..NUMBER_OF_CHUNKS {
+        let chunk = allocate(&sut, CHUNK_SIZE);
+        unsafe { core::ptr::write_bytes(chunk.as_ptr(), i as u8, CHUNK_SIZE) };
+        chunks.push(chunk);
+    }
+
+    for (i, chunk) in chunks.iter().enumerate() {
+        let content = unsafe { core::slice::from_raw_parts(chunk.as_ptr(), CHUNK_SIZE) };
+        assert_that!(content.iter().all(|v| *v == i as u8), eq true);
+    }
+}
+
+#[test]
+fn free_list_allocator_allocate_fails_when_memory_is_exhausted() {
+    let mut test = TestFixture::new();
+    let mut sut = unsafe { FreeListAllocator::new_uninit(MEMORY_SIZE, MIN_BLOCK_SIZE, ALIGNMENT) };
+    assert_that!(unsafe { sut.init(&test.bump_allocator()) }, is_ok);
+
+    let result = sut.allocate(layout(MEMORY_SIZE));
+    assert_that!(result.err(), eq Some(AllocationError::OutOfMemory));
+
+    allocate(&sut, MEMORY_SIZE - HEADER_SIZE);
+    assert_that!(sut.total_free(), eq 0);
+    assert_that!(sut.largest_free_block(), eq 0);
+
+    let result = sut.allocate(layout(1));
+    assert_that!(result.err(), eq Some(AllocationError::OutOfMemory));
+}
+
+#[test]
+fn free_list_allocator_uses_at_least_min_block_size() {
+    let mut test = TestFixture::new();
+    let mut sut = unsafe { FreeListAllocator::new_uninit(MEMORY_SIZE, MIN_BLOCK_SIZE, ALIGNMENT) };
+    assert_that!(unsafe { sut.init(&test.bump_allocator()) }, is_ok);
+
+    allocate(&sut, 1);
+    assert_that!(sut.total_free(), eq MEMORY_SIZE - MIN_BLOCK_SIZE);
+}
+
+#[test]
+fn free_list_allocator_does_not_split_off_blocks_smaller_than_min_block_size() {
+    let mut test = TestFixture::new();
+    let mut sut = unsafe { FreeListAllocator::new_uninit(MEMORY_SIZE, MIN_BLOCK_SIZE, ALIGNMENT) };
+    assert_that!(unsafe { sut.init(&test.bump_allocator()) }, is_ok);
+
+    allocate(&sut, MEMORY_SIZE - HEADER_SIZE - MIN_BLOCK_SIZE + 1);
+    assert_that!(sut.total_free(), eq 0);
+}
+
+#[test]
+fn free_list_allocator_deallocate_returns_memory() {
+    let mut test = TestFixture::new();
+    let mut sut = unsafe { FreeListAllocator::new_uninit(MEMORY_SIZE, MIN_BLOCK_SIZE, ALIGNMENT) };
+    assert_that!(unsafe { sut.init(&test.bump_allocator()) }, is_ok);
+
+    for _ in 0..10 {
+        let chunk = allocate(&sut, MEMORY_SIZE - HEADER_SIZE);
+        assert_that!(sut.total_free(), eq 0);
+        unsafe { sut.deallocate(chunk, layout(MEMORY_SIZE - HEADER_SIZE)) };
+        assert_that!(sut.total_free(), eq MEMORY_SIZE);
+    }
+}
+
+#[test]
+fn free_list_allocator_coalesces_with_next_block() {
+    const CHUNK_SIZE: usize = MEMORY_SIZE / 4 - HEADER_SIZE;
+    let mut test = TestFixture::new();
+    let mut sut = unsafe { FreeListAllocator::new_uninit(MEMORY_SIZE, MIN_BLOCK_SIZE, ALIGNMENT) };
+    assert_that!(unsafe { sut.init(&test.bump_allocator()) }, is_ok);
+
+    let chunks: Vec<_> = (0..4).map(|_| allocate(&sut, CHUNK_SIZE)).collect();
+    assert_that!(sut.total_free(), eq 0);
+
+    unsafe { sut.deallocate(chunks[2], layout(CHUNK_SIZE)) };
+    unsafe { sut.deallocate(chunks[1], layout(CHUNK_SIZE)) };
+
+    // succeeds only when the two adjacent blocks were merged
+    assert_that!(sut.largest_free_block(), eq MEMORY_SIZE / 2);
+    assert_that!(sut.allocate(layout(MEMORY_SIZE / 2 - HEADER_SIZE)), is_ok);
+}
+
+#[test]
+fn free_list_allocator_coalesces_with_previous_block() {
+    const CHUNK_SIZE: usize = MEMORY_SIZE / 4 - HEADER_SIZE;
+    let mut test = TestFixture::new();
+    let mut sut = unsafe { FreeListAllocator::new_uninit(MEMORY_SIZE, MIN_BLOCK_SIZE, ALIGNMENT) };
+    assert_that!(unsafe { sut.init(&test.bump_allocator()) }, is_ok);
+
+    let chunks: Vec<_> = (0..4).map(|_| allocate(&sut, CHUNK_SIZE)).collect();
+
+    unsafe { sut.deallocate(chunks[1], layout(CHUNK_SIZE)) };
+    unsafe { sut.deallocate(chunks[2], layout(CHUNK_SIZE)) };
+
+    assert_that!(sut.largest_free_block(), eq MEMORY_SIZE / 2);
+    assert_that!(sut.allocate(layout(MEMORY_SIZE / 2 - HEADER_SIZE)), is_ok);
+}
+
+#[test]
+fn free_list_allocator_coalesces_with_previous_and_next_block() {
+    const CHUNK_SIZE: usize = MEMORY_SIZE / 4 - HEADER_SIZE;
+    let mut test = TestFixture::new();
+    let mut sut = unsafe { FreeListAllocator::new_uninit(MEMORY_SIZE, MIN_BLOCK_SIZE, ALIGNMENT) };
+    assert_that!(unsafe { sut.init(&test.bump_allocator()) }, is_ok);
+
+    let chunks: Vec<_> = (0..4).map(|_| allocate(&sut, CHUNK_SIZE)).collect();
+
+    unsafe { sut.deallocate(chunks[0], layout(CHUNK_SIZE)) };
+    unsafe { sut.deallocate(chunks[2], layout(CHUNK_SIZE)) };
+    assert_that!(sut.largest_free_block(), eq MEMORY_SIZE / 4);
+
+    unsafe { sut.deallocate(chunks[1], layout(CHUNK_SIZE)) };
+    assert_that!(sut.largest_free_block(), eq 3 * MEMORY_SIZE / 4);
+    assert_that!(
+        sut.allocate(layout(3 * MEMORY_SIZE / 4 - HEADER_SIZE)),
+        is_ok
+    );
+}
+
+#[test]
+fn free_list_allocator_fragmented_memory_is_restored_when_all_chunks_are_released() {
+    const NUMBER_OF_CHUNKS: usize = MEMORY_SIZE / MIN_BLOCK_SIZE;
+    const CHUNK_SIZE: usize = MIN_BLOCK_SIZE - HEADER_SIZE;
+    let mut test = TestFixture::new();
+    let mut sut = unsafe { FreeListAllocator::new_uninit(MEMORY_SIZE, MIN_BLOCK_SIZE, ALIGNMENT) };
+    assert_that!(unsafe { sut.init(&test.bump_allocator()) }, is_ok);
+
+    let chunks: Vec<_> = (0..NUMBER_OF_CHUNKS)
+        .map(|_| allocate(&sut, CHUNK_SIZE))
+        .collect();
+    assert_that!(sut.total_free(), eq 0);
+
+    for chunk in chunks.iter().step_by(2) {
+        unsafe { sut.deallocate(*chunk, layout(CHUNK_SIZE)) };
+    }
+
+    assert_that!(sut.total_free(), eq MEMORY_SIZE / 2);
+    assert_that!(sut.largest_free_block(), eq MIN_BLOCK_SIZE);
+    assert_that!(sut.allocate(layout(MIN_BLOCK_SIZE)).err(), eq Some(AllocationError::OutOfMemory));
+
+    for chunk in chunks.iter().skip(1).step_by(2) {
+        unsafe { sut.deallocate(*chunk, layout(CHUNK_SIZE)) };
+    }
+
+    assert_that!(sut.total_free(), eq MEMORY_SIZE);
+    assert_that!(sut.largest_free_block(), eq MEMORY_SIZE);
+    assert_that!(sut.allocate(layout(MEMORY_SIZE - HEADER_SIZE)), is_ok);
+}
+
+#[test]
+fn free_list_allocator_releasing_chunks_in_reverse_order_restores_memory() {
+    const NUMBER_OF_CHUNKS: usize = 7;
+    let mut test = TestFixture::new();
+    let mut sut = unsafe { FreeListAllocator::new_uninit(MEMORY_SIZE, MIN_BLOCK_SIZE, ALIGNMENT) };
+    assert_that!(unsafe { sut.init(&test.bump_allocator()) }, is_ok);
+
+    let chunks: Vec<_> = (1..=NUMBER_OF_CHUNKS)
+        .map(|n| (allocate(&sut, n * 11), n * 11))
+        .collect();
+
+    for (chunk, size) in chunks.iter().rev() {
+        unsafe { sut.deallocate(*chunk, layout(*size)) };
+    }
+
+    assert_that!(sut.total_free(), eq MEMORY_SIZE);
+    assert_that!(sut.largest_free_block(), eq MEMORY_SIZE);
+}
+
+#[test]
+fn free_list_allocator_is_relocatable() {
+    #[repr(C)]
+    struct Segment {
+        allocator: FreeListAllocator,
+        memory: [u8; MEMORY_SIZE + ALIGNMENT],
+    }
+
+    let mut original = Box::new(Segment {
+        allocator: unsafe { FreeListAllocator::new_uninit(MEMORY_SIZE, MIN_BLOCK_SIZE, ALIGNMENT) },
+        memory: [0; MEMORY_SIZE + ALIGNMENT],
+    });
+    let bump_allocator = BumpAllocator::new(
+        NonNull::new(original.memory.as_mut_ptr()).unwrap(),
+        original.memory.len(),
+    );
+    assert_that!(unsafe { original.allocator.init(&bump_allocator) }, is_ok);
+    let chunk = allocate(&original.allocator, 100);
+    let chunk_offset = chunk.as_ptr() as usize - &*original as *const Segment as usize;
+
+    let mut relocated = core::mem::MaybeUninit::<Segment>::uninit();
+    unsafe {
+        core::ptr::copy_nonoverlapping(&*original as *const Segment, relocated.as_mut_ptr(), 1)
+    };
+    let relocated = unsafe { relocated.assume_init_ref() };
+
+    let relocated_chunk =
+        NonNull::new((relocated as *const Segment as usize + chunk_offset) as *mut u8).unwrap();
+    unsafe { relocated.allocator.deallocate(relocated_chunk, layout(100)) };
+
+    assert_that!(relocated.allocator.total_free(), eq MEMORY_SIZE);
+}
@@ -0,0 +1,606 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Provides the [`AccessControlList`] (ACL), an extension of the [`Permission`] of a file
+//! descriptor based construct which grants access to further users and groups. It can be
+//! applied and read with
+//! [`FileDescriptorManagement::set_access_control_list()`](crate::file_descriptor::FileDescriptorManagement::set_access_control_list())
+//! and
+//! [`FileDescriptorManagement::access_control_list()`](crate::file_descriptor::FileDescriptorManagement::access_control_list()).
+//!
+//! An [`AccessControlList`] requires an entry for the [`AclQualifier::OwningUser`], the
+//! [`AclQualifier::OwningGroup`] and [`AclQualifier::Others`], which correspond to the
+//! [`Permission`] bits. As soon as it contains entries for further users or groups, the
+//! [`AclQualifier::Mask`] limits their permissions and replaces the group bits of the
+//! [`Permission`].
+//!
+//! Requires [`POSIX_SUPPORT_ACL`](iceoryx2_pal_posix::posix::POSIX_SUPPORT_ACL) and a file
+//! system with ACL support, otherwise the operations fail with an `Unsupported` error.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2_bb_posix::access_control_list::*;
+//! use iceoryx2_bb_posix::file_descriptor::*;
+//! use iceoryx2_bb_posix::permission::Permission;
+//! use iceoryx2_bb_posix::user::Uid;
+//! use iceoryx2_bb_posix::shared_memory::*;
+//! use iceoryx2_bb_system_types::file_name::FileName;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//!
+//! let mut acl = AccessControlList::from_permission(Permission::OWNER_ALL);
+//! acl.set(AclQualifier::User(Uid::new(1001).unwrap()), AclPermission::READ | AclPermission::WRITE)
+//!    .unwrap();
+//! acl.calculate_mask().unwrap();
+//!
+//! let mut shm = SharedMemoryBuilder::new(&FileName::new(b"someShmName").unwrap())
+//!                     .creation_mode(CreationMode::PurgeAndCreate)
+//!                     .size(1024)
+//!                     .create()
+//!                     .unwrap();
+//! shm.set_access_control_list(&acl).unwrap();
+//!
+//! println!("{}", shm.access_control_list().unwrap());
+//! ```
+
+use core::fmt::{Debug, Display};
+use core::ops::{BitOr, BitOrAssign};
+
+use crate::config::ACL_LIST_CAPACITY;
+use crate::file_descriptor::FileDescriptorBased;
+use crate::group::Gid;
+use crate::handle_errno;
+use crate::permission::Permission;
+use crate::user::Uid;
+use iceoryx2_bb_log::fail;
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::*;
+
+/// Failures of [`AccessControlList::set()`] and [`AccessControlList::from_text()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum AccessControlListCreationError {
+    EntryLimitExceeded,
+    InvalidTextFormat,
+}
+
+impl core::fmt::Display for AccessControlListCreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "AccessControlListCreationError::{self:?}")
+    }
+}
+
+impl core::error::Error for AccessControlListCreationError {}
+
+/// Failures when an [`AccessControlList`] is applied to a file descriptor.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum AccessControlListApplyError {
+    Unsupported,
+    InvalidAccessControlList,
+    InvalidFileDescriptor,
+    InsufficientPermissions,
+    InsufficientMemory,
+    InsufficientSpace,
+    ReadOnlyFilesystem,
+    UnknownError(i32),
+}
+
+impl core::fmt::Display for AccessControlListApplyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "AccessControlListApplyError::{self:?}")
+    }
+}
+
+impl core::error::Error for AccessControlListApplyError {}
+
+/// Failures when the [`AccessControlList`] of a file descriptor is acquired.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum AccessControlListAcquireError {
+    Unsupported,
+    InvalidFileDescriptor,
+    InsufficientMemory,
+    InvalidAccessControlList,
+    UnknownError(i32),
+}
+
+impl core::fmt::Display for AccessControlListAcquireError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "AccessControlListAcquireError::{self:?}")
+    }
+}
+
+impl core::error::Error for AccessControlListAcquireError {}
+
+/// The permission of an [`AclEntry`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AclPermission(u8);
+
+impl AclPermission {
+    pub const READ: Self = Self(0b100);
+    pub const WRITE: Self = Self(0b010);
+    pub const EXECUTE: Self = Self(0b001);
+    pub const ALL: Self = Self(0b111);
+
+    pub fn none() -> Self {
+        Self(0)
+    }
+
+    /// Returns true when self contains the permissions of the rhs, otherwise false.
+    pub fn has(&self, rhs: AclPermission) -> bool {
+        (self.0 & rhs.0) != 0
+    }
+
+    fn from_bits(value: posix::mode_t) -> Self {
+        Self((value & Self::ALL.0 as posix::mode_t) as u8)
+    }
+
+    fn intersection(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        if value.is_empty() || value.len() > 3 {
+            return None;
+        }
+
+        let mut permission = Self::none();
+        for c in value.chars() {
+            match c {
+                'r' => permission |= Self::READ,
+                'w' => permission |= Self::WRITE,
+                'x' => permission |= Self::EXECUTE,
+                '-' => (),
+                _ => return None,
+            }
+        }
+
+        Some(permission)
+    }
+}
+
+impl BitOrAssign for AclPermission {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitOr for AclPermission {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl Display for AclPermission {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}{}{}",
+            if self.has(Self::READ) { 'r' } else { '-' },
+            if self.has(Self::WRITE) { 'w' } else { '-' },
+            if self.has(Self::EXECUTE) { 'x' } else { '-' }
+        )
+    }
+}
+
+/// Defines to whom the [`AclPermission`] of an [`AclEntry`] applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AclQualifier {
+    /// The owner of the file, corresponds to the owner bits of the [`Permission`].
+    OwningUser,
+    /// A specific user.
+    User(Uid),
+    /// The group owner of the file, corresponds to the group bits of the [`Permission`] when
+    /// no [`AclQualifier::Mask`] is present.
+    OwningGroup,
+    /// A specific group.
+    Group(Gid),
+    /// The upper bound of the permissions of [`AclQualifier::User`],
+    /// [`AclQualifier::OwningGroup`] and [`AclQualifier::Group`].
+    Mask,
+    /// Everyone else, corresponds to the others bits of the [`Permission`].
+    Others,
+}
+
+impl AclQualifier {
+    // the canonical order of the entries, it is also required by some platforms
+    fn order(&self) -> (u8, u32) {
+        match self {
+            AclQualifier::OwningUser => (0, 0),
+            AclQualifier::User(uid) => (1, uid.value()),
+            AclQualifier::OwningGroup => (2, 0),
+            AclQualifier::Group(gid) => (3, gid.value()),
+            AclQualifier::Mask => (4, 0),
+            AclQualifier::Others => (5, 0),
+        }
+    }
+
+    fn is_limited_by_mask(&self) -> bool {
+        matches!(
+            self,
+            AclQualifier::User(_) | AclQualifier::OwningGroup | AclQualifier::Group(_)
+        )
+    }
+
+    fn parse(tag: &str, qualifier: &str) -> Option<Self> {
+        match (tag, qualifier.is_empty()) {
+            ("user" | "u", true) => Some(AclQualifier::OwningUser),
+            ("user" | "u", false) => Some(AclQualifier::User(Uid::new(qualifier.parse().ok()?)?)),
+            ("group" | "g", true) => Some(AclQualifier::OwningGroup),
+            ("group" | "g", false) => Some(AclQualifier::Group(Gid::new(qualifier.parse().ok()?)?)),
+            ("mask" | "m", true) => Some(AclQualifier::Mask),
+            ("other" | "o", true) => Some(AclQualifier::Others),
+            _ => None,
+        }
+    }
+}
+
+impl Display for AclQualifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AclQualifier::OwningUser => write!(f, "user:"),
+            AclQualifier::User(uid) => write!(f, "user:{uid}"),
+            AclQualifier::OwningGroup => write!(f, "group:"),
+            AclQualifier::Group(gid) => write!(f, "group:{gid}"),
+            AclQualifier::Mask => write!(f, "mask:"),
+            AclQualifier::Others => write!(f, "other:"),
+        }
+    }
+}
+
+/// A single entry of an [`AccessControlList`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AclEntry {
+    qualifier: AclQualifier,
+    permission: AclPermission,
+}
+
+impl AclEntry {
+    /// Returns the [`AclQualifier`] of the entry.
+    pub fn qualifier(&self) -> AclQualifier {
+        self.qualifier
+    }
+
+    /// Returns the [`AclPermission`] of the entry.
+    pub fn permission(&self) -> AclPermission {
+        self.permission
+    }
+}
+
+impl Display for AclEntry {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}:{}", self.qualifier, self.permission)
+    }
+}
+
+/// An access control list, consisting of at most
+/// [`ACL_LIST_CAPACITY`](crate::config::ACL_LIST_CAPACITY) [`AclEntry`]s. The entries are
+/// always stored in their canonical order. The [`Display`] implementation and
+/// [`AccessControlList::from_text()`] use the POSIX.1e long text form with numeric ids, e.g.
+///
+/// ```text
+/// user::rw-
+/// user:1001:r--
+/// group::---
+/// mask::r--
+/// other::---
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccessControlList {
+    entries: Vec<AclEntry>,
+}
+
+impl AccessControlList {
+    /// Creates an empty [`AccessControlList`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates the minimal [`AccessControlList`] which is equivalent to the provided
+    /// [`Permission`].
+    pub fn from_permission(permission: Permission) -> Self {
+        let mode = permission.bits();
+        Self {
+            entries: vec![
+                AclEntry {
+                    qualifier: AclQualifier::OwningUser,
+                    permission: AclPermission::from_bits(mode >> 6),
+                },
+                AclEntry {
+                    qualifier: AclQualifier::OwningGroup,
+                    permission: AclPermission::from_bits(mode >> 3),
+                },
+                AclEntry {
+                    qualifier: AclQualifier::Others,
+                    permission: AclPermission::from_bits(mode),
+                },
+            ],
+        }
+    }
+
+    /// Parses the POSIX.1e text form of an [`AccessControlList`]. Entries are separated by
+    /// commas or newlines, comments start with `#` and users and groups must be provided as
+    /// numeric ids. Besides the long tags `user`, `group`, `mask` and `other` the short tags
+    /// `u`, `g`, `m` and `o` are accepted.
+    pub fn from_text(text: &str) -> Result<Self, AccessControlListCreationError> {
+        let msg = "Unable to parse access control list";
+        let origin = "AccessControlList::from_text()";
+        let mut acl = Self::new();
+
+        for line in text.lines() {
+            let line = line.split('#').next().unwrap_or_default();
+            for entry in line.split(',').map(|e| e.trim()).filter(|e| !e.is_empty()) {
+                let fields: Vec<&str> = entry.split(':').map(|f| f.trim()).collect();
+                let (tag, qualifier, permission) = match fields.len() {
+                    3 => (fields[0], fields[1], fields[2]),
+                    // the short form of mask and other entries omits the empty qualifier
+                    2 => (fields[0], "", fields[1]),
+                    _ => {
+                        fail!(from origin, with AccessControlListCreationError::InvalidTextFormat,
+                            "{} since the entry \"{}\" does not consist of a tag, a qualifier and a permission.", msg, entry);
+                    }
+                };
+
+                let qualifier = match AclQualifier::parse(tag, qualifier) {
+                    Some(qualifier) => qualifier,
+                    None => {
+                        fail!(from origin, with AccessControlListCreationError::InvalidTextFormat,
+                            "{} since the entry \"{}\" contains an invalid tag or qualifier.", msg, entry);
+                    }
+                };
+
+                let permission = match AclPermission::parse(permission) {
+                    Some(permission) => permission,
+                    None => {
+                        fail!(from origin, with AccessControlListCreationError::InvalidTextFormat,
+                            "{} since the entry \"{}\" contains an invalid permission.", msg, entry);
+                    }
+                };
+
+                fail!(from origin, when acl.set(qualifier, permission),
+                    "{} since the entry \"{}\" could not be added.", msg, entry);
+            }
+        }
+
+        Ok(acl)
+    }
+
+    /// Adds an [`AclEntry`] or replaces the [`AclPermission`] when an entry with the same
+    /// [`AclQualifier`] already exists.
+    pub fn set(
+        &mut self,
+        qualifier: AclQualifier,
+        permission: AclPermission,
+    ) -> Result<(), AccessControlListCreationError> {
+        match self
+            .entries
+            .binary_search_by_key(&qualifier.order(), |e| e.qualifier.order())
+        {
+            Ok(index) => self.entries[index].permission = permission,
+            Err(index) => {
+                if self.entries.len() >= ACL_LIST_CAPACITY as usize {
+                    fail!(from self, with AccessControlListCreationError::EntryLimitExceeded,
+                        "Unable to add the entry \"{}:{}\" since the access control list already contains the maximum of {} entries.",
+                        qualifier, permission, ACL_LIST_CAPACITY);
+                }
+
+                self.entries.insert(
+                    index,
+                    AclEntry {
+                        qualifier,
+                        permission,
+                    },
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the entry of the [`AclQualifier`] and returns its [`AclPermission`]. If the
+    /// entry does not exist it returns [`None`].
+    pub fn remove(&mut self, qualifier: AclQualifier) -> Option<AclPermission> {
+        self.entries
+            .binary_search_by_key(&qualifier.order(), |e| e.qualifier.order())
+            .ok()
+            .map(|index| self.entries.remove(index).permission)
+    }
+
+    /// Returns the [`AclPermission`] of the [`AclQualifier`] if an entry exists.
+    pub fn get(&self, qualifier: AclQualifier) -> Option<AclPermission> {
+        self.entries
+            .binary_search_by_key(&qualifier.order(), |e| e.qualifier.order())
+            .ok()
+            .map(|index| self.entries[index].permission)
+    }
+
+    /// Returns the [`AclPermission`] of the [`AclQualifier`] that is actually granted, meaning
+    /// limited by the [`AclQualifier::Mask`] if one is present.
+    pub fn effective_permission(&self, qualifier: AclQualifier) -> Option<AclPermission> {
+        let permission = self.get(qualifier)?;
+        match self.get(AclQualifier::Mask) {
+            Some(mask) if qualifier.is_limited_by_mask() => Some(permission.intersection(mask)),
+            _ => Some(permission),
+        }
+    }
+
+    /// Returns all entries in their canonical order.
+    pub fn entries(&self) -> &[AclEntry] {
+        &self.entries
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the [`AccessControlList`] does not contain any entry, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns true when the [`AccessControlList`] can be applied, meaning it contains an
+    /// entry for the owning user, the owning group and others and a mask when entries for
+    /// further users or groups exist.
+    pub fn is_valid(&self) -> bool {
+        self.get(AclQualifier::OwningUser).is_some()
+            && self.get(AclQualifier::OwningGroup).is_some()
+            && self.get(AclQualifier::Others).is_some()
+            && (!self.has_named_entries() || self.get(AclQualifier::Mask).is_some())
+    }
+
+    /// Sets the [`AclQualifier::Mask`] to the union of all permissions it limits so that
+    /// every entry is effective. If the [`AccessControlList`] contains no entries for further
+    /// users or groups, no mask is required and the mask is not modified.
+    pub fn calculate_mask(&mut self) -> Result<(), AccessControlListCreationError> {
+        if !self.has_named_entries() {
+            return Ok(());
+        }
+
+        let mask = self
+            .entries
+            .iter()
+            .filter(|e| e.qualifier.is_limited_by_mask())
+            .fold(AclPermission::none(), |mask, e| mask | e.permission);
+
+        fail!(from self, when self.set(AclQualifier::Mask, mask),
+            "Unable to calculate the mask of the access control list.");
+        Ok(())
+    }
+
+    /// Replaces the entries of the owning user, the owning group and others with the
+    /// corresponding bits of the [`Permission`] and recalculates the mask, see
+    /// [`AccessControlList::calculate_mask()`]. The entries for further users and groups are
+    /// kept.
+    pub fn merge_permission(
+        &mut self,
+        permission: Permission,
+    ) -> Result<(), AccessControlListCreationError> {
+        for entry in Self::from_permission(permission).entries {
+            fail!(from self, when self.set(entry.qualifier, entry.permission),
+                "Unable to merge the permission {} into the access control list.", permission);
+        }
+
+        self.calculate_mask()
+    }
+
+    fn has_named_entries(&self) -> bool {
+        self.entries
+            .iter()
+            .any(|e| matches!(e.qualifier, AclQualifier::User(_) | AclQualifier::Group(_)))
+    }
+
+    pub(crate) fn apply<T: FileDescriptorBased + Debug>(
+        &self,
+        this: &T,
+    ) -> Result<(), AccessControlListApplyError> {
+        let msg = "Unable to apply access control list";
+        if !posix::POSIX_SUPPORT_ACL {
+            fail!(from this, with AccessControlListApplyError::Unsupported,
+                "{} since the platform does not support access control lists.", msg);
+        }
+
+        if !self.is_valid() {
+            fail!(from this, with AccessControlListApplyError::InvalidAccessControlList,
+                "{} since it requires entries for the owning user, the owning group and others and a mask when it contains entries for further users or groups.", msg);
+        }
+
+        let mut text = self.to_string().into_bytes();
+        text.push(0);
+        let acl = AclHandle(unsafe { posix::acl_from_text(text.as_ptr().cast()) }.cast());
+        if acl.0.is_null() {
+            handle_errno!(AccessControlListApplyError, from this,
+                Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+                Errno::EINVAL => (InvalidAccessControlList, "{} since the platform rejected its text form.", msg),
+                Errno::ENOTSUP => (Unsupported, "{} since the platform does not support access control lists.", msg),
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+
+        if unsafe { posix::acl_set_fd(this.file_descriptor().native_handle(), acl.0.cast()) } == 0 {
+            return Ok(());
+        }
+
+        handle_errno!(AccessControlListApplyError, from this,
+            Errno::EBADF => (InvalidFileDescriptor, "{} since an invalid file-descriptor was provided.", msg),
+            Errno::EPERM => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+            Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+            Errno::EINVAL => (InvalidAccessControlList, "{} since the file system rejected it.", msg),
+            Errno::ENOSPC => (InsufficientSpace, "{} since there is not enough space left to store it.", msg),
+            Errno::EROFS => (ReadOnlyFilesystem, "{} since the file resides on a read-only file system.", msg),
+            Errno::ENOTSUP => (Unsupported, "{} since the file system does not support access control lists.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
+    pub(crate) fn acquire<T: FileDescriptorBased + Debug>(
+        this: &T,
+    ) -> Result<AccessControlList, AccessControlListAcquireError> {
+        let msg = "Unable to acquire access control list";
+        if !posix::POSIX_SUPPORT_ACL {
+            fail!(from this, with AccessControlListAcquireError::Unsupported,
+                "{} since the platform does not support access control lists.", msg);
+        }
+
+        let acl =
+            AclHandle(unsafe { posix::acl_get_fd(this.file_descriptor().native_handle()) }.cast());
+        if acl.0.is_null() {
+            handle_errno!(AccessControlListAcquireError, from this,
+                Errno::EBADF => (InvalidFileDescriptor, "{} since an invalid file-descriptor was provided.", msg),
+                Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+                Errno::EINVAL => (Unsupported, "{} since the file system does not support POSIX access control lists.", msg),
+                Errno::ENOTSUP => (Unsupported, "{} since the file system does not support access control lists.", msg),
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+
+        let text = unsafe { posix::acl_to_text(acl.0.cast(), core::ptr::null_mut()) };
+        if text.is_null() {
+            handle_errno!(AccessControlListAcquireError, from this,
+                Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+                Errno::EINVAL => (InvalidAccessControlList, "{} since it could not be converted into its text form.", msg),
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+        let text = AclHandle(text.cast());
+
+        let acl = match unsafe { core::ffi::CStr::from_ptr(text.0.cast()) }.to_str() {
+            Ok(text) => Self::from_text(text),
+            Err(_) => Err(AccessControlListCreationError::InvalidTextFormat),
+        };
+
+        Ok(
+            fail!(from this, when acl, with AccessControlListAcquireError::InvalidAccessControlList,
+                "{} since its text form could not be parsed.", msg),
+        )
+    }
+}
+
+impl Display for AccessControlList {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for entry in &self.entries {
+            writeln!(f, "{entry}")?;
+        }
+        Ok(())
+    }
+}
+
+// releases the objects that are returned by the acl functions
+struct AclHandle(*mut posix::void);
+
+impl Drop for AclHandle {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            unsafe { posix::acl_free(self.0) };
+        }
+    }
+}
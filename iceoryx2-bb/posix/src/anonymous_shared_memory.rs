@@ -0,0 +1,305 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Provides an [`AnonymousSharedMemory`] object that is not visible in the file system and
+//! is only accessible via its [`FileDescriptor`]. It is shared with other processes by sending
+//! the [`FileDescriptor`], for instance with
+//! [`UnixDatagramSender::try_send_with_fds()`](crate::unix_datagram_socket::UnixDatagramSender::try_send_with_fds()).
+//! The memory is released by the operating system as soon as the last [`FileDescriptor`] is
+//! closed and the last mapping is removed, therefore it never outlives a crashed process.
+//!
+//! Requires [`POSIX_SUPPORT_ANONYMOUS_SHARED_MEMORY`], otherwise the creation fails with
+//! [`AnonymousSharedMemoryCreationError::NotSupported`].
+//!
+//! # Example
+//!
+//! ```
+//! # use iceoryx2_pal_posix::posix::POSIX_SUPPORT_ANONYMOUS_SHARED_MEMORY;
+//! # if !POSIX_SUPPORT_ANONYMOUS_SHARED_MEMORY { return; }
+//! use iceoryx2_bb_posix::anonymous_shared_memory::*;
+//! use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+//! use iceoryx2_bb_system_types::file_name::FileName;
+//! use iceoryx2_bb_container::semantic_string::*;
+//!
+//! let name = FileName::new(b"someAnonymousShm").unwrap();
+//! let mut shm = AnonymousSharedMemoryBuilder::new(&name)
+//!                     .size(1024)
+//!                     // the size can no longer be changed
+//!                     .is_sealed(true)
+//!                     .create()
+//!                     .expect("failed to create anonymous shared memory");
+//!
+//! shm.as_mut_slice()[0] = 0xFF;
+//!
+//! // usually done by another process that received the file descriptor
+//! let shm_view = AnonymousSharedMemory::from_file_descriptor(
+//!                     shm.file_descriptor().clone(), AccessMode::Read)
+//!                     .expect("failed to map anonymous shared memory");
+//! println!("first byte: {}", shm_view.as_slice()[0]);
+//! ```
+
+use core::fmt::Debug;
+use core::ptr::NonNull;
+
+use crate::file::{FileStatError, FileTruncateError};
+use crate::file_descriptor::*;
+use crate::handle_errno;
+use iceoryx2_bb_container::semantic_string::*;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::{fail, fatal_panic, trace};
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::posix::POSIX_SUPPORT_ANONYMOUS_SHARED_MEMORY;
+use iceoryx2_pal_posix::*;
+
+pub use crate::access_mode::AccessMode;
+
+enum_gen! { AnonymousSharedMemoryCreationError
+  entry:
+    NotSupported,
+    UnsupportedSizeOfZero,
+    InsufficientMemory,
+    InsufficientMemoryToBeMemoryLocked,
+    InsufficientPermissions,
+    MappedRegionLimitReached,
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    InvalidName,
+    UnableToSeal,
+    UnknownError(i32)
+  mapping:
+    FileTruncateError,
+    FileStatError
+}
+
+/// Creates a new [`AnonymousSharedMemory`].
+#[derive(Debug)]
+pub struct AnonymousSharedMemoryBuilder {
+    name: FileName,
+    size: usize,
+    is_sealed: bool,
+}
+
+impl AnonymousSharedMemoryBuilder {
+    /// The name is only used for debugging purposes, for instance it is shown in the
+    /// process file descriptor listing. Multiple [`AnonymousSharedMemory`] objects can have the
+    /// same name.
+    pub fn new(name: &FileName) -> Self {
+        Self {
+            name: name.clone(),
+            size: 0,
+            is_sealed: false,
+        }
+    }
+
+    /// The size of the anonymous shared memory.
+    pub fn size(mut self, value: usize) -> Self {
+        self.size = value;
+        self
+    }
+
+    /// Defines if the size of the anonymous shared memory is sealed after creation. When it is
+    /// sealed no process can grow or shrink it anymore, so that a receiver of the
+    /// [`FileDescriptor`] can rely on the size it has mapped.
+    pub fn is_sealed(mut self, value: bool) -> Self {
+        self.is_sealed = value;
+        self
+    }
+
+    /// Creates the anonymous shared memory. The memory is always initialized with zeros.
+    pub fn create(self) -> Result<AnonymousSharedMemory, AnonymousSharedMemoryCreationError> {
+        let msg = "Unable to create anonymous shared memory";
+
+        if !POSIX_SUPPORT_ANONYMOUS_SHARED_MEMORY {
+            fail!(from self, with AnonymousSharedMemoryCreationError::NotSupported,
+                "{} since it is not supported by the platform.", msg);
+        }
+
+        if self.size == 0 {
+            fail!(from self, with AnonymousSharedMemoryCreationError::UnsupportedSizeOfZero,
+                "{} since a size of 0 is not supported for an anonymous shared memory object.", msg);
+        }
+
+        let mut file_descriptor = self.memfd_create()?;
+
+        fail!(from self, when file_descriptor.truncate(self.size),
+            "{} since the size could not be set to {} bytes.", msg, self.size);
+
+        if self.is_sealed
+            && unsafe {
+                posix::fcntl_int(
+                    file_descriptor.native_handle(),
+                    posix::F_ADD_SEALS,
+                    posix::F_SEAL_GROW | posix::F_SEAL_SHRINK,
+                )
+            } != 0
+        {
+            fail!(from self, with AnonymousSharedMemoryCreationError::UnableToSeal,
+                "{} since the size could not be sealed ({}).", msg, Errno::get());
+        }
+
+        let shm = fail!(from self, when AnonymousSharedMemory::map(file_descriptor, self.size, AccessMode::ReadWrite),
+            "{} since the memory could not be mapped.", msg);
+
+        trace!(from shm, "create");
+        Ok(shm)
+    }
+
+    fn memfd_create(&self) -> Result<FileDescriptor, AnonymousSharedMemoryCreationError> {
+        let mut flags = posix::MFD_CLOEXEC;
+        if self.is_sealed {
+            flags |= posix::MFD_ALLOW_SEALING;
+        }
+
+        if let Some(fd) =
+            FileDescriptor::new(unsafe { posix::memfd_create(self.name.as_c_str(), flags) })
+        {
+            return Ok(fd);
+        }
+
+        let msg = "Unable to create anonymous shared memory";
+        handle_errno!(AnonymousSharedMemoryCreationError, from self,
+            Errno::ENOSYS => (NotSupported, "{} since it is not supported by the system.", msg),
+            Errno::EINVAL => (InvalidName, "{} since the name \"{}\" is not supported.", msg, self.name),
+            Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the processes file descriptor limit was reached.", msg),
+            Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since the system wide file descriptor limit was reached.", msg),
+            Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+            Errno::EPERM => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+}
+
+/// An anonymous shared memory object which is build by the [`AnonymousSharedMemoryBuilder`]
+/// or mapped from a received [`FileDescriptor`] with
+/// [`AnonymousSharedMemory::from_file_descriptor()`].
+#[derive(Debug)]
+pub struct AnonymousSharedMemory {
+    size: usize,
+    base_address: *mut u8,
+    file_descriptor: FileDescriptor,
+}
+
+impl Drop for AnonymousSharedMemory {
+    fn drop(&mut self) {
+        if unsafe { posix::munmap(self.base_address as *mut posix::void, self.size) } != 0 {
+            fatal_panic!(from self, "This should never happen! Unable to unmap since the base address or range is invalid.");
+        }
+        trace!(from self, "close");
+    }
+}
+
+impl AnonymousSharedMemory {
+    /// Maps the anonymous shared memory the [`FileDescriptor`] refers to, for instance after it
+    /// was received from another process. The whole memory is mapped, its size is acquired
+    /// from the [`FileDescriptor`].
+    pub fn from_file_descriptor(
+        file_descriptor: FileDescriptor,
+        access_mode: AccessMode,
+    ) -> Result<Self, AnonymousSharedMemoryCreationError> {
+        let msg = "Unable to map anonymous shared memory from file descriptor";
+        let size = fail!(from "AnonymousSharedMemory::from_file_descriptor()", when file_descriptor.metadata(),
+            "{} since the size could not be acquired.", msg)
+        .size() as usize;
+
+        if size == 0 {
+            fail!(from "AnonymousSharedMemory::from_file_descriptor()",
+                with AnonymousSharedMemoryCreationError::UnsupportedSizeOfZero,
+                "{} since a size of 0 is not supported for an anonymous shared memory object.", msg);
+        }
+
+        let shm = Self::map(file_descriptor, size, access_mode)?;
+        trace!(from shm, "open");
+        Ok(shm)
+    }
+
+    /// Returns true when the size of the anonymous shared memory is sealed and cannot be
+    /// changed anymore, otherwise false.
+    pub fn is_sealed(&self) -> bool {
+        let seals = unsafe {
+            posix::fcntl_int(self.file_descriptor.native_handle(), posix::F_GET_SEALS, 0)
+        };
+
+        seals > 0
+            && (seals & (posix::F_SEAL_GROW | posix::F_SEAL_SHRINK))
+                == posix::F_SEAL_GROW | posix::F_SEAL_SHRINK
+    }
+
+    /// returns the base address of the anonymous shared memory. The base address is always
+    /// aligned to the page size.
+    pub fn base_address(&self) -> NonNull<u8> {
+        match NonNull::new(self.base_address) {
+            Some(v) => v,
+            None => {
+                fatal_panic!(from self,
+                    "This should never happen! A valid anonymous shared memory object should never contain a base address with null value.");
+            }
+        }
+    }
+
+    /// returns the size of the anonymous shared memory
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// returns a slice to the memory
+    pub fn as_slice(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.base_address, self.size) }
+    }
+
+    /// returns a mutable slice to the memory
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        unsafe { core::slice::from_raw_parts_mut(self.base_address, self.size) }
+    }
+
+    fn map(
+        file_descriptor: FileDescriptor,
+        size: usize,
+        access_mode: AccessMode,
+    ) -> Result<Self, AnonymousSharedMemoryCreationError> {
+        let base_address = unsafe {
+            posix::mmap(
+                core::ptr::null_mut::<posix::void>(),
+                size,
+                access_mode.as_protflag(),
+                posix::MAP_SHARED,
+                file_descriptor.native_handle(),
+                0,
+            )
+        };
+
+        if !core::ptr::eq(base_address, posix::MAP_FAILED) {
+            return Ok(Self {
+                size,
+                base_address: base_address as *mut u8,
+                file_descriptor,
+            });
+        }
+
+        let msg = "Unable to map anonymous shared memory";
+        handle_errno!(AnonymousSharedMemoryCreationError, from file_descriptor,
+            Errno::EAGAIN => (InsufficientMemoryToBeMemoryLocked, "{} since a previous mlockall() enforces all mappings to be memory locked but this mapping cannot be locked due to insufficient memory.", msg),
+            Errno::EACCES => (InsufficientPermissions, "{} since the file descriptor does not permit the access mode {:?}.", msg, access_mode),
+            Errno::ENOMEM => (InsufficientMemory, "{} since the system is out-of-memory or does not the support a shared memory with the size of {}.", msg, size),
+            Errno::EMFILE => (MappedRegionLimitReached, "{} since the number of mapped regions would exceed the process or system limit.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+}
+
+impl FileDescriptorBased for AnonymousSharedMemory {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.file_descriptor
+    }
+}
+
+impl FileDescriptorManagement for AnonymousSharedMemory {}
@@ -23,6 +23,7 @@
 
 use crate::handle_errno;
 use crate::system_configuration::Feature;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
 use core::time::Duration;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary::enum_gen;
@@ -35,6 +36,8 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum TimeError {
     ClockTypeIsNotSupported,
+    ClockTypeMismatch,
+    NegativeDuration,
     UnknownError(i32),
 }
 
@@ -60,6 +63,9 @@ impl From<TimeError> for NanosleepError {
     fn from(t: TimeError) -> Self {
         match t {
             TimeError::ClockTypeIsNotSupported => NanosleepError::ClockTypeIsNotSupported,
+            TimeError::ClockTypeMismatch | TimeError::NegativeDuration => {
+                NanosleepError::DurationOutOfRange
+            }
             TimeError::UnknownError(v) => NanosleepError::UnknownError(v),
         }
     }
@@ -75,6 +81,10 @@ pub enum ClockType {
     /// Clock which represents the current system time. Can change when the  system time is
     /// adjusted.
     Realtime,
+    /// Steady clock like [`ClockType::Monotonic`] which continues to count while the system
+    /// is suspended. On platforms without such a clock, indicated by
+    /// [`posix::POSIX_SUPPORT_BOOTTIME_CLOCK`], it falls back to [`ClockType::Monotonic`].
+    Boottime,
 }
 
 impl Default for ClockType {
@@ -90,10 +100,16 @@ impl Default for ClockType {
 impl ClockType {
     /// Returns a slice containing all supported [`ClockType`]s
     pub fn all_supported_clocks() -> &'static [ClockType] {
-        if Feature::MonotonicClock.is_available() {
-            &[ClockType::Monotonic, ClockType::Realtime]
-        } else {
+        if !Feature::MonotonicClock.is_available() {
             &[ClockType::Realtime]
+        } else if posix::POSIX_SUPPORT_BOOTTIME_CLOCK {
+            &[
+                ClockType::Monotonic,
+                ClockType::Realtime,
+                ClockType::Boottime,
+            ]
+        } else {
+            &[ClockType::Monotonic, ClockType::Realtime]
         }
     }
 
@@ -101,6 +117,7 @@ impl ClockType {
         match self {
             ClockType::Monotonic => posix::CLOCK_MONOTONIC as _,
             ClockType::Realtime => posix::CLOCK_REALTIME as _,
+            ClockType::Boottime => posix::CLOCK_BOOTTIME as _,
         }
     }
 }
@@ -113,7 +130,9 @@ pub trait AsTimespec {
 impl AsTimespec for Duration {
     fn as_timespec(&self) -> posix::timespec {
         posix::timespec {
-            tv_sec: self.as_secs() as posix::time_t,
+            // saturate instead of wrapping into a negative value, so that an infinite duration
+            // stays as far in the future as possible
+            tv_sec: self.as_secs().min(posix::time_t::MAX as u64) as posix::time_t,
             tv_nsec: self.subsec_nanos() as posix::long,
         }
     }
@@ -236,11 +255,98 @@ impl Time {
     /// let elapsed_time: Duration = now.elapsed().unwrap();
     /// ```
     pub fn elapsed(&self) -> Result<Duration, TimeError> {
-        let now =
-            fail!(from self, when Time::now_with_clock(self.clock_type), "Failed to acquire elapsed time")
-                .as_duration();
+        let now = fail!(from self, when Time::now_with_clock(self.clock_type),
+            "Failed to acquire elapsed time");
 
-        Ok(now - self.as_duration())
+        Ok(fail!(from self, when now.duration_since(*self),
+            "Failed to acquire elapsed time since the time lies in the future."))
+    }
+
+    /// Returns the [`Duration`] that has passed between `earlier` and this time. Fails with
+    /// [`TimeError::NegativeDuration`] when `earlier` is later than this time and with
+    /// [`TimeError::ClockTypeMismatch`] when both times use a different [`ClockType`].
+    ///
+    /// # Examples
+    /// ```
+    /// use iceoryx2_bb_posix::clock::*;
+    /// use core::time::Duration;
+    ///
+    /// let earlier = Time::now().unwrap();
+    /// let later = earlier + Duration::from_millis(10);
+    ///
+    /// assert_eq!(later.duration_since(earlier), Ok(Duration::from_millis(10)));
+    /// assert_eq!(earlier.duration_since(later), Err(TimeError::NegativeDuration));
+    /// ```
+    pub fn duration_since(&self, earlier: Time) -> Result<Duration, TimeError> {
+        let msg = "Unable to acquire the duration since an earlier time";
+        if self.clock_type != earlier.clock_type {
+            fail!(from self, with TimeError::ClockTypeMismatch,
+                "{} since the earlier time {:?} uses a different clock type.", msg, earlier);
+        }
+
+        match self.as_duration().checked_sub(earlier.as_duration()) {
+            Some(v) => Ok(v),
+            None => {
+                fail!(from self, with TimeError::NegativeDuration,
+                    "{} since the earlier time {:?} is later.", msg, earlier);
+            }
+        }
+    }
+
+    /// Returns the [`Duration`] that has passed between `earlier` and this time or
+    /// [`Duration::ZERO`] when `earlier` is later than this time. The [`ClockType`] of `earlier`
+    /// is not checked.
+    pub fn saturating_duration_since(&self, earlier: Time) -> Duration {
+        self.as_duration().saturating_sub(earlier.as_duration())
+    }
+
+    /// Adds the [`Duration`] to the time. Returns [`None`] when the result is not representable.
+    ///
+    /// # Examples
+    /// ```
+    /// use iceoryx2_bb_posix::clock::*;
+    /// use core::time::Duration;
+    ///
+    /// let time = TimeBuilder::new().seconds(1).nanoseconds(999_999_999).create();
+    /// let later = time.checked_add(Duration::from_nanos(1)).unwrap();
+    /// assert_eq!(later.seconds(), 2);
+    /// assert_eq!(later.nanoseconds(), 0);
+    ///
+    /// assert!(time.checked_add(Duration::MAX).is_none());
+    /// ```
+    pub fn checked_add(&self, rhs: Duration) -> Option<Time> {
+        self.as_duration()
+            .checked_add(rhs)
+            .map(|v| Self::from_duration(self.clock_type, v))
+    }
+
+    /// Subtracts the [`Duration`] from the time. Returns [`None`] when the result would be
+    /// before the epoch of the clock.
+    pub fn checked_sub(&self, rhs: Duration) -> Option<Time> {
+        self.as_duration()
+            .checked_sub(rhs)
+            .map(|v| Self::from_duration(self.clock_type, v))
+    }
+
+    /// Adds the [`Duration`] to the time. When the result is not representable it returns the
+    /// latest representable time. Useful to compute deadlines from infinite timeouts like
+    /// [`Duration::MAX`].
+    pub fn saturating_add(&self, rhs: Duration) -> Time {
+        Self::from_duration(self.clock_type, self.as_duration().saturating_add(rhs))
+    }
+
+    /// Subtracts the [`Duration`] from the time. When the result would be before the epoch of
+    /// the clock it returns the epoch.
+    pub fn saturating_sub(&self, rhs: Duration) -> Time {
+        Self::from_duration(self.clock_type, self.as_duration().saturating_sub(rhs))
+    }
+
+    fn from_duration(clock_type: ClockType, value: Duration) -> Time {
+        Time {
+            clock_type,
+            seconds: value.as_secs(),
+            nanoseconds: value.subsec_nanos(),
+        }
     }
 
     /// Returns the number of seconds
@@ -261,12 +367,92 @@ impl Time {
     pub fn as_duration(&self) -> Duration {
         Duration::from_secs(self.seconds) + Duration::from_nanos(self.nanoseconds as u64)
     }
+
+    /// Converts the time into the corresponding time of another [`ClockType`]. The offset
+    /// between both clocks is sampled when the function is called, therefore the result is
+    /// only a best-effort approximation. It becomes inaccurate when the offset changes between
+    /// the acquisition of the time and the conversion, for instance when the system time is
+    /// adjusted or the system was suspended. A time that would be before the epoch of the
+    /// target clock is saturated to zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use iceoryx2_bb_posix::clock::*;
+    ///
+    /// let now = Time::now_with_clock(ClockType::Realtime).unwrap();
+    /// let converted = now.convert_to(ClockType::default()).unwrap();
+    /// assert_eq!(converted.clock_type(), ClockType::default());
+    /// ```
+    pub fn convert_to(&self, clock_type: ClockType) -> Result<Time, TimeError> {
+        if self.clock_type == clock_type {
+            return Ok(*self);
+        }
+
+        let msg = "Unable to convert time";
+        let source_before = fail!(from self, when Time::now_with_clock(self.clock_type),
+            "{} since the current time of the source clock could not be acquired.", msg);
+        let target = fail!(from self, when Time::now_with_clock(clock_type),
+            "{} since the current time of the target clock could not be acquired.", msg);
+        let source_after = fail!(from self, when Time::now_with_clock(self.clock_type),
+            "{} since the current time of the source clock could not be acquired.", msg);
+
+        // the target clock is sampled between both source samples, the midpoint is the best
+        // estimate of the source time at that moment
+        let source =
+            (source_before.as_duration().as_nanos() + source_after.as_duration().as_nanos()) / 2;
+        let converted = (self.as_duration().as_nanos() + target.as_duration().as_nanos())
+            .saturating_sub(source);
+
+        Ok(Time {
+            clock_type,
+            seconds: (converted / 1_000_000_000) as u64,
+            nanoseconds: (converted % 1_000_000_000) as u32,
+        })
+    }
+}
+
+impl Add<Duration> for Time {
+    type Output = Time;
+
+    /// # Panics
+    ///
+    /// When the result is not representable, use [`Time::checked_add()`] or
+    /// [`Time::saturating_add()`] instead.
+    fn add(self, rhs: Duration) -> Time {
+        self.checked_add(rhs)
+            .expect("overflow when adding duration to time")
+    }
+}
+
+impl AddAssign<Duration> for Time {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Duration> for Time {
+    type Output = Time;
+
+    /// # Panics
+    ///
+    /// When the result would be before the epoch of the clock, use [`Time::checked_sub()`] or
+    /// [`Time::saturating_sub()`] instead.
+    fn sub(self, rhs: Duration) -> Time {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting duration from time")
+    }
+}
+
+impl SubAssign<Duration> for Time {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
 }
 
 impl AsTimespec for Time {
     fn as_timespec(&self) -> posix::timespec {
         posix::timespec {
-            tv_sec: self.seconds as _,
+            tv_sec: self.seconds.min(posix::time_t::MAX as u64) as _,
             tv_nsec: self.nanoseconds as _,
         }
     }
@@ -313,12 +499,9 @@ pub fn nanosleep_with_clock(
         return Ok(());
     }
 
-    let wait_time = Time::now_with_clock(clock_type)?.as_duration() + duration;
-
-    let timeout = posix::timespec {
-        tv_sec: wait_time.as_secs() as posix::time_t,
-        tv_nsec: wait_time.subsec_nanos() as posix::long,
-    };
+    let timeout = Time::now_with_clock(clock_type)?
+        .saturating_add(duration)
+        .as_timespec();
 
     let mut time_left = posix::timespec {
         tv_sec: 0,
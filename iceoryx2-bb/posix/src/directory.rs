@@ -33,16 +33,16 @@
 //!
 //! Directory::remove(&dir_name).unwrap();
 //! ```
-use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_container::semantic_string::{SemanticString, SemanticStringError};
 use iceoryx2_bb_container::string::strnlen;
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_elementary::scope_guard::ScopeGuardBuilder;
 use iceoryx2_bb_log::{error, fail, fatal_panic, trace};
 use iceoryx2_bb_system_types::{file_name::FileName, file_path::FilePath, path::Path};
 use iceoryx2_pal_configuration::PATH_SEPARATOR;
+use iceoryx2_pal_posix::posix::errno::Errno;
 use iceoryx2_pal_posix::posix::MemZeroedStruct;
 use iceoryx2_pal_posix::*;
-use iceoryx2_pal_posix::{posix::errno::Errno, posix::S_IFDIR};
 
 use crate::file::{File, FileRemoveError};
 use crate::file_type::FileType;
@@ -111,11 +111,13 @@ enum_gen! { DirectoryRemoveError
     NotADirectory,
     ResidesOnReadOnlyFileSystem,
     DanglingSymbolicLink,
+    PathIsRootOrEmpty,
     UnknownError(i32)
   mapping:
     DirectoryOpenError,
     DirectoryReadError,
-    FileRemoveError
+    FileRemoveError,
+    SemanticStringError
 }
 
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
@@ -176,6 +178,244 @@ impl DirectoryEntry {
     }
 }
 
+/// Represents an entry that was returned by the [`DirectoryIter`]. In contrast to the
+/// [`DirectoryEntry`] it provides only the name and the [`FileType`] of the entry. The
+/// [`Metadata`] is acquired on demand with [`DirectoryIterEntry::metadata()`].
+#[derive(Debug)]
+pub struct DirectoryIterEntry<'a> {
+    directory: &'a Directory,
+    name: FileName,
+    file_type: FileType,
+}
+
+impl DirectoryIterEntry<'_> {
+    pub fn name(&self) -> &FileName {
+        &self.name
+    }
+
+    /// Returns the [`FileType`] of the entry. Symbolic links are not followed, they are
+    /// reported with [`FileType::SymbolicLink`].
+    pub fn file_type(&self) -> FileType {
+        self.file_type
+    }
+
+    /// Acquires the [`Metadata`] of the entry. Symbolic links are not followed.
+    pub fn metadata(&self) -> Result<Metadata, DirectoryStatError> {
+        let msg = format!(
+            "Failed to acquire stats \"{}\" while iterating over the directory",
+            self.name
+        );
+        self.directory.acquire_metadata(&self.name, &msg)
+    }
+}
+
+/// Iterates over the entries of a [`Directory`] without reading the whole directory into
+/// memory first. Is created with [`Directory::iter()`].
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2_bb_posix::directory::*;
+/// use iceoryx2_bb_system_types::path::Path;
+/// use iceoryx2_bb_system_types::file_name::FileName;
+/// use iceoryx2_bb_container::semantic_string::SemanticString;
+///
+/// let dir_name = Path::new(b"i_am_an_iterable_directory").unwrap();
+/// let dir = Directory::create(&dir_name, Permission::OWNER_ALL).unwrap();
+///
+/// for entry in dir.iter().unwrap().prefix(&FileName::new(b"service_").unwrap()) {
+///   let entry = entry.unwrap();
+///   println!("name {}, type {:?}", entry.name(), entry.file_type());
+/// }
+/// Directory::remove(&dir_name).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct DirectoryIter<'a> {
+    directory: &'a Directory,
+    directory_stream: *mut posix::DIR,
+    prefix: Option<FileName>,
+    has_failed: bool,
+}
+
+impl Drop for DirectoryIter<'_> {
+    fn drop(&mut self) {
+        if unsafe { posix::closedir(self.directory_stream) } != 0 {
+            error!(from self, "Unable to close directory stream ({}).", Errno::get());
+        }
+    }
+}
+
+impl<'a> DirectoryIter<'a> {
+    /// Only entries whose name starts with the provided prefix are returned. The filter is
+    /// applied before any stat call is performed.
+    pub fn prefix(mut self, value: &FileName) -> Self {
+        self.prefix = Some(value.clone());
+        self
+    }
+
+    fn file_type_from_dirent(entry: *const posix::types::dirent) -> Option<FileType> {
+        match unsafe { posix::dirent_type(entry) } {
+            posix::DT_REG => Some(FileType::File),
+            posix::DT_DIR => Some(FileType::Directory),
+            posix::DT_LNK => Some(FileType::SymbolicLink),
+            posix::DT_SOCK => Some(FileType::Socket),
+            posix::DT_FIFO => Some(FileType::FiFo),
+            posix::DT_CHR => Some(FileType::Character),
+            posix::DT_BLK => Some(FileType::Block),
+            _ => None,
+        }
+    }
+
+    fn read_next_entry(&mut self) -> Result<Option<DirectoryIterEntry<'a>>, DirectoryReadError> {
+        loop {
+            Errno::reset();
+            let entry = unsafe { posix::readdir(self.directory_stream) };
+
+            if entry.is_null() {
+                if Errno::get() == Errno::ESUCCES {
+                    return Ok(None);
+                }
+
+                let msg = "Unable to read next directory entry";
+                handle_errno!(DirectoryReadError, from self,
+                    Errno::ENOENT => (DirectoryDoesNoLongerExist, "{} since the directory does not exist anymore.", msg),
+                    v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+                );
+            }
+
+            let raw_name = unsafe { (*entry).d_name.as_ptr() };
+            let raw_name_length = unsafe { strnlen(raw_name, FileName::max_len()) };
+            let name_bytes =
+                unsafe { core::slice::from_raw_parts(raw_name.cast::<u8>(), raw_name_length) };
+
+            // empty names, dot and dot dot are skipped
+            if name_bytes.is_empty() || name_bytes == b"." || name_bytes == b".." {
+                continue;
+            }
+
+            if let Some(prefix) = &self.prefix {
+                if !name_bytes.starts_with(prefix.as_bytes()) {
+                    continue;
+                }
+            }
+
+            let name = match unsafe { FileName::from_c_str(raw_name as *mut posix::c_char) } {
+                Ok(name) => name,
+                Err(v) => {
+                    error!(from self, "Directory contains entries that are not representable with FileName struct ({:?}).", v);
+                    continue;
+                }
+            };
+
+            let file_type = match Self::file_type_from_dirent(entry) {
+                Some(file_type) => file_type,
+                None => {
+                    let msg = format!(
+                        "Failed to acquire stats \"{name}\" while iterating over the directory"
+                    );
+                    match self.directory.acquire_metadata(&name, &msg) {
+                        Ok(metadata) => metadata.file_type(),
+                        Err(DirectoryStatError::DoesNotExist)
+                        | Err(DirectoryStatError::InsufficientPermissions) => continue,
+                        Err(e) => {
+                            fail!(from self, with e.into(),
+                                "{} due to an internal failure {:?}.", msg, e);
+                        }
+                    }
+                }
+            };
+
+            return Ok(Some(DirectoryIterEntry {
+                directory: self.directory,
+                name,
+                file_type,
+            }));
+        }
+    }
+}
+
+impl<'a> Iterator for DirectoryIter<'a> {
+    type Item = Result<DirectoryIterEntry<'a>, DirectoryReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.has_failed {
+            return None;
+        }
+
+        let result = self.read_next_entry();
+        self.has_failed = result.is_err();
+        result.transpose()
+    }
+}
+
+/// The number of entries that were removed by [`Directory::remove_recursive()`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RemovedStats {
+    files: usize,
+    symbolic_links: usize,
+    directories: usize,
+}
+
+impl RemovedStats {
+    /// Returns the number of removed files. Every entry that is neither a directory nor a
+    /// symbolic link, like sockets or fifos, is counted as file.
+    pub fn files(&self) -> usize {
+        self.files
+    }
+
+    /// Returns the number of removed symbolic links.
+    pub fn symbolic_links(&self) -> usize {
+        self.symbolic_links
+    }
+
+    /// Returns the number of removed directories, including the removed directory itself.
+    pub fn directories(&self) -> usize {
+        self.directories
+    }
+
+    /// Returns the total number of removed entries.
+    pub fn total(&self) -> usize {
+        self.files + self.symbolic_links + self.directories
+    }
+}
+
+/// Failure of [`Directory::remove_recursive()`]. It contains the cause and the [`RemovedStats`]
+/// of the entries that were already removed when the operation stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DirectoryRemoveRecursiveError {
+    error: DirectoryRemoveError,
+    removed: RemovedStats,
+}
+
+impl DirectoryRemoveRecursiveError {
+    /// Returns the [`DirectoryRemoveError`] that stopped the operation.
+    pub fn error(&self) -> DirectoryRemoveError {
+        self.error
+    }
+
+    /// Returns the [`RemovedStats`] of the entries that were removed before the failure.
+    pub fn removed(&self) -> RemovedStats {
+        self.removed
+    }
+}
+
+impl From<DirectoryRemoveError> for DirectoryRemoveRecursiveError {
+    fn from(error: DirectoryRemoveError) -> Self {
+        Self {
+            error,
+            removed: RemovedStats::default(),
+        }
+    }
+}
+
+impl core::fmt::Display for DirectoryRemoveRecursiveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}::{:?}", stringify!(Self), self)
+    }
+}
+
+impl core::error::Error for DirectoryRemoveRecursiveError {}
+
 /// Represents a directory implement based on the POSIX API. It implements the traits
 /// [`FileDescriptorBased`] and [`FileDescriptorManagement`] to provide extended permission
 /// and ownership handling as well as [`Metadata`].
@@ -271,10 +511,37 @@ impl Directory {
         Ok(())
     }
 
-    /// Creates a new directory at the provided path.
+    /// Creates a new directory at the provided path. Missing parent directories are created
+    /// with the same [`Permission`]. Succeeds when the directory already exists.
     pub fn create(path: &Path, permission: Permission) -> Result<Self, DirectoryCreateError> {
         let origin = "Directory::create()";
         let msg = format!("Unable to create directory \"{path}\"");
+        fail!(from origin, when Directory::create_with_parents(path, permission),
+            "{} since the directory or one of its parents could not be created.", msg);
+
+        match Directory::new(path) {
+            Ok(d) => {
+                trace!(from d, "created");
+                Ok(d)
+            }
+            Err(e) => {
+                fail!(from origin, with e.into(),
+                    "Failed to open newly created directory \"{}\".", path);
+            }
+        }
+    }
+
+    /// Creates the directory at the provided path and every missing parent directory with
+    /// the given [`Permission`]. Components that already exist as directories are accepted,
+    /// also when they were created concurrently by another process. Fails with
+    /// [`DirectoryCreateError::PartsOfThePathAreNotADirectory`] when a component exists but
+    /// is not a directory.
+    pub fn create_with_parents(
+        path: &Path,
+        permission: Permission,
+    ) -> Result<(), DirectoryCreateError> {
+        let origin = "Directory::create_with_parents()";
+        let msg = format!("Unable to create directory \"{path}\"");
         let entries = path.entries();
 
         let mut inc_path = if path.is_absolute() {
@@ -288,39 +555,51 @@ impl Directory {
                 .add_path_entry(&entry.into())
                 .expect("Always works since it recreates the provided path");
 
-            match Directory::does_exist(&inc_path) {
-                Ok(true) => (),
-                Ok(false) => match Directory::create_single_directory(&inc_path, permission) {
-                    Ok(()) | Err(DirectoryCreateError::DirectoryAlreadyExists) => (),
+            let is_directory = match Directory::entry_is_directory(&inc_path, origin, &msg)? {
+                Some(is_directory) => is_directory,
+                None => match Directory::create_single_directory(&inc_path, permission) {
+                    Ok(()) => true,
+                    // created concurrently, it must be verified that it is a directory
+                    Err(DirectoryCreateError::DirectoryAlreadyExists) => {
+                        Directory::entry_is_directory(&inc_path, origin, &msg)?.unwrap_or(false)
+                    }
                     Err(e) => {
                         fail!(from origin, with e,
                             "{} since the directory {} could not be created due to {:?}.",
                             msg, inc_path, e);
                     }
                 },
-                Err(DirectoryAccessError::InsufficientPermissions) => {
-                    fail!(from origin, with DirectoryCreateError::InsufficientPermissions,
-                        "{} since the path {} could not be accessed due to insufficient permissions.", msg, inc_path);
-                }
-                Err(DirectoryAccessError::PathPrefixIsNotADirectory) => {
-                    fail!(from origin, with DirectoryCreateError::PartsOfThePathAreNotADirectory,
-                        "{} since the path {} is not a directory.", msg, inc_path);
-                }
-                Err(v) => {
-                    fail!(from origin, with DirectoryCreateError::UnknownError(0),
-                        "{} due to a failure while accessing {} ({:?}).", msg, inc_path, v);
-                }
             };
+
+            if !is_directory {
+                fail!(from origin, with DirectoryCreateError::PartsOfThePathAreNotADirectory,
+                    "{} since the path {} exists but is not a directory.", msg, inc_path);
+            }
         }
 
-        match Directory::new(path) {
-            Ok(d) => {
-                trace!(from d, "created");
-                Ok(d)
+        Ok(())
+    }
+
+    fn entry_is_directory(
+        path: &Path,
+        origin: &str,
+        msg: &str,
+    ) -> Result<Option<bool>, DirectoryCreateError> {
+        match Directory::stat_entry(path) {
+            Ok(stat) => {
+                Ok(stat.map(|stat| FileType::from_mode_t(stat.st_mode) == FileType::Directory))
             }
-            Err(e) => {
-                fail!(from origin, with e.into(),
-                    "Failed to open newly created directory \"{}\".", path);
+            Err(DirectoryAccessError::InsufficientPermissions) => {
+                fail!(from origin, with DirectoryCreateError::InsufficientPermissions,
+                    "{} since the path {} could not be accessed due to insufficient permissions.", msg, path);
+            }
+            Err(DirectoryAccessError::PathPrefixIsNotADirectory) => {
+                fail!(from origin, with DirectoryCreateError::PartsOfThePathAreNotADirectory,
+                    "{} since the path {} is not a directory.", msg, path);
+            }
+            Err(v) => {
+                fail!(from origin, with DirectoryCreateError::UnknownError(0),
+                    "{} due to a failure while accessing {} ({:?}).", msg, path, v);
             }
         }
     }
@@ -356,32 +635,102 @@ impl Directory {
 
     /// Removes and existing directory with all of its contents.
     pub fn remove(path: &Path) -> Result<(), DirectoryRemoveError> {
-        let msg = format!("Unable to remove directory \"{path}\"");
-        let origin = "Directory::remove()";
+        Self::remove_recursive(path)
+            .map(|_| ())
+            .map_err(|e| e.error())
+    }
+
+    /// Removes an existing directory with all of its contents bottom-up and returns the
+    /// [`RemovedStats`]. Symbolic links are removed but never followed, so that nothing
+    /// outside of the directory tree is touched. The root path and the empty path are
+    /// rejected with [`DirectoryRemoveError::PathIsRootOrEmpty`]. When an entry cannot be
+    /// removed the operation stops and the returned [`DirectoryRemoveRecursiveError`] contains
+    /// the cause and the [`RemovedStats`] of the already removed entries.
+    pub fn remove_recursive(path: &Path) -> Result<RemovedStats, DirectoryRemoveRecursiveError> {
+        Self::verify_removable_tree_root(path)?;
+
+        let mut stats = RemovedStats::default();
+        match Self::remove_tree(path, &mut stats) {
+            Ok(()) => Ok(stats),
+            Err(e) => {
+                fail!(from "Directory::remove_recursive()", with DirectoryRemoveRecursiveError { error: e, removed: stats },
+                    "Unable to remove directory \"{}\" recursively since not all entries could be removed ({:?}), only {:?} were removed.",
+                    path, e, stats);
+            }
+        }
+    }
+
+    fn verify_removable_tree_root(path: &Path) -> Result<(), DirectoryRemoveError> {
+        let msg = "Unable to remove directory recursively";
+        let origin = "Directory::remove_recursive()";
 
-        let dir = fail!(from origin, when Directory::new(path),
-                            "{} since the directory {} could not be opened.", msg, path);
-        let contents = fail!(from origin, when dir.contents(),
-                            "{} since the directory contents of {} could not be read.", msg, path);
+        if path.entries().is_empty() {
+            fail!(from origin, with DirectoryRemoveError::PathIsRootOrEmpty,
+                "{} since the root path and the empty path cannot be removed.", msg);
+        }
+
+        let mut buffer = posix::stat_t::new_zeroed();
+        if unsafe { posix::lstat(path.as_c_str(), &mut buffer) } == -1 {
+            handle_errno!(DirectoryRemoveError, from origin,
+                Errno::EACCES => (InsufficientPermissions, "{} \"{}\" due to insufficient permissions.", msg, path),
+                Errno::ELOOP => (LoopInSymbolicLinks, "{} \"{}\" due to a loop in the symbolic links of the path.", msg, path),
+                Errno::ENOENT => (DirectoryDoesNotExist, "{} \"{}\" since the directory does not exist.", msg, path),
+                Errno::ENOTDIR => (NotADirectory, "{} \"{}\" since a component of the path is not a directory.", msg, path),
+                v => (UnknownError(v as i32), "{} \"{}\" since an unknown error occurred ({}).", msg, path, v)
+            );
+        }
+
+        if FileType::from_mode_t(buffer.st_mode) != FileType::Directory {
+            fail!(from origin, with DirectoryRemoveError::NotADirectory,
+                "{} \"{}\" since it is not a directory.", msg, path);
+        }
+
+        Ok(())
+    }
+
+    fn remove_tree(path: &Path, stats: &mut RemovedStats) -> Result<(), DirectoryRemoveError> {
+        let msg = "Unable to remove directory";
+        let origin = "Directory::remove_recursive()";
+
+        let contents = {
+            let dir = fail!(from origin, when Directory::new(path),
+                                "{} \"{}\" since it could not be opened.", msg, path);
+            fail!(from origin, when dir.contents(),
+                                "{} \"{}\" since its contents could not be read.", msg, path)
+        };
 
         for entry in contents {
             let mut sub_path = path.clone();
-            sub_path
-                .add_path_entry(&entry.name().into())
-                .expect("always a valid path entry");
-            if entry.metadata().file_type() == FileType::Directory {
-                fail!(from origin, when Directory::remove(&sub_path),
-                    "{} since the sub-path {} could not be removed.", msg, sub_path);
-            } else {
-                fail!(from origin, when File::remove(&unsafe{FilePath::new_unchecked(sub_path.as_bytes())}),
-                    "{} since the file {} could not be removed.", msg, sub_path);
+            fail!(from origin, when sub_path.add_path_entry(&entry.name().into()),
+                "{} \"{}\" since the path of the entry {} exceeds the maximum supported length.",
+                msg, path, entry.name());
+
+            match entry.metadata().file_type() {
+                FileType::Directory => Self::remove_tree(&sub_path, stats)?,
+                file_type => {
+                    let has_removed = fail!(from origin, when File::remove(&unsafe{FilePath::new_unchecked(sub_path.as_bytes())}),
+                        "{} \"{}\" since the entry {} could not be removed.", msg, path, sub_path);
+
+                    if !has_removed {
+                        continue;
+                    }
+
+                    if file_type == FileType::SymbolicLink {
+                        stats.symbolic_links += 1;
+                    } else {
+                        stats.files += 1;
+                    }
+                }
             }
         }
 
-        Self::remove_empty(path)
+        Self::remove_empty(path)?;
+        stats.directories += 1;
+        Ok(())
     }
 
-    /// Returns the contents of the directory inside a vector of [`DirectoryEntry`]s.
+    /// Returns the contents of the directory inside a vector of [`DirectoryEntry`]s. Symbolic
+    /// links are not followed, they are reported with [`FileType::SymbolicLink`].
     pub fn contents(&self) -> Result<Vec<DirectoryEntry>, DirectoryReadError> {
         let mut namelist: *mut *mut posix::types::dirent =
             core::ptr::null_mut::<*mut posix::types::dirent>();
@@ -459,14 +808,44 @@ impl Directory {
         Ok(contents)
     }
 
+    /// Returns a [`DirectoryIter`] that reads the entries of the directory one at a time
+    /// instead of collecting them into a vector like [`Directory::contents()`].
+    pub fn iter(&self) -> Result<DirectoryIter<'_>, DirectoryReadError> {
+        let directory_stream = unsafe { posix::opendir(self.path.as_c_str()) };
+
+        if directory_stream.is_null() {
+            let msg = "Unable to iterate over the directory contents";
+            handle_errno!(DirectoryReadError, from self,
+                Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+                Errno::ENOENT => (DirectoryDoesNoLongerExist, "{} since the directory does not exist anymore.", msg),
+                Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+                Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the file descriptor limit of the process was reached.", msg),
+                Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since the system-wide limit of file descriptors was reached.", msg),
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+
+        Ok(DirectoryIter {
+            directory: self,
+            directory_stream,
+            prefix: None,
+            has_failed: false,
+        })
+    }
+
     /// Returns true if a directory already exists, otherwise false
     pub fn does_exist(path: &Path) -> Result<bool, DirectoryAccessError> {
+        Ok(Directory::stat_entry(path)?
+            .is_some_and(|stat| FileType::from_mode_t(stat.st_mode) == FileType::Directory))
+    }
+
+    fn stat_entry(path: &Path) -> Result<Option<posix::stat_t>, DirectoryAccessError> {
         let mut buffer = posix::stat_t::new_zeroed();
         let msg = format!("Unable to determine if \"{path}\" does exist");
 
         if unsafe { posix::stat(path.as_c_str(), &mut buffer) } == -1 {
             handle_errno!(DirectoryAccessError, from "Directory::does_exist",
-                success Errno::ENOENT => false,
+                success Errno::ENOENT => None,
                 Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions to open path.", msg),
                 Errno::EIO => (IOerror, "{} due to an io error while reading directory stats.", msg),
                 Errno::ELOOP => (LoopInSymbolicLinks, "{} due to a symbolic link loop in the path.", msg),
@@ -476,7 +855,7 @@ impl Directory {
             );
         }
 
-        Ok(buffer.st_mode & S_IFDIR != 0)
+        Ok(Some(buffer))
     }
 
     fn acquire_metadata(&self, file: &FileName, msg: &str) -> Result<Metadata, DirectoryStatError> {
@@ -485,7 +864,7 @@ impl Directory {
         path.push(PATH_SEPARATOR).unwrap();
         path.push_bytes(file.as_bytes()).unwrap();
 
-        if unsafe { posix::stat(path.as_c_str(), &mut buffer) } == -1 {
+        if unsafe { posix::lstat(path.as_c_str(), &mut buffer) } == -1 {
             handle_errno!(DirectoryStatError, from self,
                 Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions to open path.", msg),
                 Errno::EIO => (IOerror, "{} due to an io error while reading directory stats.", msg),
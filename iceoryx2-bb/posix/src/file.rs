@@ -170,6 +170,25 @@ impl core::fmt::Display for FileTruncateError {
 
 impl core::error::Error for FileTruncateError {}
 
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum FileAllocateError {
+    Interrupt,
+    InsufficientSpace,
+    SizeTooBig,
+    IOerror,
+    FileNotOpenedForWriting,
+    NotSupported,
+    UnknownError(i32),
+}
+
+impl core::fmt::Display for FileAllocateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "FileAllocateError::{self:?}")
+    }
+}
+
+impl core::error::Error for FileAllocateError {}
+
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum FileStatError {
     InvalidFileDescriptor,
@@ -308,7 +327,7 @@ enum_gen! {
     FileError
   generalization:
     Create <= FileCreationError,
-    Write <= FileSyncError; FileWriteError; FileTruncateError; FileRemoveError,
+    Write <= FileSyncError; FileWriteError; FileTruncateError; FileAllocateError; FileRemoveError,
     Read <= FileOffsetError; FileReadError; FileOpenError; FileAccessError,
     Credentials <= FileSetOwnerError; FileSetPermissionError,
     Stat <= FileStatError
@@ -736,6 +755,38 @@ impl File {
         )
     }
 
+    /// Reads the content of the file beginning at `offset` into a slice and returns the number
+    /// of bytes read but at most `buf.len()` bytes. Less bytes are read when the end of the file
+    /// is reached. In contrast to [`File::read()`] the file offset is not modified, therefore
+    /// multiple threads can read different regions of the same file concurrently.
+    pub fn read_at(&self, offset: u64, buf: &mut [u8]) -> Result<u64, FileReadError> {
+        let bytes_read = unsafe {
+            posix::pread(
+                self.file_descriptor.native_handle(),
+                buf.as_mut_ptr() as *mut posix::void,
+                buf.len(),
+                offset as posix::off_t,
+            )
+        };
+
+        if bytes_read >= 0 {
+            return Ok(bytes_read as u64);
+        }
+
+        let msg = "Unable to read file at offset";
+        handle_errno!(FileReadError, from self,
+            Errno::EINTR => (Interrupt, "{} {} since an interrupt signal was received.", msg, offset),
+            Errno::EIO => (IOerror, "{} {} since an I/O error occurred.", msg, offset),
+            Errno::EISDIR => (IsDirectory, "{} {} since it is actually a directory.", msg, offset),
+            Errno::EOVERFLOW => (FileTooBig, "{} {} since the file is too big and would cause an overflow in an internal structure.", msg, offset),
+            Errno::ENOBUFS => (InsufficientResources, "{} {} due to insufficient resources to perform the operation.", msg, offset),
+            Errno::ENOMEM => (InsufficientMemory, "{} {} due to insufficient memory to perform the operation.", msg, offset),
+            Errno::ENXIO => (NonExistingOrIncapableDevice, "{} {} since the device either does not exist or is not capable of that operation.", msg, offset),
+            Errno::ESPIPE => (FileOffsetError(FileOffsetError::DoesNotSupportSeeking), "{} {} since the file type does not support positional reads.", msg, offset),
+            v => (UnknownError(v as i32), "{} {} since an unknown error occurred ({}).", msg, offset, v)
+        )
+    }
+
     /// Reads and appending the content of a file into a vector and returns the number of bytes read.
     pub fn read_to_vector(&self, buf: &mut Vec<u8>) -> Result<u64, FileReadError> {
         let attr = fail!(from self, when File::acquire_attributes(self), "Unable to acquire file length to read contents of file.");
@@ -815,15 +866,55 @@ impl File {
         );
     }
 
-    /// Writes a slice into a file beginning from `start` and returns the number of bytes which were written.
-    pub fn write_at(&mut self, start: u64, buf: &[u8]) -> Result<u64, FileWriteError> {
-        let offset = fail!(from self, when self.seek(start), "Unable to set offset to write content at a specific position.");
+    /// Writes a slice into the file beginning at `offset` and returns the number of bytes which
+    /// were written. It may write less than `buf.len()` bytes, use [`File::write_all_at()`] to
+    /// write the whole slice. In contrast to [`File::write()`] the file offset is not modified,
+    /// therefore multiple threads can write different regions of the same file concurrently.
+    pub fn write_at(&self, offset: u64, buf: &[u8]) -> Result<u64, FileWriteError> {
+        let bytes_written = unsafe {
+            posix::pwrite(
+                self.file_descriptor.native_handle(),
+                buf.as_ptr() as *const posix::void,
+                buf.len(),
+                offset as posix::off_t,
+            )
+        };
 
-        if offset != start {
-            return Ok(0);
+        if bytes_written >= 0 {
+            return Ok(bytes_written as u64);
+        }
+
+        let msg = "Unable to write content at offset";
+        handle_errno!(FileWriteError, from self,
+            Errno::EFBIG => (WriteBufferTooBig, "{} {} since the file size would then exceed the internal maximum file size limit.", msg, offset),
+            Errno::EINTR => (Interrupt, "{} {} since an interrupt signal was received.", msg, offset),
+            Errno::EIO => (IOerror, "{} {} due to an I/O error.", msg, offset),
+            Errno::ENOSPC => (NoSpaceLeft, "{} {} since there is no space left on the device containing the file.", msg, offset),
+            Errno::ENOBUFS => (InsufficientResources, "{} {} due to insufficient resources.", msg, offset),
+            Errno::ENXIO => (NonExistingOrIncapableDevice, "{} {} since the operation is outside of the capabilities of the device or the device does not exists.", msg, offset),
+            Errno::EACCES => (InsufficientPermissions, "{} {} due to insufficient permissions.", msg, offset),
+            Errno::ESPIPE => (FileOffsetError(FileOffsetError::DoesNotSupportSeeking), "{} {} since the file type does not support positional writes.", msg, offset),
+            v => (UnknownError(v as i32), "{} {} since an unknown error occurred ({}).", msg, offset, v)
+        );
+    }
+
+    /// Writes the whole slice into the file beginning at `offset` by calling
+    /// [`File::write_at()`] until all bytes are written. The file offset is not modified.
+    pub fn write_all_at(&self, offset: u64, buf: &[u8]) -> Result<(), FileWriteError> {
+        let mut bytes_written = 0;
+        while bytes_written < buf.len() {
+            let current_offset = offset + bytes_written as u64;
+            match self.write_at(current_offset, &buf[bytes_written..])? {
+                0 => {
+                    fail!(from self, with FileWriteError::NoSpaceLeft,
+                        "Unable to write all content at offset {} since no further bytes could be written at offset {}.",
+                        offset, current_offset);
+                }
+                n => bytes_written += n as usize,
+            }
         }
 
-        self.write(buf)
+        Ok(())
     }
 
     /// Syncs all file modification with the file system.
@@ -841,6 +932,35 @@ impl File {
         );
     }
 
+    /// Reserves the storage so that the file has at least `size` bytes. Afterwards, writes
+    /// inside this range cannot fail due to insufficient space on the device. The file is
+    /// never shrunk.
+    ///
+    /// On platforms without `posix_fallocate` the file is only resized with `ftruncate` and
+    /// the storage is not reserved, e.g. on QNX and Windows. On macOS the storage is reserved
+    /// with `F_PREALLOCATE`.
+    pub fn allocate(&mut self, size: u64) -> Result<(), FileAllocateError> {
+        if size == 0 {
+            return Ok(());
+        }
+
+        let msg = "Unable to allocate";
+        handle_errno!(FileAllocateError, from self,
+            errno_source unsafe { posix::posix_fallocate(self.file_descriptor.native_handle(), 0, size as posix::off_t) }.into(),
+            success Errno::ESUCCES => (),
+            Errno::EINTR => (Interrupt, "{} {} bytes since an interrupt signal was received.", msg, size),
+            Errno::ENOSPC => (InsufficientSpace, "{} {} bytes since there is not enough space left on the device.", msg, size),
+            Errno::EFBIG => (SizeTooBig, "{} {} bytes since it exceeds the maximum supported file size.", msg, size),
+            Errno::EIO => (IOerror, "{} {} bytes due to an I/O error.", msg, size),
+            Errno::EBADF => (FileNotOpenedForWriting, "{} {} bytes since the file is not opened for writing.", msg, size),
+            Errno::ENOTSUP => (NotSupported, "{} {} bytes since the underlying file system does not support the operation.", msg, size),
+            Errno::EINVAL => (NotSupported, "{} {} bytes since the underlying file system does not support the operation.", msg, size),
+            Errno::ENODEV => (NotSupported, "{} {} bytes since the file is not a regular file.", msg, size),
+            Errno::ESPIPE => (NotSupported, "{} {} bytes since the file is a pipe.", msg, size),
+            v => (UnknownError(v as i32), "{} {} bytes since an unknown error occurred ({}).", msg, size, v)
+        );
+    }
+
     /// Returns true if `path` exists, otherwise false.
     pub fn does_exist(path: &FilePath) -> Result<bool, FileAccessError> {
         let msg = "Unable to determine if file";
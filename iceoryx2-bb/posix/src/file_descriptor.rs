@@ -69,6 +69,7 @@
 
 use core::fmt::Debug;
 
+use crate::access_control_list::*;
 use crate::config::EINTR_REPETITIONS;
 use crate::file::*;
 use crate::group::Gid;
@@ -173,6 +174,12 @@ impl FileDescriptor {
         }
     }
 
+    /// Returns true if the FileDescriptor still refers to an open file descriptor, otherwise
+    /// false, for instance when it was closed by a third party.
+    pub fn is_valid(&self) -> bool {
+        unsafe { posix::fcntl2(self.value, posix::F_GETFD) >= 0 }
+    }
+
     /// Returns the underlying value of the FileDescriptor
     ///
     /// # Safety
@@ -246,6 +253,9 @@ impl FileDescriptorManagement for FileDescriptor {}
 ///    [`set_ownership`](FileDescriptorManagement::set_ownership())
 ///  * permission handling, [`permission`](FileDescriptorManagement::permission()),
 ///    [`set_permission`](FileDescriptorManagement::set_permission())
+///  * access control list handling,
+///    [`access_control_list`](FileDescriptorManagement::access_control_list()),
+///    [`set_access_control_list`](FileDescriptorManagement::set_access_control_list())
 ///  * truncate size, [`truncate`](FileDescriptorManagement::truncate())
 ///  * accessing extended stats via [`Metadata`], [`metadata`](FileDescriptorManagement::metadata())
 ///
@@ -283,6 +293,21 @@ pub trait FileDescriptorManagement: FileDescriptorBased + Debug + Sized {
         Ok(())
     }
 
+    /// Returns the current [`AccessControlList`] of the file descriptor
+    fn access_control_list(&self) -> Result<AccessControlList, AccessControlListAcquireError> {
+        AccessControlList::acquire(self)
+    }
+
+    /// Sets a new [`AccessControlList`], replaces the group permission bits with its mask
+    fn set_access_control_list(
+        &mut self,
+        acl: &AccessControlList,
+    ) -> Result<(), AccessControlListApplyError> {
+        fail!(from self, when acl.apply(self),
+                    "Unable to update access control list.");
+        Ok(())
+    }
+
     /// Truncates to the file descriptor corresponding construct
     fn truncate(&mut self, size: usize) -> Result<(), FileTruncateError> {
         fail!(from self, when File::truncate(self, size),
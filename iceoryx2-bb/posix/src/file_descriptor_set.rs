@@ -13,6 +13,8 @@
 //! Abstracts a POSIX file descriptor set based of FD_* and select.
 //! Can be used to wait on multiple objects which implement the [`SynchronousMultiplexing`]
 //! trait.
+//! Since it is based on select, it can only handle file descriptors whose values are
+//! smaller than [`FileDescriptorSet::capacity()`].
 //!
 //! # Example
 //!
@@ -109,16 +111,28 @@ pub struct FileDescriptorSet {
 
 struct Internals {
     fd_set: posix::fd_set,
+    writable_fd_set: posix::fd_set,
     file_descriptors: Vec<i32>,
+    writable_file_descriptors: Vec<i32>,
     max_fd: i32,
 }
 
+impl Internals {
+    fn all_file_descriptors(&self) -> impl Iterator<Item = i32> + '_ {
+        self.file_descriptors
+            .iter()
+            .chain(self.writable_file_descriptors.iter())
+            .copied()
+    }
+}
+
 impl Debug for FileDescriptorSet {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
-            "FileDescriptorSet {{ file_descriptors: {:?}, max_fd: {} }}",
+            "FileDescriptorSet {{ file_descriptors: {:?}, writable_file_descriptors: {:?}, max_fd: {} }}",
             self.internals().file_descriptors,
+            self.internals().writable_file_descriptors,
             self.internals().max_fd
         )
     }
@@ -129,12 +143,15 @@ impl Default for FileDescriptorSet {
         let fd_set = FileDescriptorSet {
             internals: UnsafeCell::new(Internals {
                 fd_set: posix::fd_set::new_zeroed(),
+                writable_fd_set: posix::fd_set::new_zeroed(),
                 file_descriptors: vec![],
+                writable_file_descriptors: vec![],
                 max_fd: 0,
             }),
         };
 
         unsafe { posix::FD_ZERO(&mut fd_set.internals_mut().fd_set) };
+        unsafe { posix::FD_ZERO(&mut fd_set.internals_mut().writable_fd_set) };
 
         fd_set
     }
@@ -159,18 +176,36 @@ impl FileDescriptorSet {
         &'set self,
         fd: &'fd F,
     ) -> Result<FileDescriptorSetGuard<'set, 'fd>, FileDescriptorSetAddError> {
-        self.add_impl(fd.file_descriptor())
+        self.add_impl(fd.file_descriptor(), false)
+    }
+
+    /// Adds a file descriptor that is waited on for write-readiness. It is reported in
+    /// [`FileDescriptorSet::timed_wait()`] and [`FileDescriptorSet::blocking_wait()`] as soon
+    /// as it can accept data, independent of the provided [`FileEvent`].
+    pub fn add_writable<'set, 'fd, F: SynchronousMultiplexing>(
+        &'set self,
+        fd: &'fd F,
+    ) -> Result<FileDescriptorSetGuard<'set, 'fd>, FileDescriptorSetAddError> {
+        self.add_impl(fd.file_descriptor(), true)
     }
 
     fn add_impl<'set, 'fd>(
         &'set self,
         fd: &'fd FileDescriptor,
+        is_writable: bool,
     ) -> Result<FileDescriptorSetGuard<'set, 'fd>, FileDescriptorSetAddError> {
         let msg = "Unable to add file descriptor";
-        if self.internals().file_descriptors.len() >= Self::capacity() {
+        if self.len() >= Self::capacity() {
             fail!(from self, with FileDescriptorSetAddError::CapacityExceeded,
                 "{msg} {:?} since the amount of file descriptors {} exceeds the maximum supported amount of file descriptors for a set {}.",
-                fd.file_descriptor(), self.internals().file_descriptors.len(), Self::capacity());
+                fd.file_descriptor(), self.len(), Self::capacity());
+        }
+
+        // FD_SET/FD_ISSET are only defined for file descriptor values below FD_SETSIZE
+        if unsafe { fd.file_descriptor().native_handle() } as usize >= Self::capacity() {
+            fail!(from self, with FileDescriptorSetAddError::CapacityExceeded,
+                "{msg} {:?} since its value exceeds the maximum file descriptor value {} that select can handle.",
+                fd.file_descriptor(), Self::capacity() - 1);
         }
 
         if self.contains_impl(fd) {
@@ -178,51 +213,57 @@ impl FileDescriptorSet {
                 "{msg} {:?} since it is already attached.", fd);
         }
 
-        unsafe {
-            posix::FD_SET(
-                fd.file_descriptor().native_handle(),
-                &mut self.internals_mut().fd_set,
+        let internals = self.internals_mut();
+        let (fd_set, file_descriptors) = if is_writable {
+            (
+                &mut internals.writable_fd_set,
+                &mut internals.writable_file_descriptors,
             )
+        } else {
+            (&mut internals.fd_set, &mut internals.file_descriptors)
         };
-        self.internals_mut().max_fd = core::cmp::max(
-            self.internals().max_fd,
+
+        unsafe { posix::FD_SET(fd.file_descriptor().native_handle(), fd_set) };
+        file_descriptors.push(unsafe { fd.file_descriptor().native_handle() });
+        internals.max_fd = core::cmp::max(
+            internals.max_fd,
             unsafe { fd.file_descriptor().native_handle() } + 1,
         );
-        self.internals_mut()
-            .file_descriptors
-            .push(unsafe { fd.file_descriptor().native_handle() });
 
         Ok(FileDescriptorSetGuard { set: self, fd })
     }
 
     fn remove(&self, value: i32) {
-        unsafe { posix::FD_CLR(value, &mut self.internals_mut().fd_set) };
-
-        if self.internals_mut().max_fd == value + 1 {
-            self.internals_mut().max_fd = 0;
-            for fd in &self.internals().file_descriptors {
-                self.internals_mut().max_fd = core::cmp::max(self.internals().max_fd, fd + 1);
-            }
+        let internals = self.internals_mut();
+        unsafe { posix::FD_CLR(value, &mut internals.fd_set) };
+        unsafe { posix::FD_CLR(value, &mut internals.writable_fd_set) };
+
+        internals.file_descriptors.retain(|&v| value != v);
+        internals.writable_file_descriptors.retain(|&v| value != v);
+
+        if internals.max_fd == value + 1 {
+            internals.max_fd = internals
+                .all_file_descriptors()
+                .map(|fd| fd + 1)
+                .max()
+                .unwrap_or(0);
         }
-
-        self.internals_mut()
-            .file_descriptors
-            .retain(|&v| value != v);
     }
 
-    /// Returns the maximum capacity of the [`FileDescriptorSet`]
+    /// Returns the maximum capacity of the [`FileDescriptorSet`]. Since it is based on
+    /// select, it is also the upper bound for the value of an attached [`FileDescriptor`].
     pub const fn capacity() -> usize {
         posix::FD_SETSIZE
     }
 
     /// Returns the number of attached [`FileDescriptor`]s
     pub fn len(&self) -> usize {
-        self.internals().file_descriptors.len()
+        self.internals().file_descriptors.len() + self.internals().writable_file_descriptors.len()
     }
 
     /// Returns true if the [`FileDescriptorSet`] is empty, otherwise false
     pub fn is_empty(&self) -> bool {
-        self.internals().file_descriptors.is_empty()
+        self.len() == 0
     }
 
     /// Returns true if the object is attached to the [`FileDescriptorSet`], otherwise false.
@@ -231,7 +272,15 @@ impl FileDescriptorSet {
     }
 
     fn contains_impl(&self, fd: &FileDescriptor) -> bool {
-        unsafe { posix::FD_ISSET(fd.native_handle(), &self.internals().fd_set) }
+        let native_handle = unsafe { fd.native_handle() };
+        if native_handle as usize >= Self::capacity() {
+            return false;
+        }
+
+        unsafe {
+            posix::FD_ISSET(native_handle, &self.internals().fd_set)
+                || posix::FD_ISSET(native_handle, &self.internals().writable_fd_set)
+        }
     }
 
     /// Blocks until the specified event has occurred. It
@@ -262,28 +311,52 @@ impl FileDescriptorSet {
         event: FileEvent,
         mut fd_callback: F,
     ) -> Result<usize, FileDescriptorSetWaitError> {
-        let mut fd_set: posix::fd_set = self.internals().fd_set;
-
-        let read_fd: *mut posix::fd_set = match event {
+        let internals = self.internals();
+        let has_read = matches!(
+            event,
             FileEvent::Read
-            | FileEvent::ReadWrite
-            | FileEvent::ReadExceptional
-            | FileEvent::ReadWriteExceptional => &mut fd_set,
-            _ => core::ptr::null_mut::<posix::fd_set>(),
-        };
-        let write_fd: *mut posix::fd_set = match event {
+                | FileEvent::ReadWrite
+                | FileEvent::ReadExceptional
+                | FileEvent::ReadWriteExceptional
+        );
+        let has_write = matches!(
+            event,
             FileEvent::Write
-            | FileEvent::ReadWrite
-            | FileEvent::WriteExceptional
-            | FileEvent::ReadWriteExceptional => &mut fd_set,
-            _ => core::ptr::null_mut::<posix::fd_set>(),
-        };
-        let exceptional_fd: *mut posix::fd_set = match event {
+                | FileEvent::ReadWrite
+                | FileEvent::WriteExceptional
+                | FileEvent::ReadWriteExceptional
+        );
+        let has_exceptional = matches!(
+            event,
             FileEvent::Exceptional
-            | FileEvent::ReadExceptional
-            | FileEvent::WriteExceptional
-            | FileEvent::ReadWriteExceptional => &mut fd_set,
-            _ => core::ptr::null_mut::<posix::fd_set>(),
+                | FileEvent::ReadExceptional
+                | FileEvent::WriteExceptional
+                | FileEvent::ReadWriteExceptional
+        );
+
+        // every event requires its own copy since select overwrites the sets with the result
+        let mut read_set = internals.fd_set;
+        let mut write_set = internals.writable_fd_set;
+        let mut exceptional_set = internals.fd_set;
+
+        if has_write {
+            for fd in &internals.file_descriptors {
+                unsafe { posix::FD_SET(*fd, &mut write_set) };
+            }
+        }
+
+        let read_fd: *mut posix::fd_set = match has_read {
+            true => &mut read_set,
+            false => core::ptr::null_mut::<posix::fd_set>(),
+        };
+        let write_fd: *mut posix::fd_set =
+            match has_write || !internals.writable_file_descriptors.is_empty() {
+                true => &mut write_set,
+                false => core::ptr::null_mut::<posix::fd_set>(),
+            };
+        let exceptional_fd: *mut posix::fd_set = match has_exceptional {
+            true => &mut exceptional_set,
+            false => core::ptr::null_mut::<posix::fd_set>(),
         };
 
         let msg = "Failure while waiting for file descriptor events";
@@ -309,9 +382,15 @@ impl FileDescriptorSet {
             );
         }
 
-        for raw_fd in &self.internals().file_descriptors {
-            if unsafe { posix::FD_ISSET(*raw_fd, &fd_set) } {
-                let fd = FileDescriptor::non_owning_new(*raw_fd).unwrap();
+        let is_triggered = |raw_fd: i32| unsafe {
+            (!read_fd.is_null() && posix::FD_ISSET(raw_fd, &read_set))
+                || (!write_fd.is_null() && posix::FD_ISSET(raw_fd, &write_set))
+                || (!exceptional_fd.is_null() && posix::FD_ISSET(raw_fd, &exceptional_set))
+        };
+
+        for raw_fd in internals.all_file_descriptors() {
+            if is_triggered(raw_fd) {
+                let fd = FileDescriptor::non_owning_new(raw_fd).unwrap();
                 fd_callback(&fd);
             }
         }
@@ -11,7 +11,9 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 //! A FileLock can be created around any object which implements the [`FileDescriptorBased`] trait.
-//! Either one can exclusively lock the file for writing or many can lock it for reading.
+//! Either one can exclusively lock the file for writing or many can lock it for reading. The lock
+//! covers the whole file by default, a byte range can be defined with
+//! [`FileLockBuilder::byte_range()`].
 //!
 //! # Example
 //!
@@ -51,7 +53,10 @@ use iceoryx2_pal_posix::posix::errno::Errno;
 use iceoryx2_pal_posix::posix::MemZeroedStruct;
 use iceoryx2_pal_posix::*;
 
-use crate::clock::NanosleepError;
+use crate::clock::{nanosleep, ClockType, NanosleepError, Time};
+use core::time::Duration;
+
+const TIMED_LOCK_POLLING_INTERVAL: Duration = Duration::from_millis(1);
 
 enum_gen! { FileWriterGetLockError
   mapping:
@@ -181,13 +186,25 @@ impl<T: FileDescriptorBased + Debug> Drop for FileLockReadGuard<'_, '_, T> {
 /// One has to create an object first which implements the [`FileDescriptorBased`] trait.
 ///
 #[derive(Debug, Default)]
-pub struct FileLockBuilder {}
+pub struct FileLockBuilder {
+    start: u64,
+    len: u64,
+}
 
 impl FileLockBuilder {
     pub fn new() -> Self {
         Self::default()
     }
 
+    /// Restricts the [`FileLock`] to `len` bytes beginning at `start`. When `len` is zero the
+    /// lock extends to the end of the file, even when the file grows. Locks of non-overlapping
+    /// ranges do not block each other. By default the whole file is locked.
+    pub fn byte_range(mut self, start: u64, len: u64) -> Self {
+        self.start = start;
+        self.len = len;
+        self
+    }
+
     pub fn create<T: FileDescriptorBased + Debug>(
         self,
         value: T,
@@ -208,10 +225,21 @@ impl FileLockBuilder {
 /// instance opens the file twice in the same process the read/write locking will not work anymore.
 /// But it works between processes. If two different processes are opening the same file
 /// read/writer locks will block the processes.
+///
+/// **Attention!** The underlying `fcntl` locks are owned by the process and not by the file
+/// descriptor.
+///  * A child created with `fork()` does not inherit the locks of its parent, even though it
+///    inherits the file descriptor.
+///  * The locks survive an `exec()` as long as the file descriptor stays open, so they are
+///    released when the file descriptor was created with close-on-exec.
+///  * Closing **any** file descriptor of the same file in the process releases all locks of the
+///    process on that file.
 #[derive(Debug)]
 pub struct FileLock<'a, T: FileDescriptorBased + Debug> {
     file: ReadWriteMutex<'a, 'a, T>,
     lock_state: IoxAtomicI64,
+    start: u64,
+    len: u64,
 }
 
 unsafe impl<T: Send + FileDescriptorBased + Debug> Send for FileLock<'_, T> {}
@@ -262,6 +290,8 @@ impl<'a, T: FileDescriptorBased + Debug> FileLock<'a, T> {
                 .create(value, handle),
                 "Failed to create ReadWriteMutex for FileLock."),
             lock_state: IoxAtomicI64::new(0),
+            start: config.start,
+            len: config.len,
         })
     }
 
@@ -313,6 +343,36 @@ impl<'a, T: FileDescriptorBased + Debug> FileLock<'a, T> {
         }
     }
 
+    /// Tries to acquire the write lock until the timeout has passed. If the lock could be
+    /// acquired it returns a [`FileLockWriteGuard`] which provides read and write access to the
+    /// underlying file and releases the lock as soon as it goes out of scope. Otherwise it
+    /// returns [`None`].
+    /// A write-lock can be acquired when no reader and no writer locks are acquired by any
+    /// other participant.
+    pub fn write_timed_lock(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<FileLockWriteGuard<'_, '_, T>>, FileWriterGetLockError> {
+        self.timed_lock(timeout, || {
+            let guard = match self.file.write_try_lock()? {
+                Some(guard) => guard,
+                None => return Ok(None),
+            };
+
+            match self.internal_lock(
+                LockType::Write,
+                InternalMode::NonBlocking,
+                guard.file_descriptor(),
+            )? {
+                true => Ok(Some(FileLockWriteGuard {
+                    file_lock: self,
+                    guard,
+                })),
+                false => Ok(None),
+            }
+        })
+    }
+
     /// Blocking until the read lock of the underlying file is acquired. Returns a
     /// [`FileLockReadGuard`] which provides read access to the underlying file and releases the
     /// lock as soon as it goes out of scope.
@@ -360,6 +420,35 @@ impl<'a, T: FileDescriptorBased + Debug> FileLock<'a, T> {
         }
     }
 
+    /// Tries to acquire a read lock of the underlying file until the timeout has passed. If the
+    /// lock could be acquired it returns a [`FileLockReadGuard`] which provides read access to
+    /// the underlying file and releases the lock as soon as it goes out of scope. Otherwise it
+    /// returns [`None`].
+    /// A read-lock can be acquired when no write lock is acquired by any other participant.
+    pub fn read_timed_lock(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<FileLockReadGuard<'_, '_, T>>, FileReaderGetLockError> {
+        self.timed_lock(timeout, || {
+            let guard = match self.file.read_try_lock()? {
+                Some(guard) => guard,
+                None => return Ok(None),
+            };
+
+            match self.internal_lock(
+                LockType::Read,
+                InternalMode::NonBlocking,
+                guard.file_descriptor(),
+            )? {
+                true => Ok(Some(FileLockReadGuard {
+                    file_lock: self,
+                    guard,
+                })),
+                false => Ok(None),
+            }
+        })
+    }
+
     /// Returns the current [`LockState`] of the [`FileLock`].
     pub fn get_lock_state(&self) -> Result<LockState, FileLockStateError> {
         match 0.cmp(&self.lock_state.load(Ordering::Relaxed)) {
@@ -379,8 +468,7 @@ impl<'a, T: FileDescriptorBased + Debug> FileLock<'a, T> {
         }
 
         let msg = "Unable to acquire current file lock state";
-        let mut current_lock_state = posix::flock::new_zeroed();
-        current_lock_state.l_type = posix::F_WRLCK as _;
+        let mut current_lock_state = self.new_flock(LockType::Write);
 
         let fd_guard = fail!(from self, when self.file.read_blocking_lock(),
             "{} due to an internal failure in while acquiring the mutex.", msg);
@@ -409,10 +497,42 @@ impl<'a, T: FileDescriptorBased + Debug> FileLock<'a, T> {
         }
     }
 
+    fn new_flock(&self, lock_type: LockType) -> posix::flock {
+        let mut lock = posix::flock::new_zeroed();
+        lock.l_type = lock_type as _;
+        lock.l_whence = posix::SEEK_SET as _;
+        lock.l_start = self.start as _;
+        lock.l_len = self.len as _;
+        lock
+    }
+
+    fn timed_lock<G, E: From<NanosleepError> + Debug, F: FnMut() -> Result<Option<G>, E>>(
+        &self,
+        timeout: Duration,
+        mut try_lock: F,
+    ) -> Result<Option<G>, E> {
+        let msg = "Unable to acquire file-lock with timeout";
+        let start = fail!(from self, when Time::now_with_clock(ClockType::Monotonic).map_err(NanosleepError::from),
+                            "{} since the current time could not be acquired.", msg);
+
+        loop {
+            if let Some(guard) = try_lock()? {
+                return Ok(Some(guard));
+            }
+
+            let elapsed = fail!(from self, when start.elapsed().map_err(NanosleepError::from),
+                                "{} since the elapsed time could not be acquired.", msg);
+            if elapsed >= timeout {
+                return Ok(None);
+            }
+
+            fail!(from self, when nanosleep(TIMED_LOCK_POLLING_INTERVAL.min(timeout - elapsed)),
+                "{} since the sleep between two lock attempts failed.", msg);
+        }
+    }
+
     fn release(&self, file_descriptor: &FileDescriptor) -> Result<(), FileUnlockError> {
-        let mut new_lock_state = posix::flock::new_zeroed();
-        new_lock_state.l_type = LockType::Unlock as _;
-        new_lock_state.l_whence = posix::SEEK_SET as _;
+        let mut new_lock_state = self.new_flock(LockType::Unlock);
 
         let msg = "Unable to release file-lock";
         if unsafe {
@@ -440,9 +560,7 @@ impl<'a, T: FileDescriptorBased + Debug> FileLock<'a, T> {
         mode: InternalMode,
         file_descriptor: &FileDescriptor,
     ) -> Result<bool, FileTryLockError> {
-        let mut new_lock_state = posix::flock::new_zeroed();
-        new_lock_state.l_type = lock_type as _;
-        new_lock_state.l_whence = posix::SEEK_SET as _;
+        let mut new_lock_state = self.new_flock(lock_type);
 
         if unsafe {
             posix::fcntl(
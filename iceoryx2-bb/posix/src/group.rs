@@ -278,6 +278,20 @@ impl Group {
         self.details.as_ref()
     }
 
+    /// Returns the names of all users that are listed as members of the group in the group
+    /// database. Users that have the group as their primary group are usually not listed. If
+    /// the [`GroupDetails`] are not available the list is empty.
+    pub fn members(&self) -> Vec<UserName> {
+        match self.details {
+            Some(ref details) => details.members(),
+            None => vec![],
+        }
+    }
+
+    pub(crate) fn without_details(gid: Gid) -> Group {
+        Group { gid, details: None }
+    }
+
     fn extract_group_details(
         msg: &str,
         origin: &str,
@@ -16,6 +16,8 @@
 
 //! Abstraction of POSIX constructs with a safe API
 
+extern crate alloc;
+
 use barrier::BarrierCreationError;
 use clock::ClockError;
 use directory::DirectoryError;
@@ -34,8 +36,10 @@ use thread::ThreadError;
 use unix_datagram_socket::UnixDatagramError;
 use user::UserError;
 
+pub mod access_control_list;
 pub mod access_mode;
 pub mod adaptive_wait;
+pub mod anonymous_shared_memory;
 pub mod barrier;
 pub mod clock;
 pub mod config;
@@ -66,14 +70,18 @@ pub mod scheduler;
 pub mod semaphore;
 pub mod shared_memory;
 pub mod signal;
+pub mod signal_listener;
 pub mod signal_set;
+pub mod socket_address;
 pub mod socket_ancillary;
 pub mod system_configuration;
+pub mod tcp_socket;
 #[doc(hidden)]
 pub mod testing;
 pub mod thread;
 pub mod unique_system_id;
 pub mod unix_datagram_socket;
+pub mod unix_stream_socket;
 pub mod user;
 
 enum_gen! {Error
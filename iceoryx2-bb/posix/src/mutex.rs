@@ -599,7 +599,7 @@ impl<'this, 'handle: 'this, T: Debug> Mutex<'this, 'handle, T> {
             ClockType::Realtime => {
                 let now = fail!(from self, when Time::now_with_clock(ClockType::Realtime),
                     "{} due to a failure while acquiring current system time.", msg);
-                let timeout = now.as_duration() + duration;
+                let timeout = now.saturating_add(duration);
                 handle_errno!(MutexTimedLockError, from self,
                     errno_source unsafe { posix::pthread_mutex_timedlock(self.handle.handle.get(), &timeout.as_timespec()) }.into(),
                     success Errno::ESUCCES => Some(MutexGuard { handle: self.handle });
@@ -611,8 +611,8 @@ impl<'this, 'handle: 'this, T: Debug> Mutex<'this, 'handle, T> {
                     v => (MutexLockError(MutexLockError::UnknownError(v as i32)), "{} since unknown error occurred while acquiring the lock ({})", msg, v)
                 )
             }
-            ClockType::Monotonic => {
-                let time = fail!(from self, when Time::now_with_clock(ClockType::Monotonic),
+            ClockType::Monotonic | ClockType::Boottime => {
+                let time = fail!(from self, when Time::now_with_clock(self.handle.clock_type()),
                     "{} due to a failure while acquiring current system time.", msg);
                 let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new()
                     .clock_type(self.handle.clock_type())
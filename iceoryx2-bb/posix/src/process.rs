@@ -39,18 +39,44 @@
 //!             process.get_scheduler().expect("failed to get scheduler"),
 //!             process.get_priority().expect("failed to get priority"));
 //! ```
+//!
+//! ## Spawn A Child Process
+//!
+//! ```no_run
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//! use iceoryx2_bb_posix::process::*;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//!
+//! let mut child = ProcessBuilder::new(&FilePath::new(b"/bin/sh").unwrap())
+//!                     .argument("-c")
+//!                     .argument("echo $GREETING")
+//!                     .environment_variable("GREETING", "hello")
+//!                     .spawn()
+//!                     .expect("failed to spawn process");
+//!
+//! match child.wait().expect("failed to wait for process") {
+//!     ProcessExitStatus::Exited(code) => println!("exited with {}", code),
+//!     ProcessExitStatus::Signaled(signal) => println!("terminated by signal {}", signal),
+//! }
+//! ```
+use alloc::ffi::CString;
 use core::fmt::Display;
 
 use crate::handle_errno;
-use iceoryx2_bb_elementary::enum_gen;
-use iceoryx2_bb_log::fail;
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_elementary::{enum_gen, scope_guard::ScopeGuardBuilder};
+use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_bb_system_types::file_path::*;
+use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_pal_posix::posix::{errno::Errno, MemZeroedStruct};
 use iceoryx2_pal_posix::*;
 
 use crate::{
+    directory::Directory,
+    file_descriptor::{FileDescriptor, FileDescriptorBased},
     scheduler::{Scheduler, SchedulerConversionError},
     signal::Signal,
+    system_configuration::Limit,
 };
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -86,6 +112,30 @@ enum_gen! { ProcessSetSchedulerError
     SchedulerConversionError
 }
 
+/// Lists the open file descriptors of the current process on platforms that support it.
+const OPEN_FILE_DESCRIPTORS_DIRECTORY: &[u8] = b"/proc/self/fd";
+
+enum_gen! { ProcessSpawnError
+  entry:
+    ExecutableDoesNotExist,
+    NotAnExecutable,
+    InsufficientPermissions,
+    InsufficientMemory,
+    InsufficientResources,
+    ArgumentListTooLong,
+    ContainsNullByte,
+    FileDescriptorInheritanceNotSupported,
+    WorkingDirectoryNotSupported,
+    UnknownFileDescriptorLimit,
+    UnknownError(i32)
+}
+
+enum_gen! { ProcessWaitError
+  entry:
+    Interrupt,
+    UnknownError(i32)
+}
+
 enum_gen! {
     /// The ProcessError enum is a generalization when one doesn't require the fine-grained error
     /// handling enums. One can forward ProcessError as more generic return value when a method
@@ -95,7 +145,9 @@ enum_gen! {
   generalization:
     FailedToSetSchedulerSettings <= ProcessSetSchedulerError,
     FailedToGetSchedulerSettings <= ProcessGetSchedulerError,
-    FailedToSendSignal <= ProcessSendSignalError
+    FailedToSendSignal <= ProcessSendSignalError,
+    FailedToSpawn <= ProcessSpawnError,
+    FailedToWait <= ProcessWaitError
 }
 
 /// Trait to be able to convert integers into processes by interpreting their value as the
@@ -111,7 +163,7 @@ impl ProcessExt for posix::pid_t {
 }
 
 /// Represents a process id.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct ProcessId(posix::pid_t);
 
 impl ProcessId {
@@ -278,3 +330,406 @@ impl Process {
         );
     }
 }
+
+/// Describes how a [`ChildProcess`] terminated.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum ProcessExitStatus {
+    /// The process exited regularly with the contained exit code.
+    Exited(i32),
+    /// The process was terminated by the contained signal number.
+    Signaled(i32),
+}
+
+impl ProcessExitStatus {
+    /// Returns true when the process exited regularly with the exit code 0.
+    pub fn is_success(&self) -> bool {
+        *self == ProcessExitStatus::Exited(0)
+    }
+}
+
+/// The builder for a [`ChildProcess`]. By default, the child
+///  * inherits the environment of the current process,
+///  * inherits only the standard input, output and error file descriptors, all others are
+///    closed in the child unless they are added with
+///    [`ProcessBuilder::inherit_file_descriptor()`],
+///  * starts with an empty signal mask and default signal dispositions.
+#[derive(Debug)]
+pub struct ProcessBuilder<'fd> {
+    executable: FilePath,
+    arguments: Vec<String>,
+    environment: Vec<(String, String)>,
+    inherit_environment: bool,
+    working_directory: Option<Path>,
+    inherited_file_descriptors: Vec<&'fd FileDescriptor>,
+    reset_signal_mask: bool,
+}
+
+impl<'fd> ProcessBuilder<'fd> {
+    /// Creates a new builder for a process that executes the provided executable. The
+    /// executable is not searched in `PATH`, it is also provided as first argument.
+    pub fn new(executable: &FilePath) -> Self {
+        Self {
+            executable: executable.clone(),
+            arguments: vec![],
+            environment: vec![],
+            inherit_environment: true,
+            working_directory: None,
+            inherited_file_descriptors: vec![],
+            reset_signal_mask: true,
+        }
+    }
+
+    /// Appends an argument.
+    pub fn argument(mut self, value: &str) -> Self {
+        self.arguments.push(value.to_string());
+        self
+    }
+
+    /// Sets an environment variable of the child. It overrides a variable with the same name
+    /// that is inherited from the current process.
+    pub fn environment_variable(mut self, key: &str, value: &str) -> Self {
+        self.environment.retain(|(k, _)| k != key);
+        self.environment.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Defines if the child inherits the environment of the current process. When it is
+    /// disabled, the child only has the variables set with
+    /// [`ProcessBuilder::environment_variable()`].
+    pub fn inherit_environment(mut self, value: bool) -> Self {
+        self.inherit_environment = value;
+        self
+    }
+
+    /// Sets the working directory of the child. If it is not set, the child uses the working
+    /// directory of the current process.
+    pub fn working_directory(mut self, value: &Path) -> Self {
+        self.working_directory = Some(value.clone());
+        self
+    }
+
+    /// The child inherits the file descriptor under the same value. It must remain open
+    /// until [`ProcessBuilder::spawn()`] returned.
+    pub fn inherit_file_descriptor<T: FileDescriptorBased>(mut self, value: &'fd T) -> Self {
+        self.inherited_file_descriptors
+            .push(value.file_descriptor());
+        self
+    }
+
+    /// Defines if the signal mask of the child is emptied and if all signal dispositions,
+    /// including ignored signals, are reset to their default. Otherwise, the child inherits
+    /// the signal mask and the ignored signals of the current process.
+    pub fn reset_signal_mask(mut self, value: bool) -> Self {
+        self.reset_signal_mask = value;
+        self
+    }
+
+    fn c_string(&self, value: &[u8], msg: &str) -> Result<CString, ProcessSpawnError> {
+        Ok(fail!(from self, when CString::new(value),
+                with ProcessSpawnError::ContainsNullByte,
+                "{} since \"{}\" contains a null byte.", msg, String::from_utf8_lossy(value)))
+    }
+
+    fn environment(&self, msg: &str) -> Result<Vec<CString>, ProcessSpawnError> {
+        let mut environment = vec![];
+        if self.inherit_environment {
+            for (key, value) in std::env::vars_os() {
+                let key = key.as_encoded_bytes();
+                if self.environment.iter().any(|(k, _)| k.as_bytes() == key) {
+                    continue;
+                }
+
+                let mut entry = key.to_vec();
+                entry.push(b'=');
+                entry.extend_from_slice(value.as_encoded_bytes());
+                environment.push(self.c_string(&entry, msg)?);
+            }
+        }
+
+        for (key, value) in &self.environment {
+            environment.push(self.c_string(format!("{key}={value}").as_bytes(), msg)?);
+        }
+
+        Ok(environment)
+    }
+
+    /// Returns the candidates of file descriptors that are open in the current process. When
+    /// the platform lists them in [`OPEN_FILE_DESCRIPTORS_DIRECTORY`] only the open file
+    /// descriptors are returned, otherwise all values up to
+    /// [`Limit::MaxNumberOfOpenFiles`].
+    fn open_file_descriptors(&self) -> Result<Vec<i32>, ProcessSpawnError> {
+        let fd_directory = unsafe { Path::new_unchecked(OPEN_FILE_DESCRIPTORS_DIRECTORY) };
+        // the directory is closed before the file descriptors are inspected, so that its own
+        // file descriptor is not closed in the child
+        let contents = match Directory::new(&fd_directory) {
+            Ok(dir) => dir.contents().ok(),
+            Err(_) => None,
+        };
+        if let Some(contents) = contents {
+            return Ok(contents
+                .iter()
+                .filter_map(|entry| core::str::from_utf8(entry.name().as_bytes()).ok())
+                .filter_map(|name| name.parse::<i32>().ok())
+                .collect());
+        }
+
+        let max_number_of_fds = Limit::MaxNumberOfOpenFiles.value();
+        if max_number_of_fds == 0 {
+            fail!(from self, with ProcessSpawnError::UnknownFileDescriptorLimit,
+                "Unable to close the file descriptors in the child since they cannot be listed and the maximum number of open files is unknown.");
+        }
+
+        Ok((0..max_number_of_fds.min(i32::MAX as u64) as i32).collect())
+    }
+
+    fn setup_file_actions(
+        &self,
+        file_actions: &mut posix::posix_spawn_file_actions_t,
+    ) -> Result<(), ProcessSpawnError> {
+        let mut inherited_fds = vec![];
+        for fd in &self.inherited_file_descriptors {
+            let fd = unsafe { fd.native_handle() };
+            inherited_fds.push(fd);
+
+            // duplicating a file descriptor onto itself clears FD_CLOEXEC in the child
+            let msg = "Unable to inherit file descriptor";
+            handle_errno!(ProcessSpawnError, from self,
+                errno_source unsafe { posix::posix_spawn_file_actions_adddup2(file_actions, fd, fd).into() },
+                continue_on_success,
+                success Errno::ESUCCES => (),
+                Errno::ENOMEM => (InsufficientMemory, "{} {} due to insufficient memory.", msg, fd),
+                Errno::ENOTSUP => (FileDescriptorInheritanceNotSupported, "{} {} since it is not supported by the platform.", msg, fd),
+                v => (UnknownError(v as i32), "{} {} since an unknown error occurred ({}).", msg, fd, v)
+            );
+        }
+
+        // every file descriptor without FD_CLOEXEC would be inherited by the child
+        let msg = "Unable to close file descriptor in child";
+        for fd in self.open_file_descriptors()? {
+            if fd < 3 || inherited_fds.contains(&fd) {
+                continue;
+            }
+
+            let flags = unsafe { posix::fcntl2(fd, posix::F_GETFD) };
+            if flags < 0 || flags & posix::FD_CLOEXEC != 0 {
+                continue;
+            }
+
+            handle_errno!(ProcessSpawnError, from self,
+                errno_source unsafe { posix::posix_spawn_file_actions_addclose(file_actions, fd).into() },
+                continue_on_success,
+                success Errno::ESUCCES => (),
+                Errno::ENOMEM => (InsufficientMemory, "{} {} due to insufficient memory.", msg, fd),
+                v => (UnknownError(v as i32), "{} {} since an unknown error occurred ({}).", msg, fd, v)
+            );
+        }
+
+        if let Some(working_directory) = &self.working_directory {
+            let msg = "Unable to set working directory";
+            handle_errno!(ProcessSpawnError, from self,
+                errno_source unsafe { posix::posix_spawn_file_actions_addchdir(file_actions, working_directory.as_c_str()).into() },
+                continue_on_success,
+                success Errno::ESUCCES => (),
+                Errno::ENOMEM => (InsufficientMemory, "{} \"{}\" due to insufficient memory.", msg, working_directory),
+                Errno::ENOSYS => (WorkingDirectoryNotSupported, "{} \"{}\" since it is not supported by the platform.", msg, working_directory),
+                v => (UnknownError(v as i32), "{} \"{}\" since an unknown error occurred ({}).", msg, working_directory, v)
+            );
+        }
+
+        Ok(())
+    }
+
+    fn setup_attributes(
+        &self,
+        attributes: &mut posix::posix_spawnattr_t,
+    ) -> Result<(), ProcessSpawnError> {
+        if !self.reset_signal_mask {
+            return Ok(());
+        }
+
+        let mut empty_set = posix::sigset_t::new_zeroed();
+        let mut all_signals = posix::sigset_t::new_zeroed();
+        unsafe {
+            posix::sigemptyset(&mut empty_set);
+            posix::sigfillset(&mut all_signals);
+        }
+
+        let mut result = unsafe { posix::posix_spawnattr_setsigmask(attributes, &empty_set) };
+        if result == 0 {
+            result = unsafe { posix::posix_spawnattr_setsigdefault(attributes, &all_signals) };
+        }
+        if result == 0 {
+            result = unsafe {
+                posix::posix_spawnattr_setflags(
+                    attributes,
+                    posix::POSIX_SPAWN_SETSIGMASK | posix::POSIX_SPAWN_SETSIGDEF,
+                )
+            };
+        }
+
+        if result != 0 {
+            fail!(from self, with ProcessSpawnError::UnknownError(result),
+                "Unable to reset the signal mask of the child since an unknown error occurred ({}).", result);
+        }
+
+        Ok(())
+    }
+
+    /// Spawns the child process.
+    pub fn spawn(self) -> Result<ChildProcess, ProcessSpawnError> {
+        let msg = "Unable to spawn process";
+        let mut arguments = vec![self.c_string(self.executable.as_bytes(), msg)?];
+        for argument in &self.arguments {
+            arguments.push(self.c_string(argument.as_bytes(), msg)?);
+        }
+        let mut argv: Vec<*mut posix::c_char> =
+            arguments.iter().map(|v| v.as_ptr() as *mut _).collect();
+        argv.push(core::ptr::null_mut());
+
+        let environment = self.environment(msg)?;
+        let mut envp: Vec<*mut posix::c_char> =
+            environment.iter().map(|v| v.as_ptr() as *mut _).collect();
+        envp.push(core::ptr::null_mut());
+
+        let mut file_actions = ScopeGuardBuilder::new(posix::posix_spawn_file_actions_t::new_zeroed())
+            .on_init(|actions| {
+                let msg = "Failed to initialize file actions";
+                handle_errno!(ProcessSpawnError, from self,
+                    errno_source unsafe { posix::posix_spawn_file_actions_init(actions).into() },
+                    success Errno::ESUCCES => (),
+                    Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+                    v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+                );
+            }).on_drop(|actions| match unsafe { posix::posix_spawn_file_actions_destroy(actions) } {
+                0 => (),
+                v => {
+                    fatal_panic!(from self, "This should never happen! Failed to cleanup file actions ({}).", v);
+                }
+            }).create()?;
+        self.setup_file_actions(file_actions.get_mut())?;
+
+        let mut attributes = ScopeGuardBuilder::new(posix::posix_spawnattr_t::new_zeroed())
+            .on_init(|attr| {
+                let msg = "Failed to initialize spawn attributes";
+                handle_errno!(ProcessSpawnError, from self,
+                    errno_source unsafe { posix::posix_spawnattr_init(attr).into() },
+                    success Errno::ESUCCES => (),
+                    Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+                    v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+                );
+            }).on_drop(|attr| match unsafe { posix::posix_spawnattr_destroy(attr) } {
+                0 => (),
+                v => {
+                    fatal_panic!(from self, "This should never happen! Failed to cleanup spawn attributes ({}).", v);
+                }
+            }).create()?;
+        self.setup_attributes(attributes.get_mut())?;
+
+        let mut pid: posix::pid_t = 0;
+        handle_errno!(ProcessSpawnError, from self,
+            errno_source unsafe {
+                posix::posix_spawn(
+                    &mut pid,
+                    self.executable.as_c_str(),
+                    file_actions.get(),
+                    attributes.get(),
+                    argv.as_ptr(),
+                    envp.as_ptr(),
+                ).into()
+            },
+            continue_on_success,
+            success Errno::ESUCCES => (),
+            Errno::ENOENT => (ExecutableDoesNotExist, "{} since the executable or the working directory does not exist.", msg),
+            Errno::ENOEXEC => (NotAnExecutable, "{} since the file is not an executable.", msg),
+            Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+            Errno::EPERM => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+            Errno::E2BIG => (ArgumentListTooLong, "{} since the argument list and environment are too long.", msg),
+            Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+            Errno::EAGAIN => (InsufficientResources, "{} due to insufficient resources. Maybe the system limit of processes is reached.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+
+        Ok(ChildProcess {
+            process: Process::from_pid(ProcessId::new(pid)),
+            exit_status: None,
+        })
+    }
+}
+
+/// A process that was spawned by the [`ProcessBuilder`]. When it goes out of scope while
+/// the process is still running, the process is not terminated and remains a zombie after
+/// it exited, until the current process exits.
+#[derive(Debug)]
+pub struct ChildProcess {
+    process: Process,
+    exit_status: Option<ProcessExitStatus>,
+}
+
+impl Drop for ChildProcess {
+    fn drop(&mut self) {
+        // reap the process if it already exited
+        let _ = self.try_wait();
+    }
+}
+
+impl ChildProcess {
+    /// Returns the id of the process.
+    pub fn id(&self) -> ProcessId {
+        self.process.id()
+    }
+
+    /// Blocks until the process exited and returns its [`ProcessExitStatus`].
+    pub fn wait(&mut self) -> Result<ProcessExitStatus, ProcessWaitError> {
+        loop {
+            if let Some(exit_status) = self.wait_impl(0)? {
+                return Ok(exit_status);
+            }
+        }
+    }
+
+    /// Returns the [`ProcessExitStatus`] when the process exited, otherwise [`None`].
+    pub fn try_wait(&mut self) -> Result<Option<ProcessExitStatus>, ProcessWaitError> {
+        self.wait_impl(posix::WNOHANG)
+    }
+
+    /// Sends [`Signal::Terminate`] to the process.
+    pub fn terminate(&self) -> Result<(), ProcessSendSignalError> {
+        if self.exit_status.is_some() {
+            fail!(from self, with ProcessSendSignalError::UnknownProcessId,
+                "Unable to terminate process since it already exited.");
+        }
+
+        self.process.send_signal(Signal::Terminate)
+    }
+
+    fn wait_impl(
+        &mut self,
+        options: posix::int,
+    ) -> Result<Option<ProcessExitStatus>, ProcessWaitError> {
+        if self.exit_status.is_some() {
+            return Ok(self.exit_status);
+        }
+
+        let mut status: posix::int = 0;
+        let pid = unsafe { posix::waitpid(self.id().value(), &mut status, options) };
+        if pid == 0 {
+            return Ok(None);
+        }
+
+        if pid == self.id().value() {
+            let exit_status = if posix::WIFSIGNALED(status) {
+                ProcessExitStatus::Signaled(posix::WTERMSIG(status))
+            } else {
+                ProcessExitStatus::Exited(posix::WEXITSTATUS(status))
+            };
+            self.exit_status = Some(exit_status);
+            return Ok(self.exit_status);
+        }
+
+        let msg = "Unable to wait for process";
+        handle_errno!(ProcessWaitError, from self,
+            Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+}
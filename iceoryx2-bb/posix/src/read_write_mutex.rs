@@ -12,7 +12,9 @@
 
 //! A POSIX inter-process capable [`ReadWriteMutex`] where either multiple readers can acquire
 //! multiple read-locks or one writer can acquire a write-lock.
-//! It is built by the [`ReadWriteMutexBuilder`].
+//! It is built by the [`ReadWriteMutexBuilder`]. On most platforms readers are preferred by
+//! default which can starve writers under heavy read load, this can be changed with
+//! [`ReadWriteMutexBuilder::preference()`].
 //!
 //! # Example
 //!
@@ -25,6 +27,7 @@
 //! let rw_handle = ReadWriteMutexHandle::new();
 //! let rw_mutex = ReadWriteMutexBuilder::new()
 //!                         .is_interprocess_capable(true)
+//!                         .preference(ReadWriteMutexPreference::Writer)
 //!                         .create(123, &rw_handle)
 //!                         .expect("failed to create rw mutex");
 //!
@@ -42,10 +45,20 @@
 //!         *guard = 456;
 //!         println!("The new value is: {}", *guard);
 //!     });
+//!
+//!     s.spawn(|| {
+//!         match rw_mutex.read_timed_lock(Duration::from_millis(100)) {
+//!             Ok(guard) => println!("The mutex value is: {}", *guard),
+//!             Err(ReadWriteMutexTimedReadLockError::Timeout) =>
+//!                 println!("Timeout occurred while trying to get read-lock."),
+//!             Err(e) => println!("Failed to read_lock ({:?}).", e),
+//!         }
+//!     });
 //! });
 //! ```
 pub use crate::ipc_capable::{Handle, IpcCapable};
 
+use crate::clock::{AsTimespec, ClockType, Time, TimeError};
 use crate::handle_errno;
 use crate::ipc_capable::internal::{Capability, HandleStorage, IpcConstructible};
 use iceoryx2_bb_elementary::{enum_gen, scope_guard::ScopeGuardBuilder};
@@ -55,6 +68,7 @@ use iceoryx2_pal_posix::posix::MemZeroedStruct;
 use iceoryx2_pal_posix::*;
 
 use core::marker::PhantomData;
+use core::time::Duration;
 use core::{
     cell::UnsafeCell,
     fmt::Debug,
@@ -90,6 +104,26 @@ pub enum ReadWriteMutexWriteLockError {
     UnknownError(i32),
 }
 
+enum_gen! {
+    /// Returned by [`ReadWriteMutex::read_timed_lock()`].
+    ReadWriteMutexTimedReadLockError
+  entry:
+    Timeout
+  mapping:
+    ReadWriteMutexReadLockError to ReadLockError,
+    TimeError to FailureInInternalClockWhileWait
+}
+
+enum_gen! {
+    /// Returned by [`ReadWriteMutex::write_timed_lock()`].
+    ReadWriteMutexTimedWriteLockError
+  entry:
+    Timeout
+  mapping:
+    ReadWriteMutexWriteLockError to WriteLockError,
+    TimeError to FailureInInternalClockWhileWait
+}
+
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum ReadWriteMutexOpenIpcHandleError {
     IsNotInterProcessCapable,
@@ -103,20 +137,35 @@ enum_gen! {
     /// On a higher level it is again convertable to [`crate::Error`].
     ReadWriteMutexError
   generalization:
-    FailedToLock <= ReadWriteMutexWriteLockError; ReadWriteMutexReadLockError,
+    FailedToLock <= ReadWriteMutexWriteLockError; ReadWriteMutexReadLockError; ReadWriteMutexTimedWriteLockError; ReadWriteMutexTimedReadLockError,
     FailedToCreate <= ReadWriteMutexCreationError
 }
 
+/// Defines which side is preferred when readers and writers compete for the
+/// [`ReadWriteMutex`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Default)]
+pub enum ReadWriteMutexPreference {
+    /// A read-lock can be acquired as long as no write-lock is held, even when writers are
+    /// waiting. A continuous stream of readers can starve the writers.
+    #[default]
+    Reader,
+    /// As soon as a writer waits for the write-lock, no further read-locks are granted. A thread
+    /// that acquires a read-lock recursively while a writer is waiting deadlocks.
+    Writer,
+}
+
 /// The builder for the [`ReadWriteMutex`].
 #[derive(Debug)]
 pub struct ReadWriteMutexBuilder {
     is_interprocess_capable: bool,
+    preference: Option<ReadWriteMutexPreference>,
 }
 
 impl Default for ReadWriteMutexBuilder {
     fn default() -> Self {
         ReadWriteMutexBuilder {
             is_interprocess_capable: true,
+            preference: None,
         }
     }
 }
@@ -132,6 +181,16 @@ impl ReadWriteMutexBuilder {
         self
     }
 
+    /// Defines if readers or writers are preferred when they compete for the
+    /// [`ReadWriteMutex`]. If no preference is set, the platform default applies, which is
+    /// [`ReadWriteMutexPreference::Reader`] on all platforms except FreeBSD and QNX. There,
+    /// writers are always preferred and requesting [`ReadWriteMutexPreference::Reader`] fails
+    /// with [`ReadWriteMutexCreationError::NoMutexKindSupport`].
+    pub fn preference(mut self, value: ReadWriteMutexPreference) -> Self {
+        self.preference = Some(value);
+        self
+    }
+
     fn initialize_rw_mutex(
         &self,
         mtx: *mut posix::pthread_rwlock_t,
@@ -163,6 +222,20 @@ impl ReadWriteMutexBuilder {
             }
         }
 
+        if let Some(preference) = self.preference {
+            let kind = match preference {
+                ReadWriteMutexPreference::Reader => posix::PTHREAD_PREFER_READER_NP,
+                ReadWriteMutexPreference::Writer => posix::PTHREAD_PREFER_WRITER_NONRECURSIVE_NP,
+            };
+            match unsafe { posix::pthread_rwlockattr_setkind_np(attributes.get_mut(), kind) } {
+                0 => (),
+                v => {
+                    fail!(from origin, with ReadWriteMutexCreationError::NoMutexKindSupport,
+                        "{} since the preference {:?} is not supported ({}).", msg, preference, v);
+                }
+            }
+        }
+
         match unsafe { posix::pthread_rwlock_init(mtx, attributes.get()).into() } {
             Errno::ESUCCES => (),
             Errno::EAGAIN => {
@@ -205,7 +278,8 @@ impl ReadWriteMutexBuilder {
 
 /// A guard which provides read access to the underlying value of a [`ReadWriteMutex`].
 ///
-/// Is returned by [`ReadWriteMutex::read_blocking_lock()`] and [`ReadWriteMutex::read_try_lock()`].
+/// Is returned by [`ReadWriteMutex::read_blocking_lock()`], [`ReadWriteMutex::read_try_lock()`]
+/// and [`ReadWriteMutex::read_timed_lock()`].
 #[derive(Debug)]
 pub struct MutexReadGuard<'handle, T: Debug> {
     handle: &'handle ReadWriteMutexHandle<T>,
@@ -232,7 +306,8 @@ impl<T: Debug> Drop for MutexReadGuard<'_, T> {
 
 /// A guard which provides read and write access to the underlying value of a [`ReadWriteMutex`].
 ///
-/// Is returned by [`ReadWriteMutex::write_blocking_lock()`] and [`ReadWriteMutex::write_try_lock()`].
+/// Is returned by [`ReadWriteMutex::write_blocking_lock()`], [`ReadWriteMutex::write_try_lock()`]
+/// and [`ReadWriteMutex::write_timed_lock()`].
 #[derive(Debug)]
 pub struct MutexWriteGuard<'handle, T: Debug> {
     handle: &'handle ReadWriteMutexHandle<T>,
@@ -389,6 +464,27 @@ impl<'this, 'handle: 'this, T: Sized + Debug> ReadWriteMutex<'this, 'handle, T>
         );
     }
 
+    /// Tries to acquire a read-lock until the timeout has passed. If no read-lock could be
+    /// acquired in time it fails with [`ReadWriteMutexTimedReadLockError::Timeout`].
+    pub fn read_timed_lock(
+        &'this self,
+        timeout: Duration,
+    ) -> Result<MutexReadGuard<'handle, T>, ReadWriteMutexTimedReadLockError> {
+        let msg = "Failed to acquire read-lock with timeout";
+        let now = fail!(from self, when Time::now_with_clock(ClockType::Realtime),
+            "{} due to a failure while acquiring current system time.", msg);
+        let deadline = now.saturating_add(timeout).as_timespec();
+
+        handle_errno!(ReadWriteMutexTimedReadLockError, from self,
+            errno_source unsafe { posix::pthread_rwlock_timedrdlock(self.handle.handle.get(), &deadline).into() },
+            success Errno::ESUCCES => MutexReadGuard { handle: self.handle },
+            Errno::ETIMEDOUT => (Timeout, "{} since the timeout of {:?} has passed.", msg, timeout),
+            Errno::EAGAIN => (ReadLockError(ReadWriteMutexReadLockError::MaximumAmountOfReadLocksAcquired), "{} since the maximum amount of read-locks is already acquired.", msg),
+            Errno::EDEADLK => (ReadLockError(ReadWriteMutexReadLockError::DeadlockConditionDetected), "{} since a deadlock condition was detected.", msg),
+            v => (ReadLockError(ReadWriteMutexReadLockError::UnknownError(v as i32)), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
     /// Blocks until a write-lock could be acquired and returns a [`MutexWriteGuard`] to provide
     /// read-write access to the underlying value.
     pub fn write_blocking_lock(
@@ -418,6 +514,28 @@ impl<'this, 'handle: 'this, T: Sized + Debug> ReadWriteMutex<'this, 'handle, T>
         );
     }
 
+    /// Tries to acquire a write-lock until the timeout has passed. If no write-lock could be
+    /// acquired in time it fails with [`ReadWriteMutexTimedWriteLockError::Timeout`].
+    /// With [`ReadWriteMutexPreference::Writer`], no further read-locks are granted while
+    /// the writer waits.
+    pub fn write_timed_lock(
+        &'this self,
+        timeout: Duration,
+    ) -> Result<MutexWriteGuard<'handle, T>, ReadWriteMutexTimedWriteLockError> {
+        let msg = "Failed to acquire write-lock with timeout";
+        let now = fail!(from self, when Time::now_with_clock(ClockType::Realtime),
+            "{} due to a failure while acquiring current system time.", msg);
+        let deadline = now.saturating_add(timeout).as_timespec();
+
+        handle_errno!(ReadWriteMutexTimedWriteLockError, from self,
+            errno_source unsafe { posix::pthread_rwlock_timedwrlock(self.handle.handle.get(), &deadline).into() },
+            success Errno::ESUCCES => MutexWriteGuard { handle: self.handle },
+            Errno::ETIMEDOUT => (Timeout, "{} since the timeout of {:?} has passed.", msg, timeout),
+            Errno::EDEADLK => (WriteLockError(ReadWriteMutexWriteLockError::DeadlockConditionDetected), "{} since a deadlock condition was detected.", msg),
+            v => (WriteLockError(ReadWriteMutexWriteLockError::UnknownError(v as i32)), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
     fn release(handle: &ReadWriteMutexHandle<T>) -> Result<(), ReadWriteMutexUnlockError> {
         let msg = "Unable to release lock";
         match unsafe { posix::pthread_rwlock_unlock(handle.handle.get()).into() } {
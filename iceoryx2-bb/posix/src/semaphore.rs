@@ -21,7 +21,7 @@ use core::fmt::Debug;
 use crate::ipc_capable::internal::{Capability, HandleStorage, IpcConstructible};
 use iceoryx2_bb_container::semantic_string::*;
 use iceoryx2_bb_elementary::enum_gen;
-use iceoryx2_bb_log::{debug, fail, fatal_panic, warn};
+use iceoryx2_bb_log::{debug, fail, fatal_panic, trace, warn};
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::*;
 use iceoryx2_bb_system_types::path::*;
@@ -57,6 +57,13 @@ enum_gen! { NamedSemaphoreCreationError
     UnknownError(i32)
 }
 
+enum_gen! { NamedSemaphoreRemoveError
+  entry:
+    InsufficientPermissions,
+    MaxFilePathLengthExceeded,
+    UnknownError(i32)
+}
+
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum UnnamedSemaphoreCreationError {
     InitialValueTooLarge,
@@ -103,16 +110,11 @@ enum_gen! {
     SemaphoreError
   generalization:
     FailedToCreate <= NamedSemaphoreCreationError; UnnamedSemaphoreCreationError,
+    FailedToRemove <= NamedSemaphoreRemoveError,
     FailedToPost <= SemaphorePostError,
     FailedToWait <= SemaphoreWaitError; SemaphoreTimedWaitError
 }
 
-#[derive(PartialEq, Eq)]
-enum UnlinkMode {
-    IgnoreNonExistingSemaphore,
-    FailWhenSemaphoreDoesNotExist,
-}
-
 #[derive(PartialEq, Eq)]
 enum InitMode {
     Create,
@@ -188,7 +190,7 @@ pub trait SemaphoreInterface: internal::SemaphoreHandle + Debug {
     fn timed_wait(&self, timeout: Duration) -> Result<bool, SemaphoreTimedWaitError> {
         let msg = "Unable to timed wait on semaphore";
         match self.clock_type() {
-            ClockType::Monotonic => {
+            ClockType::Monotonic | ClockType::Boottime => {
                 let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new()
                     .clock_type(self.clock_type())
                     .create(), "{} since the adaptive wait could not be created.", msg);
@@ -209,10 +211,9 @@ pub trait SemaphoreInterface: internal::SemaphoreHandle + Debug {
                 }
             }
             ClockType::Realtime => {
-                let wait_time = timeout
-                    + fail!(from self, when Time::now_with_clock(self.clock_type()),
+                let wait_time = fail!(from self, when Time::now_with_clock(self.clock_type()),
                     "{} due to a failure while acquiring the current system time.", msg)
-                    .as_duration();
+                .saturating_add(timeout);
                 if unsafe { posix::sem_timedwait(self.handle(), &wait_time.as_timespec()) } == 0 {
                     return Ok(true);
                 }
@@ -408,12 +409,9 @@ impl Drop for NamedSemaphore {
             fatal_panic!(from self, "This should never happen! The semaphore handle is invalid and cannot be closed.");
         }
 
-        if self.has_ownership
-            && self
-                .unlink(UnlinkMode::FailWhenSemaphoreDoesNotExist)
-                .is_err()
-        {
-            fatal_panic!(from self, "Failed to cleanup semaphore. Something else removed a managed semaphore which should never happen!");
+        // the semaphore could have been removed explicitly with NamedSemaphore::remove()
+        if self.has_ownership && self.unlink().is_err() {
+            fatal_panic!(from self, "Failed to cleanup semaphore.");
         }
     }
 }
@@ -433,7 +431,7 @@ impl NamedSemaphore {
             }
             Some(CreationMode::PurgeAndCreate) => {
                 new_sem.has_ownership = true;
-                fail!(from new_sem, when new_sem.unlink(UnlinkMode::IgnoreNonExistingSemaphore), "Failed to remove semaphore before creating a new one.");
+                fail!(from new_sem, when new_sem.unlink(), "Failed to remove semaphore before creating a new one.");
                 new_sem.open(config.permission, InitMode::Create, config.initial_value)?;
             }
             Some(CreationMode::CreateExclusive) => {
@@ -441,13 +439,12 @@ impl NamedSemaphore {
                 new_sem.open(config.permission, InitMode::Create, config.initial_value)?;
             }
             Some(CreationMode::OpenOrCreate) => {
-                match new_sem.open(Permission::none(), InitMode::TryOpen, 0) {
-                    Ok(()) => (),
-                    Err(NamedSemaphoreCreationError::DoesNotExist) => {
-                        new_sem.has_ownership = true;
-                        new_sem.open(config.permission, InitMode::Create, config.initial_value)?;
-                    }
-                    Err(v) => return Err(v),
+                // a non-existing semaphore is not an error in try open mode, the handle stays
+                // invalid instead
+                new_sem.open(Permission::none(), InitMode::TryOpen, 0)?;
+                if core::ptr::eq(new_sem.handle, posix::SEM_FAILED) {
+                    new_sem.has_ownership = true;
+                    new_sem.open(config.permission, InitMode::Create, config.initial_value)?;
                 }
             }
         };
@@ -455,7 +452,7 @@ impl NamedSemaphore {
         Ok(new_sem)
     }
 
-    fn unlink(&mut self, mode: UnlinkMode) -> Result<(), NamedSemaphoreCreationError> {
+    fn unlink(&mut self) -> Result<(), NamedSemaphoreCreationError> {
         let file_path =
             FilePath::from_path_and_file(&Path::new(b"/").unwrap(), &self.name).unwrap();
         if unsafe { posix::sem_unlink(file_path.as_c_str()) } == 0 {
@@ -464,10 +461,8 @@ impl NamedSemaphore {
         }
 
         let msg = "Unable to unlink semaphore";
-        let ignore_non_existing_semaphore = mode == UnlinkMode::IgnoreNonExistingSemaphore;
         handle_errno!(NamedSemaphoreCreationError, from self,
-            success_when ignore_non_existing_semaphore,
-                Errno::ENOENT => ((), AlreadyExists, "{} since no semaphore with the given name exists.", msg),
+            success Errno::ENOENT => (),
             Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
             Errno::ENAMETOOLONG => (MaxFilePathLengthExceeded, "{} since the name exceeds the maximum supported length.", msg),
             v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
@@ -533,6 +528,28 @@ impl NamedSemaphore {
     pub fn name(&self) -> &FileName {
         &self.name
     }
+
+    /// Removes the named semaphore with the given name. Processes which have already opened
+    /// the semaphore can continue to use it but it can no longer be opened. Returns `true` when
+    /// the semaphore existed and was removed, otherwise `false`.
+    /// On platforms where named semaphores are reference counted kernel objects, like Windows,
+    /// the semaphore is removed when the last handle is closed and `true` is always returned.
+    pub fn remove(name: &FileName) -> Result<bool, NamedSemaphoreRemoveError> {
+        let msg = "Unable to remove semaphore";
+        let origin = "NamedSemaphore::remove()";
+        let file_path = FilePath::from_path_and_file(&Path::new(b"/").unwrap(), name).unwrap();
+        if unsafe { posix::sem_unlink(file_path.as_c_str()) } == 0 {
+            trace!(from origin, "\"{}\"", name);
+            return Ok(true);
+        }
+
+        handle_errno!(NamedSemaphoreRemoveError, from origin,
+            success Errno::ENOENT => false,
+            Errno::EACCES => (InsufficientPermissions, "{} \"{}\" due to insufficient permissions.", msg, name),
+            Errno::ENAMETOOLONG => (MaxFilePathLengthExceeded, "{} \"{}\" since the name exceeds the maximum supported length.", msg, name),
+            v => (UnknownError(v as i32), "{} \"{}\" since an unknown error occurred ({}).", msg, name, v)
+        )
+    }
 }
 
 impl internal::SemaphoreHandle for NamedSemaphore {
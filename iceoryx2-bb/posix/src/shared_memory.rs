@@ -62,25 +62,74 @@
 //! // print the first byte of the shared memory
 //! println!("first byte: {}", shm.as_slice()[0]);
 //! ```
+//!
+//! ## Create shared memory backed by huge pages.
+//!
+//! ```
+//! use iceoryx2_bb_posix::shared_memory::*;
+//! use iceoryx2_bb_system_types::file_name::FileName;
+//! use iceoryx2_bb_container::semantic_string::*;
+//!
+//! let name = FileName::new(b"someHugeShmName").unwrap();
+//! let shm = SharedMemoryBuilder::new(&name)
+//!                     .creation_mode(CreationMode::PurgeAndCreate)
+//!                     .size(1024)
+//!                     // falls back to the default page size when no huge pages are available
+//!                     .huge_pages(HugePagePolicy::TryHuge2MB)
+//!                     .create()
+//!                     .expect("failed to create shared memory");
+//!
+//! println!("shm page size: {}", shm.page_size());
+//! ```
+//!
+//! ## Grow existing shared memory.
+//!
+//! ```no_run
+//! use iceoryx2_bb_posix::shared_memory::*;
+//! use iceoryx2_bb_system_types::file_name::FileName;
+//! use iceoryx2_bb_container::semantic_string::*;
+//!
+//! let name = FileName::new(b"someShmName").unwrap();
+//! // owner
+//! let mut shm = SharedMemoryBuilder::new(&name)
+//!                     .creation_mode(CreationMode::PurgeAndCreate)
+//!                     .size(1024)
+//!                     .create()
+//!                     .expect("failed to create shared memory");
+//! shm.resize(4096).expect("failed to resize shared memory");
+//!
+//! // other process with the old mapping
+//! let mut shm = SharedMemoryBuilder::new(&name)
+//!                     .open_existing(AccessMode::ReadWrite)
+//!                     .expect("failed to open shared memory");
+//! if shm.remap_if_resized().expect("failed to remap shared memory") {
+//!     println!("new shm size: {}", shm.size());
+//! }
+//! ```
 
-use crate::file::{FileStatError, FileTruncateError};
+use crate::directory::Directory;
+use crate::file::{File, FileBuilder, FileStatError, FileTruncateError};
 use crate::file_descriptor::*;
 use crate::handle_errno;
 use crate::memory_lock::{MemoryLock, MemoryLockCreationError};
+use crate::mutex::*;
 use crate::signal::SignalHandler;
-use crate::system_configuration::Limit;
+use crate::system_configuration::{Limit, SystemInfo};
 use iceoryx2_bb_container::semantic_string::*;
 use iceoryx2_bb_elementary::enum_gen;
-use iceoryx2_bb_log::{error, fail, fatal_panic, trace};
+use iceoryx2_bb_log::{debug, error, fail, fatal_panic, trace};
 use iceoryx2_bb_system_types::file_name::*;
 use iceoryx2_bb_system_types::file_path::*;
 use iceoryx2_bb_system_types::path::*;
 use iceoryx2_pal_configuration::PATH_SEPARATOR;
 use iceoryx2_pal_posix::posix::errno::Errno;
 use iceoryx2_pal_posix::posix::POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING;
+use iceoryx2_pal_posix::posix::POSIX_SUPPORT_HUGE_PAGES;
 use iceoryx2_pal_posix::posix::POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY;
 use iceoryx2_pal_posix::*;
+use lazy_static::lazy_static;
 
+use core::fmt::Debug;
 use core::ptr::NonNull;
 use core::sync::atomic::Ordering;
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
@@ -105,6 +154,8 @@ enum_gen! { SharedMemoryCreationError
     AlreadyExist,
     DoesNotExist,
     UnableToMapAtEnforcedBaseAddress,
+    HugePagesNotSupported,
+    InsufficientHugePages,
     UnknownError(i32)
   mapping:
     FileTruncateError,
@@ -119,6 +170,67 @@ enum_gen! { SharedMemoryRemoveError
     UnknownError(i32)
 }
 
+enum_gen! { SharedMemoryResizeError
+  entry:
+    SizeCannotBeReduced,
+    InsufficientPermissions
+  mapping:
+    FileTruncateError,
+    FileStatError,
+    MemoryLockCreationError,
+    SharedMemoryCreationError
+}
+
+const HUGE_PAGE_SIZE_2MB: usize = 2 * 1024 * 1024;
+const HUGE_PAGE_SIZE_1GB: usize = 1024 * 1024 * 1024;
+
+/// Defines if and how a newly created [`SharedMemory`] is backed by huge pages.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Default)]
+pub enum HugePagePolicy {
+    /// The shared memory uses the default page size of the system.
+    #[default]
+    None,
+    /// Tries to use 2MB huge pages. When the system does not provide them or the
+    /// huge page pool is exhausted the default page size is used instead.
+    TryHuge2MB,
+    /// Requires 2MB huge pages, the creation fails when they are not available.
+    Require2MB,
+    /// Requires 1GB huge pages, the creation fails when they are not available.
+    Require1GB,
+}
+
+impl HugePagePolicy {
+    fn page_size(&self) -> Option<usize> {
+        match self {
+            HugePagePolicy::None => None,
+            HugePagePolicy::TryHuge2MB | HugePagePolicy::Require2MB => Some(HUGE_PAGE_SIZE_2MB),
+            HugePagePolicy::Require1GB => Some(HUGE_PAGE_SIZE_1GB),
+        }
+    }
+
+    fn is_requested(&self) -> bool {
+        *self != HugePagePolicy::None
+    }
+
+    fn is_required(&self) -> bool {
+        matches!(
+            self,
+            HugePagePolicy::Require2MB | HugePagePolicy::Require1GB
+        )
+    }
+}
+
+// A hugetlbfs can be shared with other applications, therefore only the files with this prefix
+// are considered to be huge page backed shared memory objects of iceoryx2.
+const HUGE_PAGE_FILE_PREFIX: &[u8] = b"iox2_shm_";
+
+/// A mounted hugetlbfs in which huge page backed shared memory objects are stored.
+#[derive(Debug, Clone)]
+struct HugePageMount {
+    path: Path,
+    page_size: usize,
+}
+
 /// The builder for the [`SharedMemory`].
 #[derive(Debug)]
 pub struct SharedMemoryBuilder {
@@ -132,6 +244,7 @@ pub struct SharedMemoryBuilder {
     access_mode: AccessMode,
     mapping_offset: isize,
     enforce_base_address: Option<u64>,
+    huge_pages: HugePagePolicy,
 }
 
 impl SharedMemoryBuilder {
@@ -147,6 +260,7 @@ impl SharedMemoryBuilder {
             zero_memory: true,
             mapping_offset: 0,
             enforce_base_address: None,
+            huge_pages: HugePagePolicy::None,
         }
     }
 
@@ -182,14 +296,14 @@ impl SharedMemoryBuilder {
 
     fn open(mut self) -> Result<SharedMemory, SharedMemoryCreationError> {
         let msg = "Unable to open shared memory";
-        let fd = SharedMemory::shm_open(&self.name, &self)?;
+        let (fd, mount) = SharedMemory::shm_open(&self.name, &self)?;
 
         let actual_shm_size = fail!(from self, when fd.metadata(),
                 "{} since a failure occurred while acquiring the file attributes.", msg)
         .size();
         self.size = actual_shm_size as usize;
 
-        let base_address = fail!(from self, when SharedMemory::mmap(&fd, &self),
+        let base_address = fail!(from self, when SharedMemory::mmap(&fd, self.size, self.access_mode, self.mapping_offset, &self),
                         "{} since the memory could not be mapped.", msg);
 
         if self.enforce_base_address.is_some()
@@ -207,6 +321,9 @@ impl SharedMemoryBuilder {
             memory_lock: None,
             file_descriptor: fd,
             mapping_offset: self.mapping_offset,
+            access_mode: self.access_mode,
+            page_size: SharedMemory::page_size_of(mount.as_ref()),
+            huge_page_mount: mount.map(|m| m.path),
         };
 
         trace!(from shm, "open");
@@ -257,8 +374,20 @@ impl SharedMemoryCreationBuilder {
         self
     }
 
+    /// Defines if the shared memory shall be backed by huge pages. With
+    /// [`HugePagePolicy::TryHuge2MB`] the default page size is used when no huge pages are
+    /// available, all other huge page policies let the creation fail instead. The actually used
+    /// page size is provided by [`SharedMemory::page_size()`].
+    ///
+    /// Huge page backed objects are stored with an iceoryx2 specific prefix in a mounted
+    /// hugetlbfs. Without a huge page policy the hugetlbfs is not touched during creation.
+    pub fn huge_pages(mut self, value: HugePagePolicy) -> Self {
+        self.config.huge_pages = value;
+        self
+    }
+
     /// Creates the shared memory segment.
-    pub fn create(mut self) -> Result<SharedMemory, SharedMemoryCreationError> {
+    pub fn create(self) -> Result<SharedMemory, SharedMemoryCreationError> {
         let msg = "Unable to create shared memory";
 
         if self.config.size == 0 {
@@ -266,6 +395,45 @@ impl SharedMemoryCreationBuilder {
                 "{msg} since a size of 0 is not supported for a shared memory object.");
         }
 
+        let policy = self.config.huge_pages;
+        let mount = match policy.page_size() {
+            None => None,
+            Some(page_size) => match SharedMemory::huge_page_mounts()
+                .into_iter()
+                .find(|m| m.page_size == page_size)
+            {
+                Some(mount) => Some(mount),
+                None if policy.is_required() => {
+                    fail!(from self.config, with SharedMemoryCreationError::HugePagesNotSupported,
+                        "{} since the system does not provide huge pages with a size of {} bytes.", msg, page_size);
+                }
+                None => {
+                    debug!(from self.config,
+                        "The system does not provide huge pages with a size of {} bytes, falling back to the default page size.", page_size);
+                    None
+                }
+            },
+        };
+
+        match self.create_impl(mount.as_ref()) {
+            Err(SharedMemoryCreationError::InsufficientHugePages) if !policy.is_required() => {
+                debug!(from self.config,
+                    "The huge page pool is exhausted, falling back to the default page size.");
+                self.create_impl(None)
+            }
+            v => v,
+        }
+    }
+
+    fn create_impl(
+        &self,
+        mount: Option<&HugePageMount>,
+    ) -> Result<SharedMemory, SharedMemoryCreationError> {
+        let msg = "Unable to create shared memory";
+        let location = mount.map(|m| &m.path);
+
+        let mut has_ownership = self.config.has_ownership;
+        let mut opened_mount = None;
         let shm_created;
         let fd = match self
             .config
@@ -273,28 +441,43 @@ impl SharedMemoryCreationBuilder {
             .expect("CreationMode must be set on creation")
         {
             CreationMode::CreateExclusive => {
+                if self.config.huge_pages.is_requested()
+                    && SharedMemory::is_present_outside_of(&self.config.name, mount)
+                {
+                    fail!(from self.config, with SharedMemoryCreationError::AlreadyExist,
+                        "{} since it already exists.", msg);
+                }
                 shm_created = true;
-                SharedMemory::shm_create(&self.config.name, &self.config)?
+                SharedMemory::shm_create(&self.config.name, &self.config, location)?
             }
             CreationMode::PurgeAndCreate => {
                 shm_created = true;
-                fail!(from self.config, when SharedMemory::shm_unlink(&self.config.name),
-                    "Failed to remove already existing shared memory.");
-                SharedMemory::shm_create(&self.config.name, &self.config)?
+                if self.config.huge_pages.is_requested() {
+                    fail!(from self.config, when SharedMemory::remove(&self.config.name),
+                        "Failed to remove already existing shared memory.");
+                } else {
+                    fail!(from self.config, when SharedMemory::shm_unlink(&self.config.name, None),
+                        "Failed to remove already existing shared memory.");
+                }
+                SharedMemory::shm_create(&self.config.name, &self.config, location)?
             }
             CreationMode::OpenOrCreate => {
                 match SharedMemory::shm_open(&self.config.name, &self.config) {
-                    Ok(fd) => {
+                    Ok((fd, existing_mount)) => {
                         shm_created = false;
-                        self.config.has_ownership = false;
+                        has_ownership = false;
+                        opened_mount = existing_mount;
                         fd
                     }
                     Err(SharedMemoryCreationError::DoesNotExist) => {
                         shm_created = true;
-                        match SharedMemory::shm_create(&self.config.name, &self.config) {
+                        match SharedMemory::shm_create(&self.config.name, &self.config, location) {
                             Ok(fd) => fd,
                             Err(SharedMemoryCreationError::AlreadyExist) => {
-                                SharedMemory::shm_open(&self.config.name, &self.config)?
+                                let (fd, existing_mount) =
+                                    SharedMemory::shm_open(&self.config.name, &self.config)?;
+                                opened_mount = existing_mount;
+                                fd
                             }
                             Err(e) => return Err(e),
                         }
@@ -304,14 +487,22 @@ impl SharedMemoryCreationBuilder {
             }
         };
 
+        let mount = if shm_created {
+            mount.cloned()
+        } else {
+            opened_mount
+        };
         let mut shm = SharedMemory {
             name: self.config.name.clone(),
             base_address: core::ptr::null_mut::<u8>(),
             size: self.config.size,
-            has_ownership: IoxAtomicBool::new(self.config.has_ownership),
+            has_ownership: IoxAtomicBool::new(has_ownership),
             memory_lock: None,
             file_descriptor: fd,
             mapping_offset: self.config.mapping_offset,
+            access_mode: self.config.access_mode,
+            page_size: SharedMemory::page_size_of(mount.as_ref()),
+            huge_page_mount: mount.map(|m| m.path),
         };
 
         if !shm_created {
@@ -323,7 +514,7 @@ impl SharedMemoryCreationBuilder {
                     "{} since the actual size {} is not equal to the configured size {}.", msg, actual_shm_size, self.config.size);
             }
 
-            shm.base_address = fail!(from self.config, when SharedMemory::mmap(&shm.file_descriptor, &self.config),
+            shm.base_address = fail!(from self.config, when SharedMemory::mmap(&shm.file_descriptor, self.config.size, self.config.access_mode, self.config.mapping_offset, &self.config),
                                     "{} since the memory could not be mapped.", msg)
                 as *mut u8;
 
@@ -331,11 +522,32 @@ impl SharedMemoryCreationBuilder {
             return Ok(shm);
         }
 
-        fail!(from self.config, when shm.truncate(self.config.size), "{} since the shared memory truncation failed.", msg);
-
-        shm.base_address = fail!(from self.config, when SharedMemory::mmap(&shm.file_descriptor, &self.config),
-                                    "{} since the memory could not be mapped.", msg)
-            as *mut u8;
+        let size = match shm.huge_page_mount {
+            // hugetlbfs only supports sizes which are a multiple of the huge page size
+            Some(_) => self.config.size.div_ceil(shm.page_size) * shm.page_size,
+            None => self.config.size,
+        };
+        fail!(from self.config, when shm.truncate(size), "{} since the shared memory truncation failed.", msg);
+
+        shm.base_address = match SharedMemory::mmap(
+            &shm.file_descriptor,
+            size,
+            self.config.access_mode,
+            self.config.mapping_offset,
+            &self.config,
+        ) {
+            Ok(v) => v as *mut u8,
+            Err(SharedMemoryCreationError::InsufficientMemory) if shm.huge_page_mount.is_some() => {
+                // the huge page backed file was created by us and must not outlive the failure
+                shm.acquire_ownership();
+                fail!(from self.config, with SharedMemoryCreationError::InsufficientHugePages,
+                    "{} since the huge page pool cannot provide {} bytes with a page size of {} bytes.", msg, size, shm.page_size);
+            }
+            Err(e) => {
+                fail!(from self.config, with e,
+                    "{} since the memory could not be mapped.", msg);
+            }
+        };
 
         if self.config.enforce_base_address.is_some()
             && self.config.enforce_base_address.unwrap() != shm.base_address as u64
@@ -392,6 +604,9 @@ pub struct SharedMemory {
     file_descriptor: FileDescriptor,
     memory_lock: Option<MemoryLock>,
     mapping_offset: isize,
+    access_mode: AccessMode,
+    page_size: usize,
+    huge_page_mount: Option<Path>,
 }
 
 impl Drop for SharedMemory {
@@ -405,7 +620,7 @@ impl Drop for SharedMemory {
 
         if self.has_ownership() {
             match self.set_permission(Permission::OWNER_ALL) {
-                Ok(()) => match Self::shm_unlink(&self.name) {
+                Ok(()) => match Self::shm_unlink(&self.name, self.huge_page_mount.as_ref()) {
                     Ok(_) => {
                         trace!(from self, "delete");
                     }
@@ -434,6 +649,15 @@ impl SharedMemory {
             )
         })
         .is_some()
+            || Self::huge_page_mounts()
+                .iter()
+                .any(|m| Self::is_present_in(name, Some(m)))
+    }
+
+    /// Returns the size of the pages backing the shared memory. If the shared memory is backed
+    /// by huge pages it is the huge page size, otherwise the default page size of the system.
+    pub fn page_size(&self) -> usize {
+        self.page_size
     }
 
     /// Returns the mapping offset used when the shared memory object was mapped into process space
@@ -466,19 +690,23 @@ impl SharedMemory {
         self.has_ownership.store(true, Ordering::Relaxed)
     }
 
-    /// Removes a shared memory file.
+    /// Removes a shared memory file. Besides the POSIX shared memory object, the huge page
+    /// backed object with the same name is removed from every mounted hugetlbfs.
     pub fn remove(name: &FileName) -> Result<bool, SharedMemoryRemoveError> {
-        match Self::shm_unlink(name) {
-            Ok(true) => {
-                trace!(from "SharedMemory::remove", "\"{}\"", name);
-                Ok(true)
-            }
-            Ok(false) => Ok(false),
-            Err(v) => Err(v),
+        let mut was_removed = Self::shm_unlink(name, None)?;
+        for mount in Self::huge_page_mounts() {
+            was_removed |= Self::shm_unlink(name, Some(&mount.path))?;
         }
+
+        if was_removed {
+            trace!(from "SharedMemory::remove", "\"{}\"", name);
+        }
+
+        Ok(was_removed)
     }
 
-    /// Returns a list of all shared memory objects
+    /// Returns a list of all shared memory objects, including the huge page backed ones
+    /// created by [`SharedMemoryCreationBuilder::huge_pages()`].
     pub fn list() -> Vec<FileName> {
         let mut result = vec![];
 
@@ -489,6 +717,21 @@ impl SharedMemory {
             }
         }
 
+        for mount in Self::huge_page_mounts() {
+            if let Some(contents) = Directory::new(&mount.path)
+                .ok()
+                .and_then(|d| d.contents().ok())
+            {
+                result.extend(contents.iter().filter_map(|entry| {
+                    let mut name = entry.name().clone();
+                    match name.strip_prefix(HUGE_PAGE_FILE_PREFIX) {
+                        Ok(true) => Some(name),
+                        _ => None,
+                    }
+                }));
+            }
+        }
+
         result
     }
 
@@ -515,6 +758,83 @@ impl SharedMemory {
         self.size
     }
 
+    /// Enlarges the shared memory to at least `new_size` bytes and maps it again, therefore the
+    /// [`SharedMemory::base_address()`] may change. Other processes keep their old mapping,
+    /// which stays valid, and acquire the new size with [`SharedMemory::remap_if_resized()`].
+    /// The size of the underlying shared memory object serves hereby as the size field they
+    /// poll.
+    ///
+    /// Reducing the size is not supported since other processes may still access the memory
+    /// beyond the new size. On Windows the shared memory cannot grow beyond the maximum size
+    /// the platform abstraction reserves for every shared memory object.
+    pub fn resize(&mut self, new_size: usize) -> Result<(), SharedMemoryResizeError> {
+        let msg = "Unable to resize shared memory";
+        if new_size < self.size {
+            fail!(from self, with SharedMemoryResizeError::SizeCannotBeReduced,
+                "{} to {} bytes since the size cannot be reduced below the current size of {} bytes.", msg, new_size, self.size);
+        }
+
+        if self.access_mode != AccessMode::ReadWrite {
+            fail!(from self, with SharedMemoryResizeError::InsufficientPermissions,
+                "{} since it is not mapped with read and write access.", msg);
+        }
+
+        let new_size = match self.huge_page_mount {
+            Some(_) => new_size.div_ceil(self.page_size) * self.page_size,
+            None => new_size,
+        };
+
+        if new_size == self.size {
+            return Ok(());
+        }
+
+        fail!(from self, when self.truncate(new_size),
+            "{} since the underlying shared memory object could not be enlarged to {} bytes.", msg, new_size);
+        self.remap(new_size)
+    }
+
+    /// Maps the shared memory again when it was enlarged by another process with
+    /// [`SharedMemory::resize()`]. Returns true when the mapping was renewed, otherwise false.
+    /// When the mapping is renewed the [`SharedMemory::base_address()`] may change.
+    pub fn remap_if_resized(&mut self) -> Result<bool, SharedMemoryResizeError> {
+        let msg = "Unable to remap resized shared memory";
+        let actual_size = fail!(from self, when self.metadata(),
+            "{} since the size of the underlying shared memory object could not be acquired.", msg)
+        .size() as usize;
+
+        if actual_size <= self.size {
+            return Ok(false);
+        }
+
+        self.remap(actual_size)?;
+        Ok(true)
+    }
+
+    fn remap(&mut self, new_size: usize) -> Result<(), SharedMemoryResizeError> {
+        let msg = "Unable to remap shared memory";
+        // the new mapping is established first so that a failure leaves the old one intact
+        let new_base_address = fail!(from self, when Self::mmap(&self.file_descriptor, new_size, self.access_mode, self.mapping_offset, self),
+            "{} since the memory could not be mapped with the new size of {} bytes.", msg, new_size)
+            as *mut u8;
+
+        let is_memory_locked = self.memory_lock.take().is_some();
+        if unsafe { posix::munmap(self.base_address as *mut posix::void, self.size) } != 0 {
+            fatal_panic!(from self, "This should never happen! Unable to unmap since the base address or range is invalid.");
+        }
+        self.base_address = new_base_address;
+        self.size = new_size;
+
+        if is_memory_locked {
+            self.memory_lock = Some(
+                fail!(from self, when unsafe { MemoryLock::new(self.base_address.cast(), self.size) },
+                    "{} since the memory lock of the new mapping failed.", msg),
+            );
+        }
+
+        trace!(from self, "remap");
+        Ok(())
+    }
+
     /// returns a slice to the memory
     pub fn as_slice(&self) -> &[u8] {
         unsafe { core::slice::from_raw_parts(self.base_address, self.size) }
@@ -525,25 +845,51 @@ impl SharedMemory {
         unsafe { core::slice::from_raw_parts_mut(self.base_address, self.size) }
     }
 
+    fn page_size_of(mount: Option<&HugePageMount>) -> usize {
+        match mount {
+            Some(m) => m.page_size,
+            None => SystemInfo::PageSize.value(),
+        }
+    }
+
+    fn shm_path(name: &FileName, location: Option<&Path>) -> Result<FilePath, SemanticStringError> {
+        match location {
+            Some(path) => {
+                let mut file_name = FileName::new(HUGE_PAGE_FILE_PREFIX)?;
+                file_name.push_bytes(name.as_bytes())?;
+                FilePath::from_path_and_file(path, &file_name)
+            }
+            None => FilePath::from_path_and_file(&Path::new(&[PATH_SEPARATOR; 1]).unwrap(), name),
+        }
+    }
+
     fn shm_create(
         name: &FileName,
         config: &SharedMemoryBuilder,
+        location: Option<&Path>,
     ) -> Result<FileDescriptor, SharedMemoryCreationError> {
-        let file_path =
-            FilePath::from_path_and_file(&Path::new(&[PATH_SEPARATOR; 1]).unwrap(), name).unwrap();
+        let msg = "Unable to create shared memory";
+        let file_path = match Self::shm_path(name, location) {
+            Ok(v) => v,
+            Err(_) => {
+                fail!(from config, with SharedMemoryCreationError::NameTooLong,
+                    "{} since the name \"{}\" exceeds the maximum supported path length.", msg, name);
+            }
+        };
+        let oflag = CreationMode::CreateExclusive.as_oflag() | config.access_mode.as_oflag();
         let fd = FileDescriptor::new(unsafe {
-            posix::shm_open(
-                file_path.as_c_str(),
-                CreationMode::CreateExclusive.as_oflag() | config.access_mode.as_oflag(),
-                config.permission.as_mode(),
-            )
+            match location {
+                Some(_) => {
+                    posix::open_with_mode(file_path.as_c_str(), oflag, config.permission.as_mode())
+                }
+                None => posix::shm_open(file_path.as_c_str(), oflag, config.permission.as_mode()),
+            }
         });
 
         if let Some(v) = fd {
             return Ok(v);
         }
 
-        let msg = "Unable to create shared memory";
         handle_errno!(SharedMemoryCreationError, from config,
             Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
             Errno::EINVAL => (InvalidName, "{} since the provided name \"{}\" is invalid.", msg, name),
@@ -558,22 +904,50 @@ impl SharedMemory {
     fn shm_open(
         name: &FileName,
         config: &SharedMemoryBuilder,
+    ) -> Result<(FileDescriptor, Option<HugePageMount>), SharedMemoryCreationError> {
+        match Self::shm_open_at(name, config, None) {
+            Err(SharedMemoryCreationError::DoesNotExist) => (),
+            v => return v.map(|fd| (fd, None)),
+        }
+
+        for mount in Self::huge_page_mounts() {
+            match Self::shm_open_at(name, config, Some(&mount.path)) {
+                Err(SharedMemoryCreationError::DoesNotExist) => (),
+                v => return v.map(|fd| (fd, Some(mount))),
+            }
+        }
+
+        Err(SharedMemoryCreationError::DoesNotExist)
+    }
+
+    fn shm_open_at(
+        name: &FileName,
+        config: &SharedMemoryBuilder,
+        location: Option<&Path>,
     ) -> Result<FileDescriptor, SharedMemoryCreationError> {
-        let file_path =
-            FilePath::from_path_and_file(&Path::new(&[PATH_SEPARATOR; 1]).unwrap(), name).unwrap();
+        let msg = "Unable to open shared memory";
+        let file_path = match Self::shm_path(name, location) {
+            Ok(v) => v,
+            Err(_) => {
+                fail!(from config, with SharedMemoryCreationError::NameTooLong,
+                    "{} since the name \"{}\" exceeds the maximum supported path length.", msg, name);
+            }
+        };
         let fd = FileDescriptor::new(unsafe {
-            posix::shm_open(
-                file_path.as_c_str(),
-                config.access_mode.as_oflag(),
-                Permission::none().as_mode(),
-            )
+            match location {
+                Some(_) => posix::open(file_path.as_c_str(), config.access_mode.as_oflag()),
+                None => posix::shm_open(
+                    file_path.as_c_str(),
+                    config.access_mode.as_oflag(),
+                    Permission::none().as_mode(),
+                ),
+            }
         });
 
         if let Some(v) = fd {
             return Ok(v);
         }
 
-        let msg = "Unable to open shared memory";
         handle_errno!(SharedMemoryCreationError, from config,
             Errno::ENOENT => (DoesNotExist, "{} since the shared memory does not exist.", msg),
             Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
@@ -585,18 +959,156 @@ impl SharedMemory {
         );
     }
 
-    fn mmap(
+    fn is_present_in(name: &FileName, mount: Option<&HugePageMount>) -> bool {
+        let file_path = match Self::shm_path(name, mount.map(|m| &m.path)) {
+            Ok(v) => v,
+            Err(_) => return false,
+        };
+
+        match mount {
+            Some(_) => matches!(File::does_exist(&file_path), Ok(true)),
+            None => {
+                FileDescriptor::new(unsafe {
+                    posix::shm_open(
+                        file_path.as_c_str(),
+                        AccessMode::Read.as_oflag(),
+                        Permission::none().as_mode(),
+                    )
+                })
+                .is_some()
+                    || Errno::get() != Errno::ENOENT
+            }
+        }
+    }
+
+    // A shared memory name must be unique across the POSIX shared memory and all huge page
+    // locations, otherwise the open calls could end up in different objects.
+    fn is_present_outside_of(name: &FileName, mount: Option<&HugePageMount>) -> bool {
+        let mounts = Self::huge_page_mounts();
+        if mounts.is_empty() {
+            return false;
+        }
+
+        (mount.is_some() && Self::is_present_in(name, None))
+            || mounts
+                .iter()
+                .filter(|m| mount.map(|v| v.path != m.path).unwrap_or(true))
+                .any(|m| Self::is_present_in(name, Some(m)))
+    }
+
+    /// Returns all mounted hugetlbfs together with the huge page size they provide. The mount
+    /// table is parsed once and cached for the lifetime of the process.
+    fn huge_page_mounts() -> Vec<HugePageMount> {
+        if !POSIX_SUPPORT_HUGE_PAGES {
+            return vec![];
+        }
+
+        Self::huge_page_mount_cache()
+            .get_or_insert_with(Self::read_huge_page_mounts)
+            .clone()
+    }
+
+    fn huge_page_mount_cache() -> MutexGuard<'static, Option<Vec<HugePageMount>>> {
+        lazy_static! {
+            static ref HANDLE: MutexHandle<Option<Vec<HugePageMount>>> = MutexHandle::new();
+            static ref MTX: Mutex<'static, 'static, Option<Vec<HugePageMount>>> = fatal_panic!(from "SharedMemory::huge_page_mount_cache()",
+                when MutexBuilder::new().create(None, &HANDLE),
+                "Unable to create the huge page mount cache");
+        }
+
+        fatal_panic!(from "SharedMemory::huge_page_mount_cache()", when MTX.lock(),
+            "Unable to acquire the huge page mount cache")
+    }
+
+    fn read_huge_page_mounts() -> Vec<HugePageMount> {
+        let mut mounts = vec![];
+        let mount_table = match Self::read_system_file(b"/proc/mounts") {
+            Some(v) => v,
+            None => return mounts,
+        };
+
+        for line in mount_table.lines() {
+            // format: <device> <mount point> <fs type> <options> <dump> <pass>
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 4 || fields[2] != "hugetlbfs" {
+                continue;
+            }
+
+            let page_size = match fields[3]
+                .split(',')
+                .find_map(|option| option.strip_prefix("pagesize="))
+            {
+                Some(v) => Self::parse_size(v),
+                None => Self::default_huge_page_size(),
+            };
+
+            if let (Some(page_size), Ok(path)) = (page_size, Path::new(fields[1].as_bytes())) {
+                mounts.push(HugePageMount { path, page_size });
+            }
+        }
+
+        mounts
+    }
+
+    fn default_huge_page_size() -> Option<usize> {
+        // format: "Hugepagesize:       2048 kB"
+        Self::read_system_file(b"/proc/meminfo")?
+            .lines()
+            .find_map(|line| line.strip_prefix("Hugepagesize:"))
+            .and_then(|v| Self::parse_size(&v.split_whitespace().collect::<String>()))
+    }
+
+    /// Parses sizes like "2M", "1024M", "1G" or "2048kB".
+    fn parse_size(value: &str) -> Option<usize> {
+        let split = value
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(value.len());
+        let (number, unit) = value.split_at(split);
+        let factor = match unit.to_ascii_lowercase().as_str() {
+            "" | "b" => 1,
+            "k" | "kb" => 1024,
+            "m" | "mb" => 1024 * 1024,
+            "g" | "gb" => 1024 * 1024 * 1024,
+            _ => return None,
+        };
+
+        number.parse::<usize>().ok()?.checked_mul(factor)
+    }
+
+    fn read_system_file(path: &[u8]) -> Option<String> {
+        // files in procfs report a size of zero, therefore it must be read until the end
+        let file = FileBuilder::new(&FilePath::new(path).ok()?)
+            .open_existing(AccessMode::Read)
+            .ok()?;
+
+        let mut content = vec![];
+        let mut buffer = [0u8; 4096];
+        loop {
+            match file.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(n) => content.extend_from_slice(&buffer[..n as usize]),
+                Err(_) => return None,
+            }
+        }
+
+        String::from_utf8(content).ok()
+    }
+
+    fn mmap<T: Debug>(
         file_descriptor: &FileDescriptor,
-        config: &SharedMemoryBuilder,
+        size: usize,
+        access_mode: AccessMode,
+        mapping_offset: isize,
+        origin: &T,
     ) -> Result<*mut posix::void, SharedMemoryCreationError> {
         let base_address = unsafe {
             posix::mmap(
                 core::ptr::null_mut::<posix::void>(),
-                config.size,
-                config.access_mode.as_protflag(),
+                size,
+                access_mode.as_protflag(),
                 posix::MAP_SHARED,
                 file_descriptor.native_handle(),
-                config.mapping_offset as _,
+                mapping_offset as _,
             )
         };
 
@@ -605,24 +1117,36 @@ impl SharedMemory {
         }
 
         let msg = "Unable to map shared memory";
-        handle_errno!(SharedMemoryCreationError, from config,
+        handle_errno!(SharedMemoryCreationError, from origin,
             Errno::EAGAIN => (InsufficientMemoryToBeMemoryLocked, "{} since a previous mlockall() enforces all mappings to be memory locked but this mapping cannot be locked due to insufficient memory.", msg),
-            Errno::EINVAL => (UnsupportedMemoryMappingOffsetValue, "{} since the value {} is not supported as a mapping offset.", msg, config.mapping_offset),
-            Errno::ENOMEM => (InsufficientMemory, "{} since the system is out-of-memory or does not the support a shared memory with the size of {}.", msg, config.size),
+            Errno::EINVAL => (UnsupportedMemoryMappingOffsetValue, "{} since the value {} is not supported as a mapping offset.", msg, mapping_offset),
+            Errno::ENOMEM => (InsufficientMemory, "{} since the system is out-of-memory or does not the support a shared memory with the size of {}.", msg, size),
             Errno::EMFILE => (MappedRegionLimitReached, "{} since the number of mapped regions would exceed the process or system limit.", msg),
             v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
         );
     }
 
-    fn shm_unlink(name: &FileName) -> Result<bool, SharedMemoryRemoveError> {
-        let file_path =
-            FilePath::from_path_and_file(&Path::new(&[PATH_SEPARATOR; 1]).unwrap(), name).unwrap();
-        if unsafe { posix::shm_unlink(file_path.as_c_str()) } == 0 {
+    fn shm_unlink(
+        name: &FileName,
+        location: Option<&Path>,
+    ) -> Result<bool, SharedMemoryRemoveError> {
+        let msg = "Unable to remove shared memory device file";
+        let origin = "SharedMemory::unlink()";
+        let file_path = match Self::shm_path(name, location) {
+            Ok(v) => v,
+            Err(_) => return Ok(false),
+        };
+
+        let result = unsafe {
+            match location {
+                Some(_) => posix::unlink(file_path.as_c_str()),
+                None => posix::shm_unlink(file_path.as_c_str()),
+            }
+        };
+        if result == 0 {
             return Ok(true);
         }
 
-        let msg = "Unable to remove shared memory device file";
-        let origin = "SharedMemory::unlink()";
         match posix::Errno::get() {
             posix::Errno::EACCES => {
                 fail!(from origin, with SharedMemoryRemoveError::InsufficientPermissions,
@@ -367,6 +367,43 @@ impl SignalHandler {
         })
     }
 
+    /// Registers a raw `SA_SIGINFO` handler for multiple signals. The callback is only called
+    /// when the handler is replaced, for instance by
+    /// [`SignalHandler::set_auto_repeat_call_for_interrupt_signals()`].
+    pub(crate) fn register_multiple_signals_with_siginfo<F: Fn(FetchableSignal)>(
+        signals: &[FetchableSignal],
+        siginfo_handler: posix::sighandler_t,
+        callback: &'static F,
+    ) -> Result<SignalGuard, SignalRegisterError> {
+        let mut previous_state = vec![];
+
+        {
+            let mut sighandle = Self::instance();
+            for signal in signals {
+                if sighandle.is_signal_registered(*signal) {
+                    fail!(from sighandle, with SignalRegisterError::AlreadyRegistered,
+                        "The Signal::{:?} is already registered.", signal);
+                }
+            }
+
+            for signal in signals {
+                let mut action = posix::sigaction_t::new_zeroed();
+                action.set_handler(siginfo_handler);
+                action.set_flags(posix::SA_SIGINFO);
+
+                previous_state.push(SignalDetail {
+                    signal: *signal,
+                    state: sighandle.register_signal_from_state(SignalDetail::new(*signal, action)),
+                });
+                sighandle.registered_signals[*signal as usize] = Some(callback);
+            }
+        }
+
+        Ok(SignalGuard {
+            signals: Some(previous_state),
+        })
+    }
+
     /// Calls a provided callable and fetches possible signals which where raised indirectly by
     /// the call. This is helpful for instance when a low level C call can fail by emitting a
     /// signal. On example is `memset` when it writes on a preallocated chunk but the actual
@@ -0,0 +1,380 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Provides a [`SignalListener`] that converts signals into a file descriptor based
+//! notification so that they can be handled in an event loop, for instance by attaching the
+//! [`SignalListener`] to a [`FileDescriptorSet`](crate::file_descriptor_set::FileDescriptorSet)
+//! or a WaitSet, without an additional thread.
+//!
+//! The signal handler writes every received signal into a socket pair (self-pipe trick). A
+//! signalfd is not used since it requires that the signals are blocked in every thread of the
+//! process, otherwise the signals are delivered with their default disposition to every thread
+//! that does not block them.
+//!
+//! When the [`SignalListener`] goes out of scope, the previous dispositions of the signals are
+//! restored.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iceoryx2_bb_posix::signal::FetchableSignal;
+//! use iceoryx2_bb_posix::signal_listener::*;
+//!
+//! let listener = SignalListenerBuilder::new()
+//!                     .signal(FetchableSignal::Hangup)
+//!                     .signal(FetchableSignal::UserDefined1)
+//!                     .create()
+//!                     .expect("failed to create signal listener");
+//!
+//! loop {
+//!     if let Some(info) = listener.blocking_receive().expect("failed to receive signal") {
+//!         match info.signal() {
+//!             FetchableSignal::Hangup => println!("reload config"),
+//!             FetchableSignal::UserDefined1 => println!("dump stats requested by {:?}", info.sender()),
+//!             _ => (),
+//!         }
+//!     }
+//! }
+//! ```
+
+use core::fmt::Debug;
+use core::sync::atomic::Ordering;
+
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::{fail, trace};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicI32;
+use iceoryx2_pal_posix::posix::{self, Errno};
+
+use crate::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use crate::file_descriptor_set::SynchronousMultiplexing;
+use crate::process::ProcessId;
+use crate::signal::{FetchableSignal, SignalGuard, SignalHandler, SignalRegisterError};
+use crate::socket_pair::{StreamingSocket, StreamingSocketPairReceiveError};
+
+const RECORD_SIZE: usize = 2 * core::mem::size_of::<i32>();
+const NO_LISTENER: i32 = -1;
+
+/// Contains for every signal the file descriptor the signal handler writes into.
+static LISTENER_FDS: [IoxAtomicI32; posix::MAX_SIGNAL_VALUE] =
+    [const { IoxAtomicI32::new(NO_LISTENER) }; posix::MAX_SIGNAL_VALUE];
+
+enum_gen! { SignalListenerCreationError
+  entry:
+    NoSignalsProvided,
+    UnsupportedSignal,
+    SignalAlreadyRegistered,
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    InsufficientResources,
+    UnknownError(i32)
+}
+
+enum_gen! { SignalListenerReceiveError
+  entry:
+    Interrupt,
+    InsufficientResources,
+    UnknownError(i32)
+}
+
+impl From<StreamingSocketPairReceiveError> for SignalListenerReceiveError {
+    fn from(value: StreamingSocketPairReceiveError) -> Self {
+        match value {
+            StreamingSocketPairReceiveError::Interrupt => SignalListenerReceiveError::Interrupt,
+            StreamingSocketPairReceiveError::InsufficientMemory
+            | StreamingSocketPairReceiveError::InsufficientResources => {
+                SignalListenerReceiveError::InsufficientResources
+            }
+            StreamingSocketPairReceiveError::ConnectionReset => {
+                SignalListenerReceiveError::UnknownError(Errno::ECONNRESET as i32)
+            }
+            StreamingSocketPairReceiveError::UnknownError(v) => {
+                SignalListenerReceiveError::UnknownError(v)
+            }
+        }
+    }
+}
+
+/// The essentials of a signal that was received by a [`SignalListener`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub struct SignalInfo {
+    signal: FetchableSignal,
+    sender: ProcessId,
+}
+
+impl SignalInfo {
+    /// Returns the received signal.
+    pub fn signal(&self) -> FetchableSignal {
+        self.signal
+    }
+
+    /// Returns the [`ProcessId`] of the process that sent the signal. If the signal was
+    /// raised by the kernel, for instance [`FetchableSignal::BrokenPipe`], or when the
+    /// sender is unknown it is `0`.
+    pub fn sender(&self) -> ProcessId {
+        self.sender
+    }
+}
+
+fn release_listener_fds(signals: &[FetchableSignal]) {
+    for signal in signals {
+        LISTENER_FDS[*signal as usize].store(NO_LISTENER, Ordering::Release);
+    }
+}
+
+fn notify_listener(signal: posix::int, sender: posix::pid_t) {
+    if signal < 0 || signal as usize >= posix::MAX_SIGNAL_VALUE {
+        return;
+    }
+
+    let fd = LISTENER_FDS[signal as usize].load(Ordering::Acquire);
+    if fd == NO_LISTENER {
+        return;
+    }
+
+    let mut record = [0u8; RECORD_SIZE];
+    record[..4].copy_from_slice(&signal.to_ne_bytes());
+    record[4..].copy_from_slice(&sender.to_ne_bytes());
+
+    // the signal handler must not modify the errno of the interrupted code. When the socket
+    // buffer is full, the signal is dropped.
+    let errno = Errno::get();
+    unsafe { posix::send(fd, record.as_ptr().cast(), RECORD_SIZE, 0) };
+    Errno::set(errno);
+}
+
+extern "C" fn siginfo_handler(
+    signal: posix::int,
+    info: *mut posix::siginfo_t,
+    _context: *mut posix::void,
+) {
+    let sender = match unsafe { info.as_ref() } {
+        Some(info) => info.pid(),
+        None => 0,
+    };
+    notify_listener(signal, sender);
+}
+
+fn forward_signal(signal: FetchableSignal) {
+    notify_listener(signal as posix::int, 0);
+}
+
+/// Creates a [`SignalListener`].
+#[derive(Debug, Default)]
+pub struct SignalListenerBuilder {
+    signals: Vec<FetchableSignal>,
+}
+
+impl SignalListenerBuilder {
+    /// Creates a new builder without any signals.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a signal the [`SignalListener`] listens to. Signals that indicate a fault of the
+    /// process, like [`FetchableSignal::SegmentationFault`], are not supported since the process
+    /// cannot continue after them.
+    pub fn signal(mut self, value: FetchableSignal) -> Self {
+        if !self.signals.contains(&value) {
+            self.signals.push(value);
+        }
+        self
+    }
+
+    /// Creates the [`SignalListener`]. Every signal can only be registered once, either by a
+    /// [`SignalListener`] or with the [`SignalHandler`].
+    pub fn create(self) -> Result<SignalListener, SignalListenerCreationError> {
+        let msg = "Unable to create signal listener";
+        if self.signals.is_empty() {
+            fail!(from self, with SignalListenerCreationError::NoSignalsProvided,
+                "{} since no signals were provided.", msg);
+        }
+
+        for signal in &self.signals {
+            if matches!(
+                signal,
+                FetchableSignal::SegmentationFault
+                    | FetchableSignal::Bus
+                    | FetchableSignal::IllegalInstruction
+                    | FetchableSignal::FloatingPointError
+                    | FetchableSignal::TraceTrap
+            ) {
+                fail!(from self, with SignalListenerCreationError::UnsupportedSignal,
+                    "{} since the signal {:?} indicates a fault of the process and cannot be listened to.", msg, signal);
+            }
+        }
+
+        let (receiver, sender) = match StreamingSocket::create_pair() {
+            Ok(sockets) => sockets,
+            Err(e) => {
+                use crate::socket_pair::StreamingSocketPairCreationError as E;
+                let error = match e {
+                    E::PerProcessFileHandleLimitReached => {
+                        SignalListenerCreationError::PerProcessFileHandleLimitReached
+                    }
+                    E::SystemWideFileHandleLimitReached => {
+                        SignalListenerCreationError::SystemWideFileHandleLimitReached
+                    }
+                    E::InsufficientMemory | E::InsufficientResources => {
+                        SignalListenerCreationError::InsufficientResources
+                    }
+                    E::InsufficientPermissions | E::FileDescriptorBroken | E::Interrupt => {
+                        SignalListenerCreationError::UnknownError(Errno::get() as i32)
+                    }
+                    E::UnknownError(v) => SignalListenerCreationError::UnknownError(v),
+                };
+                fail!(from self, with error,
+                    "{} since the underlying socket pair could not be created ({:?}).", msg, e);
+            }
+        };
+
+        // the file descriptors must be set before the signal handler is installed, otherwise
+        // a signal that arrives in between would be lost
+        let sender_fd = unsafe { sender.file_descriptor().native_handle() };
+        for (n, signal) in self.signals.iter().enumerate() {
+            if LISTENER_FDS[*signal as usize]
+                .compare_exchange(NO_LISTENER, sender_fd, Ordering::AcqRel, Ordering::Relaxed)
+                .is_err()
+            {
+                release_listener_fds(&self.signals[..n]);
+                fail!(from self, with SignalListenerCreationError::SignalAlreadyRegistered,
+                    "{} since the signal {:?} is already used by another SignalListener.", msg, signal);
+            }
+        }
+
+        let guard = match SignalHandler::register_multiple_signals_with_siginfo(
+            &self.signals,
+            siginfo_handler as *const () as posix::sighandler_t,
+            &forward_signal,
+        ) {
+            Ok(guard) => guard,
+            Err(SignalRegisterError::AlreadyRegistered) => {
+                release_listener_fds(&self.signals);
+                fail!(from self, with SignalListenerCreationError::SignalAlreadyRegistered,
+                    "{} since at least one of the signals {:?} is already registered.", msg, self.signals);
+            }
+        };
+
+        let listener = SignalListener {
+            signals: self.signals,
+            receiver,
+            sender,
+            guard: Some(guard),
+        };
+
+        trace!(from listener, "created");
+        Ok(listener)
+    }
+}
+
+/// Receives the signals it was created for via a file descriptor. It implements
+/// [`SynchronousMultiplexing`] and can be attached to a
+/// [`FileDescriptorSet`](crate::file_descriptor_set::FileDescriptorSet) or a WaitSet. Since the
+/// file descriptor signals readiness as long as not all signals are received, all signals
+/// should be received whenever it is ready.
+pub struct SignalListener {
+    signals: Vec<FetchableSignal>,
+    receiver: StreamingSocket,
+    sender: StreamingSocket,
+    guard: Option<SignalGuard>,
+}
+
+impl Debug for SignalListener {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "SignalListener {{ signals: {:?}, receiver: {:?}, sender: {:?} }}",
+            self.signals, self.receiver, self.sender
+        )
+    }
+}
+
+impl Drop for SignalListener {
+    fn drop(&mut self) {
+        // restores the previous dispositions
+        drop(self.guard.take());
+
+        release_listener_fds(&self.signals);
+
+        trace!(from self, "dropped");
+    }
+}
+
+impl FileDescriptorBased for SignalListener {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.receiver.file_descriptor()
+    }
+}
+
+impl SynchronousMultiplexing for SignalListener {}
+
+impl SignalListener {
+    /// Returns the signals the [`SignalListener`] listens to.
+    pub fn signals(&self) -> &[FetchableSignal] {
+        &self.signals
+    }
+
+    /// Returns the next received signal. If no signal was received it returns [`None`].
+    pub fn try_receive(&self) -> Result<Option<SignalInfo>, SignalListenerReceiveError> {
+        let mut record = [0u8; RECORD_SIZE];
+        let number_of_bytes = fail!(from self, when self.receiver.try_receive(&mut record),
+            "Unable to receive signal since the underlying socket could not be read.");
+
+        self.complete_record(record, number_of_bytes)
+    }
+
+    /// Blocks until a signal was received. Spurious wakeups can cause it to return [`None`].
+    pub fn blocking_receive(&self) -> Result<Option<SignalInfo>, SignalListenerReceiveError> {
+        let mut record = [0u8; RECORD_SIZE];
+        let number_of_bytes = fail!(from self, when self.receiver.blocking_receive(&mut record),
+            "Unable to receive signal with blocking behavior since the underlying socket could not be read.");
+
+        self.complete_record(record, number_of_bytes)
+    }
+
+    /// Blocks until a signal was received or the timeout has passed. If the timeout passed it
+    /// returns [`None`].
+    pub fn timed_receive(
+        &self,
+        timeout: core::time::Duration,
+    ) -> Result<Option<SignalInfo>, SignalListenerReceiveError> {
+        let mut record = [0u8; RECORD_SIZE];
+        let number_of_bytes = fail!(from self, when self.receiver.timed_receive(&mut record, timeout),
+            "Unable to receive signal with a timeout of {:?} since the underlying socket could not be read.", timeout);
+
+        self.complete_record(record, number_of_bytes)
+    }
+
+    fn complete_record(
+        &self,
+        mut record: [u8; RECORD_SIZE],
+        mut number_of_bytes: usize,
+    ) -> Result<Option<SignalInfo>, SignalListenerReceiveError> {
+        if number_of_bytes == 0 {
+            return Ok(None);
+        }
+
+        // records are written as a whole, a partial read only occurs when the remainder is
+        // not yet visible
+        while number_of_bytes < RECORD_SIZE {
+            number_of_bytes += fail!(from self, when self.receiver.blocking_receive(&mut record[number_of_bytes..]),
+                "Unable to receive signal since the remainder of the signal could not be read.");
+        }
+
+        let signal = i32::from_ne_bytes([record[0], record[1], record[2], record[3]]);
+        let sender = i32::from_ne_bytes([record[4], record[5], record[6], record[7]]);
+
+        Ok(Some(SignalInfo {
+            signal: signal.into(),
+            sender: ProcessId::new(sender as posix::pid_t),
+        }))
+    }
+}
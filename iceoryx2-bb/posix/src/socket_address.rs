@@ -0,0 +1,70 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The [`SocketAddress`] of a network endpoint that is shared by the
+//! [`crate::udp_socket`] and the [`crate::tcp_socket`].
+
+use core::fmt::Display;
+use iceoryx2_bb_system_types::ipv4_address::Ipv4Address;
+use iceoryx2_bb_system_types::port::Port;
+use iceoryx2_pal_posix::posix::{self, MemZeroedStruct, SockAddrIn};
+
+/// The address of a network endpoint, consisting of an ip address and a [`Port`]. Currently
+/// only IPv4 is supported.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum SocketAddress {
+    V4 { address: Ipv4Address, port: Port },
+}
+
+impl Display for SocketAddress {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SocketAddress::V4 { address, port } => write!(f, "{address}:{port}"),
+        }
+    }
+}
+
+impl SocketAddress {
+    /// Creates a new IPv4 [`SocketAddress`].
+    pub const fn new_v4(address: Ipv4Address, port: Port) -> Self {
+        SocketAddress::V4 { address, port }
+    }
+
+    /// Returns the [`Port`] of the [`SocketAddress`].
+    pub const fn port(&self) -> Port {
+        match self {
+            SocketAddress::V4 { port, .. } => *port,
+        }
+    }
+
+    pub(crate) fn from_sockaddr_in(value: &posix::sockaddr_in) -> Self {
+        SocketAddress::V4 {
+            address: unsafe {
+                core::mem::transmute::<u32, Ipv4Address>(u32::from_be(value.get_s_addr()))
+            },
+            port: Port::new(u16::from_be(value.sin_port)),
+        }
+    }
+
+    pub(crate) fn to_sockaddr_in(self) -> posix::sockaddr_in {
+        match self {
+            SocketAddress::V4 { address, port } => {
+                let mut addr = posix::sockaddr_in::new_zeroed();
+                addr.sin_family = posix::AF_INET as _;
+                addr.set_s_addr(address.as_u32().to_be());
+                addr.sin_port = port.as_u16().to_be();
+                addr
+            }
+        }
+    }
+}
@@ -17,6 +17,14 @@
 //! file descriptors between processes or authenticate at another process by sending
 //! [`SocketCred`] containing the process pid, uid and gid.
 //!
+//! On platforms that support credentials for unix datagram sockets, the receiver gets the
+//! [`SocketCred`] of the sending process attached to every message, even when the sender did not
+//! call [`SocketAncillary::set_creds()`]. This can be disabled with
+//! [`crate::unix_datagram_socket::UnixDatagramReceiverBuilder::without_credentials()`] or
+//! enforced with
+//! [`crate::unix_datagram_socket::UnixDatagramReceiverBuilder::with_credentials()`], which
+//! fails on platforms without credential support.
+//!
 //! # Example
 //!
 //! ```no_run
@@ -58,7 +66,7 @@
 //! receiver.blocking_receive_msg(&mut recv_msg).unwrap();
 //! match recv_msg.get_creds() {
 //!     Some(cred) => { println!("received credentials {}", cred); }
-//!     None => { println!("Does not happen when `support_credentials(true)` is set."); }
+//!     None => { println!("Does not happen on platforms with credential support."); }
 //! };
 //!
 //! let mut fd_vec = recv_msg.extract_fds();
@@ -83,12 +91,12 @@ use iceoryx2_pal_posix::{posix::MemZeroedStruct, *};
 pub const MAX_FILE_DESCRIPTORS_PER_MESSAGE: usize = posix::SCM_MAX_FD as usize;
 
 const SIZE_OF_CRED: usize = core::mem::size_of::<posix::ucred>();
-const SIZE_OF_FD: usize = core::mem::size_of::<i32>();
+pub(crate) const SIZE_OF_FD: usize = core::mem::size_of::<i32>();
 const IOVEC_BUFFER_CAPACITY: usize = 1;
 const BUFFER_CAPACITY: usize = 3072;
 pub(crate) const CMSG_SOCKET_LEVEL: posix::int = posix::SOL_SOCKET;
 
-fn buffer_capacity() -> usize {
+pub(crate) fn buffer_capacity() -> usize {
     unsafe {
         (posix::CMSG_SPACE(SIZE_OF_FD as _) as usize) * MAX_FILE_DESCRIPTORS_PER_MESSAGE
             + posix::CMSG_SPACE(SIZE_OF_CRED as _) as usize
@@ -153,6 +161,24 @@ impl SocketCred {
     pub fn get_gid(&self) -> Gid {
         self.gid
     }
+
+    /// # Safety
+    ///
+    ///  * `cmsghdr` must point to a valid `SCM_CREDENTIALS` control message
+    pub(crate) unsafe fn from_cmsghdr(cmsghdr: *mut posix::cmsghdr) -> SocketCred {
+        let mut raw_cred = posix::ucred::new_zeroed();
+        posix::memcpy(
+            (&mut raw_cred as *mut posix::ucred) as *mut posix::void,
+            posix::CMSG_DATA(cmsghdr) as *const posix::void,
+            SIZE_OF_CRED,
+        );
+
+        SocketCred {
+            pid: ProcessId::new(raw_cred.pid),
+            uid: Uid::new_from_native(raw_cred.uid),
+            gid: Gid::new_from_native(raw_cred.gid),
+        }
+    }
 }
 
 impl Display for SocketCred {
@@ -378,20 +404,7 @@ impl SocketAncillary {
                     }
                 }
                 posix::SCM_CREDENTIALS => {
-                    let mut raw_cred = posix::ucred::new_zeroed();
-                    unsafe {
-                        posix::memcpy(
-                            (&mut raw_cred as *mut posix::ucred) as *mut posix::void,
-                            posix::CMSG_DATA(cmsghdr) as *const posix::void,
-                            SIZE_OF_CRED,
-                        )
-                    };
-
-                    self.credentials = Some(SocketCred {
-                        pid: ProcessId::new(raw_cred.pid),
-                        uid: Uid::new_from_native(raw_cred.uid),
-                        gid: Gid::new_from_native(raw_cred.gid),
-                    });
+                    self.credentials = Some(unsafe { SocketCred::from_cmsghdr(cmsghdr) });
                 }
                 v => {
                     warn!(from receiver, "A cmsghdr with an unknown cmsg_type ({}) was received.", v);
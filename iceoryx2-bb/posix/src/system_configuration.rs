@@ -45,6 +45,7 @@ pub enum SystemInfo {
     PageSize = posix::_SC_PAGESIZE,
     NumberOfClockTicksPerSecond = posix::_SC_CLK_TCK,
     NumberOfCpuCores = posix::_SC_NPROCESSORS_CONF,
+    NumberOfOnlineCpuCores = posix::_SC_NPROCESSORS_ONLN,
 }
 
 impl SystemInfo {
@@ -0,0 +1,1091 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Abstraction of TCP sockets. The [`TcpListenerBuilder`] creates a [`TcpListener`] that
+//! accepts incoming connections from a [`TcpClientBuilder`]. Every established connection
+//! is represented by a [`TcpStreamSocket`] that can send and receive a stream of bytes in both
+//! directions.
+//!
+//! Like with the [`crate::unix_stream_socket`] no message boundaries are preserved, a receive
+//! call may acquire only a part of the sent data or the data of multiple send calls.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_posix::tcp_socket::*;
+//! use iceoryx2_bb_system_types::ipv4_address;
+//! use iceoryx2_bb_system_types::port;
+//! use core::time::Duration;
+//!
+//! let listener = TcpListenerBuilder::new()
+//!                     .address(SocketAddress::new_v4(ipv4_address::LOCALHOST, port::UNSPECIFIED))
+//!                     .create().unwrap();
+//!
+//! let client = TcpClientBuilder::new(listener.local_address())
+//!                     .connect_timeout(Duration::from_secs(1))
+//!                     .no_delay(true)
+//!                     .connect().unwrap();
+//! let server = listener.blocking_accept().unwrap();
+//!
+//! // send a header and its payload with one call
+//! let header = 11u32.to_le_bytes();
+//! client.blocking_send_vectored(&[&header, b"hello world"]).unwrap();
+//!
+//! // receive some data
+//! let mut buffer = [0u8; 32];
+//! let bytes_received = server.blocking_receive(&mut buffer).unwrap();
+//! ```
+
+use core::sync::atomic::Ordering;
+use core::{mem::size_of, time::Duration};
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_log::{fail, fatal_panic, trace};
+use iceoryx2_bb_system_types::ipv4_address;
+use iceoryx2_bb_system_types::port;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+use iceoryx2_pal_posix::posix::{errno::Errno, MemZeroedStruct};
+use iceoryx2_pal_posix::*;
+
+use crate::{
+    clock::AsTimeval,
+    file_descriptor::{FileDescriptor, FileDescriptorBased},
+    file_descriptor_set::{
+        FileDescriptorSet, FileDescriptorSetWaitError, FileEvent, SynchronousMultiplexing,
+    },
+};
+
+pub use crate::socket_address::SocketAddress;
+
+const BLOCKING_TIMEOUT: Duration = Duration::from_secs(i16::MAX as _);
+const DEFAULT_BACKLOG: u32 = 128;
+
+enum_gen! {
+    /// Defines the errors that can occur when the underlying socket of a
+    /// [`TcpListener`] or a [`TcpStreamSocket`] is created.
+    TcpSocketCreationError
+  entry:
+    InsufficientPermissions,
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    InsufficientResources,
+    InsufficientMemory,
+    AddressFamilyNotSupported,
+    TcpProtocolNotSupported,
+    InetSocketsNotSupported,
+    UnknownError(i32)
+}
+
+enum_gen! {
+    /// Defines the errors that can occur when the blocking mode of a socket is changed.
+    TcpSetPropertyError
+  entry:
+    Interrupt,
+    UnknownError(i32)
+}
+
+enum_gen! {
+    /// Defines the errors that can occur when a socket option is set.
+    TcpSetSocketOptionError
+  entry:
+    InsufficientResources,
+    InsufficientMemory,
+    UnknownError(i32)
+}
+
+enum_gen! {
+    /// Defines the errors that can occur in [`TcpListenerBuilder::create()`].
+    TcpListenerCreationError
+  entry:
+    InsufficientPermissions,
+    InsufficientResources,
+    AddressAlreadyInUse,
+    AddressNotAvailable,
+    UnknownError(i32)
+  mapping:
+    TcpSocketCreationError,
+    TcpSetSocketOptionError
+}
+
+enum_gen! {
+    /// Defines the errors that can occur in [`TcpClientBuilder::connect()`].
+    TcpClientCreationError
+  entry:
+    InsufficientPermissions,
+    InsufficientResources,
+    AddressNotAvailable,
+    ConnectionRefused,
+    ConnectionReset,
+    ConnectionTimeout,
+    NetworkUnreachable,
+    HostUnreachable,
+    Interrupt,
+    UnknownError(i32)
+  mapping:
+    TcpSocketCreationError,
+    TcpSetPropertyError,
+    TcpSetSocketOptionError
+}
+
+enum_gen! {
+    /// Defines the errors that can occur in
+    /// * [`TcpListener::try_accept()`]
+    /// * [`TcpListener::blocking_accept()`]
+    TcpAcceptError
+  entry:
+    Interrupt,
+    ConnectionAborted,
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    InsufficientResources,
+    InsufficientMemory,
+    UnknownError(i32)
+  mapping:
+    TcpSetPropertyError,
+    TcpSetSocketOptionError
+}
+
+enum_gen! {
+    /// Defines the errors that can occur when a [`TcpStreamSocket`] sends data via
+    /// * [`TcpStreamSocket::try_send()`]
+    /// * [`TcpStreamSocket::timed_send()`]
+    /// * [`TcpStreamSocket::blocking_send()`]
+    /// * [`TcpStreamSocket::try_send_vectored()`]
+    /// * [`TcpStreamSocket::timed_send_vectored()`]
+    /// * [`TcpStreamSocket::blocking_send_vectored()`]
+    TcpSendError
+  entry:
+    Interrupt,
+    ConnectionReset,
+    Disconnected,
+    InsufficientResources,
+    InsufficientMemory,
+    UnknownError(i32)
+  mapping:
+    TcpSetPropertyError,
+    TcpSetSocketOptionError
+}
+
+enum_gen! {
+    /// Defines the errors that can occur when a [`TcpStreamSocket`] receives data via
+    /// * [`TcpStreamSocket::try_receive()`]
+    /// * [`TcpStreamSocket::timed_receive()`]
+    /// * [`TcpStreamSocket::blocking_receive()`]
+    TcpReceiveError
+  entry:
+    Interrupt,
+    ConnectionReset,
+    Disconnected,
+    InsufficientResources,
+    InsufficientMemory,
+    UnknownError(i32)
+  mapping:
+    TcpSetPropertyError,
+    TcpSetSocketOptionError
+}
+
+enum_gen! {
+    /// Defines the errors that can occur in [`TcpStreamSocket::shutdown()`].
+    TcpShutdownError
+  entry:
+    NotConnected,
+    UnknownError(i32)
+}
+
+enum_gen! {
+    /// Defines the errors that can occur in [`TcpStreamSocket::peer_address()`].
+    TcpPeerAddressError
+  entry:
+    NotConnected,
+    InsufficientResources,
+    UnknownError(i32)
+}
+
+/// Defines which direction of a [`TcpStreamSocket`] shall be closed with
+/// [`TcpStreamSocket::shutdown()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum TcpShutdown {
+    /// No further data can be received.
+    Read,
+    /// No further data can be sent, the peer receives
+    /// [`TcpReceiveError::Disconnected`] after it has consumed the pending data.
+    Write,
+    /// Combination of [`TcpShutdown::Read`] and [`TcpShutdown::Write`].
+    Both,
+}
+
+/// The socket options that are applied to every [`TcpStreamSocket`] that is created by
+/// the [`TcpListener`] or the [`TcpClientBuilder`].
+#[derive(Debug, Clone, Copy, Default)]
+struct TcpStreamOptions {
+    no_delay: bool,
+    keep_alive: bool,
+}
+
+#[derive(Debug)]
+struct TcpHandle {
+    file_descriptor: FileDescriptor,
+    is_non_blocking: IoxAtomicBool,
+}
+
+impl FileDescriptorBased for TcpHandle {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.file_descriptor
+    }
+}
+
+impl SynchronousMultiplexing for TcpHandle {}
+
+impl TcpHandle {
+    fn new() -> Result<Self, TcpSocketCreationError> {
+        let raw_fd = unsafe {
+            posix::socket(
+                posix::PF_INET as posix::int,
+                posix::SOCK_STREAM,
+                posix::IPPROTO_TCP,
+            )
+        };
+
+        let msg = "Unable to create TcpSocket";
+        if raw_fd < 0 {
+            handle_errno!(TcpSocketCreationError, from "TcpSocket::new",
+                Errno::EAFNOSUPPORT => (AddressFamilyNotSupported, "{} since the address family is not supported by the system.", msg),
+                Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+                Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process limit of file descriptors was reached.", msg),
+                Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since system-wide limit of file descriptors was reached.", msg),
+                Errno::ENOBUFS => (InsufficientResources, "{} due to insufficient resources.", msg),
+                Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+                Errno::EPROTOTYPE => (InetSocketsNotSupported, "{} since PF_INET socket type is not supported.", msg),
+                Errno::EPROTONOSUPPORT => (TcpProtocolNotSupported, "{} since the tcp protocol is not supported by the system.", msg),
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+
+        Ok(Self {
+            file_descriptor: FileDescriptor::new(raw_fd).unwrap(),
+            is_non_blocking: IoxAtomicBool::new(false),
+        })
+    }
+
+    fn from_file_descriptor(file_descriptor: FileDescriptor) -> Result<Self, TcpSetPropertyError> {
+        let new_self = Self {
+            file_descriptor,
+            is_non_blocking: IoxAtomicBool::new(false),
+        };
+
+        // some platforms let accepted sockets inherit the flags of the listening socket
+        let current_flags = new_self.fcntl(
+            posix::F_GETFL,
+            0,
+            "Unable to acquire current socket file descriptor flags",
+        )?;
+        new_self
+            .is_non_blocking
+            .store(current_flags & posix::O_NONBLOCK != 0, Ordering::Relaxed);
+
+        Ok(new_self)
+    }
+
+    fn local_address(&self) -> SocketAddress {
+        let mut details = posix::sockaddr_in::new_zeroed();
+        let mut details_len = size_of::<posix::sockaddr_in>() as posix::socklen_t;
+
+        if unsafe {
+            posix::getsockname(
+                self.file_descriptor.native_handle(),
+                (&mut details as *mut posix::sockaddr_in) as *mut posix::sockaddr,
+                &mut details_len,
+            )
+        } == -1
+        {
+            fatal_panic!(from self,
+                "This should never happen! Unable to acquire the local address of the socket ({:?}).",
+                Errno::get());
+        }
+
+        SocketAddress::from_sockaddr_in(&details)
+    }
+
+    fn fcntl(&self, command: i32, value: i32, msg: &str) -> Result<i32, TcpSetPropertyError> {
+        let result =
+            unsafe { posix::fcntl_int(self.file_descriptor.native_handle(), command, value) };
+
+        if result >= 0 {
+            return Ok(result);
+        }
+
+        handle_errno!(TcpSetPropertyError, from self,
+            fatal Errno::EBADF => ("This should never happen! {} since the file descriptor is invalid.", msg);
+            fatal Errno::EINVAL => ("This should never happen! {} since an internal argument was invalid.", msg),
+            Errno::EINTR => (Interrupt, "{} due to an interrupt signal.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
+    fn set_non_blocking(&self, value: bool) -> Result<(), TcpSetPropertyError> {
+        if self.is_non_blocking.load(Ordering::Relaxed) == value {
+            return Ok(());
+        }
+
+        let current_flags = self.fcntl(
+            posix::F_GETFL,
+            0,
+            "Unable to acquire current socket file descriptor flags",
+        )?;
+        let new_flags = match value {
+            true => current_flags | posix::O_NONBLOCK,
+            false => current_flags & !posix::O_NONBLOCK,
+        };
+
+        self.fcntl(posix::F_SETFL, new_flags, "Unable to set blocking mode")?;
+        self.is_non_blocking.store(value, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn set_socket_option<T>(
+        &self,
+        msg: &str,
+        value: &T,
+        level: posix::int,
+        socket_option: posix::int,
+    ) -> Result<(), TcpSetSocketOptionError> {
+        if unsafe {
+            posix::setsockopt(
+                self.file_descriptor.native_handle(),
+                level,
+                socket_option,
+                (value as *const T) as *const posix::void,
+                size_of::<T>() as u32,
+            )
+        } == 0
+        {
+            return Ok(());
+        }
+
+        handle_errno!(TcpSetSocketOptionError, from self,
+            fatal Errno::EBADF => ("This should never happen! {} since the file descriptor is invalid.", msg);
+            fatal Errno::EINVAL => ("This should never happen! {} since an argument is invalid.", msg),
+            Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+            Errno::ENOBUFS => (InsufficientResources, "{} due to insufficient resources.", msg),
+            v => (UnknownError(v as i32), "{} caused by an unknown error ({}).", msg, v)
+        );
+    }
+
+    fn apply_stream_options(
+        &self,
+        options: &TcpStreamOptions,
+    ) -> Result<(), TcpSetSocketOptionError> {
+        if options.no_delay {
+            let value: posix::int = 1;
+            self.set_socket_option(
+                "Unable to disable the nagle algorithm",
+                &value,
+                posix::IPPROTO_TCP,
+                posix::TCP_NODELAY,
+            )?;
+        }
+
+        if options.keep_alive {
+            let value: posix::int = 1;
+            self.set_socket_option(
+                "Unable to enable keep alive messages",
+                &value,
+                posix::SOL_SOCKET,
+                posix::SO_KEEPALIVE,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Creates a [`TcpListener`] which binds a socket to the given [`SocketAddress`] and waits for
+/// incoming connections.
+#[derive(Debug)]
+pub struct TcpListenerBuilder {
+    address: SocketAddress,
+    backlog: u32,
+    reuse_address: bool,
+    options: TcpStreamOptions,
+}
+
+impl Default for TcpListenerBuilder {
+    fn default() -> Self {
+        Self {
+            address: SocketAddress::new_v4(ipv4_address::UNSPECIFIED, port::UNSPECIFIED),
+            backlog: DEFAULT_BACKLOG,
+            reuse_address: false,
+            options: TcpStreamOptions::default(),
+        }
+    }
+}
+
+impl TcpListenerBuilder {
+    /// Creates a new [`TcpListenerBuilder`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Can be set optionally. Defines the [`SocketAddress`] the [`TcpListener`] is bound to. If
+    /// no address is set the listener is bound to all available addresses and the operating
+    /// system chooses a free port.
+    pub fn address(mut self, address: SocketAddress) -> Self {
+        self.address = address;
+        self
+    }
+
+    /// Defines the maximum number of pending connections that were not yet accepted. The
+    /// operating system is allowed to adjust the value.
+    pub fn backlog(mut self, value: u32) -> Self {
+        self.backlog = value;
+        self
+    }
+
+    /// Defines if the [`TcpListener`] can be bound to an address whose previous connections
+    /// are still lingering in the TIME_WAIT state. Default is false.
+    pub fn reuse_address(mut self, value: bool) -> Self {
+        self.reuse_address = value;
+        self
+    }
+
+    /// Disables the nagle algorithm on every accepted [`TcpStreamSocket`] so that small
+    /// messages are sent immediately. Default is false.
+    pub fn no_delay(mut self, value: bool) -> Self {
+        self.options.no_delay = value;
+        self
+    }
+
+    /// Enables keep alive messages on every accepted [`TcpStreamSocket`] to detect
+    /// broken connections. Default is false.
+    pub fn keep_alive(mut self, value: bool) -> Self {
+        self.options.keep_alive = value;
+        self
+    }
+
+    /// Creates the [`TcpListener`] and starts listening on the specified address.
+    pub fn create(self) -> Result<TcpListener, TcpListenerCreationError> {
+        let msg = format!("Unable to create TcpListener on {}", self.address);
+        let handle = fail!(from self, when TcpHandle::new(), "{}.", msg);
+
+        if self.reuse_address {
+            let value: posix::int = 1;
+            fail!(from self, when handle.set_socket_option("Unable to enable address reuse", &value, posix::SOL_SOCKET, posix::SO_REUSEADDR),
+                "{} since the address reuse could not be enabled.", msg);
+        }
+
+        let socket_address = self.address.to_sockaddr_in();
+        if unsafe {
+            posix::bind(
+                handle.file_descriptor.native_handle(),
+                (&socket_address as *const posix::sockaddr_in) as *const posix::sockaddr,
+                size_of::<posix::sockaddr_in>() as u32,
+            )
+        } == -1
+        {
+            handle_errno!(TcpListenerCreationError, from self,
+                Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+                Errno::EADDRINUSE => (AddressAlreadyInUse, "{} since the address is already in use.", msg),
+                Errno::EADDRNOTAVAIL => (AddressNotAvailable, "{} since the address is not available.", msg),
+                Errno::ENOBUFS => (InsufficientResources, "{} due to insufficient resources.", msg),
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+
+        if unsafe {
+            posix::listen(
+                handle.file_descriptor.native_handle(),
+                self.backlog.min(posix::int::MAX as u32) as posix::int,
+            )
+        } != 0
+        {
+            handle_errno!(TcpListenerCreationError, from self,
+                Errno::EACCES => (InsufficientPermissions, "{} since the socket could not listen due to insufficient permissions.", msg),
+                Errno::EADDRINUSE => (AddressAlreadyInUse, "{} since the address is already in use.", msg),
+                Errno::ENOBUFS => (InsufficientResources, "{} since the socket could not listen due to insufficient resources.", msg),
+                v => (UnknownError(v as i32), "{} since the socket could not listen due to an unknown error ({}).", msg, v)
+            );
+        }
+
+        let new_self = TcpListener {
+            handle,
+            options: self.options,
+        };
+        trace!(from new_self, "create and listening");
+        Ok(new_self)
+    }
+}
+
+/// Created by the [`TcpListenerBuilder`]. Accepts incoming connections with
+/// [`TcpListener::try_accept()`] or [`TcpListener::blocking_accept()`]. Already accepted
+/// [`TcpStreamSocket`]s stay connected when the listener goes out of scope.
+#[derive(Debug)]
+pub struct TcpListener {
+    handle: TcpHandle,
+    options: TcpStreamOptions,
+}
+
+impl Drop for TcpListener {
+    fn drop(&mut self) {
+        trace!(from self, "stop listening");
+    }
+}
+
+impl FileDescriptorBased for TcpListener {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.handle.file_descriptor
+    }
+}
+
+impl SynchronousMultiplexing for TcpListener {}
+
+impl TcpListener {
+    /// Returns the [`SocketAddress`] the [`TcpListener`] is bound to.
+    pub fn local_address(&self) -> SocketAddress {
+        self.handle.local_address()
+    }
+
+    fn accept_impl(&self, msg: &str) -> Result<Option<TcpStreamSocket>, TcpAcceptError> {
+        let raw_fd = unsafe {
+            posix::accept(
+                self.handle.file_descriptor.native_handle(),
+                core::ptr::null_mut(),
+                core::ptr::null_mut(),
+            )
+        };
+
+        if raw_fd >= 0 {
+            let file_descriptor = match FileDescriptor::new(raw_fd) {
+                Some(fd) => fd,
+                None => {
+                    fatal_panic!(from self,
+                        "This should never happen! {} since accept returned a broken file descriptor.", msg);
+                }
+            };
+
+            let handle = fail!(from self, when TcpHandle::from_file_descriptor(file_descriptor),
+                "{} since the properties of the accepted socket could not be acquired.", msg);
+            fail!(from self, when handle.apply_stream_options(&self.options),
+                "{} since the socket options could not be applied to the accepted socket.", msg);
+            let socket = TcpStreamSocket { handle };
+            match socket.peer_address() {
+                Ok(address) => trace!(from self, "accepted new connection from {}", address),
+                Err(e) => {
+                    trace!(from self, "accepted new connection from an unknown peer ({:?})", e)
+                }
+            }
+            return Ok(Some(socket));
+        }
+
+        handle_errno!(TcpAcceptError, from self,
+            success Errno::EAGAIN => None;
+            success Errno::ETIMEDOUT => None,
+            fatal Errno::EBADF => ("This should never happen! {} since the internal file descriptor was invalid.", msg);
+            fatal Errno::EINVAL => ("This should never happen! {} since the socket is not listening.", msg),
+            Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+            Errno::ECONNABORTED => (ConnectionAborted, "{} since the connection was aborted.", msg),
+            Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process limit of file descriptors was reached.", msg),
+            Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since system-wide limit of file descriptors was reached.", msg),
+            Errno::ENOBUFS => (InsufficientResources, "{} due to insufficient resources.", msg),
+            Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
+    /// Tries to accept a pending connection. It does not block, when no connection is pending
+    /// it returns [`None`], otherwise the connected [`TcpStreamSocket`].
+    pub fn try_accept(&self) -> Result<Option<TcpStreamSocket>, TcpAcceptError> {
+        let msg = "Unable to try accepting a connection";
+        fail!(from self, when self.handle.set_non_blocking(true),
+            "{} since the socket could not be set into non-blocking mode.", msg);
+        self.accept_impl(msg)
+    }
+
+    /// Blocks until a connection was established and returns the connected
+    /// [`TcpStreamSocket`].
+    pub fn blocking_accept(&self) -> Result<TcpStreamSocket, TcpAcceptError> {
+        let msg = "Unable to accept a connection with blocking behavior";
+        fail!(from self, when self.handle.set_non_blocking(false),
+            "{} since the socket could not be set into blocking mode.", msg);
+
+        loop {
+            if let Some(socket) = self.accept_impl(msg)? {
+                return Ok(socket);
+            }
+        }
+    }
+}
+
+/// Creates a [`TcpStreamSocket`] that is connected to a [`TcpListener`] at the given
+/// [`SocketAddress`].
+#[derive(Debug)]
+pub struct TcpClientBuilder {
+    destination: SocketAddress,
+    connect_timeout: Option<Duration>,
+    options: TcpStreamOptions,
+}
+
+impl TcpClientBuilder {
+    /// Creates a new [`TcpClientBuilder`]. Requires the address of the [`TcpListener`].
+    pub fn new(destination: SocketAddress) -> Self {
+        Self {
+            destination,
+            connect_timeout: None,
+            options: TcpStreamOptions::default(),
+        }
+    }
+
+    /// Defines the maximum time [`TcpClientBuilder::connect()`] waits for the connection to
+    /// be established. If it is not set the timeout of the operating system applies.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Disables the nagle algorithm so that small messages are sent immediately.
+    /// Default is false.
+    pub fn no_delay(mut self, value: bool) -> Self {
+        self.options.no_delay = value;
+        self
+    }
+
+    /// Enables keep alive messages to detect broken connections. Default is false.
+    pub fn keep_alive(mut self, value: bool) -> Self {
+        self.options.keep_alive = value;
+        self
+    }
+
+    /// Connects to the [`TcpListener`] and returns the connected [`TcpStreamSocket`].
+    pub fn connect(self) -> Result<TcpStreamSocket, TcpClientCreationError> {
+        let msg = format!("Unable to connect to {}", self.destination);
+        let handle = fail!(from self, when TcpHandle::new(), "{}.", msg);
+        fail!(from self, when handle.apply_stream_options(&self.options),
+            "{} since the socket options could not be applied.", msg);
+
+        if self.connect_timeout.is_some() {
+            fail!(from self, when handle.set_non_blocking(true),
+                "{} since the socket could not be set into non-blocking mode.", msg);
+        }
+
+        let socket_address = self.destination.to_sockaddr_in();
+        if unsafe {
+            posix::connect(
+                handle.file_descriptor.native_handle(),
+                (&socket_address as *const posix::sockaddr_in) as *const posix::sockaddr,
+                size_of::<posix::sockaddr_in>() as u32,
+            )
+        } == -1
+        {
+            match (Errno::get(), self.connect_timeout) {
+                (Errno::EINPROGRESS, Some(timeout)) => {
+                    self.wait_for_connection(&handle, timeout, &msg)?
+                }
+                _ => self.handle_connect_error(&msg)?,
+            }
+        }
+
+        let new_socket = TcpStreamSocket { handle };
+        trace!(from new_socket, "connected to {}", self.destination);
+        Ok(new_socket)
+    }
+
+    fn wait_for_connection(
+        &self,
+        handle: &TcpHandle,
+        timeout: Duration,
+        msg: &str,
+    ) -> Result<(), TcpClientCreationError> {
+        let fd_set = FileDescriptorSet::new();
+        let _guard = fatal_panic!(from self, when fd_set.add(handle),
+                "This should never happen! {} since the socket could not be attached to a fd set.", msg);
+
+        let mut is_connected = false;
+        match fd_set.timed_wait(timeout, FileEvent::Write, |_| is_connected = true) {
+            Ok(_) => (),
+            Err(FileDescriptorSetWaitError::Interrupt) => {
+                fail!(from self, with TcpClientCreationError::Interrupt,
+                    "{} since an interrupt signal was received while waiting for the connection.", msg);
+            }
+            Err(FileDescriptorSetWaitError::TooManyAttachedFileDescriptors) => {
+                fail!(from self, with TcpClientCreationError::InsufficientResources,
+                    "{} since the socket could not be monitored while waiting for the connection.", msg);
+            }
+            Err(FileDescriptorSetWaitError::InsufficientPermissions) => {
+                fail!(from self, with TcpClientCreationError::InsufficientPermissions,
+                    "{} due to insufficient permissions to wait for the connection.", msg);
+            }
+            Err(FileDescriptorSetWaitError::UnknownError(v)) => {
+                fail!(from self, with TcpClientCreationError::UnknownError(v),
+                    "{} since an unknown error occurred while waiting for the connection ({}).", msg, v);
+            }
+        }
+
+        if !is_connected {
+            fail!(from self, with TcpClientCreationError::ConnectionTimeout,
+                "{} since the connection could not be established within {:?}.", msg, timeout);
+        }
+
+        let mut error: posix::int = 0;
+        let mut error_len = size_of::<posix::int>() as posix::socklen_t;
+        if unsafe {
+            posix::getsockopt(
+                handle.file_descriptor.native_handle(),
+                posix::SOL_SOCKET,
+                posix::SO_ERROR,
+                (&mut error as *mut posix::int) as *mut posix::void,
+                &mut error_len,
+            )
+        } == -1
+        {
+            fail!(from self, with TcpClientCreationError::UnknownError(Errno::get() as i32),
+                "{} since the result of the connection attempt could not be acquired.", msg);
+        }
+
+        if error == 0 {
+            return Ok(());
+        }
+
+        Errno::set(error.into());
+        self.handle_connect_error(msg)
+    }
+
+    fn handle_connect_error(&self, msg: &str) -> Result<(), TcpClientCreationError> {
+        handle_errno!(TcpClientCreationError, from self,
+            Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+            Errno::EADDRNOTAVAIL => (AddressNotAvailable, "{} since the address is not available.", msg),
+            Errno::ECONNREFUSED => (ConnectionRefused, "{} since the connection was refused.", msg),
+            Errno::ECONNRESET => (ConnectionReset, "{} since the host reset the connection request.", msg),
+            Errno::ETIMEDOUT => (ConnectionTimeout, "{} since the connection attempt timed out.", msg),
+            Errno::ENETUNREACH => (NetworkUnreachable, "{} since the network is unreachable.", msg),
+            Errno::EHOSTUNREACH => (HostUnreachable, "{} since the host is unreachable.", msg),
+            Errno::EINTR => (Interrupt, "{} since an interrupt was received.", msg),
+            Errno::ENOBUFS => (InsufficientResources, "{} since there is no buffer space available.", msg),
+            v => (UnknownError(v as i32), "{} caused by an unknown error ({}).", msg, v)
+        );
+    }
+}
+
+/// A connected TCP stream socket, created either by [`TcpListener::try_accept()`],
+/// [`TcpListener::blocking_accept()`] or [`TcpClientBuilder::connect()`].
+/// Both sides can send and receive data.
+#[derive(Debug)]
+pub struct TcpStreamSocket {
+    handle: TcpHandle,
+}
+
+impl FileDescriptorBased for TcpStreamSocket {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.handle.file_descriptor
+    }
+}
+
+impl SynchronousMultiplexing for TcpStreamSocket {}
+
+impl TcpStreamSocket {
+    /// Returns the local [`SocketAddress`] of the connection.
+    pub fn local_address(&self) -> SocketAddress {
+        self.handle.local_address()
+    }
+
+    /// Returns the [`SocketAddress`] of the peer. Fails with
+    /// [`TcpPeerAddressError::NotConnected`] when the peer has already disconnected.
+    pub fn peer_address(&self) -> Result<SocketAddress, TcpPeerAddressError> {
+        let msg = "Unable to acquire the peer address";
+        let mut details = posix::sockaddr_in::new_zeroed();
+        let mut details_len = size_of::<posix::sockaddr_in>() as posix::socklen_t;
+
+        if unsafe {
+            posix::getpeername(
+                self.handle.file_descriptor.native_handle(),
+                (&mut details as *mut posix::sockaddr_in) as *mut posix::sockaddr,
+                &mut details_len,
+            )
+        } == 0
+        {
+            return Ok(SocketAddress::from_sockaddr_in(&details));
+        }
+
+        handle_errno!(TcpPeerAddressError, from self,
+            fatal Errno::EBADF => ("This should never happen! {msg} since the internal file descriptor was invalid.");
+            fatal Errno::ENOTSOCK => ("This should never happen! {msg} since the internal file descriptor is not a socket."),
+            Errno::ENOTCONN => (NotConnected, "{msg} since the socket is not connected."),
+            Errno::EINVAL => (NotConnected, "{msg} since the socket has been shut down."),
+            Errno::ENOBUFS => (InsufficientResources, "{msg} due to insufficient resources."),
+            v => (UnknownError(v as i32), "{msg} since an unknown error occurred ({v}).")
+        )
+    }
+
+    fn set_send_timeout(&self, timeout: Duration) -> Result<(), TcpSetSocketOptionError> {
+        self.handle.set_socket_option(
+            "Unable to set send timeout",
+            &timeout.as_timeval(),
+            posix::SOL_SOCKET,
+            posix::SO_SNDTIMEO,
+        )
+    }
+
+    fn set_receive_timeout(&self, timeout: Duration) -> Result<(), TcpSetSocketOptionError> {
+        self.handle.set_socket_option(
+            "Unable to set receive timeout",
+            &timeout.as_timeval(),
+            posix::SOL_SOCKET,
+            posix::SO_RCVTIMEO,
+        )
+    }
+
+    fn handle_send_result(
+        &self,
+        msg: &str,
+        number_of_bytes_written: isize,
+    ) -> Result<usize, TcpSendError> {
+        if 0 <= number_of_bytes_written {
+            return Ok(number_of_bytes_written as _);
+        }
+
+        handle_errno!(TcpSendError, from self,
+            success Errno::EAGAIN => 0;
+            success Errno::ETIMEDOUT => 0,
+            fatal Errno::EBADF => ("This should never happen! {msg} since the internal file descriptor was invalid.");
+            fatal Errno::EINVAL => ("This should never happen! {msg} since an internal argument was invalid."),
+            Errno::EINTR => (Interrupt, "{msg} since an interrupt signal was received."),
+            Errno::ECONNRESET => (ConnectionReset, "{msg} since the connection was reset."),
+            Errno::EPIPE => (Disconnected, "{msg} since the socket is no longer connected."),
+            Errno::ENOBUFS => (InsufficientResources, "{msg} due to insufficient resources."),
+            Errno::ENOMEM => (InsufficientMemory, "{msg} due to insufficient memory."),
+            v => (UnknownError(v as i32), "{msg} since an unknown error occurred ({v}).")
+        )
+    }
+
+    fn send_impl(&self, msg: &str, buf: &[u8]) -> Result<usize, TcpSendError> {
+        let number_of_bytes_written = unsafe {
+            posix::send(
+                self.handle.file_descriptor.native_handle(),
+                buf.as_ptr().cast(),
+                buf.len(),
+                posix::MSG_NOSIGNAL,
+            )
+        };
+
+        self.handle_send_result(msg, number_of_bytes_written as _)
+    }
+
+    fn send_vectored_impl(&self, msg: &str, buffers: &[&[u8]]) -> Result<usize, TcpSendError> {
+        let mut iovecs: Vec<posix::iovec> = buffers
+            .iter()
+            .map(|buffer| {
+                let mut iovec = posix::iovec::new_zeroed();
+                iovec.set_base(buffer.as_ptr() as *mut posix::void);
+                iovec.set_len(buffer.len());
+                iovec
+            })
+            .collect();
+
+        let mut message = posix::msghdr::new_zeroed();
+        message.msg_iov = iovecs.as_mut_ptr().cast();
+        message.msg_iovlen = iovecs.len() as _;
+
+        let number_of_bytes_written = unsafe {
+            posix::sendmsg(
+                self.handle.file_descriptor.native_handle(),
+                &message,
+                posix::MSG_NOSIGNAL,
+            )
+        };
+
+        if number_of_bytes_written == -1 && Errno::get() == Errno::ENOTSUP {
+            return self.send_sequentially(msg, buffers);
+        }
+
+        self.handle_send_result(msg, number_of_bytes_written as _)
+    }
+
+    // Fallback for platforms without sendmsg, stops at the first buffer that could not be
+    // delivered completely so that the bytes are sent in the same order as with sendmsg.
+    fn send_sequentially(&self, msg: &str, buffers: &[&[u8]]) -> Result<usize, TcpSendError> {
+        let mut number_of_bytes_written = 0;
+        for buffer in buffers {
+            match self.send_impl(msg, buffer) {
+                Ok(n) => {
+                    number_of_bytes_written += n;
+                    if n < buffer.len() {
+                        break;
+                    }
+                }
+                Err(_) if number_of_bytes_written != 0 => break,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(number_of_bytes_written)
+    }
+
+    /// Tries to send the given buffer. It does not block, when the internal buffer is full it
+    /// returns `0`, otherwise it returns the number of bytes that were sent which can be less
+    /// than the size of the provided buffer.
+    pub fn try_send(&self, buffer: &[u8]) -> Result<usize, TcpSendError> {
+        let msg = "Unable to try sending data";
+        fail!(from self, when self.handle.set_non_blocking(true),
+            "{msg} since the socket could not be set into non-blocking mode.");
+        self.send_impl(msg, buffer)
+    }
+
+    /// Blocks until either the timeout has passed or until the data could be delivered.
+    /// If the timeout passed it returns `0`, otherwise the number of bytes that were sent.
+    pub fn timed_send(&self, buffer: &[u8], timeout: Duration) -> Result<usize, TcpSendError> {
+        let msg = "Unable to send data with a timeout";
+        fail!(from self, when self.handle.set_non_blocking(false),
+            "{msg} ({timeout:?}) since the socket could not be set into blocking mode.");
+        fail!(from self, when self.set_send_timeout(timeout),
+            "{msg} ({timeout:?}) since the socket send timeout could not be set.");
+        self.send_impl(msg, buffer)
+    }
+
+    /// Blocks until the data could be delivered.
+    /// Despite the name, the function may not block indefinitely and spurious wakeups can cause
+    /// to return `0` when no data could be delivered.
+    pub fn blocking_send(&self, buffer: &[u8]) -> Result<usize, TcpSendError> {
+        let msg = "Unable to send data with blocking behavior";
+        fail!(from self, when self.handle.set_non_blocking(false),
+            "{msg} since the socket could not be set into blocking mode.");
+        fail!(from self, when self.set_send_timeout(BLOCKING_TIMEOUT),
+            "{msg} since the socket blocking send timeout could not be set.");
+        self.send_impl(msg, buffer)
+    }
+
+    /// Tries to send the given buffers in order with a single call, e.g. a header followed by
+    /// its payload. It does not block, when the internal buffer is full it returns `0`,
+    /// otherwise the number of bytes that were sent which can be less than the combined size
+    /// of the provided buffers.
+    pub fn try_send_vectored(&self, buffers: &[&[u8]]) -> Result<usize, TcpSendError> {
+        let msg = "Unable to try sending vectored data";
+        fail!(from self, when self.handle.set_non_blocking(true),
+            "{msg} since the socket could not be set into non-blocking mode.");
+        self.send_vectored_impl(msg, buffers)
+    }
+
+    /// Blocks until either the timeout has passed or until the buffers could be delivered.
+    /// If the timeout passed it returns `0`, otherwise the number of bytes that were sent.
+    pub fn timed_send_vectored(
+        &self,
+        buffers: &[&[u8]],
+        timeout: Duration,
+    ) -> Result<usize, TcpSendError> {
+        let msg = "Unable to send vectored data with a timeout";
+        fail!(from self, when self.handle.set_non_blocking(false),
+            "{msg} ({timeout:?}) since the socket could not be set into blocking mode.");
+        fail!(from self, when self.set_send_timeout(timeout),
+            "{msg} ({timeout:?}) since the socket send timeout could not be set.");
+        self.send_vectored_impl(msg, buffers)
+    }
+
+    /// Blocks until the buffers could be delivered. Returns the number of bytes that were sent.
+    /// Despite the name, the function may not block indefinitely and spurious wakeups can cause
+    /// to return `0` when no data could be delivered.
+    pub fn blocking_send_vectored(&self, buffers: &[&[u8]]) -> Result<usize, TcpSendError> {
+        let msg = "Unable to send vectored data with blocking behavior";
+        fail!(from self, when self.handle.set_non_blocking(false),
+            "{msg} since the socket could not be set into blocking mode.");
+        fail!(from self, when self.set_send_timeout(BLOCKING_TIMEOUT),
+            "{msg} since the socket blocking send timeout could not be set.");
+        self.send_vectored_impl(msg, buffers)
+    }
+
+    fn receive_impl(&self, msg: &str, buf: &mut [u8]) -> Result<usize, TcpReceiveError> {
+        let number_of_bytes_read = unsafe {
+            posix::recv(
+                self.handle.file_descriptor.native_handle(),
+                buf.as_mut_ptr().cast(),
+                buf.len(),
+                0,
+            )
+        };
+
+        if 0 < number_of_bytes_read || (number_of_bytes_read == 0 && buf.is_empty()) {
+            return Ok(number_of_bytes_read as _);
+        }
+
+        if number_of_bytes_read == 0 {
+            fail!(from self, with TcpReceiveError::Disconnected,
+                "{msg} since the peer has closed the connection.");
+        }
+
+        handle_errno!(TcpReceiveError, from self,
+            success Errno::EAGAIN => 0;
+            success Errno::ETIMEDOUT => 0,
+            fatal Errno::EBADF => ("This should never happen! {msg} since the internal file descriptor was invalid.");
+            fatal Errno::EINVAL => ("This should never happen! {msg} since an internal argument was invalid."),
+            Errno::EINTR => (Interrupt, "{msg} since an interrupt signal was received."),
+            Errno::ECONNRESET => (ConnectionReset, "{msg} since the connection was reset."),
+            Errno::ENOBUFS => (InsufficientResources, "{msg} due to insufficient resources."),
+            Errno::ENOMEM => (InsufficientMemory, "{msg} due to insufficient memory."),
+            v => (UnknownError(v as i32), "{msg} since an unknown error occurred ({v}).")
+        )
+    }
+
+    /// Tries to receive data. It does not block, when no data is available it
+    /// returns `0`, otherwise it returns the number of bytes that were received.
+    /// When the peer has closed the connection and all pending data was consumed it fails
+    /// with [`TcpReceiveError::Disconnected`].
+    pub fn try_receive(&self, buf: &mut [u8]) -> Result<usize, TcpReceiveError> {
+        let msg = "Unable to try receiving data";
+        fail!(from self, when self.handle.set_non_blocking(true),
+            "{msg} since the socket could not be set into non-blocking mode.");
+        self.receive_impl(msg, buf)
+    }
+
+    /// Blocks until either the timeout has passed or until data could be received.
+    /// If the timeout passed it returns `0`, otherwise the number of bytes that were received.
+    pub fn timed_receive(
+        &self,
+        buf: &mut [u8],
+        timeout: Duration,
+    ) -> Result<usize, TcpReceiveError> {
+        let msg = "Unable to receive data with a timeout";
+        fail!(from self, when self.handle.set_non_blocking(false),
+            "{msg} ({timeout:?}) since the socket could not be set into blocking mode.");
+        fail!(from self, when self.set_receive_timeout(timeout),
+            "{msg} ({timeout:?}) since the socket receive timeout could not be set.");
+        self.receive_impl(msg, buf)
+    }
+
+    /// Blocks until data could be received.
+    /// Despite the name, the function may not block indefinitely and spurious wakeups can cause
+    /// to return `0` when no data could be received.
+    pub fn blocking_receive(&self, buf: &mut [u8]) -> Result<usize, TcpReceiveError> {
+        let msg = "Unable to receive data with blocking behavior";
+        fail!(from self, when self.handle.set_non_blocking(false),
+            "{msg} since the socket could not be set into blocking mode.");
+        fail!(from self, when self.set_receive_timeout(BLOCKING_TIMEOUT),
+            "{msg} since the socket blocking receive timeout could not be set.");
+        self.receive_impl(msg, buf)
+    }
+
+    /// Closes the provided direction of the connection while the socket itself stays valid.
+    pub fn shutdown(&self, how: TcpShutdown) -> Result<(), TcpShutdownError> {
+        let how_value = match how {
+            TcpShutdown::Read => posix::SHUT_RD,
+            TcpShutdown::Write => posix::SHUT_WR,
+            TcpShutdown::Both => posix::SHUT_RDWR,
+        };
+
+        if unsafe { posix::shutdown(self.handle.file_descriptor.native_handle(), how_value) } == 0 {
+            trace!(from self, "shutdown {:?}", how);
+            return Ok(());
+        }
+
+        let msg = format!("Unable to shutdown {how:?}");
+        handle_errno!(TcpShutdownError, from self,
+            fatal Errno::EBADF => ("This should never happen! {} since the internal file descriptor was invalid.", msg);
+            fatal Errno::EINVAL => ("This should never happen! {} since an internal argument was invalid.", msg),
+            Errno::ENOTCONN => (NotConnected, "{} since the socket is not connected.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+}
@@ -56,9 +56,9 @@
 //! fn some_func() {}
 //!
 //! // when creating highly specialized threads check the system parameters first
-//! // check how many cpu cores we have available to set the CPU affinity correctly. The cores are
+//! // check how many cpu cores are online to set the CPU affinity correctly. The cores are
 //! // enumerated from 0..number_of_cpu_cores-1
-//! let number_of_cpu_cores = SystemInfo::NumberOfCpuCores.value();
+//! let number_of_cpu_cores = SystemInfo::NumberOfOnlineCpuCores.value();
 //! // the stack size must have at least this size otherwise we are unable to create a thread
 //! let minimum_stack_size = Limit::MinStackSizeOfThread.value();
 //!
@@ -94,7 +94,7 @@ use iceoryx2_pal_posix::*;
 
 use crate::{
     config::MAX_THREAD_NAME_LENGTH,
-    scheduler::Scheduler,
+    scheduler::{Scheduler, SchedulerConversionError},
     signal::Signal,
     system_configuration::{Limit, SystemInfo},
 };
@@ -117,6 +117,7 @@ enum_gen! { ThreadSpawnError
     SchedulerPriorityInheritanceNotSupported,
     ThreadPrioritiesNotSupported,
     CpuCoreOutsideOfSupportedCpuRangeForAffinity,
+    CpuAffinityNotSupported,
     UnknownError(i32)
   mapping:
     ThreadSetNameError
@@ -136,9 +137,19 @@ enum_gen! { ThreadSetNameError
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum ThreadSetAffinityError {
     InvalidCpuCores,
+    NotSupported,
     UnknownError(i32),
 }
 
+enum_gen! { ThreadGetSchedulerError
+  entry:
+    ThreadNoLongerActive,
+    UnknownError(i32)
+
+  mapping:
+    SchedulerConversionError
+}
+
 enum_gen! {
     ThreadGetNameError
   entry:
@@ -156,7 +167,8 @@ enum_gen! {
     FailedToSpawn <= ThreadSpawnError,
     NameSetupFailed <= ThreadSetNameError; ThreadGetNameError,
     FailedToSignal <= ThreadSignalError,
-    FailedToSetAffinity <= ThreadSetAffinityError
+    FailedToSetAffinity <= ThreadSetAffinityError,
+    FailedToGetSchedulerSettings <= ThreadGetSchedulerError
 }
 
 /// The builder for a [`Thread`] object.
@@ -201,21 +213,24 @@ impl ThreadBuilder {
         self
     }
 
-    /// Inherit the scheduling attributes of the calling thread.
+    /// Inherit the scheduling attributes of the calling thread. When enabled, the values of
+    /// [`ThreadBuilder::scheduler()`] and [`ThreadBuilder::priority()`] are ignored.
     pub fn inherit_scheduling_attributes(mut self, value: bool) -> Self {
         self.inherit_scheduling_attributes = value;
         self
     }
 
     /// Sets the threads CPU affinity to the provided list of `cpu_core_id`s.
-    /// The cpu cores must exist otherwise [`ThreadBuilder::spawn()`] will
+    /// The cpu cores must be online otherwise [`ThreadBuilder::spawn()`] will
     /// fail with [`ThreadSpawnError::CpuCoreOutsideOfSupportedCpuRangeForAffinity`].
+    /// On platforms without CPU affinity support, like macOS,
+    /// [`ThreadBuilder::spawn()`] fails with [`ThreadSpawnError::CpuAffinityNotSupported`].
     ///
-    /// The systems number of CPU cores can be acquired with:
+    /// The systems number of online CPU cores can be acquired with:
     /// ```
     /// use iceoryx2_bb_posix::system_configuration::*;
     ///
-    /// let number_of_cores = SystemInfo::NumberOfCpuCores.value();
+    /// let number_of_cores = SystemInfo::NumberOfOnlineCpuCores.value();
     /// ```
     pub fn affinity(mut self, cpu_core_ids: &[usize]) -> Self {
         self.affinity = [false; posix::CPU_SETSIZE];
@@ -238,14 +253,26 @@ impl ThreadBuilder {
     /// to the scheduler dependent priority.
     /// For more details about scheduler priority granularity see:
     /// [`Scheduler::priority_granularity()`]
+    ///
+    /// Disables [`ThreadBuilder::inherit_scheduling_attributes()`] so that the priority is
+    /// applied explicitly.
     pub fn priority(mut self, value: u8) -> Self {
         self.priority = value;
+        self.inherit_scheduling_attributes = false;
         self
     }
 
     /// Sets the [`Scheduler`] used by the thread.
+    ///
+    /// Disables [`ThreadBuilder::inherit_scheduling_attributes()`] so that the scheduler is
+    /// applied explicitly. Real-time schedulers like [`Scheduler::Fifo`] or
+    /// [`Scheduler::RoundRobin`] require privileges, on Linux for instance `CAP_SYS_NICE` or a
+    /// sufficient `RLIMIT_RTPRIO`. Without them [`ThreadBuilder::spawn()`] fails with
+    /// [`ThreadSpawnError::InsufficientPermissions`] instead of falling back to
+    /// [`Scheduler::Other`].
     pub fn scheduler(mut self, value: Scheduler) -> Self {
         self.scheduler = value;
+        self.inherit_scheduling_attributes = false;
         self
     }
 
@@ -295,12 +322,19 @@ impl ThreadBuilder {
         }
 
         if self.has_custom_affinity {
-            let number_of_cores = SystemInfo::NumberOfCpuCores.value();
-            for (cpu_core_id, has_affinity) in self.affinity[number_of_cores..].iter().enumerate() {
+            if !posix::support::POSIX_SUPPORT_CPU_AFFINITY {
+                fail!(from self, with ThreadSpawnError::CpuAffinityNotSupported,
+                    "Unable to set the threads cpu affinity since the platform does not support it.");
+            }
+
+            let number_of_cores = SystemInfo::NumberOfOnlineCpuCores.value();
+            for (cpu_core_id, has_affinity) in
+                self.affinity.iter().enumerate().skip(number_of_cores)
+            {
                 if *has_affinity {
                     fail!(from self,
                         with ThreadSpawnError::CpuCoreOutsideOfSupportedCpuRangeForAffinity,
-                        "Unable to set the threads affinity since the system has cores from [0, {}] and the cpu core {} was set.",
+                        "Unable to set the threads affinity since the system has online cores from [0, {}] and the cpu core {} was set.",
                     number_of_cores - 1, cpu_core_id);
                 }
             }
@@ -457,13 +491,18 @@ impl ThreadBuilder {
                 fail!(from self, with ThreadSpawnError::InsufficientResources,
                     "{} due to insufficient resources. Maybe the system limit of threads is reached.", msg);
             }
+            Errno::EINVAL if self.has_custom_affinity => {
+                fail!(from self, with ThreadSpawnError::CpuCoreOutsideOfSupportedCpuRangeForAffinity,
+                    "{} since the cpu affinity contains no online cpu core or invalid settings were provided.", msg);
+            }
             Errno::EINVAL => {
                 fail!(from self, with ThreadSpawnError::InvalidSettings,
                     "{} due to invalid settings for the thread.", msg);
             }
             Errno::EPERM => {
                 fail!(from self, with ThreadSpawnError::InsufficientPermissions,
-                    "{} due to insufficient permissions to set scheduling settings.", msg);
+                    "{} due to insufficient permissions to set the scheduler {:?} with priority {}.",
+                    msg, self.scheduler, self.priority);
             }
             v => {
                 fail!(from self, with ThreadSpawnError::UnknownError(v as i32),
@@ -521,8 +560,16 @@ pub trait ThreadProperties {
     /// thread may run.
     fn get_affinity(&self) -> Result<Vec<usize>, ThreadSetAffinityError>;
 
+    /// Returns the [`Scheduler`] the thread is running with.
+    fn get_scheduler(&self) -> Result<Scheduler, ThreadGetSchedulerError>;
+
+    /// Returns the priority of the thread whereby `0` represents the lowest and `255` the
+    /// highest priority of its [`Scheduler`].
+    fn get_priority(&self) -> Result<u8, ThreadGetSchedulerError>;
+
     /// Sets the threads affinity to the provided set of cpu core ids. If one of
-    /// the cpu core id's does not exist in the system the call will fail.
+    /// the cpu core id's is not online the call will fail. On platforms without
+    /// CPU affinity support, like macOS, it fails with [`ThreadSetAffinityError::NotSupported`].
     fn set_affinity(&mut self, cpu_core_ids: &[usize]) -> Result<(), ThreadSetAffinityError>;
 }
 
@@ -555,6 +602,27 @@ impl ThreadHandle {
             name: UnsafeCell::new(ThreadName::new()),
         }
     }
+
+    fn get_scheduling_parameters(
+        &self,
+    ) -> Result<(Scheduler, posix::sched_param), ThreadGetSchedulerError> {
+        let mut policy: posix::int = 0;
+        let mut param = posix::sched_param::new_zeroed();
+
+        let msg = "Unable to acquire threads scheduling parameters";
+        handle_errno!(ThreadGetSchedulerError, from self,
+            errno_source unsafe { posix::pthread_getschedparam(self.handle, &mut policy, &mut param).into() },
+            continue_on_success,
+            success Errno::ESUCCES => (),
+            Errno::ESRCH => (ThreadNoLongerActive, "{} since the thread is no longer active.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+
+        let scheduler = fail!(from self, when Scheduler::from_int(policy),
+                            "{} since the scheduler seems to be unknown.", msg);
+
+        Ok((scheduler, param))
+    }
 }
 
 impl ThreadProperties for ThreadHandle {
@@ -613,9 +681,23 @@ impl ThreadProperties for ThreadHandle {
         Ok(cpu_affinity_set)
     }
 
+    fn get_scheduler(&self) -> Result<Scheduler, ThreadGetSchedulerError> {
+        Ok(self.get_scheduling_parameters()?.0)
+    }
+
+    fn get_priority(&self) -> Result<u8, ThreadGetSchedulerError> {
+        let (scheduler, param) = self.get_scheduling_parameters()?;
+        Ok(scheduler.get_priority_from(&param))
+    }
+
     fn set_affinity(&mut self, cpu_core_ids: &[usize]) -> Result<(), ThreadSetAffinityError> {
         let msg = "Unable to set cpu affinity to core";
-        let number_of_cores = SystemInfo::NumberOfCpuCores.value();
+        if !posix::support::POSIX_SUPPORT_CPU_AFFINITY {
+            fail!(from self, with ThreadSetAffinityError::NotSupported,
+                "{}s {:?} since the platform does not support it.", msg, cpu_core_ids);
+        }
+
+        let number_of_cores = SystemInfo::NumberOfOnlineCpuCores.value();
 
         let mut cpuset = posix::cpu_set_t::new_zeroed();
         for cpu_core_id in cpu_core_ids {
@@ -625,9 +707,9 @@ impl ThreadProperties for ThreadHandle {
                     msg, cpu_core_id, posix::CPU_SETSIZE);
             }
 
-            if *cpu_core_id > number_of_cores {
+            if *cpu_core_id >= number_of_cores {
                 fail!(from self, with ThreadSetAffinityError::InvalidCpuCores,
-                    "{} {} since the maximum range of CPUs in the system is [0, {}].",
+                    "{} {} since the range of online CPUs in the system is [0, {}].",
                     msg, cpu_core_id, number_of_cores - 1);
             }
 
@@ -638,7 +720,7 @@ impl ThreadProperties for ThreadHandle {
         handle_errno!(ThreadSetAffinityError, from self,
             errno_source unsafe { posix::pthread_setaffinity_np(self.handle, core::mem::size_of::<posix::cpu_set_t>(), &cpuset).into() },
             success Errno::ESUCCES => (),
-            Errno::EINVAL => (InvalidCpuCores, "{} since some cpu cores were invalid (maybe exceeded maximum supported CPU core number of the system).", msg),
+            Errno::EINVAL => (InvalidCpuCores, "{} since the set contains no online cpu core (maybe exceeded maximum supported CPU core number of the system).", msg),
             v=> (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
         );
     }
@@ -718,6 +800,15 @@ impl Thread {
         Self { handle }
     }
 
+    /// Sets the CPU affinity of the calling thread. Can be used for threads that were not
+    /// created with the [`ThreadBuilder`], like the main thread.
+    /// See [`ThreadProperties::set_affinity()`].
+    pub fn set_affinity_of_calling_thread(
+        cpu_core_ids: &[usize],
+    ) -> Result<(), ThreadSetAffinityError> {
+        ThreadHandle::from_self().set_affinity(cpu_core_ids)
+    }
+
     /// Sends a [`Signal`] to the thread.
     pub fn send_signal(&mut self, signal: Signal) -> Result<(), ThreadSignalError> {
         let msg = "Unable to send signal";
@@ -739,6 +830,14 @@ impl ThreadProperties for Thread {
         self.handle.get_affinity()
     }
 
+    fn get_scheduler(&self) -> Result<Scheduler, ThreadGetSchedulerError> {
+        self.handle.get_scheduler()
+    }
+
+    fn get_priority(&self) -> Result<u8, ThreadGetSchedulerError> {
+        self.handle.get_priority()
+    }
+
     fn set_affinity(&mut self, cpu_core_ids: &[usize]) -> Result<(), ThreadSetAffinityError> {
         self.handle.set_affinity(cpu_core_ids)
     }
@@ -20,6 +20,10 @@
 //! [send](UdpServer::send_to()) to and [receive](UdpServer::try_receive_from()) from a
 //! network server.
 //!
+//! The [`UdpSocketBuilder`] creates an unconnected [`UdpSocket`] that can
+//! [send](UdpSocket::try_send_to()) to and [receive](UdpSocket::try_receive_from()) from any
+//! [`SocketAddress`] and supports broadcast and multicast.
+//!
 //! # Example
 //!
 //! ```ignore
@@ -62,7 +66,7 @@ use iceoryx2_bb_system_types::ipv4_address::{self, Ipv4Address};
 use iceoryx2_bb_system_types::port::{self, Port};
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
 use iceoryx2_pal_posix::posix::{self, MemZeroedStruct};
-use iceoryx2_pal_posix::posix::{Errno, SockAddrIn};
+use iceoryx2_pal_posix::posix::{Errno, IpMreq, SockAddrIn};
 
 use crate::file_descriptor::{FileDescriptor, FileDescriptorBased};
 use crate::file_descriptor_set::{
@@ -70,6 +74,8 @@ use crate::file_descriptor_set::{
 };
 use crate::handle_errno;
 
+pub use crate::socket_address::SocketAddress;
+
 /// Describes errors when creating and [`UdpServer`].
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum UdpServerCreateError {
@@ -130,15 +136,31 @@ pub enum UdpSendError {
     NoRouteToHost,
     InsufficientResources,
     InsufficientMemory,
+    InsufficientPermissions,
+    UnknownError(i32),
+}
+
+/// Describes errors when creating an [`UdpSocket`] with [`UdpSocketBuilder::create()`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum UdpSocketCreateError {
+    InsufficientMemory,
+    InsufficientResources,
+    InsufficientPermissions,
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    UdpProtocolNotSupported,
+    InetSocketsNotSupported,
+    AddressAlreadyInUse,
+    AddressNotAvailable,
+    AddressFamilyNotSupported,
+    NotAMulticastAddress,
+    NoSuchNetworkInterface,
+    Interrupt,
     UnknownError(i32),
 }
 
 fn create_sockaddr(address: Ipv4Address, port: Port) -> posix::sockaddr_in {
-    let mut addr = posix::sockaddr_in::new_zeroed();
-    addr.sin_family = posix::AF_INET as _;
-    addr.set_s_addr(address.as_u32().to_be());
-    addr.sin_port = port.as_u16().to_be();
-    addr
+    SocketAddress::new_v4(address, port).to_sockaddr_in()
 }
 
 /// Contains the number of bytes received as well as the origin of the data.
@@ -159,6 +181,11 @@ impl ReceiveDetails {
             source_port: Port::new(u16::from_be(source.sin_port)),
         }
     }
+
+    /// Returns the origin of the data as [`SocketAddress`].
+    pub fn source(&self) -> SocketAddress {
+        SocketAddress::new_v4(self.source_ip, self.source_port)
+    }
 }
 
 /// Builder for the [`UdpClient`]
@@ -230,7 +257,7 @@ impl UdpClientBuilder {
 /// Abstraction of an UDP client that can communicate with one specific network server.
 #[derive(Debug)]
 pub struct UdpClient {
-    socket: UdpSocket,
+    socket: UdpSocketHandle,
 }
 
 impl Drop for UdpClient {
@@ -242,7 +269,7 @@ impl Drop for UdpClient {
 impl UdpClient {
     fn new(socket_fd: FileDescriptor, server: posix::sockaddr_in) -> Self {
         let new_self = Self {
-            socket: UdpSocket::new(socket_fd, server),
+            socket: UdpSocketHandle::new(socket_fd, server),
         };
         trace!(from new_self, "connected");
         new_self
@@ -422,7 +449,7 @@ impl UdpServerBuilder {
 /// Abstraction for an UDP network server.
 #[derive(Debug)]
 pub struct UdpServer {
-    socket: UdpSocket,
+    socket: UdpSocketHandle,
 }
 
 impl Drop for UdpServer {
@@ -434,7 +461,7 @@ impl Drop for UdpServer {
 impl UdpServer {
     fn new(socket_fd: FileDescriptor, server: posix::sockaddr_in) -> Self {
         let new_self = Self {
-            socket: UdpSocket::new(socket_fd, server),
+            socket: UdpSocketHandle::new(socket_fd, server),
         };
         trace!(from new_self, "listen");
         new_self
@@ -519,17 +546,359 @@ impl UdpServer {
     }
 }
 
-struct UdpSocket {
+/// Builder for the [`UdpSocket`].
+#[derive(Debug)]
+pub struct UdpSocketBuilder {
+    address: SocketAddress,
+    is_broadcast_enabled: bool,
+    reuse_address: bool,
+    multicast_groups: Vec<(Ipv4Address, Ipv4Address)>,
+    multicast_interface: Option<Ipv4Address>,
+}
+
+impl Default for UdpSocketBuilder {
+    fn default() -> Self {
+        Self {
+            address: SocketAddress::new_v4(ipv4_address::UNSPECIFIED, port::UNSPECIFIED),
+            is_broadcast_enabled: false,
+            reuse_address: false,
+            multicast_groups: vec![],
+            multicast_interface: None,
+        }
+    }
+}
+
+impl UdpSocketBuilder {
+    /// Creates a new [`UdpSocketBuilder`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Can be set optionally. Defines the [`SocketAddress`] the [`UdpSocket`] is bound to. If no
+    /// address is set the socket is bound to all available addresses and the operating system
+    /// chooses a free port.
+    pub fn address(mut self, address: SocketAddress) -> Self {
+        self.address = address;
+        self
+    }
+
+    /// Defines if the [`UdpSocket`] is allowed to send messages to a broadcast address.
+    /// Default is false.
+    pub fn broadcast(mut self, value: bool) -> Self {
+        self.is_broadcast_enabled = value;
+        self
+    }
+
+    /// Defines if the [`UdpSocket`] can be bound to an address that is already in use. Required
+    /// when multiple sockets shall receive from the same multicast group and port.
+    /// Default is false.
+    pub fn reuse_address(mut self, value: bool) -> Self {
+        self.reuse_address = value;
+        self
+    }
+
+    /// Joins the multicast group on the network interface with the given address. Can be called
+    /// multiple times to join multiple groups.
+    pub fn join_multicast_group(mut self, group: Ipv4Address, interface: Ipv4Address) -> Self {
+        self.multicast_groups.push((group, interface));
+        self
+    }
+
+    /// Defines the network interface, identified by its address, that is used for outgoing
+    /// multicast messages. If it is not set the operating system chooses the interface.
+    pub fn multicast_interface(mut self, interface: Ipv4Address) -> Self {
+        self.multicast_interface = Some(interface);
+        self
+    }
+
+    fn set_socket_option<T>(
+        &self,
+        socket_fd: &FileDescriptor,
+        level: posix::int,
+        option: posix::int,
+        value: &T,
+        msg: &str,
+    ) -> Result<(), UdpSocketCreateError> {
+        if unsafe {
+            posix::setsockopt(
+                socket_fd.native_handle(),
+                level,
+                option,
+                (value as *const T) as *const posix::void,
+                core::mem::size_of::<T>() as u32,
+            )
+        } == 0
+        {
+            return Ok(());
+        }
+
+        handle_errno!(UdpSocketCreateError, from self,
+            Errno::EADDRNOTAVAIL => (AddressNotAvailable, "{} since the address is not available.", msg),
+            Errno::EADDRINUSE => (AddressAlreadyInUse, "{} since the address is already in use.", msg),
+            Errno::ENODEV => (NoSuchNetworkInterface, "{} since the network interface does not exist.", msg),
+            Errno::ENOBUFS => (InsufficientResources, "{} due to insufficient resources.", msg),
+            Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
+    /// Creates the [`UdpSocket`] and binds it to the specified address.
+    pub fn create(self) -> Result<UdpSocket, UdpSocketCreateError> {
+        let raw_fd = unsafe {
+            posix::socket(
+                posix::PF_INET as posix::int,
+                posix::SOCK_DGRAM,
+                posix::IPPROTO_UDP,
+            )
+        };
+
+        let msg = "Unable to create UdpSocket";
+        if raw_fd < 0 {
+            handle_errno!(UdpSocketCreateError, from self,
+                Errno::EAFNOSUPPORT => (AddressFamilyNotSupported, "{} since the address family is not supported by the system.", msg),
+                Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+                Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process limit of file descriptors was reached.", msg),
+                Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since system-wide limit of file descriptors was reached.", msg),
+                Errno::ENOBUFS => (InsufficientResources, "{} due to insufficient resources.", msg),
+                Errno::EPROTOTYPE => (InetSocketsNotSupported, "{} since PF_INET socket type is not supported.", msg),
+                Errno::EPROTONOSUPPORT => (UdpProtocolNotSupported, "{} since the udp protocol is not supported by the system.", msg),
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+        let socket_fd = unsafe { FileDescriptor::new_unchecked(raw_fd) };
+
+        if self.reuse_address {
+            let value: posix::int = 1;
+            self.set_socket_option(
+                &socket_fd,
+                posix::SOL_SOCKET,
+                posix::SO_REUSEADDR,
+                &value,
+                "Unable to enable address reuse on UdpSocket",
+            )?;
+        }
+
+        if self.is_broadcast_enabled {
+            let value: posix::int = 1;
+            self.set_socket_option(
+                &socket_fd,
+                posix::SOL_SOCKET,
+                posix::SO_BROADCAST,
+                &value,
+                "Unable to enable broadcast on UdpSocket",
+            )?;
+        }
+
+        let socket_address = match self.address {
+            SocketAddress::V4 { address, port } => create_sockaddr(address, port),
+        };
+
+        let msg = format!("Unable to bind UdpSocket to {}", self.address);
+        if unsafe {
+            posix::bind(
+                socket_fd.native_handle(),
+                (&socket_address as *const posix::sockaddr_in) as *const posix::sockaddr,
+                core::mem::size_of::<posix::sockaddr_in>() as u32,
+            ) == -1
+        } {
+            handle_errno!(UdpSocketCreateError, from self,
+                Errno::EAFNOSUPPORT => (AddressFamilyNotSupported, "{} since the address family is not supported by the system.", msg),
+                Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+                Errno::EADDRINUSE => (AddressAlreadyInUse, "{} since the address is already in use.", msg),
+                Errno::EADDRNOTAVAIL => (AddressNotAvailable, "{} since the address is not available.", msg),
+                Errno::ENOBUFS => (InsufficientResources, "{} due to insufficient resources.", msg),
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+
+        for (group, interface) in &self.multicast_groups {
+            let msg = format!("Unable to join multicast group {group} on interface {interface}");
+            if !group.is_multicast() {
+                fail!(from self, with UdpSocketCreateError::NotAMulticastAddress,
+                    "{} since {} is not a multicast address.", msg, group);
+            }
+
+            let mut membership = posix::ip_mreq::new_zeroed();
+            membership.set_multiaddr(group.as_u32().to_be());
+            membership.set_interface(interface.as_u32().to_be());
+            self.set_socket_option(
+                &socket_fd,
+                posix::IPPROTO_IP,
+                posix::IP_ADD_MEMBERSHIP,
+                &membership,
+                &msg,
+            )?;
+        }
+
+        if let Some(interface) = self.multicast_interface {
+            let value = interface.as_u32().to_be();
+            self.set_socket_option(
+                &socket_fd,
+                posix::IPPROTO_IP,
+                posix::IP_MULTICAST_IF,
+                &value,
+                &format!("Unable to use interface {interface} for outgoing multicast messages"),
+            )?;
+        }
+
+        let mut details = posix::sockaddr_in::new_zeroed();
+        let mut details_len = core::mem::size_of::<posix::sockaddr_in>() as posix::socklen_t;
+
+        let msg = "Unable to read newly created UdpSocket details";
+        if unsafe {
+            posix::getsockname(
+                socket_fd.native_handle(),
+                (&mut details as *mut posix::sockaddr_in) as *mut posix::sockaddr,
+                &mut details_len,
+            )
+        } == -1
+        {
+            handle_errno!(UdpSocketCreateError, from self,
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+
+        Ok(UdpSocket::new(socket_fd, details))
+    }
+}
+
+/// Abstraction of an UDP socket that is not bound to a specific peer. It can send to and
+/// receive from any [`SocketAddress`], including broadcast and multicast addresses, and can be
+/// attached to a [`FileDescriptorSet`].
+#[derive(Debug)]
+pub struct UdpSocket {
+    socket: UdpSocketHandle,
+}
+
+impl Drop for UdpSocket {
+    fn drop(&mut self) {
+        trace!(from self, "close");
+    }
+}
+
+impl FileDescriptorBased for UdpSocket {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.socket.file_descriptor()
+    }
+}
+
+impl SynchronousMultiplexing for UdpSocket {}
+
+impl UdpSocket {
+    fn new(socket_fd: FileDescriptor, details: posix::sockaddr_in) -> Self {
+        let new_self = Self {
+            socket: UdpSocketHandle::new(socket_fd, details),
+        };
+        trace!(from new_self, "open");
+        new_self
+    }
+
+    /// Returns the [`SocketAddress`] the [`UdpSocket`] is bound to.
+    pub fn local_address(&self) -> SocketAddress {
+        SocketAddress::from_sockaddr_in(&self.socket.details)
+    }
+
+    fn send_to(&self, data: &[u8], destination: SocketAddress) -> Result<usize, UdpSendError> {
+        match destination {
+            SocketAddress::V4 { address, port } => self.socket.send_to(data, address, port),
+        }
+    }
+
+    /// Tries to send a message to the given destination. If the message could not be sent
+    /// without blocking the method returns 0 otherwise the number of bytes sent.
+    pub fn try_send_to(
+        &self,
+        data: &[u8],
+        destination: SocketAddress,
+    ) -> Result<usize, UdpSendError> {
+        fail!(from self, when self.socket.set_non_blocking_for_send(true),
+            "Unable to try send to {} since the socket could not activate the non-blocking mode.", destination);
+
+        self.send_to(data, destination)
+    }
+
+    /// Blocks until the message was sent to the given destination. Returns the number of bytes
+    /// sent.
+    pub fn blocking_send_to(
+        &self,
+        data: &[u8],
+        destination: SocketAddress,
+    ) -> Result<usize, UdpSendError> {
+        fail!(from self, when self.socket.set_non_blocking_for_send(false),
+            "Unable to blocking send to {} since the socket could not activate the blocking mode.", destination);
+
+        self.send_to(data, destination)
+    }
+
+    /// Tries to receive a message. If no message was received the method returns [`None`]
+    /// otherwise [`ReceiveDetails`] that contain the number of bytes received as well as the
+    /// origin of the data.
+    pub fn try_receive_from(
+        &self,
+        buffer: &mut [u8],
+    ) -> Result<Option<ReceiveDetails>, UdpReceiveError> {
+        fail!(from self, when self.socket.set_non_blocking(true),
+            "Unable to try receive from socket since the socket could not activate the non-blocking mode.");
+
+        self.socket.receive_from(buffer)
+    }
+
+    /// Blocks until either a message was received or the timeout has passed. If no message was
+    /// received the method returns [`None`] otherwise [`ReceiveDetails`] that contain the number
+    /// of bytes received as well as the origin of the data.
+    pub fn timed_receive_from(
+        &self,
+        buffer: &mut [u8],
+        timeout: Duration,
+    ) -> Result<Option<ReceiveDetails>, UdpReceiveError> {
+        let msg = "Failed to timed receive from";
+        fail!(from self, when self.socket.set_non_blocking(false),
+            "{} since the socket could not activate the blocking mode.", msg);
+
+        let fd_set = FileDescriptorSet::new();
+        let _guard = fatal_panic!(from self, when fd_set.add(&self.socket),
+                            "This should never happen! {} since the socket could not be attached to a fd set.", msg);
+
+        let mut received_bytes = Ok(None);
+        match fd_set.timed_wait(timeout, FileEvent::Read, |_| {
+            received_bytes = self.socket.receive_from(buffer)
+        }) {
+            Err(FileDescriptorSetWaitError::Interrupt) => {
+                fail!(from self, with UdpReceiveError::Interrupt,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            Err(_) => {
+                fail!(from self, with UdpReceiveError::UnknownError(-1),
+                    "{} since an unknown failure occurred.", msg);
+            }
+            Ok(_) => received_bytes,
+        }
+    }
+
+    /// Blocks until a message was received. Returns [`ReceiveDetails`] that contain the number of
+    /// bytes received as well as the origin of the data.
+    pub fn blocking_receive_from(
+        &self,
+        buffer: &mut [u8],
+    ) -> Result<Option<ReceiveDetails>, UdpReceiveError> {
+        fail!(from self, when self.socket.set_non_blocking(false),
+            "Unable to blocking receive from socket since the socket could not activate the blocking mode.");
+
+        self.socket.receive_from(buffer)
+    }
+}
+
+struct UdpSocketHandle {
     socket_fd: FileDescriptor,
     details: posix::sockaddr_in,
     is_non_blocking: IoxAtomicBool,
 }
 
-impl Debug for UdpSocket {
+impl Debug for UdpSocketHandle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
-            "UdpSocket {{ socket_fd: {:?}, details: posix::sockaddr_in {{ sin_addr: {}, sin_family: {}, sin_port: {} }}, is_non_blocking: {:?} }}",
+            "UdpSocketHandle {{ socket_fd: {:?}, details: posix::sockaddr_in {{ sin_addr: {}, sin_family: {}, sin_port: {} }}, is_non_blocking: {:?} }}",
             self.socket_fd,
             self.details.get_s_addr(),
             self.details.sin_family,
@@ -539,15 +908,15 @@ impl Debug for UdpSocket {
     }
 }
 
-impl FileDescriptorBased for UdpSocket {
+impl FileDescriptorBased for UdpSocketHandle {
     fn file_descriptor(&self) -> &FileDescriptor {
         &self.socket_fd
     }
 }
 
-impl SynchronousMultiplexing for UdpSocket {}
+impl SynchronousMultiplexing for UdpSocketHandle {}
 
-impl UdpSocket {
+impl UdpSocketHandle {
     fn new(socket_fd: FileDescriptor, details: posix::sockaddr_in) -> Self {
         Self {
             socket_fd,
@@ -599,6 +968,18 @@ impl UdpSocket {
         Ok(())
     }
 
+    fn set_non_blocking_for_send(&self, value: bool) -> Result<(), UdpSendError> {
+        self.set_non_blocking(value).map_err(|e| match e {
+            UdpReceiveError::ConnectionReset => UdpSendError::ConnectionReset,
+            UdpReceiveError::Interrupt => UdpSendError::Interrupt,
+            UdpReceiveError::NotConnected => UdpSendError::UnknownError(Errno::ENOTCONN as i32),
+            UdpReceiveError::IOerror => UdpSendError::IOerror,
+            UdpReceiveError::InsufficientResources => UdpSendError::InsufficientResources,
+            UdpReceiveError::InsufficientMemory => UdpSendError::InsufficientMemory,
+            UdpReceiveError::UnknownError(v) => UdpSendError::UnknownError(v),
+        })
+    }
+
     fn receive_from(&self, buffer: &mut [u8]) -> Result<Option<ReceiveDetails>, UdpReceiveError> {
         let mut client = posix::sockaddr_in::new_zeroed();
         let mut client_len = core::mem::size_of::<posix::sockaddr_in>() as u32;
@@ -681,6 +1062,8 @@ impl UdpSocket {
 
         let msg = format!("Unable to send message to {address}:{port}");
         handle_errno!(UdpSendError, from self,
+            success Errno::EAGAIN => 0,
+            Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions, broadcast messages require an UdpSocket with broadcast enabled.", msg),
             Errno::ECONNRESET => (ConnectionReset, "{} since the connection was reset.", msg),
             Errno::EINTR => (Interrupt, "{} due to an interrupt signal.", msg),
             Errno::EMSGSIZE => (MessageTooLarge, "{} since the message is too large to be sent.", msg),
@@ -37,10 +37,12 @@
 //! }
 //! ```
 
+use iceoryx2_bb_container::semantic_string::SemanticStringError;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::fail;
+use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU32;
 use iceoryx2_pal_posix::posix;
 use serde::{Deserialize, Serialize};
@@ -61,10 +63,55 @@ enum_gen! { UniqueSystemIdCreationError
     FailedToAcquireTime
 }
 
+enum_gen! {
+    /// Failures that can occur in [`UniqueFileNameExt::with_unique_suffix()`].
+    UniqueFileNameCreationError
+  mapping:
+    UniqueSystemIdCreationError,
+    SemanticStringError
+}
+
+/// Trait to derive a system wide unique [`FileName`] from an existing one.
+pub trait UniqueFileNameExt {
+    /// Returns a new [`FileName`] that consists of the current name followed by `_` and the
+    /// value of a newly created [`UniqueSystemId`].
+    fn with_unique_suffix(&self) -> Result<FileName, UniqueFileNameCreationError>;
+}
+
+impl UniqueFileNameExt for FileName {
+    fn with_unique_suffix(&self) -> Result<FileName, UniqueFileNameCreationError> {
+        let msg = "Unable to add unique suffix to file name";
+        let id = fail!(from self, when UniqueSystemId::new(),
+                        "{} since the UniqueSystemId could not be created.", msg);
+
+        // `_` followed by at most 39 decimal digits of the u128 value
+        let mut suffix = [0u8; 40];
+        let mut value = id.value();
+        let mut start = suffix.len();
+        loop {
+            start -= 1;
+            suffix[start] = b'0' + (value % 10) as u8;
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        }
+        start -= 1;
+        suffix[start] = b'_';
+
+        Ok(fail!(from self, when self.with_suffix(&suffix[start..]),
+                "{} since the result would exceed the maximum supported file name length.", msg))
+    }
+}
+
 /// Creates a system wide unique id. There does not exist another process which has generated the
 /// same id. There will never be another process on the same system with the same id.
-/// The [`UniqueSystemId`] is generated by the processes current process id and the current system
-/// time using the [`ClockType::Monotonic`].
+/// The [`UniqueSystemId`] is generated by the processes current process id, the current system
+/// time using the [`ClockType::Monotonic`], a process wide counter and a salt. The monotonic
+/// clock restarts with every boot, therefore the salt is derived once per process from the
+/// [`ClockType::Realtime`] so that ids created in different boots do not collide.
+/// The nanoseconds require only 30 bits, the salt is stored in the remaining upper bits so
+/// that the full 32 bits are available for the counter.
 #[derive(
     Eq, Hash, PartialEq, Clone, Copy, PartialOrd, Ord, Serialize, Deserialize, ZeroCopySend,
 )]
@@ -72,10 +119,14 @@ enum_gen! { UniqueSystemIdCreationError
 pub struct UniqueSystemId {
     pid: u32,
     seconds: u32,
-    nanoseconds: u32,
+    salt_and_nanoseconds: u32,
     counter: u32,
 }
 
+const NANOSECONDS_BITS: u32 = 30;
+const NANOSECONDS_MASK: u32 = (1 << NANOSECONDS_BITS) - 1;
+const SALT_MASK: u32 = !NANOSECONDS_MASK;
+
 impl Debug for UniqueSystemId {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
@@ -109,20 +160,52 @@ impl UniqueSystemId {
                         when Time::now_with_clock(ClockType::default()),
                         with UniqueSystemIdCreationError::FailedToAcquireTime,
                         "{} since the current time could not be acquired.", msg);
+        let salt = fail!(from "UniqueSystemId::new()", when Self::salt(),
+                        "{} since the salt could not be acquired.", msg);
 
-        Ok(Self::create(pid, now))
+        Ok(Self::create(pid, now, salt))
     }
 
-    fn create(pid: u32, now: Time) -> UniqueSystemId {
+    fn create(pid: u32, now: Time, salt: u32) -> UniqueSystemId {
         static COUNTER: IoxAtomicU32 = IoxAtomicU32::new(0);
         UniqueSystemId {
             pid,
             seconds: now.seconds() as u32,
-            nanoseconds: now.nanoseconds(),
+            salt_and_nanoseconds: (salt & SALT_MASK) | (now.nanoseconds() & NANOSECONDS_MASK),
             counter: COUNTER.fetch_add(1, Ordering::Relaxed),
         }
     }
 
+    /// Returns the salt of the current process, it occupies only the bits of [`SALT_MASK`].
+    /// It is derived from the [`ClockType::Realtime`] when the first [`UniqueSystemId`] is
+    /// created and stays the same for the lifetime of the process.
+    fn salt() -> Result<u32, UniqueSystemIdCreationError> {
+        // the lowest bit marks the salt as initialized, the bits of SALT_MASK contain the salt
+        const IS_INITIALIZED: u32 = 1;
+        static SALT: IoxAtomicU32 = IoxAtomicU32::new(0);
+
+        let salt = SALT.load(Ordering::Relaxed);
+        if salt & IS_INITIALIZED != 0 {
+            return Ok(salt & SALT_MASK);
+        }
+
+        let now = fail!(from "UniqueSystemId::salt()",
+                        when Time::now_with_clock(ClockType::Realtime),
+                        with UniqueSystemIdCreationError::FailedToAcquireTime,
+                        "Unable to derive salt since the current realtime could not be acquired.");
+        let new_salt = (now.nanoseconds() ^ now.seconds() as u32).rotate_right(2) & SALT_MASK;
+
+        match SALT.compare_exchange(
+            0,
+            IS_INITIALIZED | new_salt,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => Ok(new_salt),
+            Err(salt) => Ok(salt & SALT_MASK),
+        }
+    }
+
     /// Returns the underlying value of the new system wide unique id
     pub fn value(&self) -> u128 {
         unsafe { core::mem::transmute(*self) }
@@ -138,7 +221,7 @@ impl UniqueSystemId {
         Time {
             clock_type: ClockType::default(),
             seconds: self.seconds as u64,
-            nanoseconds: self.nanoseconds,
+            nanoseconds: self.salt_and_nanoseconds & NANOSECONDS_MASK,
         }
     }
 }
@@ -152,10 +235,12 @@ fn test_unique_system_id_when_creating_simultaneously() {
     let pid = Process::from_self().id().value() as _;
     let now = Time::now_with_clock(ClockType::default()).unwrap();
 
+    let salt = UniqueSystemId::salt().unwrap();
+
     let handle1 =
-        std::thread::spawn(move || -> UniqueSystemId { UniqueSystemId::create(pid, now) });
+        std::thread::spawn(move || -> UniqueSystemId { UniqueSystemId::create(pid, now, salt) });
     let handle2 =
-        std::thread::spawn(move || -> UniqueSystemId { UniqueSystemId::create(pid, now) });
+        std::thread::spawn(move || -> UniqueSystemId { UniqueSystemId::create(pid, now, salt) });
 
     let id1 = handle1.join().unwrap();
     let id2 = handle2.join().unwrap();
@@ -170,14 +255,16 @@ fn test_unique_system_id_across_processes() {
     let pid = Process::from_self().id().value() as _;
     let now = Time::now_with_clock(ClockType::default()).unwrap();
 
-    let id1 = UniqueSystemId::create(pid, now);
+    let salt = UniqueSystemId::salt().unwrap();
+
+    let id1 = UniqueSystemId::create(pid, now, salt);
     // ideally, fork and exec the current process to reset the static counter inside UniqueSystemId::create
     // is better, but to ease the test now, we can duplicate the logic inside the lambda instead.
     static COUNTER: IoxAtomicU32 = IoxAtomicU32::new(0);
     let id2 = UniqueSystemId {
         pid: pid + 1,
         seconds: now.seconds() as u32,
-        nanoseconds: now.nanoseconds(),
+        salt_and_nanoseconds: salt | now.nanoseconds(),
         counter: COUNTER.fetch_add(1, Ordering::Relaxed),
     };
 
@@ -185,3 +272,36 @@ fn test_unique_system_id_across_processes() {
     assert_that!(id1.creation_time(), eq id2.creation_time());
     assert_that!(id1.value(), ne id2.value());
 }
+
+#[test]
+// ensures that ids created in different boots, with the same pid and monotonic time, differ.
+fn test_unique_system_id_with_different_salt_is_unique() {
+    let pid = Process::from_self().id().value() as _;
+    let now = Time::now_with_clock(ClockType::default()).unwrap();
+    let salt = UniqueSystemId::salt().unwrap();
+
+    let id1 = UniqueSystemId::create(pid, now, salt);
+    let mut id2 = id1;
+    id2.salt_and_nanoseconds ^= SALT_MASK;
+
+    assert_that!(UniqueSystemId::salt().unwrap(), eq salt);
+    assert_that!(id1.pid(), eq id2.pid());
+    assert_that!(id1.creation_time(), eq id2.creation_time());
+    assert_that!(id1.value(), ne id2.value());
+}
+
+#[test]
+// ensures the unique_system_id is unique when the clock does not advance while more ids are
+// created than a 16-bit counter can represent.
+fn test_unique_system_id_with_same_time_does_not_repeat() {
+    let pid = Process::from_self().id().value() as _;
+    let now = Time::now_with_clock(ClockType::default()).unwrap();
+    let salt = UniqueSystemId::salt().unwrap();
+
+    let mut ids = std::collections::HashSet::new();
+    for _ in 0..=u16::MAX as usize + 1 {
+        let id = UniqueSystemId::create(pid, now, salt);
+        assert_that!(id.creation_time(), eq now);
+        assert_that!(ids.insert(id.value()), eq true);
+    }
+}
@@ -42,6 +42,39 @@
 //! receiver.try_receive(recv_data.as_mut_slice()).unwrap();
 //! ```
 //!
+//! ## Transfer data together with [`FileDescriptor`]s
+//!
+//! ```no_run
+//! use iceoryx2_bb_posix::unix_datagram_socket::*;
+//! use iceoryx2_bb_posix::file::*;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//!
+//! let socket_name = FilePath::new(b"mySocket").unwrap();
+//! let file_name = FilePath::new(b"udsExampleFile").unwrap();
+//! let receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+//!                         .creation_mode(CreationMode::PurgeAndCreate)
+//!                         .create().unwrap();
+//!
+//! let sender = UnixDatagramSenderBuilder::new(&socket_name)
+//!                         .create().unwrap();
+//!
+//! let file = FileBuilder::new(&file_name)
+//!                     .creation_mode(CreationMode::PurgeAndCreate)
+//!                     .create().unwrap();
+//!
+//! // the receiver acquires a duplicate of the file descriptor
+//! sender.try_send_with_fds(b"here is my file", &[&file]).unwrap();
+//!
+//! let mut buffer = [0u8; 32];
+//! let mut fds = vec![];
+//! let bytes_received = receiver.try_receive_with_fds(&mut buffer, &mut fds).unwrap();
+//! let recv_file = File::from_file_descriptor(fds.remove(0));
+//!
+//! // cleanup
+//! File::remove(&file_name);
+//! ```
+//!
 //! ## Transfer [`SocketCred`]s
 //!
 //! ```ignore
@@ -53,6 +86,7 @@
 //! let socket_name = FilePath::new(b"myFunSocket").unwrap();
 //! let receiver = UnixDatagramReceiverBuilder::new(&socket_name)
 //!                         .creation_mode(CreationMode::PurgeAndCreate)
+//!                         .with_credentials()
 //!                         .create().unwrap();
 //!
 //! let sender = UnixDatagramSenderBuilder::new(&socket_name)
@@ -127,7 +161,7 @@ use core::{mem::size_of, time::Duration};
 use iceoryx2_bb_container::semantic_string::*;
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_elementary::scope_guard::ScopeGuardBuilder;
-use iceoryx2_bb_log::{fail, fatal_panic, trace};
+use iceoryx2_bb_log::{fail, fatal_panic, trace, warn};
 use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
 use iceoryx2_pal_posix::posix::{errno::Errno, MemZeroedStruct};
@@ -161,6 +195,7 @@ enum_gen! {
     ConnectionReset,
     WouldBlock,
     DoesNotExist,
+    AbstractNamespaceUnsupported,
     UnknownError(i32)
   mapping:
     UnixDatagramCreationError
@@ -175,6 +210,8 @@ enum_gen! {
     AddressAlreadyInUse,
     PathDoesNotExist,
     ReadOnlyFileSytem,
+    CredentialsUnsupported,
+    AbstractNamespaceUnsupported,
     UnknownError(i32)
   mapping:
     UnixDatagramSetSocketOptionError,
@@ -214,6 +251,7 @@ enum_gen! {
     InsufficientMemory,
     NotConnected,
     MaximumSupportedMessagesExceeded,
+    MaximumSupportedFileDescriptorsExceeded,
     MessagePartiallySend(u64),
     UnknownError(i32)
   mapping:
@@ -248,6 +286,8 @@ enum_gen! {
     InsufficientMemory,
     ReceivedUnexpectedMessage,
     ReceivedInvalidFileDescriptor,
+    ControlMessageTruncated,
+    CredentialsNotEnabled,
     UnknownError(i32)
   mapping:
     UnixDatagramSetPropertyError,
@@ -291,9 +331,18 @@ enum_gen! {
 
 const BLOCKING_TIMEOUT: Duration = Duration::from_secs(i16::MAX as _);
 
+/// Acquires a control message buffer with at least `len` bytes that is correctly aligned
+/// for [`posix::cmsghdr`].
+fn control_message_buffer(len: usize) -> Vec<posix::cmsghdr> {
+    (0..len.div_ceil(size_of::<posix::cmsghdr>()))
+        .map(|_| posix::cmsghdr::new_zeroed())
+        .collect()
+}
+
 #[derive(Debug)]
 struct UnixDatagramSocket {
     name: FilePath,
+    is_abstract: bool,
     is_non_blocking: IoxAtomicBool,
     file_descriptor: FileDescriptor,
 }
@@ -396,10 +445,21 @@ impl UnixDatagramSocket {
         );
     }
 
-    fn create_socket_address(&self) -> posix::sockaddr_un {
+    fn create_socket_address(&self) -> (posix::sockaddr_un, posix::socklen_t) {
         let mut socket_address = posix::sockaddr_un::new_zeroed();
         socket_address.sun_family = posix::AF_UNIX;
 
+        if self.is_abstract {
+            // abstract socket names start with a null byte and are not null terminated, the
+            // address length defines where the name ends
+            for (i, byte) in self.name.as_bytes().iter().enumerate() {
+                socket_address.sun_path[i + 1] = *byte as _;
+            }
+
+            let len = core::mem::offset_of!(posix::sockaddr_un, sun_path) + 1 + self.name.len();
+            return (socket_address, len as posix::socklen_t);
+        }
+
         unsafe {
             posix::strncpy(
                 socket_address.sun_path.as_mut_ptr(),
@@ -408,11 +468,14 @@ impl UnixDatagramSocket {
             );
         }
 
-        socket_address
+        (
+            socket_address,
+            size_of::<posix::sockaddr_un>() as posix::socklen_t,
+        )
     }
 
     fn bind(&self, permission: Permission) -> Result<(), UnixDatagramReceiverCreationError> {
-        let socket_address = self.create_socket_address();
+        let (socket_address, socket_address_len) = self.create_socket_address();
         let ptr: *const posix::sockaddr_un = &socket_address;
 
         {
@@ -430,7 +493,7 @@ impl UnixDatagramSocket {
                 posix::bind(
                     self.file_descriptor.native_handle(),
                     ptr as *const posix::sockaddr,
-                    size_of::<posix::sockaddr_un>() as u32,
+                    socket_address_len,
                 )
             } == 0
             {
@@ -451,13 +514,13 @@ impl UnixDatagramSocket {
     }
 
     fn connect(&self) -> Result<(), UnixDatagramSenderCreationError> {
-        let socket_address = self.create_socket_address();
+        let (socket_address, socket_address_len) = self.create_socket_address();
         let ptr: *const posix::sockaddr_un = &socket_address;
         if unsafe {
             posix::connect(
                 self.file_descriptor.native_handle(),
                 ptr as *const posix::sockaddr,
-                size_of::<posix::sockaddr_un>() as u32,
+                socket_address_len,
             )
         } == 0
         {
@@ -478,8 +541,9 @@ impl UnixDatagramSocket {
         );
     }
 
-    fn new(name: &FilePath) -> Result<Self, UnixDatagramCreationError> {
-        if name.len() > UNIX_DOMAIN_SOCKET_PATH_LENGTH {
+    fn new(name: &FilePath, is_abstract: bool) -> Result<Self, UnixDatagramCreationError> {
+        // the name of an abstract socket is prefixed with a null byte
+        if name.len() + is_abstract as usize > UNIX_DOMAIN_SOCKET_PATH_LENGTH {
             fail!(with UnixDatagramCreationError::SocketNameTooLong,
                 "The name \"{}\" is too long for a UnixDatagramSocket name. Maximum supported length is {}.", name, UNIX_DOMAIN_SOCKET_PATH_LENGTH);
         }
@@ -502,6 +566,7 @@ impl UnixDatagramSocket {
 
         Ok(Self {
             name: name.clone(),
+            is_abstract,
             is_non_blocking: IoxAtomicBool::new(false),
             file_descriptor: FileDescriptor::new(raw_fd).unwrap(),
         })
@@ -513,11 +578,24 @@ impl UnixDatagramSocket {
 #[derive(Debug)]
 pub struct UnixDatagramSenderBuilder {
     name: FilePath,
+    is_abstract: bool,
 }
 
 impl UnixDatagramSenderBuilder {
     pub fn new(name: &FilePath) -> Self {
-        Self { name: name.clone() }
+        Self {
+            name: name.clone(),
+            is_abstract: false,
+        }
+    }
+
+    /// Connects to a [`UnixDatagramReceiver`] in the abstract namespace, see
+    /// [`UnixDatagramReceiverBuilder::abstract_namespace()`].
+    /// If the platform does not support the abstract namespace the creation fails with
+    /// [`UnixDatagramSenderCreationError::AbstractNamespaceUnsupported`].
+    pub fn abstract_namespace(mut self, value: bool) -> Self {
+        self.is_abstract = value;
+        self
     }
 
     /// Creates a new [`UnixDatagramSender`].
@@ -543,11 +621,21 @@ impl Drop for UnixDatagramSender {
 impl UnixDatagramSender {
     fn new(config: UnixDatagramSenderBuilder) -> Result<Self, UnixDatagramSenderCreationError> {
         let msg = "Failed to created UnixDatagramSender";
+        if config.is_abstract && !posix::POSIX_SUPPORT_ABSTRACT_UNIX_DOMAIN_SOCKETS {
+            fail!(from config, with UnixDatagramSenderCreationError::AbstractNamespaceUnsupported,
+                "{} since the platform does not support abstract unix domain sockets.", msg);
+        }
+
         let new_socket = UnixDatagramSender {
-            socket: fail!(from config, when UnixDatagramSocket::new(&config.name), "{}.", msg),
+            socket: fail!(from config, when UnixDatagramSocket::new(&config.name, config.is_abstract), "{}.", msg),
         };
 
         match new_socket.socket.connect() {
+            Err(UnixDatagramSenderCreationError::ConnectionRefused) if config.is_abstract => {
+                // connecting to a non-existing abstract socket is refused by the kernel
+                fail!(from config, with UnixDatagramSenderCreationError::DoesNotExist,
+                    "{} since the connection could not be established.", msg);
+            }
             Err(UnixDatagramSenderCreationError::DoesNotExist) => {
                 fail!(from config, with UnixDatagramSenderCreationError::DoesNotExist,
                     "{} since the connection could not be established.", msg);
@@ -677,6 +765,92 @@ impl UnixDatagramSender {
         Ok(())
     }
 
+    fn send_with_fds(
+        &self,
+        data: &[u8],
+        fds: &[&dyn FileDescriptorBased],
+    ) -> Result<bool, UnixDatagramSendMsgError> {
+        let msg = "Unable to send data with file descriptors";
+        if fds.len() > MAX_FILE_DESCRIPTORS_PER_MESSAGE {
+            fail!(from self, with UnixDatagramSendMsgError::MaximumSupportedFileDescriptorsExceeded,
+                "{} since {} file descriptors were provided but at most {} are supported per message.",
+                msg, fds.len(), MAX_FILE_DESCRIPTORS_PER_MESSAGE);
+        }
+
+        let mut iovec = posix::iovec::new_zeroed();
+        iovec.set_base(data.as_ptr() as *mut posix::void);
+        iovec.set_len(data.len());
+
+        let fds_len = SIZE_OF_FD * fds.len();
+        let control_len = unsafe { posix::CMSG_SPACE(fds_len as _) } as usize;
+        let mut control_buffer = control_message_buffer(control_len);
+
+        let mut message = posix::msghdr::new_zeroed();
+        message.msg_iov = iovec.as_mut_ptr();
+        message.msg_iovlen = 1;
+
+        if !fds.is_empty() {
+            message.msg_control = control_buffer.as_mut_ptr() as *mut posix::void;
+            message.msg_controllen = control_len as _;
+
+            let header = unsafe { posix::CMSG_FIRSTHDR(&message) };
+            unsafe {
+                (*header).cmsg_level = CMSG_SOCKET_LEVEL;
+                (*header).cmsg_type = posix::SCM_RIGHTS;
+                (*header).cmsg_len = posix::CMSG_LEN(fds_len as _) as _;
+            }
+
+            let fd_data = unsafe { posix::CMSG_DATA(header) } as *mut posix::int;
+            for (n, fd) in fds.iter().enumerate() {
+                unsafe {
+                    fd_data
+                        .add(n)
+                        .write_unaligned(fd.file_descriptor().native_handle())
+                };
+            }
+        }
+
+        let bytes_sent =
+            unsafe { posix::sendmsg(self.socket.file_descriptor.native_handle(), &message, 0) };
+
+        if bytes_sent < 0 {
+            handle_errno!(UnixDatagramSendMsgError, from self,
+                success Errno::EAGAIN => false,
+                Errno::ECONNRESET => (ConnectionReset, "{} since the connection was reset by peer.", msg),
+                Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+                Errno::EMSGSIZE => (MessageTooLarge, "{} since the message size of {} bytes is too large to be send in one package.", msg, data.len()),
+                Errno::EIO => (IOerror, "{} since an I/O error occurred while writing to the file system.", msg),
+                Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+                Errno::EPERM => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+                Errno::ENOBUFS => (InsufficientResources, "{} due to insufficient resources.", msg),
+                Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+                Errno::ENOTCONN => (NotConnected, "{} since the socket is not yet connected.", msg),
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+
+        if data.len() != bytes_sent as usize {
+            fail!(from self, with UnixDatagramSendMsgError::MessagePartiallySend(bytes_sent as u64),
+                "{} since only parts of the message of length {}. {} bytes were sent and {} remain unsent.", msg, data.len(), bytes_sent, data.len() - bytes_sent as usize );
+        }
+
+        Ok(true)
+    }
+
+    /// Tries to send data together with [`FileDescriptor`]s in a non-blocking way. The receiving
+    /// process acquires a duplicate of every provided [`FileDescriptor`] with
+    /// [`UnixDatagramReceiver::try_receive_with_fds()`].
+    /// If the data was sent it returns true, otherwise false.
+    pub fn try_send_with_fds(
+        &self,
+        data: &[u8],
+        fds: &[&dyn FileDescriptorBased],
+    ) -> Result<bool, UnixDatagramSendMsgError> {
+        fail!(from self, when self.set_non_blocking(true),
+                "Unable to try send data with file descriptors since the socket could not bet set into unblocking state.");
+        self.send_with_fds(data, fds)
+    }
+
     fn send(&self, data: &[u8]) -> Result<bool, UnixDatagramSendError> {
         let bytes_sent = unsafe {
             posix::sendto(
@@ -763,6 +937,8 @@ pub struct UnixDatagramReceiverBuilder {
     name: FilePath,
     permission: Permission,
     creation_mode: CreationMode,
+    credentials: Option<bool>,
+    is_abstract: bool,
 }
 
 impl UnixDatagramReceiverBuilder {
@@ -771,6 +947,8 @@ impl UnixDatagramReceiverBuilder {
             name: name.clone(),
             permission: Permission::OWNER_ALL,
             creation_mode: CreationMode::CreateExclusive,
+            credentials: None,
+            is_abstract: false,
         }
     }
 
@@ -786,6 +964,39 @@ impl UnixDatagramReceiverBuilder {
         self
     }
 
+    /// Requires that the operating system attaches the [`SocketCred`] of the sending process
+    /// to every received message, so that they can be acquired with
+    /// [`UnixDatagramReceiver::try_receive_with_credentials()`] or
+    /// [`UnixDatagramReceiver::try_receive_msg()`]. By default, credentials are attached on
+    /// every platform that supports them. If the platform does not support credentials for
+    /// datagram sockets the creation fails with
+    /// [`UnixDatagramReceiverCreationError::CredentialsUnsupported`].
+    pub fn with_credentials(mut self) -> Self {
+        self.credentials = Some(true);
+        self
+    }
+
+    /// The operating system does not attach the [`SocketCred`] of the sending process to the
+    /// received messages. Only credentials that were explicitly sent with
+    /// [`SocketAncillary::set_creds()`] are received.
+    pub fn without_credentials(mut self) -> Self {
+        self.credentials = Some(false);
+        self
+    }
+
+    /// Creates the socket in the abstract namespace (Linux only) with the name of the
+    /// provided path instead of binding it to a socket file. The socket is removed by the
+    /// kernel as soon as it is closed, therefore no stale socket file remains when the process
+    /// crashes. The [`UnixDatagramReceiverBuilder::permission()`] is ignored and since an
+    /// abstract socket cannot be purged, [`CreationMode::PurgeAndCreate`] behaves like
+    /// [`CreationMode::CreateExclusive`].
+    /// If the platform does not support the abstract namespace the creation fails with
+    /// [`UnixDatagramReceiverCreationError::AbstractNamespaceUnsupported`].
+    pub fn abstract_namespace(mut self, value: bool) -> Self {
+        self.is_abstract = value;
+        self
+    }
+
     pub fn create(self) -> Result<UnixDatagramReceiver, UnixDatagramReceiverCreationError> {
         UnixDatagramReceiver::new(self)
     }
@@ -798,11 +1009,14 @@ impl UnixDatagramReceiverBuilder {
 #[derive(Debug)]
 pub struct UnixDatagramReceiver {
     socket: UnixDatagramSocket,
+    has_credentials: bool,
 }
 
 impl Drop for UnixDatagramReceiver {
     fn drop(&mut self) {
-        fatal_panic!(from self, when File::remove(&self.socket.name), "Failed to remove socket file.");
+        if !self.socket.is_abstract {
+            fatal_panic!(from self, when File::remove(&self.socket.name), "Failed to remove socket file.");
+        }
         trace!(from self, "stop listening and remove");
     }
 }
@@ -810,21 +1024,39 @@ impl Drop for UnixDatagramReceiver {
 impl UnixDatagramReceiver {
     fn new(config: UnixDatagramReceiverBuilder) -> Result<Self, UnixDatagramReceiverCreationError> {
         let msg = "Unable to create new socket";
+        if config.credentials == Some(true)
+            && !posix::POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS_ANCILLARY_DATA
+        {
+            fail!(from config, with UnixDatagramReceiverCreationError::CredentialsUnsupported,
+                "{} since the platform does not support credentials for unix datagram sockets.", msg);
+        }
+        let has_credentials = config
+            .credentials
+            .unwrap_or(posix::POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS_ANCILLARY_DATA);
+
+        if config.is_abstract && !posix::POSIX_SUPPORT_ABSTRACT_UNIX_DOMAIN_SOCKETS {
+            fail!(from config, with UnixDatagramReceiverCreationError::AbstractNamespaceUnsupported,
+                "{} since the platform does not support abstract unix domain sockets.", msg);
+        }
+
         let new_socket = Self {
-            socket: fail!(from config, when UnixDatagramSocket::new(&config.name), "{}.", msg),
+            socket: fail!(from config, when UnixDatagramSocket::new(&config.name, config.is_abstract), "{}.", msg),
+            has_credentials,
         };
 
-        let does_file_exist = fail!(from new_socket, when File::does_exist(&config.name), "Unable to determine if socket exists.");
+        if !config.is_abstract {
+            let does_file_exist = fail!(from new_socket, when File::does_exist(&config.name), "Unable to determine if socket exists.");
 
-        if config.creation_mode == CreationMode::PurgeAndCreate && does_file_exist {
-            fail!(from new_socket, when File::remove(&config.name), "{} since the already existing socket could not be removed.", msg);
-        } else if config.creation_mode == CreationMode::CreateExclusive && does_file_exist {
-            fail!(from new_socket, with UnixDatagramReceiverCreationError::SocketFileAlreadyExists, "{} since it already exists.", msg);
+            if config.creation_mode == CreationMode::PurgeAndCreate && does_file_exist {
+                fail!(from new_socket, when File::remove(&config.name), "{} since the already existing socket could not be removed.", msg);
+            } else if config.creation_mode == CreationMode::CreateExclusive && does_file_exist {
+                fail!(from new_socket, with UnixDatagramReceiverCreationError::SocketFileAlreadyExists, "{} since it already exists.", msg);
+            }
         }
 
         fail!(from new_socket, when new_socket.socket.bind(config.permission), "{} since the socket could not be bind.", msg);
 
-        if posix::POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS_ANCILLARY_DATA {
+        if has_credentials {
             fail!(from new_socket, when new_socket.socket.set_socket_option("Unable to activate credential support", &1u32, posix::SO_PASSCRED),
                 "{} since the credential support could not be activated.", msg);
         }
@@ -838,6 +1070,12 @@ impl UnixDatagramReceiver {
         &self.socket.name
     }
 
+    /// Returns true if the platform supports sockets in the abstract namespace, see
+    /// [`UnixDatagramReceiverBuilder::abstract_namespace()`].
+    pub fn does_support_abstract_namespace() -> bool {
+        posix::POSIX_SUPPORT_ABSTRACT_UNIX_DOMAIN_SOCKETS
+    }
+
     fn set_non_blocking(&self, value: bool) -> Result<(), UnixDatagramSetPropertyError> {
         self.socket.set_non_blocking(value)
     }
@@ -1019,6 +1257,148 @@ impl UnixDatagramReceiver {
         self.receive_msg(socket_msg)
     }
 
+    fn receive_with_ancillary_data(
+        &self,
+        buffer: &mut [u8],
+        fd_out: &mut Vec<FileDescriptor>,
+    ) -> Result<(u64, Option<SocketCred>), UnixDatagramReceiveFdError> {
+        let mut iovec = posix::iovec::new_zeroed();
+        iovec.set_base(buffer.as_mut_ptr() as *mut posix::void);
+        iovec.set_len(buffer.len());
+
+        let control_len = buffer_capacity();
+        let mut control_buffer = control_message_buffer(control_len);
+
+        let mut message = posix::msghdr::new_zeroed();
+        message.msg_iov = iovec.as_mut_ptr();
+        message.msg_iovlen = 1;
+        message.msg_control = control_buffer.as_mut_ptr() as *mut posix::void;
+        message.msg_controllen = control_len as _;
+
+        let msg = "Unable to receive data with ancillary data";
+        let bytes_received =
+            unsafe { posix::recvmsg(self.socket.file_descriptor.native_handle(), &mut message, 0) };
+
+        if bytes_received < 0 {
+            handle_errno!(UnixDatagramReceiveFdError, from self,
+                success Errno::ETIMEDOUT => (0, None);
+                success Errno::EAGAIN => (0, None),
+                Errno::ECONNRESET => (ConnectionReset, "{} since connection was forcibly closed.", msg),
+                Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+                Errno::ENOTCONN => (NotConnected, "{} since socket is not connected.", msg),
+                Errno::EIO => (IOerror, "{} since an I/O error occurred while reading from the file system.", msg),
+                Errno::ENOBUFS => (InsufficientResources, "{} due to insufficient resources.", msg),
+                Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+                v => (UnknownError(v as i32), "{} due to an unknown error ({}).", msg, v)
+            );
+        }
+
+        // every received file descriptor is owned right away so that it is closed on all
+        // error paths and cannot leak
+        let mut received_fds = vec![];
+        let mut credentials = None;
+        let mut header = unsafe { posix::CMSG_FIRSTHDR(&message) };
+        while !header.is_null() {
+            match unsafe { ((*header).cmsg_level, (*header).cmsg_type) } {
+                (CMSG_SOCKET_LEVEL, posix::SCM_CREDENTIALS) => {
+                    credentials = Some(unsafe { SocketCred::from_cmsghdr(header) });
+                }
+                (CMSG_SOCKET_LEVEL, posix::SCM_RIGHTS) => {
+                    let data_len =
+                        unsafe { (*header).cmsg_len as usize - posix::CMSG_LEN(0) as usize };
+                    let fd_data = unsafe { posix::CMSG_DATA(header) } as *const posix::int;
+                    for n in 0..data_len / SIZE_OF_FD {
+                        match FileDescriptor::new(unsafe { fd_data.add(n).read_unaligned() }) {
+                            Some(fd) => received_fds.push(fd),
+                            None => {
+                                warn!(from self, "An invalid file descriptor was received and will be ignored.")
+                            }
+                        }
+                    }
+                }
+                _ => (),
+            }
+
+            header = unsafe { posix::CMSG_NXTHDR(&message, header) };
+        }
+
+        if message.msg_flags & posix::MSG_CTRUNC != 0 {
+            fail!(from self, with UnixDatagramReceiveFdError::ControlMessageTruncated,
+                "{} since the control message was truncated and not all file descriptors could be received. The {} already received file descriptors are closed.",
+                msg, received_fds.len());
+        }
+
+        fd_out.append(&mut received_fds);
+        Ok((bytes_received as u64, credentials))
+    }
+
+    /// Tries to receive data together with the [`FileDescriptor`]s sent via
+    /// [`UnixDatagramSender::try_send_with_fds()`]. The received [`FileDescriptor`]s are appended
+    /// to `fd_out`. If no data is present it will not block and return 0.
+    /// When not all [`FileDescriptor`]s could be received, for instance since the per-process
+    /// limit of file descriptors was reached, it fails with
+    /// [`UnixDatagramReceiveFdError::ControlMessageTruncated`] and closes all [`FileDescriptor`]s
+    /// of the message.
+    pub fn try_receive_with_fds(
+        &self,
+        buffer: &mut [u8],
+        fd_out: &mut Vec<FileDescriptor>,
+    ) -> Result<u64, UnixDatagramReceiveFdError> {
+        fail!(from self, when self.set_non_blocking(true),
+                "Unable to try receive data with file descriptors since the socket could not bet set into unblocking state.");
+        Ok(self.receive_with_ancillary_data(buffer, fd_out)?.0)
+    }
+
+    /// Tries to receive data together with the [`SocketCred`] of the sending process. When the
+    /// [`UnixDatagramReceiver`] was created with
+    /// [`UnixDatagramReceiverBuilder::without_credentials()`] or on a platform without
+    /// credential support it fails with [`UnixDatagramReceiveFdError::CredentialsNotEnabled`]. [`FileDescriptor`]s that were
+    /// sent with the data are closed. If no data is present it will not block and return 0.
+    pub fn try_receive_with_credentials(
+        &self,
+        buffer: &mut [u8],
+    ) -> Result<(u64, Option<SocketCred>), UnixDatagramReceiveFdError> {
+        let msg = "Unable to try receive data with credentials";
+        if !self.has_credentials {
+            fail!(from self, with UnixDatagramReceiveFdError::CredentialsNotEnabled,
+                "{} since the receiver was not created with credential support.", msg);
+        }
+
+        fail!(from self, when self.set_non_blocking(true),
+                "{} since the socket could not bet set into unblocking state.", msg);
+        let mut unwanted_fds = vec![];
+        self.receive_with_ancillary_data(buffer, &mut unwanted_fds)
+    }
+
+    /// Blocks until data was received together with the [`SocketCred`] of the sending process.
+    /// When the [`UnixDatagramReceiver`] was created with
+    /// [`UnixDatagramReceiverBuilder::without_credentials()`] or on a platform without
+    /// credential support it fails with [`UnixDatagramReceiveFdError::CredentialsNotEnabled`].
+    /// [`FileDescriptor`]s that were sent with the data are closed.
+    pub fn blocking_receive_with_credentials(
+        &self,
+        buffer: &mut [u8],
+    ) -> Result<(u64, Option<SocketCred>), UnixDatagramReceiveFdError> {
+        let msg = "Unable to blocking receive data with credentials";
+        if !self.has_credentials {
+            fail!(from self, with UnixDatagramReceiveFdError::CredentialsNotEnabled,
+                "{} since the receiver was not created with credential support.", msg);
+        }
+
+        let mut unwanted_fds = vec![];
+        loop {
+            fail!(from self, when self.set_non_blocking(false),
+                "{} since the socket could not bet set into blocking state.", msg);
+            fail!(from self, when self.set_timeout(BLOCKING_TIMEOUT),
+                "{} since the socket blocking timeout could not be set.", msg);
+
+            match self.receive_with_ancillary_data(buffer, &mut unwanted_fds)? {
+                (0, _) => unwanted_fds.clear(),
+                v => return Ok(v),
+            }
+        }
+    }
+
     fn internal_receive(
         &self,
         flags: posix::int,
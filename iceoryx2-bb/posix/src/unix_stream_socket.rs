@@ -0,0 +1,804 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Abstraction of stream based unix domain sockets. The [`UnixStreamListener`] creates a
+//! socket to which a [`UnixStreamSenderBuilder`] can connect. Every accepted connection
+//! results in a [`UnixStreamSocket`] that can send and receive a stream of bytes in both
+//! directions.
+//!
+//! In contrast to the [`crate::unix_datagram_socket`] no message boundaries are preserved,
+//! a receive call may acquire only a part of the sent data or the data of multiple send calls.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_posix::unix_stream_socket::*;
+//! use iceoryx2_bb_posix::permission::*;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//!
+//! let socket_name = FilePath::new(b"myStreamSocket").unwrap();
+//! let listener = UnixStreamListenerBuilder::new(&socket_name)
+//!                         .permission(Permission::OWNER_ALL)
+//!                         .creation_mode(CreationMode::PurgeAndCreate)
+//!                         .create().unwrap();
+//!
+//! let client = UnixStreamSenderBuilder::new(&socket_name)
+//!                         .create().unwrap();
+//! let server = listener.blocking_accept().unwrap();
+//!
+//! // send some data
+//! client.blocking_send(b"hello world").unwrap();
+//!
+//! // receive some data
+//! let mut buffer = [0u8; 32];
+//! let bytes_received = server.blocking_receive(&mut buffer).unwrap();
+//! ```
+
+use core::sync::atomic::Ordering;
+use core::{mem::size_of, time::Duration};
+use iceoryx2_bb_container::semantic_string::*;
+use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_elementary::scope_guard::ScopeGuardBuilder;
+use iceoryx2_bb_log::{fail, fatal_panic, trace};
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+use iceoryx2_pal_posix::posix::{errno::Errno, MemZeroedStruct};
+use iceoryx2_pal_posix::*;
+
+use crate::{
+    clock::AsTimeval,
+    config::UNIX_DOMAIN_SOCKET_PATH_LENGTH,
+    file::*,
+    file_descriptor::{FileDescriptor, FileDescriptorBased},
+    file_descriptor_set::SynchronousMultiplexing,
+    permission::Permission,
+};
+
+pub use crate::creation_mode::CreationMode;
+
+const BLOCKING_TIMEOUT: Duration = Duration::from_secs(i16::MAX as _);
+const DEFAULT_BACKLOG: u32 = 128;
+
+enum_gen! {
+    /// Defines the errors that can occur when the underlying socket of a
+    /// [`UnixStreamListener`] or a [`UnixStreamSocket`] is created.
+    UnixStreamCreationError
+  entry:
+    SocketNameTooLong,
+    InsufficientPermissions,
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    InsufficientResources,
+    InsufficientMemory,
+    StreamProtocolNotSupported,
+    UnixDomainSocketsNotSupported,
+    UnknownError(i32)
+}
+
+enum_gen! {
+    /// Defines the errors that can occur when the blocking mode of a socket is changed.
+    UnixStreamSetPropertyError
+  entry:
+    Interrupt,
+    UnknownError(i32)
+}
+
+enum_gen! {
+    /// Defines the errors that can occur when a socket option is set.
+    UnixStreamSetSocketOptionError
+  entry:
+    InsufficientResources,
+    InsufficientMemory,
+    UnknownError(i32)
+}
+
+enum_gen! {
+    /// Defines the errors that can occur in [`UnixStreamListenerBuilder::create()`].
+    UnixStreamListenerCreationError
+  entry:
+    SocketFileAlreadyExists,
+    InsufficientResources,
+    InsufficientPermissions,
+    AddressAlreadyInUse,
+    PathDoesNotExist,
+    ReadOnlyFileSystem,
+    UnknownError(i32)
+  mapping:
+    UnixStreamCreationError,
+    FileAccessError,
+    FileRemoveError
+}
+
+enum_gen! {
+    /// Defines the errors that can occur in [`UnixStreamSenderBuilder::create()`].
+    UnixStreamSenderCreationError
+  entry:
+    DoesNotExist,
+    InsufficientPermissions,
+    ConnectionRefused,
+    Interrupt,
+    ConnectionReset,
+    InsufficientResources,
+    UnknownError(i32)
+  mapping:
+    UnixStreamCreationError
+}
+
+enum_gen! {
+    /// Defines the errors that can occur in
+    /// * [`UnixStreamListener::try_accept()`]
+    /// * [`UnixStreamListener::blocking_accept()`]
+    UnixStreamAcceptError
+  entry:
+    Interrupt,
+    ConnectionAborted,
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    InsufficientResources,
+    InsufficientMemory,
+    UnknownError(i32)
+  mapping:
+    UnixStreamSetPropertyError
+}
+
+enum_gen! {
+    /// Defines the errors that can occur when a [`UnixStreamSocket`] sends data via
+    /// * [`UnixStreamSocket::try_send()`]
+    /// * [`UnixStreamSocket::timed_send()`]
+    /// * [`UnixStreamSocket::blocking_send()`]
+    UnixStreamSendError
+  entry:
+    Interrupt,
+    ConnectionReset,
+    Disconnected,
+    InsufficientResources,
+    InsufficientMemory,
+    UnknownError(i32)
+  mapping:
+    UnixStreamSetPropertyError,
+    UnixStreamSetSocketOptionError
+}
+
+enum_gen! {
+    /// Defines the errors that can occur when a [`UnixStreamSocket`] receives data via
+    /// * [`UnixStreamSocket::try_receive()`]
+    /// * [`UnixStreamSocket::timed_receive()`]
+    /// * [`UnixStreamSocket::blocking_receive()`]
+    UnixStreamReceiveError
+  entry:
+    Interrupt,
+    ConnectionReset,
+    Disconnected,
+    InsufficientResources,
+    InsufficientMemory,
+    UnknownError(i32)
+  mapping:
+    UnixStreamSetPropertyError,
+    UnixStreamSetSocketOptionError
+}
+
+enum_gen! {
+    /// Defines the errors that can occur in [`UnixStreamSocket::shutdown()`].
+    UnixStreamShutdownError
+  entry:
+    NotConnected,
+    UnknownError(i32)
+}
+
+/// Defines which direction of a [`UnixStreamSocket`] shall be closed with
+/// [`UnixStreamSocket::shutdown()`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum UnixStreamShutdown {
+    /// No further data can be received.
+    Read,
+    /// No further data can be sent, the peer receives
+    /// [`UnixStreamReceiveError::Disconnected`] after it has consumed the pending data.
+    Write,
+    /// Combination of [`UnixStreamShutdown::Read`] and [`UnixStreamShutdown::Write`].
+    Both,
+}
+
+#[derive(Debug)]
+struct UnixStreamHandle {
+    file_descriptor: FileDescriptor,
+    is_non_blocking: IoxAtomicBool,
+}
+
+impl UnixStreamHandle {
+    fn new(name: &FilePath) -> Result<Self, UnixStreamCreationError> {
+        if name.len() > UNIX_DOMAIN_SOCKET_PATH_LENGTH {
+            fail!(with UnixStreamCreationError::SocketNameTooLong,
+                "The name \"{}\" is too long for a UnixStreamSocket name. Maximum supported length is {}.", name, UNIX_DOMAIN_SOCKET_PATH_LENGTH);
+        }
+
+        let raw_fd = unsafe { posix::socket(posix::PF_UNIX as posix::int, posix::SOCK_STREAM, 0) };
+
+        let msg = format!("Unable to create UnixStreamSocket named \"{name}\"");
+        if raw_fd < 0 {
+            handle_errno!(UnixStreamCreationError, from "UnixStreamSocket::new",
+                Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+                Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process limit of file descriptors was reached.", msg),
+                Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since system-wide limit of file descriptors was reached.", msg),
+                Errno::ENOBUFS => (InsufficientResources, "{} due to insufficient resources.", msg),
+                Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+                Errno::EPROTONOSUPPORT => (StreamProtocolNotSupported, "{} since the stream protocol is not supported by the system.", msg),
+                Errno::EPROTOTYPE => (UnixDomainSocketsNotSupported, "{} since UnixDomainSockets are not supported by the system.", msg),
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+
+        Ok(Self {
+            file_descriptor: FileDescriptor::new(raw_fd).unwrap(),
+            is_non_blocking: IoxAtomicBool::new(false),
+        })
+    }
+
+    fn from_file_descriptor(
+        file_descriptor: FileDescriptor,
+    ) -> Result<Self, UnixStreamSetPropertyError> {
+        let new_self = Self {
+            file_descriptor,
+            is_non_blocking: IoxAtomicBool::new(false),
+        };
+
+        // some platforms let accepted sockets inherit the flags of the listening socket
+        let current_flags = new_self.fcntl(
+            posix::F_GETFL,
+            0,
+            "Unable to acquire current socket file descriptor flags",
+        )?;
+        new_self
+            .is_non_blocking
+            .store(current_flags & posix::O_NONBLOCK != 0, Ordering::Relaxed);
+
+        Ok(new_self)
+    }
+
+    fn create_socket_address(name: &FilePath) -> posix::sockaddr_un {
+        let mut socket_address = posix::sockaddr_un::new_zeroed();
+        socket_address.sun_family = posix::AF_UNIX;
+
+        unsafe {
+            posix::strncpy(
+                socket_address.sun_path.as_mut_ptr(),
+                name.as_c_str(),
+                name.len(),
+            );
+        }
+
+        socket_address
+    }
+
+    fn fcntl(
+        &self,
+        command: i32,
+        value: i32,
+        msg: &str,
+    ) -> Result<i32, UnixStreamSetPropertyError> {
+        let result =
+            unsafe { posix::fcntl_int(self.file_descriptor.native_handle(), command, value) };
+
+        if result >= 0 {
+            return Ok(result);
+        }
+
+        handle_errno!(UnixStreamSetPropertyError, from self,
+            fatal Errno::EBADF => ("This should never happen! {} since the file descriptor is invalid.", msg);
+            fatal Errno::EINVAL => ("This should never happen! {} since an internal argument was invalid.", msg),
+            Errno::EINTR => (Interrupt, "{} due to an interrupt signal.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
+    fn set_non_blocking(&self, value: bool) -> Result<(), UnixStreamSetPropertyError> {
+        if self.is_non_blocking.load(Ordering::Relaxed) == value {
+            return Ok(());
+        }
+
+        let current_flags = self.fcntl(
+            posix::F_GETFL,
+            0,
+            "Unable to acquire current socket file descriptor flags",
+        )?;
+        let new_flags = match value {
+            true => current_flags | posix::O_NONBLOCK,
+            false => current_flags & !posix::O_NONBLOCK,
+        };
+
+        self.fcntl(posix::F_SETFL, new_flags, "Unable to set blocking mode")?;
+        self.is_non_blocking.store(value, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn set_socket_option<T>(
+        &self,
+        msg: &str,
+        value: &T,
+        socket_option: posix::int,
+    ) -> Result<(), UnixStreamSetSocketOptionError> {
+        if unsafe {
+            posix::setsockopt(
+                self.file_descriptor.native_handle(),
+                posix::SOL_SOCKET,
+                socket_option,
+                (value as *const T) as *const posix::void,
+                size_of::<T>() as u32,
+            )
+        } == 0
+        {
+            return Ok(());
+        }
+
+        handle_errno!(UnixStreamSetSocketOptionError, from self,
+            fatal Errno::EBADF => ("This should never happen! {} since the file descriptor is invalid.", msg);
+            fatal Errno::EINVAL => ("This should never happen! {} since an argument is invalid.", msg),
+            Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+            Errno::ENOBUFS => (InsufficientResources, "{} due to insufficient resources.", msg),
+            v => (UnknownError(v as i32), "{} caused by an unknown error ({}).", msg, v)
+        );
+    }
+}
+
+/// Creates a [`UnixStreamListener`] which binds a socket to the given name and waits for
+/// incoming connections.
+#[derive(Debug)]
+pub struct UnixStreamListenerBuilder {
+    name: FilePath,
+    permission: Permission,
+    creation_mode: CreationMode,
+    backlog: u32,
+}
+
+impl UnixStreamListenerBuilder {
+    pub fn new(name: &FilePath) -> Self {
+        Self {
+            name: name.clone(),
+            permission: Permission::OWNER_ALL,
+            creation_mode: CreationMode::CreateExclusive,
+            backlog: DEFAULT_BACKLOG,
+        }
+    }
+
+    /// Sets the permission of the corresponding socket file
+    pub fn permission(mut self, permission: Permission) -> Self {
+        self.permission = permission;
+        self
+    }
+
+    /// Defines the creation mode
+    pub fn creation_mode(mut self, value: CreationMode) -> Self {
+        self.creation_mode = value;
+        self
+    }
+
+    /// Defines the maximum number of pending connections that were not yet accepted. The
+    /// operating system is allowed to adjust the value.
+    pub fn backlog(mut self, value: u32) -> Self {
+        self.backlog = value;
+        self
+    }
+
+    pub fn create(self) -> Result<UnixStreamListener, UnixStreamListenerCreationError> {
+        UnixStreamListener::new(self)
+    }
+
+    fn bind(&self, handle: &UnixStreamHandle) -> Result<(), UnixStreamListenerCreationError> {
+        let socket_address = UnixStreamHandle::create_socket_address(&self.name);
+        let ptr: *const posix::sockaddr_un = &socket_address;
+
+        {
+            let _mask = ScopeGuardBuilder::new(0 as posix::mode_t)
+                .on_init(|mask| -> Result<(), ()> {
+                    *mask = unsafe { posix::umask((!self.permission).bits()) };
+                    Ok(())
+                })
+                .on_drop(|mask| unsafe {
+                    posix::umask(*mask);
+                })
+                .create();
+
+            if unsafe {
+                posix::bind(
+                    handle.file_descriptor.native_handle(),
+                    ptr as *const posix::sockaddr,
+                    size_of::<posix::sockaddr_un>() as u32,
+                )
+            } == 0
+            {
+                return Ok(());
+            }
+        }
+
+        let msg = "Failed to bind socket";
+        handle_errno!(UnixStreamListenerCreationError, from self,
+            Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+            Errno::EADDRINUSE => (AddressAlreadyInUse, "{} since the address is already in use.", msg),
+            Errno::ENOENT => (PathDoesNotExist, "{} since the path does not exist.", msg),
+            Errno::ENOTDIR => (PathDoesNotExist, "{} since the path does not exist.", msg),
+            Errno::ENOBUFS => (InsufficientResources, "{} due to insufficient resources.", msg),
+            Errno::EROFS => (ReadOnlyFileSystem, "{} since it would reside on an read-only file system.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error has occurred ({}).", msg, v)
+        );
+    }
+}
+
+/// Created by the [`UnixStreamListenerBuilder`]. Owns the socket file and accepts incoming
+/// connections with [`UnixStreamListener::try_accept()`] or
+/// [`UnixStreamListener::blocking_accept()`]. The socket file is removed when the listener
+/// goes out of scope, already accepted [`UnixStreamSocket`]s stay connected.
+#[derive(Debug)]
+pub struct UnixStreamListener {
+    name: FilePath,
+    handle: UnixStreamHandle,
+}
+
+impl Drop for UnixStreamListener {
+    fn drop(&mut self) {
+        fatal_panic!(from self, when File::remove(&self.name), "Failed to remove socket file.");
+        trace!(from self, "stop listening and remove");
+    }
+}
+
+impl FileDescriptorBased for UnixStreamListener {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.handle.file_descriptor
+    }
+}
+
+impl SynchronousMultiplexing for UnixStreamListener {}
+
+impl UnixStreamListener {
+    fn new(config: UnixStreamListenerBuilder) -> Result<Self, UnixStreamListenerCreationError> {
+        let msg = "Unable to create unix stream listener";
+        let handle = fail!(from config, when UnixStreamHandle::new(&config.name), "{}.", msg);
+
+        let does_file_exist = fail!(from config, when File::does_exist(&config.name),
+            "{} since it could not be determined if the socket already exists.", msg);
+
+        if config.creation_mode == CreationMode::PurgeAndCreate && does_file_exist {
+            fail!(from config, when File::remove(&config.name),
+                "{} since the already existing socket could not be removed.", msg);
+        } else if config.creation_mode == CreationMode::CreateExclusive && does_file_exist {
+            fail!(from config, with UnixStreamListenerCreationError::SocketFileAlreadyExists,
+                "{} since it already exists.", msg);
+        }
+
+        fail!(from config, when config.bind(&handle), "{} since the socket could not be bind.", msg);
+
+        // from here on the socket file is owned and removed on drop
+        let new_self = Self {
+            name: config.name,
+            handle,
+        };
+
+        if unsafe {
+            posix::listen(
+                new_self.handle.file_descriptor.native_handle(),
+                config.backlog.min(posix::int::MAX as u32) as posix::int,
+            )
+        } != 0
+        {
+            handle_errno!(UnixStreamListenerCreationError, from new_self,
+                Errno::EACCES => (InsufficientPermissions, "{} since the socket could not listen due to insufficient permissions.", msg),
+                Errno::EADDRINUSE => (AddressAlreadyInUse, "{} since the address is already in use.", msg),
+                Errno::ENOBUFS => (InsufficientResources, "{} since the socket could not listen due to insufficient resources.", msg),
+                v => (UnknownError(v as i32), "{} since the socket could not listen due to an unknown error ({}).", msg, v)
+            );
+        }
+
+        trace!(from new_self, "create and listening");
+        Ok(new_self)
+    }
+
+    /// Returns the name of the socket
+    pub fn name(&self) -> &FilePath {
+        &self.name
+    }
+
+    fn accept_impl(&self, msg: &str) -> Result<Option<UnixStreamSocket>, UnixStreamAcceptError> {
+        let raw_fd = unsafe {
+            posix::accept(
+                self.handle.file_descriptor.native_handle(),
+                core::ptr::null_mut(),
+                core::ptr::null_mut(),
+            )
+        };
+
+        if raw_fd >= 0 {
+            let file_descriptor = match FileDescriptor::new(raw_fd) {
+                Some(fd) => fd,
+                None => {
+                    fatal_panic!(from self,
+                        "This should never happen! {} since accept returned a broken file descriptor.", msg);
+                }
+            };
+
+            let handle = fail!(from self, when UnixStreamHandle::from_file_descriptor(file_descriptor),
+                "{} since the properties of the accepted socket could not be acquired.", msg);
+            trace!(from self, "accepted new connection");
+            return Ok(Some(UnixStreamSocket { handle }));
+        }
+
+        handle_errno!(UnixStreamAcceptError, from self,
+            success Errno::EAGAIN => None;
+            success Errno::ETIMEDOUT => None,
+            fatal Errno::EBADF => ("This should never happen! {} since the internal file descriptor was invalid.", msg);
+            fatal Errno::EINVAL => ("This should never happen! {} since the socket is not listening.", msg),
+            Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+            Errno::ECONNABORTED => (ConnectionAborted, "{} since the connection was aborted.", msg),
+            Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process limit of file descriptors was reached.", msg),
+            Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since system-wide limit of file descriptors was reached.", msg),
+            Errno::ENOBUFS => (InsufficientResources, "{} due to insufficient resources.", msg),
+            Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
+    /// Tries to accept a pending connection. It does not block, when no connection is pending
+    /// it returns [`None`], otherwise the connected [`UnixStreamSocket`].
+    pub fn try_accept(&self) -> Result<Option<UnixStreamSocket>, UnixStreamAcceptError> {
+        let msg = "Unable to try accepting a connection";
+        fail!(from self, when self.handle.set_non_blocking(true),
+            "{} since the socket could not be set into non-blocking mode.", msg);
+        self.accept_impl(msg)
+    }
+
+    /// Blocks until a connection was established and returns the connected
+    /// [`UnixStreamSocket`].
+    pub fn blocking_accept(&self) -> Result<UnixStreamSocket, UnixStreamAcceptError> {
+        let msg = "Unable to accept a connection with blocking behavior";
+        fail!(from self, when self.handle.set_non_blocking(false),
+            "{} since the socket could not be set into blocking mode.", msg);
+
+        loop {
+            if let Some(socket) = self.accept_impl(msg)? {
+                return Ok(socket);
+            }
+        }
+    }
+}
+
+/// Creates a [`UnixStreamSocket`] that is connected to an existing [`UnixStreamListener`].
+/// The connection is established as soon as the listener has room in its backlog, it does
+/// not have to be accepted first.
+#[derive(Debug)]
+pub struct UnixStreamSenderBuilder {
+    name: FilePath,
+}
+
+impl UnixStreamSenderBuilder {
+    pub fn new(name: &FilePath) -> Self {
+        Self { name: name.clone() }
+    }
+
+    pub fn create(self) -> Result<UnixStreamSocket, UnixStreamSenderCreationError> {
+        let msg = "Unable to create unix stream sender";
+        let handle = fail!(from self, when UnixStreamHandle::new(&self.name), "{}.", msg);
+
+        let socket_address = UnixStreamHandle::create_socket_address(&self.name);
+        let ptr: *const posix::sockaddr_un = &socket_address;
+        if unsafe {
+            posix::connect(
+                handle.file_descriptor.native_handle(),
+                ptr as *const posix::sockaddr,
+                size_of::<posix::sockaddr_un>() as u32,
+            )
+        } == 0
+        {
+            let new_socket = UnixStreamSocket { handle };
+            trace!(from new_socket, "connected to \"{}\"", self.name);
+            return Ok(new_socket);
+        }
+
+        handle_errno!(UnixStreamSenderCreationError, from self,
+            Errno::ENOENT => (DoesNotExist, "{} since the unix stream listener does not exist.", msg),
+            Errno::EACCES => (InsufficientPermissions, "{} due to insufficient permissions.", msg),
+            Errno::ECONNREFUSED => (ConnectionRefused, "{} since the connection was refused.", msg),
+            Errno::EINTR => (Interrupt, "{} since an interrupt was received.", msg),
+            Errno::ECONNRESET => (ConnectionReset, "{} since the host reset the connection request.", msg),
+            Errno::ENOBUFS => (InsufficientResources, "{} since there is no buffer space available.", msg),
+            v => (UnknownError(v as i32), "{} caused by an unknown error ({}).", msg, v)
+        );
+    }
+}
+
+/// A connected stream socket, created either by [`UnixStreamListener::try_accept()`],
+/// [`UnixStreamListener::blocking_accept()`] or [`UnixStreamSenderBuilder::create()`].
+/// Both sides can send and receive data.
+#[derive(Debug)]
+pub struct UnixStreamSocket {
+    handle: UnixStreamHandle,
+}
+
+impl FileDescriptorBased for UnixStreamSocket {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.handle.file_descriptor
+    }
+}
+
+impl SynchronousMultiplexing for UnixStreamSocket {}
+
+impl UnixStreamSocket {
+    fn set_send_timeout(&self, timeout: Duration) -> Result<(), UnixStreamSetSocketOptionError> {
+        self.handle.set_socket_option(
+            "Unable to set send timeout",
+            &timeout.as_timeval(),
+            posix::SO_SNDTIMEO,
+        )
+    }
+
+    fn set_receive_timeout(&self, timeout: Duration) -> Result<(), UnixStreamSetSocketOptionError> {
+        self.handle.set_socket_option(
+            "Unable to set receive timeout",
+            &timeout.as_timeval(),
+            posix::SO_RCVTIMEO,
+        )
+    }
+
+    fn send_impl(&self, msg: &str, buf: &[u8]) -> Result<usize, UnixStreamSendError> {
+        let number_of_bytes_written = unsafe {
+            posix::send(
+                self.handle.file_descriptor.native_handle(),
+                buf.as_ptr().cast(),
+                buf.len(),
+                posix::MSG_NOSIGNAL,
+            )
+        };
+
+        if 0 <= number_of_bytes_written {
+            return Ok(number_of_bytes_written as _);
+        }
+
+        handle_errno!(UnixStreamSendError, from self,
+            success Errno::EAGAIN => 0;
+            success Errno::ETIMEDOUT => 0,
+            fatal Errno::EBADF => ("This should never happen! {msg} since the internal file descriptor was invalid.");
+            fatal Errno::EINVAL => ("This should never happen! {msg} since an internal argument was invalid."),
+            Errno::EINTR => (Interrupt, "{msg} since an interrupt signal was received."),
+            Errno::ECONNRESET => (ConnectionReset, "{msg} since the connection was reset."),
+            Errno::EPIPE => (Disconnected, "{msg} since the socket is no longer connected."),
+            Errno::ENOBUFS => (InsufficientResources, "{msg} due to insufficient resources."),
+            Errno::ENOMEM => (InsufficientMemory, "{msg} due to insufficient memory."),
+            v => (UnknownError(v as i32), "{msg} since an unknown error occurred ({v}).")
+        )
+    }
+
+    /// Tries to send the given buffer. It does not block, when the internal buffer is full it
+    /// returns `0`, otherwise it returns the number of bytes that were sent which can be less
+    /// than the size of the provided buffer.
+    pub fn try_send(&self, buffer: &[u8]) -> Result<usize, UnixStreamSendError> {
+        let msg = "Unable to try sending data";
+        fail!(from self, when self.handle.set_non_blocking(true),
+            "{msg} since the socket could not be set into non-blocking mode.");
+        self.send_impl(msg, buffer)
+    }
+
+    /// Blocks until either the timeout has passed or until the data could be delivered.
+    /// If the timeout passed it returns `0`, otherwise the number of bytes that were sent.
+    pub fn timed_send(
+        &self,
+        buffer: &[u8],
+        timeout: Duration,
+    ) -> Result<usize, UnixStreamSendError> {
+        let msg = "Unable to send data with a timeout";
+        fail!(from self, when self.handle.set_non_blocking(false),
+            "{msg} ({timeout:?}) since the socket could not be set into blocking mode.");
+        fail!(from self, when self.set_send_timeout(timeout),
+            "{msg} ({timeout:?}) since the socket send timeout could not be set.");
+        self.send_impl(msg, buffer)
+    }
+
+    /// Blocks until the data could be delivered.
+    /// Despite the name, the function may not block indefinitely and spurious wakeups can cause
+    /// to return `0` when no data could be delivered.
+    pub fn blocking_send(&self, buffer: &[u8]) -> Result<usize, UnixStreamSendError> {
+        let msg = "Unable to send data with blocking behavior";
+        fail!(from self, when self.handle.set_non_blocking(false),
+            "{msg} since the socket could not be set into blocking mode.");
+        fail!(from self, when self.set_send_timeout(BLOCKING_TIMEOUT),
+            "{msg} since the socket blocking send timeout could not be set.");
+        self.send_impl(msg, buffer)
+    }
+
+    fn receive_impl(&self, msg: &str, buf: &mut [u8]) -> Result<usize, UnixStreamReceiveError> {
+        let number_of_bytes_read = unsafe {
+            posix::recv(
+                self.handle.file_descriptor.native_handle(),
+                buf.as_mut_ptr().cast(),
+                buf.len(),
+                0,
+            )
+        };
+
+        if 0 < number_of_bytes_read || (number_of_bytes_read == 0 && buf.is_empty()) {
+            return Ok(number_of_bytes_read as _);
+        }
+
+        if number_of_bytes_read == 0 {
+            fail!(from self, with UnixStreamReceiveError::Disconnected,
+                "{msg} since the peer has closed the connection.");
+        }
+
+        handle_errno!(UnixStreamReceiveError, from self,
+            success Errno::EAGAIN => 0;
+            success Errno::ETIMEDOUT => 0,
+            fatal Errno::EBADF => ("This should never happen! {msg} since the internal file descriptor was invalid.");
+            fatal Errno::EINVAL => ("This should never happen! {msg} since an internal argument was invalid."),
+            Errno::EINTR => (Interrupt, "{msg} since an interrupt signal was received."),
+            Errno::ECONNRESET => (ConnectionReset, "{msg} since the connection was reset."),
+            Errno::ENOBUFS => (InsufficientResources, "{msg} due to insufficient resources."),
+            Errno::ENOMEM => (InsufficientMemory, "{msg} due to insufficient memory."),
+            v => (UnknownError(v as i32), "{msg} since an unknown error occurred ({v}).")
+        )
+    }
+
+    /// Tries to receive data. It does not block, when no data is available it
+    /// returns `0`, otherwise it returns the number of bytes that were received.
+    /// When the peer has closed the connection and all pending data was consumed it fails
+    /// with [`UnixStreamReceiveError::Disconnected`].
+    pub fn try_receive(&self, buf: &mut [u8]) -> Result<usize, UnixStreamReceiveError> {
+        let msg = "Unable to try receiving data";
+        fail!(from self, when self.handle.set_non_blocking(true),
+            "{msg} since the socket could not be set into non-blocking mode.");
+        self.receive_impl(msg, buf)
+    }
+
+    /// Blocks until either the timeout has passed or until data could be received.
+    /// If the timeout passed it returns `0`, otherwise the number of bytes that were received.
+    pub fn timed_receive(
+        &self,
+        buf: &mut [u8],
+        timeout: Duration,
+    ) -> Result<usize, UnixStreamReceiveError> {
+        let msg = "Unable to receive data with a timeout";
+        fail!(from self, when self.handle.set_non_blocking(false),
+            "{msg} ({timeout:?}) since the socket could not be set into blocking mode.");
+        fail!(from self, when self.set_receive_timeout(timeout),
+            "{msg} ({timeout:?}) since the socket receive timeout could not be set.");
+        self.receive_impl(msg, buf)
+    }
+
+    /// Blocks until data could be received.
+    /// Despite the name, the function may not block indefinitely and spurious wakeups can cause
+    /// to return `0` when no data could be received.
+    pub fn blocking_receive(&self, buf: &mut [u8]) -> Result<usize, UnixStreamReceiveError> {
+        let msg = "Unable to receive data with blocking behavior";
+        fail!(from self, when self.handle.set_non_blocking(false),
+            "{msg} since the socket could not be set into blocking mode.");
+        fail!(from self, when self.set_receive_timeout(BLOCKING_TIMEOUT),
+            "{msg} since the socket blocking receive timeout could not be set.");
+        self.receive_impl(msg, buf)
+    }
+
+    /// Closes the provided direction of the connection while the socket itself stays valid.
+    pub fn shutdown(&self, how: UnixStreamShutdown) -> Result<(), UnixStreamShutdownError> {
+        let how_value = match how {
+            UnixStreamShutdown::Read => posix::SHUT_RD,
+            UnixStreamShutdown::Write => posix::SHUT_WR,
+            UnixStreamShutdown::Both => posix::SHUT_RDWR,
+        };
+
+        if unsafe { posix::shutdown(self.handle.file_descriptor.native_handle(), how_value) } == 0 {
+            trace!(from self, "shutdown {:?}", how);
+            return Ok(());
+        }
+
+        let msg = format!("Unable to shutdown {how:?}");
+        handle_errno!(UnixStreamShutdownError, from self,
+            fatal Errno::EBADF => ("This should never happen! {} since the internal file descriptor was invalid.", msg);
+            fatal Errno::EINVAL => ("This should never happen! {} since an internal argument was invalid.", msg),
+            Errno::ENOTCONN => (NotConnected, "{} since the socket is not connected.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+}
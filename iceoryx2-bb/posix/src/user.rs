@@ -31,11 +31,13 @@
 use core::ffi::CStr;
 use core::fmt::Display;
 
-use crate::group::Gid;
+use crate::group::{Gid, Group, GroupError};
 use crate::handle_errno;
+use crate::mutex::*;
 use crate::{config::PASSWD_BUFFER_SIZE, system_configuration::*};
 use iceoryx2_bb_container::semantic_string::*;
-use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use lazy_static::lazy_static;
 
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_system_types::file_path::FilePath;
@@ -59,6 +61,8 @@ enum_gen! { UserError
     ConfigPathIsTooLong,
     SystemUserNameLengthLongerThanSupportedLength,
     UserIdOutOfRange,
+    NotOwnerOfCurrentProcess,
+    UnableToResolveGroup,
     UnknownError(i32)
 }
 
@@ -283,6 +287,59 @@ impl User {
         self.details.as_ref()
     }
 
+    /// Returns the supplementary [`Group`]s of the user. They are a property of the process
+    /// credentials and are therefore only available for the owner of the current process, for
+    /// any other user [`UserError::NotOwnerOfCurrentProcess`] is returned. Depending on the
+    /// platform the primary group can be contained as well. Groups without an entry in the
+    /// group database are returned without
+    /// [`GroupDetails`](crate::group::GroupDetails).
+    pub fn supplementary_groups(&self) -> Result<Vec<Group>, UserError> {
+        let msg = "Unable to acquire supplementary groups";
+        let origin = format!("User::supplementary_groups({})", self.uid);
+        if self.uid.to_native() != unsafe { posix::getuid() } {
+            fail!(from origin, with UserError::NotOwnerOfCurrentProcess,
+                "{} since the user is not the owner of the current process.", msg);
+        }
+
+        let mut groups = vec![];
+        for gid in Self::supplementary_group_ids(msg, &origin)? {
+            match Group::from_gid(gid) {
+                Ok(group) => groups.push(group),
+                Err(GroupError::GroupNotFound) => groups.push(Group::without_details(gid)),
+                Err(e) => {
+                    fail!(from origin, with UserError::UnableToResolveGroup,
+                        "{} since the group {} could not be resolved ({:?}).", msg, gid, e);
+                }
+            }
+        }
+
+        Ok(groups)
+    }
+
+    fn supplementary_group_ids(msg: &str, origin: &str) -> Result<Vec<Gid>, UserError> {
+        loop {
+            let number_of_groups = unsafe { posix::getgroups(0, core::ptr::null_mut()) };
+            if number_of_groups >= 0 {
+                let mut gids: Vec<posix::gid_t> = vec![0; number_of_groups as usize];
+                let number_of_groups =
+                    unsafe { posix::getgroups(number_of_groups, gids.as_mut_ptr()) };
+                if number_of_groups >= 0 {
+                    gids.truncate(number_of_groups as usize);
+                    return Ok(gids.into_iter().map(Gid::new_from_native).collect());
+                }
+            }
+
+            match Errno::get() {
+                // the supplementary groups were changed between both calls
+                Errno::EINVAL => continue,
+                v => {
+                    fail!(from origin, with UserError::UnknownError(v as i32),
+                        "{} since an unknown error occurred ({}).", msg, v);
+                }
+            }
+        }
+    }
+
     fn handle_errno(errno_value: Errno, msg: &str, origin: &str) -> Result<(), UserError> {
         handle_errno!(UserError, from origin,
             errno_source errno_value,
@@ -358,3 +415,85 @@ impl User {
         })
     }
 }
+
+/// The resolved identity of the owner of the current process, consisting of the [`User`], the
+/// primary [`Gid`] and the supplementary [`Group`]s. Resolving it requires multiple lookups in
+/// the user and group database, therefore [`ProcessIdentity::current()`] resolves it once and
+/// caches it for the lifetime of the process.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessIdentity {
+    user: User,
+    gid: Gid,
+    supplementary_groups: Vec<Group>,
+}
+
+impl ProcessIdentity {
+    /// Returns the [`ProcessIdentity`] of the current process. It is resolved on the first call
+    /// or the first call after [`ProcessIdentity::invalidate_cache()`], every other call returns
+    /// the cached identity.
+    pub fn current() -> Result<ProcessIdentity, UserError> {
+        let mut cache = Self::cache();
+        if let Some(ref identity) = *cache {
+            return Ok(identity.clone());
+        }
+
+        let identity = Self::resolve()?;
+        *cache = Some(identity.clone());
+        Ok(identity)
+    }
+
+    /// Discards the cached [`ProcessIdentity`] so that the next call of
+    /// [`ProcessIdentity::current()`] resolves it again. Required when the credentials of the
+    /// process or the user and group database were changed, for instance in tests.
+    pub fn invalidate_cache() {
+        *Self::cache() = None;
+    }
+
+    /// Returns the [`User`] that owns the process
+    pub fn user(&self) -> &User {
+        &self.user
+    }
+
+    /// Returns the primary [`Gid`] of the process
+    pub fn gid(&self) -> Gid {
+        self.gid
+    }
+
+    /// Returns the supplementary [`Group`]s of the process, see [`User::supplementary_groups()`]
+    pub fn supplementary_groups(&self) -> &[Group] {
+        &self.supplementary_groups
+    }
+
+    /// Returns true if the process is a member of the group either via its primary group or
+    /// one of its supplementary groups, otherwise false.
+    pub fn is_member_of(&self, gid: Gid) -> bool {
+        self.gid == gid || self.supplementary_groups.iter().any(|g| g.gid() == gid)
+    }
+
+    fn resolve() -> Result<ProcessIdentity, UserError> {
+        let msg = "Unable to resolve the identity of the current process";
+        let origin = "ProcessIdentity::resolve()";
+        let user = fail!(from origin, when User::from_self(),
+            "{} since the user could not be acquired.", msg);
+        let supplementary_groups = fail!(from origin, when user.supplementary_groups(),
+            "{} since the supplementary groups could not be acquired.", msg);
+
+        Ok(ProcessIdentity {
+            user,
+            gid: Gid::new_from_native(unsafe { posix::getgid() }),
+            supplementary_groups,
+        })
+    }
+
+    fn cache() -> MutexGuard<'static, Option<ProcessIdentity>> {
+        lazy_static! {
+            static ref HANDLE: MutexHandle<Option<ProcessIdentity>> = MutexHandle::new();
+            static ref MTX: Mutex<'static, 'static, Option<ProcessIdentity>> = fatal_panic!(from "ProcessIdentity::cache()",
+                when MutexBuilder::new().create(None, &HANDLE),
+                "Unable to create the process identity cache");
+        }
+
+        fatal_panic!(from "ProcessIdentity::cache()", when MTX.lock(),
+            "Unable to acquire the process identity cache")
+    }
+}
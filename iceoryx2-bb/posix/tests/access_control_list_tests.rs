@@ -0,0 +1,222 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_posix::access_control_list::*;
+use iceoryx2_bb_posix::config::*;
+use iceoryx2_bb_posix::creation_mode::*;
+use iceoryx2_bb_posix::file::*;
+use iceoryx2_bb_posix::file_descriptor::*;
+use iceoryx2_bb_posix::group::Gid;
+use iceoryx2_bb_posix::testing::create_test_directory;
+use iceoryx2_bb_posix::user::Uid;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_pal_posix::posix::POSIX_SUPPORT_ACL;
+
+fn user(value: u32) -> AclQualifier {
+    AclQualifier::User(Uid::new(value).unwrap())
+}
+
+fn group(value: u32) -> AclQualifier {
+    AclQualifier::Group(Gid::new(value).unwrap())
+}
+
+#[test]
+fn access_control_list_from_permission_contains_base_entries() {
+    let sut = AccessControlList::from_permission(
+        Permission::OWNER_READ | Permission::OWNER_WRITE | Permission::GROUP_READ,
+    );
+
+    assert_that!(sut.len(), eq 3);
+    assert_that!(sut.is_valid(), eq true);
+    assert_that!(sut.get(AclQualifier::OwningUser), eq Some(AclPermission::READ | AclPermission::WRITE));
+    assert_that!(sut.get(AclQualifier::OwningGroup), eq Some(AclPermission::READ));
+    assert_that!(sut.get(AclQualifier::Others), eq Some(AclPermission::none()));
+    assert_that!(sut.get(AclQualifier::Mask), eq None);
+}
+
+#[test]
+fn access_control_list_entries_are_stored_in_canonical_order() {
+    let mut sut = AccessControlList::new();
+    sut.set(AclQualifier::Others, AclPermission::none())
+        .unwrap();
+    sut.set(group(200), AclPermission::READ).unwrap();
+    sut.set(user(1002), AclPermission::ALL).unwrap();
+    sut.set(AclQualifier::Mask, AclPermission::ALL).unwrap();
+    sut.set(user(1001), AclPermission::READ).unwrap();
+    sut.set(AclQualifier::OwningGroup, AclPermission::READ)
+        .unwrap();
+    sut.set(AclQualifier::OwningUser, AclPermission::ALL)
+        .unwrap();
+    sut.set(user(1001), AclPermission::WRITE).unwrap();
+
+    let qualifiers: Vec<AclQualifier> = sut.entries().iter().map(|e| e.qualifier()).collect();
+    assert_that!(qualifiers, eq vec![
+        AclQualifier::OwningUser,
+        user(1001),
+        user(1002),
+        AclQualifier::OwningGroup,
+        group(200),
+        AclQualifier::Mask,
+        AclQualifier::Others
+    ]);
+    assert_that!(sut.get(user(1001)), eq Some(AclPermission::WRITE));
+}
+
+#[test]
+fn access_control_list_text_round_trip_works() {
+    let mut sut = AccessControlList::from_permission(Permission::OWNER_ALL);
+    sut.set(user(1001), AclPermission::READ | AclPermission::WRITE)
+        .unwrap();
+    sut.set(group(300), AclPermission::READ | AclPermission::EXECUTE)
+        .unwrap();
+    sut.calculate_mask().unwrap();
+
+    let text = sut.to_string();
+    assert_that!(text, eq "user::rwx\nuser:1001:rw-\ngroup::---\ngroup:300:r-x\nmask::rwx\nother::---\n");
+
+    let parsed = AccessControlList::from_text(&text).unwrap();
+    assert_that!(parsed, eq sut);
+}
+
+#[test]
+fn access_control_list_from_text_accepts_short_form_and_comments() {
+    let sut = AccessControlList::from_text("u::rw-,u:1001:r--\t# effective: r--\ng::r, m:r, o::-")
+        .unwrap();
+
+    assert_that!(sut.len(), eq 5);
+    assert_that!(sut.get(AclQualifier::OwningUser), eq Some(AclPermission::READ | AclPermission::WRITE));
+    assert_that!(sut.get(user(1001)), eq Some(AclPermission::READ));
+    assert_that!(sut.get(AclQualifier::OwningGroup), eq Some(AclPermission::READ));
+    assert_that!(sut.get(AclQualifier::Mask), eq Some(AclPermission::READ));
+    assert_that!(sut.get(AclQualifier::Others), eq Some(AclPermission::none()));
+}
+
+#[test]
+fn access_control_list_from_text_rejects_invalid_entries() {
+    for text in [
+        "user::rwz",
+        "user::rwx-",
+        "owner::rwx",
+        "user:alice:rwx",
+        "mask:100:rwx",
+        "other",
+        "user:1:2:rwx",
+    ] {
+        assert_that!(AccessControlList::from_text(text).err(), eq Some(AccessControlListCreationError::InvalidTextFormat));
+    }
+}
+
+#[test]
+fn access_control_list_set_fails_when_capacity_is_exceeded() {
+    let mut sut = AccessControlList::new();
+    for id in 0..ACL_LIST_CAPACITY {
+        sut.set(user(id), AclPermission::READ).unwrap();
+    }
+
+    assert_that!(sut.set(user(ACL_LIST_CAPACITY), AclPermission::READ).err(), eq Some(AccessControlListCreationError::EntryLimitExceeded));
+    assert_that!(sut.set(user(0), AclPermission::WRITE), is_ok);
+    assert_that!(sut.len(), eq ACL_LIST_CAPACITY as usize);
+}
+
+#[test]
+fn access_control_list_effective_permission_is_limited_by_mask() {
+    let mut sut = AccessControlList::from_permission(Permission::OWNER_ALL | Permission::GROUP_ALL);
+    sut.set(user(1001), AclPermission::ALL).unwrap();
+    assert_that!(sut.is_valid(), eq false);
+
+    sut.set(AclQualifier::Mask, AclPermission::READ).unwrap();
+    assert_that!(sut.is_valid(), eq true);
+    assert_that!(sut.effective_permission(user(1001)), eq Some(AclPermission::READ));
+    assert_that!(sut.effective_permission(AclQualifier::OwningGroup), eq Some(AclPermission::READ));
+    assert_that!(sut.effective_permission(AclQualifier::OwningUser), eq Some(AclPermission::ALL));
+    assert_that!(sut.effective_permission(user(1002)), eq None);
+
+    sut.calculate_mask().unwrap();
+    assert_that!(sut.effective_permission(user(1001)), eq Some(AclPermission::ALL));
+}
+
+#[test]
+fn access_control_list_merge_permission_keeps_named_entries() {
+    let mut sut = AccessControlList::from_permission(Permission::ALL);
+    sut.set(group(300), AclPermission::READ).unwrap();
+
+    sut.merge_permission(Permission::OWNER_READ).unwrap();
+
+    assert_that!(sut.get(AclQualifier::OwningUser), eq Some(AclPermission::READ));
+    assert_that!(sut.get(AclQualifier::OwningGroup), eq Some(AclPermission::none()));
+    assert_that!(sut.get(AclQualifier::Others), eq Some(AclPermission::none()));
+    assert_that!(sut.get(group(300)), eq Some(AclPermission::READ));
+    assert_that!(sut.get(AclQualifier::Mask), eq Some(AclPermission::READ));
+}
+
+#[test]
+fn access_control_list_can_be_applied_to_file() {
+    create_test_directory();
+    let file_name =
+        FilePath::from_path_and_file(&test_directory(), &FileName::new(b"acl_test").unwrap())
+            .unwrap();
+
+    let mut file = FileBuilder::new(&file_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .permission(Permission::OWNER_ALL)
+        .create()
+        .unwrap();
+
+    let mut sut =
+        AccessControlList::from_permission(Permission::OWNER_READ | Permission::OWNER_WRITE);
+    sut.set(user(54321), AclPermission::READ).unwrap();
+    sut.calculate_mask().unwrap();
+
+    match file.set_access_control_list(&sut) {
+        Ok(()) => {
+            assert_that!(file.access_control_list().unwrap(), eq sut);
+            assert_that!(file.permission().unwrap(), eq Permission::OWNER_READ | Permission::OWNER_WRITE | Permission::GROUP_READ);
+        }
+        // the file system of the test directory may not support access control lists
+        Err(e) => assert_that!(e, eq AccessControlListApplyError::Unsupported),
+    }
+
+    if !POSIX_SUPPORT_ACL {
+        assert_that!(file.access_control_list().err(), eq Some(AccessControlListAcquireError::Unsupported));
+    }
+
+    File::remove_self(file).unwrap();
+}
+
+#[test]
+fn access_control_list_with_missing_base_entries_cannot_be_applied() {
+    create_test_directory();
+    let file_name =
+        FilePath::from_path_and_file(&test_directory(), &FileName::new(b"acl_test_2").unwrap())
+            .unwrap();
+
+    let mut file = FileBuilder::new(&file_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+
+    let mut sut = AccessControlList::new();
+    sut.set(AclQualifier::OwningUser, AclPermission::ALL)
+        .unwrap();
+
+    let result = file.set_access_control_list(&sut);
+    if POSIX_SUPPORT_ACL {
+        assert_that!(result.err(), eq Some(AccessControlListApplyError::InvalidAccessControlList));
+    } else {
+        assert_that!(result.err(), eq Some(AccessControlListApplyError::Unsupported));
+    }
+
+    File::remove_self(file).unwrap();
+}
@@ -0,0 +1,173 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_container::semantic_string::*;
+use iceoryx2_bb_elementary::math::ToB64;
+use iceoryx2_bb_posix::anonymous_shared_memory::*;
+use iceoryx2_bb_posix::config::test_directory;
+use iceoryx2_bb_posix::creation_mode::CreationMode;
+use iceoryx2_bb_posix::file_descriptor::*;
+use iceoryx2_bb_posix::shared_memory::SharedMemory;
+use iceoryx2_bb_posix::testing::create_test_directory;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_posix::unix_datagram_socket::*;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_bb_testing::{assert_that, test_requires};
+use iceoryx2_pal_posix::posix::POSIX_SUPPORT_ANONYMOUS_SHARED_MEMORY;
+
+fn generate_name() -> FileName {
+    let mut file_name = FileName::new(b"anonymous_shared_memory_tests_").unwrap();
+    file_name
+        .push_bytes(UniqueSystemId::new().unwrap().value().to_b64().as_bytes())
+        .unwrap();
+    file_name
+}
+
+#[test]
+fn anonymous_shared_memory_create_works() {
+    test_requires!(POSIX_SUPPORT_ANONYMOUS_SHARED_MEMORY);
+
+    let sut = AnonymousSharedMemoryBuilder::new(&generate_name())
+        .size(1024)
+        .create()
+        .unwrap();
+
+    assert_that!(sut.size(), eq 1024);
+    assert_that!(sut.is_sealed(), eq false);
+    for e in sut.as_slice() {
+        assert_that!(*e, eq 0);
+    }
+}
+
+#[test]
+fn anonymous_shared_memory_with_size_zero_fails() {
+    test_requires!(POSIX_SUPPORT_ANONYMOUS_SHARED_MEMORY);
+
+    let sut = AnonymousSharedMemoryBuilder::new(&generate_name()).create();
+
+    assert_that!(sut.err(), eq Some(AnonymousSharedMemoryCreationError::UnsupportedSizeOfZero));
+}
+
+#[test]
+fn anonymous_shared_memory_is_not_listed_as_shared_memory() {
+    test_requires!(POSIX_SUPPORT_ANONYMOUS_SHARED_MEMORY);
+
+    let name = generate_name();
+    let _sut = AnonymousSharedMemoryBuilder::new(&name)
+        .size(1024)
+        .create()
+        .unwrap();
+
+    assert_that!(SharedMemory::does_exist(&name), eq false);
+    assert_that!(SharedMemory::list().contains(&name), eq false);
+}
+
+#[test]
+fn anonymous_shared_memory_from_file_descriptor_maps_same_memory() {
+    test_requires!(POSIX_SUPPORT_ANONYMOUS_SHARED_MEMORY);
+
+    let mut sut = AnonymousSharedMemoryBuilder::new(&generate_name())
+        .size(4096)
+        .create()
+        .unwrap();
+
+    let sut_view = AnonymousSharedMemory::from_file_descriptor(
+        sut.file_descriptor().clone(),
+        AccessMode::Read,
+    )
+    .unwrap();
+
+    assert_that!(sut_view.size(), eq sut.size());
+    assert_that!(sut_view.base_address(), ne sut.base_address());
+
+    for (i, e) in sut.as_mut_slice().iter_mut().enumerate() {
+        *e = (i % 255) as u8;
+    }
+
+    for (i, e) in sut_view.as_slice().iter().enumerate() {
+        assert_that!(*e, eq(i % 255) as u8);
+    }
+}
+
+#[test]
+fn anonymous_shared_memory_sealed_size_cannot_be_changed() {
+    test_requires!(POSIX_SUPPORT_ANONYMOUS_SHARED_MEMORY);
+
+    let mut sut = AnonymousSharedMemoryBuilder::new(&generate_name())
+        .size(1024)
+        .is_sealed(true)
+        .create()
+        .unwrap();
+
+    assert_that!(sut.is_sealed(), eq true);
+    assert_that!(sut.truncate(2048), is_err);
+    assert_that!(sut.truncate(512), is_err);
+    assert_that!(sut.metadata().unwrap().size(), eq 1024);
+}
+
+#[test]
+fn anonymous_shared_memory_unsealed_size_can_be_changed() {
+    test_requires!(POSIX_SUPPORT_ANONYMOUS_SHARED_MEMORY);
+
+    let mut sut = AnonymousSharedMemoryBuilder::new(&generate_name())
+        .size(1024)
+        .create()
+        .unwrap();
+
+    assert_that!(sut.truncate(2048), is_ok);
+    assert_that!(sut.metadata().unwrap().size(), eq 2048);
+}
+
+#[test]
+fn anonymous_shared_memory_can_be_sent_via_unix_datagram_socket() {
+    test_requires!(POSIX_SUPPORT_ANONYMOUS_SHARED_MEMORY);
+
+    create_test_directory();
+    let mut socket_name = FileName::new(b"anonymous_shared_memory_tests_socket_").unwrap();
+    socket_name
+        .push_bytes(UniqueSystemId::new().unwrap().value().to_b64().as_bytes())
+        .unwrap();
+    let socket_name = FilePath::from_path_and_file(&test_directory(), &socket_name).unwrap();
+
+    let receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+    let sender = UnixDatagramSenderBuilder::new(&socket_name)
+        .create()
+        .unwrap();
+
+    let mut sut = AnonymousSharedMemoryBuilder::new(&generate_name())
+        .size(128)
+        .is_sealed(true)
+        .create()
+        .unwrap();
+    sut.as_mut_slice()[0] = 73;
+
+    assert_that!(sender.try_send_with_fds(b"shm", &[&sut]), eq Ok(true));
+    drop(sender);
+
+    let mut fds = vec![];
+    let mut buffer = [0u8; 8];
+    assert_that!(receiver.try_receive_with_fds(&mut buffer, &mut fds), eq Ok(3));
+    assert_that!(fds, len 1);
+
+    let mut sut_received =
+        AnonymousSharedMemory::from_file_descriptor(fds.remove(0), AccessMode::ReadWrite).unwrap();
+    assert_that!(sut_received.size(), eq 128);
+    assert_that!(sut_received.is_sealed(), eq true);
+    assert_that!(sut_received.as_slice()[0], eq 73);
+
+    sut_received.as_mut_slice()[1] = 37;
+    assert_that!(sut.as_slice()[1], eq 37);
+}
@@ -83,3 +83,190 @@ fn clock_time_as_timespec_works() {
     assert_that!(timespec.tv_sec, eq now.as_duration().as_secs() as _);
     assert_that!(timespec.tv_nsec, eq now.as_duration().subsec_nanos() as _);
 }
+
+#[test]
+fn clock_boottime_is_supported_when_platform_supports_it() {
+    test_requires!(Feature::MonotonicClock.is_available());
+
+    assert_that!(
+        ClockType::all_supported_clocks().contains(&ClockType::Boottime),
+        eq iceoryx2_pal_posix::posix::POSIX_SUPPORT_BOOTTIME_CLOCK
+    );
+}
+
+#[test]
+fn clock_time_now_and_nanosleep_work_with_boottime_clock() {
+    test_requires!(Feature::MonotonicClock.is_available());
+
+    let start = Time::now_with_clock(ClockType::Boottime).unwrap();
+    assert_that!(start.clock_type(), eq ClockType::Boottime);
+    assert_that!(nanosleep_with_clock(TIMEOUT, ClockType::Boottime), is_ok);
+    assert_that!(start.elapsed().unwrap(), time_at_least TIMEOUT);
+}
+
+#[test]
+fn clock_time_convert_to_same_clock_does_not_change_time() {
+    let time = TimeBuilder::new()
+        .seconds(123)
+        .nanoseconds(456)
+        .clock_type(ClockType::Realtime)
+        .create();
+
+    assert_that!(time.convert_to(ClockType::Realtime), eq Ok(time));
+}
+
+#[test]
+fn clock_time_convert_to_other_clock_preserves_elapsed_time() {
+    const TOLERANCE: Duration = Duration::from_secs(1);
+
+    for source in ClockType::all_supported_clocks() {
+        for target in ClockType::all_supported_clocks() {
+            let time = Time::now_with_clock(*source).unwrap();
+            assert_that!(nanosleep(TIMEOUT), is_ok);
+
+            let sut = time.convert_to(*target).unwrap();
+            assert_that!(sut.clock_type(), eq * target);
+
+            let elapsed = sut.elapsed().unwrap();
+            assert_that!(elapsed, time_at_least TIMEOUT);
+            assert_that!(elapsed, lt TIMEOUT + TOLERANCE);
+        }
+    }
+}
+
+#[test]
+fn clock_time_add_carries_nanoseconds_into_seconds() {
+    // (seconds, nanoseconds, added nanoseconds, expected seconds, expected nanoseconds)
+    let cases: &[(u64, u32, u64, u64, u32)] = &[
+        (0, 999_999_999, 1, 1, 0),
+        (0, 999_999_999, 2, 1, 1),
+        (7, 999_999_999, 999_999_999, 8, 999_999_998),
+        (7, 999_999_999, 1_000_000_000, 8, 999_999_999),
+        (7, 999_999_999, 1_000_000_001, 9, 0),
+        (7, 0, 999_999_999, 7, 999_999_999),
+        (u64::MAX - 1, 999_999_999, 1, u64::MAX, 0),
+    ];
+
+    for (seconds, nanoseconds, added, expected_seconds, expected_nanoseconds) in cases {
+        let time = TimeBuilder::new()
+            .seconds(*seconds)
+            .nanoseconds(*nanoseconds)
+            .create();
+        let duration = Duration::from_nanos(*added);
+
+        for sut in [
+            time + duration,
+            time.checked_add(duration).unwrap(),
+            time.saturating_add(duration),
+        ] {
+            assert_that!(sut.seconds(), eq * expected_seconds);
+            assert_that!(sut.nanoseconds(), eq * expected_nanoseconds);
+            assert_that!(sut.clock_type(), eq time.clock_type());
+        }
+
+        let mut sut = time;
+        sut += duration;
+        assert_that!(sut.seconds(), eq * expected_seconds);
+        assert_that!(sut.nanoseconds(), eq * expected_nanoseconds);
+    }
+}
+
+#[test]
+fn clock_time_sub_borrows_nanoseconds_from_seconds() {
+    // (seconds, nanoseconds, subtracted nanoseconds, expected seconds, expected nanoseconds)
+    let cases: &[(u64, u32, u64, u64, u32)] = &[
+        (1, 0, 1, 0, 999_999_999),
+        (8, 999_999_998, 999_999_999, 7, 999_999_999),
+        (9, 0, 1_000_000_001, 7, 999_999_999),
+        (7, 999_999_999, 999_999_999, 7, 0),
+        (u64::MAX, 999_999_999, 999_999_999, u64::MAX, 0),
+    ];
+
+    for (seconds, nanoseconds, subtracted, expected_seconds, expected_nanoseconds) in cases {
+        let time = TimeBuilder::new()
+            .seconds(*seconds)
+            .nanoseconds(*nanoseconds)
+            .create();
+        let duration = Duration::from_nanos(*subtracted);
+
+        for sut in [
+            time - duration,
+            time.checked_sub(duration).unwrap(),
+            time.saturating_sub(duration),
+        ] {
+            assert_that!(sut.seconds(), eq * expected_seconds);
+            assert_that!(sut.nanoseconds(), eq * expected_nanoseconds);
+        }
+
+        let mut sut = time;
+        sut -= duration;
+        assert_that!(sut.seconds(), eq * expected_seconds);
+        assert_that!(sut.nanoseconds(), eq * expected_nanoseconds);
+
+        assert_that!(sut.duration_since(time), eq Err(TimeError::NegativeDuration));
+        assert_that!(time.duration_since(sut), eq Ok(duration));
+        assert_that!(time.saturating_duration_since(sut), eq duration);
+        assert_that!(sut.saturating_duration_since(time), eq Duration::ZERO);
+    }
+}
+
+#[test]
+fn clock_time_checked_add_fails_on_overflow() {
+    let time = TimeBuilder::new()
+        .seconds(u64::MAX)
+        .nanoseconds(999_999_998)
+        .create();
+
+    assert_that!(time.checked_add(Duration::from_nanos(1)), is_some);
+    assert_that!(time.checked_add(Duration::from_nanos(2)), is_none);
+    assert_that!(Time::now().unwrap().checked_add(Duration::MAX), is_none);
+    assert_that!(
+        TimeBuilder::new().create().checked_add(Duration::MAX),
+        is_some
+    );
+}
+
+#[test]
+fn clock_time_saturating_add_saturates_on_overflow() {
+    let sut = Time::now().unwrap().saturating_add(Duration::MAX);
+
+    assert_that!(sut.seconds(), eq u64::MAX);
+    assert_that!(sut.nanoseconds(), eq 999_999_999);
+    assert_that!(sut.as_duration(), eq Duration::MAX);
+    assert_that!(sut.as_timespec().tv_sec, ge 0);
+}
+
+#[test]
+fn clock_time_checked_and_saturating_sub_handle_underflow() {
+    let time = TimeBuilder::new().seconds(1).nanoseconds(5).create();
+
+    assert_that!(time.checked_sub(Duration::new(1, 6)), is_none);
+
+    let sut = time.saturating_sub(Duration::new(1, 6));
+    assert_that!(sut.seconds(), eq 0);
+    assert_that!(sut.nanoseconds(), eq 0);
+}
+
+#[test]
+#[should_panic]
+fn clock_time_add_panics_on_overflow() {
+    let _ = Time::now().unwrap() + Duration::MAX;
+}
+
+#[test]
+fn clock_time_duration_since_fails_for_different_clock_types() {
+    let realtime = Time::now_with_clock(ClockType::Realtime).unwrap();
+    let monotonic = Time::now_with_clock(ClockType::Monotonic).unwrap();
+
+    assert_that!(realtime.duration_since(monotonic), eq Err(TimeError::ClockTypeMismatch));
+}
+
+#[test]
+fn clock_time_elapsed_fails_when_time_lies_in_the_future() {
+    let sut = TimeBuilder::new()
+        .clock_type(ClockType::Monotonic)
+        .seconds(u64::MAX)
+        .create();
+
+    assert_that!(sut.elapsed(), eq Err(TimeError::NegativeDuration));
+}
@@ -16,15 +16,18 @@ use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_posix::config::*;
 use iceoryx2_bb_posix::directory::*;
 use iceoryx2_bb_posix::file::*;
-use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptorBased, FileDescriptorManagement};
 use iceoryx2_bb_posix::file_type::*;
 use iceoryx2_bb_posix::testing::create_test_directory;
-use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_posix::unique_system_id::{UniqueFileNameExt, UniqueSystemId};
+use iceoryx2_bb_posix::unix_datagram_socket::*;
+use iceoryx2_bb_posix::user::User;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_bb_testing::assert_that;
 use iceoryx2_bb_testing::test_fail;
+use iceoryx2_bb_testing::test_requires;
 use iceoryx2_bb_testing::watchdog::Watchdog;
 use iceoryx2_pal_configuration::PATH_SEPARATOR;
 
@@ -140,6 +143,25 @@ fn directory_file_is_not_a_directory() {
     File::remove(&not_a_directory_path).unwrap();
 }
 
+#[test]
+fn directory_socket_is_not_a_directory() {
+    create_test_directory();
+
+    // S_IFSOCK shares its bits with S_IFDIR, the file type must be compared as a whole
+    let socket_name = FileName::new(b"socket_is_not_a_directory")
+        .unwrap()
+        .with_unique_suffix()
+        .unwrap();
+    let socket_path = FilePath::from_path_and_file(&test_directory(), &socket_name).unwrap();
+
+    let _receiver = UnixDatagramReceiverBuilder::new(&socket_path)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+
+    assert_that!(Directory::does_exist(&socket_path.into()).unwrap(), eq false);
+}
+
 #[test]
 fn directory_create_from_path_works() {
     let mut test = TestFixture::new();
@@ -219,6 +241,63 @@ fn directory_create_from_path_is_thread_safe() {
     assert_that!(Directory::does_exist(&sut_name).unwrap(), eq true);
 }
 
+#[test]
+fn directory_create_with_parents_creates_all_missing_levels() {
+    let mut test = TestFixture::new();
+
+    create_test_directory();
+    let mut sut_name = test.generate_path_in_test_directory();
+    sut_name
+        .add_path_entry(&Path::new(b"some").unwrap())
+        .unwrap();
+    sut_name
+        .add_path_entry(&Path::new(b"nested").unwrap())
+        .unwrap();
+
+    assert_that!(Directory::does_exist(&sut_name).unwrap(), eq false);
+    assert_that!(
+        Directory::create_with_parents(&sut_name, Permission::OWNER_ALL),
+        is_ok
+    );
+    assert_that!(Directory::does_exist(&sut_name).unwrap(), eq true);
+}
+
+#[test]
+fn directory_create_with_parents_succeeds_when_directory_exists() {
+    let mut test = TestFixture::new();
+
+    create_test_directory();
+    let sut_name = test.generate_path_in_test_directory();
+
+    assert_that!(
+        Directory::create_with_parents(&sut_name, Permission::OWNER_ALL),
+        is_ok
+    );
+    assert_that!(
+        Directory::create_with_parents(&sut_name, Permission::OWNER_ALL),
+        is_ok
+    );
+    assert_that!(Directory::does_exist(&sut_name).unwrap(), eq true);
+}
+
+#[test]
+fn directory_create_with_parents_fails_when_component_is_a_file() {
+    let mut test = TestFixture::new();
+
+    create_test_directory();
+    let directory = test.generate_path_in_test_directory();
+    Directory::create(&directory, Permission::OWNER_ALL).unwrap();
+    let file = test.create_test_file_at_path(&directory);
+
+    let mut sut_name: Path = file.path().unwrap().clone().into();
+    sut_name
+        .add_path_entry(&Path::new(b"below_a_file").unwrap())
+        .unwrap();
+
+    assert_that!(Directory::create_with_parents(&sut_name, Permission::OWNER_ALL), eq Err(DirectoryCreateError::PartsOfThePathAreNotADirectory));
+    assert_that!(Directory::create_with_parents(&file.path().unwrap().clone().into(), Permission::OWNER_ALL), eq Err(DirectoryCreateError::PartsOfThePathAreNotADirectory));
+}
+
 #[test]
 fn directory_open_from_path_works() {
     let mut test = TestFixture::new();
@@ -288,3 +367,261 @@ fn directory_list_contents_works() {
         }
     }
 }
+
+fn generate_unregistered_path_in_test_directory() -> Path {
+    let mut directory = test_directory();
+    directory
+        .add_path_entry(
+            &FileName::new(b"dir_tests_")
+                .unwrap()
+                .with_unique_suffix()
+                .unwrap()
+                .into(),
+        )
+        .unwrap();
+    directory
+}
+
+fn create_file_in(directory: &Path, name: &[u8]) -> FilePath {
+    let file = FilePath::from_path_and_file(directory, &FileName::new(name).unwrap()).unwrap();
+    FileBuilder::new(&file)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+    file
+}
+
+fn sub_directory(directory: &Path, name: &[u8]) -> Path {
+    let mut sub_directory = directory.clone();
+    sub_directory
+        .add_path_entry(&Path::new(name).unwrap())
+        .unwrap();
+    sub_directory
+}
+
+#[test]
+fn directory_remove_recursive_rejects_root_and_empty_path() {
+    let result = Directory::remove_recursive(&Path::new_root_path());
+    assert_that!(result.err().unwrap().error(), eq DirectoryRemoveError::PathIsRootOrEmpty);
+    let result = Directory::remove_recursive(&Path::new_empty());
+    assert_that!(result.err().unwrap().error(), eq DirectoryRemoveError::PathIsRootOrEmpty);
+}
+
+#[test]
+fn directory_remove_recursive_removes_nested_tree() {
+    create_test_directory();
+    let sut_name = generate_unregistered_path_in_test_directory();
+    let level_1 = sub_directory(&sut_name, b"level_1");
+    let level_2 = sub_directory(&level_1, b"level_2");
+    Directory::create(&level_2, Permission::OWNER_ALL).unwrap();
+    create_file_in(&sut_name, b"file_0");
+    create_file_in(&level_1, b"file_1");
+    create_file_in(&level_2, b"file_2a");
+    create_file_in(&level_2, b"file_2b");
+
+    let stats = Directory::remove_recursive(&sut_name).unwrap();
+
+    assert_that!(stats.files(), eq 4);
+    assert_that!(stats.symbolic_links(), eq 0);
+    assert_that!(stats.directories(), eq 3);
+    assert_that!(stats.total(), eq 7);
+    assert_that!(Directory::does_exist(&sut_name).unwrap(), eq false);
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+fn directory_remove_recursive_removes_symbolic_links_without_following_them() {
+    create_test_directory();
+    let outside = generate_unregistered_path_in_test_directory();
+    Directory::create(&outside, Permission::OWNER_ALL).unwrap();
+    let outside_file = create_file_in(&outside, b"must_survive");
+
+    let sut_name = generate_unregistered_path_in_test_directory();
+    Directory::create(&sut_name, Permission::OWNER_ALL).unwrap();
+    std::os::unix::fs::symlink(
+        outside.to_string(),
+        sub_directory(&sut_name, b"link_to_outside").to_string(),
+    )
+    .unwrap();
+    std::os::unix::fs::symlink(
+        sub_directory(&outside, b"does_not_exist").to_string(),
+        sub_directory(&sut_name, b"dangling_link").to_string(),
+    )
+    .unwrap();
+
+    let stats = Directory::remove_recursive(&sut_name).unwrap();
+
+    assert_that!(stats.files(), eq 0);
+    assert_that!(stats.symbolic_links(), eq 2);
+    assert_that!(stats.directories(), eq 1);
+    assert_that!(Directory::does_exist(&sut_name).unwrap(), eq false);
+    assert_that!(File::does_exist(&outside_file).unwrap(), eq true);
+
+    Directory::remove(&outside).unwrap();
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+fn directory_remove_recursive_does_not_follow_symbolic_link_as_root_of_the_tree() {
+    create_test_directory();
+    let target = generate_unregistered_path_in_test_directory();
+    Directory::create(&target, Permission::OWNER_ALL).unwrap();
+    let target_file = create_file_in(&target, b"must_survive");
+
+    let sut_name = generate_unregistered_path_in_test_directory();
+    std::os::unix::fs::symlink(target.to_string(), sut_name.to_string()).unwrap();
+
+    let result = Directory::remove_recursive(&sut_name);
+    assert_that!(result.err().unwrap().error(), eq DirectoryRemoveError::NotADirectory);
+    assert_that!(File::does_exist(&target_file).unwrap(), eq true);
+
+    File::remove(&FilePath::new(sut_name.as_bytes()).unwrap()).unwrap();
+    Directory::remove(&target).unwrap();
+}
+
+#[test]
+fn directory_remove_recursive_reports_entries_that_cannot_be_removed() {
+    // the permissions are not enforced for root
+    test_requires!(User::from_self().unwrap().uid().value() != 0);
+
+    create_test_directory();
+    let sut_name = generate_unregistered_path_in_test_directory();
+    let locked = sub_directory(&sut_name, b"locked");
+    let removable = sub_directory(&locked, b"removable");
+    Directory::create(&removable, Permission::OWNER_ALL).unwrap();
+    create_file_in(&removable, b"file_1");
+    create_file_in(&removable, b"file_2");
+    Directory::new(&locked)
+        .unwrap()
+        .set_permission(Permission::OWNER_READ | Permission::OWNER_EXEC)
+        .unwrap();
+
+    let result = Directory::remove_recursive(&sut_name);
+
+    let error = result.err().unwrap();
+    assert_that!(error.error(), eq DirectoryRemoveError::InsufficientPermissions);
+    // the files inside of the sub directory can be removed, the sub directory itself not
+    assert_that!(error.removed().files(), eq 2);
+    assert_that!(error.removed().directories(), eq 0);
+    assert_that!(Directory::does_exist(&removable).unwrap(), eq true);
+
+    Directory::new(&locked)
+        .unwrap()
+        .set_permission(Permission::OWNER_ALL)
+        .unwrap();
+    Directory::remove(&sut_name).unwrap();
+}
+
+#[test]
+fn directory_iter_yields_all_entries_with_file_type() {
+    create_test_directory();
+    let sut_name = generate_unregistered_path_in_test_directory();
+    let sut = Directory::create(&sut_name, Permission::OWNER_ALL).unwrap();
+    Directory::create(&sub_directory(&sut_name, b"dir_a"), Permission::OWNER_ALL).unwrap();
+    Directory::create(&sub_directory(&sut_name, b"dir_b"), Permission::OWNER_ALL).unwrap();
+    create_file_in(&sut_name, b"file_a");
+
+    let mut entries = vec![];
+    for entry in sut.iter().unwrap() {
+        let entry = entry.unwrap();
+        entries.push((entry.name().to_string(), entry.file_type()));
+    }
+    entries.sort_by(|lhs, rhs| lhs.0.cmp(&rhs.0));
+
+    assert_that!(entries, eq vec![
+        ("dir_a".to_string(), FileType::Directory),
+        ("dir_b".to_string(), FileType::Directory),
+        ("file_a".to_string(), FileType::File),
+    ]);
+
+    Directory::remove(&sut_name).unwrap();
+}
+
+#[test]
+fn directory_iter_on_empty_directory_yields_nothing() {
+    create_test_directory();
+    let sut_name = generate_unregistered_path_in_test_directory();
+    let sut = Directory::create(&sut_name, Permission::OWNER_ALL).unwrap();
+
+    assert_that!(sut.iter().unwrap().count(), eq 0);
+
+    Directory::remove(&sut_name).unwrap();
+}
+
+#[test]
+fn directory_iter_with_prefix_yields_only_matching_entries() {
+    create_test_directory();
+    let sut_name = generate_unregistered_path_in_test_directory();
+    let sut = Directory::create(&sut_name, Permission::OWNER_ALL).unwrap();
+    create_file_in(&sut_name, b"service_a");
+    create_file_in(&sut_name, b"service_b");
+    create_file_in(&sut_name, b"node_a");
+    Directory::create(
+        &sub_directory(&sut_name, b"service_dir"),
+        Permission::OWNER_ALL,
+    )
+    .unwrap();
+
+    let mut names = vec![];
+    for entry in sut
+        .iter()
+        .unwrap()
+        .prefix(&FileName::new(b"service_").unwrap())
+    {
+        names.push(entry.unwrap().name().to_string());
+    }
+    names.sort();
+
+    assert_that!(names, eq vec!["service_a".to_string(), "service_b".to_string(), "service_dir".to_string()]);
+
+    Directory::remove(&sut_name).unwrap();
+}
+
+#[test]
+fn directory_iter_entry_provides_metadata() {
+    create_test_directory();
+    let sut_name = generate_unregistered_path_in_test_directory();
+    let sut = Directory::create(&sut_name, Permission::OWNER_ALL).unwrap();
+    let file = create_file_in(&sut_name, b"file_with_metadata");
+    FileBuilder::new(&file)
+        .open_existing(AccessMode::Write)
+        .unwrap()
+        .write(b"hello")
+        .unwrap();
+
+    let entry = sut.iter().unwrap().next().unwrap().unwrap();
+    let metadata = entry.metadata().unwrap();
+
+    assert_that!(metadata.file_type(), eq FileType::File);
+    assert_that!(metadata.size(), eq 5);
+
+    Directory::remove(&sut_name).unwrap();
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+fn directory_iter_reports_symbolic_links_without_following_them() {
+    create_test_directory();
+    let sut_name = generate_unregistered_path_in_test_directory();
+    let sut = Directory::create(&sut_name, Permission::OWNER_ALL).unwrap();
+    Directory::create(&sub_directory(&sut_name, b"target"), Permission::OWNER_ALL).unwrap();
+    std::os::unix::fs::symlink(
+        sub_directory(&sut_name, b"target").to_string(),
+        sub_directory(&sut_name, b"link").to_string(),
+    )
+    .unwrap();
+
+    let mut entries = vec![];
+    for entry in sut.iter().unwrap() {
+        let entry = entry.unwrap();
+        entries.push((entry.name().to_string(), entry.file_type()));
+    }
+    entries.sort_by(|lhs, rhs| lhs.0.cmp(&rhs.0));
+
+    assert_that!(entries, eq vec![
+        ("link".to_string(), FileType::SymbolicLink),
+        ("target".to_string(), FileType::Directory),
+    ]);
+
+    Directory::remove(&sut_name).unwrap();
+}
@@ -10,10 +10,11 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::mem::ManuallyDrop;
 use core::time::Duration;
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_posix::config::*;
-use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
 use iceoryx2_bb_posix::file_descriptor_set::*;
 use iceoryx2_bb_posix::testing::create_test_directory;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
@@ -129,6 +130,31 @@ fn file_descriptor_set_add_same_fd_twice_fails() {
     assert_that!(result.err(), eq Some(FileDescriptorSetAddError::AlreadyAttached));
 }
 
+#[derive(Debug)]
+struct OutOfRangeFileDescriptor(ManuallyDrop<FileDescriptor>);
+
+impl FileDescriptorBased for OutOfRangeFileDescriptor {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.0
+    }
+}
+
+impl SynchronousMultiplexing for OutOfRangeFileDescriptor {}
+
+#[test]
+fn file_descriptor_set_add_fd_with_value_beyond_capacity_fails() {
+    let fd_set = FileDescriptorSet::new();
+    let sut = OutOfRangeFileDescriptor(ManuallyDrop::new(unsafe {
+        FileDescriptor::new_unchecked(FileDescriptorSet::capacity() as i32)
+    }));
+
+    assert_that!(fd_set.contains(&sut), eq false);
+
+    let result = fd_set.add(&sut);
+    assert_that!(result.err(), eq Some(FileDescriptorSetAddError::CapacityExceeded));
+    assert_that!(fd_set.is_empty(), eq true);
+}
+
 #[test]
 fn file_descriptor_set_timed_wait_works() {
     create_test_directory();
@@ -258,3 +284,44 @@ fn file_descriptor_triggering_many_returns_correct_number_of_notifications() {
     assert_that!(counter, eq number_of_fds);
     assert_that!(number_of_notifications, eq number_of_fds);
 }
+
+#[test]
+fn file_descriptor_set_writable_attachment_triggers_when_data_can_be_sent() {
+    create_test_directory();
+    let socket_name = generate_socket_name();
+
+    let sut_receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+
+    let sut_sender = UnixDatagramSenderBuilder::new(&socket_name)
+        .create()
+        .unwrap();
+
+    while sut_sender.try_send(b"fill").unwrap() {}
+
+    let fd_set = FileDescriptorSet::new();
+    let _guard = fd_set.add_writable(&sut_sender).unwrap();
+    assert_that!(fd_set.contains(&sut_sender), eq true);
+    assert_that!(fd_set.len(), eq 1);
+
+    let mut result = vec![];
+    fd_set
+        .timed_wait(TIMEOUT, FileEvent::Read, |fd| {
+            result.push(unsafe { fd.native_handle() })
+        })
+        .unwrap();
+    assert_that!(result, len 0);
+
+    let mut buffer = [0u8; 8];
+    while sut_receiver.try_receive(&mut buffer).unwrap() > 0 {}
+
+    fd_set
+        .timed_wait(TIMEOUT, FileEvent::Read, |fd| {
+            result.push(unsafe { fd.native_handle() })
+        })
+        .unwrap();
+    assert_that!(result, len 1);
+    assert_that!(result[0], eq unsafe{sut_sender.file_descriptor().native_handle()});
+}
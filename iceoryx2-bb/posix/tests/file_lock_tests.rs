@@ -24,7 +24,9 @@ use iceoryx2_bb_testing::test_requires;
 use iceoryx2_pal_posix::posix::POSIX_SUPPORT_FILE_LOCK;
 
 use core::sync::atomic::{AtomicU64, Ordering};
+use core::time::Duration;
 use std::thread;
+use std::time::Instant;
 
 fn generate_file_name() -> FilePath {
     let mut file = FileName::new(b"file_lock_tests_").unwrap();
@@ -352,3 +354,174 @@ fn file_lock_try_lock_fails_when_locked() {
     assert_that!(test.sut.read_try_lock().unwrap(), is_none);
     assert_that!(test.sut.write_try_lock().unwrap(), is_none);
 }
+
+#[test]
+fn file_lock_write_timed_lock_times_out_when_locked() {
+    test_requires!(POSIX_SUPPORT_FILE_LOCK);
+
+    const TIMEOUT: Duration = Duration::from_millis(25);
+    let handle = ReadWriteMutexHandle::new();
+    let test = TestFixture::new(&handle);
+
+    thread::scope(|s| {
+        let guard = test.sut.read_lock().expect("");
+
+        s.spawn(|| {
+            let start = Instant::now();
+            let result = test.sut.write_timed_lock(TIMEOUT).unwrap();
+            assert_that!(result, is_none);
+            assert_that!(start.elapsed(), ge TIMEOUT);
+        })
+        .join()
+        .unwrap();
+
+        drop(guard);
+    });
+
+    assert_that!(test.sut.write_timed_lock(TIMEOUT).unwrap(), is_some);
+}
+
+#[test]
+fn file_lock_read_timed_lock_succeeds_when_lock_is_released() {
+    test_requires!(POSIX_SUPPORT_FILE_LOCK);
+
+    let handle = ReadWriteMutexHandle::new();
+    let test = TestFixture::new(&handle);
+
+    thread::scope(|s| {
+        let guard = test.sut.write_lock().expect("");
+
+        let t = s.spawn(|| {
+            let result = test.sut.read_timed_lock(Duration::from_secs(10)).unwrap();
+            assert_that!(result, is_some);
+        });
+
+        thread::sleep(Duration::from_millis(10));
+        drop(guard);
+        t.join().unwrap();
+    });
+}
+
+const CHILD_PROCESS_LOCK_REQUEST: &str = "FILE_LOCK_TESTS_CHILD_PROCESS_LOCK_REQUEST";
+const LOCK_ACQUIRED: i32 = 10;
+const LOCK_DENIED: i32 = 11;
+
+// Executed by the processes spawned in try_lock_in_other_process(), it does nothing when
+// it is started as regular test.
+#[test]
+fn file_lock_try_lock_in_child_process() {
+    let request = match std::env::var(CHILD_PROCESS_LOCK_REQUEST) {
+        Ok(request) => request,
+        Err(_) => return,
+    };
+
+    let request: Vec<&str> = request.split(';').collect();
+    let file_name = FilePath::new(request[0].as_bytes()).unwrap();
+    let file = FileBuilder::new(&file_name)
+        .open_existing(AccessMode::ReadWrite)
+        .unwrap();
+    let handle = ReadWriteMutexHandle::new();
+    let sut = FileLockBuilder::new()
+        .byte_range(request[2].parse().unwrap(), request[3].parse().unwrap())
+        .create(file, &handle)
+        .unwrap();
+
+    let has_acquired_lock = match request[1] {
+        "read" => sut.read_try_lock().unwrap().is_some(),
+        _ => sut.write_try_lock().unwrap().is_some(),
+    };
+
+    std::process::exit(if has_acquired_lock {
+        LOCK_ACQUIRED
+    } else {
+        LOCK_DENIED
+    });
+}
+
+fn try_lock_in_other_process(
+    file_name: &FilePath,
+    lock_type: LockType,
+    start: u64,
+    len: u64,
+) -> bool {
+    let lock_type = match lock_type {
+        LockType::Read => "read",
+        _ => "write",
+    };
+
+    let status = std::process::Command::new(std::env::current_exe().unwrap())
+        .args([
+            "file_lock_try_lock_in_child_process",
+            "--exact",
+            "--nocapture",
+        ])
+        .env(
+            CHILD_PROCESS_LOCK_REQUEST,
+            format!("{file_name};{lock_type};{start};{len}"),
+        )
+        .status()
+        .unwrap();
+
+    match status.code() {
+        Some(LOCK_ACQUIRED) => true,
+        Some(LOCK_DENIED) => false,
+        v => panic!("The child process failed unexpectedly ({v:?})."),
+    }
+}
+
+#[test]
+fn file_lock_write_lock_blocks_other_process() {
+    test_requires!(POSIX_SUPPORT_FILE_LOCK);
+
+    let handle = ReadWriteMutexHandle::new();
+    let test = TestFixture::new(&handle);
+
+    let guard = test.sut.write_lock().unwrap();
+    assert_that!(try_lock_in_other_process(&test.file_name, LockType::Write, 0, 0), eq false);
+    assert_that!(try_lock_in_other_process(&test.file_name, LockType::Read, 0, 0), eq false);
+
+    drop(guard);
+    assert_that!(try_lock_in_other_process(&test.file_name, LockType::Write, 0, 0), eq true);
+}
+
+#[test]
+fn file_lock_read_lock_is_shared_with_other_process() {
+    test_requires!(POSIX_SUPPORT_FILE_LOCK);
+
+    let handle = ReadWriteMutexHandle::new();
+    let test = TestFixture::new(&handle);
+
+    let _guard = test.sut.read_lock().unwrap();
+    assert_that!(try_lock_in_other_process(&test.file_name, LockType::Read, 0, 0), eq true);
+    assert_that!(try_lock_in_other_process(&test.file_name, LockType::Write, 0, 0), eq false);
+}
+
+#[test]
+fn file_lock_byte_range_blocks_only_overlapping_ranges_of_other_process() {
+    test_requires!(POSIX_SUPPORT_FILE_LOCK);
+
+    create_test_directory();
+    let file_name = generate_file_name();
+    let file = FileBuilder::new(&file_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .permission(Permission::OWNER_ALL)
+        .create()
+        .unwrap();
+    let handle = ReadWriteMutexHandle::new();
+    {
+        let sut = FileLockBuilder::new()
+            .byte_range(0, 10)
+            .create(file, &handle)
+            .unwrap();
+
+        let guard = sut.write_lock().unwrap();
+        assert_that!(try_lock_in_other_process(&file_name, LockType::Write, 10, 10), eq true);
+        assert_that!(try_lock_in_other_process(&file_name, LockType::Write, 5, 10), eq false);
+        assert_that!(try_lock_in_other_process(&file_name, LockType::Read, 0, 0), eq false);
+
+        drop(guard);
+        assert_that!(try_lock_in_other_process(&file_name, LockType::Write, 5, 10), eq true);
+    }
+
+    File::remove(&file_name).unwrap();
+}
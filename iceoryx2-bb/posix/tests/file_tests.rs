@@ -265,7 +265,7 @@ fn file_read_line_works() {
 fn file_two_file_objects_read_work_with_ranges_in_same_file() {
     let test = TestFixture::new();
     let mut file_a = test.create_file(&test.file);
-    let mut file_b = test.open_file(&test.file);
+    let file_b = test.open_file(&test.file);
 
     let mut content = "hello".to_string();
     let result = file_a.write(unsafe { content.as_mut_vec() }.as_slice());
@@ -285,6 +285,86 @@ fn file_two_file_objects_read_work_with_ranges_in_same_file() {
     assert_that!("eworld", eq read_content);
 }
 
+#[test]
+fn file_read_at_does_not_modify_file_offset() {
+    let test = TestFixture::new();
+    let mut sut = test.create_file(&test.file);
+    assert_that!(sut.write(b"don't panic"), eq Ok(11));
+    assert_that!(sut.seek(0), eq Ok(0));
+
+    let mut buffer = [0u8; 5];
+    assert_that!(sut.read_at(6, &mut buffer), eq Ok(5));
+    assert_that!(&buffer, eq b"panic");
+
+    assert_that!(sut.read(&mut buffer), eq Ok(5));
+    assert_that!(&buffer, eq b"don't");
+}
+
+#[test]
+fn file_read_at_reports_partial_read_at_end_of_file() {
+    let test = TestFixture::new();
+    let mut sut = test.create_file(&test.file);
+    assert_that!(sut.write(b"towel"), eq Ok(5));
+
+    let mut buffer = [0u8; 8];
+    assert_that!(sut.read_at(2, &mut buffer), eq Ok(3));
+    assert_that!(&buffer[..3], eq b"wel");
+
+    assert_that!(sut.read_at(5, &mut buffer), eq Ok(0));
+    assert_that!(sut.read_at(128, &mut buffer), eq Ok(0));
+}
+
+#[test]
+fn file_write_at_does_not_modify_file_offset() {
+    let test = TestFixture::new();
+    let mut sut = test.create_file(&test.file);
+    assert_that!(sut.write(b"hello"), eq Ok(5));
+
+    assert_that!(sut.write_at(0, b"HE"), eq Ok(2));
+    assert_that!(sut.write(b"!"), eq Ok(1));
+
+    let mut content = String::new();
+    assert_that!(sut.read_range_to_string(0, 6, &mut content), eq Ok(6));
+    assert_that!(content, eq "HEllo!");
+}
+
+#[test]
+fn file_write_all_at_writes_whole_buffer_beyond_end_of_file() {
+    let test = TestFixture::new();
+    let sut = test.create_file(&test.file);
+
+    let content = [0xafu8; 8192];
+    assert_that!(sut.write_all_at(4, &content), is_ok);
+
+    let mut buffer = vec![0xffu8; 8196];
+    assert_that!(sut.read_at(0, &mut buffer), eq Ok(8196));
+    assert_that!(buffer[..4], eq [0u8; 4]);
+    assert_that!(buffer[4..].iter().all(|v| *v == 0xaf), eq true);
+}
+
+#[test]
+fn file_read_at_and_write_at_can_be_used_concurrently() {
+    const NUMBER_OF_THREADS: usize = 8;
+    const REGION_SIZE: usize = 256;
+    let test = TestFixture::new();
+    let sut = test.create_file(&test.file);
+
+    std::thread::scope(|s| {
+        for n in 0..NUMBER_OF_THREADS {
+            let sut = &sut;
+            s.spawn(move || {
+                let offset = (n * REGION_SIZE) as u64;
+                let content = [n as u8; REGION_SIZE];
+                assert_that!(sut.write_all_at(offset, &content), is_ok);
+
+                let mut buffer = [0u8; REGION_SIZE];
+                assert_that!(sut.read_at(offset, &mut buffer), eq Ok(REGION_SIZE as u64));
+                assert_that!(buffer, eq content);
+            });
+        }
+    });
+}
+
 #[test]
 fn file_created_file_does_exist() -> Result<(), FileError> {
     let test = TestFixture::new();
@@ -305,6 +385,41 @@ fn file_truncate_works() -> Result<(), FileError> {
     Ok(())
 }
 
+#[test]
+fn file_allocate_works() -> Result<(), FileError> {
+    const SIZE: u64 = 64 * 1024;
+    let test = TestFixture::new();
+    let mut sut = test.create_file(&test.file);
+
+    assert_that!(sut.allocate(SIZE), is_ok);
+    assert_that!(sut.metadata().unwrap().size(), eq SIZE);
+
+    let content = [0x5au8; 128];
+    assert_that!(
+        sut.write_all_at(SIZE - content.len() as u64, &content),
+        is_ok
+    );
+    assert_that!(sut.metadata().unwrap().size(), eq SIZE);
+
+    Ok(())
+}
+
+#[test]
+fn file_allocate_does_not_shrink_file() -> Result<(), FileError> {
+    const SIZE: usize = 4096;
+    let test = TestFixture::new();
+    let mut sut = test.create_file(&test.file);
+    assert_that!(sut.truncate(SIZE), is_ok);
+
+    assert_that!(sut.allocate(SIZE as u64 / 2), is_ok);
+    assert_that!(sut.metadata().unwrap().size(), eq SIZE as u64);
+
+    assert_that!(sut.allocate(0), is_ok);
+    assert_that!(sut.metadata().unwrap().size(), eq SIZE as u64);
+
+    Ok(())
+}
+
 #[test]
 fn file_non_existing_file_does_not_exist() -> Result<(), FileError> {
     let test = TestFixture::new();
@@ -67,3 +67,24 @@ fn group_as_works() {
 
     assert_that!(root_1.details().unwrap().members().len(), ge 0);
 }
+
+#[test]
+fn group_from_self_can_be_resolved_by_name() {
+    test_requires!(POSIX_SUPPORT_USERS_AND_GROUPS);
+
+    let sut = Group::from_self().unwrap();
+    let name = sut.details().unwrap().name().clone();
+
+    let group_from_name = Group::from_name(&name).unwrap();
+    assert_that!(group_from_name.gid(), eq sut.gid());
+    assert_that!(*group_from_name.details().unwrap().name(), eq name);
+}
+
+#[test]
+fn group_members_corresponds_to_details() {
+    test_requires!(POSIX_SUPPORT_USERS_AND_GROUPS);
+
+    let sut = Group::from_gid(Gid::new_from_native(0)).unwrap();
+
+    assert_that!(sut.members(), eq sut.details().unwrap().members());
+}
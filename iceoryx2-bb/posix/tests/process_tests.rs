@@ -10,10 +10,37 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use iceoryx2_bb_container::semantic_string::*;
+use iceoryx2_bb_posix::config::test_directory;
+use iceoryx2_bb_posix::creation_mode::CreationMode;
+use iceoryx2_bb_posix::file::*;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
 use iceoryx2_bb_posix::process::*;
+use iceoryx2_bb_posix::testing::create_test_directory;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_bb_testing::{assert_that, test_requires};
 use iceoryx2_pal_posix::posix::{self, POSIX_SUPPORT_SCHEDULER};
 
+fn shell() -> ProcessBuilder<'static> {
+    ProcessBuilder::new(&FilePath::new(b"/bin/sh").unwrap()).argument("-c")
+}
+
+fn generate_file_name() -> FilePath {
+    let mut file = FileName::new(b"process_tests_").unwrap();
+    file.push_bytes(
+        UniqueSystemId::new()
+            .unwrap()
+            .value()
+            .to_string()
+            .as_bytes(),
+    )
+    .unwrap();
+
+    FilePath::from_path_and_file(&test_directory(), &file).unwrap()
+}
+
 #[test]
 pub fn process_can_acquire_from_self() {
     let process = Process::from_self();
@@ -57,3 +84,174 @@ pub fn process_executable_path_works() {
     println!("{executable_file}");
     assert_that!(executable_file.starts_with("process_tests"), eq true);
 }
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+pub fn process_spawned_child_exits_successfully() {
+    let mut sut = ProcessBuilder::new(&FilePath::new(b"/bin/true").unwrap())
+        .spawn()
+        .unwrap();
+
+    let exit_status = sut.wait().unwrap();
+    assert_that!(exit_status, eq ProcessExitStatus::Exited(0));
+    assert_that!(exit_status.is_success(), eq true);
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+pub fn process_spawned_child_exit_code_is_returned() {
+    let mut sut = shell().argument("exit 42").spawn().unwrap();
+
+    assert_that!(sut.id().value(), ne Process::from_self().id().value());
+    assert_that!(sut.wait(), eq Ok(ProcessExitStatus::Exited(42)));
+    // the exit status is stored after the process was reaped
+    assert_that!(sut.try_wait(), eq Ok(Some(ProcessExitStatus::Exited(42))));
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+pub fn process_spawn_of_non_existing_executable_fails() {
+    let sut = ProcessBuilder::new(&FilePath::new(b"/this/does/not/exist").unwrap()).spawn();
+
+    assert_that!(sut.err(), eq Some(ProcessSpawnError::ExecutableDoesNotExist));
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+pub fn process_spawn_with_null_byte_in_argument_fails() {
+    let sut = shell().argument("exit\0").spawn();
+
+    assert_that!(sut.err(), eq Some(ProcessSpawnError::ContainsNullByte));
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+pub fn process_spawned_child_crash_is_reported_as_signal() {
+    let mut sut = shell().argument("kill -SEGV $$").spawn().unwrap();
+
+    let exit_status = sut.wait().unwrap();
+    assert_that!(exit_status, eq ProcessExitStatus::Signaled(posix::SIGSEGV));
+    assert_that!(exit_status.is_success(), eq false);
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+pub fn process_spawned_child_can_be_terminated() {
+    let mut sut = shell().argument("sleep 10").spawn().unwrap();
+
+    assert_that!(sut.try_wait(), eq Ok(None));
+    assert_that!(sut.terminate(), is_ok);
+    assert_that!(sut.wait(), eq Ok(ProcessExitStatus::Signaled(posix::SIGTERM)));
+    assert_that!(sut.terminate().err(), eq Some(ProcessSendSignalError::UnknownProcessId));
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+pub fn process_spawned_child_receives_arguments_environment_and_working_directory() {
+    create_test_directory();
+    let mut sut = shell()
+        .argument(r#"[ "$1" = "fuu" ] && [ "$SOME_VAR" = "bar" ] && [ "$(pwd -P)" = "$(cd "$2" && pwd -P)" ]"#)
+        .argument("sh")
+        .argument("fuu")
+        .argument(&test_directory().to_string())
+        .environment_variable("SOME_VAR", "bar")
+        .working_directory(&test_directory())
+        .spawn()
+        .unwrap();
+
+    assert_that!(sut.wait().unwrap().is_success(), eq true);
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+pub fn process_spawned_child_without_inherited_environment_has_only_explicit_variables() {
+    // cargo provides the CARGO_* variables to the test process, the shell does not set them
+    let inherited_var = std::env::vars()
+        .map(|(key, _)| key)
+        .find(|key| key.starts_with("CARGO_"));
+    test_requires!(inherited_var.is_some());
+    let inherited_var = inherited_var.unwrap();
+
+    let mut sut = shell()
+        .argument(&format!(r#"[ -n "${inherited_var}" ]"#))
+        .environment_variable("SOME_VAR", "bar")
+        .spawn()
+        .unwrap();
+    assert_that!(sut.wait().unwrap().is_success(), eq true);
+
+    let mut sut = shell()
+        .argument(&format!(
+            r#"[ -z "${inherited_var}" ] && [ "$SOME_VAR" = "bar" ]"#
+        ))
+        .inherit_environment(false)
+        .environment_variable("SOME_VAR", "bar")
+        .spawn()
+        .unwrap();
+    assert_that!(sut.wait().unwrap().is_success(), eq true);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+pub fn process_spawned_child_inherits_only_requested_file_descriptors() {
+    create_test_directory();
+    let file_name = generate_file_name();
+    let file = FileBuilder::new(&file_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+    let check = format!("[ -e /proc/self/fd/{} ]", unsafe {
+        file.file_descriptor().native_handle()
+    });
+
+    let mut sut = shell().argument(&check).spawn().unwrap();
+    assert_that!(sut.wait(), eq Ok(ProcessExitStatus::Exited(1)));
+
+    let mut sut = shell()
+        .argument(&check)
+        .inherit_file_descriptor(&file)
+        .spawn()
+        .unwrap();
+    assert_that!(sut.wait(), eq Ok(ProcessExitStatus::Exited(0)));
+
+    drop(file);
+    assert_that!(File::remove(&file_name), eq Ok(true));
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+pub fn process_spawned_child_does_not_inherit_file_descriptors_without_cloexec() {
+    create_test_directory();
+    let file_name = generate_file_name();
+    let file = FileBuilder::new(&file_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+    // dup() does not set FD_CLOEXEC on the new file descriptor
+    let fd = unsafe { posix::dup(file.file_descriptor().native_handle()) };
+    assert_that!(fd, ge 0);
+
+    let mut sut = shell()
+        .argument(&format!("[ -e /proc/self/fd/{fd} ]"))
+        .spawn()
+        .unwrap();
+    assert_that!(sut.wait(), eq Ok(ProcessExitStatus::Exited(1)));
+
+    unsafe { posix::close(fd) };
+    drop(file);
+    assert_that!(File::remove(&file_name), eq Ok(true));
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+pub fn process_spawned_child_has_default_signal_handling() {
+    // the rust runtime ignores SIGPIPE, the child inherits it when the signals are not reset
+    let mut sut = shell()
+        .argument("kill -PIPE $$")
+        .reset_signal_mask(false)
+        .spawn()
+        .unwrap();
+    assert_that!(sut.wait(), eq Ok(ProcessExitStatus::Exited(0)));
+
+    let mut sut = shell().argument("kill -PIPE $$").spawn().unwrap();
+    assert_that!(sut.wait(), eq Ok(ProcessExitStatus::Signaled(posix::SIGPIPE)));
+}
@@ -16,6 +16,7 @@ use iceoryx2_bb_posix::clock::*;
 use iceoryx2_bb_posix::read_write_mutex::*;
 use iceoryx2_bb_testing::assert_that;
 use iceoryx2_bb_testing::watchdog::Watchdog;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
 use std::sync::Barrier;
 use std::thread;
 
@@ -123,6 +124,160 @@ fn read_write_mutex_try_lock_fails_when_lock_was_acquired() {
     assert_that!(sut.write_try_lock().unwrap(), is_none);
 }
 
+#[test]
+fn read_write_mutex_timed_lock_works() {
+    let handle = ReadWriteMutexHandle::<i32>::new();
+    let sut = ReadWriteMutexBuilder::new().create(4711, &handle).unwrap();
+    {
+        let mut value = sut.write_timed_lock(TIMEOUT).unwrap();
+        assert_that!(*value, eq 4711);
+        *value = 815;
+    }
+
+    let value = sut.read_timed_lock(TIMEOUT).unwrap();
+    assert_that!(*value, eq 815);
+}
+
+#[test]
+fn read_write_mutex_timed_lock_fails_with_timeout_when_lock_was_acquired() {
+    let _watchdog = Watchdog::new();
+    let handle = ReadWriteMutexHandle::<i32>::new();
+    let sut = ReadWriteMutexBuilder::new().create(781, &handle).unwrap();
+    let barrier = Barrier::new(2);
+
+    thread::scope(|s| {
+        s.spawn(|| {
+            let guard = sut.write_blocking_lock().unwrap();
+            barrier.wait();
+            barrier.wait();
+            drop(guard);
+
+            let _guard = sut.read_blocking_lock().unwrap();
+            barrier.wait();
+            barrier.wait();
+        });
+
+        barrier.wait();
+        let start = Time::now().unwrap();
+        assert_that!(sut.read_timed_lock(TIMEOUT).err(), eq Some(ReadWriteMutexTimedReadLockError::Timeout));
+        assert_that!(start.elapsed().unwrap(), time_at_least TIMEOUT);
+        barrier.wait();
+
+        barrier.wait();
+        let start = Time::now().unwrap();
+        assert_that!(sut.write_timed_lock(TIMEOUT).err(), eq Some(ReadWriteMutexTimedWriteLockError::Timeout));
+        assert_that!(start.elapsed().unwrap(), time_at_least TIMEOUT);
+        assert_that!(sut.read_timed_lock(TIMEOUT), is_ok);
+        barrier.wait();
+    });
+}
+
+#[test]
+fn read_write_mutex_timed_lock_acquires_lock_when_released_in_time() {
+    let _watchdog = Watchdog::new();
+    let handle = ReadWriteMutexHandle::<i32>::new();
+    let sut = ReadWriteMutexBuilder::new().create(781, &handle).unwrap();
+    let barrier = Barrier::new(2);
+
+    thread::scope(|s| {
+        let guard = sut.write_blocking_lock().unwrap();
+
+        let t = s.spawn(|| {
+            barrier.wait();
+            sut.write_timed_lock(TIMEOUT * 100)
+                .map(|mut guard| *guard = 9)
+        });
+
+        barrier.wait();
+        nanosleep(TIMEOUT).unwrap();
+        drop(guard);
+
+        assert_that!(t.join().unwrap(), is_ok);
+    });
+
+    assert_that!(*sut.read_blocking_lock().unwrap(), eq 9);
+}
+
+#[test]
+fn read_write_mutex_with_writer_preference_does_not_starve_writer() {
+    const NUMBER_OF_READERS: usize = 4;
+    const MAX_WRITER_WAIT_TIME: Duration = Duration::from_secs(1);
+    let _watchdog = Watchdog::new();
+    let handle = ReadWriteMutexHandle::<i32>::new();
+    let sut = ReadWriteMutexBuilder::new()
+        .preference(ReadWriteMutexPreference::Writer)
+        .create(0, &handle)
+        .unwrap();
+    let keep_running = IoxAtomicBool::new(true);
+    let barrier = Barrier::new(NUMBER_OF_READERS + 1);
+
+    thread::scope(|s| {
+        for _ in 0..NUMBER_OF_READERS {
+            s.spawn(|| {
+                barrier.wait();
+                while keep_running.load(Ordering::Relaxed) {
+                    let _guard = sut.read_blocking_lock().unwrap();
+                    nanosleep(Duration::from_millis(1)).unwrap();
+                }
+            });
+        }
+
+        barrier.wait();
+        // the overlapping read-locks of the readers keep the mutex permanently read-locked
+        nanosleep(TIMEOUT).unwrap();
+
+        let start = Time::now().unwrap();
+        let mut guard = sut.write_blocking_lock().unwrap();
+        let writer_wait_time = start.elapsed().unwrap();
+        *guard = 1;
+        drop(guard);
+        keep_running.store(false, Ordering::Relaxed);
+
+        assert_that!(writer_wait_time, lt MAX_WRITER_WAIT_TIME);
+    });
+
+    assert_that!(*sut.read_blocking_lock().unwrap(), eq 1);
+}
+
+#[test]
+fn read_write_mutex_with_writer_preference_does_not_starve_timed_writer() {
+    const NUMBER_OF_READERS: usize = 4;
+    const MAX_WRITER_WAIT_TIME: Duration = Duration::from_secs(1);
+    let _watchdog = Watchdog::new();
+    let handle = ReadWriteMutexHandle::<i32>::new();
+    let sut = ReadWriteMutexBuilder::new()
+        .preference(ReadWriteMutexPreference::Writer)
+        .create(0, &handle)
+        .unwrap();
+    let keep_running = IoxAtomicBool::new(true);
+    let barrier = Barrier::new(NUMBER_OF_READERS + 1);
+
+    thread::scope(|s| {
+        for _ in 0..NUMBER_OF_READERS {
+            s.spawn(|| {
+                barrier.wait();
+                while keep_running.load(Ordering::Relaxed) {
+                    let _guard = sut.read_blocking_lock().unwrap();
+                    nanosleep(Duration::from_millis(1)).unwrap();
+                }
+            });
+        }
+
+        barrier.wait();
+        // the overlapping read-locks of the readers keep the mutex permanently read-locked
+        nanosleep(TIMEOUT).unwrap();
+
+        let result = sut
+            .write_timed_lock(MAX_WRITER_WAIT_TIME)
+            .map(|mut guard| *guard = 1);
+        keep_running.store(false, Ordering::Relaxed);
+
+        assert_that!(result, is_ok);
+    });
+
+    assert_that!(*sut.read_blocking_lock().unwrap(), eq 1);
+}
+
 #[test]
 fn read_write_mutex_multiple_ipc_mutex_are_working() {
     let handle = ReadWriteMutexHandle::<i32>::new();
@@ -164,6 +164,111 @@ fn semaphore_named_semaphore_opens_correctly() {
     assert_that!(sut.try_wait().unwrap(), eq false);
 }
 
+#[test]
+fn semaphore_named_semaphore_open_or_create_opens_existing_semaphore() {
+    test_requires!(POSIX_SUPPORT_NAMED_SEMAPHORE);
+
+    let sem_name = NamedSemaphoreTest::generate_name();
+    let creator = NamedSemaphoreBuilder::new(&sem_name)
+        .creation_mode(CreationMode::OpenOrCreate)
+        .initial_value(1)
+        .permission(Permission::OWNER_ALL)
+        .create()
+        .unwrap();
+
+    let sut = NamedSemaphoreBuilder::new(&sem_name)
+        .creation_mode(CreationMode::OpenOrCreate)
+        .initial_value(5)
+        .create()
+        .unwrap();
+
+    assert_that!(sut.try_wait().unwrap(), eq true);
+    assert_that!(sut.try_wait().unwrap(), eq false);
+
+    assert_that!(creator.post(), is_ok);
+    assert_that!(sut.try_wait().unwrap(), eq true);
+}
+
+#[test]
+fn semaphore_named_semaphore_create_exclusive_fails_when_semaphore_exists() {
+    test_requires!(POSIX_SUPPORT_NAMED_SEMAPHORE);
+
+    let sem_name = NamedSemaphoreTest::generate_name();
+    let _creator = NamedSemaphoreBuilder::new(&sem_name)
+        .creation_mode(CreationMode::CreateExclusive)
+        .create()
+        .unwrap();
+
+    let sut = NamedSemaphoreBuilder::new(&sem_name)
+        .creation_mode(CreationMode::CreateExclusive)
+        .create();
+
+    assert_that!(sut.err(), eq Some(NamedSemaphoreCreationError::AlreadyExists));
+}
+
+#[test]
+fn semaphore_named_semaphore_open_non_existing_semaphore_fails() {
+    test_requires!(POSIX_SUPPORT_NAMED_SEMAPHORE);
+
+    let sem_name = NamedSemaphoreTest::generate_name();
+    let sut = NamedSemaphoreBuilder::new(&sem_name).open_existing();
+
+    assert_that!(sut.err(), eq Some(NamedSemaphoreCreationError::DoesNotExist));
+}
+
+// kernel semaphores on windows are removed when the last handle is closed
+#[cfg(not(target_os = "windows"))]
+#[test]
+fn semaphore_named_semaphore_remove_works() {
+    test_requires!(POSIX_SUPPORT_NAMED_SEMAPHORE);
+
+    let sem_name = NamedSemaphoreTest::generate_name();
+    let creator = NamedSemaphoreBuilder::new(&sem_name)
+        .creation_mode(CreationMode::CreateExclusive)
+        .create()
+        .unwrap();
+
+    assert_that!(NamedSemaphore::remove(&sem_name), eq Ok(true));
+    assert_that!(NamedSemaphore::remove(&sem_name), eq Ok(false));
+
+    let sut = NamedSemaphoreBuilder::new(&sem_name).open_existing();
+    assert_that!(sut.err(), eq Some(NamedSemaphoreCreationError::DoesNotExist));
+
+    // the creator can still use the removed semaphore
+    assert_that!(creator.post(), is_ok);
+    assert_that!(creator.try_wait().unwrap(), eq true);
+}
+
+#[test]
+fn semaphore_named_semaphore_can_be_opened_and_triggered_from_another_thread() {
+    test_requires!(POSIX_SUPPORT_NAMED_SEMAPHORE);
+    const NUMBER_OF_POSTS: usize = 10;
+
+    let _watchdog = Watchdog::new();
+    let sem_name = NamedSemaphoreTest::generate_name();
+    let sut = NamedSemaphoreBuilder::new(&sem_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+
+    thread::scope(|s| {
+        s.spawn(|| {
+            let sut = NamedSemaphoreBuilder::new(&sem_name)
+                .open_existing()
+                .unwrap();
+            for _ in 0..NUMBER_OF_POSTS {
+                sut.post().unwrap();
+            }
+        });
+
+        for _ in 0..NUMBER_OF_POSTS {
+            assert_that!(sut.blocking_wait(), is_ok);
+        }
+    });
+
+    assert_that!(sut.try_wait().unwrap(), eq false);
+}
+
 #[test]
 fn semaphore_unnamed_semaphore_initializes_correctly() {
     let sut_handle = UnnamedSemaphoreHandle::new();
@@ -12,10 +12,13 @@
 
 use iceoryx2_bb_container::semantic_string::*;
 use iceoryx2_bb_elementary::math::ToB64;
+use iceoryx2_bb_posix::system_configuration::SystemInfo;
 use iceoryx2_bb_posix::{shared_memory::*, unique_system_id::UniqueSystemId};
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_testing::{assert_that, test_requires};
+use iceoryx2_pal_posix::posix::POSIX_SUPPORT_HUGE_PAGES;
 use iceoryx2_pal_posix::posix::POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY;
+use std::sync::Barrier;
 
 fn generate_shm_name() -> FileName {
     let mut file_name = FileName::new(b"shared_memory_tests_").unwrap();
@@ -274,3 +277,190 @@ fn shared_memory_can_be_mapped_with_a_custom_offset() {
 
     assert_that!(sut.mapping_offset(), eq MAPPING_OFFSET);
 }
+
+#[test]
+fn shared_memory_without_huge_pages_uses_default_page_size() {
+    let shm_name = generate_shm_name();
+    let sut_create = SharedMemoryBuilder::new(&shm_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .create()
+        .unwrap();
+
+    let sut_open = SharedMemoryBuilder::new(&shm_name)
+        .open_existing(AccessMode::Read)
+        .unwrap();
+
+    assert_that!(sut_create.page_size(), eq SystemInfo::PageSize.value());
+    assert_that!(sut_open.page_size(), eq SystemInfo::PageSize.value());
+}
+
+#[test]
+fn shared_memory_try_huge_pages_falls_back_to_default_page_size() {
+    const HUGE_PAGE_SIZE: usize = 2 * 1024 * 1024;
+    let shm_name = generate_shm_name();
+    let mut sut_create = SharedMemoryBuilder::new(&shm_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .huge_pages(HugePagePolicy::TryHuge2MB)
+        .create()
+        .unwrap();
+
+    let sut_open = SharedMemoryBuilder::new(&shm_name)
+        .open_existing(AccessMode::Read)
+        .unwrap();
+
+    assert_that!(sut_create.page_size(), any_of [HUGE_PAGE_SIZE, SystemInfo::PageSize.value()]);
+    assert_that!(sut_open.page_size(), eq sut_create.page_size());
+    assert_that!(sut_create.size(), ge 1024);
+
+    sut_create.as_mut_slice()[0] = 123;
+    assert_that!(sut_open.as_slice()[0], eq 123);
+}
+
+#[test]
+fn shared_memory_required_huge_pages_are_used_or_creation_fails() {
+    for (policy, page_size) in [
+        (HugePagePolicy::Require2MB, 2 * 1024 * 1024),
+        (HugePagePolicy::Require1GB, 1024 * 1024 * 1024),
+    ] {
+        let shm_name = generate_shm_name();
+        let sut = SharedMemoryBuilder::new(&shm_name)
+            .creation_mode(CreationMode::PurgeAndCreate)
+            .size(1024)
+            .huge_pages(policy)
+            .create();
+
+        match sut {
+            Ok(sut) => {
+                assert_that!(POSIX_SUPPORT_HUGE_PAGES, eq true);
+                assert_that!(sut.page_size(), eq page_size);
+            }
+            Err(e) => {
+                assert_that!(e, any_of [SharedMemoryCreationError::HugePagesNotSupported,
+                                        SharedMemoryCreationError::InsufficientHugePages]);
+                assert_that!(SharedMemory::does_exist(&shm_name), eq false);
+                if !POSIX_SUPPORT_HUGE_PAGES {
+                    assert_that!(e, eq SharedMemoryCreationError::HugePagesNotSupported);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn shared_memory_with_huge_pages_is_listed_and_removed_by_its_name() {
+    let shm_name = generate_shm_name();
+    let sut = SharedMemoryBuilder::new(&shm_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .huge_pages(HugePagePolicy::TryHuge2MB)
+        .create()
+        .unwrap();
+    sut.release_ownership();
+
+    assert_that!(SharedMemory::list(), contains shm_name.clone());
+    assert_that!(SharedMemory::does_exist(&shm_name), eq true);
+    assert_that!(SharedMemory::remove(&shm_name), eq Ok(true));
+    assert_that!(SharedMemory::does_exist(&shm_name), eq false);
+    assert_that!(
+        SharedMemory::list(),
+        not_contains_match | name | *name == shm_name
+    );
+}
+
+#[test]
+fn shared_memory_resize_grows_memory_and_keeps_content() {
+    let shm_name = generate_shm_name();
+    let mut sut = SharedMemoryBuilder::new(&shm_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .create()
+        .unwrap();
+
+    sut.as_mut_slice()[1023] = 42;
+    assert_that!(sut.resize(1024 * 1024), is_ok);
+
+    assert_that!(sut.size(), ge 1024 * 1024);
+    assert_that!(sut.as_slice()[1023], eq 42);
+    sut.as_mut_slice()[1024 * 1024 - 1] = 73;
+    assert_that!(sut.remap_if_resized(), eq Ok(false));
+}
+
+#[test]
+fn shared_memory_resize_to_smaller_size_fails() {
+    let shm_name = generate_shm_name();
+    let mut sut = SharedMemoryBuilder::new(&shm_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(8192)
+        .create()
+        .unwrap();
+
+    assert_that!(sut.resize(1024), eq Err(SharedMemoryResizeError::SizeCannotBeReduced));
+    assert_that!(sut.size(), ge 8192);
+}
+
+#[test]
+fn shared_memory_resize_fails_without_write_access() {
+    let shm_name = generate_shm_name();
+    let _sut_create = SharedMemoryBuilder::new(&shm_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(1024)
+        .create()
+        .unwrap();
+
+    let mut sut = SharedMemoryBuilder::new(&shm_name)
+        .open_existing(AccessMode::Read)
+        .unwrap();
+
+    assert_that!(sut.resize(8192), eq Err(SharedMemoryResizeError::InsufficientPermissions));
+}
+
+#[test]
+fn shared_memory_grows_while_other_thread_holds_old_mapping() {
+    const OLD_SIZE: usize = 4096;
+    const NEW_SIZE: usize = 4 * 1024 * 1024;
+    let shm_name = generate_shm_name();
+    let mut sut = SharedMemoryBuilder::new(&shm_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .size(OLD_SIZE)
+        .create()
+        .unwrap();
+    sut.as_mut_slice()[0] = 1;
+
+    let is_opened = Barrier::new(2);
+    let is_resized = Barrier::new(2);
+    let is_remapped = Barrier::new(2);
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            let mut other = SharedMemoryBuilder::new(&shm_name)
+                .open_existing(AccessMode::ReadWrite)
+                .unwrap();
+            is_opened.wait();
+            is_resized.wait();
+
+            // the old mapping stays valid after the owner has grown the memory
+            assert_that!(other.size(), eq OLD_SIZE);
+            assert_that!(other.as_slice()[0], eq 1);
+            other.as_mut_slice()[OLD_SIZE - 1] = 2;
+
+            assert_that!(other.remap_if_resized(), eq Ok(true));
+            assert_that!(other.size(), eq NEW_SIZE);
+            assert_that!(other.as_slice()[0], eq 1);
+            assert_that!(other.as_slice()[NEW_SIZE - 1], eq 3);
+            other.as_mut_slice()[NEW_SIZE - 2] = 4;
+            assert_that!(other.remap_if_resized(), eq Ok(false));
+            is_remapped.wait();
+        });
+
+        is_opened.wait();
+        assert_that!(sut.resize(NEW_SIZE), is_ok);
+        sut.as_mut_slice()[NEW_SIZE - 1] = 3;
+        is_resized.wait();
+        is_remapped.wait();
+
+        assert_that!(sut.as_slice()[OLD_SIZE - 1], eq 2);
+        assert_that!(sut.as_slice()[NEW_SIZE - 2], eq 4);
+    });
+}
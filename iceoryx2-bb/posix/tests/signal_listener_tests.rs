@@ -0,0 +1,209 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Signals are delivered to the whole process and the tests run concurrently, therefore every
+// test uses its own signal.
+#[cfg(not(target_os = "windows"))]
+mod signal_listener {
+    use core::time::Duration;
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+    use iceoryx2_bb_posix::file_descriptor_set::*;
+    use iceoryx2_bb_posix::process::*;
+    use iceoryx2_bb_posix::signal::*;
+    use iceoryx2_bb_posix::signal_listener::*;
+    use iceoryx2_bb_system_types::file_path::FilePath;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_pal_posix::posix::{self, MemZeroedStruct};
+
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    fn raise(signal: FetchableSignal) {
+        assert_that!(unsafe { posix::kill(posix::getpid(), signal as _) }, eq 0);
+    }
+
+    // returns the handler and the flags, the debug output of the mask is platform dependent
+    fn current_disposition(signal: FetchableSignal) -> (String, posix::int) {
+        let probe = posix::sigaction_t::new_zeroed();
+        let mut previous = posix::sigaction_t::new_zeroed();
+        let mut unused = posix::sigaction_t::new_zeroed();
+        unsafe {
+            assert_that!(posix::sigaction(signal as _, &probe, &mut previous), eq 0);
+            assert_that!(posix::sigaction(signal as _, &previous, &mut unused), eq 0);
+        }
+        let handler = format!("{previous:?}")
+            .split(", sa_mask")
+            .next()
+            .unwrap()
+            .to_string();
+        (handler, previous.flags())
+    }
+
+    #[test]
+    fn signal_listener_without_signals_fails() {
+        let sut = SignalListenerBuilder::new().create();
+
+        assert_that!(sut.err(), eq Some(SignalListenerCreationError::NoSignalsProvided));
+    }
+
+    #[test]
+    fn signal_listener_for_fault_signal_fails() {
+        let sut = SignalListenerBuilder::new()
+            .signal(FetchableSignal::SegmentationFault)
+            .create();
+
+        assert_that!(sut.err(), eq Some(SignalListenerCreationError::UnsupportedSignal));
+    }
+
+    #[test]
+    fn signal_listener_receives_signal_with_sender() {
+        let sut = SignalListenerBuilder::new()
+            .signal(FetchableSignal::UserDefined1)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.try_receive(), eq Ok(None));
+
+        raise(FetchableSignal::UserDefined1);
+        let info = sut.timed_receive(TIMEOUT).unwrap().unwrap();
+        assert_that!(info.signal(), eq FetchableSignal::UserDefined1);
+        assert_that!(info.sender(), eq Process::from_self().id());
+
+        assert_that!(sut.try_receive(), eq Ok(None));
+    }
+
+    #[test]
+    fn signal_listener_receives_every_signal() {
+        const NUMBER_OF_SIGNALS: usize = 5;
+        let sut = SignalListenerBuilder::new()
+            .signal(FetchableSignal::TerminalQuit)
+            .create()
+            .unwrap();
+
+        // the signal is delivered before kill returns when the calling thread does not block it
+        for _ in 0..NUMBER_OF_SIGNALS {
+            raise(FetchableSignal::TerminalQuit);
+            let info = sut.timed_receive(TIMEOUT).unwrap().unwrap();
+            assert_that!(info.signal(), eq FetchableSignal::TerminalQuit);
+        }
+        assert_that!(sut.try_receive(), eq Ok(None));
+    }
+
+    #[test]
+    fn signal_listener_signal_can_only_be_registered_once() {
+        let sut = SignalListenerBuilder::new()
+            .signal(FetchableSignal::ProfilingTimerExpired)
+            .create()
+            .unwrap();
+
+        let sut2 = SignalListenerBuilder::new()
+            .signal(FetchableSignal::ProfilingTimerExpired)
+            .create();
+        assert_that!(sut2.err(), eq Some(SignalListenerCreationError::SignalAlreadyRegistered));
+
+        let guard = SignalHandler::register(FetchableSignal::ProfilingTimerExpired, &|_| {});
+        assert_that!(guard.err(), eq Some(SignalRegisterError::AlreadyRegistered));
+
+        // the failed creation must not affect the existing listener
+        raise(FetchableSignal::ProfilingTimerExpired);
+        let info = sut.timed_receive(TIMEOUT).unwrap().unwrap();
+        assert_that!(info.signal(), eq FetchableSignal::ProfilingTimerExpired);
+    }
+
+    #[test]
+    fn signal_listener_can_be_created_after_failed_registration() {
+        let guard =
+            SignalHandler::register(FetchableSignal::FileSizeLimitExceeded, &|_| {}).unwrap();
+
+        let sut = SignalListenerBuilder::new()
+            .signal(FetchableSignal::FileSizeLimitExceeded)
+            .create();
+        assert_that!(sut.err(), eq Some(SignalListenerCreationError::SignalAlreadyRegistered));
+        drop(guard);
+
+        let sut = SignalListenerBuilder::new()
+            .signal(FetchableSignal::FileSizeLimitExceeded)
+            .create()
+            .unwrap();
+
+        raise(FetchableSignal::FileSizeLimitExceeded);
+        let info = sut.timed_receive(TIMEOUT).unwrap().unwrap();
+        assert_that!(info.signal(), eq FetchableSignal::FileSizeLimitExceeded);
+    }
+
+    #[test]
+    fn signal_listener_restores_previous_disposition_on_drop() {
+        // the SignalHandler registers signals when it is accessed the first time
+        SignalHandler::last_signal();
+        let previous_disposition =
+            current_disposition(FetchableSignal::UrgentDataAvailableAtSocket);
+
+        let sut = SignalListenerBuilder::new()
+            .signal(FetchableSignal::UrgentDataAvailableAtSocket)
+            .create()
+            .unwrap();
+        assert_that!(current_disposition(FetchableSignal::UrgentDataAvailableAtSocket), ne previous_disposition);
+
+        drop(sut);
+        assert_that!(current_disposition(FetchableSignal::UrgentDataAvailableAtSocket), eq previous_disposition);
+
+        let sut = SignalListenerBuilder::new()
+            .signal(FetchableSignal::UrgentDataAvailableAtSocket)
+            .create();
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn signal_listener_can_be_attached_to_file_descriptor_set() {
+        let sut = SignalListenerBuilder::new()
+            .signal(FetchableSignal::UserDefined2)
+            .create()
+            .unwrap();
+
+        let fd_set = FileDescriptorSet::new();
+        let _guard = fd_set.add(&sut).unwrap();
+
+        raise(FetchableSignal::UserDefined2);
+
+        let mut triggered_fds = vec![];
+        fd_set
+            .timed_wait(TIMEOUT, FileEvent::Read, |fd| {
+                triggered_fds.push(unsafe { fd.native_handle() })
+            })
+            .unwrap();
+
+        assert_that!(triggered_fds, len 1);
+        assert_that!(triggered_fds[0], eq unsafe { sut.file_descriptor().native_handle() });
+
+        let info = sut.try_receive().unwrap().unwrap();
+        assert_that!(info.signal(), eq FetchableSignal::UserDefined2);
+    }
+
+    #[test]
+    fn signal_listener_receives_sender_of_other_process() {
+        let sut = SignalListenerBuilder::new()
+            .signal(FetchableSignal::VirtualTimerExpired)
+            .create()
+            .unwrap();
+
+        let mut child = ProcessBuilder::new(&FilePath::new(b"/bin/sh").unwrap())
+            .argument("-c")
+            .argument("kill -VTALRM $PPID")
+            .spawn()
+            .unwrap();
+        assert_that!(child.wait().unwrap().is_success(), eq true);
+
+        let info = sut.timed_receive(TIMEOUT).unwrap().unwrap();
+        assert_that!(info.signal(), eq FetchableSignal::VirtualTimerExpired);
+        assert_that!(info.sender(), eq child.id());
+    }
+}
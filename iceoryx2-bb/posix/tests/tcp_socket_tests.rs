@@ -0,0 +1,286 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2_bb_posix::file_descriptor::*;
+use iceoryx2_bb_posix::file_descriptor_set::*;
+use iceoryx2_bb_posix::tcp_socket::*;
+use iceoryx2_bb_system_types::ipv4_address;
+use iceoryx2_bb_system_types::port;
+use iceoryx2_bb_testing::assert_that;
+use std::thread;
+use std::time::Instant;
+
+const TIMEOUT: Duration = Duration::from_millis(100);
+
+fn localhost() -> SocketAddress {
+    SocketAddress::new_v4(ipv4_address::LOCALHOST, port::UNSPECIFIED)
+}
+
+fn create_listener() -> TcpListener {
+    TcpListenerBuilder::new()
+        .address(localhost())
+        .create()
+        .unwrap()
+}
+
+fn create_connection() -> (TcpListener, TcpStreamSocket, TcpStreamSocket) {
+    let listener = create_listener();
+    let client = TcpClientBuilder::new(listener.local_address())
+        .connect()
+        .unwrap();
+    let server = listener.blocking_accept().unwrap();
+
+    (listener, client, server)
+}
+
+#[test]
+fn tcp_socket_send_receive_works() {
+    let (_listener, client, server) = create_connection();
+
+    let data = b"the first rule of tcp sockets";
+    assert_that!(client.blocking_send(data), eq Ok(data.len()));
+
+    let mut buffer = [0u8; 64];
+    let received = server.blocking_receive(&mut buffer).unwrap();
+    assert_that!(&buffer[..received], eq & data[..]);
+
+    assert_that!(server.try_send(b"reply"), eq Ok(5));
+    let received = client.timed_receive(&mut buffer, TIMEOUT).unwrap();
+    assert_that!(&buffer[..received], eq b"reply");
+}
+
+#[test]
+fn tcp_socket_connection_reports_addresses() {
+    let (listener, client, server) = create_connection();
+
+    assert_that!(listener.local_address().port(), ne port::UNSPECIFIED);
+    assert_that!(client.peer_address(), eq Ok(listener.local_address()));
+    assert_that!(server.local_address(), eq listener.local_address());
+    assert_that!(server.peer_address(), eq Ok(client.local_address()));
+}
+
+#[test]
+fn tcp_socket_peer_address_fails_when_connection_was_reset() {
+    let (_listener, client, server) = create_connection();
+
+    // dropping a socket with unread data resets the connection
+    assert_that!(client.try_send(b"unread"), eq Ok(6));
+    thread::sleep(Duration::from_millis(50));
+    drop(server);
+
+    let mut buffer = [0u8; 16];
+    assert_that!(client.timed_receive(&mut buffer, TIMEOUT), eq Err(TcpReceiveError::ConnectionReset));
+    assert_that!(client.peer_address(), eq Err(TcpPeerAddressError::NotConnected));
+}
+
+#[test]
+fn tcp_socket_with_no_delay_and_keep_alive_works() {
+    let listener = TcpListenerBuilder::new()
+        .address(localhost())
+        .no_delay(true)
+        .keep_alive(true)
+        .create()
+        .unwrap();
+    let client = TcpClientBuilder::new(listener.local_address())
+        .connect_timeout(TIMEOUT)
+        .no_delay(true)
+        .keep_alive(true)
+        .connect()
+        .unwrap();
+    let server = listener.blocking_accept().unwrap();
+
+    assert_that!(client.blocking_send(b"x"), eq Ok(1));
+    let mut buffer = [0u8; 4];
+    assert_that!(server.timed_receive(&mut buffer, TIMEOUT), eq Ok(1));
+}
+
+#[test]
+fn tcp_socket_send_vectored_sends_header_and_payload_in_order() {
+    let (_listener, client, server) = create_connection();
+
+    let payload = b"some payload";
+    let header = (payload.len() as u32).to_le_bytes();
+    assert_that!(client.blocking_send_vectored(&[&header, payload]), eq Ok(header.len() + payload.len()));
+    assert_that!(client.try_send_vectored(&[]), eq Ok(0));
+
+    let mut buffer = [0u8; 64];
+    let mut received = 0;
+    while received < header.len() + payload.len() {
+        received += server
+            .timed_receive(&mut buffer[received..], TIMEOUT)
+            .unwrap();
+    }
+
+    assert_that!(buffer[..4], eq header);
+    assert_that!(&buffer[4..received], eq & payload[..]);
+}
+
+#[test]
+fn tcp_socket_try_receive_without_data_returns_zero() {
+    let (_listener, client, server) = create_connection();
+
+    let mut buffer = [0u8; 16];
+    assert_that!(server.try_receive(&mut buffer), eq Ok(0));
+    assert_that!(client.try_receive(&mut buffer), eq Ok(0));
+}
+
+#[test]
+fn tcp_socket_timed_receive_blocks_at_least_timeout() {
+    let (_listener, _client, server) = create_connection();
+
+    let mut buffer = [0u8; 16];
+    let start = Instant::now();
+    assert_that!(server.timed_receive(&mut buffer, TIMEOUT), eq Ok(0));
+    assert_that!(start.elapsed(), ge TIMEOUT);
+}
+
+#[test]
+fn tcp_socket_receive_from_closed_peer_delivers_pending_data_then_disconnects() {
+    let (_listener, client, server) = create_connection();
+
+    assert_that!(client.blocking_send(b"last words"), eq Ok(10));
+    drop(client);
+
+    let mut buffer = [0u8; 16];
+    assert_that!(server.timed_receive(&mut buffer, TIMEOUT), eq Ok(10));
+    assert_that!(&buffer[..10], eq b"last words");
+    assert_that!(server.timed_receive(&mut buffer, TIMEOUT), eq Err(TcpReceiveError::Disconnected));
+}
+
+#[test]
+fn tcp_socket_shutdown_write_disconnects_peer_receiver() {
+    let (_listener, client, server) = create_connection();
+
+    assert_that!(client.shutdown(TcpShutdown::Write), is_ok);
+    assert_that!(client.try_send(b"x"), eq Err(TcpSendError::Disconnected));
+
+    let mut buffer = [0u8; 16];
+    assert_that!(server.timed_receive(&mut buffer, TIMEOUT), eq Err(TcpReceiveError::Disconnected));
+
+    // the other direction is still intact
+    assert_that!(server.blocking_send(b"still here"), eq Ok(10));
+    assert_that!(client.timed_receive(&mut buffer, TIMEOUT), eq Ok(10));
+}
+
+#[test]
+fn tcp_socket_connect_without_listener_is_refused() {
+    let listener = create_listener();
+    let address = listener.local_address();
+    drop(listener);
+
+    let sut = TcpClientBuilder::new(address).connect();
+    assert_that!(sut.err(), eq Some(TcpClientCreationError::ConnectionRefused));
+
+    let sut = TcpClientBuilder::new(address)
+        .connect_timeout(TIMEOUT)
+        .connect();
+    assert_that!(sut.err(), eq Some(TcpClientCreationError::ConnectionRefused));
+}
+
+#[test]
+fn tcp_socket_connect_to_exhausted_listener_times_out() {
+    const MAX_NUMBER_OF_CONNECTIONS: usize = 64;
+    let listener = TcpListenerBuilder::new()
+        .address(localhost())
+        .backlog(1)
+        .create()
+        .unwrap();
+
+    // the listener never accepts, as soon as its backlog is full further connection requests
+    // are not answered anymore
+    let mut clients = vec![];
+    for _ in 0..MAX_NUMBER_OF_CONNECTIONS {
+        let start = Instant::now();
+        match TcpClientBuilder::new(listener.local_address())
+            .connect_timeout(TIMEOUT)
+            .connect()
+        {
+            Ok(client) => clients.push(client),
+            Err(e) => {
+                assert_that!(e, eq TcpClientCreationError::ConnectionTimeout);
+                assert_that!(start.elapsed(), ge TIMEOUT);
+                return;
+            }
+        }
+    }
+
+    panic!("the connect timeout was never hit");
+}
+
+#[test]
+fn tcp_socket_large_transfer_is_split_across_partial_writes() {
+    const DATA_SIZE: usize = 16 * 1024 * 1024;
+    let (_listener, client, server) = create_connection();
+    let data: Vec<u8> = (0..DATA_SIZE).map(|n| (n % 251) as u8).collect();
+
+    thread::scope(|s| {
+        let sender = s.spawn(|| {
+            let mut bytes_sent = 0;
+            let mut number_of_partial_writes = 0;
+            while bytes_sent < data.len() {
+                let sent = client.try_send(&data[bytes_sent..]).unwrap();
+                if sent == 0 {
+                    thread::yield_now();
+                    continue;
+                }
+                if bytes_sent + sent < data.len() {
+                    number_of_partial_writes += 1;
+                }
+                bytes_sent += sent;
+            }
+            number_of_partial_writes
+        });
+
+        let mut received_data = Vec::with_capacity(DATA_SIZE);
+        let mut buffer = vec![0u8; 64 * 1024];
+        while received_data.len() < DATA_SIZE {
+            let received = server.blocking_receive(&mut buffer).unwrap();
+            received_data.extend_from_slice(&buffer[..received]);
+        }
+
+        assert_that!(sender.join().unwrap(), gt 1);
+        assert_that!(received_data == data, eq true);
+    });
+}
+
+#[test]
+fn tcp_socket_can_be_attached_to_file_descriptor_set() {
+    let listener = create_listener();
+    let client = TcpClientBuilder::new(listener.local_address())
+        .connect()
+        .unwrap();
+
+    let fd_set = FileDescriptorSet::new();
+    let _listener_guard = fd_set.add(&listener).unwrap();
+
+    let mut triggered_fds = vec![];
+    fd_set
+        .timed_wait(TIMEOUT, FileEvent::Read, |fd| {
+            triggered_fds.push(unsafe { fd.native_handle() })
+        })
+        .unwrap();
+    assert_that!(triggered_fds, eq vec![unsafe { listener.file_descriptor().native_handle() }]);
+
+    let server = listener.try_accept().unwrap().unwrap();
+    assert_that!(listener.try_accept().unwrap(), is_none);
+    let _server_guard = fd_set.add(&server).unwrap();
+    client.blocking_send(b"wake up").unwrap();
+
+    let mut triggered_fds = vec![];
+    fd_set
+        .timed_wait(TIMEOUT, FileEvent::Read, |fd| {
+            triggered_fds.push(unsafe { fd.native_handle() })
+        })
+        .unwrap();
+    assert_that!(triggered_fds, eq vec![unsafe { server.file_descriptor().native_handle() }]);
+}
@@ -10,11 +10,12 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use iceoryx2_bb_posix::scheduler::Scheduler;
 use iceoryx2_bb_posix::system_configuration::SystemInfo;
 use iceoryx2_bb_posix::thread::*;
 use iceoryx2_bb_testing::watchdog::Watchdog;
 use iceoryx2_bb_testing::{assert_that, test_requires};
-use iceoryx2_pal_posix::posix::{self, POSIX_SUPPORT_CPU_AFFINITY};
+use iceoryx2_pal_posix::posix::{self, POSIX_SUPPORT_CPU_AFFINITY, POSIX_SUPPORT_SCHEDULER};
 
 use core::time::Duration;
 
@@ -371,6 +372,100 @@ fn thread_set_affinity_to_non_existing_cores_from_thread_fails() {
     assert_that!(affinity, eq original_affinity);
 }
 
+#[test]
+fn thread_set_affinity_of_calling_thread_works() {
+    test_requires!(POSIX_SUPPORT_CPU_AFFINITY);
+    let _watchdog = Watchdog::new();
+    let thread = ThreadBuilder::new()
+        .spawn(|| {
+            Thread::set_affinity_of_calling_thread(&[0]).unwrap();
+            let affinity = ThreadHandle::from_self().get_affinity().unwrap();
+            assert_that!(affinity, len 1);
+            assert_that!(affinity[0], eq 0);
+        })
+        .unwrap();
+
+    drop(thread);
+}
+
+#[test]
+fn thread_set_affinity_of_calling_thread_to_first_not_online_core_fails() {
+    test_requires!(POSIX_SUPPORT_CPU_AFFINITY);
+    let _watchdog = Watchdog::new();
+    let number_of_cpu_cores = SystemInfo::NumberOfOnlineCpuCores.value();
+    let thread = ThreadBuilder::new()
+        .spawn(move || {
+            let affinity_before = ThreadHandle::from_self().get_affinity().unwrap();
+            let result = Thread::set_affinity_of_calling_thread(&[number_of_cpu_cores]);
+            assert_that!(result, eq Err(ThreadSetAffinityError::InvalidCpuCores));
+            assert_that!(ThreadHandle::from_self().get_affinity().unwrap(), eq affinity_before);
+        })
+        .unwrap();
+
+    drop(thread);
+}
+
+#[test]
+fn thread_set_affinity_on_creation_fails_when_not_supported() {
+    test_requires!(!POSIX_SUPPORT_CPU_AFFINITY);
+    let thread = ThreadBuilder::new().affinity(&[0]).spawn(|| {});
+    assert_that!(thread.err(), eq Some(ThreadSpawnError::CpuAffinityNotSupported));
+
+    let result = Thread::set_affinity_of_calling_thread(&[0]);
+    assert_that!(result, eq Err(ThreadSetAffinityError::NotSupported));
+}
+
+#[test]
+fn thread_with_other_scheduler_reports_scheduler_and_priority() {
+    test_requires!(POSIX_SUPPORT_SCHEDULER);
+    let _watchdog = Watchdog::new();
+    let barrier = Arc::new(Barrier::new(2));
+    let thread = {
+        let barrier = barrier.clone();
+        ThreadBuilder::new()
+            .scheduler(Scheduler::Other)
+            .priority(0)
+            .spawn(move || {
+                let handle = ThreadHandle::from_self();
+                assert_that!(handle.get_scheduler(), eq Ok(Scheduler::Other));
+                barrier.wait();
+            })
+            .unwrap()
+    };
+
+    assert_that!(thread.get_scheduler(), eq Ok(Scheduler::Other));
+    assert_that!(thread.get_priority(), is_ok);
+    barrier.wait();
+}
+
+#[test]
+fn thread_with_real_time_scheduler_runs_with_configured_settings() {
+    test_requires!(POSIX_SUPPORT_SCHEDULER);
+    let _watchdog = Watchdog::new();
+    const PRIORITY: u8 = 255;
+    let barrier = Arc::new(Barrier::new(2));
+    let thread = {
+        let barrier = barrier.clone();
+        ThreadBuilder::new()
+            .scheduler(Scheduler::Fifo)
+            .priority(PRIORITY)
+            .spawn(move || {
+                barrier.wait();
+            })
+    };
+
+    // the environment may lack the privileges for real-time scheduling, then spawn must
+    // fail instead of silently running with another scheduler
+    let thread = match thread {
+        Err(ThreadSpawnError::InsufficientPermissions) => return,
+        v => v.unwrap(),
+    };
+
+    assert_that!(thread.get_scheduler(), eq Ok(Scheduler::Fifo));
+    assert_that!(thread.get_priority(), eq Ok(PRIORITY));
+    barrier.wait();
+}
+
 #[test]
 fn thread_destructor_does_not_block_on_empty_thread() {
     let _watchdog = Watchdog::new();
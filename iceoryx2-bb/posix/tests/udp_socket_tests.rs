@@ -16,8 +16,11 @@ use core::{
 };
 use std::time::Instant;
 
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+use iceoryx2_bb_posix::file_descriptor_set::{FileDescriptorSet, FileEvent};
 use iceoryx2_bb_posix::{barrier::*, udp_socket::*};
 use iceoryx2_bb_system_types::ipv4_address::{self, Ipv4Address};
+use iceoryx2_bb_system_types::port::{self, Port};
 use iceoryx2_bb_testing::assert_that;
 
 const TIMEOUT: Duration = Duration::from_millis(25);
@@ -405,3 +408,149 @@ fn udp_socket_server_timed_receive_from_does_block() {
         assert_that!(send_result, is_ok);
     });
 }
+
+fn localhost() -> SocketAddress {
+    SocketAddress::new_v4(ipv4_address::LOCALHOST, port::UNSPECIFIED)
+}
+
+#[test]
+fn udp_socket_builder_send_to_and_receive_from_reports_source() {
+    let sut_receiver = UdpSocketBuilder::new()
+        .address(localhost())
+        .create()
+        .unwrap();
+    let sut_sender = UdpSocketBuilder::new()
+        .address(localhost())
+        .create()
+        .unwrap();
+
+    assert_that!(sut_receiver.local_address().port(), ne port::UNSPECIFIED);
+
+    let data = [1u8, 2u8, 3u8, 4u8];
+    assert_that!(sut_sender.try_send_to(&data, sut_receiver.local_address()), eq Ok(data.len()));
+
+    let mut buffer = [0u8; 16];
+    let details = sut_receiver
+        .timed_receive_from(&mut buffer, TIMEOUT)
+        .unwrap()
+        .unwrap();
+    assert_that!(details.number_of_bytes, eq data.len());
+    assert_that!(buffer[..data.len()], eq data);
+    assert_that!(details.source(), eq sut_sender.local_address());
+
+    assert_that!(sut_receiver.blocking_send_to(&data[..2], details.source()), eq Ok(2));
+    let details = sut_sender
+        .blocking_receive_from(&mut buffer)
+        .unwrap()
+        .unwrap();
+    assert_that!(details.number_of_bytes, eq 2);
+    assert_that!(details.source(), eq sut_receiver.local_address());
+}
+
+#[test]
+fn udp_socket_builder_try_receive_from_without_data_returns_none() {
+    let sut = UdpSocketBuilder::new()
+        .address(localhost())
+        .create()
+        .unwrap();
+
+    let mut buffer = [0u8; 16];
+    assert_that!(sut.try_receive_from(&mut buffer).unwrap(), is_none);
+
+    let start = Instant::now();
+    assert_that!(
+        sut.timed_receive_from(&mut buffer, TIMEOUT).unwrap(),
+        is_none
+    );
+    assert_that!(start.elapsed(), ge TIMEOUT);
+}
+
+#[test]
+fn udp_socket_builder_binding_used_address_fails() {
+    let sut = UdpSocketBuilder::new()
+        .address(localhost())
+        .create()
+        .unwrap();
+
+    let result = UdpSocketBuilder::new()
+        .address(sut.local_address())
+        .create();
+    assert_that!(result.err(), eq Some(UdpSocketCreateError::AddressAlreadyInUse));
+}
+
+#[test]
+fn udp_socket_builder_broadcast_requires_broadcast_option() {
+    let destination = SocketAddress::new_v4(ipv4_address::BROADCAST, Port::new(9));
+
+    let sut = UdpSocketBuilder::new().create().unwrap();
+    assert_that!(sut.try_send_to(b"hello", destination), eq Err(UdpSendError::InsufficientPermissions));
+
+    let sut = UdpSocketBuilder::new().broadcast(true).create().unwrap();
+    assert_that!(sut.try_send_to(b"hello", destination), eq Ok(5));
+}
+
+#[test]
+fn udp_socket_builder_joining_non_multicast_group_fails() {
+    let result = UdpSocketBuilder::new()
+        .join_multicast_group(ipv4_address::LOCALHOST, ipv4_address::LOCALHOST)
+        .create();
+    assert_that!(result.err(), eq Some(UdpSocketCreateError::NotAMulticastAddress));
+}
+
+#[test]
+fn udp_socket_builder_multicast_group_receives_messages() {
+    let group = Ipv4Address::new(239, 255, 42, 99);
+    let sut_receiver = UdpSocketBuilder::new()
+        .reuse_address(true)
+        .join_multicast_group(group, ipv4_address::LOCALHOST)
+        .create()
+        .unwrap();
+    let sut_sender = UdpSocketBuilder::new()
+        .multicast_interface(ipv4_address::LOCALHOST)
+        .create()
+        .unwrap();
+
+    let destination = SocketAddress::new_v4(group, sut_receiver.local_address().port());
+    assert_that!(sut_sender.try_send_to(b"to whom it may concern", destination), eq Ok(22));
+
+    let mut buffer = [0u8; 32];
+    let details = sut_receiver
+        .timed_receive_from(&mut buffer, TIMEOUT)
+        .unwrap()
+        .unwrap();
+    assert_that!(details.number_of_bytes, eq 22);
+    assert_that!(buffer[..22], eq b"to whom it may concern"[..]);
+}
+
+#[test]
+fn udp_socket_builder_socket_can_be_attached_to_file_descriptor_set() {
+    let sut_receiver = UdpSocketBuilder::new()
+        .address(localhost())
+        .create()
+        .unwrap();
+    let sut_sender = UdpSocketBuilder::new()
+        .address(localhost())
+        .create()
+        .unwrap();
+
+    let fd_set = FileDescriptorSet::new();
+    let _guard = fd_set.add(&sut_receiver).unwrap();
+
+    let mut triggered_fds = vec![];
+    fd_set
+        .timed_wait(TIMEOUT, FileEvent::Read, |fd| {
+            triggered_fds.push(unsafe { fd.native_handle() })
+        })
+        .unwrap();
+    assert_that!(triggered_fds, len 0);
+
+    sut_sender
+        .try_send_to(b"wake up", sut_receiver.local_address())
+        .unwrap();
+    fd_set
+        .timed_wait(TIMEOUT, FileEvent::Read, |fd| {
+            triggered_fds.push(unsafe { fd.native_handle() })
+        })
+        .unwrap();
+    assert_that!(triggered_fds, eq vec![unsafe { sut_receiver.file_descriptor().native_handle() }]);
+}
@@ -13,7 +13,9 @@
 use core::time::Duration;
 use std::{collections::HashSet, sync::Barrier};
 
+use iceoryx2_bb_container::semantic_string::{SemanticString, SemanticStringError};
 use iceoryx2_bb_posix::{process::Process, system_configuration::SystemInfo, unique_system_id::*};
+use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_testing::{assert_that, watchdog::Watchdog};
 
 #[test]
@@ -70,3 +72,69 @@ fn unique_system_id_concurrently_created_ids_are_unique() {
         }
     });
 }
+
+#[test]
+fn unique_system_id_file_name_with_unique_suffix_is_unique() {
+    let name = FileName::new(b"some_name").unwrap();
+
+    let sut1 = name.with_unique_suffix().unwrap();
+    let sut2 = name.with_unique_suffix().unwrap();
+
+    assert_that!(sut1.as_bytes().starts_with(b"some_name_"), eq true);
+    assert_that!(sut2.as_bytes().starts_with(b"some_name_"), eq true);
+    assert_that!(sut1, ne sut2);
+}
+
+#[test]
+fn unique_system_id_file_name_with_unique_suffix_contains_decimal_id() {
+    let name = FileName::new(b"some_name").unwrap();
+
+    let sut = name.with_unique_suffix().unwrap();
+
+    let suffix = core::str::from_utf8(&sut.as_bytes()[b"some_name_".len()..]).unwrap();
+    let id = UniqueSystemId::from(suffix.parse::<u128>().unwrap());
+    assert_that!(id.pid(), eq Process::from_self().id());
+    assert_that!(suffix, eq id.value().to_string());
+}
+
+#[test]
+fn unique_system_id_file_name_with_unique_suffix_fails_when_it_exceeds_capacity() {
+    let name = FileName::new(&vec![b'a'; FileName::max_len() - 1]).unwrap();
+
+    let sut = name.with_unique_suffix();
+
+    assert_that!(sut, eq Err(UniqueFileNameCreationError::SemanticStringError(SemanticStringError::ExceedsMaximumLength)));
+}
+
+#[test]
+fn unique_system_id_created_back_to_back_are_strictly_increasing() {
+    const NUMBER_OF_IDS: usize = 10000;
+    let mut previous = UniqueSystemId::new().unwrap();
+
+    for _ in 0..NUMBER_OF_IDS {
+        let sut = UniqueSystemId::new().unwrap();
+        assert_that!(sut, gt previous);
+        assert_that!(sut.pid(), eq previous.pid());
+        assert_that!(sut.creation_time().as_duration(), ge previous.creation_time().as_duration());
+        previous = sut;
+    }
+}
+
+#[test]
+fn unique_system_id_value_rendering_fits_into_file_name() {
+    let sut = UniqueSystemId::from(u128::MAX);
+    let rendering = format!("_{sut}");
+
+    assert_that!(rendering.len(), le FileName::max_len());
+    assert_that!(FileName::new(rendering.as_bytes()), is_ok);
+}
+
+#[test]
+fn unique_system_id_does_not_repeat_when_more_ids_than_a_16_bit_counter_are_created() {
+    const NUMBER_OF_IDS: usize = 2 * (u16::MAX as usize + 1) + 1;
+    let mut id_set = HashSet::with_capacity(NUMBER_OF_IDS);
+
+    for _ in 0..NUMBER_OF_IDS {
+        assert_that!(id_set.insert(UniqueSystemId::new().unwrap()), eq true);
+    }
+}
@@ -19,6 +19,7 @@ use iceoryx2_bb_posix::creation_mode::*;
 use iceoryx2_bb_posix::file::*;
 use iceoryx2_bb_posix::file_descriptor::*;
 use iceoryx2_bb_posix::socket_ancillary::*;
+use iceoryx2_bb_posix::system_configuration::ProcessResourceLimit;
 use iceoryx2_bb_posix::testing::create_test_directory;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_bb_posix::unix_datagram_socket::*;
@@ -26,7 +27,9 @@ use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_bb_testing::assert_that;
 use iceoryx2_bb_testing::test_requires;
-use iceoryx2_pal_posix::posix::POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS_ANCILLARY_DATA;
+use iceoryx2_pal_posix::posix::{
+    POSIX_SUPPORT_ABSTRACT_UNIX_DOMAIN_SOCKETS, POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS_ANCILLARY_DATA,
+};
 use std::thread;
 use std::time::Instant;
 
@@ -115,6 +118,76 @@ fn unix_datagram_socket_send_receive_works() {
     assert_that!(send_data, eq receive_data);
 }
 
+#[cfg(target_os = "linux")]
+#[test]
+fn unix_datagram_socket_in_abstract_namespace_send_receive_works() {
+    let socket_name = generate_socket_name();
+    let sut_receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+        .abstract_namespace(true)
+        .create()
+        .unwrap();
+
+    assert_that!(File::does_exist(&socket_name), eq Ok(false));
+
+    let sut_sender = UnixDatagramSenderBuilder::new(&socket_name)
+        .abstract_namespace(true)
+        .create()
+        .unwrap();
+
+    let send_data: Vec<u8> = vec![1u8, 3u8, 3u8, 7u8, 13u8, 37u8];
+    sut_sender.blocking_send(send_data.as_slice()).unwrap();
+
+    let mut receive_data: Vec<u8> = vec![0; 6];
+    sut_receiver
+        .blocking_receive(receive_data.as_mut_slice())
+        .unwrap();
+
+    assert_that!(send_data, eq receive_data);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn unix_datagram_socket_in_abstract_namespace_is_removed_when_receiver_goes_out_of_scope() {
+    let socket_name = generate_socket_name();
+    let sut_receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+        .abstract_namespace(true)
+        .create()
+        .unwrap();
+
+    let sut_receiver_2 = UnixDatagramReceiverBuilder::new(&socket_name)
+        .abstract_namespace(true)
+        .create();
+    assert_that!(sut_receiver_2.err(), eq Some(UnixDatagramReceiverCreationError::AddressAlreadyInUse));
+
+    drop(sut_receiver);
+
+    let sut_sender = UnixDatagramSenderBuilder::new(&socket_name)
+        .abstract_namespace(true)
+        .create();
+    assert_that!(sut_sender.err(), eq Some(UnixDatagramSenderCreationError::DoesNotExist));
+
+    let sut_receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+        .abstract_namespace(true)
+        .create();
+    assert_that!(sut_receiver, is_ok);
+}
+
+#[test]
+fn unix_datagram_socket_in_abstract_namespace_fails_when_unsupported() {
+    test_requires!(!POSIX_SUPPORT_ABSTRACT_UNIX_DOMAIN_SOCKETS);
+
+    let socket_name = generate_socket_name();
+    let sut_receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+        .abstract_namespace(true)
+        .create();
+    assert_that!(sut_receiver.err(), eq Some(UnixDatagramReceiverCreationError::AbstractNamespaceUnsupported));
+
+    let sut_sender = UnixDatagramSenderBuilder::new(&socket_name)
+        .abstract_namespace(true)
+        .create();
+    assert_that!(sut_sender.err(), eq Some(UnixDatagramSenderCreationError::AbstractNamespaceUnsupported));
+}
+
 #[test]
 fn unix_datagram_socket_adjust_buffer_size_works() {
     create_test_directory();
@@ -367,3 +440,380 @@ fn unix_datagram_socket_sending_receiving_with_max_supported_fd_and_credentials_
         assert_that!(file_recv_content, eq file_send_content[i]);
     }
 }
+
+#[test]
+fn unix_datagram_socket_sending_receiving_data_with_fds_works() {
+    test_requires!(POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS_ANCILLARY_DATA);
+
+    let mut test = TestFixture::new();
+
+    create_test_directory();
+    let socket_name = generate_socket_name();
+    let sut_receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+
+    let sut_sender = UnixDatagramSenderBuilder::new(&socket_name)
+        .create()
+        .unwrap();
+
+    let mut file_send_content = vec!["hypnotoad".to_string(), "all glory".to_string()];
+    let files: Vec<File> = file_send_content
+        .iter_mut()
+        .map(|content| test.create_file_with_content(content))
+        .collect();
+
+    let send_data = [4u8, 8, 15, 16, 23, 42];
+    assert_that!(sut_sender.try_send_with_fds(&send_data, &[&files[0], &files[1]]), eq Ok(true));
+
+    let mut fd_vec = vec![];
+    let mut receive_data = [0u8; 16];
+    let bytes_received = sut_receiver
+        .try_receive_with_fds(&mut receive_data, &mut fd_vec)
+        .unwrap();
+
+    assert_that!(bytes_received, eq send_data.len() as u64);
+    assert_that!(receive_data[..send_data.len()], eq send_data);
+    assert_that!(fd_vec, len 2);
+
+    for content in file_send_content {
+        let file_receiver = File::from_file_descriptor(fd_vec.remove(0));
+        // the received file descriptor shares the file offset with the sent one
+        file_receiver.seek(0).unwrap();
+        let mut file_recv_content = String::new();
+        file_receiver
+            .read_to_string(&mut file_recv_content)
+            .unwrap();
+        assert_that!(file_recv_content, eq content);
+    }
+}
+
+#[test]
+fn unix_datagram_socket_try_receive_with_fds_returns_zero_when_nothing_was_received() {
+    test_requires!(POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS_ANCILLARY_DATA);
+
+    create_test_directory();
+    let socket_name = generate_socket_name();
+    let sut_receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+
+    let mut fd_vec = vec![];
+    let mut receive_data = [0u8; 16];
+    assert_that!(sut_receiver.try_receive_with_fds(&mut receive_data, &mut fd_vec), eq Ok(0));
+    assert_that!(fd_vec, is_empty);
+}
+
+#[test]
+fn unix_datagram_socket_sending_more_than_max_supported_fds_fails() {
+    test_requires!(POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS_ANCILLARY_DATA);
+
+    create_test_directory();
+    let socket_name = generate_socket_name();
+    let _sut_receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+
+    let sut_sender = UnixDatagramSenderBuilder::new(&socket_name)
+        .create()
+        .unwrap();
+
+    let fds: Vec<&dyn FileDescriptorBased> =
+        vec![&sut_sender; MAX_FILE_DESCRIPTORS_PER_MESSAGE + 1];
+    assert_that!(sut_sender.try_send_with_fds(&[1u8], &fds),
+        eq Err(UnixDatagramSendMsgError::MaximumSupportedFileDescriptorsExceeded));
+}
+
+const CHILD_PROCESS_REQUEST: &str = "UNIX_DATAGRAM_SOCKET_TESTS_CHILD_PROCESS_REQUEST";
+const CHILD_PROCESS_DATA: &[u8] = b"fuu bar baz";
+const CHILD_PROCESS_SUCCESS: i32 = 10;
+
+fn child_process_send_file(socket_name: &FilePath, file_name: &FilePath) -> i32 {
+    let file = FileBuilder::new(file_name)
+        .open_existing(AccessMode::Read)
+        .unwrap();
+    let sender = UnixDatagramSenderBuilder::new(socket_name)
+        .create()
+        .unwrap();
+
+    match sender.try_send_with_fds(CHILD_PROCESS_DATA, &[&file]) {
+        Ok(true) => CHILD_PROCESS_SUCCESS,
+        _ => 1,
+    }
+}
+
+fn child_process_send_data(socket_name: &FilePath) -> i32 {
+    let sender = UnixDatagramSenderBuilder::new(socket_name)
+        .create()
+        .unwrap();
+
+    match sender.try_send(CHILD_PROCESS_DATA) {
+        Ok(true) => CHILD_PROCESS_SUCCESS,
+        _ => 1,
+    }
+}
+
+fn child_process_receive_truncated_fds(socket_name: &FilePath) -> i32 {
+    const NUMBER_OF_FDS: usize = 4;
+    const NUMBER_OF_RECEIVABLE_FDS: u64 = 2;
+
+    let receiver = UnixDatagramReceiverBuilder::new(socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+    let sender = UnixDatagramSenderBuilder::new(socket_name)
+        .create()
+        .unwrap();
+
+    // the operating system always uses the lowest available file descriptor, so the
+    // file descriptor limit can be chosen so that only some of the sent ones fit
+    let next_free_fd = || unsafe {
+        UnixDatagramSenderBuilder::new(socket_name)
+            .create()
+            .unwrap()
+            .file_descriptor()
+            .native_handle()
+    };
+    let first_free_fd = next_free_fd();
+
+    let fds: Vec<&dyn FileDescriptorBased> = vec![&sender; NUMBER_OF_FDS];
+    if sender.try_send_with_fds(CHILD_PROCESS_DATA, &fds) != Ok(true) {
+        return 1;
+    }
+
+    ProcessResourceLimit::MaxNumberOfOpenFileDescriptors
+        .set_soft_limit(first_free_fd as u64 + NUMBER_OF_RECEIVABLE_FDS);
+
+    let mut fd_vec = vec![];
+    let mut receive_data = [0u8; 16];
+    if receiver.try_receive_with_fds(&mut receive_data, &mut fd_vec)
+        != Err(UnixDatagramReceiveFdError::ControlMessageTruncated)
+    {
+        return 2;
+    }
+
+    // the partially received file descriptors must have been closed
+    if !fd_vec.is_empty() || next_free_fd() != first_free_fd {
+        return 3;
+    }
+
+    CHILD_PROCESS_SUCCESS
+}
+
+// Executed by the processes spawned in run_in_other_process(), it does nothing when
+// it is started as regular test.
+#[test]
+fn unix_datagram_socket_child_process() {
+    let request = match std::env::var(CHILD_PROCESS_REQUEST) {
+        Ok(request) => request,
+        Err(_) => return,
+    };
+
+    let request: Vec<&str> = request.split(';').collect();
+    let socket_name = FilePath::new(request[1].as_bytes()).unwrap();
+    let result = match request[0] {
+        "send_file" => {
+            child_process_send_file(&socket_name, &FilePath::new(request[2].as_bytes()).unwrap())
+        }
+        "send_data" => child_process_send_data(&socket_name),
+        _ => child_process_receive_truncated_fds(&socket_name),
+    };
+
+    std::process::exit(result);
+}
+
+// Returns the process id of the child process when it succeeded, otherwise [`None`].
+fn run_in_other_process(request: &str) -> Option<u32> {
+    let mut child = std::process::Command::new(std::env::current_exe().unwrap())
+        .args([
+            "unix_datagram_socket_child_process",
+            "--exact",
+            "--nocapture",
+        ])
+        .env(CHILD_PROCESS_REQUEST, request)
+        .spawn()
+        .unwrap();
+
+    match child.wait().unwrap().code() {
+        Some(CHILD_PROCESS_SUCCESS) => Some(child.id()),
+        _ => None,
+    }
+}
+
+#[test]
+fn unix_datagram_socket_receiving_fd_from_other_process_works() {
+    test_requires!(POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS_ANCILLARY_DATA);
+
+    let mut test = TestFixture::new();
+
+    create_test_directory();
+    let socket_name = generate_socket_name();
+    let sut_receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+
+    let mut file_content = "the file of another process".to_string();
+    test.create_file_with_content(&mut file_content);
+    let file_name = test.files[0].clone();
+
+    assert_that!(
+        run_in_other_process(&format!("send_file;{socket_name};{file_name}")),
+        is_some
+    );
+
+    let mut fd_vec = vec![];
+    let mut receive_data = [0u8; 32];
+    let bytes_received = sut_receiver
+        .try_receive_with_fds(&mut receive_data, &mut fd_vec)
+        .unwrap();
+
+    assert_that!(
+        receive_data[..bytes_received as usize],
+        eq * CHILD_PROCESS_DATA
+    );
+    assert_that!(fd_vec, len 1);
+
+    // the process which opened the file is gone, only the received descriptor keeps it open
+    let file_receiver = File::from_file_descriptor(fd_vec.remove(0));
+    let mut file_recv_content = String::new();
+    file_receiver
+        .read_to_string(&mut file_recv_content)
+        .unwrap();
+    assert_that!(file_recv_content, eq file_content);
+}
+
+#[test]
+fn unix_datagram_socket_truncated_fds_are_reported_and_closed() {
+    test_requires!(POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS_ANCILLARY_DATA);
+
+    create_test_directory();
+    let socket_name = generate_socket_name();
+
+    assert_that!(
+        run_in_other_process(&format!("receive_truncated_fds;{socket_name}")),
+        is_some
+    );
+}
+
+#[test]
+fn unix_datagram_socket_receiving_data_with_credentials_works() {
+    test_requires!(POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS_ANCILLARY_DATA);
+
+    create_test_directory();
+    let socket_name = generate_socket_name();
+    let sut_receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .with_credentials()
+        .create()
+        .unwrap();
+
+    let sut_sender = UnixDatagramSenderBuilder::new(&socket_name)
+        .create()
+        .unwrap();
+
+    let send_data = [13u8, 37, 73];
+    assert_that!(sut_sender.try_send(&send_data), eq Ok(true));
+
+    let mut receive_data = [0u8; 16];
+    let (bytes_received, credentials) = sut_receiver
+        .try_receive_with_credentials(&mut receive_data)
+        .unwrap();
+
+    assert_that!(receive_data[..bytes_received as usize], eq send_data);
+    assert_that!(credentials, eq Some(SocketCred::new()));
+
+    assert_that!(sut_receiver.try_receive_with_credentials(&mut receive_data), eq Ok((0, None)));
+}
+
+#[test]
+fn unix_datagram_socket_blocking_receiving_data_with_credentials_works() {
+    test_requires!(POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS_ANCILLARY_DATA);
+
+    create_test_directory();
+    let socket_name = generate_socket_name();
+    let sut_receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .with_credentials()
+        .create()
+        .unwrap();
+
+    let sut_sender = UnixDatagramSenderBuilder::new(&socket_name)
+        .create()
+        .unwrap();
+
+    let send_data = [37u8, 13, 73, 42];
+    assert_that!(sut_sender.try_send(&send_data), eq Ok(true));
+
+    let mut receive_data = [0u8; 16];
+    let (bytes_received, credentials) = sut_receiver
+        .blocking_receive_with_credentials(&mut receive_data)
+        .unwrap();
+
+    assert_that!(receive_data[..bytes_received as usize], eq send_data);
+    assert_that!(credentials, eq Some(SocketCred::new()));
+}
+
+#[test]
+fn unix_datagram_socket_receiving_credentials_from_other_process_works() {
+    test_requires!(POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS_ANCILLARY_DATA);
+
+    create_test_directory();
+    let socket_name = generate_socket_name();
+    let sut_receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .with_credentials()
+        .create()
+        .unwrap();
+
+    let child_pid = run_in_other_process(&format!("send_data;{socket_name}"));
+    assert_that!(child_pid, is_some);
+
+    let mut receive_data = [0u8; 32];
+    let (bytes_received, credentials) = sut_receiver
+        .try_receive_with_credentials(&mut receive_data)
+        .unwrap();
+
+    assert_that!(
+        receive_data[..bytes_received as usize],
+        eq * CHILD_PROCESS_DATA
+    );
+    assert_that!(credentials, is_some);
+
+    let credentials = credentials.unwrap();
+    assert_that!(credentials.get_pid().value() as u32, eq child_pid.unwrap());
+    assert_that!(credentials.get_uid(), eq SocketCred::new().get_uid());
+    assert_that!(credentials.get_gid(), eq SocketCred::new().get_gid());
+}
+
+#[test]
+fn unix_datagram_socket_receiving_credentials_when_they_are_disabled_fails() {
+    create_test_directory();
+    let socket_name = generate_socket_name();
+    let sut_receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .without_credentials()
+        .create()
+        .unwrap();
+
+    let mut receive_data = [0u8; 16];
+    assert_that!(sut_receiver.try_receive_with_credentials(&mut receive_data),
+        eq Err(UnixDatagramReceiveFdError::CredentialsNotEnabled));
+}
+
+#[test]
+fn unix_datagram_socket_creating_receiver_with_unsupported_credentials_fails() {
+    test_requires!(!POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS_ANCILLARY_DATA);
+
+    create_test_directory();
+    let socket_name = generate_socket_name();
+    let sut = UnixDatagramReceiverBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .with_credentials()
+        .create();
+
+    assert_that!(sut.err(), eq Some(UnixDatagramReceiverCreationError::CredentialsUnsupported));
+}
@@ -0,0 +1,295 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::time::Duration;
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_posix::config::*;
+use iceoryx2_bb_posix::file::*;
+use iceoryx2_bb_posix::file_descriptor::*;
+use iceoryx2_bb_posix::file_descriptor_set::*;
+use iceoryx2_bb_posix::testing::create_test_directory;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_posix::unix_stream_socket::*;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_bb_testing::assert_that;
+use std::thread;
+use std::time::Instant;
+
+const TIMEOUT: Duration = Duration::from_millis(100);
+
+fn generate_socket_name() -> FilePath {
+    let mut file = FileName::new(b"unix_stream_socket_tests").unwrap();
+    file.push_bytes(
+        UniqueSystemId::new()
+            .unwrap()
+            .value()
+            .to_string()
+            .as_bytes(),
+    )
+    .unwrap();
+
+    FilePath::from_path_and_file(&test_directory(), &file).unwrap()
+}
+
+fn create_connection() -> (UnixStreamListener, UnixStreamSocket, UnixStreamSocket) {
+    create_test_directory();
+    let socket_name = generate_socket_name();
+    let listener = UnixStreamListenerBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+    let client = UnixStreamSenderBuilder::new(&socket_name).create().unwrap();
+    let server = listener.blocking_accept().unwrap();
+
+    (listener, client, server)
+}
+
+#[test]
+fn unix_stream_socket_send_receive_works() {
+    let (_listener, client, server) = create_connection();
+
+    let data = b"the first rule of unix domain sockets";
+    assert_that!(client.blocking_send(data), eq Ok(data.len()));
+
+    let mut buffer = [0u8; 64];
+    let received = server.blocking_receive(&mut buffer).unwrap();
+    assert_that!(&buffer[..received], eq & data[..]);
+
+    assert_that!(server.try_send(b"reply"), eq Ok(5));
+    let received = client.timed_receive(&mut buffer, TIMEOUT).unwrap();
+    assert_that!(&buffer[..received], eq b"reply");
+}
+
+#[test]
+fn unix_stream_socket_partial_reads_preserve_the_stream() {
+    let (_listener, client, server) = create_connection();
+
+    let data: Vec<u8> = (0..64u8).collect();
+    assert_that!(client.blocking_send(&data), eq Ok(data.len()));
+
+    let mut received_data = vec![];
+    let mut buffer = [0u8; 7];
+    while received_data.len() < data.len() {
+        let received = server.timed_receive(&mut buffer, TIMEOUT).unwrap();
+        assert_that!(received, le buffer.len());
+        assert_that!(received, ne 0);
+        received_data.extend_from_slice(&buffer[..received]);
+    }
+
+    assert_that!(received_data, eq data);
+    assert_that!(server.try_receive(&mut buffer), eq Ok(0));
+}
+
+#[test]
+fn unix_stream_socket_try_receive_without_data_returns_zero() {
+    let (_listener, client, server) = create_connection();
+
+    let mut buffer = [0u8; 16];
+    assert_that!(server.try_receive(&mut buffer), eq Ok(0));
+    assert_that!(client.try_receive(&mut buffer), eq Ok(0));
+}
+
+#[test]
+fn unix_stream_socket_timed_receive_blocks_at_least_timeout() {
+    let (_listener, _client, server) = create_connection();
+
+    let mut buffer = [0u8; 16];
+    let start = Instant::now();
+    assert_that!(server.timed_receive(&mut buffer, TIMEOUT), eq Ok(0));
+    assert_that!(start.elapsed(), ge TIMEOUT);
+}
+
+#[test]
+fn unix_stream_socket_try_send_returns_zero_when_buffer_is_full() {
+    let (_listener, client, server) = create_connection();
+
+    let data = [0xafu8; 4096];
+    let mut bytes_sent = 0;
+    loop {
+        let sent = client.try_send(&data).unwrap();
+        if sent == 0 {
+            break;
+        }
+        bytes_sent += sent;
+    }
+    assert_that!(bytes_sent, gt 0);
+
+    let start = Instant::now();
+    assert_that!(client.timed_send(&data, TIMEOUT), eq Ok(0));
+    assert_that!(start.elapsed(), ge TIMEOUT);
+
+    let mut buffer = [0u8; 4096];
+    assert_that!(server.try_receive(&mut buffer).unwrap(), gt 0);
+}
+
+#[test]
+fn unix_stream_socket_send_to_closed_peer_fails_with_disconnected() {
+    let (_listener, client, server) = create_connection();
+
+    drop(server);
+
+    assert_that!(client.try_send(b"is anybody out there"), eq Err(UnixStreamSendError::Disconnected));
+    assert_that!(client.blocking_send(b"hello?"), eq Err(UnixStreamSendError::Disconnected));
+}
+
+#[test]
+fn unix_stream_socket_receive_from_closed_peer_delivers_pending_data_then_disconnects() {
+    let (_listener, client, server) = create_connection();
+
+    assert_that!(client.blocking_send(b"last words"), eq Ok(10));
+    drop(client);
+
+    let mut buffer = [0u8; 16];
+    assert_that!(server.try_receive(&mut buffer), eq Ok(10));
+    assert_that!(&buffer[..10], eq b"last words");
+    assert_that!(server.try_receive(&mut buffer), eq Err(UnixStreamReceiveError::Disconnected));
+    assert_that!(server.blocking_receive(&mut buffer), eq Err(UnixStreamReceiveError::Disconnected));
+}
+
+#[test]
+fn unix_stream_socket_shutdown_write_disconnects_peer_receiver() {
+    let (_listener, client, server) = create_connection();
+
+    assert_that!(client.shutdown(UnixStreamShutdown::Write), is_ok);
+    assert_that!(client.try_send(b"x"), eq Err(UnixStreamSendError::Disconnected));
+
+    let mut buffer = [0u8; 16];
+    assert_that!(server.try_receive(&mut buffer), eq Err(UnixStreamReceiveError::Disconnected));
+
+    // the other direction is still intact
+    assert_that!(server.blocking_send(b"still here"), eq Ok(10));
+    assert_that!(client.timed_receive(&mut buffer, TIMEOUT), eq Ok(10));
+}
+
+#[test]
+fn unix_stream_socket_try_accept_without_pending_connection_returns_none() {
+    create_test_directory();
+    let socket_name = generate_socket_name();
+    let listener = UnixStreamListenerBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+
+    assert_that!(listener.try_accept().unwrap(), is_none);
+
+    let _client = UnixStreamSenderBuilder::new(&socket_name).create().unwrap();
+    assert_that!(listener.try_accept().unwrap(), is_some);
+    assert_that!(listener.try_accept().unwrap(), is_none);
+}
+
+#[test]
+fn unix_stream_socket_blocking_accept_waits_for_connection() {
+    create_test_directory();
+    let socket_name = generate_socket_name();
+    let listener = UnixStreamListenerBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+
+    thread::scope(|s| {
+        s.spawn(|| {
+            thread::sleep(TIMEOUT);
+            let client = UnixStreamSenderBuilder::new(&socket_name).create().unwrap();
+            assert_that!(client.blocking_send(b"knock knock"), eq Ok(11));
+        });
+
+        let start = Instant::now();
+        let server = listener.blocking_accept().unwrap();
+        assert_that!(start.elapsed(), ge TIMEOUT);
+
+        let mut buffer = [0u8; 16];
+        assert_that!(server.blocking_receive(&mut buffer), eq Ok(11));
+    });
+}
+
+#[test]
+fn unix_stream_socket_listener_in_exclusive_mode_fails_when_socket_exists() {
+    create_test_directory();
+    let socket_name = generate_socket_name();
+    let _listener = UnixStreamListenerBuilder::new(&socket_name)
+        .create()
+        .unwrap();
+
+    let sut = UnixStreamListenerBuilder::new(&socket_name)
+        .creation_mode(CreationMode::CreateExclusive)
+        .create();
+    assert_that!(sut.err().unwrap(), eq UnixStreamListenerCreationError::SocketFileAlreadyExists);
+    assert_that!(File::does_exist(&socket_name).unwrap(), eq true);
+}
+
+#[test]
+fn unix_stream_socket_listener_removes_socket_file_on_drop() {
+    create_test_directory();
+    let socket_name = generate_socket_name();
+    let listener = UnixStreamListenerBuilder::new(&socket_name)
+        .create()
+        .unwrap();
+    assert_that!(*listener.name(), eq socket_name);
+    assert_that!(File::does_exist(&socket_name).unwrap(), eq true);
+
+    drop(listener);
+    assert_that!(File::does_exist(&socket_name).unwrap(), eq false);
+}
+
+#[test]
+fn unix_stream_socket_sender_without_listener_fails() {
+    create_test_directory();
+    let socket_name = generate_socket_name();
+
+    let sut = UnixStreamSenderBuilder::new(&socket_name).create();
+    assert_that!(sut.err().unwrap(), eq UnixStreamSenderCreationError::DoesNotExist);
+}
+
+#[test]
+fn unix_stream_socket_accepted_connection_outlives_listener() {
+    let (listener, client, server) = create_connection();
+    drop(listener);
+
+    assert_that!(client.blocking_send(b"abc"), eq Ok(3));
+    let mut buffer = [0u8; 4];
+    assert_that!(server.blocking_receive(&mut buffer), eq Ok(3));
+}
+
+#[test]
+fn unix_stream_socket_can_be_attached_to_file_descriptor_set() {
+    create_test_directory();
+    let socket_name = generate_socket_name();
+    let listener = UnixStreamListenerBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+    let client = UnixStreamSenderBuilder::new(&socket_name).create().unwrap();
+
+    let fd_set = FileDescriptorSet::new();
+    let _listener_guard = fd_set.add(&listener).unwrap();
+
+    let mut triggered_fds = vec![];
+    fd_set
+        .timed_wait(TIMEOUT, FileEvent::Read, |fd| {
+            triggered_fds.push(unsafe { fd.native_handle() })
+        })
+        .unwrap();
+    assert_that!(triggered_fds, eq vec![unsafe { listener.file_descriptor().native_handle() }]);
+
+    let server = listener.try_accept().unwrap().unwrap();
+    let _server_guard = fd_set.add(&server).unwrap();
+    client.blocking_send(b"wake up").unwrap();
+
+    let mut triggered_fds = vec![];
+    fd_set
+        .timed_wait(TIMEOUT, FileEvent::Read, |fd| {
+            triggered_fds.push(unsafe { fd.native_handle() })
+        })
+        .unwrap();
+    assert_that!(triggered_fds, eq vec![unsafe { server.file_descriptor().native_handle() }]);
+}
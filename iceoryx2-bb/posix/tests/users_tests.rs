@@ -40,3 +40,64 @@ fn user_works() {
 
     assert_that!(root_details.shell(), eq root_from_uid_details.shell());
 }
+
+#[test]
+fn user_from_self_can_be_resolved_by_name() {
+    test_requires!(POSIX_SUPPORT_USERS_AND_GROUPS);
+
+    let sut = User::from_self().unwrap();
+    let name = sut.details().unwrap().name().clone();
+
+    let user_from_name = User::from_name(&name).unwrap();
+    assert_that!(user_from_name.uid(), eq sut.uid());
+    assert_that!(*user_from_name.details().unwrap().name(), eq name);
+}
+
+#[test]
+fn user_supplementary_groups_of_process_owner_can_be_acquired() {
+    test_requires!(POSIX_SUPPORT_USERS_AND_GROUPS);
+
+    let sut = User::from_self().unwrap();
+    let groups = sut.supplementary_groups().unwrap();
+
+    let mut number_of_groups = 0;
+    let mut gids = [0 as iceoryx2_pal_posix::posix::gid_t; 256];
+    let n = unsafe { iceoryx2_pal_posix::posix::getgroups(256, gids.as_mut_ptr()) };
+    assert_that!(n, ge 0);
+    for gid in gids.iter().take(n as usize) {
+        assert_that!(groups.iter().any(|g| g.gid().to_native() == *gid), eq true);
+        number_of_groups += 1;
+    }
+    assert_that!(groups, len number_of_groups);
+}
+
+#[test]
+fn user_supplementary_groups_of_other_user_fail() {
+    test_requires!(POSIX_SUPPORT_USERS_AND_GROUPS);
+
+    let myself = User::from_self().unwrap();
+    let other_uid = if myself.uid().value() == 0 { 1 } else { 0 };
+    let sut = User::from_uid(Uid::new(other_uid).unwrap()).unwrap();
+
+    assert_that!(sut.supplementary_groups().err(), eq Some(UserError::NotOwnerOfCurrentProcess));
+}
+
+#[test]
+fn process_identity_is_cached_until_invalidated() {
+    test_requires!(POSIX_SUPPORT_USERS_AND_GROUPS);
+
+    let sut = ProcessIdentity::current().unwrap();
+    let myself = User::from_self().unwrap();
+
+    let supplementary_groups = myself.supplementary_groups().unwrap();
+    assert_that!(*sut.user(), eq myself);
+    assert_that!(sut.supplementary_groups(), eq supplementary_groups.as_slice());
+    assert_that!(sut.is_member_of(sut.gid()), eq true);
+    for group in sut.supplementary_groups() {
+        assert_that!(sut.is_member_of(group.gid()), eq true);
+    }
+
+    assert_that!(ProcessIdentity::current().unwrap(), eq sut);
+    ProcessIdentity::invalidate_cache();
+    assert_that!(ProcessIdentity::current().unwrap(), eq sut);
+}
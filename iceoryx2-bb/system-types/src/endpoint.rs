@@ -0,0 +1,220 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Relocatable (inter-process shared memory compatible) [`SemanticString`] implementation for
+//! [`Endpoint`]. An [`Endpoint`] describes a network or local communication endpoint in the
+//! form `scheme://host[:port][/path]`, like `tcp://10.0.0.5:9000` or `uds:///tmp/sock`.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//! use iceoryx2_bb_system_types::endpoint::*;
+//!
+//! let endpoint = Endpoint::new(b"tcp://10.0.0.5:9000").expect("invalid endpoint");
+//! assert!(endpoint.scheme() == "tcp");
+//! assert!(endpoint.host() == "10.0.0.5");
+//! assert!(endpoint.port().unwrap().as_u16() == 9000);
+//!
+//! let endpoint = Endpoint::new(b"uds:///tmp/sock").expect("invalid endpoint");
+//! assert!(endpoint.host().is_empty());
+//! assert!(endpoint.path() == "/tmp/sock");
+//!
+//! let invalid_endpoint = Endpoint::new(b"tcp://10.0.0.5:90000");
+//! assert!(invalid_endpoint.is_err());
+//! ```
+
+pub use iceoryx2_bb_container::semantic_string::SemanticString;
+
+use core::hash::{Hash, Hasher};
+use iceoryx2_bb_container::semantic_string;
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
+
+use crate::port::Port;
+
+const ENDPOINT_LENGTH: usize = 255;
+const SCHEME_SEPARATOR: &[u8] = b"://";
+const PORT_SEPARATOR: u8 = b':';
+const PATH_START: u8 = b'/';
+
+struct Components<'a> {
+    scheme: &'a [u8],
+    host: &'a [u8],
+    port: Option<&'a [u8]>,
+    path: &'a [u8],
+}
+
+impl<'a> Components<'a> {
+    fn parse(value: &'a [u8]) -> Option<Self> {
+        let scheme_end = value
+            .windows(SCHEME_SEPARATOR.len())
+            .position(|v| v == SCHEME_SEPARATOR)?;
+        let scheme = &value[..scheme_end];
+        let remainder = &value[scheme_end + SCHEME_SEPARATOR.len()..];
+
+        let authority_end = remainder
+            .iter()
+            .position(|c| *c == PATH_START)
+            .unwrap_or(remainder.len());
+        let (authority, path) = remainder.split_at(authority_end);
+        let (host, port) = Self::split_authority(authority)?;
+
+        if !is_valid_scheme(scheme) || !is_valid_host(host) {
+            return None;
+        }
+
+        if let Some(port) = port {
+            if host.is_empty() || parse_port(port).is_none() {
+                return None;
+            }
+        }
+
+        Some(Self {
+            scheme,
+            host,
+            port,
+            path,
+        })
+    }
+
+    fn split_authority(authority: &[u8]) -> Option<(&[u8], Option<&[u8]>)> {
+        if authority.first() == Some(&b'[') {
+            let host_end = authority.iter().position(|c| *c == b']')? + 1;
+            return match &authority[host_end..] {
+                [] => Some((&authority[..host_end], None)),
+                [PORT_SEPARATOR, port @ ..] => Some((&authority[..host_end], Some(port))),
+                _ => None,
+            };
+        }
+
+        match authority.iter().rposition(|c| *c == PORT_SEPARATOR) {
+            Some(n) => Some((&authority[..n], Some(&authority[n + 1..]))),
+            None => Some((authority, None)),
+        }
+    }
+}
+
+fn is_valid_scheme(value: &[u8]) -> bool {
+    match value {
+        [first, remainder @ ..] => {
+            first.is_ascii_alphabetic()
+                && remainder
+                    .iter()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, b'+' | b'-' | b'.'))
+        }
+        [] => false,
+    }
+}
+
+fn is_valid_host(value: &[u8]) -> bool {
+    match value {
+        [b'[', ipv6 @ .., b']'] => {
+            !ipv6.is_empty()
+                && ipv6
+                    .iter()
+                    .all(|c| c.is_ascii_hexdigit() || matches!(c, b':' | b'.'))
+        }
+        _ => value
+            .iter()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, b'-' | b'.' | b'_' | b'~')),
+    }
+}
+
+fn parse_port(value: &[u8]) -> Option<Port> {
+    if value.is_empty() || value.len() > 5 || !value.iter().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let port = value
+        .iter()
+        .fold(0u32, |port, c| port * 10 + (*c - b'0') as u32);
+
+    u16::try_from(port).ok().map(Port::new)
+}
+
+fn invalid_content(value: &[u8]) -> bool {
+    Components::parse(value).is_none()
+}
+
+fn invalid_characters(value: &[u8]) -> bool {
+    for c in value {
+        match c {
+            0..=32 => return true,
+            127..=255 => return true,
+            b'"' | b'<' | b'>' | b'\\' | b'^' | b'`' | b'{' | b'|' | b'}' => return true,
+            _ => (),
+        }
+    }
+
+    false
+}
+
+fn normalize(this: &Endpoint) -> Endpoint {
+    let mut raw_endpoint = [0u8; ENDPOINT_LENGTH];
+    let value = this.as_bytes();
+    raw_endpoint[..value.len()].copy_from_slice(value);
+
+    // scheme and host are case insensitive
+    let components = this.components();
+    let host_end = components.scheme.len() + SCHEME_SEPARATOR.len() + components.host.len();
+    raw_endpoint[..host_end].make_ascii_lowercase();
+
+    // SAFETY
+    // * lowercasing scheme and host does not invalidate the endpoint
+    unsafe { Endpoint::new_unchecked(&raw_endpoint[..value.len()]) }
+}
+
+semantic_string! {
+  /// Represents an endpoint in the form `scheme://host[:port][/path]`. The scheme and host are
+  /// compared case insensitively.
+  name: Endpoint,
+  capacity: ENDPOINT_LENGTH,
+  invalid_content: invalid_content,
+  invalid_characters: invalid_characters,
+  normalize: normalize
+}
+
+impl Endpoint {
+    fn components(&self) -> Components<'_> {
+        Components::parse(self.as_bytes())
+            .expect("By contract, an Endpoint always contains a valid endpoint")
+    }
+
+    /// Returns the scheme of the endpoint, e.g. `tcp` for `tcp://10.0.0.5:9000`.
+    pub fn scheme(&self) -> &str {
+        // SAFETY
+        // * the scheme consists only of ASCII characters
+        unsafe { core::str::from_utf8_unchecked(self.components().scheme) }
+    }
+
+    /// Returns the host of the endpoint, e.g. `10.0.0.5` for `tcp://10.0.0.5:9000`. The host
+    /// is empty for endpoints like `uds:///tmp/sock`.
+    pub fn host(&self) -> &str {
+        // SAFETY
+        // * the host consists only of ASCII characters
+        unsafe { core::str::from_utf8_unchecked(self.components().host) }
+    }
+
+    /// Returns the [`Port`] of the endpoint, if one was provided.
+    pub fn port(&self) -> Option<Port> {
+        self.components().port.and_then(parse_port)
+    }
+
+    /// Returns the path of the endpoint including the leading `/`, e.g. `/tmp/sock` for
+    /// `uds:///tmp/sock`. If the endpoint has no path it is empty.
+    pub fn path(&self) -> &str {
+        // SAFETY
+        // * the path consists only of ASCII characters
+        unsafe { core::str::from_utf8_unchecked(self.components().path) }
+    }
+}
@@ -34,8 +34,20 @@ use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 
 use core::hash::{Hash, Hasher};
 use iceoryx2_bb_container::semantic_string;
+use iceoryx2_bb_container::semantic_string::SemanticStringError;
+use iceoryx2_bb_container::string::as_escaped_string;
+use iceoryx2_bb_log::fail;
 use iceoryx2_pal_configuration::FILENAME_LENGTH;
 
+use crate::sip_hash::sip_hash_2_4;
+
+// Changing the keys changes all file names created with FileName::from_hashed()
+const HASHED_FILE_NAME_KEYS: [[u64; 2]; 2] = [
+    [0x69636530727978c2, 0x5f66696c656e616d],
+    [0x655f686173686564, 0x5f6b65795f74776f],
+];
+const HASHED_FILE_NAME_LENGTH: usize = HASHED_FILE_NAME_KEYS.len() * 16;
+
 fn invalid_characters(value: &[u8]) -> bool {
     for c in value {
         match c {
@@ -76,6 +88,49 @@ semantic_string! {
   normalize: normalize
 }
 
+impl FileName {
+    /// Returns a new [`FileName`] that starts with the provided `prefix` followed by the
+    /// current name. Fails when the result would exceed the maximum length or contain illegal
+    /// characters.
+    pub fn with_prefix(&self, prefix: &[u8]) -> Result<FileName, SemanticStringError> {
+        let mut new_self = self.clone();
+        fail!(from self, when new_self.insert_bytes(0, prefix),
+            "Unable to add the prefix \"{}\" to the file name.", as_escaped_string(prefix));
+        Ok(new_self)
+    }
+
+    /// Returns a new [`FileName`] that consists of the current name followed by the provided
+    /// `suffix`. Fails when the result would exceed the maximum length or contain illegal
+    /// characters.
+    pub fn with_suffix(&self, suffix: &[u8]) -> Result<FileName, SemanticStringError> {
+        let mut new_self = self.clone();
+        fail!(from self, when new_self.push_bytes(suffix),
+            "Unable to add the suffix \"{}\" to the file name.", as_escaped_string(suffix));
+        Ok(new_self)
+    }
+
+    /// Creates a deterministic [`FileName`] from an arbitrary long `value`, for instance a
+    /// name that does not fit into a [`FileName`]. It consists of the `prefix` followed by the
+    /// 32 character hex representation of two 64-bit SipHash-2-4 values of `value`, each
+    /// computed with a different key. The result is identical in every process and does not
+    /// change between versions.
+    /// Fails when the `prefix` is too long to append the hash.
+    pub fn from_hashed(value: &[u8], prefix: &FileName) -> Result<FileName, SemanticStringError> {
+        const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+        let mut hash = [0u8; HASHED_FILE_NAME_LENGTH];
+        let hash_bytes = HASHED_FILE_NAME_KEYS
+            .iter()
+            .flat_map(|key| sip_hash_2_4(*key, value).to_be_bytes());
+        for (digits, byte) in hash.chunks_exact_mut(2).zip(hash_bytes) {
+            digits[0] = HEX_DIGITS[(byte >> 4) as usize];
+            digits[1] = HEX_DIGITS[(byte & 0x0f) as usize];
+        }
+
+        prefix.with_suffix(&hash)
+    }
+}
+
 #[derive(Debug, Clone, Eq, ZeroCopySend)]
 #[repr(C)]
 pub struct RestrictedFileName<const CAPACITY: usize> {
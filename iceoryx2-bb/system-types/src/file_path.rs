@@ -34,10 +34,11 @@ use crate::path::Path;
 use core::hash::{Hash, Hasher};
 use iceoryx2_bb_container::semantic_string;
 use iceoryx2_bb_container::semantic_string::SemanticStringError;
+use iceoryx2_bb_container::string::StaticString;
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::fail;
-use iceoryx2_pal_configuration::{PATH_LENGTH, PATH_SEPARATOR};
+use iceoryx2_pal_configuration::{FILENAME_LENGTH, PATH_LENGTH, PATH_SEPARATOR};
 
 semantic_string! {
   /// Represents a file path. The restriction are choosen in a way that it is platform independent.
@@ -119,6 +120,21 @@ impl FilePath {
         Ok(new_self)
     }
 
+    /// Creates a new [`FilePath`] from a given [`Path`] and a not yet validated file name.
+    /// Fails when the name is not a valid [`FileName`] or when the concatenation would exceed
+    /// the maximum supported length.
+    pub fn from_path_and_name(
+        path: &Path,
+        name: &StaticString<FILENAME_LENGTH>,
+    ) -> Result<Self, SemanticStringError> {
+        use iceoryx2_bb_container::string::String;
+        let file = fail!(from "FilePath::from_path_and_name()", when FileName::new(name.as_bytes()),
+                "Unable to create FilePath from path and name since \"{}\" is not a valid file name.",
+                name);
+
+        Self::from_path_and_file(path, &file)
+    }
+
     /// Returns the last file part ([`FileName`]) of the path.
     pub fn file_name(&self) -> FileName {
         let file_name = self
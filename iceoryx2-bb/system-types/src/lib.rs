@@ -17,6 +17,7 @@
 extern crate alloc;
 
 pub mod base64url;
+pub mod endpoint;
 pub mod file_name;
 pub mod file_path;
 pub mod group_name;
@@ -24,3 +25,5 @@ pub mod ipv4_address;
 pub mod path;
 pub mod port;
 pub mod user_name;
+
+mod sip_hash;
@@ -21,7 +21,9 @@
 //! use iceoryx2_bb_container::semantic_string::SemanticString;
 //! use iceoryx2_bb_system_types::path::*;
 //!
-//! let name = Path::new(b"some/path/../bla/some_file.txt");
+//! let name = Path::new(b"some/path/../bla/some_file.txt").unwrap();
+//! # #[cfg(not(target_os = "windows"))]
+//! assert!(name.normalize_lexical() == b"some/bla/some_file.txt");
 //!
 //! let invalid_name = Path::new(b"/contains/illegal/\0/zero");
 //! assert!(invalid_name.is_err());
@@ -29,26 +31,24 @@
 
 pub use iceoryx2_bb_container::semantic_string::SemanticString;
 
+use core::cmp::Ordering;
 use core::hash::{Hash, Hasher};
+use core::ops::Div;
 use iceoryx2_bb_container::semantic_string;
 use iceoryx2_bb_container::semantic_string::*;
+use iceoryx2_bb_container::string::{as_escaped_string, StaticString, String as _};
 use iceoryx2_bb_derive_macros::ZeroCopySend;
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
-use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_pal_configuration::{PATH_SEPARATOR, ROOT};
+use iceoryx2_pal_posix::posix::{self, Errno};
 
 use crate::file_name::FileName;
 use crate::file_path::FilePath;
 
 const PATH_LENGTH: usize = iceoryx2_pal_configuration::PATH_LENGTH;
 
-semantic_string! {
-  name: Path,
-  capacity: PATH_LENGTH,
-  invalid_content: |_: &[u8]| {
-    false
-  },
-  invalid_characters: |string: &[u8]| {
+fn invalid_characters(string: &[u8]) -> bool {
     for value in string {
         match value {
             // linux & windows
@@ -65,8 +65,53 @@ semantic_string! {
         }
     }
 
+    false
+}
+
+/// Defines how [`Path::new_sanitized()`] handles characters that are not allowed in a
+/// [`Path`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum PathSanitization {
+    /// Invalid characters are removed.
+    Strip,
+    /// Invalid characters are replaced with the provided character. If the replacement
+    /// itself is not a valid path character, invalid characters are removed instead.
+    Replace(u8),
+}
+
+/// Describes why [`Path::canonicalize()`] failed.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum PathError {
+    /// The path or one of its entries does not exist.
+    DoesNotExist,
+    /// An entry of the path prefix is not a directory.
+    NotADirectory,
+    /// An entry of the path prefix cannot be searched due to insufficient permissions.
+    InsufficientPermissions,
+    /// Too many symbolic links were encountered while resolving the path.
+    TooManySymbolicLinks,
+    /// The resolved path exceeds the maximum supported path length.
+    ExceedsMaximumLength,
+    /// The resolved path contains characters that are not supported by [`Path`].
+    InvalidContent,
+    UnknownError(i32),
+}
+
+impl core::fmt::Display for PathError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::write!(f, "PathError::{self:?}")
+    }
+}
+
+impl core::error::Error for PathError {}
+
+semantic_string! {
+  name: Path,
+  capacity: PATH_LENGTH,
+  invalid_content: |_: &[u8]| {
     false
   },
+  invalid_characters: invalid_characters,
   normalize: |this: &Path| {
         let mut raw_path = [0u8; PATH_LENGTH];
         let value = this.as_bytes();
@@ -119,6 +164,349 @@ impl Path {
         Ok(())
     }
 
+    /// Returns a new [`Path`] that consists of the path followed by `entry`. A separator is
+    /// added when the path does not end with one - except when it is empty. It fails when the
+    /// entry contains invalid characters or when the resulting path would exceed the maximum
+    /// supported path length. It is the non-panicking counterpart of the `/` operator.
+    ///
+    /// ```
+    /// use iceoryx2_bb_container::semantic_string::SemanticString;
+    /// use iceoryx2_bb_system_types::path::*;
+    ///
+    /// let base = Path::new(b"some").unwrap();
+    /// let path = base.join(b"deep").unwrap().join(b"file.txt").unwrap();
+    /// # #[cfg(not(target_os = "windows"))]
+    /// assert!(path == b"some/deep/file.txt");
+    /// assert!(path == &base / "deep" / "file.txt");
+    /// ```
+    pub fn join(&self, entry: &[u8]) -> Result<Path, SemanticStringError> {
+        let mut builder = PathBuilder::new(self);
+        fail!(from self, when builder.push_entry(entry),
+            "Unable to join the entry \"{}\" to the path.", as_escaped_string(entry));
+        Ok(builder.build())
+    }
+
+    /// Appends all entries of the relative path `relative` to the path. Separators are added
+    /// where required and redundant separators in `relative` are dropped. The operation is
+    /// atomic, if it fails the path stays unchanged.
+    /// If `relative` is an absolute path it fails with [`SemanticStringError::InvalidContent`].
+    pub fn push_path(&mut self, relative: &Path) -> Result<(), SemanticStringError> {
+        let msg = format!("Unable to push path \"{relative}\" to path");
+        if relative.is_absolute() {
+            fail!(from self, with SemanticStringError::InvalidContent,
+                "{} since it is an absolute path.", msg);
+        }
+
+        let mut new_self = self.clone();
+        for entry in relative.entries() {
+            fail!(from self, when new_self.add_path_entry(&entry.into()),
+                "{} since it would exceed the maximum supported path length of {PATH_LENGTH}.", msg);
+        }
+
+        *self = new_self;
+        Ok(())
+    }
+
+    /// Removes the last entry of the path and returns it. Trailing separators are ignored.
+    /// The root path and the empty path stay unchanged and [`None`] is returned. It is the
+    /// counterpart of [`Path::add_path_entry()`], adding the returned entry restores the
+    /// original path.
+    pub fn pop_entry(&mut self) -> Option<FileName> {
+        let root_len = self.root_len();
+        let bytes = self.as_bytes();
+
+        let mut end = bytes.len();
+        while end > root_len && bytes[end - 1] == PATH_SEPARATOR {
+            end -= 1;
+        }
+
+        if end == root_len {
+            return None;
+        }
+
+        let mut start = end;
+        while start > root_len && bytes[start - 1] != PATH_SEPARATOR {
+            start -= 1;
+        }
+
+        // SAFETY
+        // * the entry does not contain a path separator and consists only of valid path
+        //   characters
+        let entry = unsafe { FileName::new_unchecked(&bytes[start..end]) };
+
+        let mut new_len = start;
+        while new_len > root_len && bytes[new_len - 1] == PATH_SEPARATOR {
+            new_len -= 1;
+        }
+        self.truncate(new_len)
+            .expect("a truncated path never contains invalid content");
+
+        Some(entry)
+    }
+
+    /// Removes the last entry of the path so that it points to the parent directory. Returns
+    /// `false` when the path is the root path or empty, otherwise `true`.
+    pub fn truncate_to_parent(&mut self) -> bool {
+        self.pop_entry().is_some()
+    }
+
+    /// Keeps the root, if the path is absolute, and the first `n` entries of the path and
+    /// discards everything that follows, including the trailing separator. Redundant
+    /// separators are not counted as entries. When the path has `n` or fewer entries only
+    /// the trailing separators are removed. With `n == 0` an absolute path becomes the root
+    /// path and a relative path becomes empty.
+    ///
+    /// ```
+    /// use iceoryx2_bb_container::semantic_string::SemanticString;
+    /// use iceoryx2_bb_system_types::path::*;
+    ///
+    /// # #[cfg(not(target_os = "windows"))]
+    /// # {
+    /// let mut path = Path::new(b"/a/b/c/").unwrap();
+    /// path.truncate_components(2);
+    /// assert!(path == b"/a/b");
+    /// # }
+    /// ```
+    pub fn truncate_components(&mut self, n: usize) {
+        let root_len = self.root_len();
+        let bytes = self.as_bytes();
+
+        let mut number_of_entries = 0;
+        let mut new_len = root_len;
+        let mut position = root_len;
+        while position < bytes.len() {
+            if bytes[position] == PATH_SEPARATOR {
+                position += 1;
+                continue;
+            }
+
+            if number_of_entries == n {
+                break;
+            }
+
+            while position < bytes.len() && bytes[position] != PATH_SEPARATOR {
+                position += 1;
+            }
+            number_of_entries += 1;
+            new_len = position;
+        }
+
+        self.truncate(new_len)
+            .expect("a truncated path never contains invalid content");
+    }
+
+    /// Returns a copy of the path where all separators are replaced with the Unix path
+    /// separator `/`. Drive letters stay unchanged, `C:\a\b` becomes `C:/a/b`.
+    pub fn to_unix_style(&self) -> Path {
+        self.with_separator(b'/')
+    }
+
+    /// Returns a copy of the path where all separators are replaced with the Windows path
+    /// separator `\`. Drive letters stay unchanged, `C:/a/b` becomes `C:\a\b`.
+    pub fn to_windows_style(&self) -> Path {
+        self.with_separator(b'\\')
+    }
+
+    /// Returns a copy of the path where all `.` entries are removed and every `..` entry is
+    /// folded with its preceding entry, without accessing the file system. Leading `..`
+    /// entries of a relative path are kept, on an absolute path they are dropped since
+    /// nothing is above the root. In contrast to [`Path::canonicalize()`] symbolic links are
+    /// not resolved.
+    ///
+    /// ```
+    /// use iceoryx2_bb_container::semantic_string::SemanticString;
+    /// use iceoryx2_bb_system_types::path::*;
+    ///
+    /// # #[cfg(not(target_os = "windows"))]
+    /// # {
+    /// let path = Path::new(b"a/b/../c").unwrap();
+    /// assert!(path.normalize_lexical() == b"a/c");
+    ///
+    /// let path = Path::new(b"../a/../../b").unwrap();
+    /// assert!(path.normalize_lexical() == b"../../b");
+    ///
+    /// let path = Path::new(b"/../x").unwrap();
+    /// assert!(path.normalize_lexical() == b"/x");
+    /// # }
+    /// ```
+    pub fn normalize_lexical(&self) -> Path {
+        let root_len = self.root_len();
+        let mut entries: Vec<&[u8]> = vec![];
+
+        for entry in self.as_bytes()[root_len..]
+            .split(|c| *c == PATH_SEPARATOR)
+            .filter(|entry| !entry.is_empty() && *entry != b".")
+        {
+            if entry != b".." {
+                entries.push(entry);
+                continue;
+            }
+
+            match entries.last() {
+                Some(last) if *last != b".." => {
+                    entries.pop();
+                }
+                // a relative path can point above its starting point
+                _ if root_len == 0 => entries.push(entry),
+                // nothing is above the root of an absolute path
+                _ => (),
+            }
+        }
+
+        let mut raw_path = [0u8; PATH_LENGTH];
+        raw_path[..root_len].copy_from_slice(&self.as_bytes()[..root_len]);
+        let mut n = root_len;
+        for (i, entry) in entries.iter().enumerate() {
+            if i > 0 {
+                raw_path[n] = PATH_SEPARATOR;
+                n += 1;
+            }
+            raw_path[n..n + entry.len()].copy_from_slice(entry);
+            n += entry.len();
+        }
+
+        // SAFETY
+        // * raw_path consists only of entries of the valid path `self` and is never longer
+        //   than `self`
+        unsafe { Path::new_unchecked(&raw_path[..n]) }
+    }
+
+    /// Returns `true` when both paths are logically equal, see [`Path::normalized_eq()`].
+    pub fn semantic_eq(&self, other: &Path) -> bool {
+        self.normalized_eq(other)
+    }
+
+    /// Returns `true` when both paths consist of the same sequence of components. All path
+    /// comparisons share this rule: redundant and trailing separators as well as `.` entries
+    /// are ignored and on Windows every component, including the drive letter, is compared
+    /// case insensitively.
+    pub fn normalized_eq(&self, other: &Path) -> bool {
+        self.is_absolute() == other.is_absolute()
+            && self.components().count() == other.components().count()
+            && self.starts_with(other)
+    }
+
+    /// Compares the paths component by component instead of byte-wise like the derived [`Ord`].
+    /// A path is therefore always ordered directly before the paths it contains, `a` < `a/b` <
+    /// `a.txt`, so that a sorted list of paths is grouped like a tree. Relative paths are ordered
+    /// before absolute ones, redundant separators and `.` entries are ignored.
+    pub fn cmp_by_components(&self, other: &Path) -> Ordering {
+        let ordering = self.is_absolute().cmp(&other.is_absolute());
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+
+        let mut lhs_components = self.components();
+        let mut rhs_components = other.components();
+        loop {
+            match (lhs_components.next(), rhs_components.next()) {
+                (None, None) => return Ordering::Equal,
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(lhs), Some(rhs)) => match Self::component_cmp(lhs, rhs) {
+                    Ordering::Equal => (),
+                    ordering => return ordering,
+                },
+            }
+        }
+    }
+
+    /// Returns `true` when the components of `prefix` are the leading components of the path.
+    /// In contrast to a byte-wise comparison `/foo/bar` does not start with `/foo/ba`.
+    pub fn starts_with(&self, prefix: &Path) -> bool {
+        if self.is_absolute() != prefix.is_absolute() {
+            return false;
+        }
+
+        let mut components = self.components();
+        prefix.components().all(|rhs| {
+            components
+                .next()
+                .is_some_and(|lhs| Self::component_eq(lhs, rhs))
+        })
+    }
+
+    /// Returns `true` when the components of `suffix` are the trailing components of the path.
+    /// If `suffix` is absolute it must be equal to the path, see [`Path::normalized_eq()`].
+    pub fn ends_with(&self, suffix: &Path) -> bool {
+        if suffix.is_absolute() {
+            return self.normalized_eq(suffix);
+        }
+
+        let number_of_components = self.components().count();
+        let number_of_suffix_components = suffix.components().count();
+        if number_of_components < number_of_suffix_components {
+            return false;
+        }
+
+        self.components()
+            .skip(number_of_components - number_of_suffix_components)
+            .zip(suffix.components())
+            .all(|(lhs, rhs)| Self::component_eq(lhs, rhs))
+    }
+
+    /// Returns the entries of the path starting with the last one. Empty entries and `.` are
+    /// skipped, on Windows the drive letter of an absolute path is not part of the entries.
+    /// In contrast to [`Path::entries()`] nothing is allocated.
+    pub fn components_rev(&self) -> impl DoubleEndedIterator<Item = &[u8]> {
+        self.as_bytes()[self.root_len()..]
+            .split(|c| *c == PATH_SEPARATOR)
+            .filter(|entry| !entry.is_empty())
+            .filter(|entry| !(entry.len() == 1 && entry[0] == b'.'))
+            .rev()
+    }
+
+    fn with_separator(&self, separator: u8) -> Path {
+        let mut raw_path = [0u8; PATH_LENGTH];
+        let value = self.as_bytes();
+        for (target, c) in raw_path.iter_mut().zip(value) {
+            *target = match c {
+                b'/' | b'\\' => separator,
+                _ => *c,
+            };
+        }
+
+        // SAFETY
+        // * only separators are replaced with separators, the length stays the same
+        unsafe { Path::new_unchecked(&raw_path[..value.len()]) }
+    }
+
+    fn root_len(&self) -> usize {
+        if !self.is_absolute() {
+            0
+        } else if cfg!(target_os = "windows") {
+            // drive letter like C:\
+            3
+        } else {
+            1
+        }
+    }
+
+    fn components(&self) -> impl Iterator<Item = &[u8]> {
+        self.as_bytes()
+            .split(|c| *c == PATH_SEPARATOR)
+            .filter(|entry| !entry.is_empty())
+            .filter(|entry| !(entry.len() == 1 && entry[0] == b'.'))
+    }
+
+    fn component_eq(lhs: &[u8], rhs: &[u8]) -> bool {
+        if cfg!(target_os = "windows") {
+            lhs.eq_ignore_ascii_case(rhs)
+        } else {
+            lhs == rhs
+        }
+    }
+
+    fn component_cmp(lhs: &[u8], rhs: &[u8]) -> Ordering {
+        if cfg!(target_os = "windows") {
+            lhs.iter()
+                .map(u8::to_ascii_lowercase)
+                .cmp(rhs.iter().map(u8::to_ascii_lowercase))
+        } else {
+            lhs.cmp(rhs)
+        }
+    }
+
     pub fn is_absolute(&self) -> bool {
         #[cfg(not(target_os = "windows"))]
         {
@@ -153,6 +541,116 @@ impl Path {
         Ok(Path::new(value)?.normalize())
     }
 
+    /// Creates a new [`Path`] from arbitrary bytes. In contrast to [`Path::new()`] it never
+    /// fails. Characters that are not allowed in a [`Path`] are handled as defined by
+    /// `sanitization` and the result is truncated to the maximum supported path length.
+    ///
+    /// ```
+    /// use iceoryx2_bb_container::semantic_string::SemanticString;
+    /// use iceoryx2_bb_system_types::path::*;
+    ///
+    /// let path = Path::new_sanitized(b"some<path>", PathSanitization::Replace(b'_'));
+    /// assert!(path == b"some_path_");
+    ///
+    /// let path = Path::new_sanitized(b"some<path>", PathSanitization::Strip);
+    /// assert!(path == b"somepath");
+    /// ```
+    pub fn new_sanitized(value: &[u8], sanitization: PathSanitization) -> Path {
+        let is_valid = |c: u8| c.is_ascii() && !invalid_characters(&[c]);
+        let replacement = match sanitization {
+            PathSanitization::Replace(c) if is_valid(c) => Some(c),
+            _ => None,
+        };
+
+        let mut raw_path = [0u8; PATH_LENGTH];
+        let mut n = 0;
+        for c in value {
+            if n == PATH_LENGTH {
+                break;
+            }
+
+            let c = if is_valid(*c) {
+                *c
+            } else if let Some(replacement) = replacement {
+                replacement
+            } else {
+                continue;
+            };
+
+            raw_path[n] = c;
+            n += 1;
+        }
+
+        // SAFETY
+        // * raw_path contains only valid path characters and does not exceed the maximum
+        //   supported path length
+        unsafe { Path::new_unchecked(&raw_path[..n]) }
+    }
+
+    /// Resolves all `.` and `..` entries and symbolic links against the file system and
+    /// returns the resulting absolute [`Path`]. In contrast to [`Path::normalize()`] the
+    /// path must exist.
+    ///
+    /// ```
+    /// use iceoryx2_bb_container::semantic_string::SemanticString;
+    /// use iceoryx2_bb_system_types::path::*;
+    ///
+    /// let path = Path::new(b".").unwrap().canonicalize().unwrap();
+    /// assert!(path.is_absolute());
+    ///
+    /// let path = Path::new(b"does/not/exist").unwrap().canonicalize();
+    /// assert!(path == Err(PathError::DoesNotExist));
+    /// ```
+    pub fn canonicalize(&self) -> Result<Path, PathError> {
+        let msg = "Unable to canonicalize path";
+        let resolved_path = unsafe { posix::realpath(self.as_c_str(), core::ptr::null_mut()) };
+
+        if resolved_path.is_null() {
+            match Errno::get() {
+                Errno::ENOENT => {
+                    fail!(from self, with PathError::DoesNotExist,
+                        "{} since the path or one of its entries does not exist.", msg);
+                }
+                Errno::ENOTDIR => {
+                    fail!(from self, with PathError::NotADirectory,
+                        "{} since an entry of the path prefix is not a directory.", msg);
+                }
+                Errno::EACCES => {
+                    fail!(from self, with PathError::InsufficientPermissions,
+                        "{} due to insufficient permissions.", msg);
+                }
+                Errno::ELOOP => {
+                    fail!(from self, with PathError::TooManySymbolicLinks,
+                        "{} since too many symbolic links were encountered.", msg);
+                }
+                Errno::ENAMETOOLONG => {
+                    fail!(from self, with PathError::ExceedsMaximumLength,
+                        "{} since the resolved path is too long.", msg);
+                }
+                v => {
+                    fail!(from self, with PathError::UnknownError(v as i32),
+                        "{} since an unknown error occurred ({}).", msg, v);
+                }
+            }
+        }
+
+        let canonical_path =
+            Path::new(unsafe { core::ffi::CStr::from_ptr(resolved_path) }.to_bytes());
+        unsafe { posix::free(resolved_path as *mut posix::void) };
+
+        match canonical_path {
+            Ok(path) => Ok(path),
+            Err(SemanticStringError::ExceedsMaximumLength) => {
+                fail!(from self, with PathError::ExceedsMaximumLength,
+                    "{} since the resolved path exceeds the maximum supported path length of {}.", msg, PATH_LENGTH);
+            }
+            Err(SemanticStringError::InvalidContent) => {
+                fail!(from self, with PathError::InvalidContent,
+                    "{} since the resolved path contains unsupported characters.", msg);
+            }
+        }
+    }
+
     pub fn entries(&self) -> Vec<FileName> {
         let skip_size = if cfg!(target_os = "windows") && self.is_absolute() {
             // skip drive letter like C:\ since the path is absolute
@@ -170,6 +668,80 @@ impl Path {
     }
 }
 
+/// Builds a [`Path`] from many entries. In contrast to [`Path::add_path_entry()`] only the
+/// newly added bytes are validated, the accumulated path is not revalidated on every append.
+///
+/// ```
+/// use iceoryx2_bb_container::semantic_string::SemanticString;
+/// use iceoryx2_bb_system_types::path::*;
+///
+/// let mut builder = PathBuilder::new(&Path::new(b"some").unwrap());
+/// builder.push_entry(b"deep").unwrap();
+/// builder.push_entry(b"path").unwrap();
+///
+/// let path = builder.build();
+/// # #[cfg(not(target_os = "windows"))]
+/// assert!(path == b"some/deep/path");
+/// ```
+#[derive(Debug, Clone)]
+pub struct PathBuilder {
+    value: StaticString<PATH_LENGTH>,
+}
+
+impl PathBuilder {
+    /// Creates a new [`PathBuilder`] that starts with the provided `base` path.
+    pub fn new(base: &Path) -> Self {
+        Self {
+            value: *base.as_string(),
+        }
+    }
+
+    /// Adds a new entry to the path. A separator is added when the path does not end with
+    /// one - except when it is empty. Fails when the entry contains invalid or non-ASCII
+    /// characters or when the path would exceed the maximum supported path length. On failure
+    /// the [`PathBuilder`] stays unchanged.
+    pub fn push_entry(&mut self, entry: &[u8]) -> Result<(), SemanticStringError> {
+        let msg = "Unable to add entry to path";
+        if invalid_characters(entry) || !entry.is_ascii() {
+            fail!(from self, with SemanticStringError::InvalidContent,
+                "{msg} since the entry \"{}\" contains invalid characters.", as_escaped_string(entry));
+        }
+
+        let requires_separator =
+            !self.value.is_empty() && self.value.as_bytes()[self.value.len() - 1] != PATH_SEPARATOR;
+        let new_len = self.value.len() + requires_separator as usize + entry.len();
+        if PATH_LENGTH < new_len {
+            fail!(from self, with SemanticStringError::ExceedsMaximumLength,
+                "{msg} since it would exceed the maximum supported path length of {PATH_LENGTH}.");
+        }
+
+        if requires_separator {
+            fail!(from self, when self.value.push(PATH_SEPARATOR), "{msg}.");
+        }
+        fail!(from self, when self.value.push_bytes(entry), "{msg}.");
+
+        Ok(())
+    }
+
+    /// Returns the current length of the path.
+    pub fn len(&self) -> usize {
+        self.value.len()
+    }
+
+    /// Returns true if the path is empty, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    /// Creates the [`Path`]. The [`PathBuilder`] can be reused afterwards to build further
+    /// paths that share the same prefix.
+    pub fn build(&self) -> Path {
+        // SAFETY
+        // * all bytes were validated when they were added
+        unsafe { Path::new_unchecked(self.value.as_bytes()) }
+    }
+}
+
 impl From<FilePath> for Path {
     fn from(value: FilePath) -> Self {
         unsafe { Path::new_unchecked(value.as_bytes()) }
@@ -193,3 +765,36 @@ impl From<&FileName> for Path {
         unsafe { Path::new_unchecked(value.as_bytes()) }
     }
 }
+
+macro_rules! impl_path_div {
+    ($rhs:ty, |$value:ident| $as_bytes:expr) => {
+        impl Div<$rhs> for &Path {
+            type Output = Path;
+
+            /// # Panics
+            ///
+            /// When the entry contains invalid characters or when the resulting path would
+            /// exceed the maximum supported path length, use [`Path::join()`] instead.
+            fn div(self, $value: $rhs) -> Path {
+                fatal_panic!(from self, when self.join($as_bytes),
+                    "Unable to join the entry to the path since the resulting path would be invalid.")
+            }
+        }
+
+        impl Div<$rhs> for Path {
+            type Output = Path;
+
+            /// # Panics
+            ///
+            /// When the entry contains invalid characters or when the resulting path would
+            /// exceed the maximum supported path length, use [`Path::join()`] instead.
+            fn div(self, rhs: $rhs) -> Path {
+                &self / rhs
+            }
+        }
+    };
+}
+
+impl_path_div!(&[u8], |rhs| rhs);
+impl_path_div!(&str, |rhs| rhs.as_bytes());
+impl_path_div!(&FileName, |rhs| rhs.as_bytes());
@@ -0,0 +1,82 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Stable SipHash-2-4 implementation. In contrast to the hashers of the standard library the
+//! output is guaranteed to stay the same across compiler versions, platforms and processes
+//! and can therefore be used to derive persistent resource names.
+
+struct State {
+    v0: u64,
+    v1: u64,
+    v2: u64,
+    v3: u64,
+}
+
+impl State {
+    fn new(key: [u64; 2]) -> Self {
+        Self {
+            v0: key[0] ^ 0x736f6d6570736575,
+            v1: key[1] ^ 0x646f72616e646f6d,
+            v2: key[0] ^ 0x6c7967656e657261,
+            v3: key[1] ^ 0x7465646279746573,
+        }
+    }
+
+    fn round(&mut self) {
+        self.v0 = self.v0.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(13);
+        self.v1 ^= self.v0;
+        self.v0 = self.v0.rotate_left(32);
+        self.v2 = self.v2.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(16);
+        self.v3 ^= self.v2;
+        self.v0 = self.v0.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(21);
+        self.v3 ^= self.v0;
+        self.v2 = self.v2.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(17);
+        self.v1 ^= self.v2;
+        self.v2 = self.v2.rotate_left(32);
+    }
+
+    fn compress(&mut self, message: u64) {
+        self.v3 ^= message;
+        self.round();
+        self.round();
+        self.v0 ^= message;
+    }
+}
+
+/// Returns the SipHash-2-4 of `data` for the given 128-bit `key`.
+pub(crate) fn sip_hash_2_4(key: [u64; 2], data: &[u8]) -> u64 {
+    let mut state = State::new(key);
+
+    let mut chunks = data.chunks_exact(8);
+    for chunk in &mut chunks {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(chunk);
+        state.compress(u64::from_le_bytes(bytes));
+    }
+
+    let mut last_block = [0u8; 8];
+    let remainder = chunks.remainder();
+    last_block[..remainder.len()].copy_from_slice(remainder);
+    last_block[7] = data.len() as u8;
+    state.compress(u64::from_le_bytes(last_block));
+
+    state.v2 ^= 0xff;
+    for _ in 0..4 {
+        state.round();
+    }
+
+    state.v0 ^ state.v1 ^ state.v2 ^ state.v3
+}
@@ -0,0 +1,111 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_container::semantic_string::*;
+use iceoryx2_bb_system_types::endpoint::*;
+use iceoryx2_bb_system_types::port::Port;
+use iceoryx2_bb_testing::assert_that;
+
+#[test]
+fn endpoint_with_host_and_port_works() {
+    let sut = Endpoint::new(b"tcp://10.0.0.5:9000").unwrap();
+
+    assert_that!(sut.scheme(), eq "tcp");
+    assert_that!(sut.host(), eq "10.0.0.5");
+    assert_that!(sut.port(), eq Some(Port::new(9000)));
+    assert_that!(sut.path(), eq "");
+}
+
+#[test]
+fn endpoint_with_path_and_without_host_works() {
+    let sut = Endpoint::new(b"uds:///tmp/sock").unwrap();
+
+    assert_that!(sut.scheme(), eq "uds");
+    assert_that!(sut.host(), eq "");
+    assert_that!(sut.port(), eq None);
+    assert_that!(sut.path(), eq "/tmp/sock");
+}
+
+#[test]
+fn endpoint_with_host_port_and_path_works() {
+    let sut = Endpoint::new(b"http+ws://some-host.local:65535/fuu/bar").unwrap();
+
+    assert_that!(sut.scheme(), eq "http+ws");
+    assert_that!(sut.host(), eq "some-host.local");
+    assert_that!(sut.port(), eq Some(Port::new(65535)));
+    assert_that!(sut.path(), eq "/fuu/bar");
+}
+
+#[test]
+fn endpoint_with_ipv6_host_works() {
+    let sut = Endpoint::new(b"udp://[fe80::1]:1234").unwrap();
+    assert_that!(sut.host(), eq "[fe80::1]");
+    assert_that!(sut.port(), eq Some(Port::new(1234)));
+
+    let sut = Endpoint::new(b"udp://[::1]").unwrap();
+    assert_that!(sut.host(), eq "[::1]");
+    assert_that!(sut.port(), eq None);
+}
+
+#[test]
+fn endpoint_with_invalid_scheme_fails() {
+    assert_that!(Endpoint::new(b"10.0.0.5:9000"), eq Err(SemanticStringError::InvalidContent));
+    assert_that!(Endpoint::new(b"://10.0.0.5:9000"), eq Err(SemanticStringError::InvalidContent));
+    assert_that!(Endpoint::new(b"1tcp://10.0.0.5"), eq Err(SemanticStringError::InvalidContent));
+    assert_that!(Endpoint::new(b"t_cp://10.0.0.5"), eq Err(SemanticStringError::InvalidContent));
+}
+
+#[test]
+fn endpoint_with_invalid_host_fails() {
+    assert_that!(Endpoint::new(b"tcp://fuu:bar:9000"), eq Err(SemanticStringError::InvalidContent));
+    assert_that!(Endpoint::new(b"tcp://fuu@bar"), eq Err(SemanticStringError::InvalidContent));
+    assert_that!(Endpoint::new(b"udp://[]:9000"), eq Err(SemanticStringError::InvalidContent));
+    assert_that!(Endpoint::new(b"udp://[fe80::1"), eq Err(SemanticStringError::InvalidContent));
+    assert_that!(Endpoint::new(b"udp://[fe80::1]x"), eq Err(SemanticStringError::InvalidContent));
+}
+
+#[test]
+fn endpoint_with_invalid_port_fails() {
+    assert_that!(Endpoint::new(b"tcp://10.0.0.5:"), eq Err(SemanticStringError::InvalidContent));
+    assert_that!(Endpoint::new(b"tcp://10.0.0.5:65536"), eq Err(SemanticStringError::InvalidContent));
+    assert_that!(Endpoint::new(b"tcp://10.0.0.5:123456"), eq Err(SemanticStringError::InvalidContent));
+    assert_that!(Endpoint::new(b"tcp://10.0.0.5:9a"), eq Err(SemanticStringError::InvalidContent));
+    assert_that!(Endpoint::new(b"tcp://:9000"), eq Err(SemanticStringError::InvalidContent));
+}
+
+#[test]
+fn endpoint_with_invalid_characters_fails() {
+    assert_that!(Endpoint::new(b"tcp://10.0.0.5:9000/fuu bar"), eq Err(SemanticStringError::InvalidContent));
+    assert_that!(Endpoint::new(b"tcp://10.0.0.5\0"), is_err);
+    assert_that!(Endpoint::new(b"uds:///tmp/<sock>"), eq Err(SemanticStringError::InvalidContent));
+}
+
+#[test]
+fn endpoint_compares_scheme_and_host_case_insensitive() {
+    let sut = Endpoint::new(b"TCP://Some-Host:9000/Path").unwrap();
+
+    assert_that!(sut, eq Endpoint::new(b"tcp://some-host:9000/Path").unwrap());
+    assert_that!(sut, ne Endpoint::new(b"tcp://some-host:9000/path").unwrap());
+    let normalized = sut.normalize();
+    assert_that!(normalized.as_bytes(), eq b"tcp://some-host:9000/Path");
+}
+
+#[test]
+fn endpoint_modification_to_invalid_content_fails() {
+    let mut sut = Endpoint::new(b"tcp://10.0.0.5:9000").unwrap();
+
+    assert_that!(sut.push_bytes(b"/fuu"), is_ok);
+    assert_that!(sut.as_bytes(), eq b"tcp://10.0.0.5:9000/fuu");
+    assert_that!(sut.push_bytes(b":bar:"), is_ok);
+    assert_that!(sut.truncate(4), eq Err(SemanticStringError::InvalidContent));
+    assert_that!(sut.as_bytes(), eq b"tcp://10.0.0.5:9000/fuu:bar:");
+}
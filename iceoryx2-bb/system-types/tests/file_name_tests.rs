@@ -80,3 +80,86 @@ fn file_name_retain_fails_when_it_results_in_illegal_name() {
     assert_that!(sut, len 4);
     assert_that!(sut.as_bytes(), eq b".fuu");
 }
+
+#[test]
+fn file_name_with_prefix_works() {
+    let sut = FileName::new(b"file.txt").unwrap();
+
+    let result = sut.with_prefix(b"my_").unwrap();
+
+    assert_that!(result.as_bytes(), eq b"my_file.txt");
+    assert_that!(sut.as_bytes(), eq b"file.txt");
+}
+
+#[test]
+fn file_name_with_suffix_works() {
+    let sut = FileName::new(b"file").unwrap();
+
+    let result = sut.with_suffix(b"_123.txt").unwrap();
+
+    assert_that!(result.as_bytes(), eq b"file_123.txt");
+    assert_that!(sut.as_bytes(), eq b"file");
+}
+
+#[test]
+fn file_name_with_prefix_or_suffix_with_illegal_characters_fails() {
+    let sut = FileName::new(b"file").unwrap();
+
+    assert_that!(sut.with_prefix(b"some/"), eq Err(SemanticStringError::InvalidContent));
+    assert_that!(sut.with_suffix(b"/other"), eq Err(SemanticStringError::InvalidContent));
+}
+
+#[test]
+fn file_name_with_prefix_or_suffix_exceeding_capacity_fails() {
+    let sut = FileName::new(b"file").unwrap();
+    let long_value = vec![b'a'; FileName::max_len()];
+
+    assert_that!(sut.with_prefix(&long_value), eq Err(SemanticStringError::ExceedsMaximumLength));
+    assert_that!(sut.with_suffix(&long_value), eq Err(SemanticStringError::ExceedsMaximumLength));
+}
+
+#[test]
+fn file_name_from_hashed_is_deterministic() {
+    let prefix = FileName::new(b"iox2_").unwrap();
+    let long_name = vec![b'x'; 2 * FileName::max_len()];
+
+    let sut_1 = FileName::from_hashed(&long_name, &prefix).unwrap();
+    let sut_2 = FileName::from_hashed(&long_name, &prefix).unwrap();
+
+    assert_that!(sut_1, eq sut_2);
+    assert_that!(sut_1.as_bytes().starts_with(b"iox2_"), eq true);
+    assert_that!(sut_1, len prefix.len() + 32);
+}
+
+#[test]
+fn file_name_from_hashed_is_stable_across_processes() {
+    // the expected value is fixed, every process and every version must derive the same name
+    let prefix = FileName::new(b"service_").unwrap();
+
+    let sut = FileName::from_hashed(b"some/service/name", &prefix).unwrap();
+    assert_that!(sut.as_bytes(), eq b"service_1f3ff5170c9d8125b960a07d21145f9a");
+}
+
+#[test]
+fn file_name_from_hashed_differs_for_different_values() {
+    let prefix = FileName::new(b"p").unwrap();
+
+    let sut_1 = FileName::from_hashed(b"some/service/name", &prefix).unwrap();
+    let sut_2 = FileName::from_hashed(b"some/service/nam3", &prefix).unwrap();
+    let sut_3 = FileName::from_hashed(b"", &prefix).unwrap();
+
+    assert_that!(sut_1, ne sut_2);
+    assert_that!(sut_1, ne sut_3);
+    assert_that!(sut_2, ne sut_3);
+}
+
+#[test]
+fn file_name_from_hashed_with_too_long_prefix_fails() {
+    let prefix = FileName::new(&vec![b'a'; FileName::max_len() - 31]).unwrap();
+    let sut = FileName::from_hashed(b"fuu", &prefix);
+    assert_that!(sut, eq Err(SemanticStringError::ExceedsMaximumLength));
+
+    let prefix = FileName::new(&vec![b'a'; FileName::max_len() - 32]).unwrap();
+    let sut = FileName::from_hashed(b"fuu", &prefix);
+    assert_that!(sut, is_ok);
+}
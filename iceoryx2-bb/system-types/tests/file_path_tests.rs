@@ -11,10 +11,12 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use iceoryx2_bb_container::semantic_string::*;
+use iceoryx2_bb_container::string::StaticString;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::*;
 use iceoryx2_bb_system_types::path::Path;
 use iceoryx2_bb_testing::assert_that;
+use serde_test::{assert_de_tokens_error, assert_tokens, Token};
 
 #[cfg(target_os = "windows")]
 mod windows {
@@ -154,6 +156,36 @@ mod unix {
         }
     }
 
+    #[test]
+    fn file_path_from_path_and_name_works() {
+        let name = StaticString::from_bytes(b"filename.txt").unwrap();
+
+        let sut = FilePath::from_path_and_name(&Path::new(b"/some/file/path").unwrap(), &name);
+        assert_that!(sut, is_ok);
+        assert_that!(sut.unwrap(), eq b"/some/file/path/filename.txt");
+    }
+
+    #[test]
+    fn file_path_from_path_with_trailing_separator_and_name_does_not_double_separator() {
+        let name = StaticString::from_bytes(b"filename.txt").unwrap();
+
+        let sut = FilePath::from_path_and_name(&Path::new(b"/some/file/path/").unwrap(), &name);
+        assert_that!(sut, is_ok);
+        assert_that!(sut.unwrap(), eq b"/some/file/path/filename.txt");
+
+        let sut = FilePath::from_path_and_name(&Path::new(b"/").unwrap(), &name);
+        assert_that!(sut, is_ok);
+        assert_that!(sut.unwrap(), eq b"/filename.txt");
+    }
+
+    #[test]
+    fn file_path_from_path_and_name_with_separator_fails() {
+        let name = StaticString::from_bytes(b"some/file").unwrap();
+
+        let sut = FilePath::from_path_and_name(&Path::new(b"/some/path").unwrap(), &name);
+        assert_that!(sut, is_err);
+    }
+
     #[test]
     fn file_path_extract_file_name_works() {
         let sut = FilePath::new(b"/some/file/path").unwrap();
@@ -206,6 +238,45 @@ fn file_path_from_empty_path_and_legal_file_works() {
     }
 }
 
+#[test]
+fn file_path_from_empty_path_and_name_works() {
+    let name = StaticString::from_bytes(b"filename.txt").unwrap();
+
+    let sut = FilePath::from_path_and_name(&Path::new(b"").unwrap(), &name);
+    assert_that!(sut, is_ok);
+    assert_that!(sut.unwrap(), eq b"filename.txt");
+}
+
+#[test]
+fn file_path_from_path_and_name_with_invalid_name_fails() {
+    let path = Path::new(b"some_path").unwrap();
+
+    for name in [
+        &b""[..],
+        b".",
+        b"..",
+        b"file?name",
+        b"file*name",
+        b"file\x01name",
+    ] {
+        let name = StaticString::from_bytes(name).unwrap();
+        let sut = FilePath::from_path_and_name(&path, &name);
+        assert_that!(sut, is_err);
+    }
+}
+
+#[test]
+fn file_path_from_path_and_name_exceeding_maximum_length_fails() {
+    let mut path = Path::new(b"some_path").unwrap();
+    while path.len() + 10 < path.capacity() {
+        path.push_bytes(b"a").unwrap();
+    }
+    let name = StaticString::from_bytes(b"filename.txt").unwrap();
+
+    let sut = FilePath::from_path_and_name(&path, &name);
+    assert_that!(sut.err(), eq Some(SemanticStringError::ExceedsMaximumLength));
+}
+
 #[test]
 fn file_path_extract_file_name_from_path_consisting_only_of_a_file_works() {
     let sut = FilePath::new(b"barbe").unwrap();
@@ -220,3 +291,27 @@ fn file_path_extract_path_from_path_consisting_only_of_a_file_works() {
     let sut = FilePath::new(b"barbe").unwrap();
     assert_that!(sut.path(), eq b"");
 }
+
+#[test]
+fn file_path_serialization_round_trip_works() {
+    let sut = FilePath::new(b"some_file.txt").unwrap();
+
+    assert_tokens(&sut, &[Token::Str("some_file.txt")]);
+}
+
+#[test]
+fn file_path_deserialization_with_invalid_content_fails() {
+    assert_de_tokens_error::<FilePath>(
+        &[Token::Str("some_file?.txt")],
+        "invalid FilePath provided InvalidContent.",
+    );
+}
+
+#[test]
+fn file_path_remaining_capacity_is_capacity_minus_len() {
+    let mut sut = FilePath::new(b"/some/file").unwrap();
+    assert_that!(sut.remaining_capacity(), eq sut.capacity() - 10);
+
+    assert_that!(sut.push_bytes(b"_name"), is_ok);
+    assert_that!(sut.remaining_capacity(), eq sut.capacity() - 15);
+}
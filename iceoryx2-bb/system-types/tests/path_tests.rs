@@ -10,9 +10,208 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use core::cmp::Ordering;
 use iceoryx2_bb_container::semantic_string::*;
+use iceoryx2_bb_posix::config::test_directory;
+use iceoryx2_bb_posix::directory::Directory;
+use iceoryx2_bb_posix::permission::Permission;
+use iceoryx2_bb_posix::testing::create_test_directory;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::path::*;
 use iceoryx2_bb_testing::assert_that;
+use iceoryx2_pal_configuration::PATH_LENGTH;
+use serde_test::{assert_de_tokens_error, assert_ser_tokens, assert_tokens, Token};
+
+#[test]
+fn path_to_unix_style_works() {
+    let sut = Path::new(b"C:\\a\\b").unwrap();
+    assert_that!(sut.to_unix_style(), eq b"C:/a/b");
+
+    let sut = Path::new(b"C:/a\\b/").unwrap();
+    assert_that!(sut.to_unix_style(), eq b"C:/a/b/");
+
+    let sut = Path::new(b"/a/b").unwrap();
+    assert_that!(sut.to_unix_style(), eq b"/a/b");
+}
+
+#[test]
+fn path_to_windows_style_works() {
+    let sut = Path::new(b"C:/a/b").unwrap();
+    assert_that!(sut.to_windows_style(), eq b"C:\\a\\b");
+
+    let sut = Path::new(b"C:\\a/b\\").unwrap();
+    assert_that!(sut.to_windows_style(), eq b"C:\\a\\b\\");
+
+    let sut = Path::new_empty();
+    assert_that!(sut.to_windows_style(), eq b"");
+}
+
+#[test]
+fn path_conversion_round_trip_restores_path() {
+    let sut = Path::new(b"C:\\a\\b").unwrap();
+    let converted = sut.to_unix_style().to_windows_style();
+
+    assert_that!(converted.as_bytes(), eq sut.as_bytes());
+}
+
+#[test]
+fn path_builder_creates_same_path_as_add_path_entry() {
+    const NUMBER_OF_LEVELS: usize = 10;
+    let base = Path::new(b"base").unwrap();
+    let mut sut = PathBuilder::new(&base);
+    let mut expected = base.clone();
+
+    for n in 0..NUMBER_OF_LEVELS {
+        let entry = format!("level_{n}");
+        assert_that!(sut.push_entry(entry.as_bytes()), is_ok);
+        assert_that!(
+            expected.add_path_entry(&Path::new(entry.as_bytes()).unwrap()),
+            is_ok
+        );
+    }
+
+    let path = sut.build();
+    assert_that!(path, eq expected);
+    assert_that!(path.entries(), len NUMBER_OF_LEVELS + 1);
+    assert_that!(sut.len(), eq expected.len());
+}
+
+#[test]
+fn path_builder_can_be_reused_for_paths_with_same_prefix() {
+    let mut sut = PathBuilder::new(&Path::new_empty());
+    assert_that!(sut.is_empty(), eq true);
+    assert_that!(sut.push_entry(b"prefix"), is_ok);
+
+    let mut sut_1 = sut.clone();
+    let mut sut_2 = sut.clone();
+    assert_that!(sut_1.push_entry(b"one"), is_ok);
+    assert_that!(sut_2.push_entry(b"two"), is_ok);
+
+    assert_that!(sut.build().entries(), len 1);
+    assert_that!(sut_1.build().entries()[1], eq b"one");
+    assert_that!(sut_2.build().entries()[1], eq b"two");
+}
+
+#[test]
+fn path_builder_push_entry_with_invalid_characters_fails() {
+    let mut sut = PathBuilder::new(&Path::new(b"base").unwrap());
+
+    assert_that!(sut.push_entry(b"fuu*"), eq Err(SemanticStringError::InvalidContent));
+    assert_that!(sut.push_entry(b"b\0ar"), eq Err(SemanticStringError::InvalidContent));
+    assert_that!(sut.build(), eq b"base");
+}
+
+#[test]
+fn path_builder_push_entry_with_non_ascii_characters_fails_without_adding_separator() {
+    let mut sut = PathBuilder::new(&Path::new(b"base").unwrap());
+
+    assert_that!(sut.push_entry("f\u{fc}r".as_bytes()), eq Err(SemanticStringError::InvalidContent));
+    assert_that!(sut.len(), eq 4);
+    assert_that!(sut.build(), eq b"base");
+
+    assert_that!(sut.push_entry(b"entry"), is_ok);
+    assert_that!(sut.len(), eq 10);
+    assert_that!(sut.build().entries()[1], eq b"entry");
+}
+
+#[test]
+fn path_builder_push_entry_exceeding_capacity_fails() {
+    let mut sut = PathBuilder::new(&Path::new(b"base").unwrap());
+    let entry = vec![b'a'; Path::max_len() - 5];
+    let too_long_entry = vec![b'a'; Path::max_len() - 4];
+
+    assert_that!(sut.clone().push_entry(&too_long_entry), eq Err(SemanticStringError::ExceedsMaximumLength));
+    assert_that!(sut.push_entry(&entry), is_ok);
+    assert_that!(sut.len(), eq Path::max_len());
+    assert_that!(sut.push_entry(b"x"), eq Err(SemanticStringError::ExceedsMaximumLength));
+}
+
+#[test]
+fn path_join_creates_same_path_as_add_path_entry() {
+    let base = Path::new(b"base").unwrap();
+    let mut expected = base.clone();
+    expected
+        .add_path_entry(&Path::new(b"sub").unwrap())
+        .unwrap();
+    expected
+        .add_path_entry(&Path::new(b"file.txt").unwrap())
+        .unwrap();
+
+    let sut = base.join(b"sub").unwrap().join(b"file.txt").unwrap();
+
+    assert_that!(sut, eq expected);
+    assert_that!(base, eq b"base");
+}
+
+#[test]
+fn path_join_with_invalid_entry_fails() {
+    let base = Path::new(b"base").unwrap();
+    let too_long_entry = vec![b'a'; Path::max_len() - 4];
+
+    assert_that!(base.join(b"fuu*"), eq Err(SemanticStringError::InvalidContent));
+    assert_that!(base.join(&too_long_entry), eq Err(SemanticStringError::ExceedsMaximumLength));
+    assert_that!(base.join(&too_long_entry[1..]), is_ok);
+}
+
+#[test]
+fn path_div_operator_builds_path_fluently() {
+    let base = Path::new(b"base").unwrap();
+    let file = FileName::new(b"file.txt").unwrap();
+
+    let sut = &base / "sub" / b"deep".as_slice() / &file;
+
+    assert_that!(sut, eq base.join(b"sub").unwrap().join(b"deep").unwrap().join(b"file.txt").unwrap());
+    assert_that!(Path::new_empty() / "sub", eq b"sub");
+    assert_that!(base.clone() / "sub" / "deep" / "file.txt", eq sut);
+}
+
+#[test]
+#[should_panic]
+fn path_div_operator_panics_when_path_exceeds_capacity() {
+    let too_long_entry = vec![b'a'; Path::max_len() - 4];
+
+    let _ = Path::new(b"base").unwrap() / too_long_entry.as_slice();
+}
+
+#[test]
+#[should_panic]
+fn path_div_operator_panics_on_invalid_entry() {
+    let _ = Path::new(b"base").unwrap() / "fuu*";
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+fn path_normalize_lexical_folds_parent_directory_entries() {
+    assert_that!(Path::new(b"a/b/../c").unwrap().normalize_lexical(), eq b"a/c");
+    assert_that!(Path::new(b"a/b/c/../../d").unwrap().normalize_lexical(), eq b"a/d");
+    assert_that!(Path::new(b"/a/b/../../c/").unwrap().normalize_lexical(), eq b"/c");
+    assert_that!(Path::new(b"a/..").unwrap().normalize_lexical(), eq b"");
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+fn path_normalize_lexical_removes_current_directory_entries() {
+    assert_that!(Path::new(b"a/./b").unwrap().normalize_lexical(), eq b"a/b");
+    assert_that!(Path::new(b"./a//./b/.").unwrap().normalize_lexical(), eq b"a/b");
+    assert_that!(Path::new(b"/./").unwrap().normalize_lexical(), eq b"/");
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+fn path_normalize_lexical_keeps_leading_parent_entries_of_relative_path() {
+    assert_that!(Path::new(b"../x").unwrap().normalize_lexical(), eq b"../x");
+    assert_that!(Path::new(b"../a/../../b").unwrap().normalize_lexical(), eq b"../../b");
+    assert_that!(Path::new(b"a/../../b").unwrap().normalize_lexical(), eq b"../b");
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+fn path_normalize_lexical_never_escapes_root() {
+    assert_that!(Path::new(b"/../x").unwrap().normalize_lexical(), eq b"/x");
+    assert_that!(Path::new(b"/a/../../../x").unwrap().normalize_lexical(), eq b"/x");
+    assert_that!(Path::new(b"/..").unwrap().normalize_lexical(), eq b"/");
+}
 
 #[cfg(target_os = "windows")]
 mod windows {
@@ -66,6 +265,101 @@ mod windows {
         assert_that!(sut, eq b"fuu\\blaaaha\\blub.ma");
     }
 
+    #[test]
+    fn path_push_path_works() {
+        let mut sut = Path::new(b"C:\\some").unwrap();
+        sut.push_path(&Path::new(b"subdir\\more\\file").unwrap())
+            .unwrap();
+        assert_that!(sut.as_bytes(), eq b"C:\\some\\subdir\\more\\file");
+
+        let mut sut = Path::new(b"C:\\some").unwrap();
+        let result = sut.push_path(&Path::new(b"D:\\absolute").unwrap());
+        assert_that!(result, eq Err(SemanticStringError::InvalidContent));
+        assert_that!(sut.as_bytes(), eq b"C:\\some");
+    }
+
+    #[test]
+    fn path_semantic_eq_ignores_separators_and_case() {
+        let sut = Path::new(b"C:\\Some\\Path\\").unwrap();
+
+        assert_that!(sut.semantic_eq(&Path::new(b"c:\\some\\path").unwrap()), eq true);
+        assert_that!(sut.semantic_eq(&Path::new(b"C:\\some\\\\path").unwrap()), eq true);
+        assert_that!(sut.semantic_eq(&Path::new(b"C:\\some\\other").unwrap()), eq false);
+    }
+
+    #[test]
+    fn path_starts_with_compares_case_insensitive() {
+        let sut = Path::new(b"C:\\Some\\Path").unwrap();
+
+        assert_that!(sut.starts_with(&Path::new(b"c:\\Some").unwrap()), eq true);
+        assert_that!(sut.starts_with(&Path::new(b"C:\\\\Some\\").unwrap()), eq true);
+        assert_that!(sut.starts_with(&Path::new(b"C:\\some").unwrap()), eq true);
+        assert_that!(sut.starts_with(&Path::new(b"C:\\Som").unwrap()), eq false);
+        assert_that!(sut.starts_with(&Path::new(b"D:\\Some").unwrap()), eq false);
+    }
+
+    #[test]
+    fn path_cmp_by_components_compares_case_insensitive() {
+        let sut = Path::new(b"C:\\Some\\Path").unwrap();
+
+        assert_that!(sut.cmp_by_components(&Path::new(b"c:\\Some\\Path").unwrap()), eq Ordering::Equal);
+        assert_that!(sut.cmp_by_components(&Path::new(b"C:\\some\\path").unwrap()), eq Ordering::Equal);
+        assert_that!(sut.cmp_by_components(&Path::new(b"C:\\Some").unwrap()), eq Ordering::Greater);
+        assert_that!(sut.cmp_by_components(&Path::new(b"D:\\Some").unwrap()), eq Ordering::Less);
+    }
+
+    #[test]
+    fn path_normalized_eq_compares_case_insensitive() {
+        let sut = Path::new(b"C:\\Some\\Path\\").unwrap();
+
+        assert_that!(sut.normalized_eq(&Path::new(b"c:\\Some\\\\Path").unwrap()), eq true);
+        assert_that!(sut.normalized_eq(&Path::new(b"C:\\Some").unwrap()), eq false);
+        assert_that!(sut.normalized_eq(&Path::new(b"C:\\some\\path").unwrap()), eq true);
+    }
+
+    #[test]
+    fn path_ends_with_works() {
+        let sut = Path::new(b"C:\\Some\\Path").unwrap();
+
+        assert_that!(sut.ends_with(&Path::new(b"Path").unwrap()), eq true);
+        assert_that!(sut.ends_with(&Path::new(b"Some\\Path\\").unwrap()), eq true);
+        assert_that!(sut.ends_with(&Path::new(b"c:\\Some\\Path").unwrap()), eq true);
+        assert_that!(sut.ends_with(&Path::new(b"ath").unwrap()), eq false);
+    }
+
+    #[test]
+    fn path_pop_entry_keeps_drive_root() {
+        let mut sut = Path::new(b"C:\\fuu\\bar\\").unwrap();
+
+        assert_that!(sut.pop_entry(), eq Some(FileName::new(b"bar").unwrap()));
+        assert_that!(sut, eq b"C:\\fuu");
+        assert_that!(sut.pop_entry(), eq Some(FileName::new(b"fuu").unwrap()));
+        assert_that!(sut, eq b"C:\\");
+        assert_that!(sut.pop_entry(), eq None);
+        assert_that!(sut, eq b"C:\\");
+
+        assert_that!(sut.add_path_entry(&Path::new(b"fuu").unwrap()), is_ok);
+        assert_that!(sut, eq b"C:\\fuu");
+    }
+
+    #[test]
+    fn path_truncate_components_keeps_drive_root() {
+        let mut sut = Path::new(b"C:\\fuu\\bar\\baz").unwrap();
+        sut.truncate_components(1);
+        assert_that!(sut, eq b"C:\\fuu");
+        sut.truncate_components(0);
+        assert_that!(sut, eq b"C:\\");
+    }
+
+    #[test]
+    fn path_components_rev_skips_drive_letter() {
+        let sut = Path::new(b"C:\\a\\\\b\\.\\c\\").unwrap();
+
+        let components: Vec<&[u8]> = sut.components_rev().collect();
+        assert_that!(components, eq vec![&b"c"[..], b"b", b"a"]);
+        assert_that!(Path::new(b"C:\\").unwrap().components_rev().next(), is_none);
+    }
+
     #[test]
     fn path_is_absolute_works() {
         let sut = Path::new(b"D:\\bla").unwrap();
@@ -137,6 +431,280 @@ mod unix {
         assert_that!(sut, eq b"fuu/blaaaha/blub.ma");
     }
 
+    #[test]
+    fn path_push_path_works() {
+        let mut sut = Path::new(b"/some").unwrap();
+        sut.push_path(&Path::new(b"subdir/more/file").unwrap())
+            .unwrap();
+        assert_that!(sut, eq b"/some/subdir/more/file");
+
+        let mut sut = Path::new(b"fuu/").unwrap();
+        sut.push_path(&Path::new(b"bar//baz/").unwrap()).unwrap();
+        assert_that!(sut.as_bytes(), eq b"fuu/bar/baz");
+
+        let mut sut = Path::new(b"").unwrap();
+        sut.push_path(&Path::new(b"another/testy").unwrap())
+            .unwrap();
+        assert_that!(sut.as_bytes(), eq b"another/testy");
+
+        let mut sut = Path::new(b"/").unwrap();
+        sut.push_path(&Path::new(b"a/b").unwrap()).unwrap();
+        assert_that!(sut.as_bytes(), eq b"/a/b");
+    }
+
+    #[test]
+    fn path_push_absolute_path_fails() {
+        let mut sut = Path::new(b"/some").unwrap();
+        let result = sut.push_path(&Path::new(b"/absolute/path").unwrap());
+
+        assert_that!(result, eq Err(SemanticStringError::InvalidContent));
+        assert_that!(sut.as_bytes(), eq b"/some");
+    }
+
+    #[test]
+    fn path_push_path_exceeding_capacity_leaves_path_unchanged() {
+        let entry = [b'a'; 100];
+        let mut sut = Path::new(b"/").unwrap();
+        sut.add_path_entry(&Path::new(&entry).unwrap()).unwrap();
+        let original = sut.clone();
+
+        let mut relative = Path::new(&entry).unwrap();
+        relative
+            .add_path_entry(&Path::new(&entry).unwrap())
+            .unwrap();
+        assert_that!(sut.len() + relative.len() + 1, gt Path::max_len());
+
+        let result = sut.push_path(&relative);
+
+        assert_that!(result, eq Err(SemanticStringError::ExceedsMaximumLength));
+        assert_that!(sut.as_bytes(), eq original.as_bytes());
+    }
+
+    #[test]
+    fn path_semantic_eq_ignores_trailing_separators() {
+        let sut = Path::new(b"/a/b/").unwrap();
+
+        assert_that!(sut.semantic_eq(&Path::new(b"/a/b").unwrap()), eq true);
+        assert_that!(sut.semantic_eq(&Path::new(b"/a/b///").unwrap()), eq true);
+        assert_that!(sut.semantic_eq(&Path::new(b"/a/b/c/").unwrap()), eq false);
+    }
+
+    #[test]
+    fn path_semantic_eq_ignores_redundant_separators() {
+        let sut = Path::new(b"/a//b").unwrap();
+
+        assert_that!(sut.semantic_eq(&Path::new(b"/a/b").unwrap()), eq true);
+        assert_that!(sut.semantic_eq(&Path::new(b"//a////b").unwrap()), eq true);
+        assert_that!(sut.semantic_eq(&Path::new(b"/a/./b/.").unwrap()), eq true);
+        assert_that!(sut.semantic_eq(&Path::new(b"a/b").unwrap()), eq false);
+    }
+
+    #[test]
+    fn path_semantic_eq_with_mixed_separators_works() {
+        let sut = Path::new(b"/a/b/").unwrap();
+
+        assert_that!(sut.semantic_eq(&Path::new(b"/a//b").unwrap()), eq true);
+        assert_that!(sut.semantic_eq(&Path::new(b"//a//b//").unwrap()), eq true);
+        assert_that!(sut.semantic_eq(&Path::new(b"/a/B/").unwrap()), eq false);
+    }
+
+    #[test]
+    fn path_starts_with_compares_components() {
+        let sut = Path::new(b"/foo/barbaz/fuu").unwrap();
+
+        assert_that!(sut.starts_with(&Path::new(b"/foo").unwrap()), eq true);
+        assert_that!(sut.starts_with(&Path::new(b"//foo//barbaz/").unwrap()), eq true);
+        assert_that!(sut.starts_with(&Path::new(b"/foo/./barbaz/fuu").unwrap()), eq true);
+        assert_that!(sut.starts_with(&Path::new(b"/").unwrap()), eq true);
+        assert_that!(sut.starts_with(&Path::new(b"/foo/bar").unwrap()), eq false);
+        assert_that!(sut.starts_with(&Path::new(b"/foo/barbaz/fuu/x").unwrap()), eq false);
+        assert_that!(sut.starts_with(&Path::new(b"foo").unwrap()), eq false);
+    }
+
+    #[test]
+    fn relative_path_starts_with_relative_prefix() {
+        let sut = Path::new(b"foo/bar").unwrap();
+
+        assert_that!(sut.starts_with(&Path::new(b"").unwrap()), eq true);
+        assert_that!(sut.starts_with(&Path::new(b"./foo").unwrap()), eq true);
+        assert_that!(sut.starts_with(&Path::new(b"/foo").unwrap()), eq false);
+    }
+
+    #[test]
+    fn path_ends_with_compares_components() {
+        let sut = Path::new(b"/foo/barbaz/fuu").unwrap();
+
+        assert_that!(sut.ends_with(&Path::new(b"fuu").unwrap()), eq true);
+        assert_that!(sut.ends_with(&Path::new(b"barbaz//fuu/").unwrap()), eq true);
+        assert_that!(sut.ends_with(&Path::new(b"//foo/barbaz/fuu").unwrap()), eq true);
+        assert_that!(sut.ends_with(&Path::new(b"").unwrap()), eq true);
+        assert_that!(sut.ends_with(&Path::new(b"baz/fuu").unwrap()), eq false);
+        assert_that!(sut.ends_with(&Path::new(b"/barbaz/fuu").unwrap()), eq false);
+        assert_that!(sut.ends_with(&Path::new(b"x/foo/barbaz/fuu").unwrap()), eq false);
+    }
+
+    #[test]
+    fn path_cmp_by_components_groups_paths_like_a_tree() {
+        let mut sut = [
+            Path::new(b"a.txt").unwrap(),
+            Path::new(b"a/b").unwrap(),
+            Path::new(b"a").unwrap(),
+        ];
+
+        sut.sort();
+        assert_that!(sut[0], eq b"a");
+        assert_that!(sut[1], eq b"a.txt");
+        assert_that!(sut[2], eq b"a/b");
+
+        sut.sort_by(|lhs, rhs| lhs.cmp_by_components(rhs));
+        assert_that!(sut[0], eq b"a");
+        assert_that!(sut[1], eq b"a/b");
+        assert_that!(sut[2], eq b"a.txt");
+    }
+
+    #[test]
+    fn path_cmp_by_components_ignores_redundant_separators() {
+        let sut = Path::new(b"/foo/bar").unwrap();
+
+        assert_that!(sut.cmp_by_components(&Path::new(b"//foo/./bar/").unwrap()), eq Ordering::Equal);
+        assert_that!(sut.cmp_by_components(&Path::new(b"/foo").unwrap()), eq Ordering::Greater);
+        assert_that!(sut.cmp_by_components(&Path::new(b"/foo.bar").unwrap()), eq Ordering::Less);
+        assert_that!(sut.cmp_by_components(&Path::new(b"foo/bar").unwrap()), eq Ordering::Greater);
+    }
+
+    #[test]
+    fn path_normalized_eq_compares_components() {
+        let sut = Path::new(b"/foo/bar/").unwrap();
+
+        assert_that!(sut.normalized_eq(&Path::new(b"//foo/./bar").unwrap()), eq true);
+        assert_that!(sut.normalized_eq(&Path::new(b"/foo").unwrap()), eq false);
+        assert_that!(sut.normalized_eq(&Path::new(b"/foo/bar/baz").unwrap()), eq false);
+        assert_that!(sut.normalized_eq(&Path::new(b"foo/bar").unwrap()), eq false);
+    }
+
+    #[test]
+    fn path_pop_entry_of_root_and_empty_path_does_nothing() {
+        let mut sut = Path::new_root_path();
+        assert_that!(sut.pop_entry(), eq None);
+        assert_that!(sut.truncate_to_parent(), eq false);
+        assert_that!(sut, eq b"/");
+
+        let mut sut = Path::new_empty();
+        assert_that!(sut.pop_entry(), eq None);
+        assert_that!(sut.truncate_to_parent(), eq false);
+        assert_that!(sut, eq b"");
+    }
+
+    #[test]
+    fn path_pop_entry_ignores_trailing_and_redundant_separators() {
+        let mut sut = Path::new(b"//fuu//bar//").unwrap();
+
+        assert_that!(sut.pop_entry(), eq Some(FileName::new(b"bar").unwrap()));
+        assert_that!(sut, eq b"//fuu");
+        assert_that!(sut.truncate_to_parent(), eq true);
+        assert_that!(sut, eq b"/");
+    }
+
+    #[test]
+    fn path_truncate_components_of_absolute_path_works() {
+        let mut sut = Path::new(b"/a/b/c/d/e").unwrap();
+        sut.truncate_components(2);
+        assert_that!(sut, eq b"/a/b");
+
+        let mut sut = Path::new(b"//a//b//c//").unwrap();
+        sut.truncate_components(2);
+        assert_that!(sut, eq b"//a//b");
+
+        let mut sut = Path::new(b"/a/b/").unwrap();
+        sut.truncate_components(1);
+        assert_that!(sut, eq b"/a");
+    }
+
+    #[test]
+    fn path_truncate_components_of_relative_path_works() {
+        let mut sut = Path::new(b"a/b/c").unwrap();
+        sut.truncate_components(1);
+        assert_that!(sut, eq b"a");
+
+        let mut sut = Path::new(b"a/b/c/").unwrap();
+        sut.truncate_components(2);
+        assert_that!(sut, eq b"a/b");
+    }
+
+    #[test]
+    fn path_truncate_components_with_zero_yields_root_or_empty_path() {
+        let mut sut = Path::new(b"/a/b").unwrap();
+        sut.truncate_components(0);
+        assert_that!(sut, eq b"/");
+
+        let mut sut = Path::new(b"a/b").unwrap();
+        sut.truncate_components(0);
+        assert_that!(sut, eq b"");
+
+        let mut sut = Path::new_root_path();
+        sut.truncate_components(0);
+        assert_that!(sut, eq b"/");
+
+        let mut sut = Path::new_empty();
+        sut.truncate_components(0);
+        assert_that!(sut, eq b"");
+    }
+
+    #[test]
+    fn path_truncate_components_beyond_number_of_components_strips_trailing_separator() {
+        let mut sut = Path::new(b"/a/b/").unwrap();
+        sut.truncate_components(2);
+        assert_that!(sut, eq b"/a/b");
+
+        let mut sut = Path::new(b"/a//b//").unwrap();
+        sut.truncate_components(3);
+        assert_that!(sut, eq b"/a//b");
+
+        let mut sut = Path::new(b"a/b").unwrap();
+        sut.truncate_components(12);
+        assert_that!(sut, eq b"a/b");
+
+        let mut sut = Path::new(b"//").unwrap();
+        sut.truncate_components(1);
+        assert_that!(sut, eq b"/");
+    }
+
+    #[test]
+    fn path_push_and_pop_entry_behaves_like_path_buf() {
+        const NUMBER_OF_ITERATIONS: usize = 1000;
+        let mut seed: u64 = 0x2545f4914f6cdd1d;
+        let mut next_random = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+
+        for start in [&b"/"[..], &b""[..], &b"/some/root"[..], &b"relative"[..]] {
+            let mut sut = Path::new(start).unwrap();
+            let mut model = std::path::PathBuf::from(core::str::from_utf8(start).unwrap());
+
+            for _ in 0..NUMBER_OF_ITERATIONS {
+                if next_random() % 3 == 0 || sut.len() > 200 {
+                    let expected_entry = model
+                        .file_name()
+                        .map(|v| FileName::new(v.as_encoded_bytes()).unwrap());
+                    assert_that!(model.pop(), eq expected_entry.is_some());
+                    assert_that!(sut.pop_entry(), eq expected_entry);
+                } else {
+                    let entry = format!("e{}", next_random() % 100);
+                    model.push(&entry);
+                    assert_that!(
+                        sut.add_path_entry(&Path::new(entry.as_bytes()).unwrap()),
+                        is_ok
+                    );
+                }
+
+                assert_that!(sut.as_bytes(), eq model.as_os_str().as_encoded_bytes());
+            }
+        }
+    }
+
     #[test]
     fn path_list_all_entries_works() {
         let sut = Path::new(b"/some/file/path/").unwrap();
@@ -204,6 +772,24 @@ mod unix {
         assert_that!(entries[2], eq b"oh_no");
     }
 
+    #[test]
+    fn path_components_rev_works() {
+        let sut = Path::new(b"/a/b/c").unwrap();
+
+        let components: Vec<&[u8]> = sut.components_rev().collect();
+        assert_that!(components, eq vec![&b"c"[..], b"b", b"a"]);
+    }
+
+    #[test]
+    fn path_components_rev_skips_empty_and_current_dir_entries() {
+        let sut = Path::new(b"a//./b/c/").unwrap();
+
+        let components: Vec<&[u8]> = sut.components_rev().collect();
+        assert_that!(components, eq vec![&b"c"[..], b"b", b"a"]);
+        assert_that!(Path::new_root_path().components_rev().next(), is_none);
+        assert_that!(Path::new_empty().components_rev().next(), is_none);
+    }
+
     #[test]
     fn path_is_absolute_works() {
         let sut = Path::new(b"/").unwrap();
@@ -222,3 +808,207 @@ mod unix {
         assert_that!(sut.is_absolute(), eq false);
     }
 }
+
+#[test]
+fn path_serialization_round_trip_works() {
+    let sut = Path::new(b"some_dir").unwrap();
+
+    assert_tokens(&sut, &[Token::Str("some_dir")]);
+}
+
+#[test]
+fn path_with_invalid_utf8_content_is_serialized_lossy() {
+    let sut = unsafe { Path::new_unchecked(b"some\xffdir") };
+
+    assert_ser_tokens(&sut, &[Token::Str("some\u{fffd}dir")]);
+}
+
+#[test]
+fn path_deserialization_with_invalid_characters_fails() {
+    assert_de_tokens_error::<Path>(
+        &[Token::Str("some|dir")],
+        "invalid Path provided InvalidContent.",
+    );
+}
+
+#[test]
+fn path_deserialization_exceeding_maximum_length_fails() {
+    let too_long: &'static str = "a".repeat(PATH_LENGTH + 1).leak();
+
+    assert_de_tokens_error::<Path>(
+        &[Token::Str(too_long)],
+        "invalid Path provided ExceedsMaximumLength.",
+    );
+}
+
+#[test]
+fn path_deserialization_with_non_ascii_characters_fails() {
+    assert_de_tokens_error::<Path>(
+        &[Token::Str("some_d\u{fc}r")],
+        "invalid Path provided InvalidContent.",
+    );
+}
+
+#[test]
+fn path_new_sanitized_with_strip_removes_invalid_characters() {
+    let sut = Path::new_sanitized(b"<some>/\0pa\x01th/\x1fto\x7f\xff", PathSanitization::Strip);
+
+    assert_that!(sut, eq b"some/path/to\x7f");
+    assert_that!(Path::new(sut.as_bytes()), is_ok);
+}
+
+#[test]
+fn path_new_sanitized_with_replace_replaces_invalid_characters() {
+    let sut = Path::new_sanitized(b"<some>/\0pa\x01th", PathSanitization::Replace(b'_'));
+
+    assert_that!(sut, eq b"_some_/_pa_th");
+    assert_that!(Path::new(sut.as_bytes()), is_ok);
+}
+
+#[test]
+fn path_new_sanitized_with_invalid_replacement_removes_invalid_characters() {
+    let sut = Path::new_sanitized(b"a<b>c", PathSanitization::Replace(b'*'));
+
+    assert_that!(sut, eq b"abc");
+}
+
+#[test]
+fn path_new_sanitized_truncates_to_maximum_length() {
+    let sut = Path::new_sanitized(&[b'a'; PATH_LENGTH + 10], PathSanitization::Strip);
+
+    assert_that!(sut.len(), eq PATH_LENGTH);
+    assert_that!(Path::new(sut.as_bytes()), is_ok);
+}
+
+#[test]
+fn path_new_sanitized_with_only_invalid_characters_is_empty() {
+    let sut = Path::new_sanitized(b"<>\0\x05|?*\"", PathSanitization::Strip);
+
+    assert_that!(sut, eq b"");
+}
+
+#[test]
+fn path_remaining_capacity_is_capacity_minus_len() {
+    let sut = Path::new_empty();
+    assert_that!(sut.remaining_capacity(), eq PATH_LENGTH);
+
+    let sut = Path::new(b"/some/path").unwrap();
+    assert_that!(sut.remaining_capacity(), eq PATH_LENGTH - 10);
+
+    let sut = Path::new(&[b'a'; PATH_LENGTH]).unwrap();
+    assert_that!(sut.remaining_capacity(), eq 0);
+}
+
+#[test]
+fn path_add_path_entry_fails_when_remaining_capacity_is_insufficient() {
+    let entry = Path::new(b"bc").unwrap();
+    let required_capacity = entry.len() + 1;
+
+    let mut sut = Path::new(&[b'a'; PATH_LENGTH - 3]).unwrap();
+    assert_that!(sut.remaining_capacity(), eq required_capacity);
+    assert_that!(sut.add_path_entry(&entry), is_ok);
+    assert_that!(sut.remaining_capacity(), eq 0);
+    assert_that!(sut.add_path_entry(&entry), is_err);
+
+    let mut sut = Path::new(&[b'a'; PATH_LENGTH - 2]).unwrap();
+    assert_that!(sut.remaining_capacity(), lt required_capacity);
+    assert_that!(sut.add_path_entry(&entry), is_err);
+}
+
+struct TestDirectory {
+    path: Path,
+}
+
+impl TestDirectory {
+    fn new() -> Self {
+        create_test_directory();
+        let mut path = test_directory();
+        path.add_path_entry(
+            &Path::new(format!("path_tests_{}", UniqueSystemId::new().unwrap().value()).as_bytes())
+                .unwrap(),
+        )
+        .unwrap();
+        Directory::create(&path, Permission::OWNER_ALL).unwrap();
+
+        Self {
+            path: path.canonicalize().unwrap(),
+        }
+    }
+
+    fn create_sub_directory(&self, name: &[u8]) -> Path {
+        let mut path = self.path.clone();
+        path.add_path_entry(&Path::new(name).unwrap()).unwrap();
+        Directory::create(&path, Permission::OWNER_ALL).unwrap();
+        path
+    }
+}
+
+impl Drop for TestDirectory {
+    fn drop(&mut self) {
+        Directory::remove(&self.path).unwrap();
+    }
+}
+
+#[test]
+fn path_canonicalize_resolves_current_and_parent_directory_entries() {
+    let test = TestDirectory::new();
+    let sub_directory = test.create_sub_directory(b"fuu");
+    test.create_sub_directory(b"bar");
+
+    let mut sut = test.path.clone();
+    sut.push_bytes(b"/./bar/../fuu/.").unwrap();
+
+    assert_that!(sut.canonicalize(), eq Ok(sub_directory));
+}
+
+#[test]
+fn path_canonicalize_of_relative_path_is_absolute() {
+    let sut = Path::new(b".").unwrap().canonicalize().unwrap();
+    let current_directory = std::env::current_dir().unwrap();
+
+    assert_that!(sut.is_absolute(), eq true);
+    assert_that!(sut, eq current_directory.to_str().unwrap().as_bytes());
+}
+
+#[test]
+fn path_canonicalize_of_non_existing_path_fails() {
+    let test = TestDirectory::new();
+
+    let mut sut = test.path.clone();
+    sut.add_path_entry(&Path::new(b"does_not_exist").unwrap())
+        .unwrap();
+
+    assert_that!(sut.canonicalize(), eq Err(PathError::DoesNotExist));
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+fn path_canonicalize_resolves_symbolic_links() {
+    let test = TestDirectory::new();
+    let sub_directory = test.create_sub_directory(b"target");
+
+    let mut link = test.path.clone();
+    link.add_path_entry(&Path::new(b"link").unwrap()).unwrap();
+    std::os::unix::fs::symlink("target", link.to_string()).unwrap();
+
+    assert_that!(link.canonicalize(), eq Ok(sub_directory));
+}
+
+#[cfg(not(target_os = "windows"))]
+#[test]
+fn path_canonicalize_fails_when_result_exceeds_max_length() {
+    let test = TestDirectory::new();
+
+    // the link itself is short but the directory it resolves to is not
+    let long_name = "a".repeat(PATH_LENGTH - test.path.len());
+    let long_directory = std::path::Path::new(&test.path.to_string()).join(&long_name);
+    std::fs::create_dir(&long_directory).unwrap();
+
+    let mut link = test.path.clone();
+    link.add_path_entry(&Path::new(b"link").unwrap()).unwrap();
+    std::os::unix::fs::symlink(&long_name, link.to_string()).unwrap();
+
+    assert_that!(link.canonicalize(), eq Err(PathError::ExceedsMaximumLength));
+
+    std::fs::remove_dir(&long_directory).unwrap();
+}
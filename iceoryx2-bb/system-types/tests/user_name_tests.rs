@@ -42,4 +42,19 @@ fn user_name_new_with_legal_name_works() {
 
     let sut = UserName::new(b"_fuu_bar_");
     assert_that!(sut, is_ok);
+
+    let sut = UserName::new(b"fuu.bar");
+    assert_that!(sut, is_ok);
+}
+
+#[test]
+fn user_name_new_with_illegal_characters_fails() {
+    let sut = UserName::new(b"fuu bar");
+    assert_that!(sut, is_err);
+
+    let sut = UserName::new(b"fuu/bar");
+    assert_that!(sut, is_err);
+
+    let sut = UserName::new(b"fuu:bar");
+    assert_that!(sut, is_err);
 }
@@ -113,13 +113,19 @@ impl<T: Copy + Debug> NamedConceptMgmt for Channel<T> {
             "{} due to a failure while reading the directory (\"{}\").", msg, config.path_hint);
 
         let entries = fail!(from origin,
-                            when directory.contents(),
+                            when directory.iter(),
                             map DirectoryReadError::InsufficientPermissions => NamedConceptListError::InsufficientPermissions,
                             unmatched NamedConceptListError::InternalError,
                             "{} due to a failure while reading the directory (\"{}\") contents.", msg, config.path_hint);
 
         let mut result = vec![];
-        for entry in &entries {
+        for entry in entries.prefix(config.get_prefix()) {
+            let entry = fail!(from origin,
+                              when entry,
+                              map DirectoryReadError::InsufficientPermissions => NamedConceptListError::InsufficientPermissions,
+                              unmatched NamedConceptListError::InternalError,
+                              "{} due to a failure while reading the directory (\"{}\") contents.", msg, config.path_hint);
+
             if let Some(entry_name) = config.extract_name_from_file(entry.name()) {
                 result.push(entry_name);
             }
@@ -0,0 +1,1052 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [`AnonymousSharedMemory`] based implementation of a [`DynamicStorage`]. The memory is not
+//! visible in the file system, therefore it never outlives a crashed process. Only a marker
+//! file identifies the [`DynamicStorage`]. One background thread per process serves the
+//! memory of all [`DynamicStorage`]s the process created. It sends the [`FileDescriptor`] of the
+//! memory via a [`UnixDatagramReceiver`] socket to every process that opens a
+//! [`DynamicStorage`], the marker file contains the name of this socket.
+//!
+//! The [`DynamicStorage`] lives as long as it is owned or the creating process is alive,
+//! therefore it does not support persistency. Requires [`POSIX_SUPPORT_ANONYMOUS_SHARED_MEMORY`](iceoryx2_pal_posix::posix::POSIX_SUPPORT_ANONYMOUS_SHARED_MEMORY).
+//!
+//! # Example
+//!
+//! ```
+//! # use iceoryx2_pal_posix::posix::POSIX_SUPPORT_ANONYMOUS_SHARED_MEMORY;
+//! # if !POSIX_SUPPORT_ANONYMOUS_SHARED_MEMORY { return; }
+//! use iceoryx2_bb_system_types::file_name::FileName;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//! use iceoryx2_cal::dynamic_storage::memfd::*;
+//! use iceoryx2_cal::named_concept::*;
+//! use core::sync::atomic::{AtomicI64, Ordering};
+//!
+//! let storage_name = FileName::new(b"myMemfdStorageName").unwrap();
+//! let owner = Builder::new(&storage_name)
+//!                 .create(AtomicI64::new(0)).unwrap();
+//! owner.get().store(123, Ordering::Relaxed);
+//!
+//! // usually a different process
+//! let storage = Builder::<AtomicI64>::new(&storage_name)
+//!                 .open().unwrap();
+//!
+//! println!("Initial value: {}", storage.get().load(Ordering::Relaxed));
+//! storage.get().store(456, Ordering::Relaxed);
+//! ```
+pub use crate::dynamic_storage::*;
+use crate::static_storage::file::NamedConceptConfiguration;
+use crate::static_storage::file::{
+    NamedConceptDoesExistError, NamedConceptListError, NamedConceptRemoveError,
+};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+pub use core::ops::Deref;
+use core::ptr::NonNull;
+use core::sync::atomic::Ordering;
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_elementary::package_version::PackageVersion;
+use iceoryx2_bb_log::{debug, fail, fatal_panic, warn};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use iceoryx2_bb_posix::anonymous_shared_memory::*;
+use iceoryx2_bb_posix::config::temp_directory;
+use iceoryx2_bb_posix::creation_mode::CreationMode;
+use iceoryx2_bb_posix::directory::*;
+use iceoryx2_bb_posix::file::*;
+use iceoryx2_bb_posix::file_descriptor::*;
+use iceoryx2_bb_posix::mutex::*;
+use iceoryx2_bb_posix::permission::Permission;
+use iceoryx2_bb_posix::socket_ancillary::SocketCred;
+use iceoryx2_bb_posix::system_configuration::SystemInfo;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_posix::unix_datagram_socket::*;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_bb_system_types::path::Path;
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64};
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+use self::dynamic_storage_configuration::DynamicStorageConfiguration;
+
+#[cfg(not(feature = "dev_permissions"))]
+const FINAL_PERMISSIONS: Permission = Permission::OWNER_ALL;
+
+#[cfg(feature = "dev_permissions")]
+const FINAL_PERMISSIONS: Permission = Permission::ALL;
+
+/// The maximum time an opener waits for the creating process to send the memory.
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(1);
+const REQUEST_MEMORY: u8 = 0;
+const REQUEST_REMOVE: u8 = 1;
+const RESPONSE_UNKNOWN_STORAGE: u8 = 2;
+// request layout: [type][is reply socket abstract][reply socket name length][reply socket name][marker path]
+const REQUEST_HEADER_SIZE: usize = 3;
+const REQUEST_BUFFER_SIZE: usize = REQUEST_HEADER_SIZE + 2 * FilePath::max_len();
+
+/// The socket of the thread that serves the memory of all storages of a process.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ServerSocket {
+    name: FilePath,
+    is_abstract: bool,
+}
+
+impl ServerSocket {
+    /// Stored in the marker file so that an opener knows where to request the memory.
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![self.is_abstract as u8];
+        bytes.extend_from_slice(self.name.as_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (is_abstract, name) = bytes.split_first()?;
+        Some(Self {
+            name: FilePath::new(name).ok()?,
+            is_abstract: *is_abstract != 0,
+        })
+    }
+
+    fn read_from(marker_path: &FilePath) -> Option<Self> {
+        let marker = FileBuilder::new(marker_path)
+            .open_existing(AccessMode::Read)
+            .ok()?;
+        let mut content = vec![];
+        marker.read_to_vector(&mut content).ok()?;
+        Self::from_bytes(&content)
+    }
+
+    fn unique_name(suffix: &str) -> Option<Self> {
+        let unique_id = UniqueSystemId::new().ok()?;
+        let file_name =
+            FileName::new(format!("iox2_{:x}.{}", unique_id.value(), suffix).as_bytes()).ok()?;
+        Some(Self {
+            name: FilePath::from_path_and_file(&temp_directory(), &file_name).ok()?,
+            is_abstract: UnixDatagramReceiver::does_support_abstract_namespace(),
+        })
+    }
+
+    fn create_receiver(&self) -> Result<UnixDatagramReceiver, UnixDatagramReceiverCreationError> {
+        let builder = UnixDatagramReceiverBuilder::new(&self.name)
+            .abstract_namespace(self.is_abstract)
+            .permission(FINAL_PERMISSIONS)
+            .creation_mode(CreationMode::CreateExclusive);
+
+        // the permission of an abstract socket is ignored, the server has to verify the
+        // credentials of every request instead
+        match self.is_abstract {
+            true => builder.with_credentials().create(),
+            false => builder.create(),
+        }
+    }
+
+    /// Returns true when the sender of a request would have access to a socket file with
+    /// [`FINAL_PERMISSIONS`]. Without credentials the socket has to be a socket file, so that
+    /// the operating system already enforced the permission.
+    fn is_authorized(&self, credentials: Option<SocketCred>) -> bool {
+        let credentials = match credentials {
+            Some(v) => v,
+            None => return !self.is_abstract,
+        };
+
+        let owner = SocketCred::new();
+        credentials.get_uid() == owner.get_uid()
+            || credentials.get_uid().value() == 0
+            || (FINAL_PERMISSIONS.has(Permission::GROUP_READ)
+                && credentials.get_gid() == owner.get_gid())
+            || FINAL_PERMISSIONS.has(Permission::OTHERS_READ)
+    }
+
+    fn create_sender(&self) -> Result<UnixDatagramSender, UnixDatagramSenderCreationError> {
+        UnixDatagramSenderBuilder::new(&self.name)
+            .abstract_namespace(self.is_abstract)
+            .create()
+    }
+}
+
+#[derive(Debug)]
+struct ServedMemory {
+    // owned, the marker file is removed when the memory is no longer served
+    _marker: File,
+    memory: FileDescriptor,
+}
+
+/// The memory of all storages created by this process, identified by the path of their
+/// marker file. The server thread runs as long as it serves at least one storage.
+#[derive(Debug, Default)]
+struct MemoryServer {
+    socket: Option<ServerSocket>,
+    storages: HashMap<FilePath, ServedMemory>,
+}
+
+static MEMORY_SERVER_HANDLE: Lazy<MutexHandle<MemoryServer>> = Lazy::new(MutexHandle::new);
+static MEMORY_SERVER: Lazy<Mutex<MemoryServer>> = Lazy::new(|| {
+    let result = MutexBuilder::new()
+        .is_interprocess_capable(false)
+        .create(MemoryServer::default(), &MEMORY_SERVER_HANDLE);
+
+    match result {
+        Ok(v) => v,
+        Err(e) => {
+            fatal_panic!(from "dynamic_storage::memfd::MEMORY_SERVER",
+                "Failed to create the memory server registry ({:?}).", e);
+        }
+    }
+});
+
+impl MemoryServer {
+    /// Serves the memory until it is removed with a [`REQUEST_REMOVE`]. The name of the server
+    /// socket is written into the marker file.
+    fn serve(mut marker: File, memory: FileDescriptor) -> Result<(), DynamicStorageCreateError> {
+        let msg = "Unable to serve the memory";
+        let origin = "dynamic_storage::memfd::MemoryServer::serve()";
+        let marker_path = match marker.path() {
+            Some(v) => v.clone(),
+            None => {
+                fail!(from origin, with DynamicStorageCreateError::InternalError,
+                    "{} since the path of the marker file is unknown.", msg);
+            }
+        };
+
+        let mut server = fail!(from origin, when MEMORY_SERVER.lock(),
+            with DynamicStorageCreateError::InternalError,
+            "{} since the lock of the memory server could not be acquired.", msg);
+
+        let socket = match &server.socket {
+            Some(v) => v.clone(),
+            None => {
+                let socket = Self::start()?;
+                server.socket = Some(socket.clone());
+                socket
+            }
+        };
+
+        if let Err(e) = marker.write(&socket.to_bytes()) {
+            // the storage can only be removed by a request, therefore the thread keeps running
+            fail!(from origin, with DynamicStorageCreateError::InternalError,
+                "{} since the server socket could not be written into the marker file ({:?}).", msg, e);
+        }
+
+        server.storages.insert(
+            marker_path,
+            ServedMemory {
+                _marker: marker,
+                memory,
+            },
+        );
+
+        Ok(())
+    }
+
+    fn start() -> Result<ServerSocket, DynamicStorageCreateError> {
+        let msg = "Unable to start the thread that serves the memory";
+        let origin = "dynamic_storage::memfd::MemoryServer::start()";
+
+        let socket = match ServerSocket::unique_name("memfd") {
+            Some(v) => v,
+            None => {
+                fail!(from origin, with DynamicStorageCreateError::InternalError,
+                    "{} since no unique socket name could be generated.", msg);
+            }
+        };
+
+        let receiver = fail!(from origin, when socket.create_receiver(),
+            with DynamicStorageCreateError::InternalError,
+            "{} since the socket could not be created.", msg);
+
+        let server_socket = socket.clone();
+        fail!(from origin, when std::thread::Builder::new()
+                .name("iox2-memfd".to_string())
+                .spawn(move || Self::run(&server_socket, receiver)),
+            with DynamicStorageCreateError::InternalError,
+            "{} since the thread could not be spawned.", msg);
+
+        Ok(socket)
+    }
+
+    fn run(socket: &ServerSocket, receiver: UnixDatagramReceiver) {
+        let mut buffer = [0u8; REQUEST_BUFFER_SIZE];
+        loop {
+            let stop_msg = "Stop serving the memory since the request could not be received";
+            let (received_bytes, credentials) = match socket.is_abstract {
+                true => match receiver.blocking_receive_with_credentials(&mut buffer) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        warn!(from receiver, "{} ({:?}).", stop_msg, e);
+                        return;
+                    }
+                },
+                false => match receiver.blocking_receive(&mut buffer) {
+                    Ok(v) => (v, None),
+                    Err(e) => {
+                        warn!(from receiver, "{} ({:?}).", stop_msg, e);
+                        return;
+                    }
+                },
+            };
+
+            if !socket.is_authorized(credentials) {
+                warn!(from receiver, "Ignoring request since the sender ({:?}) is not allowed to access the memory.", credentials);
+                continue;
+            }
+
+            let (request, reply_socket, marker_path) =
+                match Self::parse_request(&buffer[..received_bytes as usize]) {
+                    Some(v) => v,
+                    None => {
+                        warn!(from receiver, "Ignoring invalid request.");
+                        continue;
+                    }
+                };
+
+            let sender = match reply_socket.create_sender() {
+                Ok(v) => v,
+                Err(e) => {
+                    debug!(from receiver, "Unable to connect to the response socket \"{}\" ({:?}).", reply_socket.name, e);
+                    continue;
+                }
+            };
+
+            // the connection remains established when the socket file is removed, this way no
+            // reply socket file remains when the requesting process crashes
+            if !reply_socket.is_abstract {
+                if let Err(e) = File::remove(&reply_socket.name) {
+                    debug!(from receiver, "Unable to remove the response socket \"{}\" ({:?}).", reply_socket.name, e);
+                }
+            }
+
+            let (response, memory, stop_serving) = match Self::handle(request, &marker_path) {
+                Some(v) => v,
+                None => {
+                    warn!(from receiver, "Stop serving the memory since the lock of the memory server could not be acquired.");
+                    return;
+                }
+            };
+
+            let memory: Vec<&dyn FileDescriptorBased> = memory
+                .iter()
+                .map(|fd| fd as &dyn FileDescriptorBased)
+                .collect();
+            if let Err(e) = sender.try_send_with_fds(&[response], &memory) {
+                debug!(from sender, "Unable to respond to the request ({:?}).", e);
+            }
+
+            if stop_serving {
+                return;
+            }
+        }
+    }
+
+    /// Returns the response, the memory that shall be sent with it and if the server thread
+    /// shall stop since it serves no more storages.
+    fn handle(request: u8, marker_path: &FilePath) -> Option<(u8, Option<FileDescriptor>, bool)> {
+        let mut server = MEMORY_SERVER.lock().ok()?;
+        match request {
+            REQUEST_REMOVE => match server.storages.remove(marker_path) {
+                Some(_) => {
+                    let stop_serving = server.storages.is_empty();
+                    if stop_serving {
+                        server.socket = None;
+                    }
+                    Some((REQUEST_REMOVE, None, stop_serving))
+                }
+                None => Some((RESPONSE_UNKNOWN_STORAGE, None, false)),
+            },
+            _ => match server.storages.get(marker_path) {
+                Some(served) => Some((REQUEST_MEMORY, Some(served.memory.clone()), false)),
+                None => Some((RESPONSE_UNKNOWN_STORAGE, None, false)),
+            },
+        }
+    }
+
+    fn parse_request(request: &[u8]) -> Option<(u8, ServerSocket, FilePath)> {
+        if request.len() < REQUEST_HEADER_SIZE {
+            return None;
+        }
+
+        let reply_end = REQUEST_HEADER_SIZE + request[2] as usize;
+        let reply_socket = ServerSocket {
+            name: FilePath::new(request.get(REQUEST_HEADER_SIZE..reply_end)?).ok()?,
+            is_abstract: request[1] != 0,
+        };
+        let marker_path = FilePath::new(request.get(reply_end..)?).ok()?;
+
+        Some((request[0], reply_socket, marker_path))
+    }
+}
+
+/// The builder of [`Storage`].
+#[derive(Debug)]
+pub struct Builder<'builder, T: Send + Sync + Debug> {
+    storage_name: FileName,
+    call_drop_on_destruction: bool,
+    supplementary_size: usize,
+    has_ownership: bool,
+    huge_pages: HugePagePolicy,
+    access_control_list: Option<AccessControlList>,
+    config: Configuration<T>,
+    timeout: Duration,
+    initializer: Initializer<'builder, T>,
+    _phantom_data: PhantomData<T>,
+}
+
+#[derive(Debug)]
+pub struct Configuration<T: Send + Sync + Debug> {
+    suffix: FileName,
+    prefix: FileName,
+    path: Path,
+    _data: PhantomData<T>,
+    type_name: String,
+}
+
+impl<T: Send + Sync + Debug> Clone for Configuration<T> {
+    fn clone(&self) -> Self {
+        Self {
+            suffix: self.suffix.clone(),
+            prefix: self.prefix.clone(),
+            path: self.path.clone(),
+            _data: PhantomData,
+            type_name: self.type_name.clone(),
+        }
+    }
+}
+
+#[repr(C)]
+struct Data<T: Send + Sync + Debug> {
+    version: IoxAtomicU64,
+    call_drop_on_destruction: bool,
+    data: T,
+}
+
+impl<T: Send + Sync + Debug> Default for Configuration<T> {
+    fn default() -> Self {
+        Self {
+            path: Storage::<()>::default_path_hint(),
+            suffix: Storage::<()>::default_suffix(),
+            prefix: Storage::<()>::default_prefix(),
+            _data: PhantomData,
+            type_name: core::any::type_name::<T>().to_string(),
+        }
+    }
+}
+
+impl<T: Send + Sync + Debug> DynamicStorageConfiguration for Configuration<T> {
+    fn type_name(&self) -> &str {
+        &self.type_name
+    }
+}
+
+impl<T: Send + Sync + Debug> NamedConceptConfiguration for Configuration<T> {
+    fn prefix(mut self, value: &FileName) -> Self {
+        self.prefix = value.clone();
+        self
+    }
+
+    fn get_prefix(&self) -> &FileName {
+        &self.prefix
+    }
+
+    fn suffix(mut self, value: &FileName) -> Self {
+        self.suffix = value.clone();
+        self
+    }
+
+    fn path_hint(mut self, value: &Path) -> Self {
+        self.path = value.clone();
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path
+    }
+
+    fn path_for(&self, value: &FileName) -> FilePath {
+        self.path_for_with_type(value)
+    }
+
+    fn extract_name_from_file(&self, value: &FileName) -> Option<FileName> {
+        self.extract_name_from_file_with_type(value)
+    }
+}
+
+impl<T: Send + Sync + Debug> Configuration<T> {
+    /// Sends a request to the thread that serves the memory and returns the response together
+    /// with the received [`FileDescriptor`]s. When the thread does not respond the
+    /// [`DynamicStorage`] is considered as non-existing.
+    fn request(
+        &self,
+        name: &FileName,
+        request: u8,
+    ) -> Result<(u8, Vec<FileDescriptor>), DynamicStorageOpenError> {
+        let msg = "Unable to send request to the memory serving thread";
+        let origin = "dynamic_storage::memfd::Configuration::request()";
+
+        let marker_path = self.path_for(name);
+        // the marker is empty until the memory is served
+        let server_socket = match ServerSocket::read_from(&marker_path) {
+            Some(v) => v,
+            None => return Err(DynamicStorageOpenError::DoesNotExist),
+        };
+
+        let reply_socket = match ServerSocket::unique_name("memfd_reply") {
+            Some(v) => v,
+            None => {
+                fail!(from origin, with DynamicStorageOpenError::InternalError,
+                    "{} since no unique name could be generated for the response socket.", msg);
+            }
+        };
+        let reply_receiver = fail!(from origin, when reply_socket.create_receiver(),
+            with DynamicStorageOpenError::InternalError,
+            "{} since the response socket could not be created.", msg);
+
+        let sender = match server_socket.create_sender() {
+            Ok(sender) => sender,
+            // a connection is refused when the creating process died without cleaning up
+            Err(UnixDatagramSenderCreationError::DoesNotExist)
+            | Err(UnixDatagramSenderCreationError::ConnectionRefused) => {
+                return Err(DynamicStorageOpenError::DoesNotExist);
+            }
+            Err(e) => {
+                fail!(from origin, with DynamicStorageOpenError::InternalError,
+                    "{} since the connection could not be established ({:?}).", msg, e);
+            }
+        };
+
+        let mut request_data = vec![
+            request,
+            reply_socket.is_abstract as u8,
+            reply_socket.name.len() as u8,
+        ];
+        request_data.extend_from_slice(reply_socket.name.as_bytes());
+        request_data.extend_from_slice(marker_path.as_bytes());
+        match sender.timed_send(&request_data, RESPONSE_TIMEOUT) {
+            Ok(()) => (),
+            Err(UnixDatagramSendError::ConnectionRefused)
+            | Err(UnixDatagramSendError::ConnectionReset) => {
+                return Err(DynamicStorageOpenError::DoesNotExist);
+            }
+            Err(e) => {
+                fail!(from origin, with DynamicStorageOpenError::InternalError,
+                    "{} since the request could not be sent ({:?}).", msg, e);
+            }
+        }
+
+        let mut wait_for_response = fail!(from origin, when AdaptiveWaitBuilder::new().create(),
+            with DynamicStorageOpenError::InternalError,
+            "{} since the AdaptiveWait could not be initialized.", msg);
+        let mut response = [0u8; 1];
+        let mut fds = vec![];
+        loop {
+            match reply_receiver.try_receive_with_fds(&mut response, &mut fds) {
+                Ok(0) => (),
+                Ok(_) if response[0] == RESPONSE_UNKNOWN_STORAGE => {
+                    return Err(DynamicStorageOpenError::DoesNotExist);
+                }
+                Ok(_) => return Ok((response[0], fds)),
+                Err(e) => {
+                    fail!(from origin, with DynamicStorageOpenError::InternalError,
+                        "{} since the response could not be received ({:?}).", msg, e);
+                }
+            }
+
+            let elapsed_time = fail!(from origin, when wait_for_response.wait(),
+                with DynamicStorageOpenError::InternalError,
+                "{} since the adaptive wait call failed.", msg);
+            if elapsed_time >= RESPONSE_TIMEOUT {
+                return Err(DynamicStorageOpenError::DoesNotExist);
+            }
+        }
+    }
+
+    /// Stops serving the memory. The server removes the marker file before it responds,
+    /// therefore the [`DynamicStorage`] no longer exists when this function returns true.
+    fn stop_serving(&self, name: &FileName) -> bool {
+        self.request(name, REQUEST_REMOVE).is_ok()
+    }
+}
+
+impl<T: Send + Sync + Debug> NamedConceptBuilder<Storage<T>> for Builder<'_, T> {
+    fn new(storage_name: &FileName) -> Self {
+        Self {
+            call_drop_on_destruction: true,
+            has_ownership: true,
+            storage_name: storage_name.clone(),
+            supplementary_size: 0,
+            huge_pages: HugePagePolicy::None,
+            access_control_list: None,
+            config: Configuration::default(),
+            timeout: Duration::ZERO,
+            initializer: Initializer::new(|_, _| true),
+            _phantom_data: PhantomData,
+        }
+    }
+
+    fn config(mut self, config: &Configuration<T>) -> Self {
+        self.config = config.clone();
+        self
+    }
+}
+
+impl<T: Send + Sync + Debug> Builder<'_, T> {
+    fn open_impl(&self) -> Result<Storage<T>, DynamicStorageOpenError> {
+        let msg = "Failed to open memfd::DynamicStorage";
+
+        let marker_path = self.config.path_for(&self.storage_name);
+        let mut wait_for_initialization = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                    with DynamicStorageOpenError::InternalError,
+                                    "{} since the AdaptiveWait could not be initialized.", msg);
+
+        let mut elapsed_time = Duration::ZERO;
+        let mut fds = loop {
+            match File::does_exist(&marker_path) {
+                Ok(true) => (),
+                Ok(false) => {
+                    fail!(from self, with DynamicStorageOpenError::DoesNotExist,
+                        "{} since a dynamic storage with that name does not exists.", msg);
+                }
+                Err(e) => {
+                    fail!(from self, with DynamicStorageOpenError::InternalError,
+                        "{} since the existence of the marker file could not be verified ({:?}).", msg, e);
+                }
+            }
+
+            match self.config.request(&self.storage_name, REQUEST_MEMORY) {
+                Ok((_, fds)) => break fds,
+                // either the creator does not yet serve the memory or it died
+                Err(DynamicStorageOpenError::DoesNotExist) => {
+                    if elapsed_time >= self.timeout {
+                        fail!(from self, with DynamicStorageOpenError::InitializationNotYetFinalized,
+                            "{} since no process provides the memory - (it is not initialized after {:?}).",
+                            msg, self.timeout);
+                    }
+                }
+                Err(e) => {
+                    fail!(from self, with e, "{} since the memory could not be requested.", msg);
+                }
+            }
+
+            elapsed_time = fail!(from self, when wait_for_initialization.wait(),
+                                    with DynamicStorageOpenError::InternalError,
+                                    "{} since the adaptive wait call failed.", msg);
+        };
+
+        if fds.is_empty() {
+            fail!(from self, with DynamicStorageOpenError::InternalError,
+                "{} since the response does not contain the memory.", msg);
+        }
+
+        let shm =
+            match AnonymousSharedMemory::from_file_descriptor(fds.remove(0), AccessMode::ReadWrite)
+            {
+                Ok(shm) => shm,
+                Err(e) => {
+                    fail!(from self, with DynamicStorageOpenError::InternalError,
+                    "{} since the received memory could not be mapped ({:?}).", msg, e);
+                }
+            };
+
+        if shm.size() < core::mem::size_of::<Data<T>>() {
+            fail!(from self, with DynamicStorageOpenError::InternalError,
+                "{} since the received memory is too small to contain the data.", msg);
+        }
+
+        let init_state = shm.base_address().as_ptr() as *const Data<T>;
+
+        loop {
+            //////////////////////////////////////////
+            // SYNC POINT: read Data<T>::data
+            //////////////////////////////////////////
+            let package_version = unsafe { &(*init_state) }.version.load(Ordering::SeqCst);
+
+            let package_version = PackageVersion::from_u64(package_version);
+            if package_version.to_u64() == 0 {
+                if elapsed_time >= self.timeout {
+                    fail!(from self, with DynamicStorageOpenError::InitializationNotYetFinalized,
+                        "{} since the version number was not set - (it is not initialized after {:?}).",
+                        msg, self.timeout);
+                }
+            } else if package_version != PackageVersion::get() {
+                fail!(from self, with DynamicStorageOpenError::VersionMismatch,
+                       "{} since the dynamic storage was created with version {} but this process requires version {}.",
+                        msg, package_version, PackageVersion::get());
+            } else {
+                break;
+            }
+
+            elapsed_time = fail!(from self, when wait_for_initialization.wait(),
+                                    with DynamicStorageOpenError::InternalError,
+                                    "{} since the adaptive wait call failed.", msg);
+        }
+
+        Ok(Storage {
+            shm,
+            name: self.storage_name.clone(),
+            config: self.config.clone(),
+            has_ownership: IoxAtomicBool::new(false),
+            _phantom_data: PhantomData,
+        })
+    }
+
+    fn create_impl(&mut self) -> Result<Storage<T>, DynamicStorageCreateError> {
+        let msg = "Failed to create dynamic_storage::Memfd";
+
+        if matches!(
+            self.huge_pages,
+            HugePagePolicy::Require2MB | HugePagePolicy::Require1GB
+        ) {
+            fail!(from self, with DynamicStorageCreateError::HugePagesUnavailable,
+                "{} since anonymous shared memory is not backed by huge pages.", msg);
+        }
+
+        if self.access_control_list.is_some() {
+            fail!(from self, with DynamicStorageCreateError::AccessControlListUnsupported,
+                "{} since the memory is shared via a socket and not via the file system.", msg);
+        }
+
+        // the marker file is created first since it guarantees that the name is unique, it
+        // is removed again when it goes out of scope before the memory is served
+        let marker = match FileBuilder::new(&self.config.path_for(&self.storage_name))
+            .has_ownership(true)
+            .creation_mode(CreationMode::CreateExclusive)
+            .permission(FINAL_PERMISSIONS)
+            .create()
+        {
+            Ok(v) => v,
+            Err(FileCreationError::FileAlreadyExists) => {
+                fail!(from self, with DynamicStorageCreateError::AlreadyExists,
+                    "{} since a dynamic storage with the name already exists.", msg);
+            }
+            Err(FileCreationError::InsufficientPermissions) => {
+                fail!(from self, with DynamicStorageCreateError::InsufficientPermissions,
+                    "{} due to insufficient permissions.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with DynamicStorageCreateError::InternalError,
+                    "{} since the marker file could not be created ({:?}).", msg, e);
+            }
+        };
+
+        // the anonymous shared memory is always zeroed, therefore the version is zero until
+        // the initialization is finalized
+        let shm = match AnonymousSharedMemoryBuilder::new(&self.storage_name)
+            .size(core::mem::size_of::<Data<T>>() + self.supplementary_size)
+            .is_sealed(true)
+            .create()
+        {
+            Ok(v) => v,
+            Err(e) => {
+                fail!(from self, with DynamicStorageCreateError::InternalError,
+                    "{} since the underlying anonymous shared memory could not be created ({:?}).", msg, e);
+            }
+        };
+
+        fail!(from self, when MemoryServer::serve(marker, shm.file_descriptor().clone()),
+            "{} since the memory could not be served.", msg);
+
+        Ok(Storage {
+            shm,
+            name: self.storage_name.clone(),
+            config: self.config.clone(),
+            has_ownership: IoxAtomicBool::new(self.has_ownership),
+            _phantom_data: PhantomData,
+        })
+    }
+
+    fn init_impl(
+        &mut self,
+        storage: Storage<T>,
+        initial_value: T,
+    ) -> Result<Storage<T>, DynamicStorageCreateError> {
+        let msg = "Failed to init dynamic_storage::Memfd";
+        let value = storage.shm.base_address().as_ptr() as *mut Data<T>;
+        let version_ptr = unsafe { core::ptr::addr_of_mut!((*value).version) };
+
+        unsafe { core::ptr::addr_of_mut!((*value).data).write(initial_value) };
+        unsafe {
+            core::ptr::addr_of_mut!((*value).call_drop_on_destruction)
+                .write(self.call_drop_on_destruction)
+        };
+
+        let supplementary_start = (storage.shm.base_address().as_ptr() as usize
+            + core::mem::size_of::<Data<T>>()) as *mut u8;
+        let supplementary_len = storage.shm.size() - core::mem::size_of::<Data<T>>();
+
+        let mut allocator = BumpAllocator::new(
+            unsafe { NonNull::new_unchecked(supplementary_start) },
+            supplementary_len,
+        );
+
+        let origin = format!("{self:?}");
+        if !self
+            .initializer
+            .call(unsafe { &mut (*value).data }, &mut allocator)
+        {
+            storage.acquire_ownership();
+            drop(storage);
+            fail!(from origin, with DynamicStorageCreateError::InitializationFailed,
+                "{} since the initialization of the underlying construct failed.", msg);
+        }
+
+        //////////////////////////////////////////
+        // SYNC POINT: write Data<T>::data
+        //////////////////////////////////////////
+        unsafe { (*version_ptr).store(PackageVersion::get().to_u64(), Ordering::SeqCst) };
+
+        Ok(storage)
+    }
+}
+
+impl<'builder, T: Send + Sync + Debug> DynamicStorageBuilder<'builder, T, Storage<T>>
+    for Builder<'builder, T>
+{
+    fn call_drop_on_destruction(mut self, value: bool) -> Self {
+        self.call_drop_on_destruction = value;
+        self
+    }
+
+    fn has_ownership(mut self, value: bool) -> Self {
+        self.has_ownership = value;
+        self
+    }
+
+    fn initializer<F: FnMut(&mut T, &mut BumpAllocator) -> bool + 'builder>(
+        mut self,
+        value: F,
+    ) -> Self {
+        self.initializer = Initializer::new(value);
+        self
+    }
+
+    fn timeout(mut self, value: Duration) -> Self {
+        self.timeout = value;
+        self
+    }
+
+    fn supplementary_size(mut self, value: usize) -> Self {
+        self.supplementary_size = value;
+        self
+    }
+
+    fn huge_pages(mut self, value: HugePagePolicy) -> Self {
+        self.huge_pages = value;
+        self
+    }
+
+    fn access_control_list(mut self, value: &AccessControlList) -> Self {
+        self.access_control_list = Some(value.clone());
+        self
+    }
+
+    fn create(mut self, initial_value: T) -> Result<Storage<T>, DynamicStorageCreateError> {
+        let storage = self.create_impl()?;
+        self.init_impl(storage, initial_value)
+    }
+
+    fn open(self) -> Result<Storage<T>, DynamicStorageOpenError> {
+        self.open_impl()
+    }
+
+    fn open_or_create(
+        mut self,
+        initial_value: T,
+    ) -> Result<Storage<T>, DynamicStorageOpenOrCreateError> {
+        loop {
+            match self.open_impl() {
+                Ok(storage) => return Ok(storage),
+                Err(DynamicStorageOpenError::DoesNotExist) => match self.create_impl() {
+                    Ok(storage) => {
+                        return Ok(self.init_impl(storage, initial_value)?);
+                    }
+                    Err(DynamicStorageCreateError::AlreadyExists) => continue,
+                    Err(e) => return Err(e.into()),
+                },
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+/// Implements [`DynamicStorage`] for anonymous shared memory. It is built by
+/// [`Builder`].
+#[derive(Debug)]
+pub struct Storage<T: Debug + Send + Sync> {
+    shm: AnonymousSharedMemory,
+    name: FileName,
+    config: Configuration<T>,
+    has_ownership: IoxAtomicBool,
+    _phantom_data: PhantomData<T>,
+}
+
+unsafe impl<T: Debug + Send + Sync> Send for Storage<T> {}
+unsafe impl<T: Debug + Send + Sync> Sync for Storage<T> {}
+
+impl<T: Debug + Send + Sync> Drop for Storage<T> {
+    fn drop(&mut self) {
+        if self.has_ownership() {
+            if !self.config.stop_serving(&self.name) {
+                warn!(from self, "Unable to stop the thread that serves the memory.");
+            }
+
+            let data = unsafe { &mut (*(self.shm.base_address().as_ptr() as *mut Data<T>)) };
+            if data.call_drop_on_destruction {
+                let user_type = &mut data.data;
+                unsafe { core::ptr::drop_in_place(user_type) };
+            }
+        }
+    }
+}
+
+impl<T: Send + Sync + Debug> NamedConcept for Storage<T> {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl<T: Send + Sync + Debug> NamedConceptMgmt for Storage<T> {
+    type Configuration = Configuration<T>;
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptDoesExistError> {
+        let msg = format!("Unable to check if dynamic_storage::memfd \"{name}\" exists");
+
+        match File::does_exist(&cfg.path_for(name)) {
+            Ok(v) => Ok(v),
+            Err(v) => {
+                fail!(from "dynamic_storage::memfd::Storage::does_exist_cfg()",
+                        with NamedConceptDoesExistError::UnderlyingResourcesCorrupted,
+                    "{} due to an internal failure ({:?}), is the dynamic storage in a corrupted state?", msg, v);
+            }
+        }
+    }
+
+    fn list_cfg(config: &Self::Configuration) -> Result<Vec<FileName>, NamedConceptListError> {
+        let msg = "Unable to list all dynamic_storage::memfd";
+        let origin = "dynamic_storage::memfd::Storage::list_cfg()";
+
+        let directory = fail!(from origin, when Directory::new(&config.path),
+            map DirectoryOpenError::InsufficientPermissions => NamedConceptListError::InsufficientPermissions,
+            unmatched NamedConceptListError::InternalError,
+            "{} due to a failure while reading the directory (\"{}\").", msg, config.path);
+
+        let entries = fail!(from origin,
+                            when directory.iter(),
+                            map DirectoryReadError::InsufficientPermissions => NamedConceptListError::InsufficientPermissions,
+                            unmatched NamedConceptListError::InternalError,
+                            "{} due to a failure while reading the directory (\"{}\") contents.", msg, config.path);
+
+        let mut result = vec![];
+        for entry in entries.prefix(config.get_prefix()) {
+            let entry = fail!(from origin,
+                              when entry,
+                              map DirectoryReadError::InsufficientPermissions => NamedConceptListError::InsufficientPermissions,
+                              unmatched NamedConceptListError::InternalError,
+                              "{} due to a failure while reading the directory (\"{}\") contents.", msg, config.path);
+
+            if let Some(entry_name) = config.extract_name_from_file(entry.name()) {
+                result.push(entry_name);
+            }
+        }
+
+        Ok(result)
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptRemoveError> {
+        let msg = "Unable to remove dynamic_storage::memfd";
+        let origin = "dynamic_storage::memfd::Storage::remove_cfg()";
+
+        match Builder::<T>::new(name).config(cfg).open() {
+            Ok(s) => {
+                s.acquire_ownership();
+                return Ok(true);
+            }
+            Err(DynamicStorageOpenError::DoesNotExist) => return Ok(false),
+            Err(e) => {
+                warn!(from origin,
+                    "Removing DynamicStorage in broken state ({:?}) will not call drop of the underlying data type {:?}.",
+                    e, core::any::type_name::<T>());
+
+                if cfg.stop_serving(name) {
+                    return Ok(true);
+                }
+            }
+        }
+
+        // the leftovers of a process that died without cleaning up, an abstract server socket
+        // is removed by the kernel
+        let marker_path = cfg.path_for(name);
+        let leftover_socket = ServerSocket::read_from(&marker_path)
+            .filter(|socket| !socket.is_abstract)
+            .map(|socket| File::remove(&socket.name))
+            .unwrap_or(Ok(false));
+        match leftover_socket.and(File::remove(&marker_path)) {
+            Ok(v) => Ok(v),
+            Err(FileRemoveError::InsufficientPermissions)
+            | Err(FileRemoveError::PartOfReadOnlyFileSystem) => {
+                fail!(from origin, with NamedConceptRemoveError::InsufficientPermissions,
+                    "{} \"{}\" due to insufficient permissions.", msg, name);
+            }
+            Err(v) => {
+                fail!(from origin, with NamedConceptRemoveError::InternalError,
+                    "{} \"{}\" due to an internal failure ({:?}).", msg, name, v);
+            }
+        }
+    }
+
+    fn remove_path_hint(
+        value: &Path,
+    ) -> Result<(), crate::named_concept::NamedConceptPathHintRemoveError> {
+        crate::named_concept::remove_path_hint(value)
+    }
+}
+
+impl<T: Send + Sync + Debug> DynamicStorage<T> for Storage<T> {
+    type Builder<'builder> = Builder<'builder, T>;
+
+    fn does_support_persistency() -> bool {
+        false
+    }
+
+    fn acquire_ownership(&self) {
+        self.has_ownership.store(true, Ordering::Relaxed)
+    }
+
+    fn get(&self) -> &T {
+        unsafe { &(*(self.shm.base_address().as_ptr() as *const Data<T>)).data }
+    }
+
+    fn page_size(&self) -> usize {
+        SystemInfo::PageSize.value()
+    }
+
+    fn has_ownership(&self) -> bool {
+        self.has_ownership.load(Ordering::Relaxed)
+    }
+
+    fn release_ownership(&self) {
+        self.has_ownership.store(false, Ordering::Relaxed)
+    }
+
+    unsafe fn __internal_set_type_name_in_config(
+        config: &mut Self::Configuration,
+        type_name: &str,
+    ) {
+        config.type_name = type_name.to_string()
+    }
+}
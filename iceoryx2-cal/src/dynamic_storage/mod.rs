@@ -58,6 +58,8 @@ use core::{fmt::Debug, time::Duration};
 
 use iceoryx2_bb_elementary::enum_gen;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+pub use iceoryx2_bb_posix::access_control_list::AccessControlList;
+pub use iceoryx2_bb_posix::shared_memory::HugePagePolicy;
 use iceoryx2_bb_system_types::file_name::*;
 use tiny_fn::tiny_fn;
 
@@ -76,6 +78,7 @@ impl<T> Debug for Initializer<'_, T> {
 
 #[doc(hidden)]
 pub mod dynamic_storage_configuration;
+pub mod memfd;
 pub mod posix_shared_memory;
 pub mod process_local;
 pub mod recommended;
@@ -86,6 +89,8 @@ pub enum DynamicStorageCreateError {
     AlreadyExists,
     InsufficientPermissions,
     InitializationFailed,
+    HugePagesUnavailable,
+    AccessControlListUnsupported,
     InternalError,
 }
 
@@ -121,6 +126,20 @@ pub trait DynamicStorageBuilder<'builder, T: Send + Sync, D: DynamicStorage<T>>:
     /// the already initialized [`DynamicStorage`] with the full size is used.
     fn supplementary_size(self, value: usize) -> Self;
 
+    /// Defines if the memory of a newly created [`DynamicStorage`] shall be backed by huge
+    /// pages. If the policy requires huge pages that are not available the creation fails with
+    /// [`DynamicStorageCreateError::HugePagesUnavailable`]. The default is
+    /// [`HugePagePolicy::None`].
+    fn huge_pages(self, value: HugePagePolicy) -> Self;
+
+    /// Applies the [`AccessControlList`] to a newly created [`DynamicStorage`] when its
+    /// initialization is finalized. The entries of the owning user, the owning group and others
+    /// are replaced with the permissions of the [`DynamicStorage`]. If access control lists are
+    /// not supported the creation fails with
+    /// [`DynamicStorageCreateError::AccessControlListUnsupported`]. By default no
+    /// [`AccessControlList`] is applied.
+    fn access_control_list(self, value: &AccessControlList) -> Self;
+
     /// The timeout defines how long the [`DynamicStorageBuilder`] should wait for
     /// [`DynamicStorageBuilder::create()`]
     /// to finialize the initialization. This is required when the [`DynamicStorage`] is
@@ -176,6 +195,9 @@ pub trait DynamicStorage<T: Send + Sync>:
     /// thread-safe.
     fn get(&self) -> &T;
 
+    /// Returns the size of the pages backing the [`DynamicStorage`].
+    fn page_size(&self) -> usize;
+
     /// The default suffix of every dynamic storage
     fn default_suffix() -> FileName {
         unsafe { FileName::new_unchecked(b".dyn") }
@@ -50,6 +50,7 @@ use core::sync::atomic::Ordering;
 use iceoryx2_bb_elementary::package_version::PackageVersion;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_log::warn;
+use iceoryx2_bb_posix::access_control_list::AccessControlListApplyError;
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_bb_posix::directory::*;
 use iceoryx2_bb_posix::file_descriptor::FileDescriptorManagement;
@@ -74,6 +75,8 @@ pub struct Builder<'builder, T: Send + Sync + Debug> {
     call_drop_on_destruction: bool,
     supplementary_size: usize,
     has_ownership: bool,
+    huge_pages: HugePagePolicy,
+    access_control_list: Option<AccessControlList>,
     config: Configuration<T>,
     timeout: Duration,
     initializer: Initializer<'builder, T>,
@@ -170,6 +173,8 @@ impl<T: Send + Sync + Debug> NamedConceptBuilder<Storage<T>> for Builder<'_, T>
             has_ownership: true,
             storage_name: storage_name.clone(),
             supplementary_size: 0,
+            huge_pages: HugePagePolicy::None,
+            access_control_list: None,
             config: Configuration::default(),
             timeout: Duration::ZERO,
             initializer: Initializer::new(|_, _| true),
@@ -270,6 +275,7 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
             .permission(INIT_PERMISSIONS)
             .zero_memory(false)
             .has_ownership(self.has_ownership)
+            .huge_pages(self.huge_pages)
             .create()
         {
             Ok(v) => v,
@@ -281,6 +287,13 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
                 fail!(from self, with DynamicStorageCreateError::InsufficientPermissions,
                     "{} due to insufficient permissions.", msg);
             }
+            Err(
+                SharedMemoryCreationError::HugePagesNotSupported
+                | SharedMemoryCreationError::InsufficientHugePages,
+            ) => {
+                fail!(from self, with DynamicStorageCreateError::HugePagesUnavailable,
+                    "{} since the required huge pages are not available.", msg);
+            }
             Err(_) => {
                 fail!(from self, with DynamicStorageCreateError::InternalError,
                     "{} since the underlying shared memory could not be created.", msg);
@@ -290,6 +303,46 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
         Ok(shm)
     }
 
+    // An access control list replaces the permission bits, therefore it is applied instead of
+    // the final permissions. This makes the storage accessible for all users at once.
+    fn apply_final_permissions(
+        &self,
+        shm: &mut SharedMemory,
+    ) -> Result<(), DynamicStorageCreateError> {
+        let msg = "Unable to apply the final permissions";
+        let acl = match &self.access_control_list {
+            None => {
+                fail!(from self, when shm.set_permission(FINAL_PERMISSIONS),
+                    with DynamicStorageCreateError::InternalError,
+                    "{} {}.", msg, FINAL_PERMISSIONS);
+                return Ok(());
+            }
+            Some(acl) => {
+                let mut acl = acl.clone();
+                fail!(from self, when acl.merge_permission(FINAL_PERMISSIONS),
+                    with DynamicStorageCreateError::InternalError,
+                    "{} since they could not be merged into the access control list.", msg);
+                acl
+            }
+        };
+
+        match shm.set_access_control_list(&acl) {
+            Ok(()) => Ok(()),
+            Err(AccessControlListApplyError::Unsupported) => {
+                fail!(from self, with DynamicStorageCreateError::AccessControlListUnsupported,
+                    "{} since access control lists are not supported.", msg);
+            }
+            Err(AccessControlListApplyError::InsufficientPermissions) => {
+                fail!(from self, with DynamicStorageCreateError::InsufficientPermissions,
+                    "{} since the access control list could not be applied due to insufficient permissions.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with DynamicStorageCreateError::InternalError,
+                    "{} since the access control list could not be applied ({:?}).", msg, e);
+            }
+        }
+    }
+
     fn init_impl(
         &mut self,
         mut shm: SharedMemory,
@@ -335,12 +388,12 @@ impl<T: Send + Sync + Debug> Builder<'_, T> {
         //////////////////////////////////////////
         unsafe { (*version_ptr).store(PackageVersion::get().to_u64(), Ordering::SeqCst) };
 
-        if let Err(e) = shm.set_permission(FINAL_PERMISSIONS) {
+        if let Err(e) = self.apply_final_permissions(&mut shm) {
             unsafe { core::ptr::drop_in_place(value) };
             shm.acquire_ownership();
-            fail!(from origin, with DynamicStorageCreateError::InternalError,
-                "{} since the final permissions could not be applied to the underlying shared memory ({:?}).",
-                msg, e);
+            fail!(from origin, with e,
+                "{} since the final permissions could not be applied to the underlying shared memory.",
+                msg);
         }
 
         Ok(Storage {
@@ -382,6 +435,16 @@ impl<'builder, T: Send + Sync + Debug> DynamicStorageBuilder<'builder, T, Storag
         self
     }
 
+    fn huge_pages(mut self, value: HugePagePolicy) -> Self {
+        self.huge_pages = value;
+        self
+    }
+
+    fn access_control_list(mut self, value: &AccessControlList) -> Self {
+        self.access_control_list = Some(value.clone());
+        self
+    }
+
     fn create(mut self, initial_value: T) -> Result<Storage<T>, DynamicStorageCreateError> {
         let shm = self.create_impl()?;
         self.init_impl(shm, initial_value)
@@ -528,6 +591,10 @@ impl<T: Send + Sync + Debug> DynamicStorage<T> for Storage<T> {
         unsafe { &(*(self.shm.base_address().as_ptr() as *const Data<T>)).data }
     }
 
+    fn page_size(&self) -> usize {
+        self.shm.page_size()
+    }
+
     fn has_ownership(&self) -> bool {
         self.shm.has_ownership()
     }
@@ -49,6 +49,7 @@ use iceoryx2_bb_elementary_traits::allocator::BaseAllocator;
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_bb_memory::heap_allocator::HeapAllocator;
 use iceoryx2_bb_posix::mutex::*;
+use iceoryx2_bb_posix::system_configuration::SystemInfo;
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_bb_system_types::path::Path;
@@ -305,6 +306,10 @@ impl<T: Send + Sync + Debug + 'static> DynamicStorage<T> for Storage<T> {
         unsafe { &*self.data.data_ptr }
     }
 
+    fn page_size(&self) -> usize {
+        SystemInfo::PageSize.value()
+    }
+
     fn has_ownership(&self) -> bool {
         self.has_ownership.load(Ordering::Relaxed)
     }
@@ -343,6 +348,8 @@ pub struct Builder<'builder, T: Send + Sync + Debug> {
     supplementary_size: usize,
     has_ownership: bool,
     call_drop_on_destruction: bool,
+    huge_pages: HugePagePolicy,
+    access_control_list: Option<AccessControlList>,
     config: Configuration<T>,
     initializer: Initializer<'builder, T>,
     _phantom_data: PhantomData<T>,
@@ -355,6 +362,8 @@ impl<T: Send + Sync + Debug + 'static> NamedConceptBuilder<Storage<T>> for Build
             has_ownership: true,
             call_drop_on_destruction: true,
             supplementary_size: 0,
+            huge_pages: HugePagePolicy::None,
+            access_control_list: None,
             config: Configuration::default(),
             initializer: Initializer::new(|_, _| true),
             _phantom_data: PhantomData,
@@ -402,6 +411,20 @@ impl<T: Send + Sync + Debug + 'static> Builder<'_, T> {
     ) -> Result<Storage<T>, DynamicStorageCreateError> {
         let msg = "Failed to create dynamic storage";
 
+        if self.access_control_list.is_some() {
+            fail!(from self, with DynamicStorageCreateError::AccessControlListUnsupported,
+                "{} since the process local storage cannot be accessed by other processes.", msg);
+        }
+
+        // the process local storage lives on the heap which cannot be backed by huge pages
+        if matches!(
+            self.huge_pages,
+            HugePagePolicy::Require2MB | HugePagePolicy::Require1GB
+        ) {
+            fail!(from self, with DynamicStorageCreateError::HugePagesUnavailable,
+                "{} since the process local storage does not support huge pages.", msg);
+        }
+
         let full_path = self.config.path_for(&self.name);
         let entry = guard.get_mut(&full_path);
         if entry.is_some() {
@@ -485,6 +508,16 @@ impl<'builder, T: Send + Sync + Debug + 'static> DynamicStorageBuilder<'builder,
         self
     }
 
+    fn huge_pages(mut self, value: HugePagePolicy) -> Self {
+        self.huge_pages = value;
+        self
+    }
+
+    fn access_control_list(mut self, value: &AccessControlList) -> Self {
+        self.access_control_list = Some(value.clone());
+        self
+    }
+
     fn open(self) -> Result<Storage<T>, DynamicStorageOpenError> {
         let msg = "Failed to open dynamic storage";
         let mut guard = fail!(from self, when PROCESS_LOCAL_STORAGE.lock(),
@@ -32,7 +32,7 @@ pub mod details {
         },
     };
 
-    const TRIGGER_ID_DEFAULT_MAX: TriggerId = TriggerId::new(u16::MAX as _);
+    const TRIGGER_ID_DEFAULT_MAX: TriggerId = TriggerId::new_unchecked(u16::MAX as _);
 
     #[derive(Debug)]
     #[repr(C)]
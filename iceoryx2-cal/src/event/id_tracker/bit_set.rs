@@ -18,7 +18,7 @@ use crate::event::{NotifierNotifyError, TriggerId};
 
 impl IdTracker for RelocatableBitSet {
     fn trigger_id_max(&self) -> TriggerId {
-        TriggerId::new(self.capacity() - 1)
+        TriggerId::new_unchecked(self.capacity() - 1)
     }
 
     unsafe fn add(&self, id: TriggerId) -> Result<(), NotifierNotifyError> {
@@ -33,10 +33,10 @@ impl IdTracker for RelocatableBitSet {
     }
 
     unsafe fn acquire_all<F: FnMut(TriggerId)>(&self, mut callback: F) {
-        self.reset_all(|bit_index| callback(TriggerId::new(bit_index)))
+        self.reset_all(|bit_index| callback(TriggerId::new_unchecked(bit_index)))
     }
 
     unsafe fn acquire(&self) -> Option<TriggerId> {
-        self.reset_next().map(TriggerId::new)
+        self.reset_next().map(TriggerId::new_unchecked)
     }
 }
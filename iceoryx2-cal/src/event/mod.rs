@@ -90,14 +90,50 @@ impl core::fmt::Display for ListenerCreateError {
 
 impl core::error::Error for ListenerCreateError {}
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TriggerIdCreateError {
+    ExceedsMaxSupportedValue,
+}
+
+impl core::fmt::Display for TriggerIdCreateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}::{:?}", stringify!(Self), self)
+    }
+}
+
+impl core::error::Error for TriggerIdCreateError {}
+
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct TriggerId(usize);
 
 impl TriggerId {
-    pub const fn new(value: usize) -> Self {
+    /// Creates a new [`TriggerId`]. If the value is greater than
+    /// [`TriggerId::max_supported()`] it returns
+    /// [`TriggerIdCreateError::ExceedsMaxSupportedValue`].
+    pub const fn new(value: usize) -> Result<Self, TriggerIdCreateError> {
+        if value > Self::max_supported().0 {
+            return Err(TriggerIdCreateError::ExceedsMaxSupportedValue);
+        }
+
+        Ok(Self(value))
+    }
+
+    /// Creates a new [`TriggerId`] without comparing the value with
+    /// [`TriggerId::max_supported()`]. Intended for hot paths and const contexts where the
+    /// value is known to be in range. A value exceeding the maximum of a [`Notifier`] is still
+    /// rejected by [`Notifier::notify()`].
+    pub const fn new_unchecked(value: usize) -> Self {
         Self(value)
     }
 
+    /// Returns the largest value a [`TriggerId`] can represent. It is a bound of the type
+    /// only, since the id trackers require a capacity of `value + 1`. The largest
+    /// [`TriggerId`] a concrete [`Notifier`] supports is returned by
+    /// [`Notifier::trigger_id_max()`].
+    pub const fn max_supported() -> Self {
+        Self(usize::MAX - 1)
+    }
+
     pub const fn as_value(&self) -> usize {
         self.0
     }
@@ -105,7 +141,7 @@ impl TriggerId {
 
 pub trait Notifier: NamedConcept + Debug + Send {
     fn trigger_id_max(&self) -> TriggerId {
-        TriggerId::new(usize::MAX)
+        TriggerId::max_supported()
     }
     fn notify(&self, id: TriggerId) -> Result<(), NotifierNotifyError>;
 }
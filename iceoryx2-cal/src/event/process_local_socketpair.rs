@@ -322,7 +322,7 @@ impl Listener {
         msg: &str,
     ) -> Result<Option<TriggerId>, ListenerWaitError> {
         let trigger_id_size = core::mem::size_of::<TriggerId>();
-        let mut trigger_id = TriggerId::new(0);
+        let mut trigger_id = TriggerId::default();
         let raw_trigger_id = unsafe {
             core::slice::from_raw_parts_mut(
                 ((&mut trigger_id) as *mut TriggerId) as *mut u8,
@@ -64,19 +64,29 @@ impl NamedConceptMgmt for FileLockMonitoring {
         };
 
         let entries = fail!(from origin,
-                            when directory.contents(),
+                            when directory.iter(),
                             map DirectoryReadError::InsufficientPermissions => NamedConceptListError::InsufficientPermissions,
                             unmatched NamedConceptListError::InternalError,
                             "{} due to a failure while reading the directory contents.", msg);
 
-        Ok(entries
-            .iter()
-            .filter(|entry| {
-                let metadata = entry.metadata();
-                metadata.file_type() == FileType::File
-            })
-            .filter_map(|entry| cfg.extract_name_from_file(entry.name()))
-            .collect())
+        let mut result = vec![];
+        for entry in entries.prefix(cfg.get_prefix()) {
+            let entry = fail!(from origin,
+                              when entry,
+                              map DirectoryReadError::InsufficientPermissions => NamedConceptListError::InsufficientPermissions,
+                              unmatched NamedConceptListError::InternalError,
+                              "{} due to a failure while reading the directory contents.", msg);
+
+            if entry.file_type() != FileType::File {
+                continue;
+            }
+
+            if let Some(entry_name) = cfg.extract_name_from_file(entry.name()) {
+                result.push(entry_name);
+            }
+        }
+
+        Ok(result)
     }
 
     fn does_exist_cfg(
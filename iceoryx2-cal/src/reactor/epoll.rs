@@ -11,14 +11,15 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 pub use iceoryx2_bb_linux::epoll::{
-    Epoll, EpollBuilder, EpollCreateError, EpollEvent, EpollGuard, EventType,
+    Epoll, EpollBuilder, EpollCreateError, EpollEvent, EpollGuard, EventType, InputFlag,
 };
 use iceoryx2_bb_linux::epoll::{EpollAttachmentError, EpollWaitError};
 use iceoryx2_bb_log::{fail, warn};
 use iceoryx2_bb_posix::file_descriptor::FileDescriptor;
 
 use crate::reactor::{
-    Reactor, ReactorAttachError, ReactorBuilder, ReactorCreateError, ReactorGuard, ReactorWaitError,
+    Reactor, ReactorAttachError, ReactorBuilder, ReactorCreateError, ReactorGuard,
+    ReactorWaitError, TriggerMode,
 };
 
 impl<'reactor, 'attachment> ReactorGuard<'reactor, 'attachment>
@@ -47,6 +48,33 @@ fn handle_wait_error(
     }
 }
 
+fn handle_attach_result<'reactor, 'attachment, F: core::fmt::Debug>(
+    this: &Epoll,
+    msg: &str,
+    value: &F,
+    attach_result: Result<EpollGuard<'reactor, 'attachment>, EpollAttachmentError>,
+) -> Result<EpollGuard<'reactor, 'attachment>, ReactorAttachError> {
+    match attach_result {
+        Ok(guard) => Ok(guard),
+        Err(EpollAttachmentError::ExceedsMaxSupportedAttachments) => {
+            fail!(from this, with ReactorAttachError::CapacityExceeded,
+                "{msg} since it would exceed the maximum capacity of {}.", Reactor::capacity(this));
+        }
+        Err(EpollAttachmentError::AlreadyAttached) => {
+            fail!(from this, with ReactorAttachError::AlreadyAttached,
+                "{msg} since the file descriptor {:?} is already attached.", value);
+        }
+        Err(EpollAttachmentError::InsufficientMemory) => {
+            fail!(from this, with ReactorAttachError::InsufficientResources,
+                "{msg} due to insufficient memory.");
+        }
+        Err(e) => {
+            fail!(from this, with ReactorAttachError::InternalError,
+                "{msg} due to an internal error ({e:?}).");
+        }
+    }
+}
+
 fn wait_call<F: FnMut(&FileDescriptor)>(this: &Epoll, event: EpollEvent<'_>, fn_call: &mut F) {
     if let EpollEvent::FileDescriptor(fdev) = event {
         let native_handle = unsafe { fdev.native_fd_handle() };
@@ -82,39 +110,42 @@ impl Reactor for Epoll {
         self.is_empty()
     }
 
-    fn attach<
+    fn attach_with_trigger_mode<
         'reactor,
         'attachment,
         F: iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing + core::fmt::Debug,
     >(
         &'reactor self,
         value: &'attachment F,
+        trigger_mode: TriggerMode,
     ) -> Result<Self::Guard<'reactor, 'attachment>, ReactorAttachError> {
         let msg = "Unable to attach file descriptor to reactor::Epoll";
 
-        match self
+        let mut attachment = self
             .add(value.file_descriptor())
-            .event_type(EventType::ReadyToRead)
-            .attach()
-        {
-            Ok(guard) => Ok(guard),
-            Err(EpollAttachmentError::ExceedsMaxSupportedAttachments) => {
-                fail!(from self, with ReactorAttachError::CapacityExceeded,
-                    "{msg} since it would exceed the maximum capacity of {}.", self.capacity());
-            }
-            Err(EpollAttachmentError::AlreadyAttached) => {
-                fail!(from self, with ReactorAttachError::AlreadyAttached,
-                    "{msg} since the file descriptor {:?} is already attached.", value);
-            }
-            Err(EpollAttachmentError::InsufficientMemory) => {
-                fail!(from self, with ReactorAttachError::InsufficientResources,
-                    "{msg} due to insufficient memory.");
-            }
-            Err(e) => {
-                fail!(from self, with ReactorAttachError::InternalError,
-                    "{msg} due to an internal error ({e:?}).");
-            }
+            .event_type(EventType::ReadyToRead);
+        if trigger_mode == TriggerMode::Edge {
+            attachment = attachment.flags(InputFlag::EdgeTriggeredNotification);
         }
+
+        handle_attach_result(self, msg, value, attachment.attach())
+    }
+
+    fn attach_writable<
+        'reactor,
+        'attachment,
+        F: iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing + core::fmt::Debug,
+    >(
+        &'reactor self,
+        value: &'attachment F,
+    ) -> Result<Self::Guard<'reactor, 'attachment>, ReactorAttachError> {
+        let msg = "Unable to attach file descriptor for write-readiness to reactor::Epoll";
+
+        let attachment = self
+            .add(value.file_descriptor())
+            .event_type(EventType::ReadyToWrite);
+
+        handle_attach_result(self, msg, value, attachment.attach())
     }
 
     fn try_wait<F: FnMut(&FileDescriptor)>(
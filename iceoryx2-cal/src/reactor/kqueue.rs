@@ -0,0 +1,305 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [`Kqueue`] is the [`Reactor`](crate::reactor::Reactor) of macOS and FreeBSD. It is based on
+//! kqueue and supports as many attachments as the process can open file descriptors.
+
+use core::{fmt::Debug, mem::MaybeUninit, time::Duration};
+use std::collections::HashSet;
+use std::sync::{Mutex, MutexGuard};
+
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::{
+    clock::AsTimespec, file_descriptor::FileDescriptor,
+    file_descriptor_set::SynchronousMultiplexing, system_configuration::ProcessResourceLimit,
+};
+use iceoryx2_pal_os_api::bsd;
+use iceoryx2_pal_posix::posix;
+
+use crate::reactor::{
+    ReactorAttachError, ReactorBuilder, ReactorCreateError, ReactorGuard, ReactorWaitError,
+    TriggerMode,
+};
+
+/// Returned by [`Kqueue`] when a [`FileDescriptor`] is attached. As soon as the [`KqueueGuard`]
+/// goes out of scope the attachment is detached.
+pub struct KqueueGuard<'kqueue, 'file_descriptor> {
+    kqueue: &'kqueue Kqueue,
+    fd: &'file_descriptor FileDescriptor,
+    filter: i16,
+}
+
+impl<'kqueue, 'file_descriptor> ReactorGuard<'kqueue, 'file_descriptor>
+    for KqueueGuard<'kqueue, 'file_descriptor>
+{
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.fd
+    }
+}
+
+impl Drop for KqueueGuard<'_, '_> {
+    fn drop(&mut self) {
+        self.kqueue.remove(self.fd, self.filter)
+    }
+}
+
+/// The builder to create a new [`Kqueue`].
+#[derive(Debug)]
+pub struct KqueueBuilder {}
+
+impl ReactorBuilder<Kqueue> for KqueueBuilder {
+    fn new() -> Self {
+        Self {}
+    }
+
+    fn create(self) -> Result<Kqueue, ReactorCreateError> {
+        let msg = "Unable to create kqueue::Reactor";
+
+        // a kqueue is not inherited by child processes, therefore no close-on-exec flag is
+        // required
+        let kqueue_fd = unsafe { bsd::kqueue() };
+        if kqueue_fd == -1 {
+            match posix::Errno::get() {
+                posix::Errno::EMFILE | posix::Errno::ENFILE | posix::Errno::ENOMEM => {
+                    fail!(from self, with ReactorCreateError::InsufficientResources,
+                        "{msg} due to insufficient system resources.");
+                }
+                e => {
+                    fail!(from self, with ReactorCreateError::InternalError,
+                        "{msg} due to an internal error ({e:?}).");
+                }
+            }
+        }
+
+        match FileDescriptor::new(kqueue_fd) {
+            Some(kqueue_fd) => Ok(Kqueue {
+                kqueue_fd,
+                attachments: Mutex::new(HashSet::new()),
+            }),
+            None => {
+                fail!(from self, with ReactorCreateError::InternalError,
+                    "{msg} since the kqueue() syscall returned an invalid file descriptor.");
+            }
+        }
+    }
+}
+
+/// Abstraction of the event multiplexer kqueue.
+#[derive(Debug)]
+pub struct Kqueue {
+    kqueue_fd: FileDescriptor,
+    attachments: Mutex<HashSet<i32>>,
+}
+
+impl Kqueue {
+    /// Returns the number of wait events that can be handled at most with one wait call.
+    pub const fn max_wait_events() -> usize {
+        512
+    }
+
+    fn attachments(&self) -> MutexGuard<'_, HashSet<i32>> {
+        match self.attachments.lock() {
+            Ok(attachments) => attachments,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    fn change(&self, fd_value: i32, filter: i16, flags: u16) -> posix::int {
+        let mut change: bsd::kevent = unsafe { core::mem::zeroed() };
+        change.ident = fd_value as _;
+        change.filter = filter as _;
+        change.flags = flags as _;
+
+        unsafe {
+            bsd::kevent(
+                self.kqueue_fd.native_handle(),
+                &change,
+                1,
+                core::ptr::null_mut(),
+                0,
+                core::ptr::null(),
+            )
+        }
+    }
+
+    fn add<'kqueue, 'attachment, F: SynchronousMultiplexing + Debug>(
+        &'kqueue self,
+        value: &'attachment F,
+        filter: i16,
+        flags: u16,
+        msg: &str,
+    ) -> Result<KqueueGuard<'kqueue, 'attachment>, ReactorAttachError> {
+        let fd = value.file_descriptor();
+        let fd_value = unsafe { fd.native_handle() };
+
+        let mut attachments = self.attachments();
+        // kqueue silently modifies an existing attachment, therefore the attachments are tracked
+        // to reject duplicates like the other reactors do
+        if attachments.contains(&fd_value) {
+            fail!(from self, with ReactorAttachError::AlreadyAttached,
+                "{msg} since the file descriptor {:?} is already attached.", value);
+        }
+
+        if self.change(fd_value, filter, bsd::EV_ADD | flags) == -1 {
+            match posix::Errno::get() {
+                posix::Errno::ENOMEM => {
+                    fail!(from self, with ReactorAttachError::InsufficientResources,
+                        "{msg} due to insufficient memory.");
+                }
+                e => {
+                    fail!(from self, with ReactorAttachError::InternalError,
+                        "{msg} due to an internal error ({e:?}).");
+                }
+            }
+        }
+
+        attachments.insert(fd_value);
+
+        Ok(KqueueGuard {
+            kqueue: self,
+            fd,
+            filter,
+        })
+    }
+
+    fn remove(&self, fd: &FileDescriptor, filter: i16) {
+        let fd_value = unsafe { fd.native_handle() };
+
+        let mut attachments = self.attachments();
+        if self.change(fd_value, filter, bsd::EV_DELETE) == -1 {
+            warn!(from self,
+                "This should never happen! Failed to detach {fd_value} from kqueue due to ({:?}). This might cause unexpected behavior.",
+                posix::Errno::get());
+        }
+        attachments.remove(&fd_value);
+    }
+
+    fn wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        mut fn_call: F,
+        timeout: Option<Duration>,
+    ) -> Result<usize, ReactorWaitError> {
+        let msg = "Unable to wait on kqueue::Reactor";
+
+        let timeout_spec = timeout.map(|timeout| timeout.as_timespec());
+        let timeout_ptr = match timeout_spec.as_ref() {
+            Some(timeout_spec) => timeout_spec as *const posix::timespec,
+            None => core::ptr::null(),
+        };
+
+        let mut events: [MaybeUninit<bsd::kevent>; Self::max_wait_events()] =
+            [MaybeUninit::uninit(); Self::max_wait_events()];
+
+        let number_of_events = unsafe {
+            bsd::kevent(
+                self.kqueue_fd.native_handle(),
+                core::ptr::null(),
+                0,
+                events.as_mut_ptr().cast(),
+                Self::max_wait_events() as _,
+                timeout_ptr,
+            )
+        };
+
+        if number_of_events == -1 {
+            match posix::Errno::get() {
+                posix::Errno::EINTR => {
+                    fail!(from self, with ReactorWaitError::Interrupt,
+                        "{msg} with a timeout of {timeout:?} since an interrupt signal was raised.");
+                }
+                e => {
+                    fail!(from self, with ReactorWaitError::InternalError,
+                        "{msg} with a timeout of {timeout:?} due to an internal error ({e:?}).");
+                }
+            }
+        }
+
+        for event in &events[..number_of_events as usize] {
+            let event = unsafe { event.assume_init_ref() };
+            let fd_value = event.ident as i32;
+
+            if event.flags & bsd::EV_ERROR != 0 {
+                warn!(from self,
+                    "The attachment {fd_value} reported an error ({}). Skipping attachment!", event.data);
+                continue;
+            }
+
+            match FileDescriptor::non_owning_new(fd_value) {
+                Some(fd) => fn_call(&fd),
+                None => {
+                    warn!(from self,
+                        "The file descriptor {fd_value} is no longer valid but still attached to the reactor. Skipping attachment!");
+                }
+            }
+        }
+
+        Ok(number_of_events as usize)
+    }
+}
+
+impl crate::reactor::Reactor for Kqueue {
+    type Guard<'reactor, 'attachment> = KqueueGuard<'reactor, 'attachment>;
+    type Builder = KqueueBuilder;
+
+    fn capacity(&self) -> usize {
+        ProcessResourceLimit::MaxNumberOfOpenFileDescriptors.soft_limit() as usize
+    }
+
+    fn len(&self) -> usize {
+        self.attachments().len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.attachments().is_empty()
+    }
+
+    fn attach_with_trigger_mode<'reactor, 'attachment, F: SynchronousMultiplexing + Debug>(
+        &'reactor self,
+        value: &'attachment F,
+        trigger_mode: TriggerMode,
+    ) -> Result<Self::Guard<'reactor, 'attachment>, ReactorAttachError> {
+        let msg = "Unable to attach file descriptor to reactor::Kqueue";
+        let flags = match trigger_mode {
+            TriggerMode::Level => 0,
+            TriggerMode::Edge => bsd::EV_CLEAR,
+        };
+
+        self.add(value, bsd::EVFILT_READ, flags, msg)
+    }
+
+    fn attach_writable<'reactor, 'attachment, F: SynchronousMultiplexing + Debug>(
+        &'reactor self,
+        value: &'attachment F,
+    ) -> Result<Self::Guard<'reactor, 'attachment>, ReactorAttachError> {
+        let msg = "Unable to attach file descriptor for write-readiness to reactor::Kqueue";
+        self.add(value, bsd::EVFILT_WRITE, 0, msg)
+    }
+
+    fn try_wait<F: FnMut(&FileDescriptor)>(&self, fn_call: F) -> Result<usize, ReactorWaitError> {
+        self.wait(fn_call, Some(Duration::ZERO))
+    }
+
+    fn timed_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fn_call: F,
+        timeout: Duration,
+    ) -> Result<usize, ReactorWaitError> {
+        self.wait(fn_call, Some(timeout))
+    }
+
+    fn blocking_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fn_call: F,
+    ) -> Result<usize, ReactorWaitError> {
+        self.wait(fn_call, None)
+    }
+}
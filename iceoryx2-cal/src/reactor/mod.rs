@@ -12,6 +12,8 @@
 
 #[cfg(target_os = "linux")]
 pub mod epoll;
+#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+pub mod kqueue;
 pub mod posix_select;
 pub mod recommended;
 
@@ -33,6 +35,18 @@ pub enum ReactorAttachError {
     CapacityExceeded,
     InsufficientResources,
     InternalError,
+    UnsupportedTriggerMode,
+}
+
+/// Defines when an attachment of the [`Reactor`] is reported again.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerMode {
+    /// The attachment is reported on every wait call as long as it has unread data.
+    #[default]
+    Level,
+    /// The attachment is reported once when new data arrives and is not reported again
+    /// until further data arrives, even when the previous data was not read.
+    Edge,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -59,6 +73,20 @@ pub trait Reactor: Sized + Debug + Send {
     fn attach<'reactor, 'attachment, F: SynchronousMultiplexing + Debug>(
         &'reactor self,
         value: &'attachment F,
+    ) -> Result<Self::Guard<'reactor, 'attachment>, ReactorAttachError> {
+        self.attach_with_trigger_mode(value, TriggerMode::default())
+    }
+
+    fn attach_with_trigger_mode<'reactor, 'attachment, F: SynchronousMultiplexing + Debug>(
+        &'reactor self,
+        value: &'attachment F,
+        trigger_mode: TriggerMode,
+    ) -> Result<Self::Guard<'reactor, 'attachment>, ReactorAttachError>;
+
+    /// Attaches the value for write-readiness. It is reported as soon as it can accept data.
+    fn attach_writable<'reactor, 'attachment, F: SynchronousMultiplexing + Debug>(
+        &'reactor self,
+        value: &'attachment F,
     ) -> Result<Self::Guard<'reactor, 'attachment>, ReactorAttachError>;
 
     fn try_wait<F: FnMut(&FileDescriptor)>(&self, fn_call: F) -> Result<usize, ReactorWaitError>;
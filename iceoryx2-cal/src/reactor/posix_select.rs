@@ -22,7 +22,7 @@ use iceoryx2_bb_posix::{
     },
 };
 
-use crate::reactor::{ReactorAttachError, ReactorWaitError};
+use crate::reactor::{ReactorAttachError, ReactorWaitError, TriggerMode};
 
 impl crate::reactor::ReactorGuard<'_, '_> for FileDescriptorSetGuard<'_, '_> {
     fn file_descriptor(&self) -> &FileDescriptor {
@@ -42,6 +42,24 @@ impl Reactor {
         }
     }
 
+    fn handle_attach_result<'reactor, 'attachment>(
+        &self,
+        result: Result<FileDescriptorSetGuard<'reactor, 'attachment>, FileDescriptorSetAddError>,
+        msg: &str,
+    ) -> Result<FileDescriptorSetGuard<'reactor, 'attachment>, ReactorAttachError> {
+        match result {
+            Ok(guard) => Ok(guard),
+            Err(FileDescriptorSetAddError::CapacityExceeded) => {
+                fail!(from self, with ReactorAttachError::CapacityExceeded,
+                        "{msg} since the capacity of the underlying file descriptor set was exceeded.");
+            }
+            Err(FileDescriptorSetAddError::AlreadyAttached) => {
+                fail!(from self, with ReactorAttachError::AlreadyAttached,
+                        "{msg} since it is already attached.");
+            }
+        }
+    }
+
     fn wait<
         F: FnMut(&FileDescriptor),
         W: FnMut(F, FileEvent) -> Result<usize, FileDescriptorSetWaitError>,
@@ -105,26 +123,34 @@ impl crate::reactor::Reactor for Reactor {
         self.set.is_empty()
     }
 
-    fn attach<
+    fn attach_with_trigger_mode<
         'reactor,
         'attachment,
         F: iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing + Debug,
     >(
         &'reactor self,
         value: &'attachment F,
+        trigger_mode: TriggerMode,
     ) -> Result<Self::Guard<'reactor, 'attachment>, super::ReactorAttachError> {
         let msg = format!("Unable to attach {value:?} to the reactor");
-        match self.set.add(value) {
-            Ok(guard) => Ok(guard),
-            Err(FileDescriptorSetAddError::CapacityExceeded) => {
-                fail!(from self, with ReactorAttachError::CapacityExceeded,
-                        "{msg} since the capacity of the underlying file descriptor set was exceeded.");
-            }
-            Err(FileDescriptorSetAddError::AlreadyAttached) => {
-                fail!(from self, with ReactorAttachError::AlreadyAttached,
-                        "{msg} since it is already attached.");
-            }
+        if trigger_mode == TriggerMode::Edge {
+            fail!(from self, with ReactorAttachError::UnsupportedTriggerMode,
+                "{msg} since select() supports only level-triggered notifications.");
         }
+
+        self.handle_attach_result(self.set.add(value), &msg)
+    }
+
+    fn attach_writable<
+        'reactor,
+        'attachment,
+        F: iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing + Debug,
+    >(
+        &'reactor self,
+        value: &'attachment F,
+    ) -> Result<Self::Guard<'reactor, 'attachment>, super::ReactorAttachError> {
+        let msg = format!("Unable to attach {value:?} for write-readiness to the reactor");
+        self.handle_attach_result(self.set.add_writable(value), &msg)
     }
 
     fn try_wait<F: FnMut(&FileDescriptor)>(
@@ -10,13 +10,22 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+//! On Linux the recommended [`Reactor`](crate::reactor::Reactor) is based on epoll and on
+//! macOS and FreeBSD it is based on kqueue. Both support as many attachments as the process
+//! can open file descriptors. All other platforms fall back to select, which limits the number of attachments as well as the
+//! file descriptor values to
+//! [`FileDescriptorSet::capacity()`](iceoryx2_bb_posix::file_descriptor_set::FileDescriptorSet::capacity()).
+
 /// Provides the recommended inter-process
 /// [`Reactor`](crate::reactor::Reactor) concept
 /// implementation for the target.
 #[cfg(target_os = "linux")]
 pub type Ipc = crate::reactor::epoll::Epoll;
 
-#[cfg(not(target_os = "linux"))]
+#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+pub type Ipc = crate::reactor::kqueue::Kqueue;
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "freebsd")))]
 pub type Ipc = crate::reactor::posix_select::Reactor;
 
 /// Provides the recommended process-local
@@ -25,5 +34,8 @@ pub type Ipc = crate::reactor::posix_select::Reactor;
 #[cfg(target_os = "linux")]
 pub type Local = crate::reactor::epoll::Epoll;
 
-#[cfg(not(target_os = "linux"))]
+#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+pub type Local = crate::reactor::kqueue::Kqueue;
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "freebsd")))]
 pub type Local = crate::reactor::posix_select::Reactor;
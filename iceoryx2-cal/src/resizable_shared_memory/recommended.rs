@@ -25,3 +25,12 @@ pub type Local<Allocator> = crate::resizable_shared_memory::dynamic::DynamicMemo
     Allocator,
     crate::shared_memory::recommended::Local<Allocator>,
 >;
+
+/// Provides the recommended inter-process
+/// [`ResizableSharedMemory`](crate::resizable_shared_memory::ResizableSharedMemory)
+/// concept implementation based on
+/// [`IpcAnonymous`](crate::shared_memory::recommended::IpcAnonymous).
+pub type IpcAnonymous<Allocator> = crate::resizable_shared_memory::dynamic::DynamicMemory<
+    Allocator,
+    crate::shared_memory::recommended::IpcAnonymous<Allocator>,
+>;
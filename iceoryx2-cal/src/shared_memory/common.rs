@@ -140,6 +140,8 @@ pub mod details {
         config: Configuration<Allocator, Storage>,
         timeout: Duration,
         has_ownership: bool,
+        huge_pages: HugePagePolicy,
+        access_control_list: Option<AccessControlList>,
     }
 
     impl<Allocator: ShmAllocator + Debug, Storage: DynamicStorage<AllocatorDetails<Allocator>>>
@@ -152,6 +154,8 @@ pub mod details {
                 size: 0,
                 timeout: Duration::ZERO,
                 has_ownership: true,
+                huge_pages: HugePagePolicy::None,
+                access_control_list: None,
             }
         }
 
@@ -211,6 +215,16 @@ pub mod details {
             self
         }
 
+        fn huge_pages(mut self, value: HugePagePolicy) -> Self {
+            self.huge_pages = value;
+            self
+        }
+
+        fn access_control_list(mut self, value: &AccessControlList) -> Self {
+            self.access_control_list = Some(value.clone());
+            self
+        }
+
         fn timeout(mut self, value: Duration) -> Self {
             self.timeout = value;
             self
@@ -229,20 +243,25 @@ pub mod details {
 
             let allocator_mgmt_size = Allocator::management_size(self.size, allocator_config);
 
-            let storage = match Storage::Builder::new(&self.name)
+            let mut builder = Storage::Builder::new(&self.name)
                 .config(&self.config.dynamic_storage_config)
                 .supplementary_size(self.size + allocator_mgmt_size)
                 .has_ownership(self.has_ownership)
+                .huge_pages(self.huge_pages)
                 .initializer(|details, init_allocator| -> bool {
                     self.initialize(allocator_config, details, init_allocator)
-                })
-                .create(AllocatorDetails {
-                    allocator_id: Allocator::unique_id(),
-                    allocator: MaybeUninit::uninit(),
-                    mgmt_size: allocator_mgmt_size,
-                    payload_size: self.size,
-                    payload_start_offset: 0,
-                }) {
+                });
+            if let Some(acl) = &self.access_control_list {
+                builder = builder.access_control_list(acl);
+            }
+
+            let storage = match builder.create(AllocatorDetails {
+                allocator_id: Allocator::unique_id(),
+                allocator: MaybeUninit::uninit(),
+                mgmt_size: allocator_mgmt_size,
+                payload_size: self.size,
+                payload_start_offset: 0,
+            }) {
                 Ok(s) => s,
                 Err(DynamicStorageCreateError::AlreadyExists) => {
                     fail!(from self, with SharedMemoryCreateError::AlreadyExists,
@@ -252,6 +271,14 @@ pub mod details {
                     fail!(from self, with SharedMemoryCreateError::InsufficientPermissions,
                         "{} due to insufficient permissions.", msg);
                 }
+                Err(DynamicStorageCreateError::HugePagesUnavailable) => {
+                    fail!(from self, with SharedMemoryCreateError::HugePagesUnavailable,
+                        "{} since the required huge pages are not available.", msg);
+                }
+                Err(DynamicStorageCreateError::AccessControlListUnsupported) => {
+                    fail!(from self, with SharedMemoryCreateError::AccessControlListUnsupported,
+                        "{} since access control lists are not supported.", msg);
+                }
                 Err(DynamicStorageCreateError::InitializationFailed) => {
                     fail!(from self, with SharedMemoryCreateError::InternalError,
                         "{} since the initialization failed.", msg);
@@ -418,6 +445,10 @@ pub mod details {
             self.storage.get().payload_size
         }
 
+        fn page_size(&self) -> usize {
+            self.storage.page_size()
+        }
+
         fn max_alignment(&self) -> usize {
             unsafe { self.storage.get().allocator.assume_init_ref() }.max_alignment()
         }
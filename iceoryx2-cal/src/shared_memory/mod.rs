@@ -55,6 +55,7 @@
 //! ```
 
 pub mod common;
+pub mod memfd;
 pub mod posix;
 pub mod process_local;
 pub mod recommended;
@@ -63,6 +64,8 @@ use core::{fmt::Debug, time::Duration};
 
 pub use crate::shm_allocator::*;
 use crate::static_storage::file::{NamedConcept, NamedConceptBuilder, NamedConceptMgmt};
+pub use iceoryx2_bb_posix::access_control_list::AccessControlList;
+pub use iceoryx2_bb_posix::shared_memory::HugePagePolicy;
 use iceoryx2_bb_system_types::file_name::*;
 use pool_allocator::PoolAllocator;
 
@@ -72,6 +75,8 @@ pub enum SharedMemoryCreateError {
     AlreadyExists,
     SizeIsZero,
     InsufficientPermissions,
+    HugePagesUnavailable,
+    AccessControlListUnsupported,
     InternalError,
 }
 
@@ -118,6 +123,17 @@ pub trait SharedMemoryBuilder<Allocator: ShmAllocator, Shm: SharedMemory<Allocat
     /// space.
     fn size(self, value: usize) -> Self;
 
+    /// Defines if the [`SharedMemory`] shall be backed by huge pages. Only relevant when the
+    /// [`SharedMemory`] is created. If the policy requires huge pages that are not available
+    /// the creation fails with [`SharedMemoryCreateError::HugePagesUnavailable`].
+    fn huge_pages(self, value: HugePagePolicy) -> Self;
+
+    /// Grants the users and groups of the [`AccessControlList`] access to the [`SharedMemory`].
+    /// Only relevant when the [`SharedMemory`] is created. If access control lists are not
+    /// supported the creation fails with
+    /// [`SharedMemoryCreateError::AccessControlListUnsupported`].
+    fn access_control_list(self, value: &AccessControlList) -> Self;
+
     /// The timeout defines how long the [`SharedMemoryBuilder`] should wait for
     /// [`SharedMemoryBuilder::create()`] to finialize
     /// the initialization. This is required when the [`SharedMemory`] is created and initialized
@@ -146,6 +162,9 @@ pub trait SharedMemory<Allocator: ShmAllocator>:
     /// Returns the size of the shared memory.
     fn size(&self) -> usize;
 
+    /// Returns the size of the pages backing the shared memory.
+    fn page_size(&self) -> usize;
+
     /// Returns the max supported alignment.
     fn max_alignment(&self) -> usize;
 
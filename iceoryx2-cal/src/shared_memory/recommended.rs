@@ -19,3 +19,17 @@ pub type Ipc<Allocator> = crate::shared_memory::posix::Memory<Allocator>;
 /// [`SharedMemory`](crate::shared_memory::SharedMemory) concept
 /// implementation for the target.
 pub type Local<Allocator> = crate::shared_memory::process_local::Memory<Allocator>;
+
+/// Provides the recommended inter-process
+/// [`SharedMemory`](crate::shared_memory::SharedMemory) concept
+/// implementation that is not visible in the file system and never outlives its creating
+/// process. Falls back to [`Ipc`] on targets without anonymous shared memory.
+#[cfg(target_os = "linux")]
+pub type IpcAnonymous<Allocator> = crate::shared_memory::memfd::Memory<Allocator>;
+
+/// Provides the recommended inter-process
+/// [`SharedMemory`](crate::shared_memory::SharedMemory) concept
+/// implementation that is not visible in the file system and never outlives its creating
+/// process. Falls back to [`Ipc`] on targets without anonymous shared memory.
+#[cfg(not(target_os = "linux"))]
+pub type IpcAnonymous<Allocator> = Ipc<Allocator>;
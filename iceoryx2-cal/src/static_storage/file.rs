@@ -50,6 +50,7 @@ pub use crate::named_concept::*;
 pub use crate::static_storage::*;
 
 use iceoryx2_bb_log::{fail, trace, warn};
+use iceoryx2_bb_posix::access_control_list::AccessControlListApplyError;
 use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_bb_posix::{
     directory::*, file::*, file_descriptor::FileDescriptorManagement, file_type::FileType,
@@ -58,6 +59,11 @@ use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
 
 const FINAL_PERMISSIONS: Permission = Permission::OWNER_READ;
 
+// the group bits contain the mask of an access control list when one was applied
+fn has_final_permissions(permission: Permission) -> bool {
+    permission.bits() & !Permission::GROUP_ALL.bits() == FINAL_PERMISSIONS.bits()
+}
+
 /// The custom configuration of the [`Storage`].
 #[derive(Clone, Debug)]
 pub struct Configuration {
@@ -110,6 +116,7 @@ impl crate::static_storage::StaticStorageConfiguration for Configuration {}
 #[derive(Debug)]
 pub struct Locked {
     static_storage: Storage,
+    access_control_list: Option<AccessControlList>,
 }
 
 impl NamedConcept for Locked {
@@ -133,10 +140,27 @@ impl StaticStorageLocked<Storage> for Locked {
                 msg, contents.len(), bytes_written);
         }
 
-        fail!(from self, when self.static_storage.file.set_permission(FINAL_PERMISSIONS),
-                map FileSetPermissionError::InsufficientPermissions => StaticStorageUnlockError::InsufficientPermissions,
-                unmatched StaticStorageUnlockError::InternalError,
-                "{} due to a failure while updating the permissions to {}.", msg, FINAL_PERMISSIONS);
+        match &self.access_control_list {
+            None => {
+                fail!(from self, when self.static_storage.file.set_permission(FINAL_PERMISSIONS),
+                    map FileSetPermissionError::InsufficientPermissions => StaticStorageUnlockError::InsufficientPermissions,
+                    unmatched StaticStorageUnlockError::InternalError,
+                    "{} due to a failure while updating the permissions to {}.", msg, FINAL_PERMISSIONS);
+            }
+            // the access control list replaces the permission bits, therefore the final
+            // permissions and the additional entries are applied at once
+            Some(acl) => {
+                let mut acl = acl.clone();
+                fail!(from self, when acl.merge_permission(FINAL_PERMISSIONS),
+                    with StaticStorageUnlockError::InternalError,
+                    "{} since the final permissions {} could not be merged into the access control list.", msg, FINAL_PERMISSIONS);
+                fail!(from self, when self.static_storage.file.set_access_control_list(&acl),
+                    map AccessControlListApplyError::Unsupported => StaticStorageUnlockError::AccessControlListUnsupported;
+                        AccessControlListApplyError::InsufficientPermissions => StaticStorageUnlockError::InsufficientPermissions,
+                    unmatched StaticStorageUnlockError::InternalError,
+                    "{} due to a failure while applying the access control list.", msg);
+            }
+        }
 
         self.static_storage.len = contents.len() as u64;
 
@@ -234,19 +258,44 @@ impl crate::named_concept::NamedConceptMgmt for Storage {
         };
 
         let entries = fail!(from origin,
-                            when directory.contents(),
+                            when directory.iter(),
                             map DirectoryReadError::InsufficientPermissions => NamedConceptListError::InsufficientPermissions,
                             unmatched NamedConceptListError::InternalError,
                             "{} due to a failure while reading the storage directory (\"{}\") contents.", msg, config.path);
 
-        Ok(entries
-            .iter()
-            .filter(|entry| {
-                let metadata = entry.metadata();
-                metadata.file_type() == FileType::File && metadata.permission() == FINAL_PERMISSIONS
-            })
-            .filter_map(|entry| config.extract_name_from_file(entry.name()))
-            .collect())
+        let mut result = vec![];
+        for entry in entries.prefix(config.get_prefix()) {
+            let entry = fail!(from origin,
+                              when entry,
+                              map DirectoryReadError::InsufficientPermissions => NamedConceptListError::InsufficientPermissions,
+                              unmatched NamedConceptListError::InternalError,
+                              "{} due to a failure while reading the storage directory (\"{}\") contents.", msg, config.path);
+
+            if entry.file_type() != FileType::File {
+                continue;
+            }
+
+            let entry_name = match config.extract_name_from_file(entry.name()) {
+                Some(entry_name) => entry_name,
+                None => continue,
+            };
+
+            match entry.metadata() {
+                Ok(metadata) => {
+                    if has_final_permissions(metadata.permission()) {
+                        result.push(entry_name);
+                    }
+                }
+                Err(DirectoryStatError::DoesNotExist)
+                | Err(DirectoryStatError::InsufficientPermissions) => (),
+                Err(v) => {
+                    fail!(from origin, with NamedConceptListError::InternalError,
+                        "{} due to a failure ({:?}) while acquiring the metadata of \"{}\".", msg, v, entry.name());
+                }
+            }
+        }
+
+        Ok(result)
     }
 
     fn does_exist_cfg(
@@ -292,7 +341,7 @@ impl crate::named_concept::NamedConceptMgmt for Storage {
         }
         let metadata = metadata.unwrap();
 
-        if metadata.file_type() == FileType::File && metadata.permission() == FINAL_PERMISSIONS {
+        if metadata.file_type() == FileType::File && has_final_permissions(metadata.permission()) {
             return Ok(true);
         }
 
@@ -356,6 +405,7 @@ impl crate::static_storage::StaticStorage for Storage {
 pub struct Builder {
     storage_name: FileName,
     has_ownership: bool,
+    access_control_list: Option<AccessControlList>,
     config: Configuration,
 }
 
@@ -364,6 +414,7 @@ impl crate::named_concept::NamedConceptBuilder<Storage> for Builder {
         Self {
             storage_name: storage_name.clone(),
             has_ownership: true,
+            access_control_list: None,
             config: <Configuration as Default>::default(),
         }
     }
@@ -380,6 +431,11 @@ impl crate::static_storage::StaticStorageBuilder<Storage> for Builder {
         self
     }
 
+    fn access_control_list(mut self, value: &AccessControlList) -> Self {
+        self.access_control_list = Some(value.clone());
+        self
+    }
+
     fn create_locked(self) -> Result<Locked, StaticStorageCreateError> {
         let directory_permission = Permission::OWNER_ALL | Permission::GROUP_ALL;
 
@@ -416,6 +472,7 @@ impl crate::static_storage::StaticStorageBuilder<Storage> for Builder {
                 file,
                 len: 0,
             },
+            access_control_list: self.access_control_list,
         })
     }
 
@@ -440,7 +497,7 @@ impl crate::static_storage::StaticStorageBuilder<Storage> for Builder {
             when file.metadata(), with StaticStorageOpenError::Read,
             "{} due to a failure while reading the files metadata.", msg);
 
-            if metadata.permission() != FINAL_PERMISSIONS {
+            if !has_final_permissions(metadata.permission()) {
                 if elapsed_time > timeout {
                     fail!(from origin,
                         with StaticStorageOpenError::InitializationNotYetFinalized,
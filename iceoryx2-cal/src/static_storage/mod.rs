@@ -20,6 +20,7 @@ pub mod recommended;
 use core::{fmt::Debug, time::Duration};
 
 use iceoryx2_bb_log::fail;
+pub use iceoryx2_bb_posix::access_control_list::AccessControlList;
 use iceoryx2_bb_system_types::file_name::*;
 
 use crate::named_concept::{
@@ -32,6 +33,7 @@ pub enum StaticStorageCreateError {
     Creation,
     Write,
     InsufficientPermissions,
+    AccessControlListUnsupported,
     InternalError,
 }
 
@@ -55,6 +57,7 @@ pub enum StaticStorageReadError {
 pub enum StaticStorageUnlockError {
     InsufficientPermissions,
     NoSpaceLeft,
+    AccessControlListUnsupported,
     InternalError,
 }
 
@@ -69,16 +72,28 @@ pub trait StaticStorageBuilder<T: StaticStorage>: Sized + NamedConceptBuilder<T>
     /// Defines if a newly created [`StaticStorage`] owns the underlying resources
     fn has_ownership(self, value: bool) -> Self;
 
+    /// Grants the users and groups of the [`AccessControlList`] read access to the
+    /// [`StaticStorage`] when it is unlocked. If access control lists are not supported
+    /// [`StaticStorageLocked::unlock()`] fails with
+    /// [`StaticStorageUnlockError::AccessControlListUnsupported`].
+    fn access_control_list(self, value: &AccessControlList) -> Self;
+
     /// Creates an owning [`StaticStorage`]. When its lifetime ends the underlying resources will
     /// be removed.
     fn create(self, contents: &[u8]) -> Result<T, StaticStorageCreateError> {
         let locked_storage = self.create_locked()?;
 
-        Ok(
-            fail!(from "StaticStorageBuilder::create", when locked_storage.unlock(contents),
-            with StaticStorageCreateError::Write,
-            "Unable to unlock static storage with content"),
-        )
+        match locked_storage.unlock(contents) {
+            Ok(storage) => Ok(storage),
+            Err(StaticStorageUnlockError::AccessControlListUnsupported) => {
+                fail!(from "StaticStorageBuilder::create", with StaticStorageCreateError::AccessControlListUnsupported,
+                    "Unable to unlock static storage with content since access control lists are not supported.");
+            }
+            Err(e) => {
+                fail!(from "StaticStorageBuilder::create", with StaticStorageCreateError::Write,
+                    "Unable to unlock static storage with content ({:?}).", e);
+            }
+        }
     }
 
     /// Creates an owning [`StaticStorageLocked`]. When its lifetime ends the underlying resource
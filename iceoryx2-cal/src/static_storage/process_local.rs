@@ -285,6 +285,7 @@ impl StaticStorage for Storage {
 pub struct Builder {
     name: FileName,
     has_ownership: bool,
+    access_control_list: Option<AccessControlList>,
     config: Configuration,
 }
 
@@ -292,6 +293,7 @@ impl NamedConceptBuilder<Storage> for Builder {
     fn new(storage_name: &FileName) -> Self {
         Self {
             has_ownership: true,
+            access_control_list: None,
             name: storage_name.clone(),
             config: Configuration::default(),
         }
@@ -309,6 +311,11 @@ impl StaticStorageBuilder<Storage> for Builder {
         self
     }
 
+    fn access_control_list(mut self, value: &AccessControlList) -> Self {
+        self.access_control_list = Some(value.clone());
+        self
+    }
+
     fn open(self, timeout: Duration) -> Result<Storage, StaticStorageOpenError> {
         let msg = "Failed to open static storage";
         let mut wait_for_read_access = fail!(from self,
@@ -354,6 +361,11 @@ impl StaticStorageBuilder<Storage> for Builder {
     fn create_locked(self) -> Result<<Storage as StaticStorage>::Locked, StaticStorageCreateError> {
         let msg = "Failed to create storage";
 
+        if self.access_control_list.is_some() {
+            fail!(from self, with StaticStorageCreateError::AccessControlListUnsupported,
+                "{} since the process local storage cannot be accessed by other processes.", msg);
+        }
+
         let mut guard = fail!(from self, when PROCESS_LOCAL_STORAGE.lock(),
                 with StaticStorageCreateError::InternalError,
                 "{} due to a failure while acquiring the lock.", msg);
@@ -0,0 +1,267 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(target_os = "linux")]
+mod dynamic_storage_memfd {
+    use core::sync::atomic::Ordering;
+    use iceoryx2_bb_container::semantic_string::*;
+    use iceoryx2_bb_posix::config::temp_directory;
+    use iceoryx2_bb_posix::shared_memory::SharedMemory;
+    use iceoryx2_bb_posix::user::User;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_system_types::path::Path;
+    use iceoryx2_bb_testing::{assert_that, test_requires};
+    use iceoryx2_cal::dynamic_storage::memfd::*;
+    use iceoryx2_cal::named_concept::*;
+    use iceoryx2_cal::testing::*;
+    use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+    use std::os::unix::process::CommandExt;
+
+    type Sut = Storage<IoxAtomicU64>;
+
+    const CHILD_PROCESS_REQUEST: &str = "DYNAMIC_STORAGE_MEMFD_TESTS_REQUEST";
+    const CHILD_PROCESS_SUCCESS: i32 = 0;
+    const INITIAL_VALUE: u64 = 8127;
+    const CHILD_VALUE: u64 = 2718;
+
+    fn config_for(prefix: &FileName, path_hint: &Path) -> Configuration<IoxAtomicU64> {
+        Configuration::default().prefix(prefix).path_hint(path_hint)
+    }
+
+    fn is_listed_as_shared_memory(storage_name: &FileName) -> bool {
+        let storage_name = storage_name.to_string();
+        SharedMemory::list()
+            .iter()
+            .any(|shm| shm.to_string().contains(&storage_name))
+    }
+
+    fn run_in_other_process(
+        request: &str,
+        config: &Configuration<IoxAtomicU64>,
+        name: &FileName,
+        uid: Option<u32>,
+    ) -> bool {
+        let mut command = std::process::Command::new(std::env::current_exe().unwrap());
+        if let Some(uid) = uid {
+            command.uid(uid).gid(uid);
+        }
+
+        let mut child = command
+            .args([
+                "dynamic_storage_memfd::dynamic_storage_memfd_child_process",
+                "--exact",
+                "--nocapture",
+            ])
+            .env(
+                CHILD_PROCESS_REQUEST,
+                format!(
+                    "{request};{};{name};{}",
+                    config.get_prefix(),
+                    config.get_path_hint()
+                ),
+            )
+            .spawn()
+            .unwrap();
+
+        child.wait().unwrap().code() == Some(CHILD_PROCESS_SUCCESS)
+    }
+
+    fn child_process_open_and_write(config: &Configuration<IoxAtomicU64>, name: &FileName) -> i32 {
+        let sut = match Builder::new(name).config(config).open() {
+            Ok(sut) => sut,
+            Err(_) => return 1,
+        };
+
+        if sut.get().load(Ordering::Relaxed) != INITIAL_VALUE {
+            return 2;
+        }
+
+        sut.get().store(CHILD_VALUE, Ordering::Relaxed);
+        CHILD_PROCESS_SUCCESS
+    }
+
+    fn child_process_create_and_crash(
+        config: &Configuration<IoxAtomicU64>,
+        name: &FileName,
+    ) -> i32 {
+        match Builder::new(name)
+            .config(config)
+            .create(IoxAtomicU64::new(INITIAL_VALUE))
+        {
+            // the process terminates without running any destructor
+            Ok(_sut) => std::process::exit(CHILD_PROCESS_SUCCESS),
+            Err(_) => 1,
+        }
+    }
+
+    fn number_of_memory_server_threads() -> usize {
+        std::fs::read_dir("/proc/self/task")
+            .unwrap()
+            .filter_map(|task| std::fs::read_to_string(task.ok()?.path().join("comm")).ok())
+            .filter(|name| name.trim() == "iox2-memfd")
+            .count()
+    }
+
+    fn child_process_create_multiple_storages(
+        config: &Configuration<IoxAtomicU64>,
+        name: &FileName,
+    ) -> i32 {
+        const NUMBER_OF_STORAGES: usize = 8;
+        let mut storages = vec![];
+        for n in 0..NUMBER_OF_STORAGES {
+            let mut storage_name = name.clone();
+            storage_name.push_bytes(format!("_{n}").as_bytes()).unwrap();
+            match Builder::new(&storage_name)
+                .config(config)
+                .create(IoxAtomicU64::new(INITIAL_VALUE))
+            {
+                Ok(storage) => storages.push(storage),
+                Err(_) => return 1,
+            }
+        }
+
+        // the thread sets its name when it starts, it is named when it served a request
+        for storage in &storages {
+            if Builder::<IoxAtomicU64>::new(storage.name())
+                .config(config)
+                .open()
+                .is_err()
+            {
+                return 2;
+            }
+        }
+
+        if number_of_memory_server_threads() != 1 {
+            return 3;
+        }
+
+        storages.clear();
+        let start = std::time::Instant::now();
+        while number_of_memory_server_threads() != 0 {
+            if start.elapsed() > core::time::Duration::from_secs(10) {
+                return 4;
+            }
+            std::thread::yield_now();
+        }
+
+        CHILD_PROCESS_SUCCESS
+    }
+
+    // Executed by the processes spawned in run_in_other_process(), it does nothing when
+    // it is started as regular test.
+    #[test]
+    fn dynamic_storage_memfd_child_process() {
+        let request = match std::env::var(CHILD_PROCESS_REQUEST) {
+            Ok(request) => request,
+            Err(_) => return,
+        };
+
+        let request: Vec<&str> = request.split(';').collect();
+        let config = config_for(
+            &FileName::new(request[1].as_bytes()).unwrap(),
+            &Path::new(request[3].as_bytes()).unwrap(),
+        );
+        let name = FileName::new(request[2].as_bytes()).unwrap();
+        let result = match request[0] {
+            "open_and_write" => child_process_open_and_write(&config, &name),
+            "create_multiple_storages" => child_process_create_multiple_storages(&config, &name),
+            _ => child_process_create_and_crash(&config, &name),
+        };
+
+        std::process::exit(result);
+    }
+
+    #[test]
+    fn dynamic_storage_memfd_can_be_opened_by_other_process() {
+        let storage_name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Builder::new(&storage_name)
+            .config(&config)
+            .create(IoxAtomicU64::new(INITIAL_VALUE))
+            .unwrap();
+        assert_that!(is_listed_as_shared_memory(&storage_name), eq false);
+
+        assert_that!(run_in_other_process("open_and_write", &config, &storage_name, None), eq true);
+        assert_that!(sut.get().load(Ordering::Relaxed), eq CHILD_VALUE);
+    }
+
+    #[test]
+    fn dynamic_storage_memfd_cannot_be_opened_by_process_of_other_user() {
+        const OTHER_USER: u32 = 65534;
+        test_requires!(User::from_self().unwrap().uid().value() == 0);
+
+        let storage_name = generate_name();
+        let mut directory = temp_directory();
+        directory.add_path_entry(&generate_name().into()).unwrap();
+        std::fs::DirBuilder::new()
+            .mode(0o755)
+            .create(directory.to_string())
+            .unwrap();
+        let config = config_for(&generate_name(), &directory);
+
+        let sut = Builder::new(&storage_name)
+            .config(&config)
+            .create(IoxAtomicU64::new(INITIAL_VALUE))
+            .unwrap();
+
+        // the marker file is readable, only the server of the memory can deny the access
+        for entry in std::fs::read_dir(directory.to_string()).unwrap() {
+            std::fs::set_permissions(
+                entry.unwrap().path(),
+                std::fs::Permissions::from_mode(0o644),
+            )
+            .unwrap();
+        }
+
+        let was_opened =
+            run_in_other_process("open_and_write", &config, &storage_name, Some(OTHER_USER));
+
+        drop(sut);
+        std::fs::remove_dir_all(directory.to_string()).unwrap();
+
+        assert_that!(was_opened, eq false);
+    }
+
+    #[test]
+    fn dynamic_storage_memfd_of_crashed_process_leaves_no_shared_memory_behind() {
+        let storage_name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        assert_that!(run_in_other_process("create_and_crash", &config, &storage_name, None), eq true);
+
+        assert_that!(is_listed_as_shared_memory(&storage_name), eq false);
+        assert_that!(Sut::does_exist_cfg(&storage_name, &config), eq Ok(true));
+        let sut = Builder::<IoxAtomicU64>::new(&storage_name)
+            .config(&config)
+            .open();
+        assert_that!(sut.err(), eq Some(DynamicStorageOpenError::InitializationNotYetFinalized));
+
+        assert_that!(unsafe { Sut::remove_cfg(&storage_name, &config) }, eq Ok(true));
+        assert_that!(Sut::does_exist_cfg(&storage_name, &config), eq Ok(false));
+
+        let sut = Builder::new(&storage_name)
+            .config(&config)
+            .create(IoxAtomicU64::new(INITIAL_VALUE));
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn dynamic_storage_memfd_storages_of_a_process_are_served_by_one_thread() {
+        let storage_name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        assert_that!(run_in_other_process("create_multiple_storages", &config, &storage_name, None), eq true);
+        assert_that!(Sut::list_cfg(&config).unwrap(), len 0);
+    }
+}
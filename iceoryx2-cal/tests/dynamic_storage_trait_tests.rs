@@ -902,4 +902,9 @@ mod dynamic_storage {
     #[instantiate_tests(<iceoryx2_cal::dynamic_storage::process_local::Storage<TestData>,
                          iceoryx2_cal::dynamic_storage::process_local::Storage<u64>>)]
     mod process_local {}
+
+    #[cfg(target_os = "linux")]
+    #[instantiate_tests(<iceoryx2_cal::dynamic_storage::memfd::Storage<TestData>,
+                         iceoryx2_cal::dynamic_storage::memfd::Storage<u64>>)]
+    mod memfd {}
 }
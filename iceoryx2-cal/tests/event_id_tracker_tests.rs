@@ -54,7 +54,7 @@ mod event_id_tracker {
 
         assert_that!(unsafe { sut.acquire() }, eq None);
         for i in 0..CAPACITY {
-            let id = TriggerId::new(i);
+            let id = TriggerId::new(i).unwrap();
             assert_that!(unsafe { sut.add(id) }, is_ok);
             assert_that!(unsafe { sut.acquire() }, eq Some(id));
             assert_that!(unsafe { sut.acquire() }, is_none);
@@ -70,7 +70,7 @@ mod event_id_tracker {
         assert_that!(unsafe { sut.init(&allocator(&mut *memory)) }, is_ok);
 
         for i in 0..CAPACITY {
-            let id = TriggerId::new((i).min(sut.trigger_id_max().as_value()));
+            let id = TriggerId::new((i).min(sut.trigger_id_max().as_value())).unwrap();
             assert_that!(unsafe { sut.add(id) }, is_ok);
         }
 
@@ -93,7 +93,7 @@ mod event_id_tracker {
         assert_that!(unsafe { sut.init(&allocator(&mut *memory)) }, is_ok);
 
         for i in 0..CAPACITY {
-            let id = TriggerId::new((i).min(sut.trigger_id_max().as_value()));
+            let id = TriggerId::new((i).min(sut.trigger_id_max().as_value())).unwrap();
             assert_that!(unsafe { sut.add(id) }, is_ok);
         }
 
@@ -121,7 +121,7 @@ mod event_id_tracker {
         assert_that!(unsafe { sut.init(&allocator(&mut *memory)) }, is_ok);
 
         for i in 0..CAPACITY {
-            let id = TriggerId::new(i.min(sut.trigger_id_max().as_value()));
+            let id = TriggerId::new(i.min(sut.trigger_id_max().as_value())).unwrap();
             assert_that!(unsafe { sut.add(id) }, is_ok);
         }
 
@@ -105,7 +105,7 @@ mod event {
             .open()
             .unwrap();
 
-        let trigger_id = TriggerId::new(0);
+        let trigger_id = TriggerId::new(0).unwrap();
 
         for _ in 0..REPETITIONS {
             sut_notifier.notify(trigger_id).unwrap();
@@ -144,9 +144,9 @@ mod event {
             .unwrap();
 
         for i in 0..REPETITIONS {
-            sut_notifier.notify(TriggerId::new(i)).unwrap();
+            sut_notifier.notify(TriggerId::new(i).unwrap()).unwrap();
             let result = wait_call(&sut_listener).unwrap();
-            assert_that!(result.unwrap(), eq TriggerId::new(i));
+            assert_that!(result.unwrap(), eq TriggerId::new(i).unwrap());
         }
     }
 
@@ -185,7 +185,7 @@ mod event {
             .unwrap();
 
         for i in 0..REPETITIONS {
-            sut_notifier.notify(TriggerId::new(i)).unwrap();
+            sut_notifier.notify(TriggerId::new(i).unwrap()).unwrap();
         }
 
         let mut ids = HashSet::new();
@@ -244,7 +244,7 @@ mod event {
         for i in 0..REPETITIONS {
             for (n, notifier) in sources.iter().enumerate() {
                 let event_id = n * (SOURCES + REPETITIONS + 1) + i;
-                assert_that!(notifier.notify(TriggerId::new(event_id)), is_ok);
+                assert_that!(notifier.notify(TriggerId::new(event_id).unwrap()), is_ok);
                 event_ids.push(event_id);
             }
         }
@@ -339,7 +339,7 @@ mod event {
                 let result = sut_listener.blocking_wait_one().unwrap();
                 counter.store(1, Ordering::SeqCst);
                 assert_that!(result, is_some);
-                assert_that!(result.unwrap(), eq TriggerId::new(89));
+                assert_that!(result.unwrap(), eq TriggerId::new(89).unwrap());
             });
 
             barrier.wait();
@@ -349,7 +349,7 @@ mod event {
                 .unwrap();
             std::thread::sleep(TIMEOUT);
             let counter_old = counter.load(Ordering::SeqCst);
-            sut_notifier.notify(TriggerId::new(89)).unwrap();
+            sut_notifier.notify(TriggerId::new(89).unwrap()).unwrap();
             t.join().unwrap();
 
             assert_that!(counter_old, eq 0);
@@ -379,7 +379,7 @@ mod event {
                 let result = sut_listener.timed_wait_one(TIMEOUT * 1000).unwrap();
                 counter.store(1, Ordering::SeqCst);
                 assert_that!(result, is_some);
-                assert_that!(result.unwrap(), eq TriggerId::new(82));
+                assert_that!(result.unwrap(), eq TriggerId::new(82).unwrap());
             });
 
             barrier.wait();
@@ -389,7 +389,7 @@ mod event {
                 .unwrap();
             std::thread::sleep(TIMEOUT);
             let counter_old = counter.load(Ordering::SeqCst);
-            sut_notifier.notify(TriggerId::new(82)).unwrap();
+            sut_notifier.notify(TriggerId::new(82).unwrap()).unwrap();
             t.join().unwrap();
 
             assert_that!(counter_old, eq 0);
@@ -500,7 +500,7 @@ mod event {
     fn setting_trigger_id_limit_works<Sut: Event>() {
         test_requires!(Sut::has_trigger_id_limit());
 
-        const TRIGGER_ID_MAX: TriggerId = TriggerId::new(1234);
+        const TRIGGER_ID_MAX: TriggerId = TriggerId::new_unchecked(1234);
         let name = generate_name();
         let config = generate_isolated_config::<Sut>();
 
@@ -517,7 +517,7 @@ mod event {
         if Sut::has_trigger_id_limit() {
             assert_that!(sut_notifier.trigger_id_max(), eq TRIGGER_ID_MAX);
         } else {
-            assert_that!(sut_notifier.trigger_id_max(), eq TriggerId::new(usize::MAX));
+            assert_that!(sut_notifier.trigger_id_max(), eq TriggerId::max_supported());
         }
     }
 
@@ -525,7 +525,7 @@ mod event {
     fn triggering_up_to_trigger_id_max_works<Sut: Event>() {
         test_requires!(Sut::has_trigger_id_limit());
 
-        const TRIGGER_ID_MAX: TriggerId = TriggerId::new(1024);
+        const TRIGGER_ID_MAX: TriggerId = TriggerId::new_unchecked(1024);
         let name = generate_name();
         let config = generate_isolated_config::<Sut>();
 
@@ -540,10 +540,10 @@ mod event {
             .unwrap();
 
         for i in 0..TRIGGER_ID_MAX.as_value() {
-            assert_that!(sut_notifier.notify(TriggerId::new(i)), is_ok);
+            assert_that!(sut_notifier.notify(TriggerId::new(i).unwrap()), is_ok);
         }
 
-        let result = sut_notifier.notify(TriggerId::new(TRIGGER_ID_MAX.as_value() + 1));
+        let result = sut_notifier.notify(TriggerId::new(TRIGGER_ID_MAX.as_value() + 1).unwrap());
         assert_that!(result, is_err);
         assert_that!(
             result.err().unwrap(), eq
@@ -571,7 +571,7 @@ mod event {
         let config = generate_isolated_config::<Sut>();
 
         let sut_listener = Sut::ListenerBuilder::new(&name)
-            .trigger_id_max(TriggerId::new(REPETITIONS))
+            .trigger_id_max(TriggerId::new(REPETITIONS).unwrap())
             .config(&config)
             .create()
             .unwrap();
@@ -582,7 +582,9 @@ mod event {
 
         for i in 1..=REPETITIONS {
             for n in 0..i {
-                sut_notifier.notify(TriggerId::new(n as _)).unwrap();
+                sut_notifier
+                    .notify(TriggerId::new(n as _).unwrap())
+                    .unwrap();
             }
 
             let mut vec_of_ids = vec![];
@@ -590,7 +592,7 @@ mod event {
 
             assert_that!(vec_of_ids, len { i });
             for n in 0..i {
-                assert_that!(vec_of_ids, contains TriggerId::new(n));
+                assert_that!(vec_of_ids, contains TriggerId::new(n).unwrap());
             }
         }
     }
@@ -664,7 +666,7 @@ mod event {
         let name = generate_name();
         let barrier = Barrier::new(2);
         let counter = AtomicU64::new(0);
-        let id = TriggerId::new(5);
+        let id = TriggerId::new(5).unwrap();
         let config = Mutex::new(generate_isolated_config::<Sut>());
 
         std::thread::scope(|s| {
@@ -726,7 +728,7 @@ mod event {
 
         drop(sut_listener);
 
-        let result = sut_notifier.notify(TriggerId::new(0));
+        let result = sut_notifier.notify(TriggerId::new(0).unwrap());
 
         // either present a disconnect error when available or continue sending without counterpart, for
         // instance when the event is network socket based
@@ -105,7 +105,10 @@ mod reactor {
         let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
 
         let attachment = NotifierListenerPair::new();
-        attachment.notifier.notify(TriggerId::new(123)).unwrap();
+        attachment
+            .notifier
+            .notify(TriggerId::new(123).unwrap())
+            .unwrap();
 
         let _guard = sut.attach(&attachment.listener);
 
@@ -124,7 +127,10 @@ mod reactor {
         let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
 
         let attachment = NotifierListenerPair::new();
-        attachment.notifier.notify(TriggerId::new(123)).unwrap();
+        attachment
+            .notifier
+            .notify(TriggerId::new(123).unwrap())
+            .unwrap();
 
         let _guard = sut.attach(&attachment.listener);
 
@@ -146,7 +152,10 @@ mod reactor {
         let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
 
         let attachment = NotifierListenerPair::new();
-        attachment.notifier.notify(TriggerId::new(123)).unwrap();
+        attachment
+            .notifier
+            .notify(TriggerId::new(123).unwrap())
+            .unwrap();
 
         let _guard = sut.attach(&attachment.listener);
 
@@ -165,7 +174,10 @@ mod reactor {
         let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
 
         let attachment = NotifierListenerPair::new();
-        attachment.notifier.notify(TriggerId::new(123)).unwrap();
+        attachment
+            .notifier
+            .notify(TriggerId::new(123).unwrap())
+            .unwrap();
 
         let _guard = sut.attach(&attachment.listener);
 
@@ -195,7 +207,10 @@ mod reactor {
         let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
 
         let attachment = NotifierListenerPair::new();
-        attachment.notifier.notify(TriggerId::new(123)).unwrap();
+        attachment
+            .notifier
+            .notify(TriggerId::new(123).unwrap())
+            .unwrap();
 
         let _guard = sut.attach(&attachment.listener);
 
@@ -228,7 +243,10 @@ mod reactor {
         let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
 
         let attachment = NotifierListenerPair::new();
-        attachment.notifier.notify(TriggerId::new(123)).unwrap();
+        attachment
+            .notifier
+            .notify(TriggerId::new(123).unwrap())
+            .unwrap();
 
         let _guard = sut.attach(&attachment.listener);
 
@@ -260,7 +278,10 @@ mod reactor {
         let mut attachments = vec![];
         for _ in 0..NUMBER_OF_ATTACHMENTS {
             let attachment = NotifierListenerPair::new();
-            attachment.notifier.notify(TriggerId::new(123)).unwrap();
+            attachment
+                .notifier
+                .notify(TriggerId::new(123).unwrap())
+                .unwrap();
             attachments.push(attachment);
         }
 
@@ -288,7 +309,10 @@ mod reactor {
         let mut attachments = vec![];
         for _ in 0..NUMBER_OF_ATTACHMENTS {
             let attachment = NotifierListenerPair::new();
-            attachment.notifier.notify(TriggerId::new(123)).unwrap();
+            attachment
+                .notifier
+                .notify(TriggerId::new(123).unwrap())
+                .unwrap();
             attachments.push(attachment);
         }
 
@@ -319,7 +343,10 @@ mod reactor {
         let mut attachments = vec![];
         for _ in 0..NUMBER_OF_ATTACHMENTS {
             let attachment = NotifierListenerPair::new();
-            attachment.notifier.notify(TriggerId::new(123)).unwrap();
+            attachment
+                .notifier
+                .notify(TriggerId::new(123).unwrap())
+                .unwrap();
             attachments.push(attachment);
         }
 
@@ -369,7 +396,10 @@ mod reactor {
         let mut attachments = vec![];
         for _ in 0..NUMBER_OF_ATTACHMENTS {
             let attachment = NotifierListenerPair::new();
-            attachment.notifier.notify(TriggerId::new(123)).unwrap();
+            attachment
+                .notifier
+                .notify(TriggerId::new(123).unwrap())
+                .unwrap();
             attachments.push(attachment);
         }
 
@@ -409,7 +439,10 @@ mod reactor {
         let mut attachments = vec![];
         for _ in 0..NUMBER_OF_ATTACHMENTS {
             let attachment = NotifierListenerPair::new();
-            attachment.notifier.notify(TriggerId::new(123)).unwrap();
+            attachment
+                .notifier
+                .notify(TriggerId::new(123).unwrap())
+                .unwrap();
             attachments.push(attachment);
         }
 
@@ -452,7 +485,10 @@ mod reactor {
         let mut attachments = vec![];
         for _ in 0..NUMBER_OF_ATTACHMENTS {
             let attachment = NotifierListenerPair::new();
-            attachment.notifier.notify(TriggerId::new(123)).unwrap();
+            attachment
+                .notifier
+                .notify(TriggerId::new(123).unwrap())
+                .unwrap();
             attachments.push(attachment);
         }
 
@@ -522,7 +558,7 @@ mod reactor {
                 .config(&config.lock().unwrap())
                 .open()
                 .unwrap();
-            notifier.notify(TriggerId::new(123)).unwrap();
+            notifier.notify(TriggerId::new(123).unwrap()).unwrap();
             t.join().unwrap();
 
             assert_that!(counter_old, eq 0);
@@ -564,7 +600,7 @@ mod reactor {
                 .config(&config.lock().unwrap())
                 .open()
                 .unwrap();
-            notifier.notify(TriggerId::new(123)).unwrap();
+            notifier.notify(TriggerId::new(123).unwrap()).unwrap();
             t.join().unwrap();
 
             assert_that!(counter_old, eq 0);
@@ -577,4 +613,34 @@ mod reactor {
     #[cfg(target_os = "linux")]
     #[instantiate_tests(<iceoryx2_cal::reactor::epoll::Epoll>)]
     mod epoll {}
+
+    #[cfg(any(target_os = "macos", target_os = "freebsd"))]
+    #[instantiate_tests(<iceoryx2_cal::reactor::kqueue::Kqueue>)]
+    mod kqueue {}
+}
+
+#[test]
+fn posix_select_reactor_does_not_support_edge_triggered_attachments() {
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::event::unix_datagram_socket::*;
+    use iceoryx2_cal::event::ListenerBuilder;
+    use iceoryx2_cal::reactor::*;
+    use iceoryx2_cal::testing::{generate_isolated_config, generate_name};
+
+    let sut = <posix_select::Reactor as Reactor>::Builder::new()
+        .create()
+        .unwrap();
+    let listener = unix_datagram_socket::ListenerBuilder::new(&generate_name())
+        .config(&generate_isolated_config::<unix_datagram_socket::EventImpl>())
+        .create()
+        .unwrap();
+
+    let result = sut.attach_with_trigger_mode(&listener, TriggerMode::Edge);
+    assert_that!(result.err(), eq Some(ReactorAttachError::UnsupportedTriggerMode));
+    assert_that!(sut.is_empty(), eq true);
+
+    assert_that!(
+        sut.attach_with_trigger_mode(&listener, TriggerMode::Level),
+        is_ok
+    );
 }
@@ -15,6 +15,8 @@ mod shared_memory {
     use core::alloc::Layout;
 
     use iceoryx2_bb_container::semantic_string::*;
+    use iceoryx2_bb_posix::access_control_list::{AclPermission, AclQualifier};
+    use iceoryx2_bb_posix::user::Uid;
     use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_testing::{assert_that, test_requires};
     use iceoryx2_cal::named_concept::*;
@@ -396,9 +398,82 @@ mod shared_memory {
         assert_that!(Sut::does_exist_cfg(&name, &config), eq Ok(false));
     }
 
+    #[test]
+    fn try_huge_pages_always_creates_shared_memory<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut_create = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .huge_pages(HugePagePolicy::TryHuge2MB)
+            .config(&config)
+            .create(&SHM_CONFIG)
+            .unwrap();
+        let sut_open = Sut::Builder::new(&name).config(&config).open().unwrap();
+
+        assert_that!(sut_create.size(), ge DEFAULT_SIZE);
+        assert_that!(sut_create.page_size(), gt 0);
+        assert_that!(sut_open.page_size(), eq sut_create.page_size());
+    }
+
+    #[test]
+    fn required_huge_pages_are_used_or_creation_fails<Sut: SharedMemory<DefaultAllocator>>() {
+        const HUGE_PAGE_SIZE: usize = 1024 * 1024 * 1024;
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let sut = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .huge_pages(HugePagePolicy::Require1GB)
+            .config(&config)
+            .create(&SHM_CONFIG);
+
+        match sut {
+            Ok(sut) => assert_that!(sut.page_size(), eq HUGE_PAGE_SIZE),
+            Err(e) => {
+                assert_that!(e, eq SharedMemoryCreateError::HugePagesUnavailable);
+                assert_that!(Sut::does_exist_cfg(&name, &config), eq Ok(false));
+            }
+        }
+    }
+
+    #[test]
+    fn access_control_list_is_applied_or_creation_fails<Sut: SharedMemory<DefaultAllocator>>() {
+        let name = generate_name();
+        let config = generate_isolated_config::<Sut>();
+
+        let mut acl = AccessControlList::new();
+        acl.set(
+            AclQualifier::User(Uid::new(54321).unwrap()),
+            AclPermission::READ | AclPermission::WRITE,
+        )
+        .unwrap();
+
+        let sut = Sut::Builder::new(&name)
+            .size(DEFAULT_SIZE)
+            .access_control_list(&acl)
+            .config(&config)
+            .create(&SHM_CONFIG);
+
+        match sut {
+            Ok(_sut) => {
+                let sut_open = Sut::Builder::new(&name).config(&config).open();
+                assert_that!(sut_open, is_ok);
+            }
+            Err(e) => {
+                assert_that!(e, eq SharedMemoryCreateError::AccessControlListUnsupported);
+                assert_that!(Sut::does_exist_cfg(&name, &config), eq Ok(false));
+            }
+        }
+    }
+
     #[instantiate_tests(<iceoryx2_cal::shared_memory::posix::Memory<DefaultAllocator>>)]
     mod posix {}
 
     #[instantiate_tests(<iceoryx2_cal::shared_memory::process_local::Memory<DefaultAllocator>>)]
     mod process_local {}
+
+    #[cfg(target_os = "linux")]
+    #[instantiate_tests(<iceoryx2_cal::shared_memory::memfd::Memory<DefaultAllocator>>)]
+    mod memfd {}
 }
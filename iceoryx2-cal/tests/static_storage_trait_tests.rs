@@ -15,6 +15,8 @@ mod static_storage {
     use core::sync::atomic::{AtomicU64, Ordering};
     use core::time::Duration;
     use iceoryx2_bb_container::semantic_string::*;
+    use iceoryx2_bb_posix::access_control_list::{AclPermission, AclQualifier};
+    use iceoryx2_bb_posix::group::Gid;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_system_types::file_name::FileName;
     use iceoryx2_bb_testing::assert_that;
@@ -541,6 +543,46 @@ mod static_storage {
         assert_that!(*config.get_prefix(), eq Sut::default_prefix());
     }
 
+    #[test]
+    fn create_with_access_control_list_works_or_fails<Sut: StaticStorage>() {
+        let _test_guard = TEST_MUTEX.lock();
+        let storage_name = generate_name();
+
+        let mut acl = AccessControlList::new();
+        acl.set(
+            AclQualifier::Group(Gid::new(54321).unwrap()),
+            AclPermission::READ,
+        )
+        .unwrap();
+
+        let mut content = "some storage content".to_string();
+
+        let storage_guard = Sut::Builder::new(&storage_name)
+            .access_control_list(&acl)
+            .create(unsafe { content.as_mut_vec() }.as_slice());
+
+        match storage_guard {
+            Ok(_storage_guard) => {
+                assert_that!(Sut::does_exist(&storage_name), eq Ok(true));
+                assert_that!(<Sut as NamedConceptMgmt>::list().unwrap(), contains storage_name);
+
+                let storage_reader = Sut::Builder::new(&storage_name)
+                    .open(Duration::ZERO)
+                    .unwrap();
+
+                let mut read_content = String::from_utf8(vec![b' '; content.len()]).unwrap();
+                storage_reader
+                    .read(unsafe { read_content.as_mut_vec() }.as_mut_slice())
+                    .unwrap();
+                assert_that!(read_content, eq content);
+            }
+            Err(e) => {
+                assert_that!(e, eq StaticStorageCreateError::AccessControlListUnsupported);
+                assert_that!(Sut::does_exist(&storage_name), eq Ok(false));
+            }
+        }
+    }
+
     #[instantiate_tests(<iceoryx2_cal::static_storage::file::Storage>)]
     mod file {}
 
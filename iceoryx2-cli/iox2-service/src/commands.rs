@@ -104,7 +104,7 @@ pub fn notify(options: NotifyOptions, format: Format) -> Result<()> {
 
     let notifier = service
         .notifier_builder()
-        .default_event_id(EventId::new(options.event_id))
+        .default_event_id(EventId::new(options.event_id)?)
         .create()?;
 
     let notify_feedback = EventFeedback {
@@ -1515,6 +1515,8 @@ constexpr auto from<int, iox2::NotifierNotifyError>(const int value) noexcept ->
         return iox2::NotifierNotifyError::MissedDeadline;
     case iox2_notifier_notify_error_e_UNABLE_TO_ACQUIRE_ELAPSED_TIME:
         return iox2::NotifierNotifyError::UnableToAcquireElapsedTime;
+    case iox2_notifier_notify_error_e_DELIVERY_TIMEOUT:
+        return iox2::NotifierNotifyError::DeliveryTimeout;
     }
 
     IOX_UNREACHABLE();
@@ -1531,6 +1533,8 @@ from<iox2::NotifierNotifyError, iox2_notifier_notify_error_e>(const iox2::Notifi
         return iox2_notifier_notify_error_e_MISSED_DEADLINE;
     case iox2::NotifierNotifyError::UnableToAcquireElapsedTime:
         return iox2_notifier_notify_error_e_UNABLE_TO_ACQUIRE_ELAPSED_TIME;
+    case iox2::NotifierNotifyError::DeliveryTimeout:
+        return iox2_notifier_notify_error_e_DELIVERY_TIMEOUT;
     }
 
     IOX_UNREACHABLE();
@@ -2112,6 +2116,10 @@ constexpr auto from<int, iox2::WaitSetAttachmentError>(const int value) noexcept
         return iox2::WaitSetAttachmentError::InternalError;
     case iox2_waitset_attachment_error_e_INSUFFICIENT_RESOURCES:
         return iox2::WaitSetAttachmentError::InsufficientResources;
+    case iox2_waitset_attachment_error_e_DISCONNECTED:
+        return iox2::WaitSetAttachmentError::Disconnected;
+    case iox2_waitset_attachment_error_e_UNSUPPORTED_TRIGGER_MODE:
+        return iox2::WaitSetAttachmentError::UnsupportedTriggerMode;
     }
 
     IOX_UNREACHABLE();
@@ -2130,6 +2138,10 @@ from<iox2::WaitSetAttachmentError, iox2_waitset_attachment_error_e>(const iox2::
         return iox2_waitset_attachment_error_e_INTERNAL_ERROR;
     case iox2::WaitSetAttachmentError::InsufficientResources:
         return iox2_waitset_attachment_error_e_INSUFFICIENT_RESOURCES;
+    case iox2::WaitSetAttachmentError::Disconnected:
+        return iox2_waitset_attachment_error_e_DISCONNECTED;
+    case iox2::WaitSetAttachmentError::UnsupportedTriggerMode:
+        return iox2_waitset_attachment_error_e_UNSUPPORTED_TRIGGER_MODE;
     }
 
     IOX_UNREACHABLE();
@@ -41,6 +41,9 @@ enum class NotifierNotifyError : uint8_t {
     /// The notification was delivered but the elapsed system time could not be acquired.
     /// Therefore, it is unknown if the deadline was missed or not.
     UnableToAcquireElapsedTime,
+    /// The notification could not be delivered to all [`Listener`] ports before the timeout
+    /// has passed.
+    DeliveryTimeout,
 };
 
 } // namespace iox2
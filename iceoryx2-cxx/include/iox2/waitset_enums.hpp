@@ -47,6 +47,10 @@ enum class WaitSetAttachmentError : uint8_t {
     InternalError,
     /// Insufficient resources to add another attachment to the [`WaitSet`].
     InsufficientResources,
+    /// The file descriptor of the attachment is no longer valid.
+    Disconnected,
+    /// The requested trigger mode is not supported by the underlying reactor.
+    UnsupportedTriggerMode,
 };
 
 /// Defines the failures that can occur when calling [`WaitSet::run()`].
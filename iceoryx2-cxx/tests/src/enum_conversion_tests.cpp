@@ -262,6 +262,8 @@ TEST(EnumConversionTest, waitset_attachment_into_c_str) {
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InsufficientCapacity)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::AlreadyAttached)), 1U);
     ASSERT_GT(strlen(iox::into<const char*>(Sut::InternalError)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::InsufficientResources)), 1U);
+    ASSERT_GT(strlen(iox::into<const char*>(Sut::Disconnected)), 1U);
 }
 
 TEST(EnumConversionTest, waitset_run_into_c_str) {
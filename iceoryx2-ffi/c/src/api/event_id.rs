@@ -21,8 +21,9 @@ pub struct iox2_event_id_t {
 }
 
 impl From<iox2_event_id_t> for EventId {
+    /// Values that exceed [`EventId::max_supported()`] saturate to it.
     fn from(id: iox2_event_id_t) -> Self {
-        EventId::new(id.value)
+        EventId::new(id.value).unwrap_or(EventId::max_supported())
     }
 }
 
@@ -14,7 +14,7 @@ pub const IOX2_ATTRIBUTE_KEY_LENGTH: usize = 64;
 pub const IOX2_ATTRIBUTE_VALUE_LENGTH: usize = 256;
 pub const IOX2_MAX_ATTRIBUTES_PER_SERVICE: usize = 8;
 pub const IOX2_NODE_NAME_LENGTH: usize = 128;
-pub const IOX2_SERVICE_NAME_LENGTH: usize = 255;
+pub const IOX2_SERVICE_NAME_LENGTH: usize = 1024;
 pub const IOX2_SERVICE_ID_LENGTH: usize = 64;
 pub const IOX2_TYPE_NAME_LENGTH: usize = 256;
 
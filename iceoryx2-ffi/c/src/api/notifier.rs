@@ -17,6 +17,7 @@ use crate::api::{
     iox2_unique_notifier_id_t, AssertNonNullHandle, HandleToType, IntoCInt, IOX2_OK,
 };
 
+use iceoryx2::port::event_id::EventId;
 use iceoryx2::port::notifier::{Notifier, NotifierNotifyError};
 use iceoryx2_bb_elementary::static_assert::*;
 use iceoryx2_bb_elementary_traits::AsCStr;
@@ -34,6 +35,7 @@ pub enum iox2_notifier_notify_error_e {
     EVENT_ID_OUT_OF_BOUNDS = IOX2_OK as isize + 1,
     MISSED_DEADLINE,
     UNABLE_TO_ACQUIRE_ELAPSED_TIME,
+    DELIVERY_TIMEOUT,
 }
 
 impl IntoCInt for NotifierNotifyError {
@@ -46,6 +48,7 @@ impl IntoCInt for NotifierNotifyError {
             NotifierNotifyError::UnableToAcquireElapsedTime => {
                 iox2_notifier_notify_error_e::UNABLE_TO_ACQUIRE_ELAPSED_TIME
             }
+            NotifierNotifyError::DeliveryTimeout => iox2_notifier_notify_error_e::DELIVERY_TIMEOUT,
         }) as c_int
     }
 }
@@ -295,7 +298,10 @@ pub unsafe extern "C" fn iox2_notifier_notify_with_custom_event_id(
     notifier_handle.assert_non_null();
     debug_assert!(!custom_event_id_ptr.is_null());
 
-    let event_id = (*custom_event_id_ptr).into();
+    let event_id = match EventId::new((*custom_event_id_ptr).value) {
+        Ok(event_id) => event_id,
+        Err(_) => return iox2_notifier_notify_error_e::EVENT_ID_OUT_OF_BOUNDS as c_int,
+    };
 
     let notifier = &mut *notifier_handle.as_type();
     let notify_result = match notifier.service_type {
@@ -13,9 +13,10 @@
 #![allow(non_camel_case_types)]
 
 use crate::api::{
-    c_size_t, iox2_port_factory_event_h, iox2_port_factory_event_t, iox2_service_builder_event_h,
-    iox2_service_builder_event_h_ref, iox2_service_type_e, AssertNonNullHandle, HandleToType,
-    IntoCInt, PortFactoryEventUnion, ServiceBuilderUnion, IOX2_OK,
+    c_size_t, iox2_event_id_t, iox2_port_factory_event_h, iox2_port_factory_event_t,
+    iox2_service_builder_event_h, iox2_service_builder_event_h_ref, iox2_service_type_e,
+    AssertNonNullHandle, HandleToType, IntoCInt, PortFactoryEventUnion, ServiceBuilderUnion,
+    IOX2_OK,
 };
 
 use iceoryx2::prelude::*;
@@ -293,7 +294,7 @@ pub unsafe extern "C" fn iox2_service_builder_event_set_notifier_dead_event(
 ) {
     iox2_service_builder_event_set_notifier_dead_event_impl(
         service_builder_handle,
-        Some(EventId::new(value as _)),
+        Some(iox2_event_id_t { value }.into()),
     );
 }
 
@@ -365,7 +366,7 @@ pub unsafe extern "C" fn iox2_service_builder_event_set_notifier_created_event(
 ) {
     iox2_service_builder_event_set_notifier_created_event_impl(
         service_builder_handle,
-        Some(EventId::new(value as _)),
+        Some(iox2_event_id_t { value }.into()),
     );
 }
 
@@ -436,7 +437,7 @@ pub unsafe extern "C" fn iox2_service_builder_event_set_notifier_dropped_event(
 ) {
     iox2_service_builder_event_set_notifier_dropped_event_impl(
         service_builder_handle,
-        Some(EventId::new(value as _)),
+        Some(iox2_event_id_t { value }.into()),
     );
 }
 
@@ -49,7 +49,7 @@ impl IntoCInt for ServiceNameError {
 #[repr(C)]
 #[repr(align(8))] // alignment of Option<ServiceName>
 pub struct iox2_service_name_storage_t {
-    internal: [u8; 272], // magic number obtained with size_of::<Option<ServiceName>>()
+    internal: [u8; 1048], // magic number obtained with size_of::<Option<ServiceName>>()
 }
 
 #[repr(C)]
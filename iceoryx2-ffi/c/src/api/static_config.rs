@@ -39,7 +39,7 @@ pub union iox2_static_config_details_t {
 #[repr(C)]
 pub struct iox2_static_config_t {
     pub id: [c_char; IOX2_SERVICE_ID_LENGTH],
-    pub name: [c_char; IOX2_SERVICE_NAME_LENGTH],
+    pub name: [c_char; IOX2_SERVICE_NAME_LENGTH + 1],
     pub messaging_pattern: iox2_messaging_pattern_e,
     pub details: iox2_static_config_details_t,
     pub attributes: iox2_attribute_set_h,
@@ -70,7 +70,7 @@ impl From<&StaticConfig> for iox2_static_config_t {
                 }
             }),
             name: core::array::from_fn(|n| {
-                debug_assert!(value.name().len() + 1 < IOX2_SERVICE_NAME_LENGTH);
+                debug_assert!(value.name().len() <= IOX2_SERVICE_NAME_LENGTH);
 
                 if n < value.name().len() {
                     value.name().as_bytes()[n] as _
@@ -86,6 +86,8 @@ pub enum iox2_waitset_attachment_error_e {
     ALREADY_ATTACHED,
     INTERNAL_ERROR,
     INSUFFICIENT_RESOURCES,
+    DISCONNECTED,
+    UNSUPPORTED_TRIGGER_MODE,
 }
 
 impl IntoCInt for WaitSetAttachmentError {
@@ -103,6 +105,10 @@ impl IntoCInt for WaitSetAttachmentError {
             WaitSetAttachmentError::InsufficientResources => {
                 iox2_waitset_attachment_error_e::INSUFFICIENT_RESOURCES
             }
+            WaitSetAttachmentError::Disconnected => iox2_waitset_attachment_error_e::DISCONNECTED,
+            WaitSetAttachmentError::UnsupportedTriggerMode => {
+                iox2_waitset_attachment_error_e::UNSUPPORTED_TRIGGER_MODE
+            }
         }) as c_int
     }
 }
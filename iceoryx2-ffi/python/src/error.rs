@@ -125,6 +125,13 @@ create_exception!(
     "Errors caused when open or creating an event service."
 );
 
+create_exception!(
+    iceoryx2_ffi_python,
+    EventIdCreateError,
+    PyException,
+    "Errors caused when the value of an event id exceeds the maximum supported value."
+);
+
 create_exception!(
     iceoryx2_ffi_python,
     InvalidAlignmentValue,
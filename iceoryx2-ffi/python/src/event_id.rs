@@ -12,6 +12,8 @@
 
 use pyo3::prelude::*;
 
+use crate::error::EventIdCreateError;
+
 #[pyclass(eq, str = "{0:?}")]
 #[derive(PartialEq)]
 /// User defined identifier that can be provided in `Notifier.notify()` to signal a specific
@@ -21,9 +23,21 @@ pub struct EventId(pub(crate) iceoryx2::prelude::EventId);
 #[pymethods]
 impl EventId {
     #[staticmethod]
-    /// Creates a new `EventId` from a given integer value
-    pub fn new(value: usize) -> Self {
-        EventId(iceoryx2::prelude::EventId::new(value))
+    /// Creates a new `EventId` from a given integer value. If the value exceeds
+    /// `EventId.max_supported()` it emits an `EventIdCreateError`.
+    pub fn new(value: usize) -> PyResult<Self> {
+        match iceoryx2::prelude::EventId::new(value) {
+            Ok(v) => Ok(EventId(v)),
+            Err(e) => Err(EventIdCreateError::new_err(format!(
+                "This: ({value}) is not a valid event id ({e:?})"
+            ))),
+        }
+    }
+
+    #[staticmethod]
+    /// Returns the largest `EventId` that can be created.
+    pub fn max_supported() -> Self {
+        EventId(iceoryx2::prelude::EventId::max_supported())
     }
 
     #[getter]
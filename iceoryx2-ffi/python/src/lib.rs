@@ -218,6 +218,10 @@ fn _iceoryx2(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
         "EventOpenOrCreateError",
         py.get_type::<crate::error::EventOpenOrCreateError>(),
     )?;
+    m.add(
+        "EventIdCreateError",
+        py.get_type::<crate::error::EventIdCreateError>(),
+    )?;
     m.add(
         "InvalidAlignmentValue",
         py.get_type::<crate::error::InvalidAlignmentValue>(),
@@ -80,7 +80,7 @@ impl Listener {
     }
 
     /// Blocking wait for a new `EventId`.
-    /// Sporadic wakeups can occur and if no `EventId` was notified it returns `None`.
+    /// Spurious wakeups are handled internally, it returns only after an `EventId` was received.
     /// On error it emits `ListenerWaitError`.
     pub fn blocking_wait_one(&self) -> PyResult<Option<EventId>> {
         match &self.0 {
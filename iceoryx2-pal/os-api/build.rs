@@ -13,10 +13,7 @@
 #[cfg(feature = "libc_platform")]
 fn main() {}
 
-#[cfg(all(not(feature = "libc_platform"), not(target_os = "linux")))]
-fn main() {}
-
-#[cfg(all(not(feature = "libc_platform"), target_os = "linux"))]
+#[cfg(not(feature = "libc_platform"))]
 fn main() {
     extern crate bindgen;
     extern crate cc;
@@ -25,10 +22,18 @@ fn main() {
     use std::env;
     use std::path::PathBuf;
 
-    println!("cargo:rerun-if-changed=src/c/linux.h");
+    // #[cfg(any(...))] does not work when cross-compiling
+    let target_os = std::env::var("CARGO_CFG_TARGET_OS").unwrap();
+    let header = match target_os.as_str() {
+        "linux" => "src/c/linux.h",
+        "macos" | "freebsd" => "src/c/bsd.h",
+        _ => return,
+    };
+
+    println!("cargo:rerun-if-changed={header}");
 
     let mut builder = bindgen::Builder::default()
-        .header("src/c/linux.h")
+        .header(header)
         .parse_callbacks(Box::new(CargoCallbacks::new()))
         .use_core();
 
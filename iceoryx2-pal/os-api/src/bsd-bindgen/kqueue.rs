@@ -0,0 +1,46 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+#![allow(clippy::missing_safety_doc)]
+
+use iceoryx2_pal_posix::posix;
+
+pub type kevent = crate::internal::kevent;
+
+pub const EVFILT_READ: i16 = crate::internal::EVFILT_READ as _;
+pub const EVFILT_WRITE: i16 = crate::internal::EVFILT_WRITE as _;
+
+pub const EV_ADD: u16 = crate::internal::EV_ADD as _;
+pub const EV_DELETE: u16 = crate::internal::EV_DELETE as _;
+pub const EV_ENABLE: u16 = crate::internal::EV_ENABLE as _;
+pub const EV_DISABLE: u16 = crate::internal::EV_DISABLE as _;
+pub const EV_ONESHOT: u16 = crate::internal::EV_ONESHOT as _;
+pub const EV_CLEAR: u16 = crate::internal::EV_CLEAR as _;
+pub const EV_RECEIPT: u16 = crate::internal::EV_RECEIPT as _;
+pub const EV_EOF: u16 = crate::internal::EV_EOF as _;
+pub const EV_ERROR: u16 = crate::internal::EV_ERROR as _;
+
+pub unsafe fn kqueue() -> posix::int {
+    crate::internal::kqueue()
+}
+
+pub unsafe fn kevent(
+    kq: posix::int,
+    changelist: *const kevent,
+    nchanges: posix::int,
+    eventlist: *mut kevent,
+    nevents: posix::int,
+    timeout: *const posix::timespec,
+) -> posix::int {
+    crate::internal::kevent(kq, changelist, nchanges, eventlist, nevents, timeout.cast())
+}
@@ -0,0 +1,21 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#ifndef IOX2_PAL_OS_API_BSD_H
+#define IOX2_PAL_OS_API_BSD_H
+
+#include <sys/types.h>
+
+#include <sys/event.h>
+#include <sys/time.h>
+
+#endif
@@ -23,7 +23,24 @@ pub mod linux;
 #[path = "linux-libc/mod.rs"]
 pub mod linux;
 
-#[cfg(all(not(feature = "libc_platform"), target_os = "linux"))]
+#[cfg(all(
+    any(target_os = "macos", target_os = "freebsd"),
+    not(feature = "libc_platform")
+))]
+#[path = "bsd-bindgen/mod.rs"]
+pub mod bsd;
+
+#[cfg(all(
+    any(target_os = "macos", target_os = "freebsd"),
+    feature = "libc_platform"
+))]
+#[path = "bsd-libc/mod.rs"]
+pub mod bsd;
+
+#[cfg(all(
+    not(feature = "libc_platform"),
+    any(target_os = "linux", target_os = "macos", target_os = "freebsd")
+))]
 pub(crate) mod internal {
     #![allow(non_upper_case_globals)]
     #![allow(non_camel_case_types)]
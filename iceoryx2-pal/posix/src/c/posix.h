@@ -15,12 +15,17 @@
 
 #ifdef __FreeBSD__
 #include <libutil.h>
+#include <sys/acl.h>
 #include <sys/param.h>
 #include <sys/sysctl.h>
 #include <sys/ucred.h>
 #include <sys/user.h>
 #endif
 
+#ifdef __linux__
+#include <sys/xattr.h>
+#endif
+
 #ifdef __APPLE__
 #include <libproc.h>
 #include <mach-o/dyld.h>
@@ -36,15 +41,18 @@
 #include <dirent.h>
 #include <grp.h>
 #include <netinet/in.h>
+#include <netinet/tcp.h>
 #include <pthread.h>
 #include <pwd.h>
 #include <sched.h>
 #include <semaphore.h>
+#include <spawn.h>
 #include <sys/mman.h>
 #include <sys/resource.h>
 #include <sys/select.h>
 #include <sys/socket.h>
 #include <sys/un.h>
+#include <sys/wait.h>
 #include <unistd.h>
 #endif
 
@@ -0,0 +1,39 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use crate::posix::MemZeroedStruct;
+use crate::posix::{fstat, ftruncate, types::*, Errno};
+
+/// Emulates `posix_fallocate` by growing the file with `ftruncate`. The file is never shrunk
+/// but in contrast to `posix_fallocate` the storage is not reserved, therefore later writes
+/// may still fail with `ENOSPC`. Like `posix_fallocate` it returns the error code directly.
+pub(crate) unsafe fn fallocate_by_truncate_impl(fd: int, offset: off_t, len: off_t) -> int {
+    if offset < 0 || len <= 0 {
+        return Errno::EINVAL as int;
+    }
+
+    let mut attr = stat_t::new_zeroed();
+    if fstat(fd, &mut attr) == -1 {
+        return Errno::get() as int;
+    }
+
+    let new_size = offset + len;
+    if new_size <= attr.st_size as off_t {
+        return 0;
+    }
+
+    if ftruncate(fd, new_size) == -1 {
+        return Errno::get() as int;
+    }
+
+    0
+}
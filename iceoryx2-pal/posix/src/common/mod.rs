@@ -13,8 +13,15 @@
 pub mod cpu_set_t;
 pub(crate) mod error_enum_generator;
 pub mod mem_zeroed_struct;
+#[cfg(target_os = "linux")]
+pub(crate) mod posix_acl;
 pub mod sockaddr_in;
 pub(crate) mod string_operations;
 
+#[cfg(all(
+    any(target_os = "macos", target_os = "nto", target_os = "windows"),
+    not(feature = "libc_platform")
+))]
+pub(crate) mod fallocate;
 #[cfg(not(target_os = "windows"))]
 pub(crate) mod scandir;
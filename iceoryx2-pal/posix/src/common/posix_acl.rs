@@ -0,0 +1,388 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Emulates the subset of the POSIX.1e ACL API that is required by iceoryx2 on top of the
+//! `system.posix_acl_access` extended attribute. Linux stores the access ACL in this attribute
+//! in a stable binary format, therefore no dependency to libacl is required.
+//!
+//! In contrast to libacl, [`acl_to_text_impl()`] always prints numeric user and group ids and
+//! [`acl_from_text_impl()`] accepts only numeric ids.
+
+use alloc::vec::Vec;
+use core::alloc::Layout;
+
+use crate::posix::MemZeroedStruct;
+use crate::posix::{fstat, types::*, Errno};
+
+const XATTR_NAME: &core::ffi::CStr = c"system.posix_acl_access";
+const XATTR_VERSION: u32 = 2;
+const XATTR_HEADER_SIZE: usize = 4;
+const XATTR_ENTRY_SIZE: usize = 8;
+
+const ACL_USER_OBJ: u16 = 0x01;
+const ACL_USER: u16 = 0x02;
+const ACL_GROUP_OBJ: u16 = 0x04;
+const ACL_GROUP: u16 = 0x08;
+const ACL_MASK: u16 = 0x10;
+const ACL_OTHER: u16 = 0x20;
+const ACL_UNDEFINED_ID: u32 = u32::MAX;
+
+const ACL_READ: u16 = 0x04;
+const ACL_WRITE: u16 = 0x02;
+const ACL_EXECUTE: u16 = 0x01;
+
+// the objects returned by acl_get_fd, acl_from_text and acl_to_text are all released with
+// acl_free, the header identifies the object and stores the size of the allocation
+const ACL_OBJECT_MAGIC: u64 = 0x6963_6532_6163_6c00;
+const TEXT_OBJECT_MAGIC: u64 = 0x6963_6532_7478_7400;
+
+#[repr(C)]
+struct ObjectHeader {
+    magic: u64,
+    size: usize,
+}
+
+const OBJECT_HEADER_SIZE: usize = core::mem::size_of::<ObjectHeader>();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Entry {
+    tag: u16,
+    id: u32,
+    perm: u16,
+}
+
+unsafe fn allocate_object(magic: u64, payload: &[u8], null_terminated: bool) -> *mut u8 {
+    let size = OBJECT_HEADER_SIZE + payload.len() + null_terminated as usize;
+    let layout = Layout::from_size_align_unchecked(size, core::mem::align_of::<ObjectHeader>());
+    let memory = alloc::alloc::alloc(layout);
+    if memory.is_null() {
+        Errno::set(Errno::ENOMEM);
+        return core::ptr::null_mut();
+    }
+
+    (memory as *mut ObjectHeader).write(ObjectHeader { magic, size });
+    let data = memory.add(OBJECT_HEADER_SIZE);
+    core::ptr::copy_nonoverlapping(payload.as_ptr(), data, payload.len());
+    if null_terminated {
+        data.add(payload.len()).write(0);
+    }
+
+    data
+}
+
+unsafe fn object_header(obj: *const u8) -> *mut ObjectHeader {
+    obj.sub(OBJECT_HEADER_SIZE) as *mut ObjectHeader
+}
+
+unsafe fn acl_entries(acl: *const void) -> Option<Vec<Entry>> {
+    if acl.is_null() || (*object_header(acl.cast())).magic != ACL_OBJECT_MAGIC {
+        return None;
+    }
+
+    let size = (*object_header(acl.cast())).size - OBJECT_HEADER_SIZE;
+    decode(core::slice::from_raw_parts(acl.cast(), size))
+}
+
+fn encode(entries: &[Entry]) -> Vec<u8> {
+    let mut buffer = Vec::with_capacity(XATTR_HEADER_SIZE + entries.len() * XATTR_ENTRY_SIZE);
+    buffer.extend_from_slice(&XATTR_VERSION.to_le_bytes());
+    for entry in entries {
+        buffer.extend_from_slice(&entry.tag.to_le_bytes());
+        buffer.extend_from_slice(&entry.perm.to_le_bytes());
+        buffer.extend_from_slice(&entry.id.to_le_bytes());
+    }
+
+    buffer
+}
+
+fn decode(buffer: &[u8]) -> Option<Vec<Entry>> {
+    if buffer.len() < XATTR_HEADER_SIZE
+        || (buffer.len() - XATTR_HEADER_SIZE) % XATTR_ENTRY_SIZE != 0
+    {
+        return None;
+    }
+
+    if u32::from_le_bytes([buffer[0], buffer[1], buffer[2], buffer[3]]) != XATTR_VERSION {
+        return None;
+    }
+
+    Some(
+        buffer[XATTR_HEADER_SIZE..]
+            .chunks_exact(XATTR_ENTRY_SIZE)
+            .map(|e| Entry {
+                tag: u16::from_le_bytes([e[0], e[1]]),
+                perm: u16::from_le_bytes([e[2], e[3]]),
+                id: u32::from_le_bytes([e[4], e[5], e[6], e[7]]),
+            })
+            .collect(),
+    )
+}
+
+fn parse_id(value: &[u8]) -> Option<u32> {
+    if value.is_empty() || !value.iter().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let mut id: u32 = 0;
+    for c in value {
+        id = id.checked_mul(10)?.checked_add((c - b'0') as u32)?;
+    }
+
+    (id != ACL_UNDEFINED_ID).then_some(id)
+}
+
+fn parse_perm(value: &[u8]) -> Option<u16> {
+    if value.is_empty() || value.len() > 3 {
+        return None;
+    }
+
+    let mut perm = 0;
+    for c in value {
+        match c {
+            b'r' => perm |= ACL_READ,
+            b'w' => perm |= ACL_WRITE,
+            b'x' => perm |= ACL_EXECUTE,
+            b'-' => (),
+            _ => return None,
+        }
+    }
+
+    Some(perm)
+}
+
+fn parse_entry(value: &[u8]) -> Option<Entry> {
+    let fields: Vec<&[u8]> = value.split(|c| *c == b':').collect();
+    let (tag, qualifier, perm) = match fields.len() {
+        3 => (fields[0], fields[1], fields[2]),
+        // the short form of mask and other entries omits the empty qualifier
+        2 => (fields[0], &b""[..], fields[1]),
+        _ => return None,
+    };
+
+    let perm = parse_perm(perm.trim_ascii())?;
+    let qualifier = qualifier.trim_ascii();
+    let (tag, id) = match (tag.trim_ascii(), qualifier.is_empty()) {
+        (b"user" | b"u", true) => (ACL_USER_OBJ, ACL_UNDEFINED_ID),
+        (b"user" | b"u", false) => (ACL_USER, parse_id(qualifier)?),
+        (b"group" | b"g", true) => (ACL_GROUP_OBJ, ACL_UNDEFINED_ID),
+        (b"group" | b"g", false) => (ACL_GROUP, parse_id(qualifier)?),
+        (b"mask" | b"m", true) => (ACL_MASK, ACL_UNDEFINED_ID),
+        (b"other" | b"o", true) => (ACL_OTHER, ACL_UNDEFINED_ID),
+        _ => return None,
+    };
+
+    Some(Entry { tag, id, perm })
+}
+
+fn format_id(buffer: &mut Vec<u8>, id: u32) {
+    let mut digits = [0u8; 10];
+    let mut value = id;
+    let mut len = 0;
+    loop {
+        digits[len] = b'0' + (value % 10) as u8;
+        value /= 10;
+        len += 1;
+        if value == 0 {
+            break;
+        }
+    }
+
+    buffer.extend(digits[..len].iter().rev());
+}
+
+pub(crate) unsafe fn acl_get_fd_impl(fd: int) -> *mut void {
+    loop {
+        let size = crate::internal::fgetxattr(fd, XATTR_NAME.as_ptr(), core::ptr::null_mut(), 0);
+        if size == -1 {
+            // a file without an extended ACL has no attribute, the ACL is derived from the mode
+            if *crate::internal::__errno_location() == crate::internal::ENODATA as int {
+                let mut attr = stat_t::new_zeroed();
+                if fstat(fd, &mut attr) == -1 {
+                    return core::ptr::null_mut();
+                }
+
+                let mode = attr.st_mode as u16;
+                let entries = [
+                    Entry {
+                        tag: ACL_USER_OBJ,
+                        id: ACL_UNDEFINED_ID,
+                        perm: (mode >> 6) & 0o7,
+                    },
+                    Entry {
+                        tag: ACL_GROUP_OBJ,
+                        id: ACL_UNDEFINED_ID,
+                        perm: (mode >> 3) & 0o7,
+                    },
+                    Entry {
+                        tag: ACL_OTHER,
+                        id: ACL_UNDEFINED_ID,
+                        perm: mode & 0o7,
+                    },
+                ];
+                return allocate_object(ACL_OBJECT_MAGIC, &encode(&entries), false).cast();
+            }
+
+            return core::ptr::null_mut();
+        }
+
+        let mut buffer = alloc::vec![0u8; size as usize];
+        let size = crate::internal::fgetxattr(
+            fd,
+            XATTR_NAME.as_ptr(),
+            buffer.as_mut_ptr().cast(),
+            buffer.len() as _,
+        );
+
+        if size == -1 {
+            // the ACL was enlarged concurrently
+            if Errno::get() == Errno::ERANGE {
+                continue;
+            }
+            return core::ptr::null_mut();
+        }
+
+        buffer.truncate(size as usize);
+        if decode(&buffer).is_none() {
+            Errno::set(Errno::EINVAL);
+            return core::ptr::null_mut();
+        }
+
+        return allocate_object(ACL_OBJECT_MAGIC, &buffer, false).cast();
+    }
+}
+
+pub(crate) unsafe fn acl_set_fd_impl(fd: int, acl: *mut void) -> int {
+    let entries = match acl_entries(acl) {
+        Some(entries) => entries,
+        None => {
+            Errno::set(Errno::EINVAL);
+            return -1;
+        }
+    };
+
+    let buffer = encode(&entries);
+    crate::internal::fsetxattr(
+        fd,
+        XATTR_NAME.as_ptr(),
+        buffer.as_ptr().cast(),
+        buffer.len() as _,
+        0,
+    )
+}
+
+pub(crate) unsafe fn acl_from_text_impl(buf_p: *const c_char) -> *mut void {
+    if buf_p.is_null() {
+        Errno::set(Errno::EINVAL);
+        return core::ptr::null_mut();
+    }
+
+    let text = core::ffi::CStr::from_ptr(buf_p).to_bytes();
+    let mut entries = Vec::new();
+    for line in text.split(|c| *c == b'\n') {
+        let line = match line.iter().position(|c| *c == b'#') {
+            Some(comment_start) => &line[..comment_start],
+            None => line,
+        };
+
+        for entry in line.split(|c| *c == b',') {
+            let entry = entry.trim_ascii();
+            if entry.is_empty() {
+                continue;
+            }
+
+            match parse_entry(entry) {
+                Some(entry) => entries.push(entry),
+                None => {
+                    Errno::set(Errno::EINVAL);
+                    return core::ptr::null_mut();
+                }
+            }
+        }
+    }
+
+    // the kernel requires the entries to be ordered by tag and id
+    entries.sort();
+    allocate_object(ACL_OBJECT_MAGIC, &encode(&entries), false).cast()
+}
+
+pub(crate) unsafe fn acl_to_text_impl(acl: *mut void, len_p: *mut ssize_t) -> *mut c_char {
+    let entries = match acl_entries(acl) {
+        Some(entries) => entries,
+        None => {
+            Errno::set(Errno::EINVAL);
+            return core::ptr::null_mut();
+        }
+    };
+
+    let mut text = Vec::new();
+    for entry in entries {
+        let tag: &[u8] = match entry.tag {
+            ACL_USER_OBJ | ACL_USER => b"user",
+            ACL_GROUP_OBJ | ACL_GROUP => b"group",
+            ACL_MASK => b"mask",
+            ACL_OTHER => b"other",
+            _ => {
+                Errno::set(Errno::EINVAL);
+                return core::ptr::null_mut();
+            }
+        };
+
+        text.extend_from_slice(tag);
+        text.push(b':');
+        if entry.tag == ACL_USER || entry.tag == ACL_GROUP {
+            format_id(&mut text, entry.id);
+        }
+        text.push(b':');
+        text.push(if entry.perm & ACL_READ != 0 {
+            b'r'
+        } else {
+            b'-'
+        });
+        text.push(if entry.perm & ACL_WRITE != 0 {
+            b'w'
+        } else {
+            b'-'
+        });
+        text.push(if entry.perm & ACL_EXECUTE != 0 {
+            b'x'
+        } else {
+            b'-'
+        });
+        text.push(b'\n');
+    }
+
+    if !len_p.is_null() {
+        *len_p = text.len() as ssize_t;
+    }
+
+    allocate_object(TEXT_OBJECT_MAGIC, &text, true).cast()
+}
+
+pub(crate) unsafe fn acl_free_impl(obj_p: *mut void) -> int {
+    if obj_p.is_null() {
+        Errno::set(Errno::EINVAL);
+        return -1;
+    }
+
+    let header = object_header(obj_p.cast());
+    let magic = (*header).magic;
+    if magic != ACL_OBJECT_MAGIC && magic != TEXT_OBJECT_MAGIC {
+        Errno::set(Errno::EINVAL);
+        return -1;
+    }
+
+    let layout =
+        Layout::from_size_align_unchecked((*header).size, core::mem::align_of::<ObjectHeader>());
+    (*header).magic = 0;
+    alloc::alloc::dealloc(header.cast(), layout);
+    0
+}
@@ -14,3 +14,8 @@ pub trait SockAddrIn {
     fn set_s_addr(&mut self, value: u32);
     fn get_s_addr(&self) -> u32;
 }
+
+pub trait IpMreq {
+    fn set_multiaddr(&mut self, value: u32);
+    fn set_interface(&mut self, value: u32);
+}
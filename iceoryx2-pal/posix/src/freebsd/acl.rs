@@ -0,0 +1,38 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types, non_snake_case)]
+#![allow(clippy::missing_safety_doc)]
+
+use crate::posix::types::*;
+
+pub unsafe fn acl_get_fd(fd: int) -> acl_t {
+    // acl_get_fd would return the NFSv4 ACL on file systems like ZFS
+    crate::internal::acl_get_fd_np(fd, crate::internal::ACL_TYPE_ACCESS as _)
+}
+
+pub unsafe fn acl_set_fd(fd: int, acl: acl_t) -> int {
+    crate::internal::acl_set_fd_np(fd, acl, crate::internal::ACL_TYPE_ACCESS as _)
+}
+
+pub unsafe fn acl_from_text(buf_p: *const c_char) -> acl_t {
+    crate::internal::acl_from_text(buf_p)
+}
+
+pub unsafe fn acl_to_text(acl: acl_t, len_p: *mut ssize_t) -> *mut c_char {
+    // user and group names are not stable across systems, the ids are
+    crate::internal::acl_to_text_np(acl, len_p, crate::internal::ACL_TEXT_NUMERIC_IDS as _)
+}
+
+pub unsafe fn acl_free(obj_p: *mut void) -> int {
+    crate::internal::acl_free(obj_p)
+}
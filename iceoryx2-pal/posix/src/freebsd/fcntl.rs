@@ -43,6 +43,10 @@ pub unsafe fn fcntl2(fd: int, cmd: int) -> int {
     crate::internal::fcntl(fd, cmd)
 }
 
+pub unsafe fn posix_fallocate(fd: int, offset: off_t, len: off_t) -> int {
+    crate::internal::posix_fallocate(fd, offset, len)
+}
+
 pub unsafe fn fchmod(fd: int, mode: mode_t) -> int {
     crate::internal::fchmod(fd, mode)
 }
@@ -192,3 +192,8 @@ pub unsafe fn shm_list() -> Vec<[i8; 256]> {
     closedir(dir);
     result
 }
+
+pub unsafe fn memfd_create(_name: *const c_char, _flags: uint) -> int {
+    Errno::set(Errno::ENOSYS);
+    -1
+}
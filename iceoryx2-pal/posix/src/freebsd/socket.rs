@@ -14,7 +14,10 @@
 #![allow(clippy::missing_safety_doc)]
 
 use crate::posix::types::*;
-use crate::posix::Errno;
+use crate::posix::{
+    Errno, MemZeroedStruct, CMSG_DATA, CMSG_FIRSTHDR, CMSG_NXTHDR, SCM_CREDENTIALS, SOL_SOCKET,
+    SO_PASSCRED, SO_PEERCRED,
+};
 
 pub unsafe fn socketpair(
     domain: int,
@@ -32,6 +35,18 @@ pub unsafe fn setsockopt(
     option_value: *const void,
     option_len: socklen_t,
 ) -> int {
+    if level == SOL_SOCKET && option_name == SO_PASSCRED {
+        // the kernel attaches the credentials of the sender to every received message,
+        // they are translated into SCM_CREDENTIALS in recvmsg
+        return crate::internal::setsockopt(
+            socket,
+            crate::internal::SOL_LOCAL as _,
+            crate::internal::LOCAL_CREDS_PERSISTENT as _,
+            option_value,
+            option_len,
+        );
+    }
+
     crate::internal::setsockopt(socket, level, option_name, option_value, option_len)
 }
 
@@ -39,6 +54,10 @@ pub unsafe fn getsockname(socket: int, address: *mut sockaddr, address_len: *mut
     crate::internal::getsockname(socket, address, address_len)
 }
 
+pub unsafe fn getpeername(socket: int, address: *mut sockaddr, address_len: *mut socklen_t) -> int {
+    crate::internal::getpeername(socket, address, address_len)
+}
+
 pub unsafe fn getsockopt(
     socket: int,
     level: int,
@@ -46,9 +65,47 @@ pub unsafe fn getsockopt(
     option_value: *mut void,
     option_len: *mut socklen_t,
 ) -> int {
+    if level == SOL_SOCKET && option_name == SO_PEERCRED {
+        return peer_credentials(socket, option_value, option_len);
+    }
+
     crate::internal::getsockopt(socket, level, option_name, option_value, option_len)
 }
 
+unsafe fn peer_credentials(
+    socket: int,
+    option_value: *mut void,
+    option_len: *mut socklen_t,
+) -> int {
+    if (*option_len as usize) < core::mem::size_of::<ucred>() {
+        Errno::set(Errno::EINVAL);
+        return -1;
+    }
+
+    let mut cred = native_xucred::new_zeroed();
+    let mut cred_len = core::mem::size_of::<native_xucred>() as socklen_t;
+    if crate::internal::getsockopt(
+        socket,
+        crate::internal::SOL_LOCAL as _,
+        crate::internal::LOCAL_PEERCRED as _,
+        (&mut cred as *mut native_xucred).cast(),
+        &mut cred_len,
+    ) == -1
+    {
+        return -1;
+    }
+
+    option_value.cast::<ucred>().write_unaligned(ucred {
+        pid: cred.__bindgen_anon_1.cr_pid,
+        uid: cred.cr_uid,
+        euid: cred.cr_uid,
+        gid: cred.cr_groups[0],
+    });
+    *option_len = core::mem::size_of::<ucred>() as socklen_t;
+
+    0
+}
+
 pub unsafe fn bind(socket: int, address: *const sockaddr, address_len: socklen_t) -> int {
     crate::internal::bind(socket, address, address_len)
 }
@@ -87,7 +144,36 @@ pub unsafe fn send(socket: int, message: *const void, length: size_t, flags: int
 }
 
 pub unsafe fn recvmsg(socket: int, message: *mut msghdr, flags: int) -> ssize_t {
-    crate::internal::recvmsg(socket, message, flags)
+    let result = crate::internal::recvmsg(socket, message, flags);
+
+    if result != -1 {
+        translate_persistent_credentials(message);
+    }
+
+    result
+}
+
+// Rewrites the SCM_CREDS2 messages that are attached due to LOCAL_CREDS_PERSISTENT into the
+// ucred layout of SCM_CREDENTIALS. sockcred2 is larger than ucred, therefore the data fits
+// into the received control message.
+unsafe fn translate_persistent_credentials(message: *mut msghdr) {
+    let mut cmsg = CMSG_FIRSTHDR(message);
+    while !cmsg.is_null() {
+        if (*cmsg).cmsg_level == SOL_SOCKET
+            && (*cmsg).cmsg_type == crate::internal::SCM_CREDS2 as int
+        {
+            let data = CMSG_DATA(cmsg);
+            let persistent = data.cast::<crate::internal::sockcred2>().read_unaligned();
+            data.cast::<ucred>().write_unaligned(ucred {
+                pid: persistent.sc_pid,
+                uid: persistent.sc_uid,
+                euid: persistent.sc_euid,
+                gid: persistent.sc_gid,
+            });
+            (*cmsg).cmsg_type = SCM_CREDENTIALS;
+        }
+        cmsg = CMSG_NXTHDR(message, cmsg);
+    }
 }
 
 pub unsafe fn recvfrom(
@@ -104,3 +190,15 @@ pub unsafe fn recvfrom(
 pub unsafe fn recv(socket: int, buffer: *mut void, length: size_t, flags: int) -> ssize_t {
     crate::internal::recv(socket, buffer, length, flags)
 }
+
+pub unsafe fn listen(socket: int, backlog: int) -> int {
+    crate::internal::listen(socket, backlog)
+}
+
+pub unsafe fn accept(socket: int, address: *mut sockaddr, address_len: *mut socklen_t) -> int {
+    crate::internal::accept(socket, address, address_len)
+}
+
+pub unsafe fn shutdown(socket: int, how: int) -> int {
+    crate::internal::shutdown(socket, how)
+}
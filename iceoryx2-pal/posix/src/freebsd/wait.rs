@@ -0,0 +1,38 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_snake_case)]
+#![allow(clippy::missing_safety_doc)]
+
+use crate::posix::types::*;
+
+pub unsafe fn waitpid(pid: pid_t, status: *mut int, options: int) -> pid_t {
+    crate::internal::waitpid(pid, status, options)
+}
+
+// the status macros are not part of the generated bindings, all supported platforms use the
+// traditional layout: the lower 7 bits contain the signal, the next 8 bits the exit code
+pub fn WIFEXITED(status: int) -> bool {
+    WTERMSIG(status) == 0
+}
+
+pub fn WEXITSTATUS(status: int) -> int {
+    (status >> 8) & 0xff
+}
+
+pub fn WIFSIGNALED(status: int) -> bool {
+    WTERMSIG(status) != 0 && WTERMSIG(status) != 0x7f
+}
+
+pub fn WTERMSIG(status: int) -> int {
+    status & 0x7f
+}
@@ -69,7 +69,7 @@ pub mod posix {
 
     pub use common::cpu_set_t::cpu_set_t;
     pub use common::mem_zeroed_struct::MemZeroedStruct;
-    pub use common::sockaddr_in::SockAddrIn;
+    pub use common::sockaddr_in::{IpMreq, SockAddrIn};
 
     #[allow(unused_imports)]
     pub(crate) use common::string_operations::*;
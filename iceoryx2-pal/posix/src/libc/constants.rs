@@ -48,6 +48,7 @@ pub const F_SETFL: int = libc::F_SETFL as _;
 pub const F_GETLK: int = libc::F_GETLK as _;
 pub const F_SETLK: int = libc::F_SETLK as _;
 pub const F_SETLKW: int = libc::F_SETLKW as _;
+pub const FD_CLOEXEC: int = libc::FD_CLOEXEC as _;
 
 pub const PROT_NONE: int = libc::PROT_NONE as _;
 pub const PROT_READ: int = libc::PROT_READ as _;
@@ -57,11 +58,28 @@ pub const MCL_CURRENT: int = libc::MCL_CURRENT as _;
 pub const MCL_FUTURE: int = libc::MCL_FUTURE as _;
 pub const MAP_SHARED: int = libc::MAP_SHARED as _;
 pub const MAP_FAILED: *mut void = u64::MAX as *mut void;
+pub const MFD_CLOEXEC: uint = libc::MFD_CLOEXEC as _;
+pub const MFD_ALLOW_SEALING: uint = libc::MFD_ALLOW_SEALING as _;
+pub const F_ADD_SEALS: int = libc::F_ADD_SEALS as _;
+pub const F_GET_SEALS: int = libc::F_GET_SEALS as _;
+pub const F_SEAL_SEAL: int = libc::F_SEAL_SEAL as _;
+pub const F_SEAL_SHRINK: int = libc::F_SEAL_SHRINK as _;
+pub const F_SEAL_GROW: int = libc::F_SEAL_GROW as _;
+pub const F_SEAL_WRITE: int = libc::F_SEAL_WRITE as _;
+
+pub const POSIX_SPAWN_SETSIGDEF: short = libc::POSIX_SPAWN_SETSIGDEF as _;
+pub const POSIX_SPAWN_SETSIGMASK: short = libc::POSIX_SPAWN_SETSIGMASK as _;
+pub const WNOHANG: int = libc::WNOHANG as _;
 
 pub const PTHREAD_BARRIER_SERIAL_THREAD: int = libc::PTHREAD_BARRIER_SERIAL_THREAD as _;
 pub const PTHREAD_EXPLICIT_SCHED: int = libc::PTHREAD_EXPLICIT_SCHED as _;
 pub const PTHREAD_INHERIT_SCHED: int = libc::PTHREAD_INHERIT_SCHED as _;
 
+// not provided by the libc crate, the values correspond to the glibc definitions
+pub const PTHREAD_PREFER_READER_NP: int = 0;
+pub const PTHREAD_PREFER_WRITER_NP: int = 1;
+pub const PTHREAD_PREFER_WRITER_NONRECURSIVE_NP: int = 2;
+
 pub const MAX_SIGNAL_VALUE: usize = 32;
 
 pub const SO_PASSCRED: int = libc::SO_PASSCRED as _;
@@ -167,6 +185,7 @@ pub const SIG_ERR: sighandler_t = sighandler_t::MAX;
 pub const SIG_DFL: int = 0;
 pub const SIG_IGN: int = 1;
 pub const SA_RESTART: int = libc::SA_RESTART as _;
+pub const SA_SIGINFO: int = libc::SA_SIGINFO as _;
 
 pub const AF_LOCAL: sa_family_t = libc::AF_UNIX as _;
 pub const AF_UNIX: sa_family_t = libc::AF_UNIX as _;
@@ -182,8 +201,22 @@ pub const SO_SNDTIMEO: int = libc::SO_SNDTIMEO as _;
 pub const SOCK_STREAM: int = libc::SOCK_STREAM as _;
 pub const SOCK_DGRAM: int = libc::SOCK_DGRAM as _;
 pub const IPPROTO_UDP: int = libc::IPPROTO_UDP as _;
+pub const IPPROTO_IP: int = libc::IPPROTO_IP as _;
+pub const IPPROTO_TCP: int = libc::IPPROTO_TCP as _;
+pub const TCP_NODELAY: int = libc::TCP_NODELAY as _;
+pub const IP_ADD_MEMBERSHIP: int = libc::IP_ADD_MEMBERSHIP as _;
+pub const IP_MULTICAST_IF: int = libc::IP_MULTICAST_IF as _;
+pub const SO_BROADCAST: int = libc::SO_BROADCAST as _;
+pub const SO_KEEPALIVE: int = libc::SO_KEEPALIVE as _;
+pub const SO_ERROR: int = libc::SO_ERROR as _;
+pub const SO_REUSEADDR: int = libc::SO_REUSEADDR as _;
 pub const SOCK_NONBLOCK: int = O_NONBLOCK;
 pub const MSG_PEEK: int = libc::MSG_PEEK as _;
+pub const MSG_CTRUNC: int = libc::MSG_CTRUNC as _;
+pub const MSG_NOSIGNAL: int = libc::MSG_NOSIGNAL as _;
+pub const SHUT_RD: int = libc::SHUT_RD as _;
+pub const SHUT_WR: int = libc::SHUT_WR as _;
+pub const SHUT_RDWR: int = libc::SHUT_RDWR as _;
 pub const SCM_MAX_FD: u32 = 253;
 pub const SCM_RIGHTS: int = libc::SCM_RIGHTS as _;
 pub const SOL_SOCKET: int = libc::SOL_SOCKET as _;
@@ -198,6 +231,15 @@ pub const S_IFBLK: mode_t = libc::S_IFBLK as _;
 pub const S_IFDIR: mode_t = libc::S_IFDIR as _;
 pub const S_IFCHR: mode_t = libc::S_IFCHR as _;
 pub const S_IFIFO: mode_t = libc::S_IFIFO as _;
+
+pub const DT_UNKNOWN: uchar = libc::DT_UNKNOWN as _;
+pub const DT_FIFO: uchar = libc::DT_FIFO as _;
+pub const DT_CHR: uchar = libc::DT_CHR as _;
+pub const DT_DIR: uchar = libc::DT_DIR as _;
+pub const DT_BLK: uchar = libc::DT_BLK as _;
+pub const DT_REG: uchar = libc::DT_REG as _;
+pub const DT_LNK: uchar = libc::DT_LNK as _;
+pub const DT_SOCK: uchar = libc::DT_SOCK as _;
 pub const S_IRWXU: mode_t = libc::S_IRWXU as _;
 pub const S_IXUSR: mode_t = libc::S_IXUSR as _;
 pub const S_IWUSR: mode_t = libc::S_IWUSR as _;
@@ -216,6 +258,10 @@ pub const S_ISVTX: mode_t = libc::S_ISVTX as _;
 
 pub const CLOCK_REALTIME: clockid_t = libc::CLOCK_REALTIME as _;
 pub const CLOCK_MONOTONIC: clockid_t = libc::CLOCK_MONOTONIC as _;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub const CLOCK_BOOTTIME: clockid_t = libc::CLOCK_BOOTTIME as _;
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+pub const CLOCK_BOOTTIME: clockid_t = CLOCK_MONOTONIC;
 pub const CLOCK_TIMER_ABSTIME: int = 1;
 
 pub const F_OK: int = libc::F_OK as _;
@@ -94,3 +94,7 @@ pub unsafe fn munmap(addr: *mut void, len: size_t) -> int {
 pub unsafe fn mprotect(addr: *mut void, len: size_t, prot: int) -> int {
     libc::mprotect(addr, len, prot)
 }
+
+pub unsafe fn memfd_create(name: *const c_char, flags: uint) -> int {
+    libc::memfd_create(name, flags)
+}
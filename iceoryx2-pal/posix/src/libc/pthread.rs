@@ -120,6 +120,14 @@ pub unsafe fn pthread_kill(thread: pthread_t, sig: int) -> int {
     libc::pthread_kill(thread, sig)
 }
 
+pub unsafe fn pthread_getschedparam(
+    thread: pthread_t,
+    policy: *mut int,
+    param: *mut sched_param,
+) -> int {
+    libc::pthread_getschedparam(thread, policy, param)
+}
+
 pub unsafe fn pthread_setaffinity_np(
     thread: pthread_t,
     cpusetsize: size_t,
@@ -187,6 +195,34 @@ pub unsafe fn pthread_rwlock_trywrlock(lock: *mut pthread_rwlock_t) -> int {
     libc::pthread_rwlock_trywrlock(lock)
 }
 
+// not exposed by the libc crate
+extern "C" {
+    #[link_name = "pthread_rwlock_timedrdlock"]
+    fn libc_pthread_rwlock_timedrdlock(
+        lock: *mut pthread_rwlock_t,
+        abs_timeout: *const timespec,
+    ) -> int;
+    #[link_name = "pthread_rwlock_timedwrlock"]
+    fn libc_pthread_rwlock_timedwrlock(
+        lock: *mut pthread_rwlock_t,
+        abs_timeout: *const timespec,
+    ) -> int;
+}
+
+pub unsafe fn pthread_rwlock_timedrdlock(
+    lock: *mut pthread_rwlock_t,
+    abs_timeout: *const timespec,
+) -> int {
+    libc_pthread_rwlock_timedrdlock(lock, abs_timeout)
+}
+
+pub unsafe fn pthread_rwlock_timedwrlock(
+    lock: *mut pthread_rwlock_t,
+    abs_timeout: *const timespec,
+) -> int {
+    libc_pthread_rwlock_timedwrlock(lock, abs_timeout)
+}
+
 pub unsafe fn pthread_mutex_init(
     mtx: *mut pthread_mutex_t,
     attr: *const pthread_mutexattr_t,
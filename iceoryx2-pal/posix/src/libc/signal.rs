@@ -56,6 +56,25 @@ impl core::fmt::Debug for sigaction_t {
     }
 }
 
+#[repr(transparent)]
+pub struct siginfo_t(libc::siginfo_t);
+
+impl siginfo_t {
+    pub fn signo(&self) -> int {
+        self.0.si_signo
+    }
+
+    #[cfg(target_os = "macos")]
+    pub fn pid(&self) -> pid_t {
+        self.0.si_pid
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    pub fn pid(&self) -> pid_t {
+        unsafe { self.0.si_pid() }
+    }
+}
+
 pub unsafe fn sigaction(sig: int, act: &sigaction_t, oact: &mut sigaction_t) -> int {
     libc::sigaction(sig, &act.0, &mut oact.0)
 }
@@ -30,3 +30,7 @@ pub unsafe fn realloc(ptr: *mut void, size: size_t) -> *mut void {
 pub unsafe fn free(ptr: *mut void) {
     libc::free(ptr)
 }
+
+pub unsafe fn realpath(path: *const c_char, resolved_path: *mut c_char) -> *mut c_char {
+    libc::realpath(path, resolved_path)
+}
@@ -10,10 +10,12 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-pub const POSIX_SUPPORT_ACL: bool = true;
+pub const POSIX_SUPPORT_ACL: bool = cfg!(target_os = "linux");
 pub const POSIX_SUPPORT_NAMED_SEMAPHORE: bool = true;
 pub const POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY: bool = true;
 pub const POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS_ANCILLARY_DATA: bool = true;
+pub const POSIX_SUPPORT_ABSTRACT_UNIX_DOMAIN_SOCKETS: bool =
+    cfg!(any(target_os = "linux", target_os = "android"));
 pub const POSIX_SUPPORT_CPU_AFFINITY: bool = true;
 pub const POSIX_SUPPORT_USERS_AND_GROUPS: bool = true;
 pub const POSIX_SUPPORT_PERMISSIONS: bool = true;
@@ -23,3 +25,7 @@ pub const POSIX_SUPPORT_MESSAGE_QUEUE: bool = true;
 pub const POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING: bool = true;
 pub const POSIX_SUPPORT_CONSOLE_SIGNAL_HANDLING: bool = true;
 pub const POSIX_SUPPORT_SCHEDULER: bool = true;
+pub const POSIX_SUPPORT_HUGE_PAGES: bool = true;
+pub const POSIX_SUPPORT_ANONYMOUS_SHARED_MEMORY: bool = true;
+pub const POSIX_SUPPORT_BOOTTIME_CLOCK: bool =
+    cfg!(any(target_os = "linux", target_os = "android"));
@@ -14,7 +14,7 @@
 #![allow(clippy::missing_safety_doc)]
 
 use crate::common::mem_zeroed_struct::MemZeroedStruct;
-use crate::posix::SockAddrIn;
+use crate::posix::{IpMreq, SockAddrIn};
 pub type ulong = libc::c_ulong;
 
 #[repr(C)]
@@ -58,6 +58,7 @@ pub type uid_t = libc::uid_t;
 pub type uint = libc::c_uint;
 pub type ushort = libc::c_ushort;
 pub type void = core::ffi::c_void;
+pub type acl_t = *mut void;
 
 pub(crate) type native_cpu_set_t = libc::cpu_set_t;
 impl MemZeroedStruct for native_cpu_set_t {}
@@ -65,6 +66,12 @@ impl MemZeroedStruct for native_cpu_set_t {}
 pub type sigset_t = libc::sigset_t;
 impl MemZeroedStruct for sigset_t {}
 
+pub type posix_spawn_file_actions_t = libc::posix_spawn_file_actions_t;
+impl MemZeroedStruct for posix_spawn_file_actions_t {}
+
+pub type posix_spawnattr_t = libc::posix_spawnattr_t;
+impl MemZeroedStruct for posix_spawnattr_t {}
+
 pub type pthread_barrier_t = libc::pthread_barrier_t;
 impl MemZeroedStruct for pthread_barrier_t {}
 
@@ -194,6 +201,19 @@ impl SockAddrIn for sockaddr_in {
     }
 }
 
+pub type ip_mreq = libc::ip_mreq;
+impl MemZeroedStruct for ip_mreq {}
+
+impl IpMreq for ip_mreq {
+    fn set_multiaddr(&mut self, value: u32) {
+        self.imr_multiaddr.s_addr = value;
+    }
+
+    fn set_interface(&mut self, value: u32) {
+        self.imr_interface.s_addr = value;
+    }
+}
+
 pub type passwd = libc::passwd;
 impl MemZeroedStruct for passwd {}
 
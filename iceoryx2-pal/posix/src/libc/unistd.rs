@@ -57,6 +57,14 @@ pub unsafe fn write(fd: int, buf: *const void, count: size_t) -> ssize_t {
     libc::write(fd, buf, count)
 }
 
+pub unsafe fn pread(fd: int, buf: *mut void, count: size_t, offset: off_t) -> ssize_t {
+    libc::pread(fd, buf, count, offset)
+}
+
+pub unsafe fn pwrite(fd: int, buf: *const void, count: size_t, offset: off_t) -> ssize_t {
+    libc::pwrite(fd, buf, count, offset)
+}
+
 pub unsafe fn access(pathname: *const c_char, mode: int) -> int {
     libc::access(pathname, mode)
 }
@@ -77,6 +85,10 @@ pub unsafe fn getgid() -> gid_t {
     libc::getgid()
 }
 
+pub unsafe fn getgroups(size: int, list: *mut gid_t) -> int {
+    libc::getgroups(size, list)
+}
+
 pub unsafe fn rmdir(pathname: *const c_char) -> int {
     libc::rmdir(pathname)
 }
@@ -0,0 +1,36 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_snake_case)]
+#![allow(clippy::missing_safety_doc)]
+
+use crate::posix::types::*;
+
+pub unsafe fn waitpid(pid: pid_t, status: *mut int, options: int) -> pid_t {
+    libc::waitpid(pid, status, options)
+}
+
+pub fn WIFEXITED(status: int) -> bool {
+    libc::WIFEXITED(status)
+}
+
+pub fn WEXITSTATUS(status: int) -> int {
+    libc::WEXITSTATUS(status)
+}
+
+pub fn WIFSIGNALED(status: int) -> bool {
+    libc::WIFSIGNALED(status)
+}
+
+pub fn WTERMSIG(status: int) -> int {
+    libc::WTERMSIG(status)
+}
@@ -43,6 +43,7 @@ pub const F_SETFL: int = crate::internal::F_SETFL as _;
 pub const F_GETLK: int = crate::internal::F_GETLK as _;
 pub const F_SETLK: int = crate::internal::F_SETLK as _;
 pub const F_SETLKW: int = crate::internal::F_SETLKW as _;
+pub const FD_CLOEXEC: int = crate::internal::FD_CLOEXEC as _;
 
 pub const PROT_NONE: int = crate::internal::PROT_NONE as _;
 pub const PROT_READ: int = crate::internal::PROT_READ as _;
@@ -52,11 +53,28 @@ pub const MCL_CURRENT: int = crate::internal::MCL_CURRENT as _;
 pub const MCL_FUTURE: int = crate::internal::MCL_FUTURE as _;
 pub const MAP_SHARED: int = crate::internal::MAP_SHARED as _;
 pub const MAP_FAILED: *mut void = u64::MAX as *mut void;
+pub const MFD_CLOEXEC: uint = 1;
+pub const MFD_ALLOW_SEALING: uint = 2;
+pub const F_ADD_SEALS: int = 0x409;
+pub const F_GET_SEALS: int = 0x40a;
+pub const F_SEAL_SEAL: int = 1;
+pub const F_SEAL_SHRINK: int = 2;
+pub const F_SEAL_GROW: int = 4;
+pub const F_SEAL_WRITE: int = 8;
+
+pub const POSIX_SPAWN_SETSIGDEF: short = crate::internal::POSIX_SPAWN_SETSIGDEF as _;
+pub const POSIX_SPAWN_SETSIGMASK: short = crate::internal::POSIX_SPAWN_SETSIGMASK as _;
+pub const WNOHANG: int = crate::internal::WNOHANG as _;
 
 pub const PTHREAD_BARRIER_SERIAL_THREAD: int = crate::internal::PTHREAD_BARRIER_SERIAL_THREAD as _;
 pub const PTHREAD_EXPLICIT_SCHED: int = crate::internal::PTHREAD_EXPLICIT_SCHED as _;
 pub const PTHREAD_INHERIT_SCHED: int = crate::internal::PTHREAD_INHERIT_SCHED as _;
 
+pub const PTHREAD_PREFER_READER_NP: int = crate::internal::PTHREAD_RWLOCK_PREFER_READER_NP as _;
+pub const PTHREAD_PREFER_WRITER_NP: int = crate::internal::PTHREAD_RWLOCK_PREFER_WRITER_NP as _;
+pub const PTHREAD_PREFER_WRITER_NONRECURSIVE_NP: int =
+    crate::internal::PTHREAD_RWLOCK_PREFER_WRITER_NONRECURSIVE_NP as _;
+
 pub const MAX_SIGNAL_VALUE: usize = 32;
 
 pub const SO_PASSCRED: int = crate::internal::SO_PASSCRED as _;
@@ -164,6 +182,7 @@ pub const SIG_ERR: sighandler_t = sighandler_t::MAX;
 pub const SIG_DFL: int = 0;
 pub const SIG_IGN: int = 1;
 pub const SA_RESTART: int = crate::internal::SA_RESTART as _;
+pub const SA_SIGINFO: int = crate::internal::SA_SIGINFO as _;
 
 pub const AF_LOCAL: sa_family_t = crate::internal::AF_UNIX as _;
 pub const AF_UNIX: sa_family_t = crate::internal::AF_UNIX as _;
@@ -185,8 +204,22 @@ pub const SO_SNDTIMEO: int = crate::internal::SO_SNDTIMEO as _;
 pub const SOCK_STREAM: int = crate::internal::__socket_type_SOCK_STREAM as _;
 pub const SOCK_DGRAM: int = crate::internal::__socket_type_SOCK_DGRAM as _;
 pub const IPPROTO_UDP: int = crate::internal::IPPROTO_UDP as _;
+pub const IPPROTO_IP: int = crate::internal::IPPROTO_IP as _;
+pub const IPPROTO_TCP: int = crate::internal::IPPROTO_TCP as _;
+pub const TCP_NODELAY: int = crate::internal::TCP_NODELAY as _;
+pub const IP_ADD_MEMBERSHIP: int = crate::internal::IP_ADD_MEMBERSHIP as _;
+pub const IP_MULTICAST_IF: int = crate::internal::IP_MULTICAST_IF as _;
+pub const SO_BROADCAST: int = crate::internal::SO_BROADCAST as _;
+pub const SO_KEEPALIVE: int = crate::internal::SO_KEEPALIVE as _;
+pub const SO_ERROR: int = crate::internal::SO_ERROR as _;
+pub const SO_REUSEADDR: int = crate::internal::SO_REUSEADDR as _;
 pub const SOCK_NONBLOCK: int = O_NONBLOCK;
 pub const MSG_PEEK: int = crate::internal::MSG_PEEK as _;
+pub const MSG_CTRUNC: int = crate::internal::MSG_CTRUNC as _;
+pub const MSG_NOSIGNAL: int = crate::internal::MSG_NOSIGNAL as _;
+pub const SHUT_RD: int = crate::internal::SHUT_RD as _;
+pub const SHUT_WR: int = crate::internal::SHUT_WR as _;
+pub const SHUT_RDWR: int = crate::internal::SHUT_RDWR as _;
 pub const SCM_MAX_FD: u32 = 253;
 pub const SCM_RIGHTS: int = crate::internal::SCM_RIGHTS as _;
 pub const SOL_SOCKET: int = crate::internal::SOL_SOCKET as _;
@@ -201,6 +234,15 @@ pub const S_IFBLK: mode_t = crate::internal::S_IFBLK as _;
 pub const S_IFDIR: mode_t = crate::internal::S_IFDIR as _;
 pub const S_IFCHR: mode_t = crate::internal::S_IFCHR as _;
 pub const S_IFIFO: mode_t = crate::internal::S_IFIFO as _;
+
+pub const DT_UNKNOWN: uchar = crate::internal::DT_UNKNOWN as _;
+pub const DT_FIFO: uchar = crate::internal::DT_FIFO as _;
+pub const DT_CHR: uchar = crate::internal::DT_CHR as _;
+pub const DT_DIR: uchar = crate::internal::DT_DIR as _;
+pub const DT_BLK: uchar = crate::internal::DT_BLK as _;
+pub const DT_REG: uchar = crate::internal::DT_REG as _;
+pub const DT_LNK: uchar = crate::internal::DT_LNK as _;
+pub const DT_SOCK: uchar = crate::internal::DT_SOCK as _;
 pub const S_IRWXU: mode_t = crate::internal::S_IRWXU as _;
 pub const S_IXUSR: mode_t = crate::internal::S_IXUSR as _;
 pub const S_IWUSR: mode_t = crate::internal::S_IWUSR as _;
@@ -219,6 +261,7 @@ pub const S_ISVTX: mode_t = crate::internal::S_ISVTX as _;
 
 pub const CLOCK_REALTIME: clockid_t = crate::internal::CLOCK_REALTIME as _;
 pub const CLOCK_MONOTONIC: clockid_t = crate::internal::CLOCK_MONOTONIC as _;
+pub const CLOCK_BOOTTIME: clockid_t = crate::internal::CLOCK_BOOTTIME as _;
 pub const CLOCK_TIMER_ABSTIME: int = 1;
 
 pub const F_OK: int = crate::internal::F_OK as _;
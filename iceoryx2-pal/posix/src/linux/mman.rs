@@ -96,3 +96,17 @@ pub unsafe fn munmap(addr: *mut void, len: size_t) -> int {
 pub unsafe fn mprotect(addr: *mut void, len: size_t, prot: int) -> int {
     crate::internal::mprotect(addr, len, prot)
 }
+
+pub unsafe fn memfd_create(name: *const c_char, flags: uint) -> int {
+    internal::memfd_create(name, flags)
+}
+
+mod internal {
+    use super::*;
+
+    // requires _GNU_SOURCE and is therefore not part of the generated bindings
+    #[cfg_attr(target_os = "linux", link(name = "c"))]
+    extern "C" {
+        pub(super) fn memfd_create(name: *const c_char, flags: uint) -> int;
+    }
+}
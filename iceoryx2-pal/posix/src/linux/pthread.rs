@@ -123,6 +123,14 @@ pub unsafe fn pthread_kill(thread: pthread_t, sig: int) -> int {
     internal::pthread_kill(thread, sig)
 }
 
+pub unsafe fn pthread_getschedparam(
+    thread: pthread_t,
+    policy: *mut int,
+    param: *mut sched_param,
+) -> int {
+    internal::pthread_getschedparam(thread, policy, param)
+}
+
 pub unsafe fn pthread_setaffinity_np(
     thread: pthread_t,
     cpusetsize: size_t,
@@ -190,6 +198,20 @@ pub unsafe fn pthread_rwlock_trywrlock(lock: *mut pthread_rwlock_t) -> int {
     crate::internal::pthread_rwlock_trywrlock(lock)
 }
 
+pub unsafe fn pthread_rwlock_timedrdlock(
+    lock: *mut pthread_rwlock_t,
+    abs_timeout: *const timespec,
+) -> int {
+    crate::internal::pthread_rwlock_timedrdlock(lock, abs_timeout)
+}
+
+pub unsafe fn pthread_rwlock_timedwrlock(
+    lock: *mut pthread_rwlock_t,
+    abs_timeout: *const timespec,
+) -> int {
+    crate::internal::pthread_rwlock_timedwrlock(lock, abs_timeout)
+}
+
 pub unsafe fn pthread_mutex_init(
     mtx: *mut pthread_mutex_t,
     attr: *const pthread_mutexattr_t,
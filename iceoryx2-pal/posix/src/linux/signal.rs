@@ -55,6 +55,19 @@ impl core::fmt::Debug for sigaction_t {
     }
 }
 
+#[repr(transparent)]
+pub struct siginfo_t(crate::internal::siginfo_t);
+
+impl siginfo_t {
+    pub fn signo(&self) -> int {
+        self.0.si_signo
+    }
+
+    pub fn pid(&self) -> pid_t {
+        unsafe { self.0._sifields._kill.si_pid }
+    }
+}
+
 pub unsafe fn sigaction(sig: int, act: &sigaction_t, oact: &mut sigaction_t) -> int {
     crate::internal::sigaction(sig, &act.0, &mut oact.0)
 }
@@ -14,7 +14,7 @@
 #![allow(clippy::missing_safety_doc)]
 
 use crate::common::mem_zeroed_struct::MemZeroedStruct;
-use crate::posix::SockAddrIn;
+use crate::posix::{IpMreq, SockAddrIn};
 
 pub type ulong = crate::internal::ulong;
 
@@ -59,6 +59,7 @@ pub type uid_t = crate::internal::uid_t;
 pub type uint = crate::internal::uint;
 pub type ushort = crate::internal::ushort;
 pub type void = core::ffi::c_void;
+pub type acl_t = *mut void;
 
 pub(crate) type native_cpu_set_t = crate::internal::cpu_set_t;
 impl MemZeroedStruct for native_cpu_set_t {}
@@ -66,6 +67,12 @@ impl MemZeroedStruct for native_cpu_set_t {}
 pub type sigset_t = crate::internal::sigset_t;
 impl MemZeroedStruct for sigset_t {}
 
+pub type posix_spawn_file_actions_t = crate::internal::posix_spawn_file_actions_t;
+impl MemZeroedStruct for posix_spawn_file_actions_t {}
+
+pub type posix_spawnattr_t = crate::internal::posix_spawnattr_t;
+impl MemZeroedStruct for posix_spawnattr_t {}
+
 pub type pthread_barrier_t = crate::internal::pthread_barrier_t;
 impl MemZeroedStruct for pthread_barrier_t {}
 
@@ -195,6 +202,19 @@ impl SockAddrIn for sockaddr_in {
     }
 }
 
+pub type ip_mreq = crate::internal::ip_mreq;
+impl MemZeroedStruct for ip_mreq {}
+
+impl IpMreq for ip_mreq {
+    fn set_multiaddr(&mut self, value: u32) {
+        self.imr_multiaddr.s_addr = value;
+    }
+
+    fn set_interface(&mut self, value: u32) {
+        self.imr_interface.s_addr = value;
+    }
+}
+
 pub type passwd = crate::internal::passwd;
 impl MemZeroedStruct for passwd {}
 
@@ -45,6 +45,51 @@ pub unsafe fn fcntl2(fd: int, cmd: int) -> int {
     crate::internal::fcntl(fd, cmd)
 }
 
+pub unsafe fn posix_fallocate(fd: int, offset: off_t, len: off_t) -> int {
+    if offset < 0 || len <= 0 {
+        return Errno::EINVAL as int;
+    }
+
+    let mut attr = stat_t::new_zeroed();
+    if fstat(fd, &mut attr) == -1 {
+        return Errno::get() as int;
+    }
+
+    let new_size = offset + len;
+    if new_size <= attr.st_size as off_t {
+        return 0;
+    }
+
+    // macOS does not provide posix_fallocate, the storage is reserved with F_PREALLOCATE,
+    // preferably contiguous, and afterwards the file size is adjusted
+    let mut store = crate::internal::fstore_t {
+        fst_flags: crate::internal::F_ALLOCATECONTIG as _,
+        fst_posmode: crate::internal::F_PEOFPOSMODE as _,
+        fst_offset: 0,
+        fst_length: new_size - attr.st_size as off_t,
+        fst_bytesalloc: 0,
+    };
+
+    if crate::internal::fcntl(
+        fd,
+        crate::internal::F_PREALLOCATE as _,
+        &mut store as *mut crate::internal::fstore_t,
+    ) == -1
+    {
+        store.fst_flags = crate::internal::F_ALLOCATEALL as _;
+        if crate::internal::fcntl(
+            fd,
+            crate::internal::F_PREALLOCATE as _,
+            &mut store as *mut crate::internal::fstore_t,
+        ) == -1
+        {
+            return Errno::get() as int;
+        }
+    }
+
+    crate::common::fallocate::fallocate_by_truncate_impl(fd, offset, len)
+}
+
 pub unsafe fn fchmod(fd: int, mode: mode_t) -> int {
     // TODO iox2-156, shared memory permission cannot be adjusted with fchmod, therefore setting
     //                  it so that the owner can access everything
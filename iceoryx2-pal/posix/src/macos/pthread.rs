@@ -382,6 +382,14 @@ pub unsafe fn pthread_kill(_thread: pthread_t, _sig: int) -> int {
     todo!()
 }
 
+pub unsafe fn pthread_getschedparam(
+    thread: pthread_t,
+    policy: *mut int,
+    param: *mut sched_param,
+) -> int {
+    crate::internal::pthread_getschedparam(thread, policy, param)
+}
+
 pub unsafe fn pthread_setaffinity_np(
     thread: pthread_t,
     cpusetsize: size_t,
@@ -531,6 +539,72 @@ pub unsafe fn pthread_rwlock_trywrlock(lock: *mut pthread_rwlock_t) -> int {
     }
 }
 
+fn has_timed_out(abs_timeout: &timespec) -> bool {
+    let mut now = timespec::new_zeroed();
+    unsafe { clock_gettime(CLOCK_REALTIME, &mut now) };
+    now.tv_sec > abs_timeout.tv_sec
+        || (now.tv_sec == abs_timeout.tv_sec && now.tv_nsec >= abs_timeout.tv_nsec)
+}
+
+fn wait_until(atomic: &IoxAtomicU32, value: &u32, abs_timeout: &timespec) -> WaitAction {
+    timed_wait(atomic, value, *abs_timeout);
+    if has_timed_out(abs_timeout) {
+        WaitAction::Abort
+    } else {
+        WaitAction::Continue
+    }
+}
+
+pub unsafe fn pthread_rwlock_timedrdlock(
+    lock: *mut pthread_rwlock_t,
+    abs_timeout: *const timespec,
+) -> int {
+    let abs_timeout = &*abs_timeout;
+    let wait_result = match (*lock).lock {
+        RwLockType::PreferReader(ref l) => {
+            l.read_lock(|atomic, value| wait_until(atomic, value, abs_timeout))
+        }
+        RwLockType::PreferWriter(ref l) => {
+            l.read_lock(|atomic, value| wait_until(atomic, value, abs_timeout))
+        }
+        _ => {
+            return Errno::EINVAL as _;
+        }
+    };
+
+    if wait_result == WaitResult::Success {
+        Errno::ESUCCES as _
+    } else {
+        Errno::ETIMEDOUT as _
+    }
+}
+
+pub unsafe fn pthread_rwlock_timedwrlock(
+    lock: *mut pthread_rwlock_t,
+    abs_timeout: *const timespec,
+) -> int {
+    let abs_timeout = &*abs_timeout;
+    let wait_result = match (*lock).lock {
+        RwLockType::PreferReader(ref l) => {
+            l.write_lock(|atomic, value| wait_until(atomic, value, abs_timeout))
+        }
+        RwLockType::PreferWriter(ref l) => l.write_lock(
+            |atomic, value| wait_until(atomic, value, abs_timeout),
+            wake_one,
+            wake_all,
+        ),
+        _ => {
+            return Errno::EINVAL as _;
+        }
+    };
+
+    if wait_result == WaitResult::Success {
+        Errno::ESUCCES as _
+    } else {
+        Errno::ETIMEDOUT as _
+    }
+}
+
 pub unsafe fn pthread_mutex_init(
     mtx: *mut pthread_mutex_t,
     attr: *const pthread_mutexattr_t,
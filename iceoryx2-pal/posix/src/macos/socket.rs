@@ -14,6 +14,7 @@
 #![allow(clippy::missing_safety_doc)]
 
 use crate::posix::types::*;
+use crate::posix::{MemZeroedStruct, SOL_SOCKET, SO_PEERCRED};
 
 use super::Errno;
 
@@ -40,6 +41,10 @@ pub unsafe fn getsockname(socket: int, address: *mut sockaddr, address_len: *mut
     crate::internal::getsockname(socket, address, address_len)
 }
 
+pub unsafe fn getpeername(socket: int, address: *mut sockaddr, address_len: *mut socklen_t) -> int {
+    crate::internal::getpeername(socket, address, address_len)
+}
+
 pub unsafe fn getsockopt(
     socket: int,
     level: int,
@@ -47,9 +52,59 @@ pub unsafe fn getsockopt(
     option_value: *mut void,
     option_len: *mut socklen_t,
 ) -> int {
+    if level == SOL_SOCKET && option_name == SO_PEERCRED {
+        return peer_credentials(socket, option_value, option_len);
+    }
+
     crate::internal::getsockopt(socket, level, option_name, option_value, option_len)
 }
 
+unsafe fn peer_credentials(
+    socket: int,
+    option_value: *mut void,
+    option_len: *mut socklen_t,
+) -> int {
+    if (*option_len as usize) < core::mem::size_of::<ucred>() {
+        Errno::set(Errno::EINVAL);
+        return -1;
+    }
+
+    let mut cred = native_xucred::new_zeroed();
+    let mut cred_len = core::mem::size_of::<native_xucred>() as socklen_t;
+    if crate::internal::getsockopt(
+        socket,
+        crate::internal::SOL_LOCAL as _,
+        crate::internal::LOCAL_PEERCRED as _,
+        (&mut cred as *mut native_xucred).cast(),
+        &mut cred_len,
+    ) == -1
+    {
+        return -1;
+    }
+
+    let mut pid: pid_t = 0;
+    let mut pid_len = core::mem::size_of::<pid_t>() as socklen_t;
+    if crate::internal::getsockopt(
+        socket,
+        crate::internal::SOL_LOCAL as _,
+        crate::internal::LOCAL_PEERPID as _,
+        (&mut pid as *mut pid_t).cast(),
+        &mut pid_len,
+    ) == -1
+    {
+        return -1;
+    }
+
+    option_value.cast::<ucred>().write_unaligned(ucred {
+        pid,
+        uid: cred.cr_uid,
+        gid: cred.cr_groups[0],
+    });
+    *option_len = core::mem::size_of::<ucred>() as socklen_t;
+
+    0
+}
+
 pub unsafe fn bind(socket: int, address: *const sockaddr, address_len: socklen_t) -> int {
     crate::internal::bind(socket, address, address_len)
 }
@@ -103,3 +158,15 @@ pub unsafe fn recvfrom(
 pub unsafe fn recv(socket: int, buffer: *mut void, length: size_t, flags: int) -> ssize_t {
     crate::internal::recv(socket, buffer, length, flags)
 }
+
+pub unsafe fn listen(socket: int, backlog: int) -> int {
+    crate::internal::listen(socket, backlog)
+}
+
+pub unsafe fn accept(socket: int, address: *mut sockaddr, address_len: *mut socklen_t) -> int {
+    crate::internal::accept(socket, address, address_len)
+}
+
+pub unsafe fn shutdown(socket: int, how: int) -> int {
+    crate::internal::shutdown(socket, how)
+}
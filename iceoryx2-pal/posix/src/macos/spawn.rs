@@ -0,0 +1,97 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+#![allow(clippy::missing_safety_doc)]
+
+use crate::posix::types::*;
+
+pub unsafe fn posix_spawn(
+    pid: *mut pid_t,
+    path: *const c_char,
+    file_actions: *const posix_spawn_file_actions_t,
+    attrp: *const posix_spawnattr_t,
+    argv: *const *mut c_char,
+    envp: *const *mut c_char,
+) -> int {
+    crate::internal::posix_spawn(pid, path, file_actions, attrp, argv, envp)
+}
+
+pub unsafe fn posix_spawn_file_actions_init(file_actions: *mut posix_spawn_file_actions_t) -> int {
+    crate::internal::posix_spawn_file_actions_init(file_actions)
+}
+
+pub unsafe fn posix_spawn_file_actions_destroy(
+    file_actions: *mut posix_spawn_file_actions_t,
+) -> int {
+    crate::internal::posix_spawn_file_actions_destroy(file_actions)
+}
+
+pub unsafe fn posix_spawn_file_actions_addclose(
+    file_actions: *mut posix_spawn_file_actions_t,
+    fd: int,
+) -> int {
+    crate::internal::posix_spawn_file_actions_addclose(file_actions, fd)
+}
+
+pub unsafe fn posix_spawn_file_actions_adddup2(
+    file_actions: *mut posix_spawn_file_actions_t,
+    fd: int,
+    newfd: int,
+) -> int {
+    crate::internal::posix_spawn_file_actions_adddup2(file_actions, fd, newfd)
+}
+
+pub unsafe fn posix_spawn_file_actions_addchdir(
+    file_actions: *mut posix_spawn_file_actions_t,
+    path: *const c_char,
+) -> int {
+    internal::posix_spawn_file_actions_addchdir_np(file_actions, path)
+}
+
+pub unsafe fn posix_spawnattr_init(attr: *mut posix_spawnattr_t) -> int {
+    crate::internal::posix_spawnattr_init(attr)
+}
+
+pub unsafe fn posix_spawnattr_destroy(attr: *mut posix_spawnattr_t) -> int {
+    crate::internal::posix_spawnattr_destroy(attr)
+}
+
+pub unsafe fn posix_spawnattr_setflags(attr: *mut posix_spawnattr_t, flags: short) -> int {
+    crate::internal::posix_spawnattr_setflags(attr, flags)
+}
+
+pub unsafe fn posix_spawnattr_setsigmask(
+    attr: *mut posix_spawnattr_t,
+    sigmask: *const sigset_t,
+) -> int {
+    crate::internal::posix_spawnattr_setsigmask(attr, sigmask)
+}
+
+pub unsafe fn posix_spawnattr_setsigdefault(
+    attr: *mut posix_spawnattr_t,
+    sigdefault: *const sigset_t,
+) -> int {
+    crate::internal::posix_spawnattr_setsigdefault(attr, sigdefault)
+}
+
+mod internal {
+    use super::*;
+
+    // non-portable extension and therefore not part of the generated bindings
+    extern "C" {
+        pub(super) fn posix_spawn_file_actions_addchdir_np(
+            file_actions: *mut posix_spawn_file_actions_t,
+            path: *const c_char,
+        ) -> int;
+    }
+}
@@ -14,7 +14,8 @@ pub const POSIX_SUPPORT_ACL: bool = false;
 pub const POSIX_SUPPORT_NAMED_SEMAPHORE: bool = false;
 pub const POSIX_SUPPORT_PERSISTENT_SHARED_MEMORY: bool = false;
 pub const POSIX_SUPPORT_UNIX_DATAGRAM_SOCKETS_ANCILLARY_DATA: bool = false;
-pub const POSIX_SUPPORT_CPU_AFFINITY: bool = true;
+pub const POSIX_SUPPORT_ABSTRACT_UNIX_DOMAIN_SOCKETS: bool = false;
+pub const POSIX_SUPPORT_CPU_AFFINITY: bool = false;
 pub const POSIX_SUPPORT_USERS_AND_GROUPS: bool = false;
 pub const POSIX_SUPPORT_PERMISSIONS: bool = false;
 pub const POSIX_SUPPORT_FILE_LOCK: bool = false;
@@ -23,3 +24,6 @@ pub const POSIX_SUPPORT_MESSAGE_QUEUE: bool = false;
 pub const POSIX_SUPPORT_ADVANCED_SIGNAL_HANDLING: bool = false;
 pub const POSIX_SUPPORT_CONSOLE_SIGNAL_HANDLING: bool = false;
 pub const POSIX_SUPPORT_SCHEDULER: bool = false;
+pub const POSIX_SUPPORT_HUGE_PAGES: bool = false;
+pub const POSIX_SUPPORT_ANONYMOUS_SHARED_MEMORY: bool = false;
+pub const POSIX_SUPPORT_BOOTTIME_CLOCK: bool = false;
@@ -62,10 +62,17 @@ pub type uid_t = crate::internal::uid_t;
 pub type uint = crate::internal::uint;
 pub type ushort = crate::internal::ushort;
 pub type void = core::ffi::c_void;
+pub type acl_t = *mut void;
 
 pub type sigset_t = crate::internal::sigset_t;
 impl MemZeroedStruct for sigset_t {}
 
+pub type posix_spawn_file_actions_t = crate::internal::posix_spawn_file_actions_t;
+impl MemZeroedStruct for posix_spawn_file_actions_t {}
+
+pub type posix_spawnattr_t = crate::internal::posix_spawnattr_t;
+impl MemZeroedStruct for posix_spawnattr_t {}
+
 pub struct pthread_barrier_t {
     pub(crate) barrier: Barrier,
 }
@@ -180,6 +187,9 @@ impl MemZeroedStruct for sched_param {}
 pub(crate) type native_stat_t = crate::internal::stat;
 impl MemZeroedStruct for native_stat_t {}
 
+pub(crate) type native_xucred = crate::internal::xucred;
+impl MemZeroedStruct for native_xucred {}
+
 #[repr(C)]
 pub struct stat_t {
     pub st_dev: dev_t,
@@ -270,6 +280,19 @@ impl SockAddrIn for sockaddr_in {
     }
 }
 
+pub type ip_mreq = crate::internal::ip_mreq;
+impl MemZeroedStruct for ip_mreq {}
+
+impl IpMreq for ip_mreq {
+    fn set_multiaddr(&mut self, value: u32) {
+        self.imr_multiaddr.s_addr = value;
+    }
+
+    fn set_interface(&mut self, value: u32) {
+        self.imr_interface.s_addr = value;
+    }
+}
+
 pub type passwd = crate::internal::passwd;
 impl MemZeroedStruct for passwd {}
 
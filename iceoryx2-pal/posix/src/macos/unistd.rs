@@ -71,6 +71,14 @@ pub unsafe fn write(fd: int, buf: *const void, count: size_t) -> ssize_t {
     crate::internal::write(fd, buf, count)
 }
 
+pub unsafe fn pread(fd: int, buf: *mut void, count: size_t, offset: off_t) -> ssize_t {
+    crate::internal::pread(fd, buf, count, offset)
+}
+
+pub unsafe fn pwrite(fd: int, buf: *const void, count: size_t, offset: off_t) -> ssize_t {
+    crate::internal::pwrite(fd, buf, count, offset)
+}
+
 pub unsafe fn access(pathname: *const c_char, mode: int) -> int {
     crate::internal::access(pathname, mode)
 }
@@ -91,6 +99,10 @@ pub unsafe fn getgid() -> gid_t {
     crate::internal::getgid()
 }
 
+pub unsafe fn getgroups(size: int, list: *mut gid_t) -> int {
+    crate::internal::getgroups(size, list)
+}
+
 pub unsafe fn rmdir(pathname: *const c_char) -> int {
     crate::internal::rmdir(pathname)
 }
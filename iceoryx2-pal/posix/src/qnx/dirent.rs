@@ -43,6 +43,11 @@ pub unsafe fn readdir_r(dirp: *mut DIR, entry: *mut dirent, result: *mut *mut di
     crate::internal::readdir_r(dirp, entry, result)
 }
 
+pub unsafe fn dirent_type(_entry: *const dirent) -> uchar {
+    // QNX does not provide d_type, the caller has to fall back to stat
+    crate::posix::DT_UNKNOWN
+}
+
 pub fn dirent_size() -> usize {
     // The POSIX manual does not enforce that the name is stored in the dirent
     // struct (https://pubs.opengroup.org/onlinepubs/9699919799/):
@@ -13,7 +13,7 @@
 #![allow(non_camel_case_types)]
 #![allow(clippy::missing_safety_doc)]
 
-use crate::posix::{closedir, opendir, readdir, types::*};
+use crate::posix::{closedir, opendir, readdir, types::*, Errno};
 extern crate alloc;
 use alloc::vec;
 use alloc::vec::Vec;
@@ -147,3 +147,8 @@ mod internal {
         crate::internal::mmap64(addr, len, prot, flags, fd, off)
     }
 }
+
+pub unsafe fn memfd_create(_name: *const c_char, _flags: uint) -> int {
+    Errno::set(Errno::ENOSYS);
+    -1
+}
@@ -10,6 +10,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+pub mod acl;
 pub mod constants;
 pub mod dirent;
 pub mod errno;
@@ -23,6 +24,7 @@ pub mod select;
 pub mod semaphore;
 pub mod signal;
 pub mod socket;
+pub mod spawn;
 pub mod stat;
 pub mod stdio;
 pub mod stdlib;
@@ -31,7 +33,9 @@ pub mod support;
 pub mod time;
 pub mod types;
 pub mod unistd;
+pub mod wait;
 
+pub use acl::*;
 pub use constants::*;
 pub use dirent::*;
 pub use errno::*;
@@ -45,6 +49,7 @@ pub use select::*;
 pub use semaphore::*;
 pub use signal::*;
 pub use socket::*;
+pub use spawn::*;
 pub use stat::*;
 pub use stdio::*;
 pub use stdlib::*;
@@ -53,3 +58,4 @@ pub use support::*;
 pub use time::*;
 pub use types::*;
 pub use unistd::*;
+pub use wait::*;
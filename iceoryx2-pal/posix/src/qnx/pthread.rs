@@ -15,8 +15,12 @@
 
 use crate::posix::*;
 
-pub unsafe fn pthread_rwlockattr_setkind_np(_attr: *mut pthread_rwlockattr_t, _pref: int) -> int {
-    // Not supported on QNX
+pub unsafe fn pthread_rwlockattr_setkind_np(_attr: *mut pthread_rwlockattr_t, pref: int) -> int {
+    // read write locks always prefer writers
+    if pref == PTHREAD_PREFER_READER_NP {
+        return Errno::ENOTSUP as _;
+    }
+
     crate::internal::EOK as _
 }
 
@@ -126,6 +130,14 @@ pub unsafe fn pthread_kill(thread: pthread_t, sig: int) -> int {
     crate::internal::pthread_kill(thread, sig)
 }
 
+pub unsafe fn pthread_getschedparam(
+    thread: pthread_t,
+    policy: *mut int,
+    param: *mut sched_param,
+) -> int {
+    crate::internal::pthread_getschedparam(thread, policy, param)
+}
+
 pub unsafe fn pthread_setaffinity_np(
     thread: pthread_t,
     _cpusetsize: size_t,
@@ -199,6 +211,20 @@ pub unsafe fn pthread_rwlock_trywrlock(lock: *mut pthread_rwlock_t) -> int {
     crate::internal::pthread_rwlock_trywrlock(lock)
 }
 
+pub unsafe fn pthread_rwlock_timedrdlock(
+    lock: *mut pthread_rwlock_t,
+    abs_timeout: *const timespec,
+) -> int {
+    crate::internal::pthread_rwlock_timedrdlock(lock, abs_timeout)
+}
+
+pub unsafe fn pthread_rwlock_timedwrlock(
+    lock: *mut pthread_rwlock_t,
+    abs_timeout: *const timespec,
+) -> int {
+    crate::internal::pthread_rwlock_timedwrlock(lock, abs_timeout)
+}
+
 pub unsafe fn pthread_mutex_init(
     mtx: *mut pthread_mutex_t,
     attr: *const pthread_mutexattr_t,
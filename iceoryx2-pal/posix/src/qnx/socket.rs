@@ -38,6 +38,10 @@ pub unsafe fn getsockname(socket: int, address: *mut sockaddr, address_len: *mut
     crate::internal::getsockname(socket, address, address_len)
 }
 
+pub unsafe fn getpeername(socket: int, address: *mut sockaddr, address_len: *mut socklen_t) -> int {
+    crate::internal::getpeername(socket, address, address_len)
+}
+
 pub unsafe fn getsockopt(
     socket: int,
     level: int,
@@ -97,3 +101,15 @@ pub unsafe fn recvfrom(
 pub unsafe fn recv(socket: int, buffer: *mut void, length: size_t, flags: int) -> ssize_t {
     crate::internal::recv(socket, buffer, length, flags)
 }
+
+pub unsafe fn listen(socket: int, backlog: int) -> int {
+    crate::internal::listen(socket, backlog)
+}
+
+pub unsafe fn accept(socket: int, address: *mut sockaddr, address_len: *mut socklen_t) -> int {
+    crate::internal::accept(socket, address, address_len)
+}
+
+pub unsafe fn shutdown(socket: int, how: int) -> int {
+    crate::internal::shutdown(socket, how)
+}
@@ -27,6 +27,17 @@ pub unsafe fn stat(path: *const c_char, buf: *mut stat_t) -> int {
     }
 }
 
+pub unsafe fn lstat(path: *const c_char, buf: *mut stat_t) -> int {
+    let mut os_specific_buffer = native_stat_t::new_zeroed();
+    match internal::lstat(path, &mut os_specific_buffer) {
+        0 => {
+            *buf = os_specific_buffer.into();
+            0
+        }
+        v => v,
+    }
+}
+
 pub unsafe fn umask(mask: mode_t) -> mode_t {
     crate::internal::umask(mask)
 }
@@ -38,6 +49,10 @@ mod internal {
     pub unsafe fn stat(path: *const c_char, buf: &mut native_stat_t) -> int {
         crate::internal::stat(path, buf)
     }
+
+    pub unsafe fn lstat(path: *const c_char, buf: &mut native_stat_t) -> int {
+        crate::internal::lstat(path, buf)
+    }
 }
 
 #[cfg(target_pointer_width = "64")]
@@ -47,4 +62,8 @@ mod internal {
     pub unsafe fn stat(path: *const c_char, buf: &mut native_stat_t) -> int {
         crate::internal::stat64(path, buf)
     }
+
+    pub unsafe fn lstat(path: *const c_char, buf: &mut native_stat_t) -> int {
+        crate::internal::lstat64(path, buf)
+    }
 }
@@ -0,0 +1,43 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types, non_snake_case)]
+#![allow(clippy::missing_safety_doc)]
+#![allow(unused_variables)]
+
+use crate::posix::types::*;
+use crate::posix::Errno;
+
+pub unsafe fn acl_get_fd(fd: int) -> acl_t {
+    Errno::set(Errno::ENOTSUP);
+    core::ptr::null_mut()
+}
+
+pub unsafe fn acl_set_fd(fd: int, acl: acl_t) -> int {
+    Errno::set(Errno::ENOTSUP);
+    -1
+}
+
+pub unsafe fn acl_from_text(buf_p: *const c_char) -> acl_t {
+    Errno::set(Errno::ENOTSUP);
+    core::ptr::null_mut()
+}
+
+pub unsafe fn acl_to_text(acl: acl_t, len_p: *mut ssize_t) -> *mut c_char {
+    Errno::set(Errno::ENOTSUP);
+    core::ptr::null_mut()
+}
+
+pub unsafe fn acl_free(obj_p: *mut void) -> int {
+    Errno::set(Errno::ENOTSUP);
+    -1
+}
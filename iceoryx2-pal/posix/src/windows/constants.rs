@@ -43,6 +43,7 @@ pub const F_GETLK: int = 32;
 pub const F_SETLK: int = 64;
 pub const F_SETLKW: int = 128;
 pub const F_GETFD: int = 256;
+pub const FD_CLOEXEC: int = 1;
 
 pub const PROT_NONE: int = 1;
 pub const PROT_READ: int = 2;
@@ -52,6 +53,18 @@ pub const MCL_CURRENT: int = 16;
 pub const MCL_FUTURE: int = 32;
 pub const MAP_SHARED: int = 64;
 pub const MAP_FAILED: *mut void = core::ptr::null_mut::<void>();
+pub const MFD_CLOEXEC: uint = 1;
+pub const MFD_ALLOW_SEALING: uint = 2;
+pub const F_ADD_SEALS: int = 0x409;
+pub const F_GET_SEALS: int = 0x40a;
+pub const F_SEAL_SEAL: int = 1;
+pub const F_SEAL_SHRINK: int = 2;
+pub const F_SEAL_GROW: int = 4;
+pub const F_SEAL_WRITE: int = 8;
+
+pub const POSIX_SPAWN_SETSIGDEF: short = 4;
+pub const POSIX_SPAWN_SETSIGMASK: short = 8;
+pub const WNOHANG: int = 1;
 
 pub const PTHREAD_MUTEX_NORMAL: int = 1;
 pub const PTHREAD_MUTEX_RECURSIVE: int = 2;
@@ -133,6 +146,7 @@ pub const SIG_ERR: sighandler_t = sighandler_t::MAX;
 pub const SIG_DFL: int = 0;
 pub const SIG_IGN: int = 1;
 pub const SA_RESTART: int = 1;
+pub const SA_SIGINFO: int = 2;
 
 pub const AF_LOCAL: sa_family_t = windows_sys::Win32::Networking::WinSock::AF_UNIX as _;
 pub const AF_UNIX: sa_family_t = windows_sys::Win32::Networking::WinSock::AF_UNIX as _;
@@ -149,7 +163,22 @@ pub const SOCK_STREAM: int = windows_sys::Win32::Networking::WinSock::SOCK_STREA
 pub const SOCK_DGRAM: int = windows_sys::Win32::Networking::WinSock::SOCK_DGRAM as _;
 pub const SOCK_NONBLOCK: int = O_NONBLOCK;
 pub const IPPROTO_UDP: int = windows_sys::Win32::Networking::WinSock::IPPROTO_UDP as _;
+pub const IPPROTO_IP: int = windows_sys::Win32::Networking::WinSock::IPPROTO_IP as _;
+pub const IPPROTO_TCP: int = windows_sys::Win32::Networking::WinSock::IPPROTO_TCP as _;
+pub const TCP_NODELAY: int = windows_sys::Win32::Networking::WinSock::TCP_NODELAY as _;
+pub const IP_ADD_MEMBERSHIP: int = windows_sys::Win32::Networking::WinSock::IP_ADD_MEMBERSHIP as _;
+pub const IP_MULTICAST_IF: int = windows_sys::Win32::Networking::WinSock::IP_MULTICAST_IF as _;
+pub const SO_BROADCAST: int = windows_sys::Win32::Networking::WinSock::SO_BROADCAST as _;
+pub const SO_KEEPALIVE: int = windows_sys::Win32::Networking::WinSock::SO_KEEPALIVE as _;
+pub const SO_ERROR: int = windows_sys::Win32::Networking::WinSock::SO_ERROR as _;
+pub const SO_REUSEADDR: int = windows_sys::Win32::Networking::WinSock::SO_REUSEADDR as _;
 pub const MSG_PEEK: int = windows_sys::Win32::Networking::WinSock::MSG_PEEK as _;
+pub const MSG_CTRUNC: int = windows_sys::Win32::Networking::WinSock::MSG_CTRUNC as _;
+// SIGPIPE does not exist on windows
+pub const MSG_NOSIGNAL: int = 0;
+pub const SHUT_RD: int = windows_sys::Win32::Networking::WinSock::SD_RECEIVE as _;
+pub const SHUT_WR: int = windows_sys::Win32::Networking::WinSock::SD_SEND as _;
+pub const SHUT_RDWR: int = windows_sys::Win32::Networking::WinSock::SD_BOTH as _;
 pub const SCM_MAX_FD: u32 = 253;
 pub const SCM_RIGHTS: int = 128;
 pub const SCM_CREDENTIALS: int = 0x02;
@@ -166,6 +195,15 @@ pub const S_IFREG: mode_t = 0o0100000;
 pub const S_IFLNK: mode_t = 0o0120000;
 pub const S_IFSOCK: mode_t = 0o0140000;
 
+pub const DT_UNKNOWN: uchar = 0;
+pub const DT_FIFO: uchar = 1;
+pub const DT_CHR: uchar = 2;
+pub const DT_DIR: uchar = 4;
+pub const DT_BLK: uchar = 6;
+pub const DT_REG: uchar = 8;
+pub const DT_LNK: uchar = 10;
+pub const DT_SOCK: uchar = 12;
+
 pub const S_ISUID: mode_t = 0o04000;
 pub const S_ISGID: mode_t = 0o02000;
 pub const S_ISVTX: mode_t = 0o01000;
@@ -187,6 +225,7 @@ pub const S_IRWXO: mode_t = S_IROTH | S_IWOTH | S_IXOTH;
 
 pub const CLOCK_REALTIME: clockid_t = 1;
 pub const CLOCK_MONOTONIC: clockid_t = 2;
+pub const CLOCK_BOOTTIME: clockid_t = CLOCK_MONOTONIC;
 pub const CLOCK_TIMER_ABSTIME: int = 4;
 
 pub const F_OK: int = 1;
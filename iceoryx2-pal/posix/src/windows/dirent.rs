@@ -23,17 +23,16 @@ use crate::{
     },
     win32call,
 };
-use core::sync::atomic::Ordering;
-use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
 use iceoryx2_pal_configuration::PATH_LENGTH;
 use windows_sys::Win32::{
     Foundation::{
         ERROR_ALREADY_EXISTS, ERROR_FILE_NOT_FOUND, ERROR_NO_MORE_FILES, ERROR_PATH_NOT_FOUND,
-        FALSE, INVALID_HANDLE_VALUE,
+        FALSE, HANDLE, INVALID_HANDLE_VALUE,
     },
     Security::SECURITY_ATTRIBUTES,
     Storage::FileSystem::{
-        CreateDirectoryA, FindClose, FindFirstFileA, FindNextFileA, WIN32_FIND_DATAA,
+        CreateDirectoryA, FindClose, FindFirstFileA, FindNextFileA, FILE_ATTRIBUTE_DIRECTORY,
+        WIN32_FIND_DATAA,
     },
 };
 
@@ -118,16 +117,81 @@ pub unsafe fn mkdir(pathname: *const c_char, mode: mode_t) -> int {
     0
 }
 
+struct DirectoryStream {
+    search_path: [u8; MAX_PATH_LENGTH],
+    find_handle: HANDLE,
+    uds_files: Vec<[u8; PATH_LENGTH]>,
+    next_uds_file: usize,
+    has_more_files: bool,
+    entry: dirent,
+}
+
 pub unsafe fn opendir(dirname: *const c_char) -> *mut DIR {
-    static COUNT: IoxAtomicU64 = IoxAtomicU64::new(1);
-    let id = COUNT.fetch_add(1, Ordering::Relaxed);
+    let stream = Box::new(DirectoryStream {
+        search_path: to_dir_search_string(dirname),
+        find_handle: INVALID_HANDLE_VALUE,
+        uds_files: HandleTranslator::get_instance().list_all_uds(dirname),
+        next_uds_file: 0,
+        has_more_files: true,
+        entry: dirent::new_zeroed(),
+    });
+
+    let dirp = Box::into_raw(stream) as *mut DIR;
+    HandleTranslator::get_instance().add(FdHandleEntry::DirectoryStream(dirp as u64));
+    dirp
+}
 
-    HandleTranslator::get_instance().add(FdHandleEntry::DirectoryStream(id));
-    id as *mut DIR
+pub unsafe fn readdir(dirp: *mut DIR) -> *const dirent {
+    let stream = &mut *(dirp as *mut DirectoryStream);
+
+    if let Some(file) = stream.uds_files.get(stream.next_uds_file) {
+        stream.next_uds_file += 1;
+        stream.entry = dirent::new_zeroed();
+        stream.entry.d_name[..file.len()]
+            .copy_from_slice(core::mem::transmute::<&[u8; PATH_LENGTH], &[i8; PATH_LENGTH]>(file));
+        stream.entry.d_type = posix::DT_SOCK;
+        return &stream.entry;
+    }
+
+    if !stream.has_more_files {
+        return core::ptr::null();
+    }
+
+    let mut data = WIN32_FIND_DATAA::new_zeroed();
+    if stream.find_handle == INVALID_HANDLE_VALUE {
+        let (handle, _) = win32call! { FindFirstFileA(stream.search_path.as_ptr(), &mut data), ignore ERROR_FILE_NOT_FOUND};
+        stream.find_handle = handle;
+        if handle == INVALID_HANDLE_VALUE {
+            stream.has_more_files = false;
+            return core::ptr::null();
+        }
+    } else {
+        let (file_found, _) =
+            win32call! {FindNextFileA(stream.find_handle, &mut data), ignore ERROR_NO_MORE_FILES};
+        if file_found == FALSE {
+            stream.has_more_files = false;
+            return core::ptr::null();
+        }
+    }
+
+    stream.entry = dirent::new_zeroed();
+    stream.entry.d_name = core::array::from_fn(|i| data.cFileName[i] as i8);
+    stream.entry.d_type = if data.dwFileAttributes & FILE_ATTRIBUTE_DIRECTORY != 0 {
+        posix::DT_DIR
+    } else {
+        posix::DT_REG
+    };
+
+    &stream.entry
 }
 
 pub unsafe fn closedir(dirp: *mut DIR) -> int {
     HandleTranslator::get_instance().remove_entry(FdHandleEntry::DirectoryStream(dirp as u64));
+
+    let stream = Box::from_raw(dirp as *mut DirectoryStream);
+    if stream.find_handle != INVALID_HANDLE_VALUE {
+        win32call! {FindClose(stream.find_handle)};
+    }
     0
 }
 
@@ -135,6 +199,10 @@ pub unsafe fn dirfd(dirp: *mut DIR) -> int {
     HandleTranslator::get_instance().get_fd(FdHandleEntry::DirectoryStream(dirp as u64))
 }
 
+pub unsafe fn dirent_type(entry: *const dirent) -> uchar {
+    (*entry).d_type as _
+}
+
 pub fn dirent_size() -> usize {
     core::mem::size_of::<crate::posix::types::dirent>()
 }
@@ -215,6 +215,21 @@ pub unsafe fn fcntl_int(fd: int, cmd: int, arg: int) -> int {
 
 impl MemZeroedStruct for OVERLAPPED {}
 
+unsafe fn lock_region(arg: *const flock) -> (OVERLAPPED, u32, u32) {
+    let mut overlapped = OVERLAPPED::new_zeroed();
+    let start = (*arg).l_start.max(0) as u64;
+    overlapped.Anonymous.Anonymous.Offset = (start & 0xffffffff) as u32;
+    overlapped.Anonymous.Anonymous.OffsetHigh = (start >> 32) as u32;
+
+    // a length of zero locks everything up to the end of the file
+    if (*arg).l_len <= 0 {
+        return (overlapped, MAXWORD, MAXWORD);
+    }
+
+    let len = (*arg).l_len as u64;
+    (overlapped, (len & 0xffffffff) as u32, (len >> 32) as u32)
+}
+
 pub unsafe fn fcntl(fd: int, cmd: int, arg: *mut flock) -> int {
     let handle = match HandleTranslator::get_instance().get(fd) {
         Some(FdHandleEntry::File(_)) => HandleTranslator::get_instance().get_file_handle_mut(fd),
@@ -240,13 +255,13 @@ pub unsafe fn fcntl(fd: int, cmd: int, arg: *mut flock) -> int {
             return 0;
         }
 
-        let mut overlapped = OVERLAPPED::new_zeroed();
+        let (mut overlapped, len_low, len_high) = lock_region(arg);
         let flags = LOCKFILE_EXCLUSIVE_LOCK | LOCKFILE_FAIL_IMMEDIATELY;
-        let lock_result = LockFileEx(handle.handle, flags, 0, MAXWORD, MAXWORD, &mut overlapped);
+        let lock_result = LockFileEx(handle.handle, flags, 0, len_low, len_high, &mut overlapped);
 
         if lock_result != FALSE {
-            let mut overlapped = OVERLAPPED::new_zeroed();
-            UnlockFileEx(handle.handle, 0, MAXWORD, MAXWORD, &mut overlapped);
+            let (mut overlapped, len_low, len_high) = lock_region(arg);
+            UnlockFileEx(handle.handle, 0, len_low, len_high, &mut overlapped);
             (*arg).l_type = posix::F_UNLCK as _;
         } else {
             (*arg).l_type = posix::F_WRLCK as _;
@@ -262,13 +277,13 @@ pub unsafe fn fcntl(fd: int, cmd: int, arg: *mut flock) -> int {
     }
 
     if lock_type == F_UNLCK {
-        let mut overlapped = OVERLAPPED::new_zeroed();
+        let (mut overlapped, len_low, len_high) = lock_region(arg);
         handle.lock_state = F_UNLCK;
         let (file_unlocked, _) = win32call! {UnlockFileEx(
             handle.handle,
             0,
-            MAXWORD,
-            MAXWORD,
+            len_low,
+            len_high,
             &mut overlapped,
         )};
         if file_unlocked == FALSE {
@@ -287,10 +302,10 @@ pub unsafe fn fcntl(fd: int, cmd: int, arg: *mut flock) -> int {
         flags |= LOCKFILE_FAIL_IMMEDIATELY;
     }
 
-    let mut overlapped = OVERLAPPED::new_zeroed();
+    let (mut overlapped, len_low, len_high) = lock_region(arg);
 
     let (has_file_locked, _) =
-        win32call! {LockFileEx(handle.handle, flags, 0, MAXWORD, MAXWORD, &mut overlapped)};
+        win32call! {LockFileEx(handle.handle, flags, 0, len_low, len_high, &mut overlapped)};
     if has_file_locked == FALSE {
         return -1;
     }
@@ -330,6 +345,10 @@ unsafe fn handle_to_file_path(handle: HANDLE) -> Option<[u8; MAX_PATH_LENGTH]> {
     }
 }
 
+pub unsafe fn posix_fallocate(fd: int, offset: off_t, len: off_t) -> int {
+    crate::common::fallocate::fallocate_by_truncate_impl(fd, offset, len)
+}
+
 pub unsafe fn fchmod(fd: int, mode: mode_t) -> int {
     let handle = match HandleTranslator::get_instance().get(fd) {
         Some(FdHandleEntry::SharedMemory(handle)) => handle.state_handle,
@@ -10,6 +10,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+pub mod acl;
 pub mod constants;
 pub mod dirent;
 pub mod errno;
@@ -25,6 +26,7 @@ pub mod semaphore;
 pub mod settings;
 pub mod signal;
 pub mod socket;
+pub mod spawn;
 pub mod stat;
 pub mod stdio;
 pub mod stdlib;
@@ -33,12 +35,14 @@ pub mod support;
 pub mod time;
 pub mod types;
 pub mod unistd;
+pub mod wait;
 #[macro_use]
 mod win32_call;
 pub mod win32_handle_translator;
 pub mod win32_security_attributes;
 mod win32_udp_port_to_uds_name;
 
+pub use acl::*;
 pub use constants::*;
 pub use dirent::*;
 pub use errno::*;
@@ -52,6 +56,7 @@ pub use select::*;
 pub use semaphore::*;
 pub use signal::*;
 pub use socket::*;
+pub use spawn::*;
 pub use stat::*;
 pub use stdio::*;
 pub use stdlib::*;
@@ -60,3 +65,4 @@ pub use support::*;
 pub use time::*;
 pub use types::*;
 pub use unistd::*;
+pub use wait::*;
@@ -31,11 +31,12 @@ use windows_sys::Win32::{
         Memory::LocalFree,
         Threading::{
             CreateThread, GetCurrentThread, GetCurrentThreadId, GetExitCodeThread,
-            GetThreadDescription, GetThreadId, SetThreadAffinityMask, SetThreadDescription,
-            SetThreadPriority, TerminateThread, WaitForSingleObject, WaitOnAddress,
-            WakeByAddressAll, WakeByAddressSingle, INFINITE, THREAD_PRIORITY_ABOVE_NORMAL,
-            THREAD_PRIORITY_BELOW_NORMAL, THREAD_PRIORITY_HIGHEST, THREAD_PRIORITY_IDLE,
-            THREAD_PRIORITY_LOWEST, THREAD_PRIORITY_NORMAL, THREAD_PRIORITY_TIME_CRITICAL,
+            GetThreadDescription, GetThreadId, GetThreadPriority, SetThreadAffinityMask,
+            SetThreadDescription, SetThreadPriority, TerminateThread, WaitForSingleObject,
+            WaitOnAddress, WakeByAddressAll, WakeByAddressSingle, INFINITE,
+            THREAD_PRIORITY_ABOVE_NORMAL, THREAD_PRIORITY_BELOW_NORMAL, THREAD_PRIORITY_HIGHEST,
+            THREAD_PRIORITY_IDLE, THREAD_PRIORITY_LOWEST, THREAD_PRIORITY_NORMAL,
+            THREAD_PRIORITY_TIME_CRITICAL,
         },
     },
 };
@@ -250,6 +251,24 @@ pub unsafe fn pthread_attr_setaffinity_np(
     Errno::ESUCCES as int
 }
 
+fn from_win_priority(prio: int) -> int {
+    if prio <= THREAD_PRIORITY_IDLE {
+        -3
+    } else if prio == THREAD_PRIORITY_LOWEST {
+        -2
+    } else if prio == THREAD_PRIORITY_BELOW_NORMAL {
+        -1
+    } else if prio == THREAD_PRIORITY_NORMAL {
+        0
+    } else if prio == THREAD_PRIORITY_ABOVE_NORMAL {
+        1
+    } else if prio == THREAD_PRIORITY_HIGHEST {
+        2
+    } else {
+        3
+    }
+}
+
 fn to_win_priority(prio: int) -> int {
     if prio <= -3 {
         THREAD_PRIORITY_IDLE
@@ -397,6 +416,24 @@ pub unsafe fn pthread_kill(thread: pthread_t, sig: int) -> int {
     Errno::ESUCCES as int
 }
 
+pub unsafe fn pthread_getschedparam(
+    thread: pthread_t,
+    policy: *mut int,
+    param: *mut sched_param,
+) -> int {
+    // THREAD_PRIORITY_ERROR_RETURN
+    const PRIORITY_ERROR_RETURN: int = int::MAX;
+
+    let (priority, _) = win32call! { GetThreadPriority(thread.handle) };
+    if priority == PRIORITY_ERROR_RETURN {
+        return Errno::ESRCH as int;
+    }
+
+    *policy = SCHED_OTHER;
+    (*param).sched_priority = from_win_priority(priority);
+    Errno::ESUCCES as int
+}
+
 pub unsafe fn pthread_setaffinity_np(
     thread: pthread_t,
     cpusetsize: size_t,
@@ -588,6 +625,77 @@ pub unsafe fn pthread_rwlock_trywrlock(lock: *mut pthread_rwlock_t) -> int {
     }
 }
 
+unsafe fn wait_until(atomic: &IoxAtomicU32, value: &u32, abs_timeout: &timespec) -> WaitAction {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    let remaining_time =
+        abs_timeout.tv_sec * 1000 + abs_timeout.tv_nsec as i64 / 1000000 - now.as_millis() as i64;
+    if remaining_time <= 0 {
+        return WaitAction::Abort;
+    }
+
+    win32call! { WaitOnAddress(
+        (atomic as *const IoxAtomicU32).cast(),
+        (value as *const u32).cast(),
+        4,
+        remaining_time as _,
+    ), ignore ERROR_TIMEOUT };
+    WaitAction::Continue
+}
+
+pub unsafe fn pthread_rwlock_timedrdlock(
+    lock: *mut pthread_rwlock_t,
+    abs_timeout: *const timespec,
+) -> int {
+    let abs_timeout = &*abs_timeout;
+    let wait_result = match (*lock).lock {
+        RwLockType::PreferReader(ref l) => {
+            l.read_lock(|atomic, value| wait_until(atomic, value, abs_timeout))
+        }
+        RwLockType::PreferWriter(ref l) => {
+            l.read_lock(|atomic, value| wait_until(atomic, value, abs_timeout))
+        }
+        _ => {
+            return Errno::EINVAL as _;
+        }
+    };
+
+    if wait_result == WaitResult::Success {
+        Errno::ESUCCES as _
+    } else {
+        Errno::ETIMEDOUT as _
+    }
+}
+
+pub unsafe fn pthread_rwlock_timedwrlock(
+    lock: *mut pthread_rwlock_t,
+    abs_timeout: *const timespec,
+) -> int {
+    let abs_timeout = &*abs_timeout;
+    let wait_result = match (*lock).lock {
+        RwLockType::PreferReader(ref l) => {
+            l.write_lock(|atomic, value| wait_until(atomic, value, abs_timeout))
+        }
+        RwLockType::PreferWriter(ref l) => l.write_lock(
+            |atomic, value| wait_until(atomic, value, abs_timeout),
+            |atomic| {
+                win32call! { WakeByAddressSingle((atomic as *const IoxAtomicU32).cast()) };
+            },
+            |atomic| {
+                WakeByAddressAll((atomic as *const IoxAtomicU32).cast());
+            },
+        ),
+        _ => {
+            return Errno::EINVAL as _;
+        }
+    };
+
+    if wait_result == WaitResult::Success {
+        Errno::ESUCCES as _
+    } else {
+        Errno::ETIMEDOUT as _
+    }
+}
+
 pub unsafe fn pthread_mutex_init(
     mtx: *mut pthread_mutex_t,
     attr: *const pthread_mutexattr_t,
@@ -20,19 +20,137 @@ use std::time::UNIX_EPOCH;
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU32;
 use iceoryx2_pal_concurrency_sync::semaphore::Semaphore;
 use iceoryx2_pal_concurrency_sync::{WaitAction, WaitResult};
-use windows_sys::Win32::System::Threading::WaitOnAddress;
-use windows_sys::Win32::System::Threading::WakeByAddressSingle;
-use windows_sys::Win32::System::Threading::INFINITE;
+use windows_sys::Win32::Foundation::{
+    CloseHandle, ERROR_ALREADY_EXISTS, ERROR_FILE_NOT_FOUND, ERROR_TOO_MANY_POSTS, FALSE, HANDLE,
+    WAIT_OBJECT_0, WAIT_TIMEOUT,
+};
+use windows_sys::Win32::Security::SECURITY_ATTRIBUTES;
+use windows_sys::Win32::System::Threading::{
+    CreateSemaphoreA, ReleaseSemaphore, WaitForSingleObject, WaitOnAddress, WakeByAddressSingle,
+    INFINITE, SEMAPHORE_ALL_ACCESS,
+};
+use windows_sys::Win32::System::WindowsProgramming::OpenSemaphoreA;
 
 use crate::posix::constants::*;
+use crate::posix::settings::*;
 use crate::posix::types::*;
 use crate::posix::Errno;
+use crate::posix::MemZeroedStruct;
+use crate::win32call;
+
+// semaphores share the kernel object namespace with shared memory objects, the prefix avoids
+// name collisions
+const KERNEL_SEMAPHORE_PREFIX: &[u8] = b"iox2_sem_";
+
+unsafe fn kernel_semaphore_name(name: *const c_char) -> Option<[u8; MAX_PATH_LENGTH]> {
+    let mut name = name;
+    if *name as u8 == b'/' || *name as u8 == b'\\' {
+        name = name.add(1);
+    }
+
+    let mut kernel_name = [0u8; MAX_PATH_LENGTH];
+    kernel_name[..KERNEL_SEMAPHORE_PREFIX.len()].copy_from_slice(KERNEL_SEMAPHORE_PREFIX);
+
+    for i in 0..MAX_PATH_LENGTH - KERNEL_SEMAPHORE_PREFIX.len() {
+        let c = *name.add(i) as u8;
+        if c == 0 {
+            return Some(kernel_name);
+        }
+
+        // kernel object names are not allowed to contain a backslash
+        if c == b'\\' {
+            Errno::set(Errno::EINVAL);
+            return None;
+        }
+
+        let position = KERNEL_SEMAPHORE_PREFIX.len() + i;
+        // the last byte is reserved for the null terminator
+        if position == MAX_PATH_LENGTH - 1 {
+            break;
+        }
+
+        kernel_name[position] = c;
+    }
+
+    Errno::set(Errno::ENAMETOOLONG);
+    None
+}
+
+unsafe fn new_named_semaphore(kernel_handle: HANDLE) -> *mut sem_t {
+    let mut sem = sem_t::new_zeroed();
+    sem.kernel_handle = kernel_handle;
+
+    Errno::set(Errno::ESUCCES);
+    Box::into_raw(Box::new(sem))
+}
+
+unsafe fn wait_for_kernel_semaphore(kernel_handle: HANDLE, milli_seconds: u32) -> int {
+    let (wait_result, _) = win32call! { WaitForSingleObject(kernel_handle, milli_seconds) };
+    match wait_result {
+        WAIT_OBJECT_0 => {
+            Errno::set(Errno::ESUCCES);
+            0
+        }
+        WAIT_TIMEOUT => {
+            if milli_seconds == 0 {
+                Errno::set(Errno::EAGAIN);
+            } else {
+                Errno::set(Errno::ETIMEDOUT);
+            }
+            -1
+        }
+        _ => {
+            Errno::set(Errno::EINVAL);
+            -1
+        }
+    }
+}
 
 pub unsafe fn sem_create(name: *const c_char, oflag: int, mode: mode_t, value: uint) -> *mut sem_t {
-    SEM_FAILED
+    let kernel_name = match kernel_semaphore_name(name) {
+        Some(v) => v,
+        None => return SEM_FAILED,
+    };
+
+    if value > i32::MAX as uint {
+        Errno::set(Errno::EINVAL);
+        return SEM_FAILED;
+    }
+
+    let (kernel_handle, last_error) = win32call! {CreateSemaphoreA(
+        core::ptr::null::<SECURITY_ATTRIBUTES>(),
+        value as i32,
+        i32::MAX,
+        kernel_name.as_ptr()
+    ), ignore ERROR_ALREADY_EXISTS};
+
+    if kernel_handle == 0 {
+        return SEM_FAILED;
+    }
+
+    if last_error == ERROR_ALREADY_EXISTS && (oflag & O_EXCL) != 0 {
+        CloseHandle(kernel_handle);
+        Errno::set(Errno::EEXIST);
+        return SEM_FAILED;
+    }
+
+    new_named_semaphore(kernel_handle)
 }
 
 pub unsafe fn sem_post(sem: *mut sem_t) -> int {
+    if (*sem).kernel_handle != 0 {
+        let (has_released, last_error) = win32call! {ReleaseSemaphore((*sem).kernel_handle, 1, core::ptr::null_mut::<i32>()), ignore ERROR_TOO_MANY_POSTS};
+        if has_released == FALSE {
+            if last_error == ERROR_TOO_MANY_POSTS {
+                Errno::set(Errno::EOVERFLOW);
+            }
+            return -1;
+        }
+
+        Errno::set(Errno::ESUCCES);
+        return 0;
+    }
+
     if (*sem).semaphore.value() == u32::MAX {
         Errno::set(Errno::EOVERFLOW);
         return -1;
@@ -50,6 +168,10 @@ pub unsafe fn sem_post(sem: *mut sem_t) -> int {
 }
 
 pub unsafe fn sem_wait(sem: *mut sem_t) -> int {
+    if (*sem).kernel_handle != 0 {
+        return wait_for_kernel_semaphore((*sem).kernel_handle, INFINITE);
+    }
+
     (*sem).semaphore.wait(|atomic, value| -> WaitAction {
         WaitOnAddress(
             (atomic as *const IoxAtomicU32).cast(),
@@ -66,6 +188,10 @@ pub unsafe fn sem_wait(sem: *mut sem_t) -> int {
 }
 
 pub unsafe fn sem_trywait(sem: *mut sem_t) -> int {
+    if (*sem).kernel_handle != 0 {
+        return wait_for_kernel_semaphore((*sem).kernel_handle, 0);
+    }
+
     match (*sem).semaphore.try_wait() {
         WaitResult::Success => {
             Errno::set(Errno::ESUCCES);
@@ -83,6 +209,14 @@ pub unsafe fn sem_timedwait(sem: *mut sem_t, abs_timeout: *const timespec) -> in
     let milli_seconds = (*abs_timeout).tv_sec * 1000 + (*abs_timeout).tv_nsec as i64 / 1000000
         - now.as_millis() as i64;
 
+    if (*sem).kernel_handle != 0 {
+        // a timeout of 0 would be reported as EAGAIN
+        return wait_for_kernel_semaphore(
+            (*sem).kernel_handle,
+            milli_seconds.clamp(1, (INFINITE - 1) as i64) as u32,
+        );
+    }
+
     #[allow(clippy::blocks_in_conditions)]
     match (*sem).semaphore.wait(|atomic, value| -> WaitAction {
         WaitOnAddress(
@@ -106,15 +240,39 @@ pub unsafe fn sem_timedwait(sem: *mut sem_t, abs_timeout: *const timespec) -> in
 }
 
 pub unsafe fn sem_unlink(name: *const c_char) -> int {
-    -1
+    // kernel semaphores are reference counted and removed when the last handle is closed,
+    // there is nothing to unlink
+    Errno::set(Errno::ESUCCES);
+    0
 }
 
 pub unsafe fn sem_open(name: *const c_char, oflag: int) -> *mut sem_t {
-    SEM_FAILED
+    let kernel_name = match kernel_semaphore_name(name) {
+        Some(v) => v,
+        None => return SEM_FAILED,
+    };
+
+    let (kernel_handle, _) = win32call! {OpenSemaphoreA(SEMAPHORE_ALL_ACCESS, FALSE, kernel_name.as_ptr()),
+    ignore ERROR_FILE_NOT_FOUND};
+
+    if kernel_handle == 0 {
+        return SEM_FAILED;
+    }
+
+    new_named_semaphore(kernel_handle)
 }
 
 pub unsafe fn sem_close(sem: *mut sem_t) -> int {
-    -1
+    if sem.is_null() || (*sem).kernel_handle == 0 {
+        Errno::set(Errno::EINVAL);
+        return -1;
+    }
+
+    let sem = Box::from_raw(sem);
+    win32call! { CloseHandle(sem.kernel_handle) };
+
+    Errno::set(Errno::ESUCCES);
+    0
 }
 
 pub unsafe fn sem_destroy(sem: *mut sem_t) -> int {
@@ -123,6 +281,7 @@ pub unsafe fn sem_destroy(sem: *mut sem_t) -> int {
 
 pub unsafe fn sem_init(sem: *mut sem_t, pshared: int, value: uint) -> int {
     (*sem).semaphore = Semaphore::new(value as _);
+    (*sem).kernel_handle = 0;
     Errno::set(Errno::ESUCCES);
     0
 }
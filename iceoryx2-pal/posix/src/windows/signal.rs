@@ -17,13 +17,16 @@
 use iceoryx2_pal_concurrency_sync::mutex::Mutex;
 use iceoryx2_pal_concurrency_sync::WaitAction;
 use windows_sys::Win32::{
-    Foundation::{FALSE, TRUE},
+    Foundation::{CloseHandle, FALSE, TRUE},
     System::{
         Console::{
             GenerateConsoleCtrlEvent, SetConsoleCtrlHandler, CTRL_BREAK_EVENT, CTRL_CLOSE_EVENT,
             CTRL_C_EVENT,
         },
-        Threading::{GetExitCodeProcess, OpenProcess, PROCESS_ALL_ACCESS},
+        Threading::{
+            GetExitCodeProcess, OpenProcess, TerminateProcess, PROCESS_ALL_ACCESS,
+            PROCESS_TERMINATE,
+        },
     },
 };
 
@@ -33,7 +36,7 @@ use crate::{
     posix::getpid,
     posix::types::*,
     posix::{sighandler_t, MemZeroedStruct},
-    posix::{Errno, SIGKILL, SIGSTOP, SIGTERM, SIGUSR1},
+    posix::{Errno, SA_SIGINFO, SIGKILL, SIGSTOP, SIGTERM, SIGUSR1},
     win32call,
 };
 
@@ -69,6 +72,23 @@ impl sigaction_t {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct siginfo_t {
+    si_signo: int,
+    si_pid: pid_t,
+}
+
+impl siginfo_t {
+    pub fn signo(&self) -> int {
+        self.si_signo
+    }
+
+    pub fn pid(&self) -> pid_t {
+        self.si_pid
+    }
+}
+
 struct SigAction {
     action: UnsafeCell<sigaction_t>,
     mtx: Mutex,
@@ -108,12 +128,26 @@ unsafe impl Sync for SigAction {}
 static SIG_ACTION: SigAction = SigAction::new();
 
 unsafe extern "system" fn ctrl_handler(value: u32) -> i32 {
-    let action =
-        core::mem::transmute::<sighandler_t, extern "C" fn(int)>(SIG_ACTION.get().sa_handler);
-
+    let sig_action = SIG_ACTION.get();
     let sigval = win32_event_to_signal(value);
 
-    action(sigval);
+    if sig_action.sa_flags & SA_SIGINFO != 0 {
+        let action = core::mem::transmute::<
+            sighandler_t,
+            extern "C" fn(int, *mut siginfo_t, *mut void),
+        >(sig_action.sa_handler);
+
+        // console control events are always raised by the process itself
+        let mut info = siginfo_t {
+            si_signo: sigval,
+            si_pid: getpid(),
+        };
+        action(sigval, &mut info, core::ptr::null_mut());
+    } else {
+        let action =
+            core::mem::transmute::<sighandler_t, extern "C" fn(int)>(sig_action.sa_handler);
+        action(sigval);
+    }
     TRUE
 }
 
@@ -161,6 +195,18 @@ pub unsafe fn kill(pid: pid_t, sig: int) -> int {
         };
     }
 
+    if pid != getpid() && (sig == SIGKILL || sig == SIGTERM) {
+        let (handle, _) = win32call! { OpenProcess(PROCESS_TERMINATE, FALSE, pid) };
+        if handle == 0 {
+            Errno::set(Errno::ESRCH);
+            return -1;
+        }
+
+        let (has_terminated, _) = win32call! { TerminateProcess(handle, 128 + sig as u32) };
+        win32call! { CloseHandle(handle) };
+        return if has_terminated == FALSE { -1 } else { 0 };
+    }
+
     if pid != getpid() {
         Errno::set(Errno::ENOTSUP);
         return -1;
@@ -191,11 +237,13 @@ pub unsafe fn sigismember(set: *const sigset_t, signo: int) -> int {
 }
 
 pub unsafe fn sigfillset(set: *mut sigset_t) -> int {
-    unimplemented!("Windows does not support the sigset_t.");
+    // windows has no signal masks, the emulated sigset_t carries no state
+    0
 }
 
 pub unsafe fn sigemptyset(set: *mut sigset_t) -> int {
-    unimplemented!("Windows does not support the sigset_t.");
+    // windows has no signal masks, the emulated sigset_t carries no state
+    0
 }
 
 pub unsafe fn sigpending(set: *mut sigset_t) -> int {
@@ -272,6 +272,25 @@ pub unsafe fn listen(socket: int, backlog: int) -> int {
     0
 }
 
+pub unsafe fn shutdown(socket: int, how: int) -> int {
+    let socket_handle = match HandleTranslator::get_instance().get(socket) {
+        Some(FdHandleEntry::Socket(s)) => s.fd,
+        Some(FdHandleEntry::UdsDatagramSocket(s)) => s.fd,
+        None | Some(_) => {
+            Errno::set(Errno::EBADF);
+            return -1;
+        }
+    };
+
+    let (shutdown_result, _) =
+        win32call! {winsock windows_sys::Win32::Networking::WinSock::shutdown(socket_handle, how)};
+    if shutdown_result == SOCKET_ERROR {
+        return -1;
+    }
+
+    0
+}
+
 pub unsafe fn bind(socket: int, address: *const sockaddr, address_len: socklen_t) -> int {
     match HandleTranslator::get_instance().get(socket) {
         Some(FdHandleEntry::Socket(s)) => {
@@ -516,6 +535,23 @@ pub unsafe fn getsockname(socket: int, address: *mut sockaddr, address_len: *mut
     0
 }
 
+pub unsafe fn getpeername(socket: int, address: *mut sockaddr, address_len: *mut socklen_t) -> int {
+    let socket_fd = match HandleTranslator::get_instance().get(socket) {
+        Some(FdHandleEntry::Socket(s)) => s.fd,
+        Some(FdHandleEntry::UdsDatagramSocket(s)) => s.fd,
+        None | Some(_) => {
+            Errno::set(Errno::EBADF);
+            return -1;
+        }
+    };
+
+    let (peer_name_result, _) = win32call! {winsock windows_sys::Win32::Networking::WinSock::getpeername(socket_fd, address as *mut SOCKADDR, address_len as _) };
+    if peer_name_result == SOCKET_ERROR {
+        return -1;
+    }
+    0
+}
+
 pub unsafe fn send(socket: int, message: *const void, length: size_t, flags: int) -> ssize_t {
     match HandleTranslator::get_instance().get_socket(socket) {
         Some(s) => {
@@ -0,0 +1,233 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+#![allow(clippy::missing_safety_doc)]
+
+use std::sync::Mutex;
+
+use windows_sys::Win32::{
+    Foundation::{CloseHandle, FALSE, HANDLE},
+    System::Threading::{CreateProcessA, PROCESS_INFORMATION, STARTUPINFOA},
+};
+
+use crate::posix::{c_string_length, types::*, Errno, MemZeroedStruct};
+use crate::win32call;
+
+use super::settings::MAX_PATH_LENGTH;
+
+impl MemZeroedStruct for STARTUPINFOA {}
+impl MemZeroedStruct for PROCESS_INFORMATION {}
+
+// The process handle must stay open until the exit code was acquired with waitpid(),
+// otherwise the exit code is lost and the pid could be reused.
+static SPAWNED_PROCESSES: Mutex<Vec<(pid_t, HANDLE)>> = Mutex::new(Vec::new());
+
+pub(crate) fn spawned_process_handle(pid: pid_t) -> Option<HANDLE> {
+    SPAWNED_PROCESSES
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|(p, _)| *p == pid)
+        .map(|(_, handle)| *handle)
+}
+
+pub(crate) fn remove_spawned_process(pid: pid_t) {
+    SPAWNED_PROCESSES.lock().unwrap().retain(|(p, _)| *p != pid);
+}
+
+unsafe fn as_bytes<'a>(value: *const c_char) -> &'a [u8] {
+    core::slice::from_raw_parts(value.cast(), c_string_length(value))
+}
+
+// quotes the argument so that it is split correctly by CommandLineToArgvW
+fn append_argument(command_line: &mut Vec<u8>, argument: &[u8]) {
+    if !argument.is_empty()
+        && !argument
+            .iter()
+            .any(|c| matches!(c, b' ' | b'\t' | b'\n' | b'\x0b' | b'"'))
+    {
+        command_line.extend_from_slice(argument);
+        return;
+    }
+
+    command_line.push(b'"');
+    let mut number_of_backslashes = 0;
+    for c in argument {
+        match c {
+            b'\\' => number_of_backslashes += 1,
+            b'"' => {
+                command_line.resize(command_line.len() + number_of_backslashes * 2 + 1, b'\\');
+                command_line.push(b'"');
+                number_of_backslashes = 0;
+            }
+            _ => {
+                command_line.resize(command_line.len() + number_of_backslashes, b'\\');
+                command_line.push(*c);
+                number_of_backslashes = 0;
+            }
+        }
+    }
+    command_line.resize(command_line.len() + number_of_backslashes * 2, b'\\');
+    command_line.push(b'"');
+}
+
+pub unsafe fn posix_spawn(
+    pid: *mut pid_t,
+    path: *const c_char,
+    file_actions: *const posix_spawn_file_actions_t,
+    _attrp: *const posix_spawnattr_t,
+    argv: *const *mut c_char,
+    envp: *const *mut c_char,
+) -> int {
+    let mut command_line = vec![];
+    let mut n = 0;
+    while !argv.is_null() && !(*argv.add(n)).is_null() {
+        if n != 0 {
+            command_line.push(b' ');
+        }
+        append_argument(&mut command_line, as_bytes(*argv.add(n)));
+        n += 1;
+    }
+    command_line.push(0);
+
+    // the environment block is a sequence of null-terminated strings terminated by an
+    // additional null
+    let mut environment = vec![];
+    if !envp.is_null() {
+        n = 0;
+        while !(*envp.add(n)).is_null() {
+            environment.extend_from_slice(as_bytes(*envp.add(n)));
+            environment.push(0);
+            n += 1;
+        }
+        if n == 0 {
+            environment.push(0);
+        }
+        environment.push(0);
+    }
+
+    let environment_ptr = if envp.is_null() {
+        core::ptr::null()
+    } else {
+        environment.as_ptr() as *const void
+    };
+
+    let working_directory = if !file_actions.is_null() && (*file_actions).has_working_directory {
+        (*file_actions).working_directory.as_ptr()
+    } else {
+        core::ptr::null()
+    };
+
+    let mut startup_info = STARTUPINFOA::new_zeroed();
+    startup_info.cb = core::mem::size_of::<STARTUPINFOA>() as _;
+    let mut process_info = PROCESS_INFORMATION::new_zeroed();
+
+    // handles are never inherited since the file descriptors are emulated and
+    // only valid inside the process that created them
+    let (has_created, _) = win32call! { CreateProcessA(
+    path.cast(),
+    command_line.as_mut_ptr(),
+    core::ptr::null(),
+    core::ptr::null(),
+    FALSE,
+    0,
+    environment_ptr,
+    working_directory,
+    &startup_info,
+    &mut process_info) };
+
+    if has_created == FALSE {
+        return Errno::get() as _;
+    }
+
+    win32call! { CloseHandle(process_info.hThread) };
+    SPAWNED_PROCESSES
+        .lock()
+        .unwrap()
+        .push((process_info.dwProcessId, process_info.hProcess));
+    *pid = process_info.dwProcessId;
+
+    0
+}
+
+pub unsafe fn posix_spawn_file_actions_init(file_actions: *mut posix_spawn_file_actions_t) -> int {
+    *file_actions = posix_spawn_file_actions_t::new_zeroed();
+    0
+}
+
+pub unsafe fn posix_spawn_file_actions_destroy(
+    _file_actions: *mut posix_spawn_file_actions_t,
+) -> int {
+    0
+}
+
+pub unsafe fn posix_spawn_file_actions_addclose(
+    _file_actions: *mut posix_spawn_file_actions_t,
+    _fd: int,
+) -> int {
+    // nothing to do, handles are never inherited
+    0
+}
+
+pub unsafe fn posix_spawn_file_actions_adddup2(
+    _file_actions: *mut posix_spawn_file_actions_t,
+    _fd: int,
+    _newfd: int,
+) -> int {
+    Errno::ENOTSUP as _
+}
+
+pub unsafe fn posix_spawn_file_actions_addchdir(
+    file_actions: *mut posix_spawn_file_actions_t,
+    path: *const c_char,
+) -> int {
+    let path = as_bytes(path);
+    if path.len() >= MAX_PATH_LENGTH {
+        return Errno::ENAMETOOLONG as _;
+    }
+
+    (*file_actions).working_directory = [0; MAX_PATH_LENGTH];
+    (*file_actions).working_directory[..path.len()].copy_from_slice(path);
+    (*file_actions).has_working_directory = true;
+    0
+}
+
+pub unsafe fn posix_spawnattr_init(attr: *mut posix_spawnattr_t) -> int {
+    *attr = posix_spawnattr_t::new_zeroed();
+    0
+}
+
+pub unsafe fn posix_spawnattr_destroy(_attr: *mut posix_spawnattr_t) -> int {
+    0
+}
+
+pub unsafe fn posix_spawnattr_setflags(attr: *mut posix_spawnattr_t, flags: short) -> int {
+    (*attr).flags = flags;
+    0
+}
+
+pub unsafe fn posix_spawnattr_setsigmask(
+    _attr: *mut posix_spawnattr_t,
+    _sigmask: *const sigset_t,
+) -> int {
+    // windows has no signal masks, a new process always starts without them
+    0
+}
+
+pub unsafe fn posix_spawnattr_setsigdefault(
+    _attr: *mut posix_spawnattr_t,
+    _sigdefault: *const sigset_t,
+) -> int {
+    // windows has no signal dispositions that could be inherited
+    0
+}
@@ -57,6 +57,11 @@ pub unsafe fn stat(path: *const c_char, buf: *mut stat_t) -> int {
     0
 }
 
+pub unsafe fn lstat(path: *const c_char, buf: *mut stat_t) -> int {
+    // symbolic links are not supported, therefore there is nothing that could be followed
+    stat(path, buf)
+}
+
 pub unsafe fn umask(mask: mode_t) -> mode_t {
     mode_t::MAX
 }
@@ -14,6 +14,9 @@
 #![allow(clippy::missing_safety_doc)]
 
 use crate::posix::types::*;
+use crate::posix::{Errno, MemZeroedStruct};
+
+use super::settings::MAX_PATH_LENGTH;
 
 pub unsafe fn malloc(size: size_t) -> *mut void {
     crate::internal::malloc(size as _)
@@ -30,3 +33,23 @@ pub unsafe fn realloc(ptr: *mut void, size: size_t) -> *mut void {
 pub unsafe fn free(ptr: *mut void) {
     crate::internal::free(ptr)
 }
+
+pub unsafe fn realpath(path: *const c_char, resolved_path: *mut c_char) -> *mut c_char {
+    // symbolic links are not supported, therefore it is sufficient to acquire the absolute
+    // path and to verify that it exists
+    let result = crate::internal::_fullpath(resolved_path, path, MAX_PATH_LENGTH as _);
+    if result.is_null() {
+        Errno::set(Errno::ENAMETOOLONG);
+        return core::ptr::null_mut();
+    }
+
+    let mut buffer = stat_t::new_zeroed();
+    if crate::posix::stat(result, &mut buffer) != 0 {
+        if resolved_path.is_null() {
+            free(result as *mut void);
+        }
+        return core::ptr::null_mut();
+    }
+
+    result
+}
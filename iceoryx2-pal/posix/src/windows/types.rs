@@ -22,7 +22,7 @@ use iceoryx2_pal_concurrency_sync::mutex::Mutex;
 use iceoryx2_pal_concurrency_sync::rwlock::*;
 use iceoryx2_pal_concurrency_sync::semaphore::Semaphore;
 use windows_sys::Win32::Foundation::{HANDLE, INVALID_HANDLE_VALUE};
-use windows_sys::Win32::Networking::WinSock::{SOCKADDR_IN, TIMEVAL};
+use windows_sys::Win32::Networking::WinSock::{IP_MREQ, SOCKADDR_IN, TIMEVAL};
 
 use crate::posix::MemZeroedStruct;
 use crate::posix::*;
@@ -60,11 +60,26 @@ pub type uint = u32;
 pub type ushort = u16;
 pub type ulong = u64;
 pub type void = core::ffi::c_void;
+pub type acl_t = *mut void;
 
 #[derive(Clone, Copy, Debug)]
 pub struct sigset_t {}
 impl MemZeroedStruct for sigset_t {}
 
+#[derive(Clone, Copy)]
+pub struct posix_spawn_file_actions_t {
+    pub(crate) working_directory: [u8; MAX_PATH_LENGTH],
+    pub(crate) has_working_directory: bool,
+}
+
+impl MemZeroedStruct for posix_spawn_file_actions_t {}
+
+#[derive(Clone, Copy)]
+pub struct posix_spawnattr_t {
+    pub(crate) flags: short,
+}
+impl MemZeroedStruct for posix_spawnattr_t {}
+
 pub struct pthread_barrier_t {
     pub(crate) barrier: Barrier,
 }
@@ -168,11 +183,14 @@ impl MemZeroedStruct for pthread_mutexattr_t {}
 
 pub struct sem_t {
     pub(crate) semaphore: Semaphore,
+    // named semaphores are backed by a kernel semaphore, unnamed ones use 0
+    pub(crate) kernel_handle: HANDLE,
 }
 impl MemZeroedStruct for sem_t {
     fn new_zeroed() -> Self {
         Self {
             semaphore: Semaphore::new(0),
+            kernel_handle: 0,
         }
     }
 }
@@ -321,3 +339,16 @@ impl SockAddrIn for sockaddr_in {
         unsafe { self.sin_addr.S_un.S_addr }
     }
 }
+
+pub type ip_mreq = IP_MREQ;
+impl MemZeroedStruct for ip_mreq {}
+
+impl IpMreq for ip_mreq {
+    fn set_multiaddr(&mut self, value: u32) {
+        self.imr_multiaddr.S_un.S_addr = value;
+    }
+
+    fn set_interface(&mut self, value: u32) {
+        self.imr_interface.S_un.S_addr = value;
+    }
+}
@@ -16,7 +16,8 @@
 
 use windows_sys::Win32::{
     Foundation::{
-        CloseHandle, ERROR_FILE_NOT_FOUND, ERROR_NO_MORE_FILES, FALSE, INVALID_HANDLE_VALUE, TRUE,
+        CloseHandle, ERROR_FILE_NOT_FOUND, ERROR_HANDLE_EOF, ERROR_NO_MORE_FILES, FALSE, HANDLE,
+        INVALID_HANDLE_VALUE, TRUE,
     },
     Networking::WinSock::{
         closesocket, WSADuplicateSocketA, WSASocketA, INVALID_SOCKET, SOCKET_ERROR,
@@ -80,6 +81,7 @@ pub unsafe fn sysconf(name: int) -> long {
         _SC_MONOTONIC_CLOCK => 0,
         _SC_PAGESIZE => system_info.dwPageSize as long,
         _SC_NPROCESSORS_CONF => system_info.dwNumberOfProcessors as long,
+        _SC_NPROCESSORS_ONLN => system_info.dwNumberOfProcessors as long,
         _SC_VERSION => POSIX_VERSION,
         _SC_BARRIERS => POSIX_VERSION,
         _SC_MAPPED_FILES => POSIX_VERSION,
@@ -254,6 +256,73 @@ pub unsafe fn write(fd: int, buf: *const void, count: size_t) -> ssize_t {
     }
 }
 
+unsafe fn positional_io<F: FnOnce(HANDLE, *mut OVERLAPPED) -> ssize_t>(
+    fd: int,
+    offset: off_t,
+    io_call: F,
+) -> ssize_t {
+    match HandleTranslator::get_instance().get(fd) {
+        Some(FdHandleEntry::File(handle)) => {
+            // ReadFile/WriteFile move the file pointer of synchronous handles even when an
+            // offset is provided, therefore it is restored afterwards
+            let mut current_position = 0;
+            let (has_success, _) = win32call! {SetFilePointerEx(handle.handle, 0, &mut current_position, FILE_CURRENT)};
+            if has_success == FALSE {
+                return -1;
+            }
+
+            let mut overlapped = OVERLAPPED::new_zeroed();
+            overlapped.Anonymous.Anonymous.Offset = (offset as u64 & 0xffffffff) as u32;
+            overlapped.Anonymous.Anonymous.OffsetHigh = (offset as u64 >> 32) as u32;
+
+            let result = io_call(handle.handle, &mut overlapped);
+
+            win32call! {SetFilePointerEx(handle.handle, current_position, core::ptr::null_mut(), FILE_BEGIN)};
+            result
+        }
+        _ => {
+            Errno::set(Errno::EBADF);
+            -1
+        }
+    }
+}
+
+pub unsafe fn pread(fd: int, buf: *mut void, count: size_t, offset: off_t) -> ssize_t {
+    positional_io(fd, offset, |handle, overlapped| {
+        let mut bytes_read = 0;
+        let (file_read, last_error) = win32call! {ReadFile(
+            handle,
+            buf,
+            count as u32,
+            &mut bytes_read,
+            overlapped,
+        ), ignore ERROR_HANDLE_EOF};
+        if file_read != FALSE || last_error == ERROR_HANDLE_EOF {
+            bytes_read as ssize_t
+        } else {
+            -1
+        }
+    })
+}
+
+pub unsafe fn pwrite(fd: int, buf: *const void, count: size_t, offset: off_t) -> ssize_t {
+    positional_io(fd, offset, |handle, overlapped| {
+        let mut bytes_written = 0;
+        let (file_written, _) = win32call! {WriteFile(
+            handle,
+            buf as *const u8,
+            count as u32,
+            &mut bytes_written,
+            overlapped,
+        )};
+        if file_written == FALSE {
+            -1
+        } else {
+            bytes_written as ssize_t
+        }
+    })
+}
+
 pub unsafe fn access(pathname: *const c_char, mode: int) -> int {
     let (attributes, _) =
         win32call! {GetFileAttributesA(pathname as *const u8), ignore ERROR_FILE_NOT_FOUND};
@@ -318,6 +387,11 @@ pub unsafe fn getgid() -> gid_t {
     gid_t::MAX
 }
 
+pub unsafe fn getgroups(size: int, list: *mut gid_t) -> int {
+    // windows does not know the concept of supplementary groups
+    0
+}
+
 pub unsafe fn rmdir(pathname: *const c_char) -> int {
     let (has_removed, _) =
         win32call! {RemoveDirectoryA(pathname as*const u8), ignore ERROR_FILE_NOT_FOUND};
@@ -0,0 +1,74 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_snake_case)]
+#![allow(clippy::missing_safety_doc)]
+
+use windows_sys::Win32::{
+    Foundation::{CloseHandle, WAIT_OBJECT_0, WAIT_TIMEOUT},
+    System::Threading::{GetExitCodeProcess, WaitForSingleObject, INFINITE},
+};
+
+use crate::posix::{constants::*, types::*, Errno};
+use crate::win32call;
+
+use super::spawn::{remove_spawned_process, spawned_process_handle};
+
+pub unsafe fn waitpid(pid: pid_t, status: *mut int, options: int) -> pid_t {
+    let handle = match spawned_process_handle(pid) {
+        Some(handle) => handle,
+        None => {
+            Errno::set(Errno::ECHILD);
+            return -1i32 as _;
+        }
+    };
+
+    let timeout = if options & WNOHANG != 0 { 0 } else { INFINITE };
+    let (wait_result, _) = win32call! { WaitForSingleObject(handle, timeout) };
+    if wait_result == WAIT_TIMEOUT {
+        return 0;
+    } else if wait_result != WAIT_OBJECT_0 {
+        return -1i32 as _;
+    }
+
+    let mut exit_code = 0;
+    let (has_exit_code, _) = win32call! { GetExitCodeProcess(handle, &mut exit_code) };
+    if has_exit_code == 0 {
+        return -1i32 as _;
+    }
+
+    remove_spawned_process(pid);
+    win32call! { CloseHandle(handle) };
+
+    // windows has no signals, every process exits with an exit code
+    if !status.is_null() {
+        *status = ((exit_code & 0xff) << 8) as int;
+    }
+
+    pid
+}
+
+pub fn WIFEXITED(status: int) -> bool {
+    WTERMSIG(status) == 0
+}
+
+pub fn WEXITSTATUS(status: int) -> int {
+    (status >> 8) & 0xff
+}
+
+pub fn WIFSIGNALED(status: int) -> bool {
+    WTERMSIG(status) != 0 && WTERMSIG(status) != 0x7f
+}
+
+pub fn WTERMSIG(status: int) -> int {
+    status & 0x7f
+}
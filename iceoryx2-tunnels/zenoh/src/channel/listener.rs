@@ -83,13 +83,15 @@ impl<ServiceType: iceoryx2::service::Service> Channel for ListenerChannel<Servic
     /// Propagate remote notifications for a particular service to local listeners.
     fn propagate(&self) -> Result<(), PropagationError> {
         // Collect all notified ids
-        let mut received_ids: HashSet<usize> = HashSet::new();
+        let mut received_ids: HashSet<EventId> = HashSet::new();
         while let Ok(Some(sample)) = self.z_listener.try_recv() {
             let payload = sample.payload();
             if payload.len() == std::mem::size_of::<usize>() {
                 let id: usize =
                     unsafe { payload.to_bytes().as_ptr().cast::<usize>().read_unaligned() };
-                received_ids.insert(id);
+                if let Ok(id) = EventId::new(id) {
+                    received_ids.insert(id);
+                }
             } else {
                 // Error, invalid event id. Skip.
             }
@@ -99,14 +101,14 @@ impl<ServiceType: iceoryx2::service::Service> Channel for ListenerChannel<Servic
         for event_id in received_ids {
             fail!(
                 from &self,
-                when self.iox_notifier.__internal_notify(EventId::new(event_id), true),
+                when self.iox_notifier.__internal_notify(event_id, true),
                 with PropagationError::IceoryxPort,
                 "failed to propagate remote notification to local listeners"
             );
 
             info!(
                 "PROPAGATE ListenerChannel(EventId={}) {} [{}]",
-                event_id,
+                event_id.as_value(),
                 self.iox_service_config.service_id().as_str(),
                 self.iox_service_config.name()
             );
@@ -608,9 +608,9 @@ mod zenoh_tunnel_events {
 
         // ==================== TEST =====================
         // Send multiple notifications on different event ids
-        let event_a = EventId::new(42);
-        let event_b = EventId::new(73);
-        let event_c = EventId::new(127);
+        let event_a = EventId::new(42).unwrap();
+        let event_b = EventId::new(73).unwrap();
+        let event_c = EventId::new(127).unwrap();
 
         const NUM_NOTIFICATIONS: usize = 10;
         for _ in 0..NUM_NOTIFICATIONS {
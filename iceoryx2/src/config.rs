@@ -541,11 +541,11 @@ impl Config {
     }
 
     fn load_user_config_path(origin: &str, msg: &str) -> Result<FilePath, ConfigIterationFailure> {
-        let user = fail!(from origin,
-                         when iceoryx2_bb_posix::user::User::from_self(),
+        let identity = fail!(from origin,
+                         when iceoryx2_bb_posix::user::ProcessIdentity::current(),
                          with ConfigIterationFailure::UnableToAcquireCurrentUserDetails,
                          "{} since the current user details could not be acquired.", msg);
-        match user.details() {
+        match identity.user().details() {
             Some(details) => {
                 let mut user_config = details.config_dir().clone();
                 fail!(from origin,
@@ -10,8 +10,11 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-/// Defines the maximum length of a [`Service`](crate::service::Service)
-pub const MAX_SERVICE_NAME_LENGTH: usize = 255;
+/// Defines the maximum length of a [`Service`](crate::service::Service). The name is never
+/// used directly as file name, the underlying resources are identified by the
+/// [`ServiceId`](crate::service::service_id::ServiceId), a hash of the name, and therefore the
+/// length is not restricted by the platforms maximum file name length.
+pub const MAX_SERVICE_NAME_LENGTH: usize = 1024;
 
 /// Defines how many [`Attribute`](crate::service::attribute::Attribute)s a
 /// [`Service`](crate::service::Service) can have at most
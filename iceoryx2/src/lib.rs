@@ -246,7 +246,7 @@
 //! let mut counter: usize = 0;
 //! while node.wait(CYCLE_TIME).is_ok() {
 //!     counter += 1;
-//!     notifier.notify_with_custom_event_id(EventId::new(counter))?;
+//!     notifier.notify_with_custom_event_id(EventId::new(counter)?)?;
 //!
 //!     println!("Trigger event with id {} ...", counter);
 //! }
@@ -422,11 +422,11 @@
 //!     //          configurations that use a bitset as event tracking mechanism
 //!     .event_id_max_value(256)
 //!     // optional event id that is emitted when a new notifier was created
-//!     .notifier_created_event(EventId::new(999))
+//!     .notifier_created_event(EventId::new(999)?)
 //!     // optional event id that is emitted when a notifier is dropped
-//!     .notifier_dropped_event(EventId::new(0))
+//!     .notifier_dropped_event(EventId::new(0)?)
 //!     // optional event id that is emitted when a notifier is identified as dead
-//!     .notifier_dead_event(EventId::new(2000))
+//!     .notifier_dead_event(EventId::new(2000)?)
 //!     // the deadline of the service defines how long a listener has to wait at most until
 //!     // a signal will be received
 //!     .deadline(Duration::from_secs(1))
@@ -13,7 +13,7 @@
 use iceoryx2_bb_container::slotmap::SlotMapKey;
 use iceoryx2_cal::shm_allocator::PointerOffset;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub(crate) struct ChunkDetails {
     pub(crate) connection_key: SlotMapKey,
     pub(crate) offset: PointerOffset,
@@ -22,13 +22,13 @@
 //!
 //! let mut listener = event.listener_builder().create()?;
 //! let mut notifier = event.notifier_builder()
-//!     .default_event_id(EventId::new(12))
+//!     .default_event_id(EventId::new(12)?)
 //!     .create()?;
 //!
 //! // notify the listener with default event id 12
 //! notifier.notify()?;
 //!
-//! notifier.notify_with_custom_event_id(EventId::new(5));
+//! notifier.notify_with_custom_event_id(EventId::new(5)?);
 //!
 //! while let Some(event_id) = listener.try_wait_one()? {
 //!     println!("event was triggered with id: {:?}", event_id);
@@ -40,3 +40,7 @@
 
 /// Type that allows to identify an event uniquely.
 pub type EventId = iceoryx2_cal::event::TriggerId;
+
+/// Failure that is returned by [`EventId::new()`] when the value exceeds
+/// [`EventId::max_supported()`].
+pub type EventIdCreateError = iceoryx2_cal::event::TriggerIdCreateError;
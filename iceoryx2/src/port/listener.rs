@@ -107,6 +107,10 @@ impl core::error::Error for ListenerCreateError {}
 #[derive(Debug)]
 pub struct Listener<Service: service::Service> {
     dynamic_listener_handle: Option<ContainerHandle>,
+    // declared before the listener so that the helper thread is joined before the listeners
+    // file descriptor is closed
+    #[cfg(feature = "async")]
+    async_waiter: std::sync::Mutex<Option<asynchronous::ListenerWaiter>>,
     listener:
         Service::ArcThreadSafetyPolicy<<Service::Event as iceoryx2_cal::event::Event>::Listener>,
     service_state: Arc<ServiceState<Service, NoResource>>,
@@ -167,7 +171,7 @@ impl<Service: service::Service> Listener<Service> {
 
         let listener = fail!(from origin,
                              when <Service::Event as iceoryx2_cal::event::Event>::ListenerBuilder::new(&event_name).config(&event_config)
-                                .trigger_id_max(TriggerId::new(service.static_config.event().event_id_max_value))
+                                .trigger_id_max(TriggerId::new_unchecked(service.static_config.event().event_id_max_value))
                                 .create(),
                              with ListenerCreateError::ResourceCreationFailed,
                              "{} since the underlying event concept \"{}\" could not be created.", msg, event_name);
@@ -183,6 +187,8 @@ impl<Service: service::Service> Listener<Service> {
         let mut new_self = Self {
             service_state: service.clone(),
             dynamic_listener_handle: None,
+            #[cfg(feature = "async")]
+            async_waiter: std::sync::Mutex::new(None),
             listener,
             listener_id,
         };
@@ -210,6 +216,18 @@ impl<Service: service::Service> Listener<Service> {
         Ok(new_self)
     }
 
+    /// Returns the number of [`crate::port::notifier::Notifier`]s that are currently connected
+    /// to the [`Service`](crate::service::Service) and can notify the [`Listener`]. Notifiers
+    /// can connect or disconnect at any time, therefore the value is only a snapshot and may
+    /// already be outdated when it is returned.
+    pub fn number_of_connected_notifiers(&self) -> usize {
+        self.service_state
+            .dynamic_storage
+            .get()
+            .event()
+            .number_of_notifiers()
+    }
+
     /// Returns the deadline of the corresponding [`Service`](crate::service::Service).
     pub fn deadline(&self) -> Option<Duration> {
         self.service_state
@@ -245,13 +263,20 @@ impl<Service: service::Service> Listener<Service> {
     /// Blocking wait for new [`EventId`]s. Unblocks as soon
     /// as an [`EventId`] was received and then collects all [`EventId`]s that were received and
     /// calls the provided callback is with the [`EventId`] as input argument.
+    /// Spurious wakeups of the underlying event mechanism are handled internally, the callback
+    /// is called at least once before the function returns successfully.
     pub fn blocking_wait_all<F: FnMut(EventId)>(
         &self,
-        callback: F,
+        mut callback: F,
     ) -> Result<(), ListenerWaitError> {
         use iceoryx2_cal::event::Listener;
-        fail!(from self, when self.listener.lock().blocking_wait_all(callback),
-            "Failed to while calling blocking_wait on underlying event::Listener");
+        let mut has_received_event = false;
+        while !has_received_event {
+            fail!(from self, when self.listener.lock().blocking_wait_all(|id| {
+                has_received_event = true;
+                callback(id)
+            }), "Failed to while calling blocking_wait on underlying event::Listener");
+        }
         Ok(())
     }
 
@@ -277,15 +302,31 @@ impl<Service: service::Service> Listener<Service> {
     }
 
     /// Blocking wait for a new [`EventId`].
-    /// Sporadic wakeups can occur and if no [`EventId`] was notified it returns [`None`].
+    /// Spurious wakeups of the underlying event mechanism are handled internally, therefore it
+    /// returns only when an [`EventId`] was received and never returns [`None`].
     /// On error it returns [`ListenerWaitError`] is returned which describes the error
     /// in detail.
     pub fn blocking_wait_one(&self) -> Result<Option<EventId>, ListenerWaitError> {
         use iceoryx2_cal::event::Listener;
-        Ok(
-            fail!(from self, when self.listener.lock().blocking_wait_one(),
-            "Failed to while calling blocking_wait on underlying event::Listener"),
-        )
+        loop {
+            if let Some(event_id) = fail!(from self, when self.listener.lock().blocking_wait_one(),
+                "Failed to while calling blocking_wait on underlying event::Listener")
+            {
+                return Ok(Some(event_id));
+            }
+        }
+    }
+
+    /// Non-blocking discard of all pending [`EventId`]s without processing them, for instance
+    /// to get rid of stale events after a reconnect. Returns the number of discarded
+    /// [`EventId`]s. It is faster than calling [`Listener::try_wait_one()`] repeatedly since
+    /// all pending [`EventId`]s are acquired at once.
+    pub fn drain(&self) -> Result<usize, ListenerWaitError> {
+        use iceoryx2_cal::event::Listener;
+        let mut number_of_discarded_events = 0;
+        fail!(from self, when self.listener.lock().try_wait_all(|_| number_of_discarded_events += 1),
+            "Failed to while draining the underlying event::Listener");
+        Ok(number_of_discarded_events)
     }
 
     /// Returns the [`UniqueListenerId`] of the [`Listener`]
@@ -294,6 +335,300 @@ impl<Service: service::Service> Listener<Service> {
     }
 }
 
+#[cfg(feature = "async")]
+pub use asynchronous::ListenerWaitFuture;
+
+#[cfg(feature = "async")]
+mod asynchronous {
+    use core::task::Waker;
+    use iceoryx2_bb_log::warn;
+    use iceoryx2_bb_posix::socket_pair::StreamingSocket;
+    use iceoryx2_bb_posix::thread::{Thread, ThreadBuilder};
+    use iceoryx2_cal::reactor::{Reactor, ReactorBuilder, ReactorWaitError};
+    use std::sync::{Mutex, MutexGuard};
+
+    use super::*;
+
+    const ARM_COMMAND: u8 = 1;
+    const STOP_COMMAND: u8 = 2;
+
+    fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+        match mutex.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    impl<Service: service::Service> Listener<Service>
+    where
+        <Service::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing,
+    {
+        /// Returns a [`Future`](core::future::Future) that resolves with the next received
+        /// [`EventId`]. It does not depend on a specific async runtime. The first pending poll
+        /// starts a helper thread that is reused by all subsequent polls until the [`Listener`]
+        /// is dropped. Whenever a poll is pending, the helper thread waits on the [`Listener`]s
+        /// file descriptor with the [`Reactor`] of the [`Service`](crate::service::Service) and
+        /// wakes the task as soon as an [`EventId`] arrives.
+        ///
+        /// ```
+        /// use iceoryx2::prelude::*;
+        /// use iceoryx2::port::listener::Listener;
+        /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+        /// let node = NodeBuilder::new().create::<ipc_threadsafe::Service>()?;
+        /// let event = node.service_builder(&"MyEventName".try_into()?)
+        ///     .event()
+        ///     .open_or_create()?;
+        ///
+        /// let listener = event.listener_builder().create()?;
+        ///
+        /// async fn process(listener: &Listener<ipc_threadsafe::Service>) {
+        ///     while let Ok(event_id) = listener.wait_async().await {
+        ///         println!("event was triggered with id: {:?}", event_id);
+        ///     }
+        /// }
+        /// # Ok(())
+        /// # }
+        /// ```
+        pub fn wait_async(&self) -> ListenerWaitFuture<'_, Service> {
+            ListenerWaitFuture { listener: self }
+        }
+
+        fn arm_async_waiter(&self, waker: &Waker) -> Result<(), ListenerWaitError> {
+            let mut async_waiter = lock(&self.async_waiter);
+            let waiter = match async_waiter.take() {
+                Some(waiter) => waiter,
+                None => ListenerWaiter::new::<Service::Reactor>(self.file_descriptor())?,
+            };
+
+            if !waiter.arm(waker) {
+                fail!(from self, with ListenerWaitError::InternalFailure,
+                    "Unable to wait asynchronously on the listener since the underlying reactor failed.");
+            }
+
+            *async_waiter = Some(waiter);
+            Ok(())
+        }
+    }
+
+    /// The [`Future`](core::future::Future) returned by [`Listener::wait_async()`].
+    #[derive(Debug)]
+    pub struct ListenerWaitFuture<'listener, Service: service::Service> {
+        listener: &'listener Listener<Service>,
+    }
+
+    impl<Service: service::Service> core::future::Future for ListenerWaitFuture<'_, Service>
+    where
+        <Service::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing,
+    {
+        type Output = Result<EventId, ListenerWaitError>;
+
+        fn poll(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut core::task::Context<'_>,
+        ) -> core::task::Poll<Self::Output> {
+            match self.listener.try_wait_one() {
+                Ok(Some(event_id)) => core::task::Poll::Ready(Ok(event_id)),
+                Ok(None) => match self.listener.arm_async_waiter(cx.waker()) {
+                    // an event that arrives after try_wait_one() makes the file descriptor
+                    // readable, the armed helper thread then wakes the task immediately
+                    Ok(()) => core::task::Poll::Pending,
+                    Err(e) => core::task::Poll::Ready(Err(e)),
+                },
+                Err(e) => core::task::Poll::Ready(Err(e)),
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    struct ListenerFileDescriptor(FileDescriptor);
+
+    impl FileDescriptorBased for ListenerFileDescriptor {
+        fn file_descriptor(&self) -> &FileDescriptor {
+            &self.0
+        }
+    }
+
+    impl SynchronousMultiplexing for ListenerFileDescriptor {}
+
+    #[derive(Debug, Default)]
+    struct ListenerWaiterState {
+        wakers: Vec<Waker>,
+        is_armed: bool,
+        has_failed: bool,
+    }
+
+    /// Helper thread with its own [`Reactor`] that is created on the first pending poll of a
+    /// [`ListenerWaitFuture`] and lives as long as the [`Listener`]. Every pending poll arms it,
+    /// it then waits until the listeners file descriptor becomes readable and wakes all tasks
+    /// that were registered in the meantime.
+    #[derive(Debug)]
+    pub(super) struct ListenerWaiter {
+        state: Arc<Mutex<ListenerWaiterState>>,
+        command_sender: StreamingSocket,
+        _thread: Thread,
+    }
+
+    impl Drop for ListenerWaiter {
+        fn drop(&mut self) {
+            if lock(&self.state).has_failed {
+                return;
+            }
+
+            // wakes up the helper thread, it is joined when the thread handle is dropped
+            if let Err(e) = self.command_sender.try_send(&[STOP_COMMAND]) {
+                warn!(from self, "Unable to stop the helper thread ({e:?}), waiting until it finishes.");
+            }
+        }
+    }
+
+    impl ListenerWaiter {
+        fn new<R: Reactor>(listener_fd: &FileDescriptor) -> Result<Self, ListenerWaitError> {
+            let msg = "Unable to start the helper thread to wait asynchronously on the listener";
+            let origin = "ListenerWaiter::new()";
+
+            let (command_sender, command_receiver) = fail!(from origin,
+                when StreamingSocket::create_pair(),
+                with ListenerWaitError::InternalFailure,
+                "{msg} since the command socket pair could not be created.");
+
+            let listener_fd =
+                match FileDescriptor::non_owning_new(unsafe { listener_fd.native_handle() }) {
+                    Some(fd) => ListenerFileDescriptor(fd),
+                    None => {
+                        fail!(from origin, with ListenerWaitError::InternalFailure,
+                        "{msg} since the file descriptor of the listener is invalid.");
+                    }
+                };
+
+            let state = Arc::new(Mutex::new(ListenerWaiterState::default()));
+
+            let thread_state = state.clone();
+            let thread = fail!(from origin,
+                when ThreadBuilder::new().spawn(move || {
+                    if !Self::run::<R>(&listener_fd, &command_receiver, &thread_state) {
+                        let mut state = lock(&thread_state);
+                        state.has_failed = true;
+                        state.wakers.drain(..).for_each(Waker::wake);
+                    }
+                }),
+                with ListenerWaitError::InternalFailure,
+                "{msg} since the thread could not be spawned.");
+
+            Ok(Self {
+                state,
+                command_sender,
+                _thread: thread,
+            })
+        }
+
+        /// Runs until it receives the stop command. Returns `false` when the reactor failed.
+        fn run<R: Reactor>(
+            listener_fd: &ListenerFileDescriptor,
+            command_receiver: &StreamingSocket,
+            state: &Mutex<ListenerWaiterState>,
+        ) -> bool {
+            let origin = "ListenerWaiter::run()";
+            let msg = "Unable to wait on the listener in the helper thread";
+
+            let reactor = match <R::Builder as ReactorBuilder<R>>::new().create() {
+                Ok(reactor) => reactor,
+                Err(e) => {
+                    warn!(from origin, "{msg} since the reactor could not be created ({e:?}).");
+                    return false;
+                }
+            };
+
+            let _command_guard = match reactor.attach(command_receiver) {
+                Ok(guard) => guard,
+                Err(e) => {
+                    warn!(from origin, "{msg} since the command socket could not be attached ({e:?}).");
+                    return false;
+                }
+            };
+
+            let listener_handle = unsafe { listener_fd.0.native_handle() };
+            let mut is_armed = false;
+            loop {
+                // the listener is only attached while armed, otherwise a readable listener
+                // would wake the reactor over and over until the task consumed the event
+                let _listener_guard = if is_armed {
+                    match reactor.attach(listener_fd) {
+                        Ok(guard) => Some(guard),
+                        Err(e) => {
+                            warn!(from origin, "{msg} since the listener could not be attached ({e:?}).");
+                            return false;
+                        }
+                    }
+                } else {
+                    None
+                };
+
+                let mut is_readable = false;
+                match reactor.blocking_wait(|fd| {
+                    if unsafe { fd.native_handle() } == listener_handle {
+                        is_readable = true;
+                    }
+                }) {
+                    Ok(_) | Err(ReactorWaitError::Interrupt) => (),
+                    Err(e) => {
+                        warn!(from origin, "{msg} due to an internal reactor failure ({e:?}).");
+                        return false;
+                    }
+                }
+
+                let mut commands = [0u8; 16];
+                loop {
+                    match command_receiver.try_receive(&mut commands) {
+                        Ok(0) => break,
+                        Ok(n) => {
+                            for command in &commands[..n] {
+                                match *command {
+                                    STOP_COMMAND => return true,
+                                    _ => is_armed = true,
+                                }
+                            }
+                        }
+                        Err(e) => {
+                            warn!(from origin, "{msg} since the command could not be received ({e:?}).");
+                            return false;
+                        }
+                    }
+                }
+
+                if is_readable {
+                    is_armed = false;
+                    let mut state = lock(state);
+                    state.is_armed = false;
+                    state.wakers.drain(..).for_each(Waker::wake);
+                }
+            }
+        }
+
+        /// Registers the waker and arms the helper thread. Returns `false` when the helper
+        /// thread has failed.
+        fn arm(&self, waker: &Waker) -> bool {
+            let mut state = lock(&self.state);
+            if state.has_failed {
+                return false;
+            }
+
+            if !state.wakers.iter().any(|w| w.will_wake(waker)) {
+                state.wakers.push(waker.clone());
+            }
+
+            if !state.is_armed {
+                if let Err(e) = self.command_sender.try_send(&[ARM_COMMAND]) {
+                    warn!(from self, "Unable to arm the helper thread ({e:?}).");
+                    return false;
+                }
+                state.is_armed = true;
+            }
+
+            true
+        }
+    }
+}
+
 pub(crate) unsafe fn remove_connection_of_listener<Service: service::Service>(
     listener_id: &UniqueListenerId,
     config: &Config,
@@ -22,14 +22,17 @@
 //!
 //! let notifier = event
 //!     .notifier_builder()
-//!     .default_event_id(EventId::new(12))
+//!     .default_event_id(EventId::new(12)?)
 //!     .create()?;
 //!
 //! // notify with default event id 123
 //! notifier.notify()?;
 //!
 //! // notify with some custom event id
-//! notifier.notify_with_custom_event_id(EventId::new(6))?;
+//! notifier.notify_with_custom_event_id(EventId::new(6)?)?;
+//!
+//! // retry the notification of listeners with a full event buffer for up to 10ms
+//! notifier.timed_notify(core::time::Duration::from_millis(10))?;
 //!
 //! # Ok(())
 //! # }
@@ -50,6 +53,7 @@ use crate::{
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{debug, fail, warn};
+use iceoryx2_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use iceoryx2_cal::{
     arc_sync_policy::ArcSyncPolicy, dynamic_storage::DynamicStorage, event::NotifierBuilder,
 };
@@ -94,6 +98,10 @@ pub enum NotifierNotifyError {
     /// The notification was delivered but the elapsed system time could not be acquired.
     /// Therefore, it is unknown if the deadline was missed or not.
     UnableToAcquireElapsedTime,
+    /// A [`Notifier::timed_notify()`] was called and the notification could not be delivered
+    /// to all [`Listener`](crate::port::listener::Listener) ports before the timeout has
+    /// passed.
+    DeliveryTimeout,
 }
 
 impl core::fmt::Display for NotifierNotifyError {
@@ -301,7 +309,7 @@ impl<Service: service::Service> Notifier<Service> {
             Self::new_without_auto_event_emission(service.clone(), default_event_id)?;
 
         let static_config = service.static_config.event();
-        new_self.on_drop_notification = static_config.notifier_dropped_event.map(EventId::new);
+        new_self.on_drop_notification = static_config.notifier_dropped_event();
 
         if let Some(event_id) = static_config.notifier_created_event() {
             match new_self.notify_with_custom_event_id(event_id) {
@@ -403,6 +411,30 @@ impl<Service: service::Service> Notifier<Service> {
         self.notify_with_custom_event_id(self.default_event_id)
     }
 
+    /// Notifies all [`crate::port::listener::Listener`] connected to the service with the default
+    /// event id provided on creation. When the notification cannot be delivered to a
+    /// [`crate::port::listener::Listener`] since its event buffer is full, the delivery is
+    /// retried until the `timeout` has passed.
+    /// On success the number of
+    /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
+    /// [`NotifierNotifyError`]. If not all [`crate::port::listener::Listener`]s could be
+    /// notified in time it fails with [`NotifierNotifyError::DeliveryTimeout`].
+    pub fn timed_notify(&self, timeout: Duration) -> Result<usize, NotifierNotifyError> {
+        self.notify_impl(self.default_event_id, false, Some(timeout))
+    }
+
+    /// Returns the number of [`crate::port::listener::Listener`]s the [`Notifier`] is
+    /// currently connected to. Listeners can connect or disconnect at any time, therefore the
+    /// value is only a snapshot and may already be outdated when it is returned.
+    pub fn number_of_connected_listeners(&self) -> usize {
+        let listener_connections = self.listener_connections.lock();
+        listener_connections.update_connections();
+
+        (0..listener_connections.len())
+            .filter(|i| listener_connections.get(*i).is_some())
+            .count()
+    }
+
     /// Returns the deadline of the corresponding [`Service`](crate::service::Service).
     pub fn deadline(&self) -> Option<Duration> {
         self.listener_connections
@@ -440,6 +472,15 @@ impl<Service: service::Service> Notifier<Service> {
         &self,
         value: EventId,
         skip_self_deliver: bool,
+    ) -> Result<usize, NotifierNotifyError> {
+        self.notify_impl(value, skip_self_deliver, None)
+    }
+
+    fn notify_impl(
+        &self,
+        value: EventId,
+        skip_self_deliver: bool,
+        timeout: Option<Duration>,
     ) -> Result<usize, NotifierNotifyError> {
         let msg = "Unable to notify event";
         let listener_connections = self.listener_connections.lock();
@@ -454,6 +495,7 @@ impl<Service: service::Service> Notifier<Service> {
                             msg, value, self.event_id_max_value);
         }
 
+        let mut pending_deliveries = vec![];
         for i in 0..listener_connections.len() {
             if let Some(ref connection) = listener_connections.get(i) {
                 if !(skip_self_deliver && connection.node_id == self.node_id) {
@@ -461,6 +503,11 @@ impl<Service: service::Service> Notifier<Service> {
                         Err(iceoryx2_cal::event::NotifierNotifyError::Disconnected) => {
                             listener_connections.remove(i);
                         }
+                        Err(iceoryx2_cal::event::NotifierNotifyError::FailedToDeliverSignal)
+                            if timeout.is_some() =>
+                        {
+                            pending_deliveries.push(i);
+                        }
                         Err(e) => {
                             warn!(from self, "Unable to send notification via connection {:?} due to {:?}.",
                                     connection, e)
@@ -473,6 +520,49 @@ impl<Service: service::Service> Notifier<Service> {
             }
         }
 
+        let mut has_timed_out = false;
+        if let Some(timeout) = timeout.filter(|_| !pending_deliveries.is_empty()) {
+            let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                with NotifierNotifyError::UnableToAcquireElapsedTime,
+                                "{} since the elapsed system time could not be acquired which is required to retry the delivery.",
+                                msg);
+
+            let mut elapsed = Duration::ZERO;
+            while !pending_deliveries.is_empty() {
+                if timeout <= elapsed {
+                    has_timed_out = true;
+                    break;
+                }
+
+                elapsed = fail!(from self, when adaptive_wait.wait(),
+                                with NotifierNotifyError::UnableToAcquireElapsedTime,
+                                "{} since the elapsed system time could not be acquired which is required to retry the delivery.",
+                                msg);
+
+                pending_deliveries.retain(|i| match listener_connections.get(*i) {
+                    Some(ref connection) => match connection.notifier.notify(value) {
+                        Err(iceoryx2_cal::event::NotifierNotifyError::FailedToDeliverSignal) => {
+                            true
+                        }
+                        Err(iceoryx2_cal::event::NotifierNotifyError::Disconnected) => {
+                            listener_connections.remove(*i);
+                            false
+                        }
+                        Err(e) => {
+                            warn!(from self, "Unable to send notification via connection {:?} due to {:?}.",
+                                    connection, e);
+                            false
+                        }
+                        Ok(_) => {
+                            number_of_triggered_listeners += 1;
+                            false
+                        }
+                    },
+                    None => false,
+                });
+            }
+        }
+
         if let Some(deadline) = listener_connections
             .service_state
             .static_config
@@ -497,13 +587,19 @@ impl<Service: service::Service> Notifier<Service> {
                 duration_since_creation.as_nanos() as u64 - previous_duration_since_creation,
             );
 
-            if deadline.value < duration_since_last_notification {
+            if deadline.value < duration_since_last_notification && !has_timed_out {
                 fail!(from self, with NotifierNotifyError::MissedDeadline,
                 "{} but the deadline was hit. The service requires a notification after {:?} but {:?} passed without a notification.",
                 msg, deadline.value, duration_since_last_notification);
             }
         }
 
+        if has_timed_out {
+            fail!(from self, with NotifierNotifyError::DeliveryTimeout,
+                "{} since the notification could be delivered only to {} listeners, {} listeners could not be notified within the timeout of {:?}.",
+                msg, number_of_triggered_listeners, pending_deliveries.len(), timeout.unwrap_or_default());
+        }
+
         Ok(number_of_triggered_listeners)
     }
 }
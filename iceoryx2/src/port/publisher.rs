@@ -520,6 +520,21 @@ impl<
             .sender
             .unable_to_deliver_strategy
     }
+
+    /// Returns the number of [`Subscriber`](crate::port::subscriber::Subscriber)s that are
+    /// currently connected to the [`Service`](crate::service::Service). Subscribers can connect
+    /// or disconnect at any time, therefore the value is only a snapshot and may already be
+    /// outdated when it is returned.
+    pub fn number_of_subscribers(&self) -> usize {
+        self.publisher_shared_state
+            .lock()
+            .sender
+            .service_state
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .number_of_subscribers()
+    }
 }
 
 ////////////////////////
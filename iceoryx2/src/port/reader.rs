@@ -306,7 +306,7 @@ impl<
     ) -> Self {
         Self {
             atomic,
-            entry_id: EventId::new(offset as _),
+            entry_id: EventId::new_unchecked(offset as _),
             _shared_state: reader_state.clone(),
         }
     }
@@ -366,7 +366,7 @@ impl<Service: service::Service> Reader<Service, u64> {
         Ok(__InternalEntryHandle {
             atomic_mgmt_ptr,
             data_ptr: data_ptr as *const u8,
-            entry_id: EventId::new(offset as _),
+            entry_id: EventId::new_unchecked(offset as _),
             _shared_state: self.shared_state.clone(),
         })
     }
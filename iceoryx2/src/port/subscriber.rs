@@ -57,7 +57,11 @@ use crate::service::header::publish_subscribe::Header;
 use crate::service::port_factory::subscriber::SubscriberConfig;
 use crate::service::static_config::publish_subscribe::StaticConfig;
 use crate::service::{NoResource, ServiceState};
-use crate::{raw_sample::RawSample, sample::Sample, service};
+use crate::{
+    raw_sample::RawSample,
+    sample::{Sample, SampleRef},
+    service,
+};
 
 use super::details::chunk::Chunk;
 use super::details::chunk_details::ChunkDetails;
@@ -93,10 +97,101 @@ impl core::fmt::Display for SubscriberCreateError {
 
 impl core::error::Error for SubscriberCreateError {}
 
+#[derive(Debug)]
+struct PeekedChunk {
+    details: ChunkDetails,
+    chunk: Chunk,
+    number_of_references: usize,
+    is_received: bool,
+}
+
+// the chunk points into the data segment of the connection which is owned by the receiver
+unsafe impl Send for PeekedChunk {}
+
+impl PeekedChunk {
+    fn refers_to(&self, details: &ChunkDetails) -> bool {
+        self.details.connection_key == details.connection_key
+            && self.details.offset == details.offset
+            && self.details.origin == details.origin
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct SubscriberSharedState<Service: service::Service> {
     pub(crate) receiver: Receiver<Service>,
     pub(crate) publisher_list_state: UnsafeCell<ContainerState<PublisherDetails>>,
+    peeked_chunks: UnsafeCell<Vec<PeekedChunk>>,
+}
+
+impl<Service: service::Service> SubscriberSharedState<Service> {
+    fn has_peeked_chunk(&self) -> bool {
+        unsafe { &*self.peeked_chunks.get() }
+            .iter()
+            .any(|c| !c.is_received)
+    }
+
+    fn peek(&self) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
+        let peeked_chunks = unsafe { &mut *self.peeked_chunks.get() };
+        if let Some(peeked) = peeked_chunks.iter_mut().find(|c| !c.is_received) {
+            peeked.number_of_references += 1;
+            return Ok(Some((peeked.details, peeked.chunk)));
+        }
+
+        Ok(self
+            .receiver
+            .receive(ChannelId::new(0))?
+            .inspect(|(details, chunk)| {
+                peeked_chunks.push(PeekedChunk {
+                    details: *details,
+                    chunk: *chunk,
+                    number_of_references: 1,
+                    is_received: false,
+                })
+            }))
+    }
+
+    fn receive(&self) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
+        let peeked_chunks = unsafe { &mut *self.peeked_chunks.get() };
+        if let Some(index) = peeked_chunks.iter().position(|c| !c.is_received) {
+            // nobody looks at the peeked chunk anymore, the sample becomes its sole owner
+            if peeked_chunks[index].number_of_references == 0 {
+                let peeked = peeked_chunks.remove(index);
+                return Ok(Some((peeked.details, peeked.chunk)));
+            }
+
+            let peeked = &mut peeked_chunks[index];
+            peeked.is_received = true;
+            peeked.number_of_references += 1;
+            return Ok(Some((peeked.details, peeked.chunk)));
+        }
+
+        self.receiver.receive(ChannelId::new(0))
+    }
+
+    pub(crate) fn release(&self, details: &ChunkDetails) {
+        let peeked_chunks = unsafe { &mut *self.peeked_chunks.get() };
+        if let Some(index) = peeked_chunks.iter().position(|c| c.refers_to(details)) {
+            let peeked = &mut peeked_chunks[index];
+            peeked.number_of_references -= 1;
+            if peeked.number_of_references != 0 || !peeked.is_received {
+                return;
+            }
+
+            peeked_chunks.remove(index);
+        }
+
+        self.receiver.release_offset(details, ChannelId::new(0));
+    }
+
+    fn release_unreceived_peeked_chunks(&self) {
+        let peeked_chunks = unsafe { &mut *self.peeked_chunks.get() };
+        peeked_chunks.retain(|c| {
+            if !c.is_received {
+                self.receiver.release_offset(&c.details, ChannelId::new(0));
+            }
+            c.is_received
+        });
+    }
 }
 
 /// The receiving endpoint of a publish-subscribe communication.
@@ -140,6 +235,10 @@ impl<
     > Drop for Subscriber<Service, Payload, UserHeader>
 {
     fn drop(&mut self) {
+        self.subscriber_shared_state
+            .lock()
+            .release_unreceived_peeked_chunks();
+
         if let Some(handle) = self.dynamic_subscriber_handle {
             self.subscriber_shared_state
                 .lock()
@@ -194,6 +293,7 @@ impl<
 
         let subscriber_shared_state = Service::ArcThreadSafetyPolicy::new(SubscriberSharedState {
             publisher_list_state: UnsafeCell::new(unsafe { publisher_list.get_state() }),
+            peeked_chunks: UnsafeCell::new(Vec::new()),
             receiver: Receiver {
                 connections: PolymorphicVec::from_fn(
                     HeapAllocator::global(),
@@ -322,21 +422,25 @@ impl<
     pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
         fail!(from self, when self.update_connections(),
                 "Some samples are not being received since not all connections to publishers could be established.");
-        Ok(self
-            .subscriber_shared_state
-            .lock()
-            .receiver
-            .has_samples(ChannelId::new(0)))
+        let subscriber_shared_state = self.subscriber_shared_state.lock();
+        Ok(subscriber_shared_state.has_peeked_chunk()
+            || subscriber_shared_state
+                .receiver
+                .has_samples(ChannelId::new(0)))
     }
 
     fn receive_impl(&self) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
         fail!(from self, when self.update_connections(),
                 "Some samples are not being received since not all connections to publishers could be established.");
 
-        self.subscriber_shared_state
-            .lock()
-            .receiver
-            .receive(ChannelId::new(0))
+        self.subscriber_shared_state.lock().receive()
+    }
+
+    fn peek_impl(&self) -> Result<Option<(ChunkDetails, Chunk)>, ReceiveError> {
+        fail!(from self, when self.update_connections(),
+                "Some samples are not being peeked since not all connections to publishers could be established.");
+
+        self.subscriber_shared_state.lock().peek()
     }
 }
 
@@ -387,6 +491,60 @@ impl<
             },
         }))
     }
+
+    /// Returns a [`SampleRef`] to the next [`crate::sample::Sample`] without removing it from
+    /// the receive queue, the next call to [`Subscriber::receive()`] returns the same sample.
+    /// If no sample is available [`None`] is returned. If a failure occurs [`ReceiveError`] is
+    /// returned.
+    ///
+    /// The peeked sample stays valid as long as the [`SampleRef`] exists and it counts
+    /// towards the borrowed samples of the [`Subscriber`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #   .publish_subscribe::<u64>()
+    /// #   .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    /// let subscriber = service.subscriber_builder().create()?;
+    /// # publisher.send_copy(1234)?;
+    ///
+    /// if let Some(sample) = subscriber.peek()? {
+    ///     println!("next sample: {:?}", *sample);
+    /// }
+    ///
+    /// // returns the previously peeked sample
+    /// let sample = subscriber.receive()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn peek(
+        &self,
+    ) -> Result<Option<SampleRef<'_, Service, Payload, UserHeader>>, ReceiveError> {
+        Ok(self.peek_impl()?.map(|(details, chunk)| SampleRef {
+            subscriber_shared_state: &self.subscriber_shared_state,
+            details,
+            ptr: unsafe {
+                RawSample::new_unchecked(
+                    chunk.header.cast(),
+                    chunk.user_header.cast(),
+                    chunk.payload.cast(),
+                )
+            },
+        }))
+    }
+
+    /// Returns an iterator that lazily receives all [`crate::sample::Sample`]s that are
+    /// currently available. The iteration stops as soon as no more samples are available or
+    /// when [`Subscriber::receive()`] would return a [`ReceiveError`]. Samples that were not
+    /// yet received when the iterator is dropped remain available for the next call.
+    pub fn receive_all(&self) -> impl Iterator<Item = Sample<Service, Payload, UserHeader>> + '_ {
+        core::iter::from_fn(|| self.receive().ok().flatten())
+    }
 }
 
 impl<
@@ -417,6 +575,47 @@ impl<
             }
         }))
     }
+
+    /// Returns a [`SampleRef`] to the next [`crate::sample::Sample`] without removing it from
+    /// the receive queue, the next call to [`Subscriber::receive()`] returns the same sample.
+    /// If no sample is available [`None`] is returned. If a failure occurs [`ReceiveError`] is
+    /// returned.
+    ///
+    /// The peeked sample stays valid as long as the [`SampleRef`] exists and it counts
+    /// towards the borrowed samples of the [`Subscriber`].
+    pub fn peek(
+        &self,
+    ) -> Result<Option<SampleRef<'_, Service, [Payload], UserHeader>>, ReceiveError> {
+        debug_assert!(TypeId::of::<Payload>() != TypeId::of::<CustomPayloadMarker>());
+
+        Ok(self.peek_impl()?.map(|(details, chunk)| {
+            let header_ptr = chunk.header as *const Header;
+            let number_of_elements = unsafe { (*header_ptr).number_of_elements() };
+
+            SampleRef {
+                subscriber_shared_state: &self.subscriber_shared_state,
+                details,
+                ptr: unsafe {
+                    RawSample::<Header, UserHeader, [Payload]>::new_slice_unchecked(
+                        header_ptr,
+                        chunk.user_header.cast(),
+                        core::ptr::slice_from_raw_parts(
+                            chunk.payload.cast(),
+                            number_of_elements as _,
+                        ),
+                    )
+                },
+            }
+        }))
+    }
+
+    /// Returns an iterator that lazily receives all [`crate::sample::Sample`]s that are
+    /// currently available. The iteration stops as soon as no more samples are available or
+    /// when [`Subscriber::receive()`] would return a [`ReceiveError`]. Samples that were not
+    /// yet received when the iterator is dropped remain available for the next call.
+    pub fn receive_all(&self) -> impl Iterator<Item = Sample<Service, [Payload], UserHeader>> + '_ {
+        core::iter::from_fn(|| self.receive().ok().flatten())
+    }
 }
 
 impl<Service: service::Service, UserHeader: Debug + ZeroCopySend>
@@ -326,7 +326,7 @@ impl<
                 Ok(Self {
                     producer: p,
                     _shared_state: writer_state.clone(),
-                    entry_id: EventId::new(offset as _),
+                    entry_id: EventId::new_unchecked(offset as _),
                 })
             }
         }
@@ -602,7 +602,7 @@ impl<Service: service::Service> Writer<Service, u64> {
         match __InternalEntryHandleMut::new(
             atomic_mgmt_ptr,
             data_ptr as *mut u8,
-            EventId::new(offset as _),
+            EventId::new_unchecked(offset as _),
             self.shared_state.clone(),
         ) {
             Ok(handle) => Ok(handle),
@@ -35,7 +35,6 @@ use core::{fmt::Debug, ops::Deref};
 use iceoryx2_bb_elementary_traits::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_cal::arc_sync_policy::ArcSyncPolicy;
-use iceoryx2_cal::zero_copy_connection::ChannelId;
 
 use crate::port::details::chunk_details::ChunkDetails;
 use crate::port::port_identifiers::UniquePublisherId;
@@ -105,10 +104,7 @@ impl<
     > Drop for Sample<Service, Payload, UserHeader>
 {
     fn drop(&mut self) {
-        self.subscriber_shared_state
-            .lock()
-            .receiver
-            .release_offset(&self.details, ChannelId::new(0));
+        self.subscriber_shared_state.lock().release(&self.details);
     }
 }
 
@@ -138,3 +134,90 @@ impl<
         UniquePublisherId(UniqueSystemId::from(self.details.origin))
     }
 }
+
+/// A view of the next [`Sample`] that is acquired via
+/// [`Subscriber::peek()`](crate::port::subscriber::Subscriber::peek()). The sample remains in
+/// the receive queue and is returned by the next call to
+/// [`Subscriber::receive()`](crate::port::subscriber::Subscriber::receive()). The underlying
+/// memory stays valid as long as the [`SampleRef`] exists, even when the [`Sample`] was already
+/// received and dropped in the meantime.
+pub struct SampleRef<
+    'a,
+    Service: crate::service::Service,
+    Payload: Debug + ?Sized + ZeroCopySend,
+    UserHeader: ZeroCopySend,
+> {
+    pub(crate) ptr: RawSample<Header, UserHeader, Payload>,
+    pub(crate) subscriber_shared_state:
+        &'a Service::ArcThreadSafetyPolicy<SubscriberSharedState<Service>>,
+    pub(crate) details: ChunkDetails,
+}
+
+impl<
+        Service: crate::service::Service,
+        Payload: Debug + ZeroCopySend + ?Sized,
+        UserHeader: ZeroCopySend,
+    > Debug for SampleRef<'_, Service, Payload, UserHeader>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "SampleRef<{}, {}, {}> {{ ptr: {:?}, details: {:?} }}",
+            core::any::type_name::<Payload>(),
+            core::any::type_name::<UserHeader>(),
+            core::any::type_name::<Service>(),
+            self.ptr,
+            self.details,
+        )
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        Payload: Debug + ZeroCopySend + ?Sized,
+        UserHeader: ZeroCopySend,
+    > Deref for SampleRef<'_, Service, Payload, UserHeader>
+{
+    type Target = Payload;
+    fn deref(&self) -> &Self::Target {
+        self.ptr.as_payload_ref()
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        Payload: Debug + ZeroCopySend + ?Sized,
+        UserHeader: ZeroCopySend,
+    > Drop for SampleRef<'_, Service, Payload, UserHeader>
+{
+    fn drop(&mut self) {
+        self.subscriber_shared_state.lock().release(&self.details);
+    }
+}
+
+impl<
+        Service: crate::service::Service,
+        Payload: Debug + ZeroCopySend + ?Sized,
+        UserHeader: ZeroCopySend,
+    > SampleRef<'_, Service, Payload, UserHeader>
+{
+    /// Returns a reference to the payload of the [`SampleRef`]
+    pub fn payload(&self) -> &Payload {
+        self.ptr.as_payload_ref()
+    }
+
+    /// Returns a reference to the user_header of the [`SampleRef`]
+    pub fn user_header(&self) -> &UserHeader {
+        self.ptr.as_user_header_ref()
+    }
+
+    /// Returns a reference to the [`Header`] of the [`SampleRef`].
+    pub fn header(&self) -> &Header {
+        self.ptr.as_header_ref()
+    }
+
+    /// Returns the [`UniquePublisherId`] of the [`Publisher`](crate::port::publisher::Publisher)
+    pub fn origin(&self) -> UniquePublisherId {
+        UniquePublisherId(UniqueSystemId::from(self.details.origin))
+    }
+}
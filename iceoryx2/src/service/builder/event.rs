@@ -31,6 +31,14 @@ use self::attribute::{AttributeSpecifier, AttributeVerifier};
 
 use super::ServiceState;
 
+// the static config is stored as TOML, which supports only signed 64-bit integers
+const MAX_EVENT_ID_MAX_VALUE: usize =
+    if EventId::max_supported().as_value() as u64 > i64::MAX as u64 {
+        i64::MAX as usize
+    } else {
+        EventId::max_supported().as_value()
+    };
+
 /// Failures that can occur when an existing [`MessagingPattern::Event`] [`Service`] shall be opened.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EventOpenError {
@@ -450,6 +458,52 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
         }
     }
 
+    /// Opens an existing [`Service`] as passive [`event::Observer`] that provides read-only
+    /// access to the static and dynamic configuration. In contrast to [`Builder::open()`] the
+    /// [`Node`](crate::node::Node) is not registered at the [`Service`] and no ports can be
+    /// created, so the observer never counts against the participant limits of the
+    /// [`Service`].
+    pub fn open_observer(self) -> Result<event::Observer<ServiceType>, EventOpenError> {
+        let msg = "Unable to open event service as observer";
+
+        let static_config = match self.base.is_service_available(msg)? {
+            None => {
+                fail!(from self, with EventOpenError::DoesNotExist,
+                    "{} since the event does not exist.", msg);
+            }
+            Some((static_config, _)) => static_config,
+        };
+
+        self.verify_service_configuration(&static_config, &AttributeVerifier::new())?;
+
+        let dynamic_config = match self.base.open_unregistered_dynamic_config_storage() {
+            Ok(v) => v,
+            Err(DynamicStorageOpenError::DoesNotExist) => {
+                if self.base.is_service_available(msg)?.is_none() {
+                    fail!(from self, with EventOpenError::DoesNotExist,
+                        "{} since the event does not exist.", msg);
+                }
+
+                fail!(from self, with EventOpenError::ServiceInCorruptedState,
+                    "{} since the dynamic segment of the service is missing.", msg);
+            }
+            Err(DynamicStorageOpenError::InitializationNotYetFinalized) => {
+                fail!(from self, with EventOpenError::HangsInCreation,
+                    "{} since the dynamic segment of the service is not yet initialized.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with EventOpenError::InternalFailure,
+                    "{} since the dynamic service information could not be opened ({:?}).", msg, e);
+            }
+        };
+
+        Ok(event::Observer::new(
+            static_config,
+            self.base.shared_node,
+            dynamic_config,
+        ))
+    }
+
     /// Creates a new [`Service`].
     pub fn create(mut self) -> Result<event::PortFactory<ServiceType>, EventCreateError> {
         self.create_impl(&AttributeSpecifier::new())
@@ -577,6 +631,12 @@ impl<ServiceType: service::Service> Builder<ServiceType> {
             warn!(from origin, "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_nodes = 1;
         }
+
+        if settings.event_id_max_value > MAX_EVENT_ID_MAX_VALUE {
+            warn!(from origin, "Setting the maximum event id to {} is not supported. Adjust it to {}, the largest supported value.",
+                settings.event_id_max_value, MAX_EVENT_ID_MAX_VALUE);
+            settings.event_id_max_value = MAX_EVENT_ID_MAX_VALUE;
+        }
     }
 
     fn verify_service_configuration(
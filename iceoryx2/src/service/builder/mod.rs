@@ -433,17 +433,7 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         &self,
     ) -> Result<ServiceType::DynamicStorage, OpenDynamicStorageFailure> {
         let msg = "Failed to open dynamic service information";
-        let storage = fail!(from self, when
-            <<ServiceType::DynamicStorage as DynamicStorage<
-                    DynamicConfig,
-                >>::Builder<'_> as NamedConceptBuilder<
-                    ServiceType::DynamicStorage,
-                >>::new(&self.service_config.service_id().0.clone().into())
-                    .timeout(self.shared_node.config().global.service.creation_timeout)
-                    .config(&dynamic_config_storage_config::<ServiceType>(self.shared_node.config()))
-                .has_ownership(false)
-                .open(),
-            "{} since the dynamic storage could not be opened.", msg);
+        let storage = self.open_unregistered_dynamic_config_storage()?;
 
         self.shared_node
             .registered_services()
@@ -465,6 +455,26 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         Ok(storage)
     }
 
+    // opens the dynamic storage without registering the node at the service
+    fn open_unregistered_dynamic_config_storage(
+        &self,
+    ) -> Result<ServiceType::DynamicStorage, DynamicStorageOpenError> {
+        let msg = "Failed to open dynamic service information";
+        let storage = fail!(from self, when
+            <<ServiceType::DynamicStorage as DynamicStorage<
+                    DynamicConfig,
+                >>::Builder<'_> as NamedConceptBuilder<
+                    ServiceType::DynamicStorage,
+                >>::new(&self.service_config.service_id().0.clone().into())
+                    .timeout(self.shared_node.config().global.service.creation_timeout)
+                    .config(&dynamic_config_storage_config::<ServiceType>(self.shared_node.config()))
+                .has_ownership(false)
+                .open(),
+            "{} since the dynamic storage could not be opened.", msg);
+
+        Ok(storage)
+    }
+
     fn create_node_service_tag<ErrorType>(
         &self,
         error_msg: &str,
@@ -91,9 +91,9 @@
 //!     .max_notifiers(12)
 //!     .max_listeners(2)
 //!     .event_id_max_value(32)
-//!     .notifier_created_event(EventId::new(999))
-//!     .notifier_dropped_event(EventId::new(0))
-//!     .notifier_dead_event(EventId::new(2000))
+//!     .notifier_created_event(EventId::new(999)?)
+//!     .notifier_dropped_event(EventId::new(0)?)
+//!     .notifier_dead_event(EventId::new(2000)?)
 //!     // if the service already exists, open it, otherwise create it
 //!     .open_or_create()?;
 //!
@@ -250,6 +250,10 @@ pub mod ipc;
 /// [`Send`] but at the cost of an additional internal mutex.
 pub mod ipc_threadsafe;
 
+/// A configuration when communicating between different processes using posix mechanisms where
+/// the payload is stored in anonymous shared memory which never outlives its creating process.
+pub mod ipc_memfd;
+
 pub(crate) mod config_scheme;
 pub(crate) mod naming_scheme;
 
@@ -510,14 +514,14 @@ pub mod internal {
             return;
         }
 
-        let event_id = match service.static_config().notifier_dead_event {
+        let event_id = match service.static_config().notifier_dead_event() {
             Some(event_id) => event_id,
             None => return,
         };
 
         let notifier = match Notifier::new_without_auto_event_emission(
             service.service,
-            EventId::new(0),
+            EventId::default(),
         ) {
             Ok(notifier) => notifier,
             Err(e) => {
@@ -528,7 +532,7 @@ pub mod internal {
             }
         };
 
-        if let Err(e) = notifier.notify_with_custom_event_id(EventId::new(event_id)) {
+        if let Err(e) = notifier.notify_with_custom_event_id(event_id) {
             warn!(from origin,
                             "Unable to send dead node signal to waiting listeners on service {} due to ({:?})",
                             service_name, e);
@@ -37,9 +37,10 @@
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 
-use crate::node::NodeListFailure;
+use crate::node::{NodeListFailure, SharedNode};
 use crate::service::attribute::AttributeSet;
 use crate::service::service_id::ServiceId;
+use crate::service::static_config::StaticConfig;
 use crate::service::{self, static_config, NoResource, ServiceState};
 use crate::service::{dynamic_config, ServiceName};
 
@@ -148,3 +149,95 @@ impl<Service: service::Service> PortFactory<Service> {
         PortFactoryListener { factory: self }
     }
 }
+
+/// A passive, read-only observer of a
+/// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
+/// based service, created with
+/// [`Builder::open_observer()`](crate::service::builder::event::Builder::open_observer()).
+/// It provides the same introspection as the [`PortFactory`] but cannot create any ports and
+/// its [`Node`](crate::node::Node) is not registered at the service. Therefore, it never
+/// occupies a slot that is counted against the services node, notifier or listener limits.
+///
+/// Events can only be received with a [`crate::port::listener::Listener`], an observer
+/// cannot receive them.
+///
+/// # Example
+///
+/// ```
+/// use iceoryx2::prelude::*;
+///
+/// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+/// let node = NodeBuilder::new().create::<ipc::Service>()?;
+/// let event = node.service_builder(&"MyEventName".try_into()?)
+///     .event()
+///     .open_or_create()?;
+///
+/// let observer = node.service_builder(&"MyEventName".try_into()?)
+///     .event()
+///     .open_observer()?;
+///
+/// println!("max listeners:                {:?}", observer.static_config().max_listeners());
+/// println!("number of active listeners:   {:?}", observer.dynamic_config().number_of_listeners());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Observer<Service: service::Service> {
+    dynamic_storage: Service::DynamicStorage,
+    static_config: StaticConfig,
+    shared_node: Arc<SharedNode<Service>>,
+}
+
+unsafe impl<Service: service::Service> Send for Observer<Service> {}
+unsafe impl<Service: service::Service> Sync for Observer<Service> {}
+
+impl<Service: service::Service> crate::service::port_factory::PortFactory for Observer<Service> {
+    type Service = Service;
+    type StaticConfig = static_config::event::StaticConfig;
+    type DynamicConfig = dynamic_config::event::DynamicConfig;
+
+    fn name(&self) -> &ServiceName {
+        self.static_config.name()
+    }
+
+    fn service_id(&self) -> &ServiceId {
+        self.static_config.service_id()
+    }
+
+    fn attributes(&self) -> &AttributeSet {
+        self.static_config.attributes()
+    }
+
+    fn static_config(&self) -> &static_config::event::StaticConfig {
+        self.static_config.event()
+    }
+
+    fn dynamic_config(&self) -> &dynamic_config::event::DynamicConfig {
+        self.dynamic_storage.get().event()
+    }
+
+    fn nodes<F: FnMut(crate::node::NodeState<Service>) -> CallbackProgression>(
+        &self,
+        callback: F,
+    ) -> Result<(), NodeListFailure> {
+        nodes(
+            self.dynamic_storage.get(),
+            self.shared_node.config(),
+            callback,
+        )
+    }
+}
+
+impl<Service: service::Service> Observer<Service> {
+    pub(crate) fn new(
+        static_config: StaticConfig,
+        shared_node: Arc<SharedNode<Service>>,
+        dynamic_storage: Service::DynamicStorage,
+    ) -> Self {
+        Self {
+            dynamic_storage,
+            static_config,
+            shared_node,
+        }
+    }
+}
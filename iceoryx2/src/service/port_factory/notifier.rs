@@ -23,7 +23,7 @@
 //!     .open_or_create()?;
 //!
 //! let listener = event.notifier_builder()
-//!                     .default_event_id(EventId::new(1234))
+//!                     .default_event_id(EventId::new(1234)?)
 //!                     .create()?;
 //! # Ok(())
 //! # }
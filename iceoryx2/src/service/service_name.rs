@@ -64,8 +64,73 @@ impl From<StringModificationError> for ServiceNameError {
     }
 }
 
+/// Separates the segments of a hierarchical [`ServiceName`] like `vehicle/front_lidar/points`.
+pub const SERVICE_NAME_SEGMENT_SEPARATOR: char = '/';
+
+/// Wildcard in a [`ServiceNamePattern`] that matches exactly one segment.
+pub const SINGLE_SEGMENT_WILDCARD: &str = "*";
+
+/// Wildcard in a [`ServiceNamePattern`] that matches any number of segments, including none.
+pub const MULTI_SEGMENT_WILDCARD: &str = "**";
+
 type ServiceNameString = StaticString<MAX_SERVICE_NAME_LENGTH>;
 
+fn segments_of(value: &str) -> impl Iterator<Item = &str> + Clone {
+    value
+        .split(SERVICE_NAME_SEGMENT_SEPARATOR)
+        .filter(|segment| !segment.is_empty())
+}
+
+fn is_wildcard(segment: &str) -> bool {
+    segment == SINGLE_SEGMENT_WILDCARD || segment == MULTI_SEGMENT_WILDCARD
+}
+
+/// Validates the segments of a [`ServiceName`] or, when `allow_wildcards` is set, of a
+/// [`ServiceNamePattern`]. A segment must not contain [`SINGLE_SEGMENT_WILDCARD`] unless it
+/// is itself a wildcard in a pattern. Otherwise a name could contain segments that no
+/// literal pattern segment is able to match.
+fn validate_segments(value: &str, allow_wildcards: bool) -> Result<(), ServiceNameError> {
+    if value.is_empty() {
+        return Err(ServiceNameError::InvalidContent);
+    }
+
+    for segment in segments_of(value) {
+        let is_allowed_wildcard = allow_wildcards && is_wildcard(segment);
+        if !is_allowed_wildcard && segment.contains(SINGLE_SEGMENT_WILDCARD) {
+            return Err(ServiceNameError::InvalidContent);
+        }
+    }
+
+    Ok(())
+}
+
+fn matches_segments<'a, N, P>(mut name: N, mut pattern: P) -> bool
+where
+    N: Iterator<Item = &'a str> + Clone,
+    P: Iterator<Item = &'a str> + Clone,
+{
+    loop {
+        match pattern.next() {
+            None => return name.next().is_none(),
+            Some(MULTI_SEGMENT_WILDCARD) => loop {
+                if matches_segments(name.clone(), pattern.clone()) {
+                    return true;
+                }
+
+                if name.next().is_none() {
+                    return false;
+                }
+            },
+            Some(pattern_segment) => match name.next() {
+                Some(name_segment)
+                    if pattern_segment == SINGLE_SEGMENT_WILDCARD
+                        || pattern_segment == name_segment => {}
+                _ => return false,
+            },
+        }
+    }
+}
+
 /// The name of a [`Service`](crate::service::Service).
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, ZeroCopySend)]
 #[repr(C)]
@@ -76,12 +141,18 @@ pub struct ServiceName {
 impl ServiceName {
     /// Creates a new [`ServiceName`].
     ///
-    /// The name is not allowed to be empty nor be prefixed with "iox2://".
+    /// The name is not allowed to be empty nor be prefixed with "iox2://" and its segments
+    /// must not contain [`SINGLE_SEGMENT_WILDCARD`].
+    ///
+    /// Names of already existing services are not validated, they can still be deserialized,
+    /// converted with [`TryInto`] and opened even when they contain
+    /// [`SINGLE_SEGMENT_WILDCARD`].
     pub fn new(name: &str) -> Result<Self, ServiceNameError> {
         if Self::has_iox2_prefix(name) {
             return Err(ServiceNameError::InvalidContent);
         }
 
+        validate_segments(name, false)?;
         Self::__internal_new(name)
     }
 
@@ -115,6 +186,92 @@ impl ServiceName {
     pub fn max_len() -> usize {
         ServiceNameString::capacity()
     }
+
+    /// Returns an iterator over all segments of the [`ServiceName`] that are separated by
+    /// [`SERVICE_NAME_SEGMENT_SEPARATOR`]. Empty segments are skipped.
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let service_name = ServiceName::new("vehicle/front_lidar/points")?;
+    /// let mut segments = service_name.segments();
+    ///
+    /// assert_eq!(segments.next(), Some("vehicle"));
+    /// assert_eq!(segments.next(), Some("front_lidar"));
+    /// assert_eq!(segments.next(), Some("points"));
+    /// assert_eq!(segments.next(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn segments(&self) -> impl Iterator<Item = &str> + Clone {
+        segments_of(self.as_str())
+    }
+
+    /// Returns `true` when all segments of `namespace` are the leading segments of the
+    /// [`ServiceName`], otherwise `false`. `vehicle/front_lidar/points` is in the namespace
+    /// `vehicle/front_lidar` but not in `vehicle/front`.
+    pub fn starts_with_namespace(&self, namespace: &ServiceName) -> bool {
+        let mut segments = self.segments();
+        namespace
+            .segments()
+            .all(|namespace_segment| segments.next() == Some(namespace_segment))
+    }
+
+    /// Returns `true` when the [`ServiceName`] matches the provided [`ServiceNamePattern`].
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::service::service_name::ServiceNamePattern;
+    ///
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let service_name = ServiceName::new("vehicle/front_lidar/points")?;
+    ///
+    /// assert!(service_name.matches(&ServiceNamePattern::new("vehicle/*/points")?));
+    /// assert!(service_name.matches(&ServiceNamePattern::new("vehicle/**")?));
+    /// assert!(!service_name.matches(&ServiceNamePattern::new("*/points")?));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn matches(&self, pattern: &ServiceNamePattern) -> bool {
+        matches_segments(self.segments(), pattern.segments())
+    }
+}
+
+/// A pattern to filter [`ServiceName`]s segment-wise, see [`ServiceName::matches()`]. A
+/// segment can be either a literal that must be equal to the corresponding segment of the
+/// [`ServiceName`], [`SINGLE_SEGMENT_WILDCARD`] that matches exactly one segment or
+/// [`MULTI_SEGMENT_WILDCARD`] that matches any number of segments.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ServiceNamePattern {
+    value: ServiceNameString,
+}
+
+impl ServiceNamePattern {
+    /// Creates a new [`ServiceNamePattern`]. The pattern must not be empty and wildcards must
+    /// always span a whole segment, `vehicle/*/points` is valid but `vehicle/front*` is not.
+    pub fn new(pattern: &str) -> Result<Self, ServiceNameError> {
+        validate_segments(pattern, true)?;
+
+        let value = ServiceNameString::try_from(pattern).map_err(ServiceNameError::from)?;
+
+        Ok(Self { value })
+    }
+
+    /// Returns a str reference to the [`ServiceNamePattern`]
+    pub fn as_str(&self) -> &str {
+        self.value.as_str()
+    }
+
+    fn segments(&self) -> impl Iterator<Item = &str> + Clone {
+        segments_of(self.as_str())
+    }
+}
+
+impl core::fmt::Display for ServiceNamePattern {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.value)
+    }
 }
 
 impl core::fmt::Display for ServiceName {
@@ -112,16 +112,16 @@ impl StaticConfig {
 
     /// Returns the emitted [`EventId`] when a new notifier is created.
     pub fn notifier_created_event(&self) -> Option<EventId> {
-        self.notifier_created_event.map(EventId::new)
+        self.notifier_created_event.map(EventId::new_unchecked)
     }
 
     /// Returns the emitted [`EventId`] when a notifier is dropped.
     pub fn notifier_dropped_event(&self) -> Option<EventId> {
-        self.notifier_dropped_event.map(EventId::new)
+        self.notifier_dropped_event.map(EventId::new_unchecked)
     }
 
     /// Returns the emitted [`EventId`] when a notifier is identified as dead.
     pub fn notifier_dead_event(&self) -> Option<EventId> {
-        self.notifier_dead_event.map(EventId::new)
+        self.notifier_dead_event.map(EventId::new_unchecked)
     }
 }
@@ -27,6 +27,9 @@
 //!     the sensor data latest after 120ms. If after 120ms an update
 //!     is not available the application must wake up and take counter measures. If the update
 //!     arrives within the timeout, the timeout is reset back to 120ms.
+//! * **Writable** - An object that can send data, like a socket. Whenever it can accept data
+//!     without blocking the [`WaitSet`](crate::waitset::WaitSet) wakes up and informs the user.
+//!     This allows flow-controlled senders to avoid blocking sends.
 //! * **Interval** - An time period after which the [`WaitSet`](crate::waitset::WaitSet)
 //!     wakes up and informs the user that the time has passed by.
 //!     This is useful when a [`Publisher`](crate::port::publisher::Publisher) shall send an
@@ -213,6 +216,7 @@
 //! # Ok(())
 //! # }
 
+use alloc::collections::BTreeMap;
 use core::{
     cell::RefCell, fmt::Debug, hash::Hash, marker::PhantomData, sync::atomic::Ordering,
     time::Duration,
@@ -220,14 +224,18 @@ use core::{
 use std::collections::HashMap;
 
 use iceoryx2_bb_elementary::CallbackProgression;
-use iceoryx2_bb_log::fail;
+use iceoryx2_bb_log::{fail, warn};
 use iceoryx2_bb_posix::{
+    clock::Time,
     deadline_queue::{DeadlineQueue, DeadlineQueueBuilder, DeadlineQueueGuard, DeadlineQueueIndex},
     file_descriptor::FileDescriptor,
     file_descriptor_set::SynchronousMultiplexing,
     signal::SignalHandler,
 };
 use iceoryx2_cal::reactor::*;
+
+pub use iceoryx2_bb_posix::clock::ClockType;
+pub use iceoryx2_cal::reactor::TriggerMode;
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
 
 use crate::signal_handling_mode::SignalHandlingMode;
@@ -257,6 +265,11 @@ pub enum WaitSetAttachmentError {
     InternalError,
     /// Insufficient resources to add another attachment to the [`WaitSet`].
     InsufficientResources,
+    /// The file descriptor of the attachment is no longer valid, for instance since it was
+    /// closed.
+    Disconnected,
+    /// The requested [`TriggerMode`] is not supported by the underlying reactor.
+    UnsupportedTriggerMode,
 }
 
 impl core::fmt::Display for WaitSetAttachmentError {
@@ -438,6 +451,38 @@ impl<Service: crate::service::Service> WaitSetAttachmentId<Service> {
     }
 }
 
+/// Describes how an attachment was attached to the [`WaitSet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitSetAttachmentKind {
+    /// Attached with [`WaitSet::attach_notification()`].
+    Notification,
+    /// Attached with [`WaitSet::attach_writable()`].
+    Writable,
+    /// Attached with [`WaitSet::attach_deadline()`] and the provided deadline.
+    Deadline(Duration),
+    /// Attached with [`WaitSet::attach_interval()`] and the provided interval.
+    Interval(Duration),
+}
+
+/// Describes an attachment of the [`WaitSet`]. Is returned by [`WaitSet::attachments()`].
+#[derive(Debug)]
+pub struct WaitSetAttachmentInfo<Service: crate::service::Service> {
+    id: WaitSetAttachmentId<Service>,
+    kind: WaitSetAttachmentKind,
+}
+
+impl<Service: crate::service::Service> WaitSetAttachmentInfo<Service> {
+    /// Returns the [`WaitSetAttachmentId`] of the attachment.
+    pub fn id(&self) -> &WaitSetAttachmentId<Service> {
+        &self.id
+    }
+
+    /// Returns the [`WaitSetAttachmentKind`] of the attachment.
+    pub fn kind(&self) -> WaitSetAttachmentKind {
+        self.kind
+    }
+}
+
 enum GuardType<'waitset, 'attachment, Service: crate::service::Service>
 where
     Service::Reactor: 'waitset,
@@ -466,6 +511,10 @@ impl<Service: crate::service::Service> Drop for WaitSetGuard<'_, '_, Service> {
             self.waitset
                 .remove_deadline(unsafe { r.file_descriptor().native_handle() }, t.index())
         }
+        self.waitset
+            .attachments
+            .borrow_mut()
+            .remove(&WaitSetAttachmentId::from_guard(self).attachment_type);
         self.waitset.detach();
     }
 }
@@ -474,6 +523,7 @@ impl<Service: crate::service::Service> Drop for WaitSetGuard<'_, '_, Service> {
 #[derive(Default, Debug, Clone)]
 pub struct WaitSetBuilder {
     signal_handling_mode: SignalHandlingMode,
+    clock_source: ClockType,
 }
 
 impl WaitSetBuilder {
@@ -491,12 +541,23 @@ impl WaitSetBuilder {
         self
     }
 
+    /// Defines the [`ClockType`] against which deadlines, intervals and timeouts of the
+    /// [`WaitSet`] are measured. By default it is [`ClockType::Monotonic`] when the platform
+    /// supports it, so that adjustments of the system time, e.g. by NTP, do not let
+    /// deadlines fire too early or too late. [`ClockType::Realtime`] follows all adjustments
+    /// of the system time and [`ClockType::Boottime`] keeps counting while the system is
+    /// suspended.
+    pub fn clock_source(mut self, value: ClockType) -> Self {
+        self.clock_source = value;
+        self
+    }
+
     /// Creates the [`WaitSet`].
     pub fn create<Service: crate::service::Service>(
         self,
     ) -> Result<WaitSet<Service>, WaitSetCreateError> {
         let msg = "Unable to create WaitSet";
-        let deadline_queue = fail!(from self, when DeadlineQueueBuilder::new().create(),
+        let deadline_queue = fail!(from self, when DeadlineQueueBuilder::new().clock_type(self.clock_source).create(),
                 with WaitSetCreateError::InternalError,
                 "{msg} since the underlying Timer could not be created.");
 
@@ -506,8 +567,10 @@ impl WaitSetBuilder {
                 deadline_queue,
                 attachment_to_deadline: RefCell::new(HashMap::new()),
                 deadline_to_attachment: RefCell::new(HashMap::new()),
+                attachments: RefCell::new(BTreeMap::new()),
                 attachment_counter: IoxAtomicUsize::new(0),
                 signal_handling_mode: self.signal_handling_mode,
+                clock_source: self.clock_source,
             }),
             Err(ReactorCreateError::InternalError) => {
                 fail!(from self, with WaitSetCreateError::InternalError,
@@ -538,8 +601,10 @@ pub struct WaitSet<Service: crate::service::Service> {
     deadline_queue: DeadlineQueue,
     attachment_to_deadline: RefCell<HashMap<i32, DeadlineQueueIndex>>,
     deadline_to_attachment: RefCell<HashMap<DeadlineQueueIndex, i32>>,
+    attachments: RefCell<BTreeMap<AttachmentIdType, WaitSetAttachmentKind>>,
     attachment_counter: IoxAtomicUsize,
     signal_handling_mode: SignalHandlingMode,
+    clock_source: ClockType,
 }
 
 impl<Service: crate::service::Service> WaitSet<Service> {
@@ -557,6 +622,12 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         Ok(())
     }
 
+    fn register_attachment(&self, guard: &WaitSetGuard<Service>, kind: WaitSetAttachmentKind) {
+        self.attachments
+            .borrow_mut()
+            .insert(WaitSetAttachmentId::from_guard(guard).attachment_type, kind);
+    }
+
     fn remove_deadline(&self, reactor_idx: i32, deadline_queue_idx: DeadlineQueueIndex) {
         self.attachment_to_deadline
             .borrow_mut()
@@ -647,17 +718,118 @@ impl<Service: crate::service::Service> WaitSet<Service> {
     /// object the [`WaitSet`] informs the user in [`WaitSet::wait_and_process()`] to handle the event.
     /// The object cannot be attached twice and the
     /// [`WaitSet::capacity()`] is limited by the underlying implementation.
+    ///
+    /// The object is attached with [`TriggerMode::Level`], therefore the [`WaitSet`] wakes up
+    /// again as long as the event was not fully consumed. Use
+    /// [`WaitSet::attach_notification_with_trigger_mode()`] to choose another [`TriggerMode`].
     pub fn attach_notification<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
         &'waitset self,
         attachment: &'attachment T,
     ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
-        let reactor_guard = self.attach_to_reactor(attachment)?;
+        self.attach_notification_with_trigger_mode(attachment, TriggerMode::Level)
+    }
+
+    /// Like [`WaitSet::attach_notification()`] but the user defines with the [`TriggerMode`]
+    /// when the [`WaitSet`] wakes up again for the same object.
+    ///
+    /// * [`TriggerMode::Level`] - wakes up as long as the event was not fully consumed.
+    /// * [`TriggerMode::Edge`] - wakes up once whenever a new event arrives, even if older
+    ///   events were not consumed.
+    ///
+    /// Not every platform supports [`TriggerMode::Edge`], then
+    /// [`WaitSetAttachmentError::UnsupportedTriggerMode`] is returned.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::waitset::TriggerMode;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let event = node.service_builder(&"MyEventName_1".try_into()?)
+    /// #     .event()
+    /// #     .open_or_create()?;
+    ///
+    /// let listener = event.listener_builder().create()?;
+    ///
+    /// let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+    /// // wakes up once per batch of notifications
+    /// let guard = waitset.attach_notification_with_trigger_mode(&listener, TriggerMode::Edge)?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn attach_notification_with_trigger_mode<
+        'waitset,
+        'attachment,
+        T: SynchronousMultiplexing + Debug,
+    >(
+        &'waitset self,
+        attachment: &'attachment T,
+        trigger_mode: TriggerMode,
+    ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
+        let reactor_guard = self.attach_to_reactor(attachment, trigger_mode, || {
+            self.reactor
+                .attach_with_trigger_mode(attachment, trigger_mode)
+        })?;
         self.attach()?;
 
-        Ok(WaitSetGuard {
+        let guard = WaitSetGuard {
             waitset: self,
             guard_type: GuardType::Notification(reactor_guard),
-        })
+        };
+        self.register_attachment(&guard, WaitSetAttachmentKind::Notification);
+        Ok(guard)
+    }
+
+    /// Attaches an object for write-readiness to the [`WaitSet`]. Whenever the object can
+    /// accept data without blocking, the [`WaitSet`] informs the user in
+    /// [`WaitSet::wait_and_process()`] and [`WaitSetAttachmentId::has_event_from()`] returns
+    /// true for the returned [`WaitSetGuard`].
+    /// The object cannot be attached twice and the
+    /// [`WaitSet::capacity()`] is limited by the underlying implementation.
+    ///
+    /// The object is attached with [`TriggerMode::Level`], therefore the [`WaitSet`] wakes up
+    /// as long as the object is writable. Detach it when there is nothing more to send.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2_bb_posix::unix_datagram_socket::*;
+    /// # use iceoryx2_bb_system_types::file_path::FilePath;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// # let socket_name = FilePath::new(b"some_socket")?;
+    ///
+    /// let sender = UnixDatagramSenderBuilder::new(&socket_name).create().unwrap();
+    ///
+    /// let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+    /// let guard = waitset.attach_writable(&sender)?;
+    ///
+    /// waitset.wait_and_process_once(|attachment_id| {
+    ///     if attachment_id.has_event_from(&guard) {
+    ///         sender.try_send(b"hello").unwrap();
+    ///     }
+    ///     CallbackProgression::Continue
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn attach_writable<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+        &'waitset self,
+        attachment: &'attachment T,
+    ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
+        let reactor_guard = self.attach_to_reactor(attachment, TriggerMode::Level, || {
+            self.reactor.attach_writable(attachment)
+        })?;
+        self.attach()?;
+
+        let guard = WaitSetGuard {
+            waitset: self,
+            guard_type: GuardType::Notification(reactor_guard),
+        };
+        self.register_attachment(&guard, WaitSetAttachmentKind::Writable);
+        Ok(guard)
     }
 
     /// Attaches an object as deadline to the [`WaitSet`]. Whenever the event is received or the
@@ -670,7 +842,9 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         attachment: &'attachment T,
         deadline: Duration,
     ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
-        let reactor_guard = self.attach_to_reactor(attachment)?;
+        let reactor_guard = self.attach_to_reactor(attachment, TriggerMode::Level, || {
+            self.reactor.attach(attachment)
+        })?;
         let deadline_queue_guard = self.attach_to_deadline_queue(deadline)?;
 
         let reactor_idx = unsafe { reactor_guard.file_descriptor().native_handle() };
@@ -684,10 +858,12 @@ impl<Service: crate::service::Service> WaitSet<Service> {
             .insert(deadline_idx, reactor_idx);
         self.attach()?;
 
-        Ok(WaitSetGuard {
+        let guard = WaitSetGuard {
             waitset: self,
             guard_type: GuardType::Deadline(reactor_guard, deadline_queue_guard),
-        })
+        };
+        self.register_attachment(&guard, WaitSetAttachmentKind::Deadline(deadline));
+        Ok(guard)
     }
 
     /// Attaches a tick event to the [`WaitSet`]. Whenever the timeout is reached the [`WaitSet`]
@@ -699,10 +875,12 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         let deadline_queue_guard = self.attach_to_deadline_queue(interval)?;
         self.attach()?;
 
-        Ok(WaitSetGuard {
+        let guard = WaitSetGuard {
             waitset: self,
             guard_type: GuardType::Tick(deadline_queue_guard),
-        })
+        };
+        self.register_attachment(&guard, WaitSetAttachmentKind::Interval(interval));
+        Ok(guard)
     }
 
     /// Waits until an event arrives on the [`WaitSet`], then collects all events by calling the
@@ -767,6 +945,66 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         }
     }
 
+    /// Like [`WaitSet::wait_and_process()`] but returns at the latest when the provided
+    /// `timeout` has passed. The time spent in previous iterations is subtracted so that the
+    /// whole loop, and not a single iteration, is bounded by `timeout`.
+    ///
+    /// When the `timeout` has passed without any signal or stop request it returns
+    /// [`WaitSetRunResult::AllEventsHandled`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use iceoryx2::prelude::*;
+    /// # use core::time::Duration;
+    /// # fn main() -> Result<(), Box<dyn core::error::Error>> {
+    /// let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+    /// let guard = waitset.attach_interval(Duration::from_millis(50))?;
+    ///
+    /// let on_event = |attachment_id: WaitSetAttachmentId<ipc::Service>| {
+    ///     // do some periodic work
+    ///     CallbackProgression::Continue
+    /// };
+    ///
+    /// // processes events for 200ms and then returns
+    /// waitset.wait_and_process_with_timeout(on_event, Duration::from_millis(200))?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn wait_and_process_with_timeout<
+        F: FnMut(WaitSetAttachmentId<Service>) -> CallbackProgression,
+    >(
+        &self,
+        mut fn_call: F,
+        timeout: Duration,
+    ) -> Result<WaitSetRunResult, WaitSetRunError> {
+        let msg = "Unable to run in WaitSet::wait_and_process_with_timeout() loop";
+        let start = fail!(from self, when Time::now_with_clock(self.clock_source),
+                        with WaitSetRunError::InternalError,
+                        "{msg} since the current time could not be acquired.");
+
+        let deadline = start.saturating_add(timeout);
+
+        loop {
+            let now = fail!(from self, when Time::now_with_clock(self.clock_source),
+                                with WaitSetRunError::InternalError,
+                                "{msg} since the current time could not be acquired.");
+            let remaining_timeout = deadline.saturating_duration_since(now);
+            if remaining_timeout.is_zero() {
+                return Ok(WaitSetRunResult::AllEventsHandled);
+            }
+
+            match self.wait_and_process_once_with_timeout(&mut fn_call, remaining_timeout) {
+                Ok(WaitSetRunResult::AllEventsHandled) => (),
+                Ok(v) => return Ok(v),
+                Err(e) => {
+                    fail!(from self, with e, "{msg} since ({:?}) has occurred.", e);
+                }
+            }
+        }
+    }
+
     /// Waits until an event arrives on the [`WaitSet`], then
     /// collects all events by calling the provided `fn_call` callback with the corresponding
     /// [`WaitSetAttachmentId`] and then returns. This makes it ideal to be called in some kind of
@@ -941,19 +1179,75 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         self.len() == 0
     }
 
+    /// Returns the duration until the next deadline or interval of the attachments is reached.
+    /// If a deadline was already missed it returns [`Duration::ZERO`]. When no deadline or
+    /// interval is attached it returns [`None`].
+    pub fn time_until_next_deadline(&self) -> Option<Duration> {
+        if self.deadline_queue.is_empty() {
+            return None;
+        }
+
+        match self.deadline_queue.duration_until_next_deadline() {
+            Ok(duration) => Some(duration),
+            Err(e) => {
+                warn!(from self,
+                    "Unable to acquire the time until the next deadline ({:?}). Assuming that the deadline is already reached.", e);
+                Some(Duration::ZERO)
+            }
+        }
+    }
+
+    /// Returns a [`WaitSetAttachmentInfo`] for every attachment that is currently attached to
+    /// the [`WaitSet`]. The attachments are captured at the time of the call.
+    pub fn attachments(&self) -> impl Iterator<Item = WaitSetAttachmentInfo<Service>> {
+        self.attachments
+            .borrow()
+            .iter()
+            .map(|(attachment_type, kind)| WaitSetAttachmentInfo {
+                id: WaitSetAttachmentId {
+                    attachment_type: *attachment_type,
+                    _data: PhantomData,
+                },
+                kind: *kind,
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
     /// Returns the [`SignalHandlingMode`] with which the [`WaitSet`] was created.
     pub fn signal_handling_mode(&self) -> SignalHandlingMode {
         self.signal_handling_mode
     }
 
-    fn attach_to_reactor<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+    /// Returns the [`ClockType`] against which the deadlines and intervals of the [`WaitSet`]
+    /// are measured.
+    pub fn clock_source(&self) -> ClockType {
+        self.clock_source
+    }
+
+    fn attach_to_reactor<
+        'waitset,
+        'attachment,
+        T: SynchronousMultiplexing + Debug,
+        F: FnOnce() -> Result<
+            <Service::Reactor as Reactor>::Guard<'waitset, 'attachment>,
+            ReactorAttachError,
+        >,
+    >(
         &'waitset self,
         attachment: &'attachment T,
+        trigger_mode: TriggerMode,
+        attach_call: F,
     ) -> Result<<Service::Reactor as Reactor>::Guard<'waitset, 'attachment>, WaitSetAttachmentError>
     {
         let msg = "Unable to attach object to internal reactor";
 
-        match self.reactor.attach(attachment) {
+        if !attachment.file_descriptor().is_valid() {
+            fail!(from self, with WaitSetAttachmentError::Disconnected,
+                "{msg} {:?} since its file descriptor is no longer valid.", attachment);
+        }
+
+        match attach_call() {
             Ok(guard) => Ok(guard),
             Err(ReactorAttachError::AlreadyAttached) => {
                 fail!(from self, with WaitSetAttachmentError::AlreadyAttached,
@@ -972,6 +1266,10 @@ impl<Service: crate::service::Service> WaitSet<Service> {
                 fail!(from self, with WaitSetAttachmentError::InsufficientResources,
                     "{msg} {:?} due to insufficient resources", attachment);
             }
+            Err(ReactorAttachError::UnsupportedTriggerMode) => {
+                fail!(from self, with WaitSetAttachmentError::UnsupportedTriggerMode,
+                    "{msg} {:?} since the trigger mode {:?} is not supported.", attachment, trigger_mode);
+            }
         }
     }
 
@@ -0,0 +1,51 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod event_id {
+    use iceoryx2::port::event_id::EventIdCreateError;
+    use iceoryx2::prelude::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    #[test]
+    fn creating_with_zero_works() {
+        let sut = EventId::new(0);
+
+        assert_that!(sut, is_ok);
+        assert_that!(sut.unwrap().as_value(), eq 0);
+    }
+
+    #[test]
+    fn creating_with_max_supported_value_works() {
+        let max = EventId::max_supported().as_value();
+        let sut = EventId::new(max);
+
+        assert_that!(sut, is_ok);
+        assert_that!(sut.unwrap(), eq EventId::max_supported());
+    }
+
+    #[test]
+    fn creating_with_value_exceeding_max_supported_value_fails() {
+        let max = EventId::max_supported().as_value();
+
+        assert_that!(EventId::new(max + 1), eq Err(EventIdCreateError::ExceedsMaxSupportedValue));
+        assert_that!(EventId::new(usize::MAX), eq Err(EventIdCreateError::ExceedsMaxSupportedValue));
+    }
+
+    #[test]
+    fn new_unchecked_creates_same_event_id_as_new() {
+        const VALUE: usize = 8192;
+        const SUT: EventId = EventId::new_unchecked(VALUE);
+
+        assert_that!(SUT, eq EventId::new(VALUE).unwrap());
+        assert_that!(SUT.as_value(), eq VALUE);
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "async")]
+
+mod listener_async {
+    use core::time::Duration;
+
+    use iceoryx2::port::listener::Listener;
+    use iceoryx2::port::notifier::Notifier;
+    use iceoryx2::prelude::*;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_testing::assert_that;
+
+    type Sut = ipc_threadsafe::Service;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    fn create_event(node: &Node<Sut>) -> (Listener<Sut>, Notifier<Sut>) {
+        let service = node
+            .service_builder(&generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+
+        (
+            service.listener_builder().create().unwrap(),
+            service.notifier_builder().create().unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn wait_async_receives_event_from_other_task() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let (listener, notifier) = create_event(&node);
+
+        let notifier_task = tokio::spawn(async move {
+            tokio::time::sleep(TIMEOUT).await;
+            notifier
+                .notify_with_custom_event_id(EventId::new(7).unwrap())
+                .unwrap();
+        });
+
+        let event_id = listener.wait_async().await;
+        assert_that!(event_id, eq Ok(EventId::new(7).unwrap()));
+
+        notifier_task.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn wait_async_resolves_immediately_when_event_is_pending() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let (listener, notifier) = create_event(&node);
+
+        notifier
+            .notify_with_custom_event_id(EventId::new(3).unwrap())
+            .unwrap();
+
+        let event_id = tokio::time::timeout(TIMEOUT, listener.wait_async()).await;
+        assert_that!(event_id, eq Ok(Ok(EventId::new(3).unwrap())));
+    }
+
+    #[tokio::test]
+    async fn dropped_wait_async_future_does_not_consume_events() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let (listener, notifier) = create_event(&node);
+
+        let result = tokio::time::timeout(TIMEOUT, listener.wait_async()).await;
+        assert_that!(result, is_err);
+
+        notifier
+            .notify_with_custom_event_id(EventId::new(5).unwrap())
+            .unwrap();
+
+        let event_id = tokio::time::timeout(TIMEOUT, listener.wait_async()).await;
+        assert_that!(event_id, eq Ok(Ok(EventId::new(5).unwrap())));
+        assert_that!(listener.try_wait_one().unwrap(), eq None);
+    }
+
+    #[tokio::test]
+    async fn wait_async_receives_multiple_events_in_order() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let (listener, notifier) = create_event(&node);
+
+        let notifier_task = tokio::spawn(async move {
+            for id in 1..=3 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                notifier
+                    .notify_with_custom_event_id(EventId::new(id).unwrap())
+                    .unwrap();
+            }
+        });
+
+        for id in 1..=3 {
+            assert_that!(listener.wait_async().await, eq Ok(EventId::new(id).unwrap()));
+        }
+
+        notifier_task.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn wait_async_receives_events_after_many_pending_polls() {
+        const NUMBER_OF_ITERATIONS: usize = 50;
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let (listener, notifier) = create_event(&node);
+
+        let notifier_task = tokio::spawn(async move {
+            for id in 1..=NUMBER_OF_ITERATIONS {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                notifier
+                    .notify_with_custom_event_id(EventId::new(id).unwrap())
+                    .unwrap();
+            }
+        });
+
+        for id in 1..=NUMBER_OF_ITERATIONS {
+            let result = tokio::time::timeout(TIMEOUT * 10, listener.wait_async()).await;
+            assert_that!(result, eq Ok(Ok(EventId::new(id).unwrap())));
+        }
+
+        notifier_task.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn concurrent_wait_async_futures_of_same_listener_are_all_woken() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let (listener, notifier) = create_event(&node);
+
+        let notifier_task = tokio::spawn(async move {
+            tokio::time::sleep(TIMEOUT).await;
+            notifier
+                .notify_with_custom_event_id(EventId::new(1).unwrap())
+                .unwrap();
+            tokio::time::sleep(TIMEOUT).await;
+            notifier
+                .notify_with_custom_event_id(EventId::new(2).unwrap())
+                .unwrap();
+        });
+
+        let (first, second) = tokio::time::timeout(TIMEOUT * 10, async {
+            tokio::join!(listener.wait_async(), listener.wait_async())
+        })
+        .await
+        .unwrap();
+
+        let mut event_ids = [first.unwrap().as_value(), second.unwrap().as_value()];
+        event_ids.sort();
+        assert_that!(event_ids, eq [1, 2]);
+
+        notifier_task.await.unwrap();
+    }
+}
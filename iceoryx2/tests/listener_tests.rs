@@ -14,6 +14,7 @@
 mod listener {
     use std::collections::HashSet;
 
+    use iceoryx2::port::event_id::EventId;
     use iceoryx2::testing::*;
     use iceoryx2::{node::NodeBuilder, port::listener::ListenerCreateError, service::Service};
     use iceoryx2_bb_testing::assert_that;
@@ -50,6 +51,62 @@ mod listener {
         }
     }
 
+    #[test]
+    fn number_of_connected_notifiers_is_updated<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const MAX_NOTIFIERS: usize = 8;
+
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .max_notifiers(MAX_NOTIFIERS)
+            .create()
+            .unwrap();
+
+        let sut = service.listener_builder().create().unwrap();
+        assert_that!(sut.number_of_connected_notifiers(), eq 0);
+
+        let mut notifiers = vec![];
+        for n in 0..MAX_NOTIFIERS {
+            notifiers.push(service.notifier_builder().create().unwrap());
+            assert_that!(sut.number_of_connected_notifiers(), eq n + 1);
+        }
+
+        for n in (0..MAX_NOTIFIERS).rev() {
+            notifiers.pop();
+            assert_that!(sut.number_of_connected_notifiers(), eq n);
+        }
+    }
+
+    #[test]
+    fn drain_discards_all_pending_events<Sut: Service>() {
+        let service_name = generate_service_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const NUMBER_OF_EVENTS: usize = 8;
+
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .event_id_max_value(NUMBER_OF_EVENTS)
+            .create()
+            .unwrap();
+
+        let sut = service.listener_builder().create().unwrap();
+        let notifier = service.notifier_builder().create().unwrap();
+        assert_that!(sut.drain(), eq Ok(0));
+
+        for i in 0..NUMBER_OF_EVENTS {
+            assert_that!(notifier.notify_with_custom_event_id(EventId::new(i).unwrap()), eq Ok(1));
+        }
+
+        assert_that!(sut.drain(), eq Ok(NUMBER_OF_EVENTS));
+        assert_that!(sut.try_wait_one(), eq Ok(None));
+        assert_that!(sut.drain(), eq Ok(0));
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -62,3 +119,246 @@ mod listener {
     #[instantiate_tests(<iceoryx2::service::local_threadsafe::Service>)]
     mod local_threadsafe {}
 }
+
+mod listener_spurious_wakeup {
+    use core::cell::Cell;
+    use core::fmt::Debug;
+    use core::time::Duration;
+
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::dynamic_config::DynamicConfig;
+    use iceoryx2::testing::*;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_system_types::path::Path;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::event::{
+        Event, ListenerCreateError, ListenerWaitError, NotifierCreateError, TriggerId,
+    };
+    use iceoryx2_cal::named_concept::*;
+    use iceoryx2_cal::shm_allocator::bump_allocator::BumpAllocator;
+    use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+    use iceoryx2_cal::*;
+
+    type InnerEvent = event::recommended::Local;
+
+    thread_local! {
+        static SPURIOUS_WAKEUPS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn is_spurious_wakeup() -> bool {
+        SPURIOUS_WAKEUPS.with(|v| match v.get() {
+            0 => false,
+            n => {
+                v.set(n - 1);
+                true
+            }
+        })
+    }
+
+    /// Event concept that wakes up a blocking listener without an event as long as
+    /// `SPURIOUS_WAKEUPS` of the waiting thread is not zero.
+    #[derive(Debug)]
+    struct SpuriousEvent;
+
+    impl NamedConceptMgmt for SpuriousEvent {
+        type Configuration = <InnerEvent as NamedConceptMgmt>::Configuration;
+
+        unsafe fn remove_cfg(
+            name: &FileName,
+            cfg: &Self::Configuration,
+        ) -> Result<bool, NamedConceptRemoveError> {
+            InnerEvent::remove_cfg(name, cfg)
+        }
+
+        fn does_exist_cfg(
+            name: &FileName,
+            cfg: &Self::Configuration,
+        ) -> Result<bool, NamedConceptDoesExistError> {
+            InnerEvent::does_exist_cfg(name, cfg)
+        }
+
+        fn list_cfg(cfg: &Self::Configuration) -> Result<Vec<FileName>, NamedConceptListError> {
+            InnerEvent::list_cfg(cfg)
+        }
+
+        fn remove_path_hint(value: &Path) -> Result<(), NamedConceptPathHintRemoveError> {
+            InnerEvent::remove_path_hint(value)
+        }
+    }
+
+    impl Event for SpuriousEvent {
+        type Notifier = <InnerEvent as Event>::Notifier;
+        type NotifierBuilder = SpuriousNotifierBuilder;
+        type Listener = SpuriousListener;
+        type ListenerBuilder = SpuriousListenerBuilder;
+
+        fn has_trigger_id_limit() -> bool {
+            InnerEvent::has_trigger_id_limit()
+        }
+    }
+
+    #[derive(Debug)]
+    struct SpuriousNotifierBuilder(<InnerEvent as Event>::NotifierBuilder);
+
+    impl NamedConceptBuilder<SpuriousEvent> for SpuriousNotifierBuilder {
+        fn new(name: &FileName) -> Self {
+            Self(<InnerEvent as Event>::NotifierBuilder::new(name))
+        }
+
+        fn config(self, config: &<SpuriousEvent as NamedConceptMgmt>::Configuration) -> Self {
+            Self(self.0.config(config))
+        }
+    }
+
+    impl event::NotifierBuilder<SpuriousEvent> for SpuriousNotifierBuilder {
+        fn timeout(self, timeout: Duration) -> Self {
+            Self(self.0.timeout(timeout))
+        }
+
+        fn open(self) -> Result<<SpuriousEvent as Event>::Notifier, NotifierCreateError> {
+            self.0.open()
+        }
+    }
+
+    #[derive(Debug)]
+    struct SpuriousListenerBuilder(<InnerEvent as Event>::ListenerBuilder);
+
+    impl NamedConceptBuilder<SpuriousEvent> for SpuriousListenerBuilder {
+        fn new(name: &FileName) -> Self {
+            Self(<InnerEvent as Event>::ListenerBuilder::new(name))
+        }
+
+        fn config(self, config: &<SpuriousEvent as NamedConceptMgmt>::Configuration) -> Self {
+            Self(self.0.config(config))
+        }
+    }
+
+    impl event::ListenerBuilder<SpuriousEvent> for SpuriousListenerBuilder {
+        fn trigger_id_max(self, id: TriggerId) -> Self {
+            Self(self.0.trigger_id_max(id))
+        }
+
+        fn create(self) -> Result<SpuriousListener, ListenerCreateError> {
+            Ok(SpuriousListener(self.0.create()?))
+        }
+    }
+
+    #[derive(Debug)]
+    struct SpuriousListener(<InnerEvent as Event>::Listener);
+
+    impl NamedConcept for SpuriousListener {
+        fn name(&self) -> &FileName {
+            self.0.name()
+        }
+    }
+
+    impl event::Listener for SpuriousListener {
+        fn try_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+            self.0.try_wait_one()
+        }
+
+        fn timed_wait_one(
+            &self,
+            timeout: Duration,
+        ) -> Result<Option<TriggerId>, ListenerWaitError> {
+            self.0.timed_wait_one(timeout)
+        }
+
+        fn blocking_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+            if is_spurious_wakeup() {
+                return Ok(None);
+            }
+            self.0.blocking_wait_one()
+        }
+
+        fn try_wait_all<F: FnMut(TriggerId)>(&self, callback: F) -> Result<(), ListenerWaitError> {
+            self.0.try_wait_all(callback)
+        }
+
+        fn timed_wait_all<F: FnMut(TriggerId)>(
+            &self,
+            callback: F,
+            timeout: Duration,
+        ) -> Result<(), ListenerWaitError> {
+            self.0.timed_wait_all(callback, timeout)
+        }
+
+        fn blocking_wait_all<F: FnMut(TriggerId)>(
+            &self,
+            callback: F,
+        ) -> Result<(), ListenerWaitError> {
+            if is_spurious_wakeup() {
+                return Ok(());
+            }
+            self.0.blocking_wait_all(callback)
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Sut {}
+
+    impl iceoryx2::service::Service for Sut {
+        type StaticStorage = static_storage::recommended::Local;
+        type ConfigSerializer = serialize::recommended::Recommended;
+        type DynamicStorage = dynamic_storage::recommended::Local<DynamicConfig>;
+        type ServiceNameHasher = hash::recommended::Recommended;
+        type SharedMemory = shared_memory::recommended::Local<PoolAllocator>;
+        type ResizableSharedMemory = resizable_shared_memory::recommended::Local<PoolAllocator>;
+        type Connection = zero_copy_connection::recommended::Local;
+        type Event = SpuriousEvent;
+        type Monitoring = monitoring::recommended::Local;
+        type Reactor = reactor::recommended::Local;
+        type ArcThreadSafetyPolicy<T: Send + Debug> =
+            arc_sync_policy::single_threaded::SingleThreaded<T>;
+        type BlackboardMgmt<KeyType: Send + Sync + Debug + 'static> =
+            dynamic_storage::recommended::Local<KeyType>;
+        type BlackboardPayload = shared_memory::recommended::Local<BumpAllocator>;
+        const BLACKBOARD_MAX_KEY_SIZE: usize = 64;
+    }
+
+    impl iceoryx2::service::internal::ServiceInternal<Sut> for Sut {}
+
+    #[test]
+    fn blocking_wait_one_keeps_waiting_on_spurious_wakeup() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+        let sut = service.listener_builder().create().unwrap();
+        let notifier = service.notifier_builder().create().unwrap();
+
+        notifier
+            .notify_with_custom_event_id(EventId::new(4).unwrap())
+            .unwrap();
+        SPURIOUS_WAKEUPS.with(|v| v.set(3));
+
+        assert_that!(sut.blocking_wait_one(), eq Ok(Some(EventId::new(4).unwrap())));
+        assert_that!(SPURIOUS_WAKEUPS.with(|v| v.get()), eq 0);
+    }
+
+    #[test]
+    fn blocking_wait_all_keeps_waiting_on_spurious_wakeup() {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_service_name())
+            .event()
+            .create()
+            .unwrap();
+        let sut = service.listener_builder().create().unwrap();
+        let notifier = service.notifier_builder().create().unwrap();
+
+        notifier
+            .notify_with_custom_event_id(EventId::new(9).unwrap())
+            .unwrap();
+        SPURIOUS_WAKEUPS.with(|v| v.set(3));
+
+        let mut event_ids = vec![];
+        assert_that!(sut.blocking_wait_all(|id| event_ids.push(id)), is_ok);
+        assert_that!(event_ids, eq vec![EventId::new(9).unwrap()]);
+        assert_that!(SPURIOUS_WAKEUPS.with(|v| v.get()), eq 0);
+    }
+}
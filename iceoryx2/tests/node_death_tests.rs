@@ -276,7 +276,7 @@ mod node_death_tests {
         let _watchdog = Watchdog::new();
         let mut config = generate_isolated_config();
         let service_name = generate_service_name();
-        let notifier_dead_event = EventId::new(8);
+        let notifier_dead_event = EventId::new(8).unwrap();
         config.global.node.cleanup_dead_nodes_on_creation = false;
 
         let mut dead_node = S::create_test_node(&config).node;
@@ -289,8 +289,8 @@ mod node_death_tests {
             .service_builder(&service_name)
             .event()
             .notifier_dead_event(notifier_dead_event)
-            .notifier_created_event(EventId::new(0))
-            .notifier_dropped_event(EventId::new(0))
+            .notifier_created_event(EventId::new(0).unwrap())
+            .notifier_dropped_event(EventId::new(0).unwrap())
             .create()
             .unwrap();
         let dead_notifier = dead_service.notifier_builder().create().unwrap();
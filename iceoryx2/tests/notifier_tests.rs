@@ -58,6 +58,35 @@ mod notifier {
         }
     }
 
+    #[test]
+    fn number_of_connected_listeners_is_updated<Sut: Service>() {
+        let config = generate_isolated_config();
+        let service_name = generate_service_name();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        const MAX_LISTENERS: usize = 8;
+
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .max_listeners(MAX_LISTENERS)
+            .create()
+            .unwrap();
+
+        let sut = service.notifier_builder().create().unwrap();
+        assert_that!(sut.number_of_connected_listeners(), eq 0);
+
+        let mut listeners = vec![];
+        for n in 0..MAX_LISTENERS {
+            listeners.push(service.listener_builder().create().unwrap());
+            assert_that!(sut.number_of_connected_listeners(), eq n + 1);
+        }
+
+        for n in (0..MAX_LISTENERS).rev() {
+            listeners.pop();
+            assert_that!(sut.number_of_connected_listeners(), eq n);
+        }
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
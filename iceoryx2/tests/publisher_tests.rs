@@ -265,6 +265,67 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn publisher_send_copy_delivers_value_to_all_subscribers<Sut: Service>() -> TestResult<()> {
+        const NUMBER_OF_SUBSCRIBERS: usize = 3;
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(NUMBER_OF_SUBSCRIBERS)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        assert_that!(sut.send_copy(12), eq Ok(0));
+
+        let mut subscribers = vec![];
+        for _ in 0..NUMBER_OF_SUBSCRIBERS {
+            subscribers.push(service.subscriber_builder().create()?);
+        }
+
+        assert_that!(sut.send_copy(8127), eq Ok(NUMBER_OF_SUBSCRIBERS));
+
+        for subscriber in subscribers {
+            let sample = subscriber.receive()?;
+            assert_that!(sample, is_some);
+            assert_that!(*sample.unwrap(), eq 8127);
+            assert_that!(subscriber.receive()?, is_none);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_reports_number_of_connected_subscribers<Sut: Service>() -> TestResult<()> {
+        const MAX_SUBSCRIBERS: usize = 4;
+        let service_name = generate_name()?;
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(MAX_SUBSCRIBERS)
+            .create()?;
+
+        let sut = service.publisher_builder().create()?;
+        assert_that!(sut.number_of_subscribers(), eq 0);
+
+        let subscriber_1 = service.subscriber_builder().create()?;
+        let subscriber_2 = service.subscriber_builder().create()?;
+        assert_that!(sut.number_of_subscribers(), eq 2);
+        assert_that!(service.static_config().max_subscribers() - sut.number_of_subscribers(), eq 2);
+
+        drop(subscriber_1);
+        assert_that!(sut.number_of_subscribers(), eq 1);
+
+        drop(subscriber_2);
+        assert_that!(sut.number_of_subscribers(), eq 0);
+
+        Ok(())
+    }
+
     #[test]
     fn publisher_max_loaned_samples_works<Sut: Service>() -> TestResult<()> {
         let service_name = generate_name()?;
@@ -668,6 +729,9 @@ mod publisher {
     #[instantiate_tests(<iceoryx2::service::ipc_threadsafe::Service>)]
     mod ipc_threadsafe {}
 
+    #[instantiate_tests(<iceoryx2::service::ipc_memfd::Service>)]
+    mod ipc_memfd {}
+
     #[instantiate_tests(<iceoryx2::service::local_threadsafe::Service>)]
     mod local_threadsafe {}
 }
@@ -19,7 +19,7 @@ mod service_event {
     use std::time::Instant;
 
     use iceoryx2::port::listener::{Listener, ListenerCreateError};
-    use iceoryx2::port::notifier::{NotifierCreateError, NotifierNotifyError};
+    use iceoryx2::port::notifier::{Notifier, NotifierCreateError, NotifierNotifyError};
     use iceoryx2::prelude::*;
     use iceoryx2::service::builder::event::{EventCreateError, EventOpenError};
     use iceoryx2::testing::*;
@@ -101,6 +101,67 @@ mod service_event {
         assert_that!(sut2, is_ok);
     }
 
+    #[test]
+    fn open_observer_fails_when_service_does_not_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node.service_builder(&service_name).event().open_observer();
+        assert_that!(sut.err(), eq Some(EventOpenError::DoesNotExist));
+    }
+
+    #[test]
+    fn open_observer_does_not_occupy_participant_slots<Sut: Service>() {
+        const MAX_LISTENERS: usize = 4;
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let observer_node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .max_nodes(1)
+            .max_listeners(MAX_LISTENERS)
+            .create()
+            .unwrap();
+
+        let mut listeners = vec![];
+        for _ in 0..MAX_LISTENERS {
+            listeners.push(service.listener_builder().create().unwrap());
+        }
+
+        let sut = observer_node
+            .service_builder(&service_name)
+            .event()
+            .open_observer()
+            .unwrap();
+
+        assert_that!(sut.name(), eq & service_name);
+        assert_that!(sut.service_id(), eq service.service_id());
+        assert_that!(sut.static_config().max_listeners(), eq MAX_LISTENERS);
+        assert_that!(sut.dynamic_config().number_of_listeners(), eq MAX_LISTENERS);
+
+        let mut number_of_nodes = 0;
+        sut.nodes(|_| {
+            number_of_nodes += 1;
+            CallbackProgression::Continue
+        })
+        .unwrap();
+        assert_that!(number_of_nodes, eq 1);
+
+        let service_of_observer_node = observer_node.service_builder(&service_name).event().open();
+        assert_that!(service_of_observer_node.err(), eq Some(EventOpenError::ExceedsMaxNumberOfNodes));
+
+        let listener = service.listener_builder().create();
+        assert_that!(listener.err(), eq Some(ListenerCreateError::ExceedsMaxSupportedListeners));
+
+        listeners.pop();
+        assert_that!(sut.dynamic_config().number_of_listeners(), eq MAX_LISTENERS - 1);
+        listeners.push(service.listener_builder().create().unwrap());
+        assert_that!(sut.dynamic_config().number_of_listeners(), eq MAX_LISTENERS);
+    }
+
     #[test]
     fn open_fails_when_service_does_not_satisfy_opener_notifier_requirements<Sut: Service>() {
         let service_name = generate_name();
@@ -273,6 +334,24 @@ mod service_event {
         assert_that!(sut2, is_ok);
     }
 
+    #[test]
+    fn create_adjusts_event_id_max_value_to_largest_storable_event_id<Sut: Service>() {
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .event_id_max_value(usize::MAX)
+            .create()
+            .unwrap();
+
+        // the static config supports only values that fit into a signed 64-bit integer
+        let max_value = (EventId::max_supported().as_value() as u64).min(i64::MAX as u64);
+        assert_that!(sut.static_config().event_id_max_value() as u64, eq max_value);
+    }
+
     #[test]
     fn open_uses_predefined_settings_when_nothing_is_specified<Sut: Service>() {
         let service_name = generate_name();
@@ -284,25 +363,25 @@ mod service_event {
             .max_nodes(7)
             .max_notifiers(4)
             .max_listeners(5)
-            .notifier_dead_event(EventId::new(8))
-            .notifier_dropped_event(EventId::new(9))
-            .notifier_created_event(EventId::new(10))
+            .notifier_dead_event(EventId::new(8).unwrap())
+            .notifier_dropped_event(EventId::new(9).unwrap())
+            .notifier_created_event(EventId::new(10).unwrap())
             .create()
             .unwrap();
         assert_that!(sut.static_config().max_nodes(), eq 7);
         assert_that!(sut.static_config().max_notifiers(), eq 4);
         assert_that!(sut.static_config().max_listeners(), eq 5);
-        assert_that!(sut.static_config().notifier_dead_event(), eq Some(EventId::new(8)));
-        assert_that!(sut.static_config().notifier_dropped_event(), eq Some(EventId::new(9)));
-        assert_that!(sut.static_config().notifier_created_event(), eq Some(EventId::new(10)));
+        assert_that!(sut.static_config().notifier_dead_event(), eq Some(EventId::new(8).unwrap()));
+        assert_that!(sut.static_config().notifier_dropped_event(), eq Some(EventId::new(9).unwrap()));
+        assert_that!(sut.static_config().notifier_created_event(), eq Some(EventId::new(10).unwrap()));
 
         let sut2 = node.service_builder(&service_name).event().open().unwrap();
         assert_that!(sut2.static_config().max_nodes(), eq 7);
         assert_that!(sut2.static_config().max_notifiers(), eq 4);
         assert_that!(sut2.static_config().max_listeners(), eq 5);
-        assert_that!(sut2.static_config().notifier_dead_event(), eq Some(EventId::new(8)));
-        assert_that!(sut2.static_config().notifier_dropped_event(), eq Some(EventId::new(9)));
-        assert_that!(sut2.static_config().notifier_created_event(), eq Some(EventId::new(10)));
+        assert_that!(sut2.static_config().notifier_dead_event(), eq Some(EventId::new(8).unwrap()));
+        assert_that!(sut2.static_config().notifier_dropped_event(), eq Some(EventId::new(9).unwrap()));
+        assert_that!(sut2.static_config().notifier_created_event(), eq Some(EventId::new(10).unwrap()));
     }
 
     #[test]
@@ -337,7 +416,7 @@ mod service_event {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let event_id = EventId::new(32);
+        let event_id = EventId::new(32).unwrap();
 
         let sut = node
             .service_builder(&service_name)
@@ -369,7 +448,7 @@ mod service_event {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let event_id = EventId::new(23);
+        let event_id = EventId::new(23).unwrap();
 
         let sut = node
             .service_builder(&service_name)
@@ -435,8 +514,8 @@ mod service_event {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let notifier_created = EventId::new(31);
-        let notifier_dropped = EventId::new(28);
+        let notifier_created = EventId::new(31).unwrap();
+        let notifier_dropped = EventId::new(28).unwrap();
 
         let sut = node
             .service_builder(&service_name)
@@ -495,7 +574,7 @@ mod service_event {
         for i in 0..MAX_NOTIFIERS {
             notifiers.push(
                 sut.notifier_builder()
-                    .default_event_id(EventId::new(i + 3))
+                    .default_event_id(EventId::new(i + 3).unwrap())
                     .create()
                     .unwrap(),
             );
@@ -508,7 +587,7 @@ mod service_event {
                 for listener in &mut listeners {
                     let mut received_events = 0;
                     for event in listener.try_wait_one().unwrap().iter() {
-                        assert_that!(*event, eq EventId::new(i + 3));
+                        assert_that!(*event, eq EventId::new(i + 3).unwrap());
                         received_events += 1;
                     }
                     assert_that!(received_events, eq 1);
@@ -544,7 +623,7 @@ mod service_event {
         for i in 0..MAX_NOTIFIERS {
             notifiers.push(
                 sut.notifier_builder()
-                    .default_event_id(EventId::new(i))
+                    .default_event_id(EventId::new(i).unwrap())
                     .create()
                     .unwrap(),
             );
@@ -714,12 +793,13 @@ mod service_event {
 
         for i in 0..=EVENT_ID_MAX_VALUE {
             assert_that!(notifier
-                .notify_with_custom_event_id(EventId::new(i))
+                .notify_with_custom_event_id(EventId::new(i).unwrap())
                 .unwrap(), eq 1);
-            assert_that!(listener.try_wait_one().unwrap(), eq Some(EventId::new(i)));
+            assert_that!(listener.try_wait_one().unwrap(), eq Some(EventId::new(i).unwrap()));
         }
 
-        let result = notifier.notify_with_custom_event_id(EventId::new(EVENT_ID_MAX_VALUE + 1));
+        let result =
+            notifier.notify_with_custom_event_id(EventId::new(EVENT_ID_MAX_VALUE + 1).unwrap());
         assert_that!(result, is_err);
         assert_that!(result.err().unwrap(), eq NotifierNotifyError::EventIdOutOfBounds);
     }
@@ -731,7 +811,7 @@ mod service_event {
         let number_of_listener_threads = 2;
         let number_of_notifier_threads = 2;
         const NUMBER_OF_ITERATIONS: usize = 100;
-        const EVENT_ID: EventId = EventId::new(8);
+        const EVENT_ID: EventId = EventId::new_unchecked(8);
 
         let keep_running = AtomicBool::new(true);
         let service_name = generate_name();
@@ -791,7 +871,7 @@ mod service_event {
         let number_of_listener_threads = 2;
         let number_of_notifier_threads = 2;
         const NUMBER_OF_ITERATIONS: usize = 100;
-        const EVENT_ID: EventId = EventId::new(8);
+        const EVENT_ID: EventId = EventId::new_unchecked(8);
 
         let keep_running = AtomicBool::new(true);
         let service_name = generate_name();
@@ -852,7 +932,7 @@ mod service_event {
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let event_id = EventId::new(12);
+        let event_id = EventId::new(12).unwrap();
 
         let sut = node
             .service_builder(&service_name)
@@ -916,7 +996,7 @@ mod service_event {
 
     #[test]
     fn service_can_be_opened_when_there_is_a_notifier<Sut: Service>() {
-        let event_id = EventId::new(76);
+        let event_id = EventId::new(76).unwrap();
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
@@ -954,7 +1034,7 @@ mod service_event {
 
     #[test]
     fn service_can_be_opened_when_there_is_a_listener<Sut: Service>() {
-        let event_id = EventId::new(93);
+        let event_id = EventId::new(93).unwrap();
         let service_name = generate_name();
         let config = generate_isolated_config();
         let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
@@ -1056,7 +1136,7 @@ mod service_event {
             std::thread::sleep(TIMEOUT);
             assert_that!(counter.load(Ordering::Relaxed), eq 0);
 
-            assert_that!(notifier.notify_with_custom_event_id(EventId::new(13)).unwrap(), eq 1);
+            assert_that!(notifier.notify_with_custom_event_id(EventId::new(13).unwrap()).unwrap(), eq 1);
             t.join().unwrap();
             assert_that!(counter.load(Ordering::Relaxed), eq 1);
         });
@@ -1066,7 +1146,7 @@ mod service_event {
     fn timed_wait_blocks_until_notification<Sut: Service>() {
         wait_blocks_until_notification(|l: &Listener<Sut>| {
             let id = l.timed_wait_one(TIMEOUT * 1000).unwrap();
-            assert_that!(id, eq Some(EventId::new(13)));
+            assert_that!(id, eq Some(EventId::new(13).unwrap()));
         })
     }
 
@@ -1074,7 +1154,7 @@ mod service_event {
     fn blocking_wait_blocks_until_notification<Sut: Service>() {
         wait_blocks_until_notification(|l: &Listener<Sut>| {
             let id = l.blocking_wait_one().unwrap();
-            assert_that!(id, eq Some(EventId::new(13)));
+            assert_that!(id, eq Some(EventId::new(13).unwrap()));
         })
     }
 
@@ -1162,7 +1242,7 @@ mod service_event {
             assert_that!(
                 l.timed_wait_all(
                     |id| {
-                        assert_that!(id, eq EventId::new(13));
+                        assert_that!(id, eq EventId::new(13).unwrap());
                         callback_was_called = true;
                     },
                     TIMEOUT * 1000
@@ -1179,7 +1259,7 @@ mod service_event {
         wait_blocks_until_notification(|l: &Listener<Sut>| {
             assert_that!(
                 l.blocking_wait_all(|id| {
-                    assert_that!(id, eq EventId::new(13));
+                    assert_that!(id, eq EventId::new(13).unwrap());
                     callback_was_called = true;
                 }),
                 is_ok
@@ -1210,7 +1290,7 @@ mod service_event {
         let notifier = sut.notifier_builder().create().unwrap();
 
         for i in 0..number_of_notifications {
-            assert_that!(notifier.notify_with_custom_event_id(EventId::new(i)).unwrap(), eq 1);
+            assert_that!(notifier.notify_with_custom_event_id(EventId::new(i).unwrap()).unwrap(), eq 1);
         }
 
         let mut id_set = HashSet::new();
@@ -1530,7 +1610,7 @@ mod service_event {
         let config = generate_isolated_config();
         let node_1 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
         let node_2 = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
-        let event_id = EventId::new(23);
+        let event_id = EventId::new(23).unwrap();
 
         let sut_1 = node_1
             .service_builder(&service_name)
@@ -1568,6 +1648,79 @@ mod service_event {
         assert_that!(received_events, eq 1);
     }
 
+    fn fill_event_buffer_of_listener<Sut: Service>(notifier: &Notifier<Sut>) -> bool {
+        const MAX_NUMBER_OF_NOTIFICATIONS: usize = 1_000_000;
+        for _ in 0..MAX_NUMBER_OF_NOTIFICATIONS {
+            if notifier.notify().unwrap() == 0 {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    #[test]
+    fn timed_notify_fails_with_timeout_when_event_buffer_of_listener_is_full<Sut: Service>() {
+        const TIMEOUT: Duration = Duration::from_millis(50);
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let notifier = sut.notifier_builder().create().unwrap();
+        let listener = sut.listener_builder().create().unwrap();
+
+        // event mechanisms that never run full cannot block the notifier
+        if !fill_event_buffer_of_listener(&notifier) {
+            return;
+        }
+
+        let start = Instant::now();
+        assert_that!(notifier.timed_notify(TIMEOUT).err(), eq Some(NotifierNotifyError::DeliveryTimeout));
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+
+        assert_that!(listener.drain(), is_ok);
+        assert_that!(notifier.timed_notify(TIMEOUT), eq Ok(1));
+    }
+
+    #[test]
+    fn timed_notify_delivers_notification_when_listener_catches_up<Sut: Service>() {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let listener = sut.listener_builder().create().unwrap();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let notifier = sut.notifier_builder().create().unwrap();
+                let is_full = fill_event_buffer_of_listener(&notifier);
+                barrier.wait();
+
+                if is_full {
+                    assert_that!(notifier.timed_notify(Duration::from_secs(10)), eq Ok(1));
+                }
+            });
+
+            barrier.wait();
+            std::thread::sleep(Duration::from_millis(10));
+            assert_that!(listener.drain(), is_ok);
+        });
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
@@ -53,7 +53,7 @@ fn notifying_events_concurrently_works() {
                 barrier.wait();
                 for n in 0..NUMBER_OF_ITERATIONS {
                     while notifier
-                        .notify_with_custom_event_id(EventId::new(n))
+                        .notify_with_custom_event_id(EventId::new(n).unwrap())
                         .unwrap()
                         == 0
                     {}
@@ -141,7 +141,7 @@ fn listening_on_events_concurrently_works() {
         barrier.wait();
         for n in 0..NUMBER_OF_ITERATIONS {
             while notifier
-                .notify_with_custom_event_id(EventId::new(n))
+                .notify_with_custom_event_id(EventId::new(n).unwrap())
                 .unwrap()
                 == 0
             {}
@@ -0,0 +1,115 @@
+// Copyright (c) 2025 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod service_name {
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::service_name::{ServiceNameError, ServiceNamePattern};
+    use iceoryx2_bb_testing::assert_that;
+    use serde::Deserialize;
+
+    fn matches(name: &str, pattern: &str) -> bool {
+        ServiceName::new(name)
+            .unwrap()
+            .matches(&ServiceNamePattern::new(pattern).unwrap())
+    }
+
+    #[test]
+    fn segments_works() {
+        let sut = ServiceName::new("vehicle/front_lidar/points").unwrap();
+
+        let segments: Vec<&str> = sut.segments().collect();
+        assert_that!(segments, eq vec!["vehicle", "front_lidar", "points"]);
+    }
+
+    #[test]
+    fn segments_skips_empty_segments() {
+        let sut = ServiceName::new("/vehicle//points/").unwrap();
+
+        let segments: Vec<&str> = sut.segments().collect();
+        assert_that!(segments, eq vec!["vehicle", "points"]);
+    }
+
+    #[test]
+    fn starts_with_namespace_compares_whole_segments() {
+        let sut = ServiceName::new("vehicle/front_lidar/points").unwrap();
+
+        let namespace = ServiceName::new("vehicle").unwrap();
+        assert_that!(sut.starts_with_namespace(&namespace), eq true);
+        let namespace = ServiceName::new("vehicle/front_lidar").unwrap();
+        assert_that!(sut.starts_with_namespace(&namespace), eq true);
+        let namespace = ServiceName::new("vehicle/front_lidar/points").unwrap();
+        assert_that!(sut.starts_with_namespace(&namespace), eq true);
+
+        let namespace = ServiceName::new("vehicle/front").unwrap();
+        assert_that!(sut.starts_with_namespace(&namespace), eq false);
+        let namespace = ServiceName::new("vehicle/front_lidar/points/x").unwrap();
+        assert_that!(sut.starts_with_namespace(&namespace), eq false);
+        let namespace = ServiceName::new("front_lidar").unwrap();
+        assert_that!(sut.starts_with_namespace(&namespace), eq false);
+    }
+
+    #[test]
+    fn literal_pattern_matches_only_equal_name() {
+        assert_that!(matches("a/b/c", "a/b/c"), eq true);
+        assert_that!(matches("a/b/c", "a/b"), eq false);
+        assert_that!(matches("a/b", "a/b/c"), eq false);
+        assert_that!(matches("a/b/c", "a/x/c"), eq false);
+    }
+
+    #[test]
+    fn single_segment_wildcard_matches_exactly_one_segment() {
+        assert_that!(matches("a/b/c", "a/*/c"), eq true);
+        assert_that!(matches("a/b/c", "*/*/*"), eq true);
+        assert_that!(matches("a/c", "a/*/c"), eq false);
+        assert_that!(matches("a/b/x/c", "a/*/c"), eq false);
+    }
+
+    #[test]
+    fn multi_segment_wildcard_matches_any_depth() {
+        assert_that!(matches("a/b/c", "a/**"), eq true);
+        assert_that!(matches("a", "a/**"), eq true);
+        assert_that!(matches("a/b/c/d", "a/**/d"), eq true);
+        assert_that!(matches("a/d", "a/**/d"), eq true);
+        assert_that!(matches("a/b/c", "**"), eq true);
+        assert_that!(matches("a/b/c", "**/c"), eq true);
+        assert_that!(matches("a/b/c", "**/b/*"), eq true);
+        assert_that!(matches("a/b/c", "**/x"), eq false);
+        assert_that!(matches("b/c", "a/**"), eq false);
+    }
+
+    #[test]
+    fn pattern_with_partial_wildcard_segment_is_invalid() {
+        assert_that!(ServiceNamePattern::new("a/b*"), eq Err(ServiceNameError::InvalidContent));
+        assert_that!(ServiceNamePattern::new("a/***/c"), eq Err(ServiceNameError::InvalidContent));
+        assert_that!(ServiceNamePattern::new(""), eq Err(ServiceNameError::InvalidContent));
+    }
+
+    #[test]
+    fn service_name_with_wildcard_segment_is_invalid() {
+        assert_that!(ServiceName::new("a/*/c"), eq Err(ServiceNameError::InvalidContent));
+        assert_that!(ServiceName::new("a/**"), eq Err(ServiceNameError::InvalidContent));
+        assert_that!(ServiceName::new("a/b*"), eq Err(ServiceNameError::InvalidContent));
+        assert_that!(ServiceName::new(""), eq Err(ServiceNameError::InvalidContent));
+    }
+
+    #[test]
+    fn existing_service_name_with_wildcard_segment_can_be_converted() {
+        let result: Result<ServiceName, _> = "a/*/c".try_into();
+        assert_that!(result.map(|name| name == "a/*/c"), eq Ok(true));
+    }
+
+    #[test]
+    fn existing_service_name_with_wildcard_segment_can_be_deserialized() {
+        let result = ServiceName::deserialize(toml::Value::String("a/*/c".to_string()));
+        assert_that!(result.map(|name| name == "a/*/c"), eq Ok(true));
+    }
+}
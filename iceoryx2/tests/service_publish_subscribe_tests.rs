@@ -2512,6 +2512,42 @@ mod service_publish_subscribe {
         }
     }
 
+    #[test]
+    fn sliced_service_with_manually_initialized_uninit_slice_works<Sut: Service>() {
+        const MAX_ELEMENTS: usize = 37;
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(MAX_ELEMENTS)
+            .allocation_strategy(AllocationStrategy::Static)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan_slice_uninit(MAX_ELEMENTS).unwrap();
+        for (i, element) in sample.payload_mut().iter_mut().enumerate() {
+            element.write(i as u64 * 3);
+        }
+        unsafe { sample.assume_init() }.send().unwrap();
+
+        let recv_sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(recv_sample.payload(), len MAX_ELEMENTS);
+        for (i, element) in recv_sample.payload().iter().enumerate() {
+            assert_that!(*element, eq i as u64 * 3);
+        }
+
+        let sample = publisher.loan_slice_uninit(MAX_ELEMENTS + 1);
+        assert_that!(sample.err(), eq Some(LoanError::ExceedsMaxLoanSize));
+    }
+
     #[test]
     fn slice_aligned_service_works<Sut: Service>() {
         const MAX_ELEMENTS: usize = 91;
@@ -3474,6 +3510,9 @@ mod service_publish_subscribe {
     #[instantiate_tests(<iceoryx2::service::ipc_threadsafe::Service>)]
     mod ipc_threadsafe {}
 
+    #[instantiate_tests(<iceoryx2::service::ipc_memfd::Service>)]
+    mod ipc_memfd {}
+
     #[instantiate_tests(<iceoryx2::service::local_threadsafe::Service>)]
     mod local_threadsafe {}
 }
@@ -393,7 +393,7 @@ mod service {
         let received_sample = sut_subscriber.receive().unwrap().unwrap();
         assert_that!(*received_sample, eq(SAMPLE_VALUE));
 
-        const EVENT_ID: EventId = EventId::new(31);
+        const EVENT_ID: EventId = EventId::new_unchecked(31);
         sut_notifier.notify_with_custom_event_id(EVENT_ID).unwrap();
         let received_event = sut_listener.try_wait_one().unwrap();
         assert_that!(received_event, eq Some(EVENT_ID));
@@ -1195,6 +1195,40 @@ mod service {
         }
     }
 
+    #[test]
+    fn service_name_longer_than_file_name_length_works<Sut: Service, Factory: SutFactory<Sut>>() {
+        const NAME_LENGTH: usize = 500;
+        let test = Factory::new();
+        let prefix = generate_name();
+        let service_name = ServiceName::new(&format!(
+            "{}/{}",
+            prefix.as_str(),
+            "s".repeat(NAME_LENGTH - prefix.len() - 1)
+        ))
+        .unwrap();
+        assert_that!(service_name.len(), eq NAME_LENGTH);
+
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+        let sut = test.create(&node, &service_name, &AttributeSpecifier::new());
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+
+        let sut2 = test.open(&node, &service_name, &AttributeVerifier::new());
+        assert_that!(sut2, is_ok);
+        let sut2 = sut2.unwrap();
+        assert_that!(sut.name(), eq & service_name);
+        assert_that!(sut2.name(), eq & service_name);
+
+        let mut listed_names = vec![];
+        let result = Sut::list(&config, |service| {
+            listed_names.push(service.static_details.name().clone());
+            CallbackProgression::Continue
+        });
+        assert_that!(result, is_ok);
+        assert_that!(listed_names, contains service_name);
+    }
+
     #[test]
     fn uuid_is_equal_in_within_all_opened_instances<Sut: Service, Factory: SutFactory<Sut>>() {
         let test = Factory::new();
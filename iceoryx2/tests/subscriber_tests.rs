@@ -72,6 +72,190 @@ mod subscriber {
         }
     }
 
+    #[test]
+    fn receive_all_drains_all_samples<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 5;
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create()
+            .unwrap();
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let received: Vec<u64> = sut.receive_all().map(|sample| *sample).collect();
+        assert_that!(received, eq(0..NUMBER_OF_SAMPLES).collect::<Vec<u64>>());
+
+        assert_that!(sut.receive_all().next(), is_none);
+    }
+
+    #[test]
+    fn dropping_receive_all_iterator_keeps_remaining_samples<Sut: Service>() {
+        const NUMBER_OF_SAMPLES: u64 = 5;
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .buffer_size(NUMBER_OF_SAMPLES as usize)
+            .create()
+            .unwrap();
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            publisher.send_copy(n).unwrap();
+        }
+
+        let received: Vec<u64> = sut.receive_all().take(2).map(|sample| *sample).collect();
+        assert_that!(received, eq vec![0, 1]);
+
+        let received: Vec<u64> = sut.receive_all().map(|sample| *sample).collect();
+        assert_that!(received, eq vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn peek_without_samples_returns_none<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut = service.subscriber_builder().create().unwrap();
+
+        assert_that!(sut.peek().unwrap(), is_none);
+    }
+
+    #[test]
+    fn peek_then_receive_returns_same_sample<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(2)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(1234).unwrap();
+        publisher.send_copy(5678).unwrap();
+
+        let peeked = sut.peek().unwrap().unwrap();
+        assert_that!(*peeked, eq 1234);
+        assert_that!(peeked.origin(), eq publisher.id());
+        drop(peeked);
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 1234);
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 5678);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn peek_does_not_advance_the_queue<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(2)
+            .create()
+            .unwrap();
+
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(12).unwrap();
+        publisher.send_copy(34).unwrap();
+
+        let peeked_1 = sut.peek().unwrap().unwrap();
+        let peeked_2 = sut.peek().unwrap().unwrap();
+        assert_that!(*peeked_1, eq 12);
+        assert_that!(*peeked_2, eq 12);
+        drop(peeked_1);
+        drop(peeked_2);
+
+        assert_that!(*sut.peek().unwrap().unwrap(), eq 12);
+        assert_that!(sut.has_samples().unwrap(), eq true);
+
+        let received: Vec<u64> = sut.receive_all().map(|sample| *sample).collect();
+        assert_that!(received, eq vec![12, 34]);
+        assert_that!(sut.has_samples().unwrap(), eq false);
+    }
+
+    #[test]
+    fn peeked_sample_stays_valid_after_it_was_received_and_released<Sut: Service>() {
+        let service_name = generate_name();
+        let config = testing::generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<Sut>().unwrap();
+
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(1)
+            .subscriber_max_borrowed_samples(2)
+            .create()
+            .unwrap();
+
+        let publisher = service
+            .publisher_builder()
+            .max_loaned_samples(1)
+            .create()
+            .unwrap();
+        let sut = service.subscriber_builder().create().unwrap();
+
+        publisher.send_copy(42).unwrap();
+
+        let peeked = sut.peek().unwrap().unwrap();
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 42);
+        assert_that!(sample.header().publisher_id(), eq peeked.header().publisher_id());
+        drop(sample);
+
+        // the peeked sample is still owned by the subscriber, publishing must not reuse it
+        publisher.send_copy(43).unwrap();
+        assert_that!(*peeked, eq 42);
+        drop(peeked);
+
+        assert_that!(*sut.peek().unwrap().unwrap(), eq 43);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 43);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
     #[test]
     #[should_panic]
     #[cfg(debug_assertions)]
@@ -12,6 +12,7 @@
 
 #[generic_tests::define]
 mod waitset {
+    use core::mem::ManuallyDrop;
     use core::time::Duration;
     use std::time::Instant;
 
@@ -19,7 +20,10 @@ mod waitset {
     use iceoryx2::port::notifier::Notifier;
     use iceoryx2::prelude::{WaitSetBuilder, *};
     use iceoryx2::testing::*;
-    use iceoryx2::waitset::{WaitSetAttachmentError, WaitSetRunError};
+    use iceoryx2::waitset::{
+        ClockType, TriggerMode, WaitSetAttachmentError, WaitSetAttachmentKind, WaitSetRunError,
+        WaitSetRunResult,
+    };
     use iceoryx2_bb_posix::config::test_directory;
     use iceoryx2_bb_posix::directory::Directory;
     use iceoryx2_bb_posix::file::Permission;
@@ -27,7 +31,9 @@ mod waitset {
         UnixDatagramReceiver, UnixDatagramSender, UnixDatagramSenderBuilder,
     };
     use iceoryx2_bb_posix::{
-        file_descriptor_set::SynchronousMultiplexing, unique_system_id::UniqueSystemId,
+        file_descriptor::{FileDescriptor, FileDescriptorBased},
+        file_descriptor_set::SynchronousMultiplexing,
+        unique_system_id::{UniqueFileNameExt, UniqueSystemId},
         unix_datagram_socket::UnixDatagramReceiverBuilder,
     };
     use iceoryx2_bb_testing::watchdog::Watchdog;
@@ -46,12 +52,13 @@ mod waitset {
 
     fn generate_uds_name() -> FilePath {
         let mut path = test_directory();
-        Directory::create(&path, Permission::OWNER_ALL).unwrap();
+        Directory::create_with_parents(&path, Permission::OWNER_ALL).unwrap();
         let _ = path.add_path_entry(
-            &Path::new(
-                format!("waitset_tests_{}", UniqueSystemId::new().unwrap().value()).as_bytes(),
-            )
-            .unwrap(),
+            &FileName::new(b"waitset_tests")
+                .unwrap()
+                .with_unique_suffix()
+                .unwrap()
+                .into(),
         );
 
         FilePath::new(path.as_bytes()).unwrap()
@@ -90,6 +97,40 @@ mod waitset {
         assert_that!(result.err(), eq Some(WaitSetRunError::NoAttachments));
     }
 
+    #[derive(Debug)]
+    struct InvalidFileDescriptor(ManuallyDrop<FileDescriptor>);
+
+    impl InvalidFileDescriptor {
+        fn new() -> Self {
+            // no process can have that many open file descriptors, therefore it is never valid
+            Self(ManuallyDrop::new(unsafe {
+                FileDescriptor::new_unchecked(i32::MAX)
+            }))
+        }
+    }
+
+    impl FileDescriptorBased for InvalidFileDescriptor {
+        fn file_descriptor(&self) -> &FileDescriptor {
+            &self.0
+        }
+    }
+
+    impl SynchronousMultiplexing for InvalidFileDescriptor {}
+
+    #[test]
+    fn attaching_disconnected_attachment_fails<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let attachment = InvalidFileDescriptor::new();
+
+        let result = sut.attach_notification(&attachment);
+        assert_that!(result.err(), eq Some(WaitSetAttachmentError::Disconnected));
+
+        let result = sut.attach_deadline(&attachment, TIMEOUT);
+        assert_that!(result.err(), eq Some(WaitSetAttachmentError::Disconnected));
+
+        assert_that!(sut.is_empty(), eq true);
+    }
+
     #[test]
     fn attach_multiple_notifications_works<S: Service>()
     where
@@ -273,6 +314,184 @@ mod waitset {
         assert_that!(start.elapsed(), time_at_least TIMEOUT);
     }
 
+    #[test]
+    fn waitset_uses_monotonic_clock_source_by_default<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        assert_that!(sut.clock_source(), eq ClockType::default());
+        if ClockType::all_supported_clocks().contains(&ClockType::Monotonic) {
+            assert_that!(sut.clock_source(), eq ClockType::Monotonic);
+        }
+    }
+
+    #[test]
+    fn deadline_fires_after_timeout_with_every_clock_source<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+
+        for clock_source in ClockType::all_supported_clocks() {
+            let sut = WaitSetBuilder::new()
+                .clock_source(*clock_source)
+                .create::<S>()
+                .unwrap();
+            assert_that!(sut.clock_source(), eq * clock_source);
+
+            let (listener, _) = create_event::<S>(&node);
+            let guard = sut.attach_deadline(&listener, TIMEOUT).unwrap();
+
+            let start = Instant::now();
+            let mut missed_deadline = false;
+            sut.wait_and_process_once(|id| {
+                missed_deadline |= id.has_missed_deadline(&guard);
+                CallbackProgression::Continue
+            })
+            .unwrap();
+
+            assert_that!(missed_deadline, eq true);
+            assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        }
+    }
+
+    #[test]
+    fn time_until_next_deadline_is_none_without_timed_attachments<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        assert_that!(sut.time_until_next_deadline(), eq None);
+
+        let (listener, _) = create_event::<S>(&node);
+        let _guard = sut.attach_notification(&listener).unwrap();
+        assert_that!(sut.time_until_next_deadline(), eq None);
+    }
+
+    #[test]
+    fn time_until_next_deadline_returns_shortest_deadline<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        const SHORT_DEADLINE: Duration = Duration::from_secs(5);
+        const LONG_DEADLINE: Duration = Duration::from_secs(50);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+
+        let (listener_1, _) = create_event::<S>(&node);
+        let (listener_2, _) = create_event::<S>(&node);
+        let _guard_1 = sut.attach_deadline(&listener_1, LONG_DEADLINE).unwrap();
+        let guard_2 = sut.attach_deadline(&listener_2, SHORT_DEADLINE).unwrap();
+
+        let result = sut.time_until_next_deadline();
+        assert_that!(result, is_some);
+        let result = result.unwrap();
+        assert_that!(result, le SHORT_DEADLINE);
+        assert_that!(result, ge SHORT_DEADLINE - TIMEOUT);
+
+        drop(guard_2);
+        let result = sut.time_until_next_deadline().unwrap();
+        assert_that!(result, gt SHORT_DEADLINE);
+        assert_that!(result, le LONG_DEADLINE);
+    }
+
+    #[test]
+    fn attachments_reports_all_attachments_with_their_kind<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        const DEADLINE: Duration = Duration::from_millis(100);
+        const INTERVAL: Duration = Duration::from_millis(250);
+        let config = generate_isolated_config();
+        let node = NodeBuilder::new().config(&config).create::<S>().unwrap();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        assert_that!(sut.attachments().count(), eq 0);
+
+        let (listener_1, _) = create_event::<S>(&node);
+        let (listener_2, _) = create_event::<S>(&node);
+        let (listener_3, _) = create_event::<S>(&node);
+        let (listener_4, _) = create_event::<S>(&node);
+        let notification_guard_1 = sut.attach_notification(&listener_1).unwrap();
+        let notification_guard_2 = sut.attach_notification(&listener_2).unwrap();
+        let notification_guard_3 = sut.attach_notification(&listener_3).unwrap();
+        let deadline_guard = sut.attach_deadline(&listener_4, DEADLINE).unwrap();
+        let interval_guard = sut.attach_interval(INTERVAL).unwrap();
+
+        let attachments: Vec<_> = sut.attachments().collect();
+        assert_that!(attachments, len 5);
+
+        let kind_of = |guard: &WaitSetGuard<S>| {
+            let id = WaitSetAttachmentId::from_guard(guard);
+            attachments.iter().find(|a| *a.id() == id).map(|a| a.kind())
+        };
+        assert_that!(kind_of(&notification_guard_1), eq Some(WaitSetAttachmentKind::Notification));
+        assert_that!(kind_of(&notification_guard_2), eq Some(WaitSetAttachmentKind::Notification));
+        assert_that!(kind_of(&notification_guard_3), eq Some(WaitSetAttachmentKind::Notification));
+        assert_that!(kind_of(&deadline_guard), eq Some(WaitSetAttachmentKind::Deadline(DEADLINE)));
+        assert_that!(kind_of(&interval_guard), eq Some(WaitSetAttachmentKind::Interval(INTERVAL)));
+
+        drop(notification_guard_2);
+        drop(deadline_guard);
+
+        let attachments: Vec<_> = sut.attachments().collect();
+        assert_that!(attachments, len 3);
+        let number_of_notifications = attachments
+            .iter()
+            .filter(|a| a.kind() == WaitSetAttachmentKind::Notification)
+            .count();
+        assert_that!(number_of_notifications, eq 2);
+    }
+
+    #[test]
+    fn wait_and_process_with_timeout_returns_after_timeout<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        const TOTAL_TIMEOUT: Duration = Duration::from_millis(200);
+        const INTERVAL: Duration = Duration::from_millis(50);
+        let _watchdog = Watchdog::new();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let guard = sut.attach_interval(INTERVAL).unwrap();
+
+        let mut number_of_ticks = 0;
+        let start = Instant::now();
+        let result = sut.wait_and_process_with_timeout(
+            |id| {
+                if id.has_event_from(&guard) {
+                    number_of_ticks += 1;
+                }
+                CallbackProgression::Continue
+            },
+            TOTAL_TIMEOUT,
+        );
+        let elapsed = start.elapsed();
+
+        assert_that!(result, eq Ok(WaitSetRunResult::AllEventsHandled));
+        assert_that!(elapsed, time_at_least TOTAL_TIMEOUT);
+        assert_that!(elapsed, lt TOTAL_TIMEOUT + 2 * INTERVAL);
+        assert_that!(number_of_ticks, ge 2);
+        assert_that!(number_of_ticks, le 4);
+    }
+
+    #[test]
+    fn wait_and_process_with_timeout_returns_on_stop_request<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let _guard = sut.attach_interval(Duration::from_millis(1)).unwrap();
+
+        let result = sut
+            .wait_and_process_with_timeout(|_| CallbackProgression::Stop, Duration::from_secs(10));
+
+        assert_that!(result, eq Ok(WaitSetRunResult::StopRequest));
+    }
+
     #[test]
     fn wait_and_process_once_does_not_block_longer_than_provided_timeout<S: Service>()
     where
@@ -604,6 +823,178 @@ mod waitset {
         assert_that!(now.elapsed(), time_at_least TIMEOUT / 2);
     }
 
+    fn count_wake_ups<S: Service>(sut: &WaitSet<S>, guard: &WaitSetGuard<S>) -> usize {
+        let mut number_of_wake_ups = 0;
+        sut.wait_and_process_once_with_timeout(
+            |attachment_id| {
+                if attachment_id.has_event_from(guard) {
+                    number_of_wake_ups += 1;
+                }
+                CallbackProgression::Continue
+            },
+            TIMEOUT,
+        )
+        .unwrap();
+
+        number_of_wake_ups
+    }
+
+    #[test]
+    fn level_triggered_notification_fires_until_consumed<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let (receiver, sender) = create_socket();
+
+        let guard = sut
+            .attach_notification_with_trigger_mode(&receiver, TriggerMode::Level)
+            .unwrap();
+
+        sender.try_send(b"bla").unwrap();
+
+        assert_that!(count_wake_ups(&sut, &guard), eq 1);
+        assert_that!(count_wake_ups(&sut, &guard), eq 1);
+
+        let mut buffer = [0u8; 8];
+        assert_that!(receiver.try_receive(&mut buffer), eq Ok(3));
+        assert_that!(count_wake_ups(&sut, &guard), eq 0);
+    }
+
+    #[test]
+    fn udp_socket_notification_wakes_up_waitset<S: Service>() {
+        use iceoryx2_bb_posix::udp_socket::{SocketAddress, UdpSocketBuilder};
+        use iceoryx2_bb_system_types::ipv4_address;
+        use iceoryx2_bb_system_types::port;
+
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let localhost = SocketAddress::new_v4(ipv4_address::LOCALHOST, port::UNSPECIFIED);
+        let receiver = UdpSocketBuilder::new().address(localhost).create().unwrap();
+        let sender = UdpSocketBuilder::new().address(localhost).create().unwrap();
+
+        let guard = sut.attach_notification(&receiver).unwrap();
+        assert_that!(count_wake_ups(&sut, &guard), eq 0);
+
+        sender
+            .try_send_to(b"bla", receiver.local_address())
+            .unwrap();
+        assert_that!(count_wake_ups(&sut, &guard), eq 1);
+
+        let mut buffer = [0u8; 8];
+        let details = receiver.try_receive_from(&mut buffer).unwrap().unwrap();
+        assert_that!(details.number_of_bytes, eq 3);
+        assert_that!(details.source(), eq sender.local_address());
+        assert_that!(count_wake_ups(&sut, &guard), eq 0);
+    }
+
+    #[test]
+    fn writable_attachment_fires_when_socket_can_accept_data<S: Service>() {
+        let _watchdog = Watchdog::new();
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let (receiver, sender) = create_socket();
+
+        while sender.try_send(b"fill").unwrap() {}
+
+        let guard = sut.attach_writable(&sender).unwrap();
+        let kinds: Vec<_> = sut.attachments().map(|a| a.kind()).collect();
+        assert_that!(kinds, eq vec![WaitSetAttachmentKind::Writable]);
+        assert_that!(count_wake_ups(&sut, &guard), eq 0);
+
+        let mut buffer = [0u8; 8];
+        while receiver.try_receive(&mut buffer).unwrap() > 0 {}
+
+        assert_that!(count_wake_ups(&sut, &guard), eq 1);
+        assert_that!(sender.try_send(b"bla"), eq Ok(true));
+    }
+
+    #[test]
+    fn attaching_the_same_socket_as_writable_twice_fails<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let (_receiver, sender) = create_socket();
+
+        let _guard = sut.attach_writable(&sender).unwrap();
+        let result = sut.attach_writable(&sender);
+
+        assert_that!(result.err(), eq Some(WaitSetAttachmentError::AlreadyAttached));
+        assert_that!(sut.len(), eq 1);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "macos", target_os = "freebsd"))]
+    #[test]
+    fn edge_triggered_notification_fires_once_until_new_event_arrives<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let (receiver, sender) = create_socket();
+
+        let guard = sut
+            .attach_notification_with_trigger_mode(&receiver, TriggerMode::Edge)
+            .unwrap();
+
+        sender.try_send(b"bla").unwrap();
+
+        assert_that!(count_wake_ups(&sut, &guard), eq 1);
+        assert_that!(count_wake_ups(&sut, &guard), eq 0);
+
+        sender.try_send(b"fuu").unwrap();
+
+        assert_that!(count_wake_ups(&sut, &guard), eq 1);
+        assert_that!(count_wake_ups(&sut, &guard), eq 0);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "macos", target_os = "freebsd"))]
+    #[test]
+    fn attaching_more_than_fd_setsize_notifications_works<S: Service>() {
+        use iceoryx2_bb_posix::file_descriptor_set::FileDescriptorSet;
+        use iceoryx2_bb_posix::socket_pair::StreamingSocket;
+        use iceoryx2_bb_posix::system_configuration::ProcessResourceLimit;
+        use iceoryx2_bb_testing::test_requires;
+
+        const NUMBER_OF_ATTACHMENTS: usize = FileDescriptorSet::capacity() + 64;
+        // every attachment is a socket pair, the rest is reserved for the test environment
+        let required_file_descriptors = (2 * NUMBER_OF_ATTACHMENTS + 128) as u64;
+        let limit = ProcessResourceLimit::MaxNumberOfOpenFileDescriptors;
+        test_requires!(limit.hard_limit() >= required_file_descriptors);
+        if limit.soft_limit() < required_file_descriptors {
+            limit.set_soft_limit(required_file_descriptors);
+        }
+
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let sockets: Vec<_> = (0..NUMBER_OF_ATTACHMENTS)
+            .map(|_| StreamingSocket::create_pair().unwrap())
+            .collect();
+        let guards: Vec<_> = sockets
+            .iter()
+            .map(|(receiver, _)| sut.attach_notification(receiver).unwrap())
+            .collect();
+
+        assert_that!(sut.len(), eq NUMBER_OF_ATTACHMENTS);
+
+        let (receiver, sender) = sockets.last().unwrap();
+        let last_guard = guards.last().unwrap();
+        assert_that!(unsafe { receiver.file_descriptor().native_handle() } as usize, ge FileDescriptorSet::capacity());
+
+        sender.try_send(b"bla").unwrap();
+
+        let mut triggered_attachments = vec![];
+        sut.wait_and_process_once_with_timeout(
+            |attachment_id| {
+                triggered_attachments.push(attachment_id.has_event_from(last_guard));
+                CallbackProgression::Continue
+            },
+            TIMEOUT,
+        )
+        .unwrap();
+
+        assert_that!(triggered_attachments, eq vec![true]);
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    #[test]
+    fn edge_triggered_notification_is_not_supported<S: Service>() {
+        let sut = WaitSetBuilder::new().create::<S>().unwrap();
+        let (receiver, _sender) = create_socket();
+
+        let result = sut.attach_notification_with_trigger_mode(&receiver, TriggerMode::Edge);
+        assert_that!(result.err(), eq Some(WaitSetAttachmentError::UnsupportedTriggerMode));
+        assert_that!(sut.is_empty(), eq true);
+    }
+
     #[test]
     fn signal_handling_mechanism_can_be_configured<S: Service>() {
         let sut_1 = WaitSetBuilder::new()